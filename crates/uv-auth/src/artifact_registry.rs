@@ -0,0 +1,139 @@
+//! Automatic authentication for Google Artifact Registry repositories.
+//!
+//! Artifact Registry accepts OAuth access tokens, which are minted from Application Default
+//! Credentials (i.e., a service account key in `GOOGLE_APPLICATION_CREDENTIALS`, the credentials
+//! from `gcloud auth application-default login`, the metadata server, or the `gcloud` CLI).
+
+use std::sync::{Arc, LazyLock};
+
+use gcp_auth::TokenProvider;
+use tokio::sync::Mutex;
+use tracing::debug;
+use url::Url;
+
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::Credentials;
+
+/// The username used alongside OAuth access tokens.
+const USERNAME: &str = "oauth2accesstoken";
+
+/// The OAuth scope requested for access tokens.
+const SCOPES: &[&str] = &["https://www.googleapis.com/auth/cloud-platform"];
+
+/// Whether Artifact Registry authentication is disabled via `UV_NO_ARTIFACT_REGISTRY_TOKEN`.
+static DISABLED: LazyLock<bool> = LazyLock::new(|| {
+    let disabled = std::env::var_os(EnvVars::UV_NO_ARTIFACT_REGISTRY_TOKEN).is_some();
+    if disabled {
+        debug!(
+            "Ignoring Google Artifact Registry authentication due to `UV_NO_ARTIFACT_REGISTRY_TOKEN`"
+        );
+    }
+    disabled
+});
+
+/// The discovery of the token provider for the Application Default Credentials.
+static PROVIDER: Mutex<Discovery> = Mutex::const_new(Discovery::Pending);
+
+/// The state of the discovery of the Application Default Credentials.
+enum Discovery {
+    /// The credentials haven't been discovered yet.
+    Pending,
+    /// The token provider for the credentials, which caches the access token and refreshes it
+    /// before it expires.
+    Found(Arc<dyn TokenProvider>),
+    /// No credentials were found.
+    Missing,
+}
+
+/// Returns `true` if the URL is an Artifact Registry repository, e.g.,
+/// `https://us-central1-python.pkg.dev/my-project/my-repository/simple/`.
+fn is_artifact_registry(url: &Url) -> bool {
+    url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| host.ends_with(".pkg.dev"))
+}
+
+/// A provider for authentication credentials for Google Artifact Registry repositories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ArtifactRegistryProvider;
+
+impl ArtifactRegistryProvider {
+    /// Returns the credentials for an Artifact Registry repository URL, if it is one, minting an
+    /// access token or refreshing an expiring one as needed.
+    pub(crate) async fn credentials_for(url: &Url) -> Option<Credentials> {
+        if !is_artifact_registry(url) || *DISABLED {
+            return None;
+        }
+
+        let provider = {
+            let mut discovery = PROVIDER.lock().await;
+            if matches!(*discovery, Discovery::Pending) {
+                *discovery = match gcp_auth::provider().await {
+                    Ok(provider) => {
+                        debug!(
+                            "Found Application Default Credentials for Google Artifact Registry"
+                        );
+                        Discovery::Found(provider)
+                    }
+                    Err(err) => {
+                        warn_user_once!(
+                            "Failed to find Application Default Credentials for Google Artifact Registry: {err}"
+                        );
+                        Discovery::Missing
+                    }
+                };
+            }
+            match &*discovery {
+                Discovery::Found(provider) => provider.clone(),
+                Discovery::Pending | Discovery::Missing => return None,
+            }
+        };
+
+        match provider.token(SCOPES).await {
+            Ok(token) => Some(Credentials::basic(
+                Some(USERNAME.to_string()),
+                Some(token.as_str().to_string()),
+            )),
+            Err(err) => {
+                warn_user_once!("Failed to acquire a Google Artifact Registry access token: {err}");
+                None
+            }
+        }
+    }
+
+    /// Discard the access token (e.g., after it was rejected), such that a new one is minted for
+    /// the next request.
+    pub(crate) async fn invalidate() {
+        let mut discovery = PROVIDER.lock().await;
+        if matches!(*discovery, Discovery::Found(_)) {
+            // The provider caches its token, so discover the credentials again.
+            *discovery = Discovery::Pending;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn artifact_registry_url() {
+        for url in [
+            "https://us-central1-python.pkg.dev/my-project/my-repository/simple/",
+            "https://europe-west1-python.pkg.dev/my-project/my-repository/simple/flask/",
+        ] {
+            assert!(is_artifact_registry(&Url::parse(url).unwrap()));
+        }
+        for url in [
+            "https://pypi.org/simple/",
+            "https://pkg.dev/simple/",
+            "https://us-central1-python.pkg.dev.example.com/simple/",
+            "http://us-central1-python.pkg.dev/my-project/my-repository/simple/",
+        ] {
+            assert!(!is_artifact_registry(&Url::parse(url).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! Authentication for Azure Artifacts feeds with Microsoft Entra ID tokens.
+//!
+//! Tokens are acquired with the flow configured for the index (managed identity, service
+//! principal, or device code), and refreshed as they near expiry.
+
+use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, bail};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use uv_static::EnvVars;
+use uv_warnings::{warn_user, warn_user_once};
+
+use crate::Credentials;
+
+/// The Entra ID application ID of Azure DevOps, i.e., the resource for which tokens are acquired.
+const AZURE_DEVOPS_RESOURCE: &str = "499b84ac-1321-427f-aa17-267ca6975798";
+
+/// The Entra ID application ID of the Azure CLI, the public client used for the device code flow.
+const DEVICE_CODE_CLIENT_ID: &str = "04b07795-8ddb-461a-bbee-02f9e1bd7b46";
+
+/// The endpoint of the Azure Instance Metadata Service, for managed identities.
+const IMDS_ENDPOINT: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+
+/// The time before expiry at which a token is refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The lifetime assumed for a token if its expiry isn't reported.
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// The tokens acquired for each identity, or `None` if acquisition failed.
+static TOKENS: LazyLock<Mutex<FxHashMap<AzureIdentity, Option<Token>>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// The client used for requests to Microsoft Entra ID and the metadata service.
+static CLIENT: LazyLock<Result<reqwest::Client, reqwest::Error>> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(10))
+        .build()
+});
+
+/// Returns the client used for requests to Microsoft Entra ID and the metadata service.
+fn client() -> anyhow::Result<&'static reqwest::Client> {
+    CLIENT
+        .as_ref()
+        .map_err(|err| anyhow::anyhow!("Failed to build HTTP client: {err}"))
+}
+
+/// How to acquire Microsoft Entra ID tokens for an Azure Artifacts feed.
+#[derive(
+    Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AzureIdentity {
+    /// Use the managed identity of the Azure resource on which uv is running (e.g., a virtual
+    /// machine, App Service, or Azure Pipelines agent).
+    ///
+    /// To use a user-assigned identity, set `AZURE_CLIENT_ID` to its client ID.
+    ManagedIdentity,
+    /// Use a service principal, as configured by the `AZURE_TENANT_ID`, `AZURE_CLIENT_ID`, and
+    /// `AZURE_CLIENT_SECRET` environment variables.
+    ServicePrincipal,
+    /// Sign in interactively, by entering a code in the browser on any device.
+    ///
+    /// The tenant can be set with `AZURE_TENANT_ID`.
+    DeviceCode,
+}
+
+impl Display for AzureIdentity {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AzureIdentity::ManagedIdentity => write!(f, "managed-identity"),
+            AzureIdentity::ServicePrincipal => write!(f, "service-principal"),
+            AzureIdentity::DeviceCode => write!(f, "device-code"),
+        }
+    }
+}
+
+/// An Entra ID access token.
+#[derive(Debug, Clone)]
+struct Token {
+    value: String,
+    expiration: SystemTime,
+    /// The refresh token, with which a new access token can be acquired without signing in
+    /// again.
+    refresh_token: Option<String>,
+}
+
+impl Token {
+    /// Returns `true` if the token has expired, or will expire within the refresh margin.
+    fn expires_soon(&self) -> bool {
+        !self
+            .expiration
+            .duration_since(SystemTime::now())
+            .is_ok_and(|remaining| remaining > REFRESH_MARGIN)
+    }
+
+    /// The credentials with which to authenticate requests using the token.
+    fn credentials(&self) -> Credentials {
+        Credentials::Bearer {
+            token: self.value.clone().into_bytes(),
+        }
+    }
+}
+
+/// A token response from Microsoft Entra ID or a managed identity endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// The lifetime of the token, in seconds.
+    #[serde(default)]
+    expires_in: Option<Seconds>,
+    /// The expiry of the token, in seconds since the Unix epoch.
+    #[serde(default)]
+    expires_on: Option<Seconds>,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+impl From<TokenResponse> for Token {
+    fn from(response: TokenResponse) -> Self {
+        let now = SystemTime::now();
+        let expiration = if let Some(seconds) = response.expires_in.and_then(Seconds::value) {
+            now + Duration::from_secs(seconds)
+        } else if let Some(seconds) = response.expires_on.and_then(Seconds::value) {
+            UNIX_EPOCH + Duration::from_secs(seconds)
+        } else {
+            now + DEFAULT_LIFETIME
+        };
+        Self {
+            value: response.access_token,
+            expiration,
+            refresh_token: response.refresh_token,
+        }
+    }
+}
+
+/// A number of seconds, which managed identity endpoints report as a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Seconds {
+    Number(u64),
+    String(String),
+}
+
+impl Seconds {
+    fn value(self) -> Option<u64> {
+        match self {
+            Seconds::Number(seconds) => Some(seconds),
+            Seconds::String(seconds) => seconds.parse().ok(),
+        }
+    }
+}
+
+/// An error response from Microsoft Entra ID.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+impl ErrorResponse {
+    /// A description of the error, for display.
+    fn describe(&self) -> String {
+        match self.error_description.as_deref() {
+            Some(description) => format!("{}: {description}", self.error),
+            None => self.error.clone(),
+        }
+    }
+}
+
+/// A device authorization response from Microsoft Entra ID.
+#[derive(Debug, Deserialize)]
+struct DeviceCodeResponse {
+    device_code: String,
+    /// The instructions to show to the user.
+    message: String,
+    expires_in: u64,
+    interval: u64,
+}
+
+/// A provider for authentication credentials for Azure Artifacts feeds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AzureProvider;
+
+impl AzureProvider {
+    /// Returns the credentials for the identity, acquiring a token or refreshing an expiring one
+    /// as needed.
+    pub(crate) async fn credentials_for(identity: AzureIdentity) -> Option<Credentials> {
+        // Hold the lock while fetching, such that concurrent requests share a single token (and
+        // the user is only asked to sign in once).
+        let mut tokens = TOKENS.lock().await;
+        let refresh_token = match tokens.get(&identity) {
+            Some(None) => return None,
+            Some(Some(token)) if !token.expires_soon() => return Some(token.credentials()),
+            Some(Some(token)) => {
+                debug!("Refreshing Microsoft Entra ID token for `{identity}` identity");
+                token.refresh_token.clone()
+            }
+            None => {
+                debug!("Acquiring Microsoft Entra ID token for `{identity}` identity");
+                None
+            }
+        };
+
+        let token = match fetch_token(identity, refresh_token.as_deref()).await {
+            Ok(token) => Some(token),
+            Err(err) => {
+                warn_user_once!(
+                    "Failed to acquire a Microsoft Entra ID token for Azure Artifacts with the `{identity}` identity: {err:#}"
+                );
+                None
+            }
+        };
+        let credentials = token.as_ref().map(Token::credentials);
+        tokens.insert(identity, token);
+        credentials
+    }
+
+    /// Mark the token for the identity as expired (e.g., after it was rejected), such that it's
+    /// refreshed for the next request.
+    pub(crate) async fn invalidate(identity: AzureIdentity) {
+        if let Some(Some(token)) = TOKENS.lock().await.get_mut(&identity) {
+            token.expiration = UNIX_EPOCH;
+        }
+    }
+}
+
+/// Fetch a token for Azure DevOps with the given identity.
+async fn fetch_token(
+    identity: AzureIdentity,
+    refresh_token: Option<&str>,
+) -> anyhow::Result<Token> {
+    match identity {
+        AzureIdentity::ManagedIdentity => fetch_managed_identity_token().await,
+        AzureIdentity::ServicePrincipal => fetch_service_principal_token().await,
+        AzureIdentity::DeviceCode => {
+            if let Some(refresh_token) = refresh_token {
+                match fetch_refreshed_token(refresh_token).await {
+                    Ok(token) => return Ok(token),
+                    Err(err) => debug!("Failed to refresh Microsoft Entra ID token: {err:#}"),
+                }
+            }
+            fetch_device_code_token().await
+        }
+    }
+}
+
+/// Fetch a token for the managed identity, from the App Service identity endpoint if available,
+/// or the Instance Metadata Service otherwise.
+async fn fetch_managed_identity_token() -> anyhow::Result<Token> {
+    let client_id = std::env::var(EnvVars::AZURE_CLIENT_ID).ok();
+    let mut query = vec![("resource", AZURE_DEVOPS_RESOURCE)];
+    if let Some(client_id) = client_id.as_deref() {
+        query.push(("client_id", client_id));
+    }
+
+    let request = if let (Ok(endpoint), Ok(header)) = (
+        std::env::var(EnvVars::IDENTITY_ENDPOINT),
+        std::env::var(EnvVars::IDENTITY_HEADER),
+    ) {
+        query.push(("api-version", "2019-08-01"));
+        client()?
+            .get(endpoint)
+            .query(&query)
+            .header("X-IDENTITY-HEADER", header)
+    } else {
+        query.push(("api-version", "2018-02-01"));
+        client()?
+            .get(IMDS_ENDPOINT)
+            .query(&query)
+            .header("Metadata", "true")
+    };
+
+    let response = request.send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!(
+            "Managed identity endpoint returned {status}: {}",
+            response.text().await.unwrap_or_default()
+        );
+    }
+    Ok(response.json::<TokenResponse>().await?.into())
+}
+
+/// Fetch a token for the service principal configured in the environment.
+async fn fetch_service_principal_token() -> anyhow::Result<Token> {
+    let tenant = std::env::var(EnvVars::AZURE_TENANT_ID)
+        .with_context(|| format!("`{}` is not set", EnvVars::AZURE_TENANT_ID))?;
+    let client_id = std::env::var(EnvVars::AZURE_CLIENT_ID)
+        .with_context(|| format!("`{}` is not set", EnvVars::AZURE_CLIENT_ID))?;
+    let client_secret = std::env::var(EnvVars::AZURE_CLIENT_SECRET)
+        .with_context(|| format!("`{}` is not set", EnvVars::AZURE_CLIENT_SECRET))?;
+    request_token(
+        &tenant,
+        &[
+            ("grant_type", "client_credentials"),
+            ("client_id", &client_id),
+            ("client_secret", &client_secret),
+            ("scope", &format!("{AZURE_DEVOPS_RESOURCE}/.default")),
+        ],
+    )
+    .await?
+    .map(Token::from)
+    .map_err(|err| anyhow::anyhow!("{}", err.describe()))
+}
+
+/// Fetch a new token with the refresh token from an earlier sign-in.
+async fn fetch_refreshed_token(refresh_token: &str) -> anyhow::Result<Token> {
+    request_token(
+        &tenant(),
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", DEVICE_CODE_CLIENT_ID),
+            ("refresh_token", refresh_token),
+            ("scope", &device_code_scope()),
+        ],
+    )
+    .await?
+    .map(Token::from)
+    .map_err(|err| anyhow::anyhow!("{}", err.describe()))
+}
+
+/// Fetch a token by asking the user to sign in with the device code flow.
+async fn fetch_device_code_token() -> anyhow::Result<Token> {
+    let tenant = tenant();
+    let response = client()?
+        .post(format!(
+            "https://login.microsoftonline.com/{tenant}/oauth2/v2.0/devicecode"
+        ))
+        .form(&[
+            ("client_id", DEVICE_CODE_CLIENT_ID),
+            ("scope", &device_code_scope()),
+        ])
+        .send()
+        .await?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!(
+            "Device authorization returned {status}: {}",
+            response.text().await.unwrap_or_default()
+        );
+    }
+    let device_code = response.json::<DeviceCodeResponse>().await?;
+
+    // Ask the user to sign in, then poll for the result.
+    warn_user!(
+        "Sign-in is required to access Azure Artifacts. {}",
+        device_code.message
+    );
+    let deadline = SystemTime::now() + Duration::from_secs(device_code.expires_in);
+    let mut interval = Duration::from_secs(device_code.interval.max(1));
+    loop {
+        tokio::time::sleep(interval).await;
+        if SystemTime::now() > deadline {
+            bail!("The device code expired before sign-in completed");
+        }
+        match request_token(
+            &tenant,
+            &[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                ("client_id", DEVICE_CODE_CLIENT_ID),
+                ("device_code", &device_code.device_code),
+            ],
+        )
+        .await?
+        {
+            Ok(response) => return Ok(response.into()),
+            Err(err) if err.error == "authorization_pending" => {}
+            Err(err) if err.error == "slow_down" => interval += Duration::from_secs(5),
+            Err(err) => bail!("{}", err.describe()),
+        }
+    }
+}
+
+/// Request a token from Microsoft Entra ID, returning the error response on failure.
+async fn request_token(
+    tenant: &str,
+    form: &[(&str, &str)],
+) -> anyhow::Result<Result<TokenResponse, ErrorResponse>> {
+    let response = client()?
+        .post(format!(
+            "https://login.microsoftonline.com/{tenant}/oauth2/v2.0/token"
+        ))
+        .form(form)
+        .send()
+        .await?;
+    if response.status().is_success() {
+        Ok(Ok(response.json().await?))
+    } else {
+        Ok(Err(response.json().await?))
+    }
+}
+
+/// The tenant for interactive sign-in, i.e., `AZURE_TENANT_ID`, or any work or school account.
+fn tenant() -> String {
+    std::env::var(EnvVars::AZURE_TENANT_ID).unwrap_or_else(|_| "organizations".to_string())
+}
+
+/// The scopes requested for interactive sign-in, including a refresh token.
+fn device_code_scope() -> String {
+    format!("{AZURE_DEVOPS_RESOURCE}/.default offline_access")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_response() {
+        // Entra ID reports the lifetime as a number.
+        let response: TokenResponse = serde_json::from_str(
+            r#"{"token_type": "Bearer", "expires_in": 3599, "access_token": "token", "refresh_token": "refresh"}"#,
+        )
+        .unwrap();
+        let token = Token::from(response);
+        assert_eq!(token.value, "token");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh"));
+        assert!(!token.expires_soon());
+
+        // Managed identity endpoints report the expiry as a string.
+        let response: TokenResponse = serde_json::from_str(
+            r#"{"access_token": "token", "expires_on": "1000", "resource": "499b84ac-1321-427f-aa17-267ca6975798", "token_type": "Bearer"}"#,
+        )
+        .unwrap();
+        let token = Token::from(response);
+        assert_eq!(token.expiration, UNIX_EPOCH + Duration::from_secs(1000));
+        assert!(token.expires_soon());
+    }
+}
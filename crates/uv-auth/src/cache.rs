@@ -22,6 +22,8 @@ pub(crate) enum FetchUrl {
     Index(DisplaySafeUrl),
     /// A realm URL
     Realm(Realm),
+    /// A full URL, for hosts with credentials scoped to paths on the host
+    Url(DisplaySafeUrl),
 }
 
 impl Display for FetchUrl {
@@ -29,6 +31,7 @@ impl Display for FetchUrl {
         match self {
             Self::Index(index) => Display::fmt(index, f),
             Self::Realm(realm) => Display::fmt(realm, f),
+            Self::Url(url) => Display::fmt(url, f),
         }
     }
 }
@@ -133,6 +136,18 @@ impl CredentialsCache {
         urls.insert(url, credentials);
     }
 
+    /// Update the cache with the given credentials for the URL only, i.e., without sharing them
+    /// across its realm.
+    pub(crate) fn insert_url(&self, url: &Url, credentials: Arc<Credentials>) {
+        // Do not cache empty credentials
+        if credentials.is_empty() {
+            return;
+        }
+
+        let mut urls = self.urls.write().unwrap();
+        urls.insert(url, credentials);
+    }
+
     /// Private interface to update a realm cache entry.
     ///
     /// Returns replaced credentials, if any.
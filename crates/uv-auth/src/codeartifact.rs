@@ -0,0 +1,344 @@
+//! Automatic authentication for AWS CodeArtifact repositories.
+//!
+//! CodeArtifact requires a short-lived authorization token, which is acquired via the AWS SDK's
+//! default credential chain (i.e., environment variables, shared config and credential files, SSO,
+//! instance metadata, etc.) and refreshed as it nears expiry.
+
+use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context;
+use aws_sdk_codeartifact::error::DisplayErrorContext;
+use aws_sdk_codeartifact::types::{PackageFormat, PackageVersionStatus};
+use rustc_hash::FxHashMap;
+use tokio::sync::Mutex;
+use tracing::debug;
+use url::Url;
+
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::Credentials;
+
+/// The username used alongside CodeArtifact authorization tokens.
+const USERNAME: &str = "aws";
+
+/// The time before expiry at which a token is refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The lifetime assumed for a token if CodeArtifact doesn't report its expiry (the default token
+/// duration is twelve hours).
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// Whether CodeArtifact authentication is disabled via `UV_NO_CODEARTIFACT_TOKEN`.
+static DISABLED: LazyLock<bool> = LazyLock::new(|| {
+    let disabled = std::env::var_os(EnvVars::UV_NO_CODEARTIFACT_TOKEN).is_some();
+    if disabled {
+        debug!("Ignoring AWS CodeArtifact authentication due to `UV_NO_CODEARTIFACT_TOKEN`");
+    }
+    disabled
+});
+
+/// The tokens acquired for each CodeArtifact domain, or `None` if acquisition failed.
+static TOKENS: LazyLock<Mutex<FxHashMap<CodeArtifactDomain, Option<Token>>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// A CodeArtifact domain, as identified by the host of its repository endpoints, e.g.,
+/// `my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CodeArtifactDomain {
+    /// The name of the domain.
+    domain: String,
+    /// The AWS account ID that owns the domain.
+    owner: String,
+    /// The AWS region of the domain.
+    region: String,
+}
+
+impl CodeArtifactDomain {
+    /// Parse the CodeArtifact domain from a URL, if its host is a CodeArtifact repository
+    /// endpoint.
+    fn from_url(url: &Url) -> Option<Self> {
+        if url.scheme() != "https" {
+            return None;
+        }
+        let host = url.host_str()?;
+        let (prefix, rest) = host.split_once(".d.codeartifact.")?;
+        let region = rest
+            .strip_suffix(".amazonaws.com")
+            .or_else(|| rest.strip_suffix(".amazonaws.com.cn"))?;
+        let (domain, owner) = prefix.rsplit_once('-')?;
+        if domain.is_empty()
+            || region.is_empty()
+            || region.contains('.')
+            || owner.len() != 12
+            || !owner.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return None;
+        }
+        Some(Self {
+            domain: domain.to_string(),
+            owner: owner.to_string(),
+            region: region.to_string(),
+        })
+    }
+}
+
+impl Display for CodeArtifactDomain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{} ({})", self.domain, self.owner, self.region)
+    }
+}
+
+/// A CodeArtifact authorization token.
+#[derive(Debug, Clone)]
+struct Token {
+    value: String,
+    expiration: SystemTime,
+}
+
+impl Token {
+    /// Returns `true` if the token has expired, or will expire within the refresh margin.
+    fn expires_soon(&self) -> bool {
+        !self
+            .expiration
+            .duration_since(SystemTime::now())
+            .is_ok_and(|remaining| remaining > REFRESH_MARGIN)
+    }
+
+    /// The credentials with which to authenticate requests using the token.
+    fn credentials(&self) -> Credentials {
+        Credentials::basic(Some(USERNAME.to_string()), Some(self.value.clone()))
+    }
+}
+
+/// A provider for authentication credentials for AWS CodeArtifact repositories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CodeArtifactProvider;
+
+impl CodeArtifactProvider {
+    /// Returns the credentials for a CodeArtifact repository URL, if it is one, acquiring a token
+    /// or refreshing an expiring one as needed.
+    pub(crate) async fn credentials_for(url: &Url) -> Option<Credentials> {
+        let domain = CodeArtifactDomain::from_url(url)?;
+        if *DISABLED {
+            return None;
+        }
+
+        // Hold the lock while fetching, such that concurrent requests share a single token.
+        let mut tokens = TOKENS.lock().await;
+        match tokens.get(&domain) {
+            Some(None) => return None,
+            Some(Some(token)) if !token.expires_soon() => return Some(token.credentials()),
+            Some(Some(_)) => debug!("Refreshing AWS CodeArtifact token for {domain}"),
+            None => debug!("Acquiring AWS CodeArtifact token for {domain}"),
+        }
+
+        let token = match fetch_token(&domain).await {
+            Ok(token) => Some(token),
+            Err(err) => {
+                warn_user_once!(
+                    "Failed to acquire an AWS CodeArtifact token for {domain}: {err:#}"
+                );
+                None
+            }
+        };
+        let credentials = token.as_ref().map(Token::credentials);
+        tokens.insert(domain, token);
+        credentials
+    }
+
+    /// Discard the token for a CodeArtifact repository URL (e.g., after it was rejected), such
+    /// that a new one is acquired for the next request.
+    pub(crate) async fn invalidate(url: &Url) {
+        if let Some(domain) = CodeArtifactDomain::from_url(url) {
+            TOKENS.lock().await.remove(&domain);
+        }
+    }
+}
+
+/// Returns `true` if the URL is a CodeArtifact repository endpoint.
+pub fn is_codeartifact(url: &Url) -> bool {
+    CodeArtifactDomain::from_url(url).is_some()
+}
+
+/// Parse the name of the repository from a CodeArtifact repository endpoint, which has the form
+/// `/pypi/<REPOSITORY>/...`.
+fn repository(url: &Url) -> Option<&str> {
+    let mut segments = url.path_segments()?;
+    if segments.next() != Some("pypi") {
+        return None;
+    }
+    segments.next().filter(|repository| !repository.is_empty())
+}
+
+/// Unlist a version of a Python package in a CodeArtifact repository, or list it again.
+///
+/// Unlisted versions are omitted from the version list of the package, but can still be
+/// installed when requested explicitly, which corresponds to yanking
+/// ([PEP 592](https://peps.python.org/pep-0592/)).
+pub async fn set_version_listed(
+    url: &Url,
+    package: &str,
+    version: &str,
+    listed: bool,
+) -> anyhow::Result<()> {
+    let domain = CodeArtifactDomain::from_url(url)
+        .with_context(|| format!("Not an AWS CodeArtifact repository: {url}"))?;
+    let repository = repository(url)
+        .with_context(|| format!("Missing the repository in the CodeArtifact URL: {url}"))?;
+
+    let (target_status, expected_status) = if listed {
+        (
+            PackageVersionStatus::Published,
+            PackageVersionStatus::Unlisted,
+        )
+    } else {
+        (
+            PackageVersionStatus::Unlisted,
+            PackageVersionStatus::Published,
+        )
+    };
+    debug!(
+        "Setting the status of {package} {version} in {domain}/{repository} to `{}`",
+        target_status.as_str()
+    );
+
+    let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+        .region(aws_config::Region::new(domain.region.clone()))
+        .load()
+        .await;
+    let output = aws_sdk_codeartifact::Client::new(&config)
+        .update_package_versions_status()
+        .domain(&domain.domain)
+        .domain_owner(&domain.owner)
+        .repository(repository)
+        .format(PackageFormat::Pypi)
+        .package(package)
+        .versions(version)
+        .expected_status(expected_status)
+        .target_status(target_status)
+        .send()
+        .await
+        .map_err(|err| anyhow::anyhow!("{}", DisplayErrorContext(err)))?;
+
+    if let Some(error) = output
+        .failed_versions()
+        .and_then(|failed| failed.get(version))
+    {
+        anyhow::bail!(
+            "{}",
+            error
+                .error_message()
+                .or(error.error_code().map(|code| code.as_str()))
+                .unwrap_or("Unknown error")
+        );
+    }
+    Ok(())
+}
+
+/// Fetch an authorization token for the domain using the default AWS credential chain.
+async fn fetch_token(domain: &CodeArtifactDomain) -> anyhow::Result<Token> {
+    let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+        .region(aws_config::Region::new(domain.region.clone()))
+        .load()
+        .await;
+    let output = aws_sdk_codeartifact::Client::new(&config)
+        .get_authorization_token()
+        .domain(&domain.domain)
+        .domain_owner(&domain.owner)
+        .send()
+        .await
+        .map_err(|err| anyhow::anyhow!("{}", DisplayErrorContext(err)))?;
+
+    let value = output
+        .authorization_token()
+        .context("No authorization token in response")?
+        .to_string();
+    let expiration = output
+        .expiration()
+        .and_then(|expiration| SystemTime::try_from(*expiration).ok())
+        .unwrap_or_else(|| SystemTime::now() + DEFAULT_LIFETIME);
+    Ok(Token { value, expiration })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_url() {
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(
+            CodeArtifactDomain::from_url(&url),
+            Some(CodeArtifactDomain {
+                domain: "my-domain".to_string(),
+                owner: "111122223333".to_string(),
+                region: "us-west-2".to_string(),
+            })
+        );
+
+        let url = Url::parse(
+            "https://domain-111122223333.d.codeartifact.cn-north-1.amazonaws.com.cn/pypi/repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(
+            CodeArtifactDomain::from_url(&url).map(|domain| domain.region),
+            Some("cn-north-1".to_string())
+        );
+
+        // Not CodeArtifact hosts.
+        for url in [
+            "https://pypi.org/simple/",
+            "https://my-domain.d.codeartifact.us-west-2.amazonaws.com/pypi/repo/simple/",
+            "https://my-domain-1234.d.codeartifact.us-west-2.amazonaws.com/pypi/repo/simple/",
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.example.com/pypi/repo/simple/",
+            "http://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/repo/simple/",
+        ] {
+            assert_eq!(
+                CodeArtifactDomain::from_url(&Url::parse(url).unwrap()),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn repository_from_url() {
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(repository(&url), Some("repo"));
+
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/npm/repo/",
+        )
+        .unwrap();
+        assert_eq!(repository(&url), None);
+    }
+
+    #[test]
+    fn expires_soon() {
+        let token = Token {
+            value: "token".to_string(),
+            expiration: SystemTime::now() + Duration::from_secs(60 * 60),
+        };
+        assert!(!token.expires_soon());
+
+        let token = Token {
+            value: "token".to_string(),
+            expiration: SystemTime::now() + Duration::from_secs(60),
+        };
+        assert!(token.expires_soon());
+
+        let token = Token {
+            value: "token".to_string(),
+            expiration: SystemTime::now() - Duration::from_secs(60),
+        };
+        assert!(token.expires_soon());
+    }
+}
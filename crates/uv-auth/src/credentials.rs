@@ -148,9 +148,18 @@ impl Credentials {
         username: Option<&str>,
     ) -> Option<Self> {
         let host = url.host_str()?;
+
+        // Entries may be scoped to a path on the host (e.g., `machine example.com/repository`), in
+        // which case the most specific entry with a matching username applies.
         let entry = netrc
             .hosts
-            .get(host)
+            .iter()
+            .filter(|(_, entry)| username.is_none_or(|username| username == entry.login))
+            .filter_map(|(machine, entry)| {
+                netrc_machine_specificity(machine, host, url.path()).map(|length| (length, entry))
+            })
+            .max_by_key(|(length, _)| *length)
+            .map(|(_, entry)| entry)
             .or_else(|| netrc.hosts.get("default"))?;
 
         // Ensure the username matches if provided
@@ -326,6 +335,42 @@ impl Credentials {
     }
 }
 
+/// Returns the specificity of a netrc `machine` for a URL (i.e., the length of the path to which
+/// it's scoped), or `None` if it doesn't apply to the URL.
+///
+/// A machine is either a host (e.g., `example.com`), or a host and a path under it (e.g.,
+/// `example.com/repository`), which applies to the URLs under that path.
+fn netrc_machine_specificity(machine: &str, host: &str, path: &str) -> Option<usize> {
+    let (machine_host, prefix) = match machine.split_once('/') {
+        Some((machine_host, prefix)) => (machine_host, prefix.trim_end_matches('/')),
+        None => (machine, ""),
+    };
+    if machine_host != host {
+        return None;
+    }
+    if prefix.is_empty() {
+        return Some(0);
+    }
+    path.trim_start_matches('/')
+        .strip_prefix(prefix)
+        .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+        .map(|_| prefix.len())
+}
+
+/// Returns `true` if the netrc file has entries scoped to a path on the URL's host.
+pub(crate) fn netrc_has_path_scoped(netrc: &Netrc, url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    netrc.hosts.keys().any(|machine| {
+        machine
+            .split_once('/')
+            .is_some_and(|(machine_host, prefix)| {
+                machine_host == host && !prefix.trim_end_matches('/').is_empty()
+            })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_debug_snapshot;
@@ -446,4 +491,25 @@ mod tests {
             "Basic { username: Username(Some(\"user\")), password: Some(****) }"
         );
     }
+
+    #[test]
+    fn netrc_machine_path_scope() {
+        let specificity = |machine: &str, url: &str| {
+            let url = Url::parse(url).unwrap();
+            netrc_machine_specificity(machine, url.host_str().unwrap(), url.path())
+        };
+        let url = "https://example.com/artifactory/api/pypi/first/simple/";
+        assert_eq!(specificity("example.com", url), Some(0));
+        assert_eq!(specificity("example.com/artifactory", url), Some(11));
+        assert_eq!(
+            specificity("example.com/artifactory/api/pypi/first/", url),
+            Some(26)
+        );
+        assert_eq!(
+            specificity("example.com/artifactory/api/pypi/second", url),
+            None
+        );
+        assert_eq!(specificity("example.com/art", url), None);
+        assert_eq!(specificity("other.com/artifactory", url), None);
+    }
 }
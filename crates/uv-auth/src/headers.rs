@@ -0,0 +1,84 @@
+//! Additional headers configured per index, e.g., for proxies that authenticate via headers other
+//! than `Authorization`.
+
+use http::{HeaderName, HeaderValue};
+use reqwest::Request;
+use tracing::trace;
+
+use uv_warnings::warn_user_once;
+
+/// Attach the configured headers to a request, interpolating environment variables in their
+/// values.
+///
+/// Headers that are invalid, or that reference unset environment variables, are omitted.
+pub(crate) fn attach_headers(request: &mut Request, headers: &[(String, String)]) {
+    for (name, value) in headers {
+        let Ok(header_name) = HeaderName::try_from(name.as_str()) else {
+            warn_user_once!("Ignoring invalid index header name: `{name}`");
+            continue;
+        };
+        let value = match interpolate(value, |var| std::env::var(var).ok()) {
+            Ok(value) => value,
+            Err(var) => {
+                warn_user_once!(
+                    "Ignoring index header `{name}`, as it references `{var}`, which is not set"
+                );
+                continue;
+            }
+        };
+        let Ok(mut header_value) = HeaderValue::try_from(value) else {
+            warn_user_once!("Ignoring index header `{name}`, as its value is invalid");
+            continue;
+        };
+        header_value.set_sensitive(true);
+        trace!(
+            "Attaching index header `{name}` to request for {}",
+            request.url()
+        );
+        request.headers_mut().insert(header_name, header_value);
+    }
+}
+
+/// Replace each `${VAR}` in the value with the variable's value, as returned by `lookup`.
+///
+/// Returns the name of the first variable without a value, if any.
+fn interpolate(value: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let var = &rest[start + 2..start + 2 + end];
+        result.push_str(&rest[..start]);
+        result.push_str(&lookup(var).ok_or_else(|| var.to_string())?);
+        rest = &rest[start + 2 + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_vars() {
+        let lookup = |var: &str| match var {
+            "TOKEN" => Some("secret".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        };
+        assert_eq!(interpolate("plain", lookup), Ok("plain".to_string()));
+        assert_eq!(interpolate("${TOKEN}", lookup), Ok("secret".to_string()));
+        assert_eq!(
+            interpolate("Token ${TOKEN}-${EMPTY}!", lookup),
+            Ok("Token secret-!".to_string())
+        );
+        assert_eq!(interpolate("${TOKEN", lookup), Ok("${TOKEN".to_string()));
+        assert_eq!(
+            interpolate("${MISSING}", lookup),
+            Err("MISSING".to_string())
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Credentials from external credential helpers, configured per index.
+//!
+//! The protocol mirrors Git's credential helpers: uv runs the helper with a `get` argument, writes
+//! the index URL to its standard input as `url=<url>` followed by a blank line, and reads
+//! `key=value` lines from its standard output:
+//!
+//! - `username`: the username for "Basic" authentication.
+//! - `password`: the password for "Basic" authentication.
+//! - `token`: a token for "Bearer" authentication, used instead of the username and password.
+//! - `expiry`: when the credentials expire, in seconds since the Unix epoch.
+//!
+//! Unknown keys are ignored, such that the protocol can be extended.
+
+use std::process::Stdio;
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, bail};
+use rustc_hash::FxHashMap;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user_once;
+
+use crate::Credentials;
+
+/// The time before expiry at which the helper is run again.
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// The credentials returned by each helper for each index URL, or `None` if the helper failed.
+static CREDENTIALS: LazyLock<Mutex<FxHashMap<(String, String), Option<HelperCredentials>>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// The credentials returned by a credential helper.
+#[derive(Debug, Clone, PartialEq)]
+struct HelperCredentials {
+    credentials: Credentials,
+    /// When the credentials expire, if ever.
+    expiration: Option<SystemTime>,
+}
+
+impl HelperCredentials {
+    /// Parse the output of a credential helper.
+    fn parse(output: &str) -> anyhow::Result<Self> {
+        let mut username = None;
+        let mut password = None;
+        let mut token = None;
+        let mut expiration = None;
+        for line in output.lines() {
+            if line.is_empty() {
+                break;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                bail!("Expected a `key=value` line, but found: `{line}`");
+            };
+            match key {
+                "username" => username = Some(value.to_string()),
+                "password" => password = Some(value.to_string()),
+                "token" => token = Some(value.to_string()),
+                "expiry" => {
+                    let seconds = value
+                        .parse::<u64>()
+                        .with_context(|| format!("Invalid `expiry`: `{value}`"))?;
+                    expiration = Some(UNIX_EPOCH + Duration::from_secs(seconds));
+                }
+                _ => {}
+            }
+        }
+
+        let credentials = if let Some(token) = token {
+            Credentials::Bearer {
+                token: token.into_bytes(),
+            }
+        } else if username.is_some() || password.is_some() {
+            Credentials::basic(username, password)
+        } else {
+            bail!("Expected a `username`, `password`, or `token`");
+        };
+        Ok(Self {
+            credentials,
+            expiration,
+        })
+    }
+
+    /// Returns `true` if the credentials have expired, or will expire within the refresh margin.
+    fn expires_soon(&self) -> bool {
+        self.expiration.is_some_and(|expiration| {
+            !expiration
+                .duration_since(SystemTime::now())
+                .is_ok_and(|remaining| remaining > REFRESH_MARGIN)
+        })
+    }
+}
+
+/// A provider for credentials from the credential helper configured for an index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CredentialHelperProvider;
+
+impl CredentialHelperProvider {
+    /// Returns the credentials for an index from its credential helper, running the helper if the
+    /// credentials haven't been fetched yet, or are about to expire.
+    pub(crate) async fn credentials_for(
+        helper: &str,
+        index_url: &DisplaySafeUrl,
+    ) -> Option<Credentials> {
+        let key = (helper.to_string(), index_url.to_string());
+
+        // Hold the lock while running the helper, such that it runs once for concurrent requests.
+        let mut credentials = CREDENTIALS.lock().await;
+        match credentials.get(&key) {
+            Some(None) => return None,
+            Some(Some(cached)) if !cached.expires_soon() => {
+                return Some(cached.credentials.clone());
+            }
+            Some(Some(_)) => debug!("Credentials from `{helper}` for {index_url} are expiring"),
+            None => {}
+        }
+
+        let fetched = match run(helper, index_url).await {
+            Ok(fetched) => Some(fetched),
+            Err(err) => {
+                warn_user_once!(
+                    "Failed to fetch credentials for {index_url} from credential helper `{helper}`: {err:#}"
+                );
+                None
+            }
+        };
+        let result = fetched.as_ref().map(|fetched| fetched.credentials.clone());
+        credentials.insert(key, fetched);
+        result
+    }
+
+    /// Discard the credentials for an index (e.g., after they were rejected), such that the
+    /// helper is run again for the next request.
+    pub(crate) async fn invalidate(helper: &str, index_url: &DisplaySafeUrl) {
+        CREDENTIALS
+            .lock()
+            .await
+            .remove(&(helper.to_string(), index_url.to_string()));
+    }
+}
+
+/// Run the credential helper for an index URL.
+async fn run(helper: &str, index_url: &DisplaySafeUrl) -> anyhow::Result<HelperCredentials> {
+    let mut args = helper.split_whitespace();
+    let program = args.next().context("The credential helper is empty")?;
+    debug!("Running credential helper `{helper}` for {index_url}");
+
+    let mut child = Command::new(program)
+        .args(args)
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        // Stream stderr, such that the helper can prompt the user.
+        .stderr(Stdio::inherit())
+        .spawn()
+        .with_context(|| format!("Failed to run `{program}`"))?;
+
+    let mut stdin = child.stdin.take().context("Failed to open stdin")?;
+    stdin
+        .write_all(format!("url={index_url}\n\n").as_bytes())
+        .await?;
+    drop(stdin);
+
+    let output = child.wait_with_output().await?;
+    if !output.status.success() {
+        bail!("The credential helper exited with {}", output.status);
+    }
+    let output = String::from_utf8(output.stdout).context("The output is not valid UTF-8")?;
+    HelperCredentials::parse(&output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let parsed =
+            HelperCredentials::parse("username=user\npassword=pass\nother=ignored\n").unwrap();
+        assert_eq!(
+            parsed,
+            HelperCredentials {
+                credentials: Credentials::basic(Some("user".to_string()), Some("pass".to_string())),
+                expiration: None,
+            }
+        );
+        assert!(!parsed.expires_soon());
+
+        let parsed = HelperCredentials::parse("token=abc\nexpiry=1000\n").unwrap();
+        assert_eq!(
+            parsed.credentials,
+            Credentials::Bearer {
+                token: b"abc".to_vec()
+            }
+        );
+        assert!(parsed.expires_soon());
+
+        assert!(HelperCredentials::parse("expiry=1000\n").is_err());
+        assert!(HelperCredentials::parse("username\n").is_err());
+        assert!(HelperCredentials::parse("token=abc\nexpiry=soon\n").is_err());
+    }
+}
@@ -4,6 +4,8 @@ use rustc_hash::FxHashSet;
 use url::Url;
 use uv_redacted::DisplaySafeUrl;
 
+use crate::{AzureIdentity, TokenExchange};
+
 /// When to use authentication.
 #[derive(
     Copy,
@@ -59,6 +61,16 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// The identity with which to acquire Microsoft Entra ID tokens for the index, if it's an
+    /// Azure Artifacts feed.
+    pub azure_identity: Option<AzureIdentity>,
+    /// The command of the credential helper from which to fetch credentials for the index.
+    pub credential_helper: Option<String>,
+    /// Additional headers to send with each request to the index, as names and values, with any
+    /// `${VAR}` references in the values yet to be interpolated.
+    pub headers: Vec<(String, String)>,
+    /// The endpoint at which to exchange the OIDC token of a CI job for an index token.
+    pub token_exchange: Option<TokenExchange>,
 }
 
 impl Index {
@@ -107,6 +119,35 @@ impl Indexes {
             .unwrap_or(AuthPolicy::Auto)
     }
 
+    /// Get the [`AzureIdentity`] for a URL, if one is configured.
+    pub fn azure_identity_for(&self, url: &Url) -> Option<AzureIdentity> {
+        self.find_prefix_index(url)
+            .and_then(|index| index.azure_identity)
+    }
+
+    /// Get the index URL and credential helper for a URL, if a credential helper is configured.
+    pub fn credential_helper_for(&self, url: &Url) -> Option<(&DisplaySafeUrl, &str)> {
+        self.find_prefix_index(url).and_then(|index| {
+            index
+                .credential_helper
+                .as_deref()
+                .map(|helper| (&index.url, helper))
+        })
+    }
+
+    /// Get the [`TokenExchange`] for a URL, if one is configured.
+    pub fn token_exchange_for(&self, url: &Url) -> Option<&TokenExchange> {
+        self.find_prefix_index(url)
+            .and_then(|index| index.token_exchange.as_ref())
+    }
+
+    /// Get the additional headers for a URL, if any are configured.
+    pub fn headers_for(&self, url: &Url) -> &[(String, String)] {
+        self.find_prefix_index(url)
+            .map(|index| index.headers.as_slice())
+            .unwrap_or_default()
+    }
+
     fn find_prefix_index(&self, url: &Url) -> Option<&Index> {
         self.0.iter().find(|&index| index.is_prefix_for(url))
     }
@@ -1,4 +1,6 @@
 use std::{io::Write, process::Stdio};
+
+use anyhow::bail;
 use tokio::process::Command;
 use tracing::{instrument, trace, warn};
 use uv_redacted::DisplaySafeUrl;
@@ -19,6 +21,8 @@ pub struct KeyringProvider {
 pub(crate) enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
+    /// Use the operating system's credential store to fetch credentials.
+    Native,
     #[cfg(test)]
     Dummy(Vec<(String, &'static str, &'static str)>),
 }
@@ -31,6 +35,13 @@ impl KeyringProvider {
         }
     }
 
+    /// Create a new [`KeyringProvider::Native`].
+    pub fn native() -> Self {
+        Self {
+            backend: KeyringProviderBackend::Native,
+        }
+    }
+
     /// Fetch credentials for the given [`Url`] from the keyring.
     ///
     /// Returns [`None`] if no password was found for the username or if any errors
@@ -58,6 +69,7 @@ impl KeyringProvider {
             KeyringProviderBackend::Subprocess => {
                 self.fetch_subprocess(url.as_str(), username).await
             }
+            KeyringProviderBackend::Native => Self::fetch_native(url.as_str(), username).await,
             #[cfg(test)]
             KeyringProviderBackend::Dummy(ref store) => {
                 Self::fetch_dummy(store, url.as_str(), username)
@@ -65,14 +77,11 @@ impl KeyringProvider {
         };
         // And fallback to a check for the host
         if credentials.is_none() {
-            let host = if let Some(port) = url.port() {
-                format!("{}:{}", url.host_str()?, port)
-            } else {
-                url.host_str()?.to_string()
-            };
+            let host = Self::host_service_name(url)?;
             trace!("Checking keyring for host {host}");
             credentials = match self.backend {
                 KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
+                KeyringProviderBackend::Native => Self::fetch_native(&host, username).await,
                 #[cfg(test)]
                 KeyringProviderBackend::Dummy(ref store) => {
                     Self::fetch_dummy(store, &host, username)
@@ -83,6 +92,17 @@ impl KeyringProvider {
         credentials.map(|(username, password)| Credentials::basic(Some(username), Some(password)))
     }
 
+    /// Return the service name under which the credentials for any URL on the given URL's host
+    /// are stored, i.e., the host, along with the port if it's not the default.
+    pub fn host_service_name(url: &DisplaySafeUrl) -> Option<String> {
+        let host = url.host_str()?;
+        if let Some(port) = url.port() {
+            Some(format!("{host}:{port}"))
+        } else {
+            Some(host.to_string())
+        }
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(
         &self,
@@ -175,6 +195,79 @@ impl KeyringProvider {
         }
     }
 
+    #[instrument]
+    async fn fetch_native(service_name: &str, username: Option<&str>) -> Option<(String, String)> {
+        // The native credential stores are keyed by service and username, so a username is
+        // required for a lookup.
+        let Some(username) = username else {
+            trace!("Skipping native keyring lookup for `{service_name}` without a username");
+            return None;
+        };
+        let service_name = service_name.to_string();
+        let username = username.to_string();
+        tokio::task::spawn_blocking(move || {
+            let entry = keyring::Entry::new(&service_name, &username)
+                .inspect_err(|err| warn!("Failed to access the native keyring: {err}"))
+                .ok()?;
+            match entry.get_password() {
+                Ok(password) => Some((username, password)),
+                Err(keyring::Error::NoEntry) => None,
+                Err(err) => {
+                    warn!(
+                        "Failed to read `{username}@{service_name}` from the native keyring: {err}"
+                    );
+                    None
+                }
+            }
+        })
+        .await
+        .inspect_err(|err| warn!("Failed to wait for the native keyring: {err}"))
+        .ok()?
+    }
+
+    /// Store the password for the given service and username in the keyring.
+    ///
+    /// Only the native keyring supports storing credentials.
+    pub async fn store(
+        &self,
+        service_name: &str,
+        username: &str,
+        password: &str,
+    ) -> anyhow::Result<()> {
+        if !matches!(self.backend, KeyringProviderBackend::Native) {
+            bail!("Storing credentials is only supported by the native keyring provider");
+        }
+        let service_name = service_name.to_string();
+        let username = username.to_string();
+        let password = password.to_string();
+        tokio::task::spawn_blocking(move || {
+            keyring::Entry::new(&service_name, &username)?.set_password(&password)
+        })
+        .await??;
+        Ok(())
+    }
+
+    /// Remove the password for the given service and username from the keyring.
+    ///
+    /// Returns `false` if no password was stored. Only the native keyring supports removing
+    /// credentials.
+    pub async fn remove(&self, service_name: &str, username: &str) -> anyhow::Result<bool> {
+        if !matches!(self.backend, KeyringProviderBackend::Native) {
+            bail!("Removing credentials is only supported by the native keyring provider");
+        }
+        let service_name = service_name.to_string();
+        let username = username.to_string();
+        let removed = tokio::task::spawn_blocking(move || {
+            match keyring::Entry::new(&service_name, &username)?.delete_credential() {
+                Ok(()) => Ok(true),
+                Err(keyring::Error::NoEntry) => Ok(false),
+                Err(err) => Err(err),
+            }
+        })
+        .await??;
+        Ok(removed)
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         store: &Vec<(String, &'static str, &'static str)>,
@@ -392,4 +485,22 @@ mod tests {
             .await;
         assert_eq!(credentials, None);
     }
+
+    #[test]
+    fn host_service_name() {
+        let url = DisplaySafeUrl::parse("https://example.com/simple").unwrap();
+        assert_eq!(
+            KeyringProvider::host_service_name(&url).as_deref(),
+            Some("example.com")
+        );
+
+        let url = DisplaySafeUrl::parse("https://example.com:8080/simple").unwrap();
+        assert_eq!(
+            KeyringProvider::host_service_name(&url).as_deref(),
+            Some("example.com:8080")
+        );
+
+        let url = DisplaySafeUrl::parse("file:///simple").unwrap();
+        assert_eq!(KeyringProvider::host_service_name(&url), None);
+    }
 }
@@ -2,21 +2,35 @@ use std::sync::{Arc, LazyLock};
 
 use tracing::trace;
 
+pub use azure::AzureIdentity;
 use cache::CredentialsCache;
+#[cfg(feature = "codeartifact")]
+pub use codeartifact::{is_codeartifact, set_version_listed};
 pub use credentials::Credentials;
 pub use index::{AuthPolicy, Index, Indexes};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
+pub use oidc::TokenExchange;
 use realm::Realm;
+pub use store::{CredentialStore, SealKey, StoredCredentials};
 use uv_redacted::DisplaySafeUrl;
 
+#[cfg(feature = "artifact-registry")]
+mod artifact_registry;
+mod azure;
 mod cache;
+#[cfg(feature = "codeartifact")]
+mod codeartifact;
 mod credentials;
+mod headers;
+mod helper;
 mod index;
 mod keyring;
 mod middleware;
+mod oidc;
 mod providers;
 mod realm;
+mod store;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
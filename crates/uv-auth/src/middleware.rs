@@ -6,12 +6,14 @@ use netrc::Netrc;
 use reqwest::{Request, Response};
 use reqwest_middleware::{Error, Middleware, Next};
 use tracing::{debug, trace, warn};
+use url::Url;
 
-use crate::providers::HuggingFaceProvider;
+use crate::headers::attach_headers;
+use crate::providers::{HuggingFaceProvider, TokenSource};
 use crate::{
-    CREDENTIALS_CACHE, CredentialsCache, KeyringProvider,
+    CREDENTIALS_CACHE, CredentialStore, CredentialsCache, KeyringProvider,
     cache::FetchUrl,
-    credentials::{Credentials, Username},
+    credentials::{Credentials, Username, netrc_has_path_scoped},
     index::{AuthPolicy, Indexes},
     realm::Realm,
 };
@@ -40,6 +42,39 @@ impl Default for NetrcMode {
     }
 }
 
+/// Strategy for loading uv's credential store.
+enum CredentialStoreMode {
+    Automatic(LazyLock<Option<CredentialStore>>),
+    Enabled(CredentialStore),
+    Disabled,
+}
+
+impl Default for CredentialStoreMode {
+    fn default() -> Self {
+        CredentialStoreMode::Automatic(LazyLock::new(|| {
+            let path = CredentialStore::default_path()?;
+            match CredentialStore::read(&path) {
+                Ok(store) => Some(store),
+                Err(err) => {
+                    warn!("Error reading credential store: {err}");
+                    None
+                }
+            }
+        }))
+    }
+}
+
+impl CredentialStoreMode {
+    /// Get the credential store if enabled.
+    fn get(&self) -> Option<&CredentialStore> {
+        match self {
+            CredentialStoreMode::Automatic(lock) => lock.as_ref(),
+            CredentialStoreMode::Enabled(store) => Some(store),
+            CredentialStoreMode::Disabled => None,
+        }
+    }
+}
+
 impl NetrcMode {
     /// Get the parsed netrc file if enabled.
     fn get(&self) -> Option<&Netrc> {
@@ -54,9 +89,10 @@ impl NetrcMode {
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file and the keyring.
+/// fetches credentials from uv's credential store, a netrc file, and the keyring.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    store: CredentialStoreMode,
     keyring: Option<KeyringProvider>,
     cache: Option<CredentialsCache>,
     /// Auth policies for specific URLs.
@@ -70,6 +106,7 @@ impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            store: CredentialStoreMode::default(),
             keyring: None,
             cache: None,
             indexes: Indexes::new(),
@@ -90,6 +127,19 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialStore`] to use.
+    ///
+    /// `None` disables authentication via the credential store.
+    #[must_use]
+    pub fn with_credential_store(mut self, store: Option<CredentialStore>) -> Self {
+        self.store = if let Some(store) = store {
+            CredentialStoreMode::Enabled(store)
+        } else {
+            CredentialStoreMode::Disabled
+        };
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -164,6 +214,9 @@ impl Middleware for AuthMiddleware {
     /// server tells us authorization is needed. This pattern avoids attaching credentials to
     /// requests that do not need them, which can cause some servers to deny the request.
     ///
+    /// - For AWS CodeArtifact or Google Artifact Registry repositories, or indexes with an Azure
+    ///   identity or a credential helper, attach a token and perform the request
+    ///     - On 401 or 403, fetch a new token and retry the request
     /// - Check the cache (URL key)
     /// - Perform the request
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
@@ -187,6 +240,10 @@ impl Middleware for AuthMiddleware {
         let auth_policy = self.indexes.auth_policy_for(request.url());
         trace!("Handling request for {url} with authentication policy {auth_policy}");
 
+        // Attach any additional headers configured for the index, regardless of the policy.
+        let headers = self.indexes.headers_for(request.url());
+        attach_headers(&mut request, headers);
+
         let credentials: Option<Arc<Credentials>> = if matches!(auth_policy, AuthPolicy::Never) {
             None
         } else {
@@ -207,6 +264,19 @@ impl Middleware for AuthMiddleware {
             // We have no credentials
             trace!("Request for {url} is unauthenticated, checking cache");
 
+            // AWS CodeArtifact, Google Artifact Registry, Microsoft Entra ID, and credential helper
+            // tokens expire, so they're attached to every request (and refreshed as needed) rather
+            // than cached.
+            let request_url = request.url().clone();
+            if let Some((source, credentials)) =
+                TokenSource::credentials_for(&self.indexes, &request_url).await
+            {
+                debug!("Using credentials from {source} for {url}");
+                return self
+                    .complete_token_request(source, credentials, request, extensions, next, &url)
+                    .await;
+            }
+
             // Check the cache for a URL match first. This can save us from
             // making a failing request
             let credentials = self.cache().get_url(request.url(), &Username::none());
@@ -282,15 +352,13 @@ impl Middleware for AuthMiddleware {
             .map(|credentials| credentials.to_username())
             .unwrap_or(Username::none());
         let credentials = if let Some(index_url) = maybe_index_url {
-            self.cache().get_url(index_url, &username).or_else(|| {
-                self.cache()
-                    .get_realm(Realm::from(&**retry_request_url), username)
-            })
+            self.cache()
+                .get_url(index_url, &username)
+                .or_else(|| self.realm_credentials(retry_request_url, username))
         } else {
             // Since there is no known index for this URL, check if there are credentials in
             // the realm-level cache.
-            self.cache()
-                .get_realm(Realm::from(&**retry_request_url), username)
+            self.realm_credentials(retry_request_url, username)
         }
         .or(credentials);
 
@@ -376,12 +444,60 @@ impl AuthMiddleware {
             .is_ok_and(|response| response.error_for_status_ref().is_ok())
         {
             trace!("Updating cached credentials for {url} to {credentials:?}");
-            self.cache().insert(&url, credentials);
+            if self.has_path_scoped_credentials(&url) {
+                self.cache().insert_url(&url, credentials);
+            } else {
+                self.cache().insert(&url, credentials);
+            }
         }
 
         result
     }
 
+    /// Complete a request with credentials from a [`TokenSource`].
+    ///
+    /// If the credentials are rejected (e.g., because a token expired partway through a long
+    /// operation), they're fetched again, and the request is retried once with the new
+    /// credentials.
+    async fn complete_token_request(
+        &self,
+        source: TokenSource<'_>,
+        credentials: Credentials,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+        url: &DisplaySafeUrl,
+    ) -> reqwest_middleware::Result<Response> {
+        let retry_request = request.try_clone();
+        let response = next
+            .clone()
+            .run(credentials.authenticate(request), extensions)
+            .await?;
+        if !matches!(
+            response.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) {
+            return Ok(response);
+        }
+        let Some(retry_request) = retry_request else {
+            return Ok(response);
+        };
+
+        source.invalidate().await;
+        let Some(refreshed) = source.credentials().await else {
+            return Ok(response);
+        };
+        if refreshed == credentials {
+            return Ok(response);
+        }
+        debug!(
+            "Request for {url} failed with {}, retrying with new credentials from {source}",
+            response.status()
+        );
+        next.run(refreshed.authenticate(retry_request), extensions)
+            .await
+    }
+
     /// Use known request credentials to complete the request.
     async fn complete_request_with_request_credentials(
         &self,
@@ -412,8 +528,7 @@ impl AuthMiddleware {
             self.cache()
                 .get_url(index_url, credentials.as_username().as_ref())
         } else {
-            self.cache()
-                .get_realm(Realm::from(request.url()), credentials.to_username())
+            self.realm_credentials(request.url(), credentials.to_username())
         };
         if let Some(credentials) = maybe_cached_credentials {
             request = credentials.authenticate(request);
@@ -444,9 +559,8 @@ impl AuthMiddleware {
             Some(credentials)
         } else if index_url.is_some() {
             // If this is a known index, we fall back to checking for the realm.
-            if let Some(credentials) = self
-                .cache()
-                .get_realm(Realm::from(request.url()), credentials.to_username())
+            if let Some(credentials) =
+                self.realm_credentials(request.url(), credentials.to_username())
             {
                 request = credentials.authenticate(request);
                 Some(credentials)
@@ -462,9 +576,31 @@ impl AuthMiddleware {
             .await
     }
 
+    /// Returns `true` if the credential store or netrc file has credentials scoped to a path on
+    /// the URL's host, such that different paths on the host may use different credentials.
+    fn has_path_scoped_credentials(&self, url: &Url) -> bool {
+        self.store
+            .get()
+            .is_some_and(|store| store.has_path_scoped(url))
+            || self
+                .netrc
+                .get()
+                .is_some_and(|netrc| netrc_has_path_scoped(netrc, url))
+    }
+
+    /// Return the cached credentials for the realm of a URL, unless credentials are scoped to
+    /// paths on its host, in which case they can't be shared across the realm.
+    fn realm_credentials(&self, url: &Url, username: Username) -> Option<Arc<Credentials>> {
+        if self.has_path_scoped_credentials(url) {
+            trace!("Skipping realm cache for {url} due to path-scoped credentials");
+            return None;
+        }
+        self.cache().get_realm(Realm::from(url), username)
+    }
+
     /// Fetch credentials for a URL.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Supports credential store, netrc file, and keyring lookups.
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
@@ -480,6 +616,8 @@ impl AuthMiddleware {
         // All other requests for the same realm or index URL will wait until the first one completes
         let key = if let Some(index_url) = maybe_index_url {
             (FetchUrl::Index(index_url.clone()), username)
+        } else if self.has_path_scoped_credentials(url) {
+            (FetchUrl::Url(url.clone()), username)
         } else {
             (FetchUrl::Realm(Realm::from(&**url)), username)
         };
@@ -511,7 +649,17 @@ impl AuthMiddleware {
         }
 
         // Netrc support based on: <https://github.com/gribouille/netrc>.
-        let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
+        let credentials = if let Some(credentials) = self
+            .fetch_stored_credentials(
+                credentials.and_then(|credentials| credentials.username()),
+                url,
+                maybe_index_url,
+            )
+            .await
+        {
+            debug!("Found credentials in credential store for {url}");
+            Some(credentials)
+        } else if let Some(credentials) = self.netrc.get().and_then(|netrc| {
             debug!("Checking netrc for credentials for {url}");
             Credentials::from_netrc(
                 netrc,
@@ -569,6 +717,37 @@ impl AuthMiddleware {
 
         credentials
     }
+
+    /// Fetch credentials for a URL from uv's credential store.
+    ///
+    /// Credentials stored for the index URL apply to any request to the index. If the password
+    /// is stored in the native keyring, it's retrieved from there.
+    async fn fetch_stored_credentials(
+        &self,
+        username: Option<&str>,
+        url: &DisplaySafeUrl,
+        maybe_index_url: Option<&DisplaySafeUrl>,
+    ) -> Option<Credentials> {
+        let store = self.store.get()?;
+        trace!("Checking credential store for credentials for {url}");
+        let (service, stored) = store
+            .get(url, username)
+            .map(|stored| (url, stored))
+            .or_else(|| {
+                let index_url = maybe_index_url?;
+                Some((index_url, store.get(index_url, username)?))
+            })?;
+        if stored.password.is_some() {
+            return Some(Credentials::from(stored));
+        }
+        debug!(
+            "Checking native keyring for credentials for {}@{service}",
+            stored.username
+        );
+        KeyringProvider::native()
+            .fetch(service, Some(&stored.username))
+            .await
+    }
 }
 
 fn tracing_url(request: &Request, credentials: Option<&Credentials>) -> DisplaySafeUrl {
@@ -594,11 +773,11 @@ mod tests {
     use test_log::test;
 
     use url::Url;
-    use wiremock::matchers::{basic_auth, method, path_regex};
+    use wiremock::matchers::{basic_auth, header, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
-    use crate::Index;
     use crate::credentials::Password;
+    use crate::{Index, StoredCredentials};
 
     use super::*;
 
@@ -904,6 +1083,235 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_credential_store() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut store = CredentialStore::default();
+        store.insert(StoredCredentials {
+            service: DisplaySafeUrl::from(base_url.clone()),
+            username: username.to_string(),
+            password: Some(password.to_string()),
+        });
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_credential_store(Some(store)),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials should be pulled from the credential store"
+        );
+
+        let mut url = base_url.clone();
+        url.set_username("other").unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials should not be used for another username"
+        );
+
+        Ok(())
+    }
+
+    /// Rejected credentials from a credential helper should be fetched again, and the request
+    /// retried, as for a token that expired partway through an operation.
+    #[cfg(unix)]
+    #[test(tokio::test)]
+    async fn test_credential_helper_refresh() -> Result<(), Error> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("authorization", "Bearer token-2"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        // A helper that returns a new token each time it runs.
+        let directory = tempfile::tempdir()?;
+        let helper = directory.path().join("helper");
+        fs_err::write(
+            &helper,
+            r#"#!/bin/sh
+count=$(cat "$0.count" 2>/dev/null || echo 0)
+count=$((count + 1))
+echo "$count" > "$0.count"
+echo "token=token-$count"
+"#,
+        )?;
+        fs_err::set_permissions(&helper, std::fs::Permissions::from_mode(0o755))?;
+
+        let base_url = DisplaySafeUrl::parse(&server.uri())?;
+        let indexes = Indexes::from_indexes(vec![Index {
+            url: base_url.clone(),
+            root_url: base_url,
+            auth_policy: AuthPolicy::Auto,
+            azure_identity: None,
+            credential_helper: Some(helper.to_string_lossy().into_owned()),
+            headers: Vec::new(),
+            token_exchange: None,
+        }]);
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(indexes),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "The request should be retried with a new token"
+        );
+        assert_eq!(
+            fs_err::read_to_string(directory.path().join("helper.count"))?.trim(),
+            "2"
+        );
+
+        Ok(())
+    }
+
+    /// Headers configured for an index should be sent with requests to the index, and only to
+    /// the index.
+    #[test(tokio::test)]
+    async fn test_index_headers() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("x-api-key", "secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let index_url = DisplaySafeUrl::parse(&format!("{}/index/", server.uri()))?;
+        let indexes = Indexes::from_indexes(vec![Index {
+            url: index_url.clone(),
+            root_url: index_url.clone(),
+            auth_policy: AuthPolicy::Auto,
+            azure_identity: None,
+            credential_helper: None,
+            headers: vec![("X-Api-Key".to_string(), "secret".to_string())],
+            token_exchange: None,
+        }]);
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_indexes(indexes),
+            )
+            .build();
+
+        assert_eq!(
+            client
+                .get(format!("{index_url}flask/"))
+                .send()
+                .await?
+                .status(),
+            200,
+            "The header should be sent to the index"
+        );
+        assert_eq!(
+            client
+                .get(format!("{}/other/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            401,
+            "The header should not be sent outside of the index"
+        );
+
+        Ok(())
+    }
+
+    /// Netrc entries scoped to paths on the same host should apply to the URLs under their
+    /// paths, rather than being shared across the host.
+    #[test(tokio::test)]
+    async fn test_netrc_file_path_scoped() -> Result<(), Error> {
+        let server = MockServer::start().await;
+        for (repository, username, password) in [
+            ("first", "user1", "password1"),
+            ("second", "user2", "password2"),
+        ] {
+            Mock::given(method("GET"))
+                .and(path_regex(format!("^/{repository}/")))
+                .and(basic_auth(username, password))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let base_url = Url::parse(&server.uri())?;
+        let host = base_url.host_str().unwrap();
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            "machine {host}/first login user1 password password1\n\
+             machine {host}/second/ login user2 password password2"
+        )?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
+            )
+            .build();
+
+        for _ in 0..2 {
+            assert_eq!(
+                client
+                    .get(format!("{}/first/simple/", server.uri()))
+                    .send()
+                    .await?
+                    .status(),
+                200
+            );
+            assert_eq!(
+                client
+                    .get(format!("{}/second/simple/", server.uri()))
+                    .send()
+                    .await?
+                    .status(),
+                200
+            );
+        }
+        assert_eq!(
+            client
+                .get(format!("{}/third/simple/", server.uri()))
+                .send()
+                .await?
+                .status(),
+            401,
+            "No credentials should apply outside of the scoped paths"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_netrc_file_mismatched_host() -> Result<(), Error> {
         let username = "user";
@@ -1758,11 +2166,19 @@ mod tests {
                 url: DisplaySafeUrl::from(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                azure_identity: None,
+                credential_helper: None,
+                headers: Vec::new(),
+                token_exchange: None,
             },
             Index {
                 url: DisplaySafeUrl::from(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                azure_identity: None,
+                credential_helper: None,
+                headers: Vec::new(),
+                token_exchange: None,
             },
         ]);
 
@@ -1866,6 +2282,10 @@ mod tests {
             url: DisplaySafeUrl::from(index_url.clone()),
             root_url: DisplaySafeUrl::from(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            azure_identity: None,
+            credential_helper: None,
+            headers: Vec::new(),
+            token_exchange: None,
         }]);
 
         let client = test_client_builder()
@@ -1925,6 +2345,10 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            azure_identity: None,
+            credential_helper: None,
+            headers: Vec::new(),
+            token_exchange: None,
         }])
     }
 
@@ -0,0 +1,291 @@
+//! Short-lived index tokens, exchanged for the OIDC identity token of a CI job, as for trusted
+//! publishing.
+//!
+//! uv requests an identity token for the configured audience from the CI provider (currently,
+//! GitHub Actions), and posts it to the index's token exchange endpoint as `{"token": "<OIDC
+//! token>"}`. The endpoint responds with `{"token": "<index token>"}`, along with an optional
+//! `expires` (in seconds since the Unix epoch) and `username`. The index token is sent as a
+//! "Bearer" token, or with "Basic" authentication if the endpoint returned a username.
+
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::Credentials;
+
+/// The time before expiry at which a token is exchanged again.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// The lifetime assumed for a token if the endpoint doesn't report its expiry (the lifetime of
+/// PyPI's trusted publishing tokens).
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// The tokens exchanged at each endpoint, or `None` if the exchange failed.
+static TOKENS: LazyLock<Mutex<FxHashMap<TokenExchange, Option<Token>>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// The client used for requests to the CI provider and the token exchange endpoints.
+static CLIENT: LazyLock<Result<reqwest::Client, reqwest::Error>> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(10))
+        .build()
+});
+
+/// Returns the client used for requests to the CI provider and the token exchange endpoints.
+fn client() -> anyhow::Result<&'static reqwest::Client> {
+    CLIENT
+        .as_ref()
+        .map_err(|err| anyhow::anyhow!("Failed to build HTTP client: {err}"))
+}
+
+/// A token exchange endpoint, at which the OIDC identity token of a CI job is exchanged for a
+/// short-lived index token.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenExchange {
+    /// The URL of the token exchange endpoint.
+    pub url: DisplaySafeUrl,
+    /// The audience for which to request the OIDC token.
+    ///
+    /// Defaults to the audience returned by `/_/oidc/audience` on the endpoint's host, as for
+    /// trusted publishing to PyPI.
+    #[serde(default)]
+    pub audience: Option<String>,
+}
+
+/// An index token.
+#[derive(Debug, Clone)]
+struct Token {
+    value: String,
+    username: Option<String>,
+    expiration: SystemTime,
+}
+
+impl Token {
+    /// Returns `true` if the token has expired, or will expire within the refresh margin.
+    fn expires_soon(&self) -> bool {
+        !self
+            .expiration
+            .duration_since(SystemTime::now())
+            .is_ok_and(|remaining| remaining > REFRESH_MARGIN)
+    }
+
+    /// The credentials with which to authenticate requests using the token.
+    fn credentials(&self) -> Credentials {
+        match &self.username {
+            Some(username) => Credentials::basic(Some(username.clone()), Some(self.value.clone())),
+            None => Credentials::Bearer {
+                token: self.value.clone().into_bytes(),
+            },
+        }
+    }
+}
+
+/// The response from querying `/_/oidc/audience`.
+#[derive(Deserialize)]
+struct AudienceResponse {
+    audience: String,
+}
+
+/// The response from querying `$ACTIONS_ID_TOKEN_REQUEST_URL`.
+#[derive(Deserialize)]
+struct OidcTokenResponse {
+    value: String,
+}
+
+/// The body of the request to the token exchange endpoint.
+#[derive(Serialize)]
+struct ExchangeRequest<'a> {
+    token: &'a str,
+}
+
+/// The response from the token exchange endpoint.
+#[derive(Debug, Deserialize)]
+struct ExchangeResponse {
+    token: String,
+    #[serde(default)]
+    username: Option<String>,
+    /// The expiry of the token, in seconds since the Unix epoch.
+    #[serde(default)]
+    expires: Option<u64>,
+}
+
+impl From<ExchangeResponse> for Token {
+    fn from(response: ExchangeResponse) -> Self {
+        let expiration = response.expires.map_or_else(
+            || SystemTime::now() + DEFAULT_LIFETIME,
+            |seconds| UNIX_EPOCH + Duration::from_secs(seconds),
+        );
+        Self {
+            value: response.token,
+            username: response.username,
+            expiration,
+        }
+    }
+}
+
+/// A provider for index tokens, exchanged for the OIDC identity token of a CI job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TokenExchangeProvider;
+
+impl TokenExchangeProvider {
+    /// Returns the credentials from the token exchange endpoint, exchanging a token or refreshing
+    /// an expiring one as needed.
+    ///
+    /// Outside of a CI job that can request OIDC tokens, returns `None`, such that other sources
+    /// of credentials (e.g., the keyring) apply.
+    pub(crate) async fn credentials_for(exchange: &TokenExchange) -> Option<Credentials> {
+        let (Ok(request_url), Ok(request_token)) = (
+            std::env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_URL),
+            std::env::var(EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN),
+        ) else {
+            if std::env::var(EnvVars::GITHUB_ACTIONS).as_deref() == Ok("true") {
+                warn_user_once!(
+                    "Skipping the token exchange for {}, as `{}` is not set; is the `id-token: write` permission missing?",
+                    exchange.url,
+                    EnvVars::ACTIONS_ID_TOKEN_REQUEST_TOKEN
+                );
+            } else {
+                debug!(
+                    "Skipping the token exchange for {}, as no OIDC token is available",
+                    exchange.url
+                );
+            }
+            return None;
+        };
+
+        // Hold the lock while exchanging, such that concurrent requests share a single token.
+        let mut tokens = TOKENS.lock().await;
+        match tokens.get(exchange) {
+            Some(None) => return None,
+            Some(Some(token)) if !token.expires_soon() => return Some(token.credentials()),
+            Some(Some(_)) => debug!("Refreshing the index token from {}", exchange.url),
+            None => debug!(
+                "Exchanging an OIDC token for an index token at {}",
+                exchange.url
+            ),
+        }
+
+        let token = match exchange_token(exchange, &request_url, &request_token).await {
+            Ok(token) => Some(token),
+            Err(err) => {
+                warn_user_once!(
+                    "Failed to exchange an OIDC token for an index token at {}: {err:#}",
+                    exchange.url
+                );
+                None
+            }
+        };
+        let credentials = token.as_ref().map(Token::credentials);
+        tokens.insert(exchange.clone(), token);
+        credentials
+    }
+
+    /// Discard the token from the endpoint (e.g., after it was rejected), such that a new one is
+    /// exchanged for the next request.
+    pub(crate) async fn invalidate(exchange: &TokenExchange) {
+        TOKENS.lock().await.remove(exchange);
+    }
+}
+
+/// Exchange the OIDC token of the CI job for an index token.
+async fn exchange_token(
+    exchange: &TokenExchange,
+    request_url: &str,
+    request_token: &str,
+) -> anyhow::Result<Token> {
+    let audience = match &exchange.audience {
+        Some(audience) => audience.clone(),
+        None => fetch_audience(&exchange.url).await?,
+    };
+
+    let mut oidc_token_url = DisplaySafeUrl::parse(request_url)
+        .with_context(|| format!("Invalid `{}`", EnvVars::ACTIONS_ID_TOKEN_REQUEST_URL))?;
+    oidc_token_url
+        .query_pairs_mut()
+        .append_pair("audience", &audience);
+    debug!("Requesting an OIDC token for the audience `{audience}`");
+    let oidc_token = client()?
+        .get(oidc_token_url.as_str())
+        .bearer_auth(request_token)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<OidcTokenResponse>()
+        .await?
+        .value;
+
+    let response = client()?
+        .post(exchange.url.as_str())
+        .json(&ExchangeRequest { token: &oidc_token })
+        .send()
+        .await?;
+    let response = response.error_for_status()?;
+    let token = Token::from(response.json::<ExchangeResponse>().await?);
+
+    // Tell GitHub Actions to mask the token in any console logs.
+    #[allow(clippy::print_stdout)]
+    if std::env::var(EnvVars::GITHUB_ACTIONS).as_deref() == Ok("true") {
+        println!("::add-mask::{}", token.value);
+    }
+
+    Ok(token)
+}
+
+/// Fetch the audience for the token exchange endpoint from `/_/oidc/audience` on its host.
+async fn fetch_audience(url: &DisplaySafeUrl) -> anyhow::Result<String> {
+    let mut audience_url = url.clone();
+    audience_url.set_path("/_/oidc/audience");
+    audience_url.set_query(None);
+    debug!("Querying the OIDC audience from {audience_url}");
+    let audience = client()?
+        .get(audience_url.as_str())
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<AudienceResponse>()
+        .await
+        .with_context(|| format!("Failed to read the OIDC audience from {audience_url}"))?
+        .audience;
+    Ok(audience)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exchange_response() {
+        let response: ExchangeResponse =
+            serde_json::from_str(r#"{"success": true, "token": "pypi-token", "expires": 1000}"#)
+                .unwrap();
+        let token = Token::from(response);
+        assert_eq!(token.expiration, UNIX_EPOCH + Duration::from_secs(1000));
+        assert!(token.expires_soon());
+        assert_eq!(
+            token.credentials(),
+            Credentials::Bearer {
+                token: b"pypi-token".to_vec()
+            }
+        );
+
+        let response: ExchangeResponse =
+            serde_json::from_str(r#"{"token": "token", "username": "__token__"}"#).unwrap();
+        let token = Token::from(response);
+        assert!(!token.expires_soon());
+        assert_eq!(
+            token.credentials(),
+            Credentials::basic(Some("__token__".to_string()), Some("token".to_string()))
+        );
+    }
+}
@@ -1,11 +1,20 @@
+use std::fmt::{self, Display, Formatter};
 use std::sync::LazyLock;
 use tracing::debug;
 use url::Url;
 
+use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
-use crate::Credentials;
+#[cfg(feature = "artifact-registry")]
+use crate::artifact_registry::ArtifactRegistryProvider;
+use crate::azure::AzureProvider;
+#[cfg(feature = "codeartifact")]
+use crate::codeartifact::CodeArtifactProvider;
+use crate::helper::CredentialHelperProvider;
+use crate::oidc::TokenExchangeProvider;
 use crate::realm::{Realm, RealmRef};
+use crate::{AzureIdentity, Credentials, Indexes, TokenExchange};
 
 /// The [`Realm`] for the Hugging Face platform.
 static HUGGING_FACE_REALM: LazyLock<Realm> = LazyLock::new(|| {
@@ -47,3 +56,101 @@ impl HuggingFaceProvider {
         None
     }
 }
+
+/// A source of short-lived tokens, which are attached to every request to its URLs, and refreshed
+/// as they expire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenSource<'a> {
+    /// The credential helper configured for an index.
+    CredentialHelper {
+        helper: &'a str,
+        index_url: &'a DisplaySafeUrl,
+    },
+    /// The OIDC token exchange endpoint configured for an index.
+    TokenExchange(&'a TokenExchange),
+    /// The Azure identity configured for an index.
+    Azure(AzureIdentity),
+    /// An AWS CodeArtifact repository.
+    #[cfg(feature = "codeartifact")]
+    CodeArtifact(&'a Url),
+    /// A Google Artifact Registry repository.
+    #[cfg(feature = "artifact-registry")]
+    ArtifactRegistry(&'a Url),
+}
+
+impl<'a> TokenSource<'a> {
+    /// Returns the first token source that applies to a URL, along with its credentials.
+    ///
+    /// Sources configured for the index take precedence over those detected from the URL.
+    pub(crate) async fn credentials_for(
+        indexes: &'a Indexes,
+        url: &'a Url,
+    ) -> Option<(Self, Credentials)> {
+        let mut sources = Vec::new();
+        if let Some((index_url, helper)) = indexes.credential_helper_for(url) {
+            sources.push(Self::CredentialHelper { helper, index_url });
+        }
+        if let Some(exchange) = indexes.token_exchange_for(url) {
+            sources.push(Self::TokenExchange(exchange));
+        }
+        if let Some(identity) = indexes.azure_identity_for(url) {
+            sources.push(Self::Azure(identity));
+        }
+        #[cfg(feature = "codeartifact")]
+        sources.push(Self::CodeArtifact(url));
+        #[cfg(feature = "artifact-registry")]
+        sources.push(Self::ArtifactRegistry(url));
+
+        for source in sources {
+            if let Some(credentials) = source.credentials().await {
+                return Some((source, credentials));
+            }
+        }
+        None
+    }
+
+    /// Returns the credentials from the source, fetching or refreshing them as needed.
+    pub(crate) async fn credentials(self) -> Option<Credentials> {
+        match self {
+            Self::CredentialHelper { helper, index_url } => {
+                CredentialHelperProvider::credentials_for(helper, index_url).await
+            }
+            Self::TokenExchange(exchange) => TokenExchangeProvider::credentials_for(exchange).await,
+            Self::Azure(identity) => AzureProvider::credentials_for(identity).await,
+            #[cfg(feature = "codeartifact")]
+            Self::CodeArtifact(url) => CodeArtifactProvider::credentials_for(url).await,
+            #[cfg(feature = "artifact-registry")]
+            Self::ArtifactRegistry(url) => ArtifactRegistryProvider::credentials_for(url).await,
+        }
+    }
+
+    /// Discard the credentials from the source (e.g., after they were rejected), such that they're
+    /// fetched again.
+    pub(crate) async fn invalidate(self) {
+        match self {
+            Self::CredentialHelper { helper, index_url } => {
+                CredentialHelperProvider::invalidate(helper, index_url).await;
+            }
+            Self::TokenExchange(exchange) => TokenExchangeProvider::invalidate(exchange).await,
+            Self::Azure(identity) => AzureProvider::invalidate(identity).await,
+            #[cfg(feature = "codeartifact")]
+            Self::CodeArtifact(url) => CodeArtifactProvider::invalidate(url).await,
+            #[cfg(feature = "artifact-registry")]
+            Self::ArtifactRegistry(_) => ArtifactRegistryProvider::invalidate().await,
+        }
+    }
+}
+
+impl Display for TokenSource<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CredentialHelper { helper, .. } => write!(f, "credential helper `{helper}`"),
+            Self::TokenExchange(exchange) => write!(f, "token exchange at {}", exchange.url),
+            Self::Azure(identity) => write!(f, "Microsoft Entra ID (`{identity}`)"),
+            #[cfg(feature = "codeartifact")]
+            Self::CodeArtifact(_) => write!(f, "AWS CodeArtifact"),
+            #[cfg(feature = "artifact-registry")]
+            Self::ArtifactRegistry(_) => write!(f, "Google Artifact Registry"),
+        }
+    }
+}
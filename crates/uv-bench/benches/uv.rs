@@ -216,7 +216,7 @@ mod resolver {
             &hashes,
             &build_context,
             installed_packages,
-            DistributionDatabase::new(client, &build_context, concurrency.downloads),
+            DistributionDatabase::new(client, &build_context, concurrency),
         )?;
 
         Ok(resolver.resolve().await?)
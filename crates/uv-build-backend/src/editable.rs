@@ -0,0 +1,182 @@
+//! The files that make the module importable from the source tree in an editable install.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use indoc::formatdoc;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+
+use crate::Error;
+use crate::settings::EditableMode;
+
+/// The files to add to the editable wheel, as path in the wheel and contents.
+///
+/// `modules_relative` are the module paths relative to `src_root`.
+pub(crate) fn editable_files(
+    source_tree: &Path,
+    name: &PackageName,
+    src_root: &Path,
+    modules_relative: &[PathBuf],
+    mode: EditableMode,
+) -> Result<Vec<(String, Vec<u8>)>, Error> {
+    let name = name.as_dist_info_name();
+    let pth = format!("{name}.pth");
+    match mode {
+        EditableMode::Compat => Ok(vec![(
+            pth,
+            src_root.as_os_str().as_encoded_bytes().to_vec(),
+        )]),
+        EditableMode::Strict => {
+            let finder = format!("__editable___{name}_finder");
+            Ok(vec![
+                (
+                    format!("{finder}.py"),
+                    finder_module(src_root, modules_relative).into_bytes(),
+                ),
+                (pth, format!("import {finder}\n").into_bytes()),
+            ])
+        }
+        EditableMode::Symlink => {
+            let link_dir = source_tree
+                .join("build")
+                .join(format!("__editable__.{name}"));
+            link_modules(&link_dir, src_root, modules_relative)?;
+            Ok(vec![(
+                pth,
+                link_dir.as_os_str().as_encoded_bytes().to_vec(),
+            )])
+        }
+    }
+}
+
+/// A module with an import hook that finds the modules of the package in the source tree, and
+/// the parents of dotted modules as namespace packages.
+fn finder_module(src_root: &Path, modules_relative: &[PathBuf]) -> String {
+    // The dotted module name and the directory that contains it.
+    let mut modules = BTreeMap::new();
+    let mut namespaces = BTreeSet::new();
+    for module_relative in modules_relative {
+        let module_name = module_relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(".");
+        let parent = src_root.join(module_relative.parent().unwrap_or(Path::new("")));
+        for (position, _) in module_name.match_indices('.') {
+            namespaces.insert(module_name[..position].to_string());
+        }
+        modules.insert(module_name, parent.simplified_display().to_string());
+    }
+
+    formatdoc! {r#"
+        import importlib.machinery
+        import sys
+
+        _MODULES = {modules}
+        _NAMESPACES = frozenset({namespaces})
+
+
+        class EditableFinder:
+            """Find the modules of the editable install in the source tree."""
+
+            @classmethod
+            def find_spec(cls, fullname, path=None, target=None):
+                if fullname in _MODULES:
+                    return importlib.machinery.PathFinder.find_spec(fullname, [_MODULES[fullname]])
+                if fullname in _NAMESPACES:
+                    spec = importlib.machinery.ModuleSpec(fullname, None, is_package=True)
+                    spec.submodule_search_locations = []
+                    return spec
+                return None
+
+
+        sys.meta_path.append(EditableFinder)
+    "#,
+        modules = serde_json::to_string(&modules).expect("strings are serializable"),
+        namespaces = serde_json::to_string(&namespaces).expect("strings are serializable"),
+    }
+}
+
+/// Symlink the modules of the package into `link_dir`, keeping the directories of dotted modules.
+fn link_modules(
+    link_dir: &Path,
+    src_root: &Path,
+    modules_relative: &[PathBuf],
+) -> Result<(), Error> {
+    // Remove links to previously built modules.
+    if link_dir.is_dir() {
+        fs_err::remove_dir_all(link_dir)?;
+    }
+    for module_relative in modules_relative {
+        let link = link_dir.join(module_relative);
+        if let Some(parent) = link.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        let module = src_root.join(module_relative);
+        debug!(
+            "Linking module for editable install: `{}` -> `{}`",
+            link.user_display(),
+            module.user_display()
+        );
+        uv_fs::create_symlink(&module, &link).map_err(|err| Error::EditableSymlink(link, err))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+    use std::str::FromStr;
+    use tempfile::TempDir;
+
+    #[cfg(unix)]
+    #[test]
+    fn strict_finder() {
+        let modules_relative = [
+            PathBuf::from("foo"),
+            PathBuf::from_iter(["cloud", "database"]),
+        ];
+        let finder = finder_module(Path::new("/project/src"), &modules_relative);
+        assert_snapshot!(finder.lines().take(5).collect::<Vec<_>>().join("\n"), @r#"
+        import importlib.machinery
+        import sys
+
+        _MODULES = {"cloud.database":"/project/src/cloud","foo":"/project/src"}
+        _NAMESPACES = frozenset(["cloud"])
+        "#);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_modules() {
+        let source_tree = TempDir::new().unwrap();
+        let src_root = source_tree.path().join("src");
+        fs_err::create_dir_all(src_root.join("cloud/database")).unwrap();
+        fs_err::write(src_root.join("cloud/database/__init__.py"), "").unwrap();
+        fs_err::write(src_root.join("unrelated.py"), "").unwrap();
+
+        let files = editable_files(
+            source_tree.path(),
+            &PackageName::from_str("cloud-database").unwrap(),
+            &src_root,
+            &[PathBuf::from_iter(["cloud", "database"])],
+            EditableMode::Symlink,
+        )
+        .unwrap();
+
+        let link_dir = source_tree.path().join("build/__editable__.cloud_database");
+        assert_eq!(
+            files,
+            [(
+                "cloud_database.pth".to_string(),
+                link_dir.as_os_str().as_encoded_bytes().to_vec()
+            )]
+        );
+        assert!(link_dir.join("cloud/database/__init__.py").is_file());
+        assert!(!link_dir.join("unrelated.py").exists());
+    }
+}
@@ -0,0 +1,631 @@
+//! Compile extension modules for platform-specific wheels.
+//!
+//! C and C++ extension modules are compiled and linked with a single invocation of the system
+//! compiler, Rust extension modules are built with `cargo rustc`. Both are built for the Python
+//! interpreter that runs the build, which the `uv_build` shim passes through
+//! `UV_BUILD_BACKEND_PYTHON`. For cross builds, `_PYTHON_HOST_PLATFORM` sets the target platform.
+
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use serde::Deserialize;
+use tracing::{debug, trace};
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+use uv_globfilter::{GlobDirFilter, PortableGlobParser};
+use uv_platform_tags::{AbiTag, Arch, LanguageTag, PlatformTag};
+use uv_pypi_types::Identifier;
+use uv_static::EnvVars;
+
+use crate::Error;
+use crate::settings::ExtensionModule;
+
+/// Print the properties of the interpreter required to build and tag extension modules, one per
+/// line.
+const QUERY_SCRIPT: &str = "\
+import sys, sysconfig
+print(sys.implementation.name)
+print(sys.version_info[0])
+print(sys.version_info[1])
+print(int(bool(sysconfig.get_config_var('Py_GIL_DISABLED'))))
+print(sysconfig.get_platform())
+print(sysconfig.get_config_var('EXT_SUFFIX'))
+print(sysconfig.get_path('include'))
+";
+
+/// The Python interpreter to build extension modules for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TargetPython {
+    executable: PathBuf,
+    python_version: (u8, u8),
+    gil_disabled: bool,
+    platform_tag: PlatformTag,
+    /// The filename suffix for extension modules, e.g., `.cpython-312-x86_64-linux-gnu.so`.
+    ext_suffix: String,
+    /// The directory containing `Python.h`.
+    include_dir: PathBuf,
+}
+
+/// The Python interpreter running the build from `UV_BUILD_BACKEND_PYTHON`, or the one from the
+/// `PATH`.
+pub(crate) fn backend_python() -> PathBuf {
+    std::env::var_os(EnvVars::UV_BUILD_BACKEND_PYTHON)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(if cfg!(windows) { "python" } else { "python3" }))
+}
+
+impl TargetPython {
+    /// Query the interpreter from `UV_BUILD_BACKEND_PYTHON`, or from the `PATH`.
+    pub(crate) fn query() -> Result<Self, Error> {
+        let executable = backend_python();
+        debug!(
+            "Querying Python interpreter for extension modules: `{}`",
+            executable.user_display()
+        );
+
+        let output = Command::new(&executable)
+            .arg("-c")
+            .arg(QUERY_SCRIPT)
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|err| Error::PythonQuery(executable.clone(), err))?;
+        if !output.status.success() {
+            return Err(Error::PythonQueryOutput(
+                executable,
+                format!("The interpreter exited with {}", output.status),
+            ));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let python = Self::parse(executable, &stdout)?;
+        match std::env::var(EnvVars::_PYTHON_HOST_PLATFORM) {
+            Ok(host_platform) if !host_platform.is_empty() => {
+                python.with_host_platform(&host_platform)
+            }
+            _ => Ok(python),
+        }
+    }
+
+    /// Parse the output of [`QUERY_SCRIPT`].
+    fn parse(executable: PathBuf, output: &str) -> Result<Self, Error> {
+        let invalid = |message: String| Error::PythonQueryOutput(executable.clone(), message);
+
+        let lines: Vec<&str> = output.lines().map(str::trim).collect();
+        let [
+            implementation,
+            major,
+            minor,
+            gil_disabled,
+            platform,
+            ext_suffix,
+            include_dir,
+        ] = lines.as_slice()
+        else {
+            return Err(invalid(format!("Expected 7 lines, found: `{output}`")));
+        };
+
+        if *implementation != "cpython" {
+            return Err(Error::UnsupportedImplementation(
+                (*implementation).to_string(),
+            ));
+        }
+        let major = major
+            .parse::<u8>()
+            .map_err(|_| invalid(format!("Invalid major version: `{major}`")))?;
+        let minor = minor
+            .parse::<u8>()
+            .map_err(|_| invalid(format!("Invalid minor version: `{minor}`")))?;
+        // `sysconfig.get_platform()` returns e.g. `linux-x86_64` or `macosx-11.0-arm64`, while the
+        // platform tag is `linux_x86_64` or `macosx_11_0_arm64`.
+        let platform_tag = PlatformTag::from_str(&platform.replace(['-', '.'], "_"))
+            .map_err(|err| invalid(format!("Invalid platform `{platform}`: {err}")))?;
+        if *ext_suffix == "None" {
+            return Err(invalid(
+                "The interpreter does not define `EXT_SUFFIX`".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            python_version: (major, minor),
+            gil_disabled: *gil_disabled == "1",
+            platform_tag,
+            ext_suffix: (*ext_suffix).to_string(),
+            include_dir: PathBuf::from(include_dir),
+            executable,
+        })
+    }
+
+    /// Build for another platform than the one of the interpreter, with a `sysconfig.get_platform()`
+    /// style platform such as `manylinux_2_28-aarch64`.
+    ///
+    /// `sysconfig` already respects `_PYTHON_HOST_PLATFORM` on Unix but not on Windows, and
+    /// `EXT_SUFFIX` is always the one of the interpreter, so we derive both from the target
+    /// platform.
+    fn with_host_platform(mut self, host_platform: &str) -> Result<Self, Error> {
+        let platform_tag = PlatformTag::from_str(&host_platform.replace(['-', '.'], "_"))
+            .map_err(|err| Error::InvalidHostPlatform(host_platform.to_string(), err))?;
+        if platform_tag == self.platform_tag {
+            return Ok(self);
+        }
+        debug!("Building extension modules for `{platform_tag}`");
+
+        let (major, minor) = self.python_version;
+        let version = format!("{major}{minor}{}", if self.gil_disabled { "t" } else { "" });
+        // The multiarch suffix of the target, falling back to the untagged `.so`, which all
+        // platforms except Windows can import.
+        let multiarch = |arch: Arch, libc: &str| match arch {
+            Arch::X86_64 | Arch::Aarch64 => format!(".cpython-{version}-{arch}-linux-{libc}.so"),
+            _ => ".so".to_string(),
+        };
+        self.ext_suffix = match &platform_tag {
+            PlatformTag::Manylinux { arch, .. }
+            | PlatformTag::Manylinux1 { arch }
+            | PlatformTag::Manylinux2010 { arch }
+            | PlatformTag::Manylinux2014 { arch }
+            | PlatformTag::Linux { arch } => multiarch(*arch, "gnu"),
+            PlatformTag::Musllinux { arch, .. } => multiarch(*arch, "musl"),
+            PlatformTag::Macos { .. } => format!(".cpython-{version}-darwin.so"),
+            PlatformTag::Pyodide { .. } => format!(".cpython-{version}-wasm32-emscripten.so"),
+            PlatformTag::Win32 => format!(".cp{version}-win32.pyd"),
+            PlatformTag::WinAmd64 => format!(".cp{version}-win_amd64.pyd"),
+            PlatformTag::WinArm64 => format!(".cp{version}-win_arm64.pyd"),
+            _ => ".so".to_string(),
+        };
+        self.platform_tag = platform_tag;
+        Ok(self)
+    }
+
+    /// Whether the extension modules are built for macOS.
+    fn is_macos(&self) -> bool {
+        matches!(self.platform_tag, PlatformTag::Macos { .. })
+    }
+
+    /// The language, ABI and platform tag for wheels built for this interpreter, e.g.,
+    /// `cp312-cp312-linux_x86_64`.
+    pub(crate) fn tags(&self) -> (LanguageTag, AbiTag, PlatformTag) {
+        (
+            LanguageTag::CPython {
+                python_version: self.python_version,
+            },
+            AbiTag::CPython {
+                gil_disabled: self.gil_disabled,
+                python_version: self.python_version,
+            },
+            self.platform_tag.clone(),
+        )
+    }
+
+    /// The path of the extension module relative to the wheel root (or the module root),
+    /// e.g., `foo/_speedups.cpython-312-x86_64-linux-gnu.so` for `foo._speedups`.
+    pub(crate) fn module_path(&self, module: &ExtensionModule) -> Result<String, Error> {
+        for part in module.name.split('.') {
+            Identifier::from_str(part)
+                .map_err(|err| Error::InvalidModuleName(module.name.clone(), err))?;
+        }
+        Ok(format!(
+            "{}{}",
+            module.name.replace('.', "/"),
+            self.ext_suffix
+        ))
+    }
+}
+
+/// Compile the extension modules into `target_dir`.
+///
+/// Returns the path of each extension module relative to `target_dir`, and its absolute path.
+pub(crate) fn build_extension_modules(
+    source_tree: &Path,
+    ext_modules: &[ExtensionModule],
+    python: &TargetPython,
+    target_dir: &Path,
+) -> Result<Vec<(String, PathBuf)>, Error> {
+    let mut built = Vec::new();
+    for module in ext_modules {
+        let relative = python.module_path(module)?;
+        let output = target_dir.join(&relative);
+        if let Some(parent) = output.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        match (&module.cargo_manifest, module.sources.is_empty()) {
+            (Some(manifest), true) => {
+                if !module.include_dirs.is_empty() || !module.define_macros.is_empty() {
+                    return Err(Error::InvalidExtensionModule(
+                        module.name.clone(),
+                        "`include-dirs` and `define-macros` are not supported for Rust extension modules",
+                    ));
+                }
+                build_cargo(source_tree, module, manifest, python, &output)?;
+            }
+            (None, false) => build_c(source_tree, module, python, &output)?,
+            (Some(_), false) => {
+                return Err(Error::InvalidExtensionModule(
+                    module.name.clone(),
+                    "`sources` and `cargo-manifest` are mutually exclusive",
+                ));
+            }
+            (None, true) => {
+                return Err(Error::InvalidExtensionModule(
+                    module.name.clone(),
+                    "Either `sources` or `cargo-manifest` is required",
+                ));
+            }
+        }
+
+        debug!("Built extension module: `{}`", output.user_display());
+        built.push((relative, output));
+    }
+    Ok(built)
+}
+
+/// The files, directories and globs of the extension modules to include in the source
+/// distribution.
+pub(crate) fn source_dist_includes(ext_modules: &[ExtensionModule]) -> Vec<String> {
+    let mut includes = Vec::new();
+    for module in ext_modules {
+        includes.extend(module.sources.iter().cloned());
+        for include_dir in &module.include_dirs {
+            let include_dir = uv_fs::normalize_path(include_dir);
+            includes.push(format!(
+                "{}/**",
+                globset::escape(&include_dir.portable_display().to_string())
+            ));
+        }
+        if let Some(manifest) = &module.cargo_manifest {
+            let manifest = uv_fs::normalize_path(manifest);
+            let crate_dir = manifest
+                .parent()
+                .map(|parent| parent.portable_display().to_string())
+                .filter(|parent| !parent.is_empty())
+                .map(|parent| format!("{}/", globset::escape(&parent)))
+                .unwrap_or_default();
+            includes.push(globset::escape(&manifest.portable_display().to_string()));
+            // Include the crate sources, but not the `target` directory.
+            for file in ["Cargo.lock", "build.rs", "src/**"] {
+                includes.push(format!("{crate_dir}{file}"));
+            }
+        }
+    }
+    includes
+}
+
+/// Compile and link a C or C++ extension module with the system compiler.
+fn build_c(
+    source_tree: &Path,
+    module: &ExtensionModule,
+    python: &TargetPython,
+    output: &Path,
+) -> Result<(), Error> {
+    if cfg!(windows) {
+        return Err(Error::InvalidExtensionModule(
+            module.name.clone(),
+            "C and C++ extension modules are not supported on Windows, use a Rust extension module instead",
+        ));
+    }
+
+    let sources = find_sources(source_tree, module)?;
+    if sources.is_empty() {
+        return Err(Error::InvalidExtensionModule(
+            module.name.clone(),
+            "`sources` did not match any files",
+        ));
+    }
+    let cpp = sources.iter().any(|source| {
+        matches!(
+            source.extension().and_then(OsStr::to_str),
+            Some("cc" | "cpp" | "cxx")
+        )
+    });
+    let (compiler_var, default_compiler, flags_var) = if cpp {
+        (EnvVars::CXX, "c++", EnvVars::CXXFLAGS)
+    } else {
+        (EnvVars::CC, "cc", EnvVars::CFLAGS)
+    };
+
+    // `CC` may contain a wrapper or arguments, e.g., `ccache gcc`.
+    let compiler = std::env::var(compiler_var).unwrap_or_else(|_| default_compiler.to_string());
+    let mut compiler = compiler.split_whitespace();
+    let program = compiler.next().unwrap_or(default_compiler);
+    let mut command = Command::new(program);
+    command
+        .args(compiler)
+        .current_dir(source_tree)
+        .args(["-shared", "-fPIC", "-O2"])
+        .arg("-I")
+        .arg(&python.include_dir);
+    for include_dir in &module.include_dirs {
+        command.arg("-I").arg(include_dir);
+    }
+    for define_macro in &module.define_macros {
+        command.arg(format!("-D{define_macro}"));
+    }
+    if let Ok(flags) = std::env::var(flags_var) {
+        command.args(flags.split_whitespace());
+    }
+    command.args(&module.extra_compile_args).args(&sources);
+    for library_dir in &module.library_dirs {
+        command.arg("-L").arg(library_dir);
+    }
+    for library in &module.libraries {
+        command.arg(format!("-l{library}"));
+    }
+    // Symbols from libpython are resolved when the module is loaded.
+    if python.is_macos() {
+        command.args(["-undefined", "dynamic_lookup"]);
+    }
+    if let Ok(flags) = std::env::var(EnvVars::LDFLAGS) {
+        command.args(flags.split_whitespace());
+    }
+    command.args(&module.extra_link_args).arg("-o").arg(output);
+    // The backend reports the built filename on stdout, forward the compiler output to stderr.
+    command.stdout(std::io::stderr());
+
+    run(&module.name, &mut command)
+}
+
+/// A message from `cargo --message-format json`.
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    #[serde(default)]
+    filenames: Vec<PathBuf>,
+}
+
+/// Build a Rust extension module from a crate with a `cdylib` target.
+fn build_cargo(
+    source_tree: &Path,
+    module: &ExtensionModule,
+    manifest: &Path,
+    python: &TargetPython,
+    output: &Path,
+) -> Result<(), Error> {
+    let mut command = Command::new("cargo");
+    command
+        .current_dir(source_tree)
+        .args(["rustc", "--lib", "--release", "--message-format"])
+        .arg("json-render-diagnostics")
+        .arg("--manifest-path")
+        .arg(manifest)
+        .env(EnvVars::PYO3_PYTHON, &python.executable)
+        .arg("--");
+    for library_dir in &module.library_dirs {
+        command.arg("-L").arg(library_dir);
+    }
+    for library in &module.libraries {
+        command.arg("-l").arg(library);
+    }
+    command.args(&module.extra_compile_args);
+    // Symbols from libpython are resolved when the module is loaded.
+    if python.is_macos() {
+        command.args(["-C", "link-arg=-undefined", "-C", "link-arg=dynamic_lookup"]);
+    }
+    for link_arg in &module.extra_link_args {
+        command.arg("-C").arg(format!("link-arg={link_arg}"));
+    }
+    command.stdout(Stdio::piped());
+
+    debug!("Building extension module `{}`: {command:?}", module.name);
+    let mut child = command
+        .spawn()
+        .map_err(|err| Error::ExtensionCompile(module.name.clone(), err))?;
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let mut library = None;
+    for line in BufReader::new(stdout).lines() {
+        let line = line?;
+        let Ok(message) = serde_json::from_str::<CargoMessage>(&line) else {
+            trace!("Ignoring cargo output: {line}");
+            continue;
+        };
+        if message.reason != "compiler-artifact" {
+            continue;
+        }
+        if let Some(filename) = message.filenames.into_iter().find(|filename| {
+            matches!(
+                filename.extension().and_then(OsStr::to_str),
+                Some("so" | "dylib" | "dll")
+            )
+        }) {
+            library = Some(filename);
+        }
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(Error::ExtensionCompilerStatus(
+            module.name.clone(),
+            format!("{command:?}"),
+            status,
+        ));
+    }
+
+    let library = library.ok_or_else(|| Error::MissingCdylib(module.name.clone()))?;
+    debug!(
+        "Copying `{}` to `{}`",
+        library.user_display(),
+        output.user_display()
+    );
+    fs_err::copy(&library, output)?;
+    Ok(())
+}
+
+/// Find the source files matching the `sources` globs of an extension module.
+fn find_sources(source_tree: &Path, module: &ExtensionModule) -> Result<Vec<PathBuf>, Error> {
+    let field = format!("tool.uv.build-backend.ext-modules.{}.sources", module.name);
+    let globs: Vec<_> = module
+        .sources
+        .iter()
+        .map(|source| PortableGlobParser::Pep639.parse(source))
+        .collect::<Result<_, _>>()
+        .map_err(|err| Error::PortableGlob {
+            field: field.clone(),
+            source: err,
+        })?;
+    let matcher = GlobDirFilter::from_globs(&globs).map_err(|err| Error::GlobSetTooLarge {
+        field: field.clone(),
+        source: err,
+    })?;
+
+    let mut sources = Vec::new();
+    for entry in WalkDir::new(source_tree)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let relative = entry
+                .path()
+                .strip_prefix(source_tree)
+                .expect("walkdir starts with root");
+            // Fast path: Don't descend into a directory that can't be included.
+            matcher.match_directory(relative)
+        })
+    {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: source_tree.to_path_buf(),
+            err,
+        })?;
+        let relative = entry
+            .path()
+            .strip_prefix(source_tree)
+            .expect("walkdir starts with root");
+        if entry.file_type().is_file() && matcher.match_path(relative) {
+            trace!("Extension module source: `{}`", relative.user_display());
+            sources.push(relative.to_path_buf());
+        }
+    }
+    Ok(sources)
+}
+
+/// Run a compiler, failing if it didn't exit successfully.
+fn run(name: &str, command: &mut Command) -> Result<(), Error> {
+    debug!("Building extension module `{name}`: {command:?}");
+    let status = command
+        .status()
+        .map_err(|err| Error::ExtensionCompile(name.to_string(), err))?;
+    if !status.success() {
+        return Err(Error::ExtensionCompilerStatus(
+            name.to_string(),
+            format!("{command:?}"),
+            status,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_target_python() {
+        let output = "cpython\n3\n12\n0\nlinux-x86_64\n.cpython-312-x86_64-linux-gnu.so\n/usr/include/python3.12\n";
+        let python = TargetPython::parse(PathBuf::from("python3"), output).unwrap();
+        let (language, abi, platform) = python.tags();
+        assert_eq!(
+            format!("{language}-{abi}-{platform}"),
+            "cp312-cp312-linux_x86_64"
+        );
+        let module = ExtensionModule {
+            name: "foo._speedups".to_string(),
+            ..ExtensionModule::default()
+        };
+        assert_eq!(
+            python.module_path(&module).unwrap(),
+            "foo/_speedups.cpython-312-x86_64-linux-gnu.so"
+        );
+
+        let output = "cpython\n3\n13\n1\nmacosx-11.0-arm64\n.cpython-313t-darwin.so\n/include\n";
+        let python = TargetPython::parse(PathBuf::from("python3"), output).unwrap();
+        let (language, abi, platform) = python.tags();
+        assert_eq!(
+            format!("{language}-{abi}-{platform}"),
+            "cp313-cp313t-macosx_11_0_arm64"
+        );
+
+        let output = "pypy\n3\n10\n0\nlinux-x86_64\n.pypy310-pp73-x86_64-linux-gnu.so\n/include\n";
+        assert!(matches!(
+            TargetPython::parse(PathBuf::from("pypy3"), output),
+            Err(Error::UnsupportedImplementation(_))
+        ));
+    }
+
+    #[test]
+    fn cross_target_python() {
+        let output = "cpython\n3\n12\n0\nlinux-x86_64\n.cpython-312-x86_64-linux-gnu.so\n/usr/include/python3.12\n";
+        let python = TargetPython::parse(PathBuf::from("python3"), output).unwrap();
+        let module = ExtensionModule {
+            name: "foo._speedups".to_string(),
+            ..ExtensionModule::default()
+        };
+
+        let cross = |host_platform: &str| {
+            let python = python.clone().with_host_platform(host_platform).unwrap();
+            let (language, abi, platform) = python.tags();
+            (
+                format!("{language}-{abi}-{platform}"),
+                python.module_path(&module).unwrap(),
+            )
+        };
+        assert_eq!(
+            cross("manylinux_2_28-aarch64"),
+            (
+                "cp312-cp312-manylinux_2_28_aarch64".to_string(),
+                "foo/_speedups.cpython-312-aarch64-linux-gnu.so".to_string()
+            )
+        );
+        assert_eq!(
+            cross("macosx-13.0-arm64"),
+            (
+                "cp312-cp312-macosx_13_0_arm64".to_string(),
+                "foo/_speedups.cpython-312-darwin.so".to_string()
+            )
+        );
+        assert_eq!(
+            cross("win-amd64"),
+            (
+                "cp312-cp312-win_amd64".to_string(),
+                "foo/_speedups.cp312-win_amd64.pyd".to_string()
+            )
+        );
+        // Targeting the platform of the interpreter keeps its `EXT_SUFFIX`.
+        assert_eq!(
+            cross("linux-x86_64"),
+            (
+                "cp312-cp312-linux_x86_64".to_string(),
+                "foo/_speedups.cpython-312-x86_64-linux-gnu.so".to_string()
+            )
+        );
+        assert!(matches!(
+            python.clone().with_host_platform("amiga"),
+            Err(Error::InvalidHostPlatform(..))
+        ));
+    }
+
+    #[test]
+    fn sdist_includes() {
+        let ext_modules = [
+            ExtensionModule {
+                name: "foo._speedups".to_string(),
+                sources: vec!["src/foo/*.c".to_string()],
+                include_dirs: vec![PathBuf::from("include")],
+                ..ExtensionModule::default()
+            },
+            ExtensionModule {
+                name: "foo._native".to_string(),
+                cargo_manifest: Some(PathBuf::from("rust/Cargo.toml")),
+                ..ExtensionModule::default()
+            },
+        ];
+        assert_eq!(
+            source_dist_includes(&ext_modules),
+            [
+                "src/foo/*.c",
+                "include/**",
+                "rust/Cargo.toml",
+                "rust/Cargo.lock",
+                "rust/build.rs",
+                "rust/src/**"
+            ]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Run the build hooks that generate files, such as protobuf stubs, before building a wheel.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::Error;
+use crate::extension::backend_python;
+use crate::settings::BuildHook;
+
+/// Call the `module:function` entry point in the first argument with the build directory in the
+/// second argument.
+const ENTRY_POINT_SCRIPT: &str = "\
+import functools, importlib, sys
+module, _, function = sys.argv[1].partition(':')
+functools.reduce(getattr, function.split('.'), importlib.import_module(module))(sys.argv[2])
+";
+
+/// A readable name of the hook for messages.
+fn hook_name(hook: &BuildHook) -> String {
+    hook.entry_point
+        .clone()
+        .unwrap_or_else(|| hook.command.join(" "))
+}
+
+/// Run the build hooks in order, with `build_dir` as output directory.
+pub(crate) fn run_build_hooks(
+    source_tree: &Path,
+    hooks: &[BuildHook],
+    build_dir: &Path,
+) -> Result<(), Error> {
+    for (index, hook) in hooks.iter().enumerate() {
+        let mut command = match (&hook.entry_point, hook.command.as_slice()) {
+            (Some(entry_point), []) => {
+                if !entry_point.contains(':') {
+                    return Err(Error::InvalidBuildHook(
+                        index,
+                        "`entry-point` must have the form `module:function`",
+                    ));
+                }
+                let mut command = Command::new(backend_python());
+                command
+                    .arg("-c")
+                    .arg(ENTRY_POINT_SCRIPT)
+                    .arg(entry_point)
+                    .arg(build_dir);
+                command
+            }
+            (None, [program, args @ ..]) => {
+                let mut command = Command::new(program);
+                command.args(args);
+                command
+            }
+            (Some(_), [_, ..]) => {
+                return Err(Error::InvalidBuildHook(
+                    index,
+                    "`command` and `entry-point` are mutually exclusive",
+                ));
+            }
+            (None, []) => {
+                return Err(Error::InvalidBuildHook(
+                    index,
+                    "Either `command` or `entry-point` is required",
+                ));
+            }
+        };
+
+        let name = hook_name(hook);
+        debug!("Running build hook: `{name}`");
+        command
+            .current_dir(source_tree)
+            .env(EnvVars::UV_BUILD_HOOK_OUTPUT_DIR, build_dir)
+            // The backend reports the built filename on stdout, forward the hook output to stderr.
+            .stdout(std::io::stderr());
+        let status = command
+            .status()
+            .map_err(|err| Error::BuildHookSpawn(name.clone(), err))?;
+        if !status.success() {
+            return Err(Error::BuildHookStatus(name, status));
+        }
+
+        for output in &hook.outputs {
+            if !source_tree.join(output).is_file() {
+                return Err(Error::MissingBuildHookOutput(name, output.clone()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The declared outputs of the hooks in the source tree, as path in the wheel and file.
+pub(crate) fn hook_outputs(
+    source_tree: &Path,
+    src_root: &Path,
+    hooks: &[BuildHook],
+) -> Result<Vec<(String, PathBuf)>, Error> {
+    let mut outputs = Vec::new();
+    for (index, hook) in hooks.iter().enumerate() {
+        for output in &hook.outputs {
+            let file = source_tree.join(output);
+            let wheel_path = file.strip_prefix(src_root).map_err(|_| {
+                Error::InvalidBuildHook(index, "`outputs` must be inside the `module-root`")
+            })?;
+            outputs.push((wheel_path.portable_display().to_string(), file.clone()));
+        }
+    }
+    Ok(outputs)
+}
+
+/// The files the hooks wrote to the build directory, as path in the wheel and file.
+pub(crate) fn build_dir_files(build_dir: &Path) -> Result<Vec<(String, PathBuf)>, Error> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(build_dir).sort_by_file_name() {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: build_dir.to_path_buf(),
+            err,
+        })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let wheel_path = entry
+            .path()
+            .strip_prefix(build_dir)
+            .expect("walkdir starts with root");
+        files.push((
+            wheel_path.portable_display().to_string(),
+            entry.path().to_path_buf(),
+        ));
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn invalid_hooks() {
+        let source_tree = TempDir::new().unwrap();
+        let run = |hook: BuildHook| {
+            run_build_hooks(source_tree.path(), &[hook], source_tree.path())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            run(BuildHook::default()),
+            "Invalid `tool.uv.build-backend.build-hooks[0]`: Either `command` or `entry-point` is required"
+        );
+        assert_eq!(
+            run(BuildHook {
+                command: vec!["protoc".to_string()],
+                entry_point: Some("tools.codegen:generate".to_string()),
+                ..BuildHook::default()
+            }),
+            "Invalid `tool.uv.build-backend.build-hooks[0]`: `command` and `entry-point` are mutually exclusive"
+        );
+        assert_eq!(
+            run(BuildHook {
+                entry_point: Some("tools.codegen".to_string()),
+                ..BuildHook::default()
+            }),
+            "Invalid `tool.uv.build-backend.build-hooks[0]`: `entry-point` must have the form `module:function`"
+        );
+    }
+
+    #[test]
+    fn outputs() {
+        let source_tree = TempDir::new().unwrap();
+        let src_root = source_tree.path().join("src");
+        let hooks = [BuildHook {
+            command: vec!["protoc".to_string()],
+            outputs: vec![PathBuf::from("src/foo/foo_pb2.py")],
+            ..BuildHook::default()
+        }];
+        let outputs = hook_outputs(source_tree.path(), &src_root, &hooks).unwrap();
+        assert_eq!(
+            outputs,
+            [(
+                "foo/foo_pb2.py".to_string(),
+                source_tree.path().join("src/foo/foo_pb2.py")
+            )]
+        );
+
+        let hooks = [BuildHook {
+            command: vec!["protoc".to_string()],
+            outputs: vec![PathBuf::from("generated/foo_pb2.py")],
+            ..BuildHook::default()
+        }];
+        assert_eq!(
+            hook_outputs(source_tree.path(), &src_root, &hooks)
+                .unwrap_err()
+                .to_string(),
+            "Invalid `tool.uv.build-backend.build-hooks[0]`: `outputs` must be inside the `module-root`"
+        );
+    }
+
+    #[test]
+    fn build_dir() {
+        let build_dir = TempDir::new().unwrap();
+        fs_err::create_dir_all(build_dir.path().join("foo")).unwrap();
+        fs_err::write(build_dir.path().join("foo/_constants.py"), "").unwrap();
+        fs_err::write(build_dir.path().join("foo/__init__.py"), "").unwrap();
+
+        let files: Vec<String> = build_dir_files(build_dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|(wheel_path, _file)| wheel_path)
+            .collect();
+        assert_eq!(files, ["foo/__init__.py", "foo/_constants.py"]);
+    }
+}
@@ -1,25 +1,35 @@
+mod editable;
+mod extension;
+mod hooks;
 mod metadata;
 mod serde_verbatim;
 mod settings;
 mod source_dist;
+mod vcs;
 mod wheel;
 
 pub use metadata::{PyProjectToml, check_direct_build};
-pub use settings::{BuildBackendSettings, WheelDataIncludes};
+pub use settings::{
+    BuildBackendSettings, BuildHook, DirtyVersion, EditableMode, ExtensionModule, VcsVersion,
+    VersionScheme, WheelDataInclude, WheelDataIncludes,
+};
 pub use source_dist::{build_source_dist, list_source_dist};
 pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
 
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
 use std::str::FromStr;
 use thiserror::Error;
 use tracing::debug;
-use walkdir::DirEntry;
+use walkdir::{DirEntry, WalkDir};
 
 use uv_fs::Simplified;
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
+use uv_pep440::VersionParseError;
 use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_static::EnvVars;
 
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
@@ -61,6 +71,12 @@ pub enum Error {
     Csv(#[from] csv::Error),
     #[error("Expected a Python module at: `{}`", _0.user_display())]
     MissingInitPy(PathBuf),
+    #[error(
+        "Expected a Python module at: `{}`, but found `{modules}`, set `tool.uv.build-backend.module-name` to build them",
+        _0.user_display(),
+        modules = _1.join("`, `")
+    )]
+    MissingInitPyFound(PathBuf, Vec<String>),
     #[error("For namespace packages, `__init__.py[i]` is not allowed in parent directory: `{}`", _0.user_display())]
     NotANamespace(PathBuf),
     /// Either an absolute path or a parent path through `..`.
@@ -70,6 +86,53 @@ pub enum Error {
     InconsistentSteps(&'static str),
     #[error("Failed to write to {}", _0.user_display())]
     TarWrite(PathBuf, #[source] io::Error),
+    #[error("Failed to query the Python interpreter for extension modules: `{}`", _0.user_display())]
+    PythonQuery(PathBuf, #[source] io::Error),
+    #[error("Failed to query the Python interpreter for extension modules: `{}`: {1}", _0.user_display())]
+    PythonQueryOutput(PathBuf, String),
+    #[error("Extension modules are only supported for CPython, not `{0}`")]
+    UnsupportedImplementation(String),
+    #[error("Invalid value for `_PYTHON_HOST_PLATFORM`: `{0}`")]
+    InvalidHostPlatform(String, #[source] uv_platform_tags::ParsePlatformTagError),
+    #[error("Invalid extension module `{0}`: {1}")]
+    InvalidExtensionModule(String, &'static str),
+    #[error("Failed to build extension module `{0}`")]
+    ExtensionCompile(String, #[source] io::Error),
+    #[error("Failed to build extension module `{0}`: `{1}` exited with {2}")]
+    ExtensionCompilerStatus(String, String, ExitStatus),
+    #[error(
+        "Cargo did not build a shared library for extension module `{0}`, is the crate type `cdylib`?"
+    )]
+    MissingCdylib(String),
+    #[error(
+        "Invalid value for `SOURCE_DATE_EPOCH`: `{0}` (expected a non-negative integer number of seconds)"
+    )]
+    InvalidSourceDateEpoch(String),
+    #[error("Invalid `tool.uv.build-backend.build-hooks[{0}]`: {1}")]
+    InvalidBuildHook(usize, &'static str),
+    #[error("Failed to run build hook `{0}`")]
+    BuildHookSpawn(String, #[source] io::Error),
+    #[error("Build hook `{0}` exited with {1}")]
+    BuildHookStatus(String, ExitStatus),
+    #[error("Build hook `{0}` did not generate its declared output `{}`", _1.user_display())]
+    MissingBuildHookOutput(String, PathBuf),
+    #[error("Failed to link the module for the editable install: `{}`", _0.user_display())]
+    EditableSymlink(PathBuf, #[source] io::Error),
+    #[error("Data subdirectory must be a relative path inside the data directory: `{0}`")]
+    InvalidDataSubdirectory(String),
+    #[error("No files match `{glob}` in: `{field}`")]
+    MissingDataFiles { field: String, glob: String },
+    #[error(
+        "Two files named `{file_name}` in `{field}`: `{}` and `{}`",
+        first.user_display(),
+        second.user_display()
+    )]
+    DuplicateDataFile {
+        field: String,
+        file_name: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
 }
 
 /// Dispatcher between writing to a directory, writing to a zip, writing to a `.tar.gz` and
@@ -141,6 +204,26 @@ impl DirectoryWriter for ListWriter<'_> {
     }
 }
 
+/// The modification time for the files in source distributions and wheels, in seconds since the
+/// Unix epoch.
+///
+/// Uses `SOURCE_DATE_EPOCH` if set, and a fixed timestamp otherwise, such that the archives are
+/// reproducible either way.
+///
+/// See: <https://reproducible-builds.org/specs/source-date-epoch/>
+fn source_date_epoch() -> Result<u64, Error> {
+    let Some(value) = std::env::var_os(EnvVars::SOURCE_DATE_EPOCH) else {
+        return Ok(0);
+    };
+    let value = value.to_string_lossy();
+    if value.is_empty() {
+        return Ok(0);
+    }
+    value
+        .parse::<u64>()
+        .map_err(|_| Error::InvalidSourceDateEpoch(value.to_string()))
+}
+
 /// PEP 517 requires that the metadata directory from the prepare metadata call is identical to the
 /// build wheel call. This method performs a prudence check that `METADATA` and `entry_points.txt`
 /// match.
@@ -264,31 +347,102 @@ fn find_roots(
 /// There are potential false positives if someone had a regular package with `-stubs`.
 /// The `Identifier` checks in `module_path_from_module_name` are here covered by the `PackageName`
 /// validation.
+///
+/// A module directory without an `__init__.py` or `__init__.pyi` that contains Python files is
+/// built as PEP 420 implicit namespace package, as with `namespace = true`.
 fn find_module_path_from_package_name(
     src_root: &Path,
     package_name: &PackageName,
 ) -> Result<PathBuf, Error> {
-    if let Some(stem) = package_name.to_string().strip_suffix("-stubs") {
-        debug!("Building stubs package instead of a regular package");
-        let module_name = PackageName::from_str(stem)
-            .expect("non-empty package name prefix must be valid package name")
-            .as_dist_info_name()
-            .to_string();
-        let module_relative = PathBuf::from(format!("{module_name}-stubs"));
-        let init_pyi = src_root.join(&module_relative).join("__init__.pyi");
-        if !init_pyi.is_file() {
-            return Err(Error::MissingInitPy(init_pyi));
-        }
-        Ok(module_relative)
+    let (module_relative, init_py) =
+        if let Some(stem) = package_name.to_string().strip_suffix("-stubs") {
+            debug!("Building stubs package instead of a regular package");
+            let module_name = PackageName::from_str(stem)
+                .expect("non-empty package name prefix must be valid package name")
+                .as_dist_info_name()
+                .to_string();
+            (
+                PathBuf::from(format!("{module_name}-stubs")),
+                "__init__.pyi",
+            )
+        } else {
+            // This name is always lowercase.
+            (
+                PathBuf::from(package_name.as_dist_info_name().to_string()),
+                "__init__.py",
+            )
+        };
+
+    let init_py = src_root.join(&module_relative).join(init_py);
+    if init_py.is_file() {
+        return Ok(module_relative);
+    }
+
+    let module_dir = src_root.join(&module_relative);
+    if !module_dir.join("__init__.py").exists()
+        && !module_dir.join("__init__.pyi").exists()
+        && contains_python_files(&module_dir)?
+    {
+        debug!(
+            "Building implicit namespace package: `{}`",
+            module_dir.user_display()
+        );
+        return Ok(module_relative);
+    }
+
+    // Point the user to the modules in the module root that don't match the package name.
+    let modules: Vec<String> = top_level_modules(src_root)?
+        .into_iter()
+        .filter(|module| Path::new(module) != module_relative)
+        .collect();
+    if modules.is_empty() {
+        Err(Error::MissingInitPy(init_py))
     } else {
-        // This name is always lowercase.
-        let module_relative = PathBuf::from(package_name.as_dist_info_name().to_string());
-        let init_py = src_root.join(&module_relative).join("__init__.py");
-        if !init_py.is_file() {
-            return Err(Error::MissingInitPy(init_py));
+        Err(Error::MissingInitPyFound(init_py, modules))
+    }
+}
+
+/// Whether the directory contains any `.py` or `.pyi` files, at any depth.
+fn contains_python_files(dir: &Path) -> Result<bool, Error> {
+    if !dir.is_dir() {
+        return Ok(false);
+    }
+    for entry in WalkDir::new(dir) {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: dir.to_path_buf(),
+            err,
+        })?;
+        if entry.file_type().is_file()
+            && entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "py" || ext == "pyi")
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// The names of the regular and stubs packages directly in the module root.
+fn top_level_modules(src_root: &Path) -> Result<Vec<String>, Error> {
+    let entries = match fs_err::read_dir(src_root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut modules = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if !(path.join("__init__.py").is_file() || path.join("__init__.pyi").is_file()) {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            modules.push(name.to_string());
         }
-        Ok(module_relative)
     }
+    modules.sort();
+    Ok(modules)
 }
 
 /// Determine the relative module path from an explicit module name.
@@ -1198,6 +1352,77 @@ mod tests {
         assert_eq!(build1, build2);
     }
 
+    /// A namespace package without an `__init__.py` is detected without `namespace = true`.
+    #[test]
+    fn implicit_namespace_package() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "implicit-namespace"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        let namespace = src.path().join("src").join("implicit_namespace");
+        fs_err::create_dir_all(namespace.join("part_a")).unwrap();
+        File::create(namespace.join("part_a").join("__init__.py")).unwrap();
+        fs_err::create_dir_all(namespace.join("part_b")).unwrap();
+        File::create(namespace.join("part_b").join("module.py")).unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build.wheel_contents.join("\n"), @r"
+        implicit_namespace-1.0.0.dist-info/
+        implicit_namespace-1.0.0.dist-info/METADATA
+        implicit_namespace-1.0.0.dist-info/RECORD
+        implicit_namespace-1.0.0.dist-info/WHEEL
+        implicit_namespace/
+        implicit_namespace/part_a/
+        implicit_namespace/part_a/__init__.py
+        implicit_namespace/part_b/
+        implicit_namespace/part_b/module.py
+        ");
+    }
+
+    /// If the module doesn't match the package name, show the modules in the module root.
+    #[test]
+    fn missing_module_found_modules() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "multiple-modules"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        for module in ["foo", "bar-stubs"] {
+            fs_err::create_dir_all(src.path().join("src").join(module)).unwrap();
+        }
+        File::create(src.path().join("src").join("foo").join("__init__.py")).unwrap();
+        File::create(
+            src.path()
+                .join("src")
+                .join("bar-stubs")
+                .join("__init__.pyi"),
+        )
+        .unwrap();
+        // Not a module.
+        fs_err::create_dir_all(src.path().join("src").join("data")).unwrap();
+
+        assert_snapshot!(
+            build_err(src.path()),
+            @"Expected a Python module at: `[TEMP_PATH]/src/multiple_modules/__init__.py`, but found `bar-stubs`, `foo`, set `tool.uv.build-backend.module-name` to build them"
+        );
+    }
+
     /// Stubs for a namespace package.
     #[test]
     fn stubs_namespace() {
@@ -1360,4 +1585,99 @@ mod tests {
         simple_namespace_part-1.0.0.dist-info/WHEEL
         ");
     }
+
+    /// Map individual data files to subdirectories of the data directories, such as man pages and
+    /// shell completions.
+    #[test]
+    fn data_files() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "1.0.0"
+
+            [tool.uv.build-backend.data]
+            scripts = { "" = ["tools/foo-helper"] }
+            data = { "share/man/man1" = ["docs/*.1"], "share/bash-completion/completions" = ["completions/foo"] }
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("foo")).unwrap();
+        File::create(src.path().join("src").join("foo").join("__init__.py")).unwrap();
+        fs_err::create_dir_all(src.path().join("tools")).unwrap();
+        fs_err::write(src.path().join("tools").join("foo-helper"), "#!/bin/sh\n").unwrap();
+        fs_err::create_dir_all(src.path().join("docs")).unwrap();
+        fs_err::write(src.path().join("docs").join("foo.1"), ".TH FOO 1\n").unwrap();
+        fs_err::write(
+            src.path().join("docs").join("foo-helper.1"),
+            ".TH FOO-HELPER 1\n",
+        )
+        .unwrap();
+        fs_err::write(src.path().join("docs").join("index.md"), "# Foo\n").unwrap();
+
+        // The completions don't exist yet.
+        assert_snapshot!(
+            build_err(src.path()),
+            @"No files match `completions/foo` in: `tool.uv.build-backend.data.data.share/bash-completion/completions`"
+        );
+
+        fs_err::create_dir_all(src.path().join("completions")).unwrap();
+        fs_err::write(
+            src.path().join("completions").join("foo"),
+            "complete -F _foo foo\n",
+        )
+        .unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build.source_dist_contents.join("\n"), @r"
+        foo-1.0.0/
+        foo-1.0.0/PKG-INFO
+        foo-1.0.0/completions
+        foo-1.0.0/completions/foo
+        foo-1.0.0/docs
+        foo-1.0.0/docs/foo-helper.1
+        foo-1.0.0/docs/foo.1
+        foo-1.0.0/pyproject.toml
+        foo-1.0.0/src
+        foo-1.0.0/src/foo
+        foo-1.0.0/src/foo/__init__.py
+        foo-1.0.0/tools
+        foo-1.0.0/tools/foo-helper
+        ");
+        assert_snapshot!(build.wheel_contents.join("\n"), @r"
+        foo-1.0.0.data/data/
+        foo-1.0.0.data/data/share/
+        foo-1.0.0.data/data/share/bash-completion/
+        foo-1.0.0.data/data/share/bash-completion/completions/
+        foo-1.0.0.data/data/share/bash-completion/completions/foo
+        foo-1.0.0.data/data/share/man/
+        foo-1.0.0.data/data/share/man/man1/
+        foo-1.0.0.data/data/share/man/man1/foo-helper.1
+        foo-1.0.0.data/data/share/man/man1/foo.1
+        foo-1.0.0.data/scripts/
+        foo-1.0.0.data/scripts/foo-helper
+        foo-1.0.0.dist-info/
+        foo-1.0.0.dist-info/METADATA
+        foo-1.0.0.dist-info/RECORD
+        foo-1.0.0.dist-info/WHEEL
+        foo/
+        foo/__init__.py
+        ");
+
+        // Data files must stay inside the data directory.
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            pyproject_toml.replace("share/man/man1", "../man1"),
+        )
+        .unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"Data subdirectory must be a relative path inside the data directory: `../man1`"
+        );
+    }
 }
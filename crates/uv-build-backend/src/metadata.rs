@@ -21,6 +21,7 @@ use uv_pep508::{
 use uv_pypi_types::{Metadata23, VerbatimParsedUrl};
 
 use crate::serde_verbatim::SerdeVerbatim;
+use crate::vcs;
 use crate::{BuildBackendSettings, Error};
 
 /// By default, we ignore generated python files.
@@ -44,8 +45,12 @@ pub enum ValidationError {
     UnsupportedContentType(String),
     #[error("`project.description` must be a single line")]
     DescriptionNewlines,
-    #[error("Dynamic metadata is not supported")]
+    #[error("Dynamic metadata is not supported, except for `version`")]
     Dynamic,
+    #[error("`project.version` is required, unless `version` is listed in `project.dynamic`")]
+    MissingVersion,
+    #[error("`project.version` must not be set when `version` is listed in `project.dynamic`")]
+    StaticAndDynamicVersion,
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -82,6 +87,23 @@ pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
                 return false;
             }
         };
+    // Extension modules are built for the interpreter running the build backend, which requires
+    // a build environment.
+    if pyproject_toml
+        .settings()
+        .is_some_and(|settings| !settings.ext_modules.is_empty())
+    {
+        debug!("Not using uv build backend direct build of {name}, it has extension modules");
+        return false;
+    }
+    // Build hooks may depend on the build requirements, e.g., `grpcio-tools`.
+    if pyproject_toml
+        .settings()
+        .is_some_and(|settings| !settings.build_hooks.is_empty())
+    {
+        debug!("Not using uv build backend direct build of {name}, it has build hooks");
+        return false;
+    }
     match pyproject_toml
         .check_build_system(uv_version::version())
         .as_slice()
@@ -123,11 +145,51 @@ impl PyProjectToml {
     }
 
     pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+        self.project
+            .version
+            .as_ref()
+            .expect("a dynamic version is resolved when loading")
     }
 
     pub(crate) fn parse(contents: &str) -> Result<Self, Error> {
-        Ok(toml::from_str(contents)?)
+        let pyproject_toml: Self = toml::from_str(contents)?;
+        match (
+            &pyproject_toml.project.version,
+            pyproject_toml.has_dynamic_version(),
+        ) {
+            (Some(_), true) => Err(ValidationError::StaticAndDynamicVersion.into()),
+            (None, false) => Err(ValidationError::MissingVersion.into()),
+            _ => Ok(pyproject_toml),
+        }
+    }
+
+    /// Read the `pyproject.toml` in the source tree, deriving the version from git if it is
+    /// dynamic.
+    pub(crate) fn load(source_tree: &Path) -> Result<Self, Error> {
+        let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
+        let mut pyproject_toml = Self::parse(&contents)?;
+        if pyproject_toml.project.version.is_none() {
+            let settings = pyproject_toml
+                .settings()
+                .map(|settings| settings.vcs_version.clone())
+                .unwrap_or_default();
+            let version = vcs::resolve_version(source_tree, &settings)?;
+            debug!("Using dynamic version: {version}");
+            if let Some(version_file) = &settings.version_file {
+                vcs::write_version_file(source_tree, version_file, &version)?;
+            }
+            pyproject_toml.project.version = Some(version);
+        }
+        Ok(pyproject_toml)
+    }
+
+    /// Whether `version` is listed in `project.dynamic`.
+    fn has_dynamic_version(&self) -> bool {
+        self.project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "version")
     }
 
     pub(crate) fn readme(&self) -> Option<&Readme> {
@@ -309,11 +371,13 @@ impl PyProjectToml {
             None => (None, None),
         };
 
+        // A dynamic version is resolved when loading.
         if self
             .project
             .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
+            .iter()
+            .flatten()
+            .any(|field| field != "version")
         {
             return Err(ValidationError::Dynamic.into());
         }
@@ -525,7 +589,7 @@ impl PyProjectToml {
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.to_string(),
-            version: self.project.version.to_string(),
+            version: self.version().to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -647,7 +711,9 @@ struct Project {
     /// The name of the project.
     name: PackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// If `version` is listed in `dynamic`, the version is derived from git.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -696,7 +762,7 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     ///
-    /// Not supported, an error if anything but the default empty list.
+    /// Only `version` is supported, which is derived from git, anything else is an error.
     dynamic: Option<Vec<String>>,
 }
 
@@ -1381,8 +1447,25 @@ mod tests {
             .unwrap_err();
         assert_snapshot!(format_err(err), @r###"
         Invalid pyproject.toml
-          Caused by: Dynamic metadata is not supported
+          Caused by: Dynamic metadata is not supported, except for `version`
+        "###);
+    }
+
+    #[test]
+    fn dynamic_version() {
+        let contents = extend_project(indoc! {r#"
+            dynamic = ["version"]
+        "#
+        });
+        let err = PyProjectToml::parse(&contents).unwrap_err();
+        assert_snapshot!(format_err(err), @r###"
+        Invalid pyproject.toml
+          Caused by: `project.version` must not be set when `version` is listed in `project.dynamic`
         "###);
+
+        let contents = contents.replace("version = \"0.1.0\"\n", "");
+        let pyproject_toml = PyProjectToml::parse(&contents).unwrap();
+        assert!(pyproject_toml.has_dynamic_version());
     }
 
     fn script_error(contents: &str) -> String {
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use uv_macros::OptionsMetadata;
 
@@ -31,6 +32,9 @@ pub struct BuildBackendSettings {
     /// `__init__.py`. An exception are stubs packages, whose name ends with `-stubs`, with the stem
     /// being the module name, and which contain a `__init__.pyi` file.
     ///
+    /// Without a `module-name`, a module directory with Python files but no `__init__.py[i]` is
+    /// built as an implicit namespace package.
+    ///
     /// For namespace packages with a single module, the path can be dotted, e.g., `foo.bar` or
     /// `foo-stubs.bar`.
     ///
@@ -137,9 +141,12 @@ pub struct BuildBackendSettings {
 
     /// Data includes for wheels.
     ///
-    /// Each entry is a directory, whose contents are copied to the matching directory in the wheel
-    /// in `<name>-<version>.data/(purelib|platlib|headers|scripts|data)`. Upon installation, this
-    /// data is moved to its target location, as defined by
+    /// Each entry is either a directory, whose contents are copied to the matching directory in the
+    /// wheel in `<name>-<version>.data/(purelib|platlib|headers|scripts|data)`, or a table mapping
+    /// a subdirectory of the data directory to glob expressions for files relative to the project
+    /// root, e.g., `data = { "share/man/man1" = ["docs/foo.1"] }`. The matched files are copied
+    /// into the subdirectory by their file name, and each glob needs to match at least one file.
+    /// Upon installation, this data is moved to its target location, as defined by
     /// <https://docs.python.org/3.12/library/sysconfig.html#installation-paths>. Usually, small
     /// data files are included by placing them in the Python module instead of using data includes.
     ///
@@ -164,10 +171,81 @@ pub struct BuildBackendSettings {
     // ```
     #[option(
         default = r#"{}"#,
-        value_type = "dict[str, str]",
-        example = r#"data = { "headers": "include/headers", "scripts": "bin" }"#
+        value_type = "dict[str, str | dict[str, list[str]]]",
+        example = r#"data = { scripts = "bin", data = { "share/man/man1" = ["docs/*.1"] } }"#
     )]
     pub data: WheelDataIncludes,
+
+    /// Extension modules to compile into the wheel.
+    ///
+    /// Each entry is either a C or C++ extension, compiled from the files matching `sources` with
+    /// the system compiler (`CC` or `cc`, respectively `CXX` or `c++`), or a Rust extension,
+    /// compiled from the crate at `cargo-manifest` with `cargo`. The compiled module is placed at
+    /// the dotted module path `name` in the wheel, e.g., `foo/_speedups.cpython-312-x86_64-linux-gnu.so`
+    /// for `foo._speedups`.
+    ///
+    /// Wheels with extension modules are tagged for the interpreter and platform they were built
+    /// with, instead of `py3-none-any`. For editable installs, the extension modules are compiled
+    /// in place, next to their Python sources.
+    ///
+    /// The sources of the extension modules are included in the source distribution.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[dict]",
+        example = r#"ext-modules = [{ name = "foo._speedups", sources = ["src/foo/_speedups.c"] }]"#
+    )]
+    pub ext_modules: Vec<ExtensionModule>,
+
+    /// Commands or Python functions to run before building a wheel, e.g., to generate protobuf
+    /// stubs.
+    ///
+    /// Each hook is either a `command`, run in the project root, or a Python `entry-point` of the
+    /// form `module:function`, imported from the project root and called with the build
+    /// directory. The hooks run in order before each wheel build, including editable builds, but
+    /// not for source distributions, which need to include the inputs of the hooks instead.
+    ///
+    /// Files written to the build directory, which commands receive as
+    /// `UV_BUILD_HOOK_OUTPUT_DIR`, are added to the wheel at the same relative path. Files
+    /// generated in the source tree must be declared in `outputs` and are added to the wheel
+    /// relative to the `module-root`, even if they are excluded.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[dict]",
+        example = r#"build-hooks = [{ command = ["protoc", "--python_out=src/foo", "foo.proto"], outputs = ["src/foo/foo_pb2.py"] }]"#
+    )]
+    pub build_hooks: Vec<BuildHook>,
+
+    /// How editable installs make the module importable.
+    ///
+    /// - `compat`: A `.pth` file adds the `module-root` to `sys.path`. This is supported by static
+    ///   analyzers and type checkers, but also exposes other files in the `module-root`.
+    /// - `strict`: A `.pth` file installs an import hook that exposes only the modules of the
+    ///   package. Static analyzers and type checkers generally don't support import hooks.
+    /// - `symlink`: The modules of the package are symlinked into
+    ///   `build/__editable__.<name>` in the project root, which a `.pth` file adds to `sys.path`.
+    ///   This exposes only the modules of the package while remaining visible to static analyzers.
+    ///   New top-level modules require reinstalling the package.
+    #[option(
+        default = r#""compat""#,
+        value_type = r#""compat" | "strict" | "symlink""#,
+        example = r#"editable-mode = "strict""#
+    )]
+    pub editable_mode: EditableMode,
+
+    /// How to derive the version from git when `version` is listed in `project.dynamic`.
+    ///
+    /// The version is determined from the closest tag matching `tag-pattern`. If the current
+    /// commit is tagged, the version of the tag is used, otherwise, the version is derived from
+    /// the version of the tag and the number of commits since the tag according to `scheme`.
+    ///
+    /// When building from a source distribution, the version is read from its `PKG-INFO`
+    /// instead.
+    #[option(
+        default = r#"{}"#,
+        value_type = "dict",
+        example = r#"vcs-version = { tag-pattern = "^release-(?P<version>.+)$", version-file = "src/foo/_version.py" }"#
+    )]
+    pub vcs_version: VcsVersion,
 }
 
 impl Default for BuildBackendSettings {
@@ -181,6 +259,10 @@ impl Default for BuildBackendSettings {
             wheel_exclude: Vec::new(),
             namespace: false,
             data: WheelDataIncludes::default(),
+            ext_modules: Vec::new(),
+            build_hooks: Vec::new(),
+            editable_mode: EditableMode::default(),
+            vcs_version: VcsVersion::default(),
         }
     }
 }
@@ -196,6 +278,136 @@ pub enum ModuleName {
     Names(Vec<String>),
 }
 
+/// An extension module compiled into the wheel.
+///
+/// See `BuildBackendSettings::ext_modules`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExtensionModule {
+    /// The dotted module path of the extension module, e.g., `foo._speedups`.
+    pub name: String,
+    /// Glob expressions for the C or C++ source files, relative to the project root.
+    ///
+    /// If any source file has a `.cc`, `.cpp` or `.cxx` extension, the module is compiled as C++.
+    pub sources: Vec<String>,
+    /// The path to the `Cargo.toml` of a Rust crate with a `cdylib` target, e.g., using PyO3.
+    ///
+    /// Mutually exclusive with `sources`.
+    pub cargo_manifest: Option<PathBuf>,
+    /// Additional directories to search for header files.
+    pub include_dirs: Vec<PathBuf>,
+    /// Preprocessor macros to define, either as `NAME` or `NAME=VALUE`.
+    pub define_macros: Vec<String>,
+    /// Additional directories to search for libraries.
+    pub library_dirs: Vec<PathBuf>,
+    /// Libraries to link against.
+    pub libraries: Vec<String>,
+    /// Additional arguments passed to the compiler, or to `rustc` for Rust extensions.
+    pub extra_compile_args: Vec<String>,
+    /// Additional arguments passed to the linker.
+    pub extra_link_args: Vec<String>,
+}
+
+/// A command or Python function that runs before building a wheel.
+///
+/// See `BuildBackendSettings::build_hooks`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BuildHook {
+    /// The program to run, followed by its arguments.
+    ///
+    /// Mutually exclusive with `entry-point`.
+    pub command: Vec<String>,
+    /// The Python function to call with the build directory, as `module:function`, e.g.,
+    /// `tools.codegen:generate`.
+    pub entry_point: Option<String>,
+    /// The files the hook generates in the source tree, relative to the project root.
+    pub outputs: Vec<PathBuf>,
+}
+
+/// How editable installs make the module importable.
+///
+/// See `BuildBackendSettings::editable_mode`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum EditableMode {
+    /// Add the module root to `sys.path` with a `.pth` file.
+    #[default]
+    Compat,
+    /// Expose only the modules of the package with an import hook.
+    Strict,
+    /// Add a directory with symlinks to the modules of the package to `sys.path`.
+    Symlink,
+}
+
+/// Settings for deriving the version from git.
+///
+/// See `BuildBackendSettings::vcs_version`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VcsVersion {
+    /// A regular expression matching the tags to derive the version from, with a `version` group
+    /// capturing the version, e.g., `^v(?P<version>.+)$`.
+    ///
+    /// Defaults to tags with an optional `v` prefix.
+    pub tag_pattern: String,
+    /// How to derive the version for commits after the tag.
+    pub scheme: VersionScheme,
+    /// How to handle uncommitted changes to tracked files.
+    pub dirty: DirtyVersion,
+    /// The version to use if there is no matching tag, or the source tree is not a git
+    /// repository.
+    pub fallback_version: Option<String>,
+    /// A Python file to write the version to, relative to the project root, e.g.,
+    /// `src/foo/_version.py`.
+    ///
+    /// The file defines `__version__` and `version`.
+    pub version_file: Option<PathBuf>,
+}
+
+impl Default for VcsVersion {
+    fn default() -> Self {
+        Self {
+            tag_pattern: r"^v?(?P<version>\d.*)$".to_string(),
+            scheme: VersionScheme::default(),
+            dirty: DirtyVersion::default(),
+            fallback_version: None,
+            version_file: None,
+        }
+    }
+}
+
+/// How to derive the version for commits after the tag.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VersionScheme {
+    /// A development release of the next version, e.g., `1.2.4.dev3` for three commits after
+    /// `1.2.3`, or `1.0rc2.dev3` after `1.0rc1`.
+    #[default]
+    Dev,
+    /// A post release of the tagged version, e.g., `1.2.3.post3` for three commits after `1.2.3`.
+    Post,
+}
+
+/// How to handle uncommitted changes to tracked files.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DirtyVersion {
+    /// Add a `dirty` local version segment, e.g., `1.2.3+dirty`.
+    #[default]
+    Local,
+    /// Ignore uncommitted changes.
+    Ignore,
+    /// Fail the build.
+    Error,
+}
+
 /// Data includes for wheels.
 ///
 /// See `BuildBackendSettings::data`.
@@ -204,24 +416,38 @@ pub enum ModuleName {
 #[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WheelDataIncludes {
-    purelib: Option<String>,
-    platlib: Option<String>,
-    headers: Option<String>,
-    scripts: Option<String>,
-    data: Option<String>,
+    purelib: Option<WheelDataInclude>,
+    platlib: Option<WheelDataInclude>,
+    headers: Option<WheelDataInclude>,
+    scripts: Option<WheelDataInclude>,
+    data: Option<WheelDataInclude>,
 }
 
 impl WheelDataIncludes {
-    /// Yield all data directories name and corresponding paths.
-    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+    /// Yield all data directories name and corresponding includes.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &WheelDataInclude)> {
         [
-            ("purelib", self.purelib.as_deref()),
-            ("platlib", self.platlib.as_deref()),
-            ("headers", self.headers.as_deref()),
-            ("scripts", self.scripts.as_deref()),
-            ("data", self.data.as_deref()),
+            ("purelib", self.purelib.as_ref()),
+            ("platlib", self.platlib.as_ref()),
+            ("headers", self.headers.as_ref()),
+            ("scripts", self.scripts.as_ref()),
+            ("data", self.data.as_ref()),
         ]
         .into_iter()
         .filter_map(|(name, value)| Some((name, value?)))
     }
 }
+
+/// The files to include in a data directory of the wheel.
+///
+/// See `BuildBackendSettings::data`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum WheelDataInclude {
+    /// A directory, whose contents are copied to the data directory.
+    Directory(String),
+    /// Subdirectories of the data directory, each with glob expressions for the files to copy
+    /// into it, relative to the project root.
+    Files(BTreeMap<String, Vec<String>>),
+}
@@ -1,9 +1,12 @@
+use crate::extension::source_dist_includes;
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::wheel::build_exclude_matcher;
 use crate::{
-    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, find_roots,
+    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
+    WheelDataInclude, find_roots, source_date_epoch,
 };
 use flate2::Compression;
+use flate2::GzBuilder;
 use flate2::write::GzEncoder;
 use fs_err::File;
 use globset::{Glob, GlobSet};
@@ -24,15 +27,15 @@ pub fn build_source_dist(
     source_dist_directory: &Path,
     uv_version: &str,
 ) -> Result<SourceDistFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
         extension: SourceDistExtension::TarGz,
     };
+    let source_date_epoch = source_date_epoch()?;
     let source_dist_path = source_dist_directory.join(filename.to_string());
-    let writer = TarGzWriter::new(&source_dist_path)?;
+    let writer = TarGzWriter::new(&source_dist_path, source_date_epoch)?;
     write_source_dist(source_tree, writer, uv_version)?;
     Ok(filename)
 }
@@ -42,8 +45,7 @@ pub fn list_source_dist(
     source_tree: &Path,
     uv_version: &str,
 ) -> Result<(SourceDistFilename, FileList), Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
@@ -122,18 +124,48 @@ fn source_dist_matcher(
     }
 
     // Include the data files
-    for (name, directory) in settings.data.iter() {
-        let directory = uv_fs::normalize_path(Path::new(directory));
-        trace!(
-            "Including data ({}) at: `{}`",
-            name,
-            directory.user_display()
-        );
-        let directory = directory.portable_display().to_string();
+    for (name, include) in settings.data.iter() {
+        match include {
+            WheelDataInclude::Directory(directory) => {
+                let directory = uv_fs::normalize_path(Path::new(directory));
+                trace!(
+                    "Including data ({}) at: `{}`",
+                    name,
+                    directory.user_display()
+                );
+                let directory = directory.portable_display().to_string();
+                let glob = PortableGlobParser::Uv
+                    .parse(&format!("{}/**", globset::escape(&directory)))
+                    .map_err(|err| Error::PortableGlob {
+                        field: format!("tool.uv.build-backend.data.{name}"),
+                        source: err,
+                    })?;
+                include_globs.push(glob);
+            }
+            WheelDataInclude::Files(subdirectories) => {
+                for (subdirectory, globs) in subdirectories {
+                    for glob in globs {
+                        trace!("Including data ({name}) files at: `{glob}`");
+                        let glob = PortableGlobParser::Pep639.parse(glob).map_err(|err| {
+                            Error::PortableGlob {
+                                field: format!("tool.uv.build-backend.data.{name}.{subdirectory}"),
+                                source: err,
+                            }
+                        })?;
+                        include_globs.push(glob);
+                    }
+                }
+            }
+        }
+    }
+
+    // Include the extension module sources
+    for include in source_dist_includes(&settings.ext_modules) {
+        trace!("Including extension module sources at: `{include}`");
         let glob = PortableGlobParser::Uv
-            .parse(&format!("{}/**", globset::escape(&directory)))
+            .parse(&include)
             .map_err(|err| Error::PortableGlob {
-                field: format!("tool.uv.build-backend.data.{name}"),
+                field: "tool.uv.build-backend.ext-modules".to_string(),
                 source: err,
             })?;
         include_globs.push(glob);
@@ -176,8 +208,7 @@ fn write_source_dist(
     mut writer: impl DirectoryWriter,
     uv_version: &str,
 ) -> Result<SourceDistFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
@@ -273,21 +304,27 @@ fn write_source_dist(
 struct TarGzWriter {
     path: PathBuf,
     tar: tar::Builder<GzEncoder<File>>,
+    /// The modification time of all entries, in seconds since the Unix epoch.
+    mtime: u64,
 }
 
 impl TarGzWriter {
-    fn new(path: impl Into<PathBuf>) -> Result<Self, Error> {
+    fn new(path: impl Into<PathBuf>, mtime: u64) -> Result<Self, Error> {
         let path = path.into();
         let file = File::create(&path)?;
-        let enc = GzEncoder::new(file, Compression::default());
+        // The gzip header can't represent times after 2106, where `0` means no timestamp.
+        let enc = GzBuilder::new()
+            .mtime(u32::try_from(mtime).unwrap_or(0))
+            .write(file, Compression::default());
         let tar = tar::Builder::new(enc);
-        Ok(Self { path, tar })
+        Ok(Self { path, tar, mtime })
     }
 }
 
 impl DirectoryWriter for TarGzWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         let mut header = Header::new_gnu();
+        header.set_mtime(self.mtime);
         header.set_size(bytes.len() as u64);
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
@@ -301,6 +338,7 @@ impl DirectoryWriter for TarGzWriter {
     fn write_file(&mut self, path: &str, file: &Path) -> Result<(), Error> {
         let metadata = fs_err::metadata(file)?;
         let mut header = Header::new_gnu();
+        header.set_mtime(self.mtime);
         // Preserve the executable bit, especially for scripts
         #[cfg(unix)]
         let executable_bit = {
@@ -329,6 +367,7 @@ impl DirectoryWriter for TarGzWriter {
 
     fn write_directory(&mut self, directory: &str) -> Result<(), Error> {
         let mut header = Header::new_gnu();
+        header.set_mtime(self.mtime);
         // Directories are always executable, which means they can be listed.
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
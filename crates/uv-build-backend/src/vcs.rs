@@ -0,0 +1,301 @@
+//! Derive the version from git tags for projects with `dynamic = ["version"]`.
+
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use regex::Regex;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_pep440::{LocalSegment, Prerelease, Version};
+
+use crate::Error;
+use crate::settings::{DirtyVersion, VcsVersion, VersionScheme};
+
+/// The result of `git describe`: The closest matching tag and how far the working tree is from
+/// it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Describe {
+    /// The tag name, e.g., `v1.2.3`.
+    tag: String,
+    /// The number of commits since the tag.
+    distance: u64,
+    /// Whether there are uncommitted changes to tracked files.
+    dirty: bool,
+}
+
+impl Describe {
+    /// Parse the output of `git describe --tags --long --dirty`, e.g., `v1.2.3-4-gabcdef0-dirty`.
+    fn parse(output: &str) -> Option<Self> {
+        let output = output.trim();
+        let (output, dirty) = match output.strip_suffix("-dirty") {
+            Some(output) => (output, true),
+            None => (output, false),
+        };
+        let (output, _hash) = output.rsplit_once('-')?;
+        let (tag, distance) = output.rsplit_once('-')?;
+        Some(Self {
+            tag: tag.to_string(),
+            distance: distance.parse().ok()?,
+            dirty,
+        })
+    }
+}
+
+/// Determine the version of the project in the source tree.
+///
+/// Source distributions don't contain the git repository, so their version is read from
+/// `PKG-INFO`.
+pub(crate) fn resolve_version(source_tree: &Path, settings: &VcsVersion) -> Result<Version, Error> {
+    let pkg_info = source_tree.join("PKG-INFO");
+    if pkg_info.is_file() {
+        let contents = fs_err::read_to_string(&pkg_info)?;
+        // The version is in the headers, before the first empty line.
+        let version = contents
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .find_map(|line| line.strip_prefix("Version:"))
+            .ok_or_else(|| Error::VcsVersion("`PKG-INFO` does not contain a version".to_string()))?
+            .trim();
+        debug!("Using version from `PKG-INFO`: {version}");
+        return parse_version(version);
+    }
+
+    let tag_pattern = Regex::new(&settings.tag_pattern).map_err(Error::TagPattern)?;
+    match describe(source_tree, &tag_pattern) {
+        Ok((describe, tag_version)) => {
+            debug!(
+                "Found tag `{}` with {} commits since{}",
+                describe.tag,
+                describe.distance,
+                if describe.dirty {
+                    " and uncommitted changes"
+                } else {
+                    ""
+                }
+            );
+            derive_version(&tag_version, &describe, settings)
+        }
+        Err(err) => {
+            if let Some(fallback_version) = &settings.fallback_version {
+                debug!("Using fallback version {fallback_version}: {err}");
+                parse_version(fallback_version)
+            } else {
+                Err(Error::VcsVersion(err))
+            }
+        }
+    }
+}
+
+/// Write the version to a Python file, if it changed.
+pub(crate) fn write_version_file(
+    source_tree: &Path,
+    version_file: &Path,
+    version: &Version,
+) -> Result<(), Error> {
+    let path = source_tree.join(version_file);
+    let contents = format!(
+        "# This file is generated by the uv build backend, do not edit or check it in.\n\
+        __version__ = version = \"{version}\"\n"
+    );
+    if fs_err::read_to_string(&path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    debug!("Writing version to: `{}`", path.user_display());
+    fs_err::write(&path, contents)?;
+    Ok(())
+}
+
+fn parse_version(version: &str) -> Result<Version, Error> {
+    Version::from_str(version).map_err(|err| Error::InvalidVersion(version.to_string(), err))
+}
+
+/// Find the closest tag matching the pattern with `git describe`.
+fn describe(source_tree: &Path, tag_pattern: &Regex) -> Result<(Describe, Version), String> {
+    // `git describe --match` takes globs, so we match the tags ourselves and pass the matching
+    // tags literally.
+    let tags = git(source_tree, &["tag", "--merged", "HEAD"])?;
+    let matching: Vec<&str> = tags
+        .lines()
+        .filter(|tag| tag_version(tag_pattern, tag).is_some())
+        .collect();
+    if matching.is_empty() {
+        return Err(format!("No tags matching `{tag_pattern}` found"));
+    }
+
+    let mut args = vec![
+        "describe".to_string(),
+        "--tags".to_string(),
+        "--long".to_string(),
+        "--dirty".to_string(),
+    ];
+    for tag in matching {
+        args.push("--match".to_string());
+        args.push(escape_glob(tag));
+    }
+    let output = git(source_tree, &args)?;
+    let describe = Describe::parse(&output)
+        .ok_or_else(|| format!("Unexpected `git describe` output: `{}`", output.trim()))?;
+    let version = tag_version(tag_pattern, &describe.tag)
+        .ok_or_else(|| format!("Tag `{}` does not match `{tag_pattern}`", describe.tag))?;
+    Ok((describe, version))
+}
+
+/// The version captured by the `version` group of the pattern, if the tag matches.
+fn tag_version(tag_pattern: &Regex, tag: &str) -> Option<Version> {
+    let captures = tag_pattern.captures(tag)?;
+    Version::from_str(captures.name("version")?.as_str()).ok()
+}
+
+/// Escape the wildcards of a tag name for `git describe --match`.
+fn escape_glob(tag: &str) -> String {
+    let mut escaped = String::with_capacity(tag.len());
+    for character in tag.chars() {
+        if matches!(character, '*' | '?' | '[' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+/// Run git in the source tree, returning stdout.
+fn git(source_tree: &Path, args: &[impl AsRef<str>]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(source_tree)
+        .args(args.iter().map(AsRef::as_ref))
+        .output()
+        .map_err(|err| format!("Failed to run `git`: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`git` exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|err| format!("Invalid `git` output: {err}"))
+}
+
+/// Derive the version from the version of the closest tag and the state of the working tree.
+fn derive_version(
+    tag_version: &Version,
+    describe: &Describe,
+    settings: &VcsVersion,
+) -> Result<Version, Error> {
+    let mut version = if describe.distance == 0 {
+        tag_version.clone()
+    } else {
+        match settings.scheme {
+            VersionScheme::Dev => {
+                let next = if let Some(pre) = tag_version.pre() {
+                    // `1.0rc1` -> `1.0rc2.dev3`
+                    Version::new(tag_version.release().iter().copied()).with_pre(Some(Prerelease {
+                        kind: pre.kind,
+                        number: pre.number + 1,
+                    }))
+                } else {
+                    // `1.2.3` -> `1.2.4.dev3`
+                    let mut release = tag_version.release().to_vec();
+                    if let Some(last) = release.last_mut() {
+                        *last += 1;
+                    }
+                    Version::new(release)
+                };
+                next.with_epoch(tag_version.epoch())
+                    .with_dev(Some(describe.distance))
+            }
+            // `1.2.3` -> `1.2.3.post3`
+            VersionScheme::Post => tag_version
+                .clone()
+                .without_local()
+                .with_dev(None)
+                .with_post(Some(tag_version.post().unwrap_or(0) + describe.distance)),
+        }
+    };
+
+    if describe.dirty {
+        match settings.dirty {
+            DirtyVersion::Local => {
+                version =
+                    version.with_local_segments(vec![LocalSegment::String("dirty".to_string())]);
+            }
+            DirtyVersion::Ignore => {}
+            DirtyVersion::Error => {
+                return Err(Error::VcsVersion(
+                    "The working tree has uncommitted changes".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_describe() {
+        assert_eq!(
+            Describe::parse("v1.2.3-4-gabcdef0-dirty\n"),
+            Some(Describe {
+                tag: "v1.2.3".to_string(),
+                distance: 4,
+                dirty: true,
+            })
+        );
+        assert_eq!(
+            Describe::parse("release-1.0-0-gabcdef0"),
+            Some(Describe {
+                tag: "release-1.0".to_string(),
+                distance: 0,
+                dirty: false,
+            })
+        );
+        assert_eq!(Describe::parse("v1.2.3"), None);
+    }
+
+    #[test]
+    fn derive() {
+        let derive = |tag: &str, distance: u64, dirty: bool, settings: &VcsVersion| {
+            let tag_pattern = Regex::new(&settings.tag_pattern).unwrap();
+            let describe = Describe {
+                tag: tag.to_string(),
+                distance,
+                dirty,
+            };
+            derive_version(
+                &tag_version(&tag_pattern, tag).unwrap(),
+                &describe,
+                settings,
+            )
+            .map(|version| version.to_string())
+        };
+
+        let dev = VcsVersion::default();
+        assert_eq!(derive("v1.2.3", 0, false, &dev).unwrap(), "1.2.3");
+        assert_eq!(derive("1.2.3", 3, false, &dev).unwrap(), "1.2.4.dev3");
+        assert_eq!(derive("v1.0rc1", 3, false, &dev).unwrap(), "1.0rc2.dev3");
+        assert_eq!(derive("v1.2.3", 0, true, &dev).unwrap(), "1.2.3+dirty");
+
+        let post = VcsVersion {
+            scheme: VersionScheme::Post,
+            dirty: DirtyVersion::Error,
+            ..VcsVersion::default()
+        };
+        assert_eq!(derive("v1.2.3", 3, false, &post).unwrap(), "1.2.3.post3");
+        assert!(derive("v1.2.3", 3, true, &post).is_err());
+
+        let pattern = VcsVersion {
+            tag_pattern: r"^release-(?P<version>.+)$".to_string(),
+            dirty: DirtyVersion::Ignore,
+            ..VcsVersion::default()
+        };
+        assert_eq!(
+            derive("release-2.0", 1, true, &pattern).unwrap(),
+            "2.1.dev1"
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use fs_err::File;
-use globset::{GlobSet, GlobSetBuilder};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::io::{BufReader, Read, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::{io, mem};
 use tracing::{debug, trace};
 use walkdir::WalkDir;
@@ -16,9 +17,13 @@ use uv_globfilter::{GlobDirFilter, PortableGlobParser};
 use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
 use uv_warnings::warn_user_once;
 
+use crate::editable::editable_files;
+use crate::extension::{TargetPython, build_extension_modules};
+use crate::hooks::{build_dir_files, hook_outputs, run_build_hooks};
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
-    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, find_roots,
+    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
+    WheelDataInclude, find_roots, source_date_epoch,
 };
 
 /// Build a wheel from the source tree and place it in the output directory.
@@ -28,33 +33,62 @@ pub fn build_wheel(
     metadata_directory: Option<&Path>,
     uv_version: &str,
 ) -> Result<WheelFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let python = target_python(&settings)?;
+    let filename = wheel_filename(&pyproject_toml, python.as_ref());
 
+    // Run the hooks and compile the extension modules before creating the wheel, to not leave a
+    // partial wheel behind when they fail.
+    let mut generated = Vec::new();
+    let _hook_dir = if settings.build_hooks.is_empty() {
+        None
+    } else {
+        let (src_root, _module_relative) = find_roots(
+            source_tree,
+            &pyproject_toml,
+            &settings.module_root,
+            settings.module_name.as_ref(),
+            settings.namespace,
+        )?;
+        let hook_dir = tempfile::tempdir()?;
+        run_build_hooks(source_tree, &settings.build_hooks, hook_dir.path())?;
+        generated.extend(build_dir_files(hook_dir.path())?);
+        generated.extend(hook_outputs(source_tree, &src_root, &settings.build_hooks)?);
+        Some(hook_dir)
+    };
+    let _build_dir = if let Some(python) = &python {
+        let build_dir = tempfile::tempdir()?;
+        generated.extend(build_extension_modules(
+            source_tree,
+            &settings.ext_modules,
+            python,
+            build_dir.path(),
+        )?);
+        Some(build_dir)
+    } else {
+        None
+    };
+
+    let source_date_epoch = source_date_epoch()?;
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
-    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?, source_date_epoch);
 
     write_wheel(
         source_tree,
         &pyproject_toml,
         &filename,
         uv_version,
+        &generated,
         wheel_writer,
     )?;
 
@@ -66,26 +100,48 @@ pub fn list_wheel(
     source_tree: &Path,
     uv_version: &str,
 ) -> Result<(WheelFilename, FileList), Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let python = target_python(&settings)?;
+    let filename = wheel_filename(&pyproject_toml, python.as_ref());
+
+    // Hooks only run when building the wheel, so we only know their declared outputs.
+    let hook_outputs = if settings.build_hooks.is_empty() {
+        Vec::new()
+    } else {
+        let (src_root, _module_relative) = find_roots(
+            source_tree,
+            &pyproject_toml,
+            &settings.module_root,
+            settings.module_name.as_ref(),
+            settings.namespace,
+        )?;
+        hook_outputs(source_tree, &src_root, &settings.build_hooks)?
+    };
 
     let mut files = FileList::new();
     let writer = ListWriter::new(&mut files);
-    write_wheel(source_tree, &pyproject_toml, &filename, uv_version, writer)?;
+    write_wheel(
+        source_tree,
+        &pyproject_toml,
+        &filename,
+        uv_version,
+        &hook_outputs,
+        writer,
+    )?;
+    // Extension modules are only compiled when building the wheel.
+    if let Some(python) = &python {
+        for module in &settings.ext_modules {
+            files.push((python.module_path(module)?, None));
+        }
+    }
     Ok((filename, files))
 }
 
@@ -94,6 +150,7 @@ fn write_wheel(
     pyproject_toml: &PyProjectToml,
     filename: &WheelFilename,
     uv_version: &str,
+    generated: &[(String, PathBuf)],
     mut wheel_writer: impl DirectoryWriter,
 ) -> Result<(), Error> {
     let settings = pyproject_toml
@@ -180,12 +237,24 @@ fn write_wheel(
             }
 
             let entry_path = entry_path.portable_display().to_string();
+            // Skip extension modules from an in-place (editable) build and hook outputs, we add the
+            // fresh files below.
+            if generated.iter().any(|(path, _)| *path == entry_path) {
+                trace!("Skipping generated file: `{entry_path}`");
+                continue;
+            }
             debug!("Adding to wheel: {entry_path}");
             wheel_writer.write_dir_entry(&entry, &entry_path)?;
         }
     }
     debug!("Visited {files_visited} files for wheel build");
 
+    // Add the hook outputs and the compiled extension modules
+    for (wheel_path, file) in generated {
+        debug!("Adding generated file to wheel: {wheel_path}");
+        wheel_writer.write_file(wheel_path, file)?;
+    }
+
     // Add the license files
     if pyproject_toml.license_files_wheel().next().is_some() {
         debug!("Adding license files");
@@ -205,8 +274,7 @@ fn write_wheel(
     }
 
     // Add the data files
-    for (name, directory) in settings.data.iter() {
-        debug!("Adding {name} data files from: `{directory}`");
+    for (name, include) in settings.data.iter() {
         let data_dir = format!(
             "{}-{}.data/{}/",
             pyproject_toml.name().as_dist_info_name(),
@@ -214,13 +282,27 @@ fn write_wheel(
             name
         );
 
-        wheel_subdir_from_globs(
-            &source_tree.join(directory),
-            &data_dir,
-            &["**".to_string()],
-            &mut wheel_writer,
-            &format!("tool.uv.build-backend.data.{name}"),
-        )?;
+        match include {
+            WheelDataInclude::Directory(directory) => {
+                debug!("Adding {name} data files from: `{directory}`");
+                wheel_subdir_from_globs(
+                    &source_tree.join(directory),
+                    &data_dir,
+                    &["**".to_string()],
+                    &mut wheel_writer,
+                    &format!("tool.uv.build-backend.data.{name}"),
+                )?;
+            }
+            WheelDataInclude::Files(subdirectories) => {
+                wheel_data_files(
+                    source_tree,
+                    &data_dir,
+                    subdirectories,
+                    &mut wheel_writer,
+                    &format!("tool.uv.build-backend.data.{name}"),
+                )?;
+            }
+        }
     }
 
     debug!("Adding metadata files to wheel");
@@ -243,8 +325,7 @@ pub fn build_editable(
     metadata_directory: Option<&Path>,
     uv_version: &str,
 ) -> Result<WheelFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
@@ -255,24 +336,11 @@ pub fn build_editable(
 
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let python = target_python(&settings)?;
+    let filename = wheel_filename(&pyproject_toml, python.as_ref());
 
-    let wheel_path = wheel_dir.join(filename.to_string());
-    debug!("Writing wheel at {}", wheel_path.user_display());
-    let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
-
-    debug!("Adding pth file to {}", wheel_path.user_display());
     // Check that a module root exists in the directory we're linking from the `.pth` file
-    let (src_root, _module_relative) = find_roots(
+    let (src_root, modules_relative) = find_roots(
         source_tree,
         &pyproject_toml,
         &settings.module_root,
@@ -280,10 +348,33 @@ pub fn build_editable(
         settings.namespace,
     )?;
 
-    wheel_writer.write_bytes(
-        &format!("{}.pth", pyproject_toml.name().as_dist_info_name()),
-        src_root.as_os_str().as_encoded_bytes(),
-    )?;
+    // The `.pth` file links the module root, so the hooks write to the module root and the
+    // extension modules are compiled in place.
+    run_build_hooks(source_tree, &settings.build_hooks, &src_root)?;
+    if let Some(python) = &python {
+        build_extension_modules(source_tree, &settings.ext_modules, python, &src_root)?;
+    }
+
+    let source_date_epoch = source_date_epoch()?;
+    let wheel_path = wheel_dir.join(filename.to_string());
+    debug!("Writing wheel at {}", wheel_path.user_display());
+    let mut wheel_writer =
+        ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?, source_date_epoch);
+
+    debug!(
+        "Adding editable files ({:?}) to {}",
+        settings.editable_mode,
+        wheel_path.user_display()
+    );
+    for (path, contents) in editable_files(
+        source_tree,
+        pyproject_toml.name(),
+        &src_root,
+        &modules_relative,
+        settings.editable_mode,
+    )? {
+        wheel_writer.write_bytes(&path, &contents)?;
+    }
 
     debug!("Adding metadata files to: `{}`", wheel_path.user_display());
     let dist_info_dir = write_dist_info(
@@ -304,22 +395,17 @@ pub fn metadata(
     metadata_directory: &Path,
     uv_version: &str,
 ) -> Result<String, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let python = target_python(&settings)?;
+    let filename = wheel_filename(&pyproject_toml, python.as_ref());
 
     debug!(
         "Writing metadata files to {}",
@@ -338,6 +424,35 @@ pub fn metadata(
     Ok(dist_info_dir)
 }
 
+/// Query the interpreter to build the extension modules for, if the project has any.
+fn target_python(settings: &BuildBackendSettings) -> Result<Option<TargetPython>, Error> {
+    if settings.ext_modules.is_empty() {
+        Ok(None)
+    } else {
+        TargetPython::query().map(Some)
+    }
+}
+
+/// The wheel filename, `py3-none-any` for pure Python projects and tagged for the target
+/// interpreter for projects with extension modules.
+fn wheel_filename(pyproject_toml: &PyProjectToml, python: Option<&TargetPython>) -> WheelFilename {
+    let (language_tag, abi_tag, platform_tag) = python.map(TargetPython::tags).unwrap_or((
+        LanguageTag::Python {
+            major: 3,
+            minor: None,
+        },
+        AbiTag::None,
+        PlatformTag::Any,
+    ));
+    WheelFilename::new(
+        pyproject_toml.name().clone(),
+        pyproject_toml.version().clone(),
+        language_tag,
+        abi_tag,
+        platform_tag,
+    )
+}
+
 /// An entry in the `RECORD` file.
 ///
 /// <https://packaging.python.org/en/latest/specifications/recording-installed-packages/#the-record-file>
@@ -525,6 +640,118 @@ fn wheel_subdir_from_globs(
     Ok(())
 }
 
+/// Add the files matching the globs, relative to the source tree, to their subdirectory of the
+/// data directory, flattened to their file names.
+fn wheel_data_files(
+    source_tree: &Path,
+    data_dir: &str,
+    subdirectories: &BTreeMap<String, Vec<String>>,
+    wheel_writer: &mut impl DirectoryWriter,
+    // For error messages
+    field: &str,
+) -> Result<(), Error> {
+    wheel_writer.write_directory(data_dir)?;
+    // Subdirectories may share parent directories, which must only be added once.
+    let mut directories = FxHashSet::default();
+    for (subdirectory, globs) in subdirectories {
+        debug!("Adding data files to: `{data_dir}{subdirectory}`");
+        let globs_field = format!("{field}.{subdirectory}");
+        let files = find_data_files(source_tree, globs, &globs_field)?;
+
+        // The subdirectory must stay inside the data directory.
+        let mut target = data_dir.trim_end_matches('/').to_string();
+        for component in Path::new(subdirectory).components() {
+            let Component::Normal(component) = component else {
+                return Err(Error::InvalidDataSubdirectory(subdirectory.clone()));
+            };
+            target = format!("{target}/{}", component.to_string_lossy());
+            if directories.insert(target.clone()) {
+                wheel_writer.write_directory(&target)?;
+            }
+        }
+
+        for (file_name, relative) in files {
+            debug!("Adding for {}: `{}`", globs_field, relative.user_display());
+            wheel_writer.write_file(
+                &format!("{target}/{file_name}"),
+                &source_tree.join(relative),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Find the files matching the globs in the source tree, by file name.
+///
+/// Each glob must match at least one file, and the file names must be unique.
+fn find_data_files(
+    source_tree: &Path,
+    globs: &[String],
+    globs_field: &str,
+) -> Result<BTreeMap<String, PathBuf>, Error> {
+    let parsed: Vec<_> = globs
+        .iter()
+        .map(|glob| PortableGlobParser::Pep639.parse(glob))
+        .collect::<Result<_, _>>()
+        .map_err(|err| Error::PortableGlob {
+            field: globs_field.to_string(),
+            source: err,
+        })?;
+    let matcher = GlobDirFilter::from_globs(&parsed).map_err(|err| Error::GlobSetTooLarge {
+        field: globs_field.to_string(),
+        source: err,
+    })?;
+    let glob_matchers: Vec<_> = parsed.iter().map(Glob::compile_matcher).collect();
+
+    let mut files: BTreeMap<String, PathBuf> = BTreeMap::new();
+    let mut matched = vec![false; globs.len()];
+    for entry in WalkDir::new(source_tree)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let relative = entry
+                .path()
+                .strip_prefix(source_tree)
+                .expect("walkdir starts with root");
+            // Fast path: Don't descend into a directory that can't be included.
+            matcher.match_directory(relative)
+        })
+    {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: source_tree.to_path_buf(),
+            err,
+        })?;
+        let relative = entry
+            .path()
+            .strip_prefix(source_tree)
+            .expect("walkdir starts with root");
+        if !entry.file_type().is_file() || !matcher.match_path(relative) {
+            continue;
+        }
+        for (matched, glob_matcher) in matched.iter_mut().zip(&glob_matchers) {
+            *matched |= glob_matcher.is_match(relative);
+        }
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if let Some(first) = files.insert(file_name.clone(), relative.to_path_buf()) {
+            return Err(Error::DuplicateDataFile {
+                field: globs_field.to_string(),
+                file_name,
+                first,
+                second: relative.to_path_buf(),
+            });
+        }
+    }
+
+    // Catch typos in the globs, which would otherwise silently omit files.
+    if let Some((glob, _)) = globs.iter().zip(&matched).find(|(_, matched)| !**matched) {
+        return Err(Error::MissingDataFiles {
+            field: globs_field.to_string(),
+            glob: glob.clone(),
+        });
+    }
+    Ok(files)
+}
+
 /// Add `METADATA` and `entry_points.txt` to the dist-info directory.
 ///
 /// Returns the name of the dist-info directory.
@@ -567,10 +794,15 @@ fn write_dist_info(
 /// Returns the `WHEEL` file contents.
 fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
     // https://packaging.python.org/en/latest/specifications/binary-distribution-format/#file-contents
+    // Wheels with extension modules are installed to `platlib`.
+    let purelib = filename
+        .platform_tags()
+        .iter()
+        .all(|platform_tag| *platform_tag == PlatformTag::Any);
     let mut wheel_info = vec![
         ("Wheel-Version", "1.0".to_string()),
         ("Generator", format!("uv {uv_version}")),
-        ("Root-Is-Purelib", "true".to_string()),
+        ("Root-Is-Purelib", purelib.to_string()),
     ];
     for python_tag in filename.python_tags() {
         for abi_tag in filename.abi_tags() {
@@ -585,20 +817,46 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
         .join("\n")
 }
 
+/// Convert `SOURCE_DATE_EPOCH` to a zip timestamp.
+///
+/// Zip timestamps have no timezone and can't represent times before 1980, so we use UTC and fall
+/// back to the earliest representable time, which is also the default for an unset
+/// `SOURCE_DATE_EPOCH`.
+fn zip_date_time(source_date_epoch: u64) -> zip::DateTime {
+    let convert = || {
+        let timestamp =
+            jiff::Timestamp::from_second(i64::try_from(source_date_epoch).ok()?).ok()?;
+        let date_time = timestamp.to_zoned(jiff::tz::TimeZone::UTC);
+        zip::DateTime::from_date_and_time(
+            u16::try_from(date_time.year()).ok()?,
+            u8::try_from(date_time.month()).ok()?,
+            u8::try_from(date_time.day()).ok()?,
+            u8::try_from(date_time.hour()).ok()?,
+            u8::try_from(date_time.minute()).ok()?,
+            u8::try_from(date_time.second()).ok()?,
+        )
+        .ok()
+    };
+    convert().unwrap_or_default()
+}
+
 /// Zip archive (wheel) writer.
 struct ZipDirectoryWriter {
     writer: ZipWriter<File>,
     compression: CompressionMethod,
+    /// The modification time of all entries.
+    last_modified: zip::DateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
 
 impl ZipDirectoryWriter {
     /// A wheel writer with deflate compression.
-    fn new_wheel(file: File) -> Self {
+    fn new_wheel(file: File, source_date_epoch: u64) -> Self {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Deflated,
+            last_modified: zip_date_time(source_date_epoch),
             record: Vec::new(),
         }
     }
@@ -607,14 +865,22 @@ impl ZipDirectoryWriter {
     ///
     /// Since editables are temporary, we save time be skipping compression and decompression.
     #[expect(dead_code)]
-    fn new_editable(file: File) -> Self {
+    fn new_editable(file: File, source_date_epoch: u64) -> Self {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Stored,
+            last_modified: zip_date_time(source_date_epoch),
             record: Vec::new(),
         }
     }
 
+    /// The options shared by all entries, with a fixed timestamp for reproducibility.
+    fn options(&self) -> zip::write::SimpleFileOptions {
+        zip::write::SimpleFileOptions::default()
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified)
+    }
+
     /// Add a file with the given name and return a writer for it.
     fn new_writer<'slf>(
         &'slf mut self,
@@ -623,9 +889,7 @@ impl ZipDirectoryWriter {
     ) -> Result<Box<dyn Write + 'slf>, Error> {
         // 644 is the default of the zip crate.
         let permissions = if executable_bit { 775 } else { 664 };
-        let options = zip::write::SimpleFileOptions::default()
-            .unix_permissions(permissions)
-            .compression_method(self.compression);
+        let options = self.options().unix_permissions(permissions);
         self.writer.start_file(path, options)?;
         Ok(Box::new(&mut self.writer))
     }
@@ -634,7 +898,7 @@ impl ZipDirectoryWriter {
 impl DirectoryWriter for ZipDirectoryWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         trace!("Adding {}", path);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        let options = self.options();
         self.writer.start_file(path, options)?;
         self.writer.write_all(bytes)?;
 
@@ -669,7 +933,7 @@ impl DirectoryWriter for ZipDirectoryWriter {
 
     fn write_directory(&mut self, directory: &str) -> Result<(), Error> {
         trace!("Adding directory {}", directory);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        let options = self.options();
         Ok(self.writer.add_directory(directory, options)?)
     }
 
@@ -804,6 +1068,18 @@ mod test {
         ");
     }
 
+    #[test]
+    fn test_zip_date_time() {
+        // Unset `SOURCE_DATE_EPOCH` and times before 1980 use the earliest zip timestamp.
+        assert_eq!(zip_date_time(0), zip::DateTime::default());
+        assert_eq!(zip_date_time(315_532_799), zip::DateTime::default());
+        // 2024-02-29 12:34:56 UTC
+        assert_eq!(
+            zip_date_time(1_709_210_096),
+            zip::DateTime::from_date_and_time(2024, 2, 29, 12, 34, 56).unwrap()
+        );
+    }
+
     /// Snapshot all files from the prepare metadata hook.
     #[test]
     fn test_prepare_metadata() {
@@ -11,9 +11,9 @@ use regex::Regex;
 use thiserror::Error;
 use tracing::error;
 use uv_configuration::BuildOutput;
-use uv_distribution_types::IsBuildBackendError;
+use uv_distribution_types::{InstalledDistError, IsBuildBackendError};
 use uv_fs::Simplified;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::PackageName;
 use uv_types::AnyErrorBuild;
 
@@ -90,6 +90,20 @@ pub enum Error {
     NoSourceDistBuilds,
     #[error("Cyclic build dependency detected for `{0}`")]
     CyclicBuildDependency(PackageName),
+    #[error(
+        "The shared build requirement `{0}` is not installed in the environment (from `no-build-isolation-package`)"
+    )]
+    MissingSharedPackage(PackageName),
+    #[error(
+        "The shared build requirement `{name}=={version}` does not satisfy the build requirement `{name}{specifier}` (from `no-build-isolation-package`)"
+    )]
+    IncompatibleSharedPackage {
+        name: PackageName,
+        version: Version,
+        specifier: VersionSpecifiers,
+    },
+    #[error("Failed to read the shared build requirements from the environment")]
+    SharedPackageMetadata(#[source] InstalledDistError),
 }
 
 impl IsBuildBackendError for Error {
@@ -105,7 +119,10 @@ impl IsBuildBackendError for Error {
             | Self::Virtualenv(_)
             | Self::NoSourceDistBuild(_)
             | Self::NoSourceDistBuilds
-            | Self::CyclicBuildDependency(_) => false,
+            | Self::CyclicBuildDependency(_)
+            | Self::MissingSharedPackage(_)
+            | Self::IncompatibleSharedPackage { .. }
+            | Self::SharedPackageMetadata(_) => false,
             Self::CommandFailed(_, _)
             | Self::BuildBackend(_)
             | Self::MissingHeader(_)
@@ -414,7 +431,7 @@ mod test {
     use std::process::ExitStatus;
     use std::str::FromStr;
     use uv_configuration::BuildOutput;
-    use uv_pep440::Version;
+    use uv_pep440::{Version, VersionSpecifiers};
     use uv_pep508::PackageName;
 
     #[test]
@@ -3,6 +3,7 @@
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
 mod error;
+mod shared;
 
 use std::ffi::OsString;
 use std::fmt::Formatter;
@@ -12,7 +13,7 @@ use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::rc::Rc;
 use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock, MutexGuard, PoisonError};
 use std::{env, iter};
 
 use fs_err as fs;
@@ -29,9 +30,9 @@ use tracing::{Instrument, debug, info_span, instrument, warn};
 
 use uv_cache_key::cache_digest;
 use uv_configuration::PreviewMode;
-use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
+use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, Constraints, SourceStrategy};
 use uv_distribution::BuildRequires;
-use uv_distribution_types::{IndexLocations, Requirement, Resolution};
+use uv_distribution_types::{IndexLocations, Name, Requirement, Resolution};
 use uv_fs::LockedFile;
 use uv_fs::{PythonExt, Simplified};
 use uv_pep440::Version;
@@ -43,6 +44,8 @@ use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBu
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
+use crate::shared::{SharedPackages, module_distribution};
+
 pub use crate::error::{Error, MissingHeaderCause};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
@@ -215,6 +218,104 @@ impl Pep517Backend {
 pub struct SourceBuildContext {
     /// An in-memory resolution of the default backend's requirements for PEP 517 builds.
     default_resolution: Rc<Mutex<Option<Resolution>>>,
+    /// In-memory resolutions of `build-system.requires`, such that builds with the same build
+    /// requirements (e.g., the members of a workspace) install the same build environment.
+    resolutions: Rc<Mutex<FxHashMap<Vec<Requirement>, Resolution>>>,
+    /// Isolated build environments that are not in use, such that builds with the same resolved
+    /// build requirements reuse an environment instead of creating and seeding a new one.
+    environments: BuildEnvironments,
+}
+
+/// Isolated build environments that are not in use, by [`BuildEnvironmentKey`].
+///
+/// Uses an [`Arc`] internally, clone freely. Environments are returned to the pool when a
+/// [`SourceBuild`] is dropped, so the pool is guarded by a synchronous mutex.
+#[derive(Debug, Default, Clone)]
+struct BuildEnvironments(
+    Arc<std::sync::Mutex<FxHashMap<BuildEnvironmentKey, Vec<BuildEnvironment>>>>,
+);
+
+impl BuildEnvironments {
+    /// Take an unused environment with the given key, if any.
+    fn pop(&self, key: &BuildEnvironmentKey) -> Option<BuildEnvironment> {
+        self.lock().get_mut(key).and_then(Vec::pop)
+    }
+
+    /// Return an environment to the pool, for reuse by other builds.
+    fn push(&self, environment: BuildEnvironment) {
+        self.lock()
+            .entry(environment.key.clone())
+            .or_default()
+            .push(environment);
+    }
+
+    /// Lock the pool.
+    ///
+    /// The lock is only held by [`BuildEnvironments::pop`] and [`BuildEnvironments::push`], which
+    /// can't leave the map in an inconsistent state, so a poisoned lock is safe to recover.
+    fn lock(&self) -> MutexGuard<'_, FxHashMap<BuildEnvironmentKey, Vec<BuildEnvironment>>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The inputs that determine the contents of an isolated build environment, such that
+/// environments are only reused across builds that would have produced an identical environment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BuildEnvironmentKey {
+    /// The interpreter used to create the environment.
+    interpreter: PathBuf,
+    /// The installed build requirements, sorted.
+    distributions: Vec<String>,
+    /// The digest of the `--config-settings` passed to the build backend.
+    config_settings: String,
+    /// The extra environment variables set for the build, sorted.
+    environment_variables: Vec<(OsString, OsString)>,
+    /// The environment from which build requirements are shared under
+    /// `no-build-isolation-package`, if any.
+    shared_environment: Option<PathBuf>,
+}
+
+impl BuildEnvironmentKey {
+    fn new(
+        interpreter: &Interpreter,
+        resolution: &Resolution,
+        config_settings: &ConfigSettings,
+        environment_variables: &FxHashMap<OsString, OsString>,
+        build_isolation: BuildIsolation<'_>,
+    ) -> Self {
+        Self {
+            interpreter: interpreter.sys_executable().to_path_buf(),
+            distributions: resolution
+                .distributions()
+                .map(ToString::to_string)
+                .sorted()
+                .collect(),
+            config_settings: cache_digest(config_settings),
+            environment_variables: environment_variables
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .sorted()
+                .collect(),
+            shared_environment: match build_isolation {
+                BuildIsolation::SharedPackage(environment, _) => {
+                    Some(environment.root().to_path_buf())
+                }
+                BuildIsolation::Isolated | BuildIsolation::Shared(_) => None,
+            },
+        }
+    }
+}
+
+/// An isolated build environment with the build requirements installed.
+#[derive(Debug)]
+struct BuildEnvironment {
+    key: BuildEnvironmentKey,
+    venv: PythonEnvironment,
+    /// The directory containing the virtual environment, removed when the environment is dropped.
+    _venv_dir: TempDir,
+    /// Whether the backend requested additional requirements that were installed into the
+    /// environment, which means it can't be reused.
+    modified: bool,
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -232,6 +333,10 @@ pub struct SourceBuild {
     project: Option<Project>,
     /// The virtual environment in which to build the source distribution.
     venv: PythonEnvironment,
+    /// The isolated build environment, if any, which is returned to `build_environments` for
+    /// reuse when the build is dropped.
+    build_environment: Option<BuildEnvironment>,
+    build_environments: BuildEnvironments,
     /// Populated if `prepare_metadata_for_build_wheel` was called.
     ///
     /// > If the build frontend has previously called `prepare_metadata_for_build_wheel` and depends
@@ -259,6 +364,44 @@ pub struct SourceBuild {
     environment_variables: FxHashMap<OsString, OsString>,
     /// Runner for Python scripts.
     runner: PythonRunner,
+    /// The build constraints that applied to the packages in the isolated build environment.
+    applied_build_constraints: Vec<AppliedBuildConstraint>,
+}
+
+/// A build constraint that applied to a package installed into an isolated build environment.
+#[derive(Debug, Clone)]
+pub struct AppliedBuildConstraint {
+    /// The build constraint, e.g., `setuptools<70`.
+    pub constraint: Requirement,
+    /// The version of the constrained package in the build environment.
+    pub version: Option<Version>,
+}
+
+impl AppliedBuildConstraint {
+    /// The build constraints that applied to the resolved build requirements.
+    fn from_resolution(
+        constraints: &Constraints,
+        resolution: &Resolution,
+        interpreter: &Interpreter,
+    ) -> Vec<Self> {
+        resolution
+            .distributions()
+            .sorted_by(|a, b| a.name().cmp(b.name()))
+            .flat_map(|dist| {
+                constraints
+                    .get(dist.name())
+                    .into_iter()
+                    .flatten()
+                    .filter(|constraint| {
+                        constraint.evaluate_markers(Some(interpreter.markers()), &[])
+                    })
+                    .map(|constraint| Self {
+                        constraint: constraint.clone(),
+                        version: dist.version().cloned(),
+                    })
+            })
+            .collect()
+    }
 }
 
 impl SourceBuild {
@@ -299,7 +442,7 @@ impl SourceBuild {
         let default_backend: Pep517Backend = DEFAULT_BACKEND.clone();
 
         // Check if we have a PEP 517 build backend.
-        let (pep517_backend, project) = Self::extract_pep517_backend(
+        let (mut pep517_backend, project) = Self::extract_pep517_backend(
             &source_tree,
             install_path,
             fallback_package_name,
@@ -322,44 +465,77 @@ impl SourceBuild {
             .or(fallback_package_version)
             .cloned();
 
-        // Create a virtual environment, or install into the shared environment if requested.
-        let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
-            venv.clone()
-        } else {
-            uv_virtualenv::create_venv(
-                temp_dir.path(),
-                interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                uv_virtualenv::OnExisting::Remove,
-                false,
-                false,
-                false,
-                preview,
-            )?
-        };
-
-        // Set up the build environment. If build isolation is disabled, we assume the build
-        // environment is already setup.
-        if build_isolation.is_isolated(package_name.as_ref()) {
-            debug!("Resolving build requirements");
+        // Set up an isolated build environment, or use the shared environment if requested. If
+        // build isolation is disabled, we assume the build environment is already setup.
+        let build_environments = source_build_context.environments.clone();
+        let shared_packages = build_isolation.shared_packages(package_name.as_ref());
+        let build_constraints = package_name
+            .as_ref()
+            .and_then(|package_name| build_context.build_constraints_package(package_name))
+            .unwrap_or_else(|| build_context.build_constraints());
+        let mut applied_build_constraints = Vec::new();
+        let (venv, mut build_environment) =
+            if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
+                debug!("Proceeding without build isolation");
+                (venv.clone(), None)
+            } else {
+                // Share the requested build requirements from the environment, instead of
+                // installing them into the isolated build environment.
+                let shared = if let Some((environment, names)) = shared_packages {
+                    let shared = SharedPackages::from_environment(
+                        environment,
+                        names,
+                        &pep517_backend.requirements,
+                    )?;
+                    pep517_backend
+                        .requirements
+                        .retain(|requirement| !names.contains(&requirement.name));
+                    Some(shared)
+                } else {
+                    None
+                };
 
-            let resolved_requirements = Self::get_resolved_requirements(
-                build_context,
-                source_build_context,
-                &default_backend,
-                &pep517_backend,
-                build_stack,
-            )
-            .await?;
+                debug!("Resolving build requirements");
+
+                let resolved_requirements = Self::get_resolved_requirements(
+                    build_context,
+                    source_build_context,
+                    &default_backend,
+                    &pep517_backend,
+                    package_name.as_ref(),
+                    build_stack,
+                )
+                .await?;
+                applied_build_constraints = AppliedBuildConstraint::from_resolution(
+                    build_constraints,
+                    &resolved_requirements,
+                    interpreter,
+                );
 
-            build_context
-                .install(&resolved_requirements, &venv, build_stack)
-                .await
-                .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err.into()))?;
-        } else {
-            debug!("Proceeding without build isolation");
-        }
+                let key = BuildEnvironmentKey::new(
+                    interpreter,
+                    &resolved_requirements,
+                    &config_settings,
+                    &environment_variables,
+                    build_isolation,
+                );
+                let mut environment = Self::build_environment(
+                    build_context,
+                    &build_environments,
+                    key,
+                    interpreter,
+                    &resolved_requirements,
+                    build_stack,
+                    preview,
+                )
+                .await?;
+                if let Some(shared) = shared {
+                    shared.install(&environment.venv)?;
+                    // Don't reuse an environment that differs from its resolved build requirements.
+                    environment.modified = true;
+                }
+                (environment.venv.clone(), Some(environment))
+            };
 
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
@@ -398,7 +574,7 @@ impl SourceBuild {
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            let extra_resolution = create_pep517_build_environment(
                 &runner,
                 &source_tree,
                 install_path,
@@ -418,8 +594,38 @@ impl SourceBuild {
                 &environment_variables,
                 &modified_path,
                 &temp_dir,
+                shared_packages.map(|(_, names)| names).unwrap_or_default(),
             )
             .await?;
+
+            if let Some(extra_resolution) = extra_resolution {
+                applied_build_constraints = AppliedBuildConstraint::from_resolution(
+                    build_constraints,
+                    &extra_resolution,
+                    interpreter,
+                );
+                // Don't reuse an environment that differs from its resolved build requirements.
+                if let Some(environment) = &mut build_environment {
+                    environment.modified = true;
+                }
+            }
+        }
+
+        for applied in &applied_build_constraints {
+            debug!(
+                "Applied build constraint `{}` in the build environment of `{}`{}",
+                applied.constraint,
+                package_name
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .or_else(|| version_id.map(ToString::to_string))
+                    .unwrap_or_else(|| source_tree.user_display().to_string()),
+                applied
+                    .version
+                    .as_ref()
+                    .map(|version| format!(", installing `{}=={version}`", applied.constraint.name))
+                    .unwrap_or_default()
+            );
         }
 
         Ok(Self {
@@ -428,6 +634,8 @@ impl SourceBuild {
             pep517_backend,
             project,
             venv,
+            build_environment,
+            build_environments,
             build_kind,
             level,
             config_settings,
@@ -438,6 +646,7 @@ impl SourceBuild {
             environment_variables,
             modified_path,
             runner,
+            applied_build_constraints,
         })
     }
 
@@ -471,8 +680,23 @@ impl SourceBuild {
         source_build_context: SourceBuildContext,
         default_backend: &Pep517Backend,
         pep517_backend: &Pep517Backend,
+        package_name: Option<&PackageName>,
         build_stack: &BuildStack,
     ) -> Result<Resolution, Error> {
+        // Package-specific build constraints lead to a different resolution, which must not be
+        // shared with other builds.
+        if package_name.is_some_and(|package_name| {
+            build_context
+                .build_constraints_package(package_name)
+                .is_some()
+        }) {
+            debug!("Applying package-specific build constraints");
+            return build_context
+                .resolve(&pep517_backend.requirements, package_name, build_stack)
+                .await
+                .map_err(|err| Error::RequirementsResolve("`build-system.requires`", err.into()));
+        }
+
         Ok(
             if pep517_backend.requirements == default_backend.requirements {
                 let mut resolution = source_build_context.default_resolution.lock().await;
@@ -480,7 +704,7 @@ impl SourceBuild {
                     resolved_requirements.clone()
                 } else {
                     let resolved_requirements = build_context
-                        .resolve(&default_backend.requirements, build_stack)
+                        .resolve(&default_backend.requirements, package_name, build_stack)
                         .await
                         .map_err(|err| {
                             Error::RequirementsResolve("`setup.py` build", err.into())
@@ -489,16 +713,75 @@ impl SourceBuild {
                     resolved_requirements
                 }
             } else {
-                build_context
-                    .resolve(&pep517_backend.requirements, build_stack)
+                let cached = source_build_context
+                    .resolutions
+                    .lock()
                     .await
-                    .map_err(|err| {
-                        Error::RequirementsResolve("`build-system.requires`", err.into())
-                    })?
+                    .get(&pep517_backend.requirements)
+                    .cloned();
+                if let Some(resolved_requirements) = cached {
+                    debug!("Reusing the resolution of matching build requirements");
+                    resolved_requirements
+                } else {
+                    let resolved_requirements = build_context
+                        .resolve(&pep517_backend.requirements, package_name, build_stack)
+                        .await
+                        .map_err(|err| {
+                            Error::RequirementsResolve("`build-system.requires`", err.into())
+                        })?;
+                    source_build_context.resolutions.lock().await.insert(
+                        pep517_backend.requirements.clone(),
+                        resolved_requirements.clone(),
+                    );
+                    resolved_requirements
+                }
             },
         )
     }
 
+    /// Reuse an unused isolated environment with the same [`BuildEnvironmentKey`], or create a
+    /// new one.
+    async fn build_environment(
+        build_context: &impl BuildContext,
+        build_environments: &BuildEnvironments,
+        key: BuildEnvironmentKey,
+        interpreter: &Interpreter,
+        resolved_requirements: &Resolution,
+        build_stack: &BuildStack,
+        preview: PreviewMode,
+    ) -> Result<BuildEnvironment, Error> {
+        if let Some(environment) = build_environments.pop(&key) {
+            debug!(
+                "Reusing build environment: `{}`",
+                environment.venv.root().user_display()
+            );
+            return Ok(environment);
+        }
+
+        let venv_dir = build_context.cache().venv_dir()?;
+        let venv = uv_virtualenv::create_venv(
+            venv_dir.path(),
+            interpreter.clone(),
+            uv_virtualenv::Prompt::None,
+            false,
+            uv_virtualenv::OnExisting::Remove,
+            false,
+            false,
+            false,
+            preview,
+        )?;
+        build_context
+            .install(resolved_requirements, &venv, build_stack)
+            .await
+            .map_err(|err| Error::RequirementsInstall("`build-system.requires`", err.into()))?;
+        Ok(BuildEnvironment {
+            key,
+            venv,
+            _venv_dir: venv_dir,
+            modified: false,
+        })
+    }
+
     /// Extract the PEP 517 backend from the `pyproject.toml` or `setup.py` file.
     async fn extract_pep517_backend(
         source_tree: &Path,
@@ -753,6 +1036,23 @@ impl SourceBuild {
         Ok(filename)
     }
 
+    /// The build constraints that applied to the packages in the isolated build environment.
+    pub fn applied_build_constraints(&self) -> &[AppliedBuildConstraint] {
+        &self.applied_build_constraints
+    }
+
+    /// The PEP 517 build backend, e.g., `hatchling.build`.
+    pub fn backend(&self) -> &str {
+        &self.pep517_backend.backend
+    }
+
+    /// The name and version of the distribution that provides the build backend, if it's
+    /// installed in the build environment.
+    pub fn backend_distribution(&self) -> Option<(PackageName, Version)> {
+        let module = self.pep517_backend.backend.split([':', '.']).next()?;
+        module_distribution(&self.venv, module)
+    }
+
     /// Perform a PEP 517 build for a wheel or source distribution (sdist).
     async fn pep517_build(&self, output_dir: &Path) -> Result<String, Error> {
         // Lock the source tree, if necessary.
@@ -870,6 +1170,18 @@ impl SourceBuild {
     }
 }
 
+impl Drop for SourceBuild {
+    fn drop(&mut self) {
+        // Return the build environment for reuse by other builds, unless the backend installed
+        // additional requirements into it.
+        if let Some(environment) = self.build_environment.take() {
+            if !environment.modified {
+                self.build_environments.push(environment);
+            }
+        }
+    }
+}
+
 impl SourceBuildTrait for SourceBuild {
     async fn metadata(&mut self) -> Result<Option<PathBuf>, AnyErrorBuild> {
         Ok(self.get_metadata_without_build().await?)
@@ -887,6 +1199,10 @@ fn escape_path_for_python(path: &Path) -> String {
 }
 
 /// Not a method because we call it before the builder is completely initialized
+///
+/// Returns the resolution including the additional requirements from `get_requires_for_build_*`,
+/// if they were installed. The `shared_packages` are skipped, as they are shared from another
+/// environment.
 async fn create_pep517_build_environment(
     runner: &PythonRunner,
     source_tree: &Path,
@@ -907,7 +1223,8 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
-) -> Result<(), Error> {
+    shared_packages: &[PackageName],
+) -> Result<Option<Resolution>, Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -1008,6 +1325,10 @@ async fn create_pep517_build_environment(
         }
         SourceStrategy::Disabled => extra_requires.into_iter().map(Requirement::from).collect(),
     };
+    let extra_requires: Vec<_> = extra_requires
+        .into_iter()
+        .filter(|requirement| !shared_packages.contains(&requirement.name))
+        .collect();
 
     // Some packages (such as tqdm 4.66.1) list only extra requires that have already been part of
     // the pyproject.toml requires (in this case, `wheel`). We can skip doing the whole resolution
@@ -1025,7 +1346,7 @@ async fn create_pep517_build_environment(
             .chain(extra_requires)
             .collect();
         let resolution = build_context
-            .resolve(&requirements, build_stack)
+            .resolve(&requirements, package_name, build_stack)
             .await
             .map_err(|err| {
                 Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
@@ -1037,9 +1358,10 @@ async fn create_pep517_build_environment(
             .map_err(|err| {
                 Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
+        return Ok(Some(resolution));
     }
 
-    Ok(())
+    Ok(None)
 }
 
 /// A runner that manages the execution of external python processes with a
@@ -0,0 +1,284 @@
+//! Read the distributions installed in an environment, to share build requirements into an
+//! isolated build environment for packages in a `no-build-isolation-package` table, and to find
+//! the distribution of a build backend.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use indoc::formatdoc;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use uv_distribution_types::{InstalledDist, Name, Requirement, RequirementSource};
+use uv_fs::Simplified;
+use uv_pep440::Version;
+use uv_pep508::{ExtraName, PackageName};
+use uv_python::PythonEnvironment;
+
+use crate::Error;
+
+/// The name of the module that makes the shared packages importable, imported by a `.pth` file
+/// of the same name.
+const SHARED_PACKAGES_MODULE: &str = "_uv_shared_packages";
+
+/// The build requirements shared from an environment, including their dependencies.
+#[derive(Debug)]
+pub(crate) struct SharedPackages {
+    /// The `site-packages` directories of the environment.
+    site_packages: Vec<PathBuf>,
+    /// The top-level modules of the shared distributions.
+    modules: BTreeSet<String>,
+    /// The shared distributions with their `.dist-info` directory.
+    distributions: Vec<(PackageName, PathBuf)>,
+}
+
+impl SharedPackages {
+    /// Collect the shared packages and their dependencies from the environment, checking that the
+    /// installed versions satisfy the build requirements.
+    pub(crate) fn from_environment(
+        environment: &PythonEnvironment,
+        names: &[PackageName],
+        requirements: &[Requirement],
+    ) -> Result<Self, Error> {
+        let installed = installed_distributions(environment)?;
+
+        for name in names {
+            let dist = installed
+                .get(name)
+                .ok_or_else(|| Error::MissingSharedPackage(name.clone()))?;
+            for requirement in requirements {
+                if &requirement.name != name
+                    || !requirement.evaluate_markers(Some(environment.interpreter().markers()), &[])
+                {
+                    continue;
+                }
+                if let RequirementSource::Registry { specifier, .. } = &requirement.source {
+                    if !specifier.contains(dist.version()) {
+                        return Err(Error::IncompatibleSharedPackage {
+                            name: name.clone(),
+                            version: dist.version().clone(),
+                            specifier: specifier.clone(),
+                        });
+                    }
+                }
+            }
+            debug!(
+                "Sharing `{name}=={}` from: `{}`",
+                dist.version(),
+                environment.root().user_display()
+            );
+        }
+
+        // The shared packages need their own dependencies to be importable.
+        let mut seen = FxHashSet::default();
+        let mut queue: VecDeque<(PackageName, Vec<ExtraName>)> = names
+            .iter()
+            .map(|name| (name.clone(), Vec::new()))
+            .collect();
+        let mut modules = BTreeSet::new();
+        let mut distributions = Vec::new();
+        while let Some((name, extras)) = queue.pop_front() {
+            if !seen.insert((name.clone(), extras.clone())) {
+                continue;
+            }
+            let Some(dist) = installed.get(&name) else {
+                debug!("Dependency `{name}` of the shared packages is not installed, skipping");
+                continue;
+            };
+            if extras.is_empty() {
+                modules.extend(top_level_modules(dist.install_path())?);
+                distributions.push((name.clone(), dist.install_path().to_path_buf()));
+            }
+            let metadata = dist.metadata().map_err(Error::SharedPackageMetadata)?;
+            for requirement in metadata.requires_dist {
+                if !requirement.evaluate_markers(environment.interpreter().markers(), &extras) {
+                    continue;
+                }
+                queue.push_back((requirement.name.clone(), Vec::new()));
+                if !requirement.extras.is_empty() {
+                    queue.push_back((requirement.name, requirement.extras.into_vec()));
+                }
+            }
+        }
+
+        Ok(Self {
+            site_packages: environment
+                .site_packages()
+                .map(|path| path.to_path_buf())
+                .collect(),
+            modules,
+            distributions,
+        })
+    }
+
+    /// Make the shared packages importable in the isolated build environment, with a `.pth` file
+    /// that registers an import hook for their modules and distribution metadata.
+    ///
+    /// The hook comes after the regular import machinery, so the build requirements installed
+    /// into the isolated environment take precedence.
+    pub(crate) fn install(&self, venv: &PythonEnvironment) -> Result<(), Error> {
+        let site_packages = venv.interpreter().purelib();
+        let module = formatdoc! {r#"
+            import importlib.machinery
+            import importlib.metadata
+            import pathlib
+            import re
+            import sys
+
+            _SITE_PACKAGES = {site_packages}
+            _MODULES = frozenset({modules})
+            _DISTRIBUTIONS = {distributions}
+
+
+            class SharedPackagesFinder(importlib.metadata.DistributionFinder):
+                """Find the packages shared from the environment by uv."""
+
+                @classmethod
+                def find_spec(cls, fullname, path=None, target=None):
+                    if path is None and fullname in _MODULES:
+                        return importlib.machinery.PathFinder.find_spec(fullname, _SITE_PACKAGES)
+                    return None
+
+                @classmethod
+                def find_distributions(cls, context=importlib.metadata.DistributionFinder.Context()):
+                    name = context.name and re.sub(r"[-_.]+", "-", context.name).lower()
+                    for distribution_name, path in _DISTRIBUTIONS:
+                        if name is None or name == distribution_name:
+                            yield importlib.metadata.PathDistribution(pathlib.Path(path))
+
+
+            sys.meta_path.append(SharedPackagesFinder)
+        "#,
+            site_packages = serde_json::to_string(&self.site_packages).map_err(io::Error::from)?,
+            modules = serde_json::to_string(&self.modules).map_err(io::Error::from)?,
+            distributions = serde_json::to_string(&self.distributions).map_err(io::Error::from)?,
+        };
+        fs::write(
+            site_packages.join(format!("{SHARED_PACKAGES_MODULE}.py")),
+            module,
+        )?;
+        fs::write(
+            site_packages.join(format!("{SHARED_PACKAGES_MODULE}.pth")),
+            format!("import {SHARED_PACKAGES_MODULE}\n"),
+        )?;
+        Ok(())
+    }
+}
+
+/// The name and version of the installed distribution that provides the top-level module.
+pub(crate) fn module_distribution(
+    environment: &PythonEnvironment,
+    module: &str,
+) -> Option<(PackageName, Version)> {
+    let installed = installed_distributions(environment).ok()?;
+    installed
+        .into_values()
+        .sorted_by(|a, b| a.name().cmp(b.name()))
+        .find(|dist| {
+            top_level_modules(dist.install_path()).is_ok_and(|modules| modules.contains(module))
+        })
+        .map(|dist| (dist.name().clone(), dist.version().clone()))
+}
+
+/// The distributions installed in the environment, by name.
+fn installed_distributions(
+    environment: &PythonEnvironment,
+) -> Result<FxHashMap<PackageName, InstalledDist>, Error> {
+    let mut distributions = FxHashMap::default();
+    for site_packages in environment.site_packages() {
+        let entries = match fs::read_dir(site_packages.as_ref()) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if let Some(dist) =
+                InstalledDist::try_from_path(&path).map_err(Error::SharedPackageMetadata)?
+            {
+                distributions.entry(dist.name().clone()).or_insert(dist);
+            }
+        }
+    }
+    Ok(distributions)
+}
+
+/// The top-level modules of an installed distribution, from the `top_level.txt` or the `RECORD`
+/// in its metadata directory.
+fn top_level_modules(metadata_dir: &Path) -> Result<BTreeSet<String>, Error> {
+    match fs::read_to_string(metadata_dir.join("top_level.txt")) {
+        Ok(top_level) => {
+            return Ok(top_level
+                .lines()
+                .map(str::trim)
+                .filter(|module| !module.is_empty())
+                .map(ToString::to_string)
+                .collect());
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let record = match fs::read_to_string(metadata_dir.join("RECORD")) {
+        Ok(record) => record,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+        Err(err) => return Err(err.into()),
+    };
+    Ok(record
+        .lines()
+        .filter_map(|line| {
+            let path = line.split(',').next()?;
+            let (top_level, rest) = path.split_once('/').unwrap_or((path, ""));
+            if top_level.starts_with('.')
+                || top_level == "__pycache__"
+                || Path::new(top_level)
+                    .extension()
+                    .is_some_and(|ext| ext == "dist-info" || ext == "data" || ext == "pth")
+            {
+                return None;
+            }
+            if rest.is_empty() {
+                // A single-file module, e.g., `six.py` or `_cffi_backend.cpython-312-darwin.so`.
+                let (module, _extension) = top_level.split_once('.')?;
+                Some(module.to_string())
+            } else {
+                Some(top_level.to_string())
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn record_top_level_modules() {
+        let metadata_dir = TempDir::new().unwrap();
+        fs::write(
+            metadata_dir.path().join("RECORD"),
+            indoc::indoc! {"
+                torch/__init__.py,sha256=abc,123
+                torch/nn/__init__.py,sha256=abc,123
+                functorch/__init__.py,sha256=abc,123
+                _cffi_backend.cpython-312-x86_64-linux-gnu.so,sha256=abc,123
+                six.py,sha256=abc,123
+                distutils-precedence.pth,sha256=abc,123
+                torch-2.7.0.dist-info/METADATA,sha256=abc,123
+                torch-2.7.0.data/scripts/torchrun,sha256=abc,123
+                ../../../bin/torchrun,sha256=abc,123
+                __pycache__/six.cpython-312.pyc,,
+            "},
+        )
+        .unwrap();
+        let modules = top_level_modules(metadata_dir.path()).unwrap();
+        assert_eq!(
+            modules.into_iter().collect::<Vec<_>>(),
+            ["_cffi_backend", "functorch", "six", "torch"]
+        );
+    }
+}
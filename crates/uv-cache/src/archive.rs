@@ -1,6 +1,9 @@
 use std::path::Path;
 use std::str::FromStr;
 
+use uv_cache_key::{CanonicalUrl, cache_digest};
+use uv_distribution_types::IndexUrl;
+
 /// A unique identifier for an archive (unzipped wheel) in the cache.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct ArchiveId(String);
@@ -16,6 +19,21 @@ impl ArchiveId {
     pub fn new() -> Self {
         Self(nanoid::nanoid!())
     }
+
+    /// Derive a content-addressed identifier for an archive from the SHA-256 digest of the wheel
+    /// it was unpacked from.
+    pub fn from_sha256(digest: &str) -> Self {
+        Self(format!("sha256-{digest}"))
+    }
+
+    /// Derive a content-addressed identifier for an archive from the SHA-256 digest of the wheel
+    /// it was unpacked from, scoped to the index from which the wheel was fetched.
+    pub fn from_sha256_in_index(digest: &str, index: &IndexUrl) -> Self {
+        Self(format!(
+            "sha256-{digest}-{}",
+            cache_digest(&CanonicalUrl::new(index.url()))
+        ))
+    }
 }
 
 impl AsRef<Path> for ArchiveId {
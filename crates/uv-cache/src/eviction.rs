@@ -0,0 +1,203 @@
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+
+use uv_static::EnvVars;
+
+use crate::removal::{Pruner, Removal};
+use crate::units::{ArchiveUsage, disk_usage};
+use crate::{Cache, CacheBucket};
+
+/// The relative weights used to prioritize cache entries for eviction when the cache exceeds its
+/// maximum size.
+///
+/// Entries are evicted in order of their weighted age (i.e., the time since the entry was last
+/// used, multiplied by the weight of the entry's kind), such that entries with a higher weight are
+/// evicted sooner. Entries with a weight of `0` are never evicted.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CacheEvictionWeights {
+    /// The weight for pre-built wheels downloaded from an index or URL.
+    pub wheels: f64,
+    /// The weight for source distributions, along with the wheels built from them.
+    ///
+    /// Defaults to `0.5`, as evicted source distributions must be rebuilt.
+    pub source_distributions: f64,
+    /// The weight for Python interpreter archives cached in `UV_PYTHON_CACHE_DIR`.
+    pub python: f64,
+}
+
+impl Default for CacheEvictionWeights {
+    fn default() -> Self {
+        Self {
+            wheels: 1.0,
+            source_distributions: 0.5,
+            python: 1.0,
+        }
+    }
+}
+
+/// An entry that can be evicted from the cache as a unit.
+#[derive(Debug)]
+struct Candidate {
+    /// The path to the entry.
+    path: PathBuf,
+    /// The size of the entry, in bytes, excluding any referenced archives.
+    size: u64,
+    /// The time at which the entry, or any archive it references, was last used.
+    last_used: SystemTime,
+    /// The weight of the entry.
+    weight: f64,
+    /// The archives referenced by the entry.
+    archives: Vec<PathBuf>,
+}
+
+impl Candidate {
+    /// Return the weighted age of the entry, in seconds.
+    fn weighted_age(&self, now: SystemTime) -> f64 {
+        let age = now
+            .duration_since(self.last_used)
+            .unwrap_or(Duration::ZERO)
+            .as_secs_f64();
+        age * self.weight
+    }
+}
+
+impl Cache {
+    /// Return the total size of the cache, in bytes, including any buckets that are routed to
+    /// another root and any Python interpreter archives cached in `UV_PYTHON_CACHE_DIR`.
+    pub fn size(&self) -> Result<u64, io::Error> {
+        let (mut size, _) = disk_usage(&self.root)?;
+        for (bucket, _) in self.roots().iter() {
+            size += disk_usage(&self.bucket(bucket))?.0;
+        }
+        let python = match python_cache_dir() {
+            Some(dir) => disk_usage(&dir)?.0,
+            None => 0,
+        };
+        Ok(size + python)
+    }
+
+    /// Evict the least-recently-used wheels, source distributions, and Python interpreter archives
+    /// from the cache until its total size is at most `max_size` bytes.
+    ///
+    /// Entries are prioritized by their weighted age, per the cache's [`CacheEvictionWeights`].
+    /// Archives are removed once they're no longer referenced by any remaining entry.
+    pub fn evict(&self, max_size: u64, pruner: &mut Pruner) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+
+        let mut size = self.size()?.saturating_sub(pruner.pending_bytes());
+        if size <= max_size {
+            return Ok(summary);
+        }
+
+        debug!(
+            "Cache size ({size} bytes) exceeds the maximum ({max_size} bytes); evicting entries"
+        );
+
+        let ArchiveUsage {
+            mut units,
+            mut archives,
+        } = self.archive_usage(pruner)?;
+
+        let mut candidates = Vec::new();
+        for (bucket, weight) in [
+            (CacheBucket::Wheels, self.eviction_weights.wheels),
+            (
+                CacheBucket::SourceDistributions,
+                self.eviction_weights.source_distributions,
+            ),
+        ] {
+            if weight <= 0.0 {
+                continue;
+            }
+            for path in self.units(bucket)? {
+                if pruner.is_removed(&path) {
+                    continue;
+                }
+                let (size, mut last_used) = disk_usage(&path)?;
+                let unit_archives = units.remove(&path).unwrap_or_default();
+                for archive in &unit_archives {
+                    if let Some(archive) = archives.get(archive) {
+                        last_used = last_used.max(archive.last_used);
+                    }
+                }
+                candidates.push(Candidate {
+                    path,
+                    size,
+                    last_used,
+                    weight,
+                    archives: unit_archives,
+                });
+            }
+        }
+
+        if self.eviction_weights.python > 0.0 {
+            if let Some(dir) = python_cache_dir() {
+                for path in uv_fs::files(&dir)? {
+                    if pruner.is_removed(&path) {
+                        continue;
+                    }
+                    let (size, last_used) = disk_usage(&path)?;
+                    candidates.push(Candidate {
+                        path,
+                        size,
+                        last_used,
+                        weight: self.eviction_weights.python,
+                        archives: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        // Evict the entries with the greatest weighted age first.
+        let now = SystemTime::now();
+        candidates.sort_by(|a, b| {
+            b.weighted_age(now)
+                .total_cmp(&a.weighted_age(now))
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        for candidate in candidates {
+            if size <= max_size {
+                break;
+            }
+
+            debug!("Evicting cache entry: {}", candidate.path.display());
+            summary += pruner.rm_rf(&candidate.path)?;
+            size = size.saturating_sub(candidate.size);
+
+            for archive in candidate.archives {
+                let Some(entry) = archives.get_mut(&archive) else {
+                    continue;
+                };
+                entry.references = entry.references.saturating_sub(1);
+                if entry.references == 0 {
+                    debug!("Removing unreferenced cache archive: {}", archive.display());
+                    summary += pruner.rm_rf(&archive)?;
+                    size = size.saturating_sub(entry.size);
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Evict entries from the cache until it's within the configured maximum size, if any.
+    pub fn enforce_max_size(&self) -> Result<Removal, io::Error> {
+        match self.max_size {
+            Some(max_size) if !self.is_temporary() => self.evict(max_size, &mut Pruner::default()),
+            _ => Ok(Removal::default()),
+        }
+    }
+}
+
+/// Return the directory in which Python interpreter archives are cached, if any.
+pub(crate) fn python_cache_dir() -> Option<PathBuf> {
+    std::env::var_os(EnvVars::UV_PYTHON_CACHE_DIR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
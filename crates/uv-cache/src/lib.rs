@@ -11,6 +11,7 @@ use tracing::debug;
 
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
+use uv_distribution_types::IndexUrl;
 use uv_fs::{LockedFile, cachedir, directories};
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
@@ -18,8 +19,14 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::eviction::CacheEvictionWeights;
+pub use crate::prune::{PruneBucket, PruneFilter};
+pub use crate::remote::{RemoteCache, RemoteCacheBackend, RemoteCacheError};
 use crate::removal::Remover;
-pub use crate::removal::{Removal, rm_rf};
+pub use crate::removal::{Pruner, Removal, rm_rf};
+pub use crate::roots::CacheRoots;
+pub use crate::stats::{AgeStats, BucketStats, CacheRun, CacheStats, PackageStats, RunStats};
+pub use crate::transfer::{ExportSummary, ImportSummary};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -27,7 +34,14 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod eviction;
+mod prune;
+mod remote;
 mod removal;
+mod roots;
+mod stats;
+mod transfer;
+mod units;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -146,6 +160,17 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The maximum size of the cache, in bytes, beyond which entries should be evicted.
+    max_size: Option<u64>,
+    /// The weights used to prioritize entries for eviction.
+    eviction_weights: CacheEvictionWeights,
+    /// The remote cache to consult before building source distributions, if any.
+    remote: Option<RemoteCache>,
+    /// Whether to isolate the artifacts fetched from each index, such that they're never shared
+    /// with other indexes.
+    index_isolation: bool,
+    /// The directories to which individual buckets are routed, in lieu of the cache root.
+    roots: CacheRoots,
 }
 
 impl Cache {
@@ -155,6 +180,11 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            max_size: None,
+            eviction_weights: CacheEvictionWeights::default(),
+            remote: None,
+            index_isolation: false,
+            roots: CacheRoots::default(),
         }
     }
 
@@ -165,6 +195,11 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            max_size: None,
+            eviction_weights: CacheEvictionWeights::default(),
+            remote: None,
+            index_isolation: false,
+            roots: CacheRoots::default(),
         })
     }
 
@@ -174,6 +209,47 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the maximum size of the cache, in bytes.
+    #[must_use]
+    pub fn with_max_size(self, max_size: Option<u64>) -> Self {
+        Self { max_size, ..self }
+    }
+
+    /// Set the [`CacheEvictionWeights`] used when the cache exceeds its maximum size.
+    #[must_use]
+    pub fn with_eviction_weights(self, eviction_weights: CacheEvictionWeights) -> Self {
+        Self {
+            eviction_weights,
+            ..self
+        }
+    }
+
+    /// Set the [`RemoteCache`] to consult before building source distributions.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<RemoteCache>) -> Self {
+        Self { remote, ..self }
+    }
+
+    /// Set whether to isolate the artifacts fetched from each index.
+    #[must_use]
+    pub fn with_index_isolation(self, index_isolation: bool) -> Self {
+        Self {
+            index_isolation,
+            ..self
+        }
+    }
+
+    /// Set the [`CacheRoots`] to which individual buckets are routed.
+    ///
+    /// Ignored for temporary caches, which are always stored in a single directory.
+    #[must_use]
+    pub fn with_roots(self, roots: CacheRoots) -> Self {
+        if self.is_temporary() {
+            return self;
+        }
+        Self { roots, ..self }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -184,9 +260,35 @@ impl Cache {
         &self.refresh
     }
 
+    /// Return the maximum size of the cache, in bytes, if any.
+    pub fn max_size(&self) -> Option<u64> {
+        self.max_size
+    }
+
+    /// Return the [`RemoteCache`] to consult before building source distributions, if any.
+    pub fn remote(&self) -> Option<&RemoteCache> {
+        self.remote.as_ref()
+    }
+
+    /// Returns `true` if the artifacts fetched from each index are isolated from other indexes.
+    pub fn index_isolation(&self) -> bool {
+        self.index_isolation
+    }
+
+    /// Return the [`CacheRoots`] to which individual buckets are routed.
+    pub fn roots(&self) -> &CacheRoots {
+        &self.roots
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
-        self.root.join(cache_bucket.to_str())
+        self.bucket_root(cache_bucket).join(cache_bucket.to_str())
+    }
+
+    /// Return the root directory under which the given bucket is stored, i.e., the cache root,
+    /// unless the bucket is routed elsewhere.
+    pub fn bucket_root(&self, cache_bucket: CacheBucket) -> &Path {
+        self.roots.get(cache_bucket).unwrap_or(&self.root)
     }
 
     /// Compute an entry in the cache.
@@ -210,15 +312,27 @@ impl Cache {
     }
 
     /// Create a temporary directory to be used as a Python virtual environment.
+    ///
+    /// The directory is created alongside the archive bucket, into which cached environments are
+    /// persisted.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
-        fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
-        tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
+        let builds = self
+            .bucket_root(CacheBucket::Archive)
+            .join(CacheBucket::Builds.to_str());
+        fs_err::create_dir_all(&builds)?;
+        tempfile::tempdir_in(builds)
     }
 
     /// Create a temporary directory to be used for executing PEP 517 source distribution builds.
+    ///
+    /// The directory is created alongside the source distribution bucket, into which built wheels
+    /// are persisted.
     pub fn build_dir(&self) -> io::Result<tempfile::TempDir> {
-        fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
-        tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
+        let builds = self
+            .bucket_root(CacheBucket::SourceDistributions)
+            .join(CacheBucket::Builds.to_str());
+        fs_err::create_dir_all(&builds)?;
+        tempfile::tempdir_in(builds)
     }
 
     /// Returns `true` if a cache entry must be revalidated given the [`Refresh`] policy.
@@ -290,7 +404,6 @@ impl Cache {
         path: impl AsRef<Path>,
     ) -> io::Result<ArchiveId> {
         // Create a unique ID for the artifact.
-        // TODO(charlie): Support content-addressed persistence via SHAs.
         let id = ArchiveId::new();
 
         // Move the temporary directory into the directory store.
@@ -305,6 +418,79 @@ impl Cache {
         Ok(id)
     }
 
+    /// Persist a temporary directory to the artifact store, keyed by the SHA-256 digest of the
+    /// wheel it was unpacked from.
+    ///
+    /// If the store already contains an archive with the same digest, the temporary directory is
+    /// discarded in favor of the existing archive, such that identical wheels (e.g., fetched from
+    /// different indexes, or referenced by both a URL and a local path) share a single unpacked
+    /// copy, which is in turn linked into each environment.
+    ///
+    /// If index isolation is enabled, wheels fetched from an index are only shared with other
+    /// wheels fetched from the same index.
+    pub async fn persist_content_addressed(
+        &self,
+        temp_dir: impl AsRef<Path>,
+        path: impl AsRef<Path>,
+        sha256: &str,
+        index: Option<&IndexUrl>,
+    ) -> io::Result<ArchiveId> {
+        let id = self.content_addressed_id(sha256, index);
+
+        // Move the temporary directory into the directory store, unless it's already present.
+        let archive_entry = self.entry(CacheBucket::Archive, "", &id);
+        fs_err::create_dir_all(archive_entry.dir())?;
+        if archive_entry.path().is_dir() {
+            debug!(
+                "Reusing existing archive: {}",
+                archive_entry.path().display()
+            );
+            fs_err::tokio::remove_dir_all(temp_dir.as_ref()).await?;
+        } else {
+            match uv_fs::rename_with_retry(temp_dir.as_ref(), archive_entry.path()).await {
+                Ok(()) => {}
+                // Another process may have persisted the same archive concurrently.
+                Err(_) if archive_entry.path().is_dir() => {
+                    debug!(
+                        "Reusing existing archive: {}",
+                        archive_entry.path().display()
+                    );
+                    fs_err::tokio::remove_dir_all(temp_dir.as_ref()).await?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        // Create a symlink to the directory store.
+        fs_err::create_dir_all(path.as_ref().parent().expect("Cache entry to have parent"))?;
+        self.create_link(&id, path.as_ref())?;
+
+        Ok(id)
+    }
+
+    /// Return the ID of the archive in the store that was unpacked from a wheel with the given
+    /// SHA-256 digest, if any.
+    ///
+    /// Since content-addressed archives are only persisted with digests computed from the wheel
+    /// itself, a match guarantees that the archive is identical to the wheel, regardless of the
+    /// source from which it was originally fetched.
+    pub fn find_content_addressed(
+        &self,
+        sha256: &str,
+        index: Option<&IndexUrl>,
+    ) -> Option<ArchiveId> {
+        let id = self.content_addressed_id(sha256, index);
+        self.archive(&id).is_dir().then_some(id)
+    }
+
+    /// Derive the content-addressed [`ArchiveId`] for a wheel with the given SHA-256 digest.
+    fn content_addressed_id(&self, sha256: &str, index: Option<&IndexUrl>) -> ArchiveId {
+        match index {
+            Some(index) if self.index_isolation => ArchiveId::from_sha256_in_index(sha256, index),
+            _ => ArchiveId::from_sha256(sha256),
+        }
+    }
+
     /// Returns `true` if the [`Cache`] is temporary.
     pub fn is_temporary(&self) -> bool {
         self.temp_dir.is_some()
@@ -312,36 +498,24 @@ impl Cache {
 
     /// Initialize the [`Cache`].
     pub fn init(self) -> Result<Self, io::Error> {
-        let root = &self.root;
-
-        // Create the cache directory, if it doesn't exist.
-        fs_err::create_dir_all(root)?;
+        let roots = self.roots.clone().absolute()?;
 
-        // Add the CACHEDIR.TAG.
-        cachedir::ensure_tag(root)?;
-
-        // Add the .gitignore.
-        match fs_err::OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(root.join(".gitignore"))
-        {
-            Ok(mut file) => file.write_all(b"*")?,
-            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => (),
-            Err(err) => return Err(err),
+        // Create the cache directory, along with the directory for any routed buckets.
+        init_root(&self.root)?;
+        for (_, root) in roots.iter() {
+            init_root(root)?;
         }
 
         // Add an empty .gitignore to the build bucket, to ensure that the cache's own .gitignore
         // doesn't interfere with source distribution builds. Build backends (like hatchling) will
         // traverse upwards to look for .gitignore files.
-        fs_err::create_dir_all(root.join(CacheBucket::SourceDistributions.to_str()))?;
+        let sdists = self.bucket(CacheBucket::SourceDistributions);
+        fs_err::create_dir_all(&sdists)?;
         match fs_err::OpenOptions::new()
             .write(true)
             .create_new(true)
-            .open(
-                root.join(CacheBucket::SourceDistributions.to_str())
-                    .join(".gitignore"),
-            ) {
+            .open(sdists.join(".gitignore"))
+        {
             Ok(_) => {}
             Err(err) if err.kind() == io::ErrorKind::AlreadyExists => (),
             Err(err) => return Err(err),
@@ -354,20 +528,27 @@ impl Cache {
         // We have to put this below the gitignore. Otherwise, if the build backend uses the rust
         // ignore crate it will walk up to the top level .gitignore and ignore its python source
         // files.
-        fs_err::OpenOptions::new().create(true).write(true).open(
-            root.join(CacheBucket::SourceDistributions.to_str())
-                .join(".git"),
-        )?;
+        fs_err::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(sdists.join(".git"))?;
 
         Ok(Self {
-            root: std::path::absolute(root)?,
+            root: std::path::absolute(&self.root)?,
+            roots,
             ..self
         })
     }
 
     /// Clear the cache, removing all entries.
     pub fn clear(&self, reporter: Box<dyn CleanReporter>) -> Result<Removal, io::Error> {
-        Remover::new(reporter).rm_rf(&self.root)
+        let remover = Remover::new(reporter);
+        let mut summary = Removal::default();
+        for (bucket, _) in self.roots.iter() {
+            summary += remover.rm_rf(self.bucket(bucket))?;
+        }
+        summary += remover.rm_rf(&self.root)?;
+        Ok(summary)
     }
 
     /// Remove a package from the cache.
@@ -394,8 +575,32 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Return the cached Simple API responses for a package, across all indexes.
+    pub fn simple_entries(&self, name: &PackageName) -> Result<Vec<PathBuf>, io::Error> {
+        self.simple_files(&format!("{name}.rkyv"))
+    }
+
+    /// Return the cached markers for indexes on which a package was recently not found.
+    pub fn simple_not_found_entries(&self, name: &PackageName) -> Result<Vec<PathBuf>, io::Error> {
+        self.simple_files(&format!("{name}.not-found"))
+    }
+
+    /// Return the files with the given name in the Simple API bucket, across all indexes.
+    fn simple_files(&self, filename: &str) -> Result<Vec<PathBuf>, io::Error> {
+        let bucket = self.bucket(CacheBucket::Simple);
+
+        // For `pypi`, we expect a rkyv file per package, indexed by name. For alternate indices,
+        // we expect a directory for every index (under an `index` subdirectory).
+        let mut entries = vec![bucket.join(WheelCacheKind::Pypi).join(filename)];
+        for directory in directories(bucket.join(WheelCacheKind::Index))? {
+            entries.push(directory.join(filename));
+        }
+        entries.retain(|entry| entry.is_file());
+        Ok(entries)
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
-    pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
+    pub fn prune(&self, ci: bool, pruner: &mut Pruner) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
 
         // First, remove any top-level directories that are unused. These typically represent
@@ -416,13 +621,13 @@ impl Cache {
                 if CacheBucket::iter().all(|bucket| entry.file_name() != bucket.to_str()) {
                     let path = entry.path();
                     debug!("Removing dangling cache bucket: {}", path.display());
-                    summary += rm_rf(path)?;
+                    summary += pruner.rm_rf(path)?;
                 }
             } else {
                 // If the file is not a marker file, remove it.
                 let path = entry.path();
                 debug!("Removing dangling cache bucket: {}", path.display());
-                summary += rm_rf(path)?;
+                summary += pruner.rm_rf(path)?;
             }
         }
 
@@ -434,7 +639,7 @@ impl Cache {
                     let entry = entry?;
                     let path = fs_err::canonicalize(entry.path())?;
                     debug!("Removing dangling cache environment: {}", path.display());
-                    summary += rm_rf(path)?;
+                    summary += pruner.rm_rf(path)?;
                 }
             }
             Err(err) if err.kind() == io::ErrorKind::NotFound => (),
@@ -451,7 +656,7 @@ impl Cache {
                         let path = fs_err::canonicalize(entry.path())?;
                         if path.is_dir() {
                             debug!("Removing unzipped wheel entry: {}", path.display());
-                            summary += rm_rf(path)?;
+                            summary += pruner.rm_rf(path)?;
                         }
                     }
                 }
@@ -459,20 +664,38 @@ impl Cache {
                 Err(err) => return Err(err),
             }
 
-            for entry in walkdir::WalkDir::new(self.bucket(CacheBucket::SourceDistributions)) {
+            // Collect the built wheel shards up-front, since pruning them modifies the tree. Skip
+            // the unzipped source distributions (`src`), which are removed below.
+            let mut shards = Vec::new();
+            let walker = walkdir::WalkDir::new(self.bucket(CacheBucket::SourceDistributions))
+                .into_iter()
+                .filter_entry(|entry| entry.file_name() != "src");
+            for entry in walker {
                 let entry = entry?;
-
-                // If the directory contains a `metadata.msgpack`, then it's a built wheel revision.
-                if !entry.file_type().is_dir() {
-                    continue;
+                if entry.file_type().is_dir() && is_built_wheel_shard(entry.path()) {
+                    shards.push(entry.into_path());
                 }
+            }
 
-                if !entry.path().join("metadata.msgpack").exists() {
-                    continue;
+            for shard in shards {
+                // Remove the unzipped source distribution, which is stored within the revision,
+                // alongside the shards for each build environment.
+                for source in [
+                    shard.join("src"),
+                    shard.parent().unwrap_or(&shard).join("src"),
+                ] {
+                    if source.is_dir() {
+                        debug!(
+                            "Removing unzipped source distribution: {}",
+                            source.display()
+                        );
+                        summary += pruner.rm_rf(source)?;
+                    }
                 }
 
-                // Remove everything except the built wheel archive and the metadata.
-                for entry in fs_err::read_dir(entry.path())? {
+                // Remove everything except the built wheel archives, the metadata, and any nested
+                // shards.
+                for entry in fs_err::read_dir(&shard)? {
                     let entry = entry?;
                     let path = entry.path();
 
@@ -492,13 +715,32 @@ impl Cache {
                         continue;
                     }
 
+                    // Retain the shards for each build environment, which are pruned separately.
+                    if entry.file_type()?.is_dir() && is_built_wheel_shard(&path) {
+                        continue;
+                    }
+
                     debug!("Removing unzipped built wheel entry: {}", path.display());
-                    summary += rm_rf(path)?;
+                    summary += pruner.rm_rf(path)?;
                 }
             }
         }
 
-        // Fourth, remove any unused archives (by searching for archives that are not symlinked).
+        // Fourth, remove any unused archives.
+        summary += self.prune_archives(pruner)?;
+
+        Ok(summary)
+    }
+
+    /// Remove any unused archives from the artifact store (i.e., archives that are not referenced
+    /// by an entry in any other cache bucket).
+    ///
+    /// Environments never reference archives directly (outside of the `symlink` link mode), so
+    /// removing an archive doesn't affect any existing installations.
+    pub fn prune_archives(&self, pruner: &mut Pruner) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+
+        // Search for archives that are not symlinked.
         let references = self.find_archive_references()?;
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
@@ -506,9 +748,12 @@ impl Cache {
                 for entry in entries {
                     let entry = entry?;
                     let path = fs_err::canonicalize(entry.path())?;
-                    if !references.contains_key(&path) {
+                    let referenced = references
+                        .get(&path)
+                        .is_some_and(|links| links.iter().any(|link| !pruner.is_removed(link)));
+                    if !referenced {
                         debug!("Removing dangling cache archive: {}", path.display());
-                        summary += rm_rf(path)?;
+                        summary += pruner.rm_rf(path)?;
                     }
                 }
             }
@@ -671,6 +916,28 @@ impl Cache {
     }
 }
 
+/// Create a cache root, marking it with a `CACHEDIR.TAG` and a `.gitignore`.
+fn init_root(root: &Path) -> Result<(), io::Error> {
+    // Create the cache directory, if it doesn't exist.
+    fs_err::create_dir_all(root)?;
+
+    // Add the CACHEDIR.TAG.
+    cachedir::ensure_tag(root)?;
+
+    // Add the .gitignore.
+    match fs_err::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(root.join(".gitignore"))
+    {
+        Ok(mut file) => file.write_all(b"*")?,
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => (),
+        Err(err) => return Err(err),
+    }
+
+    Ok(())
+}
+
 /// An archive (unzipped wheel) that exists in the local cache.
 #[derive(Debug, Clone)]
 #[allow(unused)]
@@ -918,6 +1185,10 @@ pub enum CacheBucket {
     /// Structurally, the `manifest.msgpack` is empty, and only contains the caching information
     /// needed to invalidate the cache. The `metadata.msgpack` contains the metadata of the source
     /// distribution.
+    ///
+    /// Within each revision, the built wheels and metadata are further scoped to a shard per
+    /// build environment, keyed by a digest of the build settings, build constraints, target
+    /// interpreter, and compiler toolchain, alongside the unzipped source distribution (`src`).
     SourceDistributions,
     /// Flat index responses, a format very similar to the simple metadata API.
     ///
@@ -985,6 +1256,14 @@ pub enum CacheBucket {
     Builds,
     /// Reusable virtual environments used to invoke Python tools.
     Environments,
+    /// Usage statistics, such as the cache hits and misses of recent runs.
+    ///
+    /// Cache structure: `stats-v0/runs.jsonl`
+    Stats,
+    /// Known vulnerabilities of a package version, as returned by the OSV API.
+    ///
+    /// Cache structure: `advisories-v0/<package_name>/<version>.json`
+    Advisories,
 }
 
 impl CacheBucket {
@@ -992,7 +1271,7 @@ impl CacheBucket {
         match self {
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_prune.rs`.
-            Self::SourceDistributions => "sdists-v9",
+            Self::SourceDistributions => "sdists-v10",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
             Self::Interpreter => "interpreter-v4",
@@ -1007,6 +1286,8 @@ impl CacheBucket {
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
             Self::Environments => "environments-v2",
+            Self::Stats => "stats-v0",
+            Self::Advisories => "advisories-v0",
         }
     }
 
@@ -1014,17 +1295,6 @@ impl CacheBucket {
     ///
     /// Returns the number of entries removed from the cache.
     fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
-        /// Returns `true` if the [`Path`] represents a built wheel for the given package.
-        fn is_match(path: &Path, name: &PackageName) -> bool {
-            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
-                return false;
-            };
-            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
-                return false;
-            };
-            metadata.name == *name
-        }
-
         let mut summary = Removal::default();
         match self {
             Self::Wheels => {
@@ -1091,15 +1361,18 @@ impl CacheBucket {
                 }
             }
             Self::Simple => {
-                // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
+                // For `pypi` wheels, we expect a rkyv file per package, indexed by name, along
+                // with a marker for packages that were recently not found.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(format!("{name}.rkyv")))?;
+                summary += rm_rf(root.join(format!("{name}.not-found")))?;
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
                     summary += rm_rf(directory.join(format!("{name}.rkyv")))?;
+                    summary += rm_rf(directory.join(format!("{name}.not-found")))?;
                 }
             }
             Self::FlatIndex => {
@@ -1108,7 +1381,17 @@ impl CacheBucket {
                 let root = cache.bucket(self);
                 summary += rm_rf(root)?;
             }
-            Self::Git | Self::Interpreter | Self::Archive | Self::Builds | Self::Environments => {
+            Self::Advisories => {
+                // For advisories, we expect a directory per package (indexed by name).
+                let root = cache.bucket(self);
+                summary += rm_rf(root.join(name.to_string()))?;
+            }
+            Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Stats => {
                 // Nothing to do.
             }
         }
@@ -1127,12 +1410,53 @@ impl CacheBucket {
             Self::Archive,
             Self::Builds,
             Self::Environments,
+            Self::Stats,
+            Self::Advisories,
         ]
         .iter()
         .copied()
     }
 }
 
+/// Returns `true` if the directory contains built wheels or their metadata (i.e., it's a revision
+/// of a source distribution, or the shard for a build environment within a revision).
+fn is_built_wheel_shard(path: &Path) -> bool {
+    if path.join("metadata.msgpack").is_file() {
+        return true;
+    }
+    fs_err::read_dir(path).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("whl"))
+                && entry.file_type().is_ok_and(|file_type| file_type.is_file())
+        })
+    })
+}
+
+/// Returns `true` if the [`Path`] represents a built wheel for the given package.
+fn is_match(path: &Path, name: &PackageName) -> bool {
+    read_package_name(path).is_some_and(|package| package == *name)
+}
+
+/// Read the package name from the `metadata.msgpack` file in the given directory, or in any of
+/// its immediate subdirectories (i.e., the shards for each build environment), if any.
+fn read_package_name(path: &Path) -> Option<PackageName> {
+    read_metadata_name(path).or_else(|| {
+        directories(path)
+            .ok()?
+            .find_map(|shard| read_metadata_name(&shard))
+    })
+}
+
+/// Read the package name from the `metadata.msgpack` file in the given directory, if any.
+fn read_metadata_name(path: &Path) -> Option<PackageName> {
+    let metadata = fs_err::read(path.join("metadata.msgpack")).ok()?;
+    let metadata = rmp_serde::from_slice::<ResolutionMetadata>(&metadata).ok()?;
+    Some(metadata.name)
+}
+
 impl Display for CacheBucket {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.to_str())
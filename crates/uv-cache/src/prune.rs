@@ -0,0 +1,184 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+
+use uv_fs::directories;
+use uv_normalize::PackageName;
+
+use crate::removal::{Pruner, Removal};
+use crate::units::disk_usage;
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket, is_match};
+
+/// A cache bucket that can be selected for pruning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum PruneBucket {
+    /// Pre-built wheels, as downloaded from an index or URL.
+    Wheels,
+    /// Source distributions, along with the wheels built from them.
+    Sdists,
+    /// Cached Python interpreter metadata.
+    Interpreters,
+    /// Cached package index responses.
+    Simple,
+}
+
+impl PruneBucket {
+    /// Return an iterator over all prunable buckets.
+    fn iter() -> impl Iterator<Item = Self> {
+        [Self::Wheels, Self::Sdists, Self::Interpreters, Self::Simple].into_iter()
+    }
+}
+
+/// Filters that select cache entries for removal, regardless of whether they're still in use.
+///
+/// An entry is selected if it matches every filter that's provided.
+#[derive(Debug, Default, Clone)]
+pub struct PruneFilter {
+    /// Only select entries that haven't been used within the given duration.
+    pub older_than: Option<Duration>,
+    /// Only select entries for the given packages.
+    pub packages: Vec<PackageName>,
+    /// Only select entries within the given buckets. If empty, all buckets are selected.
+    pub buckets: Vec<PruneBucket>,
+}
+
+impl PruneFilter {
+    /// Returns `true` if no filters are provided.
+    pub fn is_empty(&self) -> bool {
+        self.older_than.is_none() && self.packages.is_empty() && self.buckets.is_empty()
+    }
+}
+
+impl Cache {
+    /// Remove any cache entries that match the given [`PruneFilter`], followed by any archives that
+    /// are no longer referenced.
+    ///
+    /// Entries are removed at the granularity of a package (e.g., all wheels for a package from a
+    /// given index), and are considered used whenever any of their files (or the files in any
+    /// archive they reference) were last accessed or modified.
+    pub fn prune_matching(
+        &self,
+        filter: &PruneFilter,
+        pruner: &mut Pruner,
+    ) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+
+        if filter.is_empty() {
+            return Ok(summary);
+        }
+
+        let now = SystemTime::now();
+        let usage = self.archive_usage(pruner)?;
+
+        let buckets = if filter.buckets.is_empty() {
+            PruneBucket::iter().collect::<Vec<_>>()
+        } else {
+            filter.buckets.clone()
+        };
+
+        for bucket in buckets {
+            for entry in self.prune_entries(bucket)? {
+                if pruner.is_removed(&entry) {
+                    continue;
+                }
+
+                if !filter.packages.is_empty()
+                    && !filter
+                        .packages
+                        .iter()
+                        .any(|package| is_package_entry(bucket, &entry, package))
+                {
+                    continue;
+                }
+
+                if let Some(older_than) = filter.older_than {
+                    let (_, last_used) = disk_usage(&entry)?;
+                    let last_used = usage.last_used(&entry, last_used);
+                    let age = now.duration_since(last_used).unwrap_or(Duration::ZERO);
+                    if age < older_than {
+                        continue;
+                    }
+                }
+
+                debug!("Removing cache entry: {}", entry.display());
+                summary += pruner.rm_rf(&entry)?;
+            }
+        }
+
+        // Remove any archives that are no longer referenced.
+        summary += self.prune_archives(pruner)?;
+
+        Ok(summary)
+    }
+
+    /// Return the entries within a [`PruneBucket`] that can be removed independently.
+    fn prune_entries(&self, bucket: PruneBucket) -> Result<Vec<PathBuf>, io::Error> {
+        match bucket {
+            PruneBucket::Wheels => self.units(CacheBucket::Wheels),
+            PruneBucket::Sdists => self.units(CacheBucket::SourceDistributions),
+            PruneBucket::Interpreters => {
+                Ok(directories(self.bucket(CacheBucket::Interpreter))?.collect())
+            }
+            PruneBucket::Simple => {
+                // We expect a file per package (e.g., `pypi/{package}.rkyv` or
+                // `index/{digest}/{package}.rkyv`).
+                let root = self.bucket(CacheBucket::Simple);
+                if !root.is_dir() {
+                    return Ok(Vec::new());
+                }
+                let mut entries = Vec::new();
+                for entry in walkdir::WalkDir::new(root) {
+                    let entry = entry?;
+                    if entry.file_type().is_file()
+                        && entry
+                            .path()
+                            .extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("rkyv"))
+                    {
+                        entries.push(entry.into_path());
+                    }
+                }
+                Ok(entries)
+            }
+        }
+    }
+}
+
+/// Returns `true` if the cache entry within the given [`PruneBucket`] belongs to the given package.
+pub(crate) fn is_package_entry(bucket: PruneBucket, entry: &Path, package: &PackageName) -> bool {
+    match bucket {
+        // Wheel entries are always keyed by package name.
+        PruneBucket::Wheels => entry
+            .file_name()
+            .is_some_and(|name| name == package.as_str()),
+        PruneBucket::Sdists => {
+            // Registry entries are keyed by package name, while entries for direct URLs, local
+            // paths, and Git dependencies require a search for a built wheel that matches the
+            // package name.
+            let registry = entry.parent().is_some_and(|parent| {
+                parent.ends_with(WheelCacheKind::Pypi)
+                    || parent
+                        .parent()
+                        .is_some_and(|parent| parent.ends_with(WheelCacheKind::Index))
+            });
+            if registry {
+                entry
+                    .file_name()
+                    .is_some_and(|name| name == package.as_str())
+            } else {
+                is_match(entry, package)
+                    || directories(entry)
+                        .is_ok_and(|mut revisions| revisions.any(|rev| is_match(&rev, package)))
+            }
+        }
+        // Interpreter entries aren't associated with a package.
+        PruneBucket::Interpreters => false,
+        PruneBucket::Simple => entry
+            .file_stem()
+            .is_some_and(|name| name == package.as_str()),
+    }
+}
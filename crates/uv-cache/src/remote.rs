@@ -0,0 +1,172 @@
+use uv_redacted::DisplaySafeUrl;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteCacheError {
+    #[error(
+        "Unsupported remote cache URL scheme `{0}` (expected one of `http`, `https`, `s3`, `gs`, or `az`)"
+    )]
+    UnsupportedScheme(String),
+    #[error("Remote cache URL is missing a bucket name: `{0}`")]
+    MissingBucket(DisplaySafeUrl),
+    #[error(
+        "Remote cache URL is missing a container name: `{0}` (expected `az://<account>/<container>`)"
+    )]
+    MissingContainer(DisplaySafeUrl),
+}
+
+/// The storage backend for a [`RemoteCache`], as determined by the scheme of its URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteCacheBackend {
+    /// An HTTP server that supports `GET` and `PUT`, e.g., `https://cache.example.com/uv`.
+    Http,
+    /// An Amazon S3 (or S3-compatible) bucket, e.g., `s3://bucket/prefix`.
+    S3 { bucket: String },
+    /// A Google Cloud Storage bucket, e.g., `gs://bucket/prefix`.
+    Gcs { bucket: String },
+    /// An Azure Blob Storage container, e.g., `az://account/container/prefix`.
+    Azure { account: String, container: String },
+}
+
+impl RemoteCacheBackend {
+    /// Returns `true` if the backend is an object store, rather than a plain HTTP server.
+    pub fn is_object_store(&self) -> bool {
+        !matches!(self, Self::Http)
+    }
+}
+
+/// A remote cache that's shared across machines, from which wheels built from source
+/// distributions can be fetched rather than built locally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteCache {
+    /// The base URL of the remote cache.
+    url: DisplaySafeUrl,
+    /// The storage backend, as determined by the URL scheme.
+    backend: RemoteCacheBackend,
+    /// The key prefix within the bucket or container, for object store backends.
+    prefix: String,
+    /// Whether to upload locally built wheels to the remote cache.
+    upload: bool,
+}
+
+impl RemoteCache {
+    /// Create a [`RemoteCache`] at the given base URL.
+    pub fn new(url: DisplaySafeUrl, upload: bool) -> Result<Self, RemoteCacheError> {
+        let (backend, prefix) = match url.scheme() {
+            "http" | "https" => (RemoteCacheBackend::Http, String::new()),
+            "s3" | "gs" => {
+                let bucket = url
+                    .host_str()
+                    .filter(|bucket| !bucket.is_empty())
+                    .ok_or_else(|| RemoteCacheError::MissingBucket(url.clone()))?
+                    .to_string();
+                let prefix = url.path().trim_matches('/').to_string();
+                if url.scheme() == "s3" {
+                    (RemoteCacheBackend::S3 { bucket }, prefix)
+                } else {
+                    (RemoteCacheBackend::Gcs { bucket }, prefix)
+                }
+            }
+            "az" => {
+                let account = url
+                    .host_str()
+                    .filter(|account| !account.is_empty())
+                    .ok_or_else(|| RemoteCacheError::MissingBucket(url.clone()))?
+                    .to_string();
+                let path = url.path().trim_matches('/');
+                let (container, prefix) = path.split_once('/').unwrap_or((path, ""));
+                if container.is_empty() {
+                    return Err(RemoteCacheError::MissingContainer(url));
+                }
+                (
+                    RemoteCacheBackend::Azure {
+                        account,
+                        container: container.to_string(),
+                    },
+                    prefix.to_string(),
+                )
+            }
+            scheme => return Err(RemoteCacheError::UnsupportedScheme(scheme.to_string())),
+        };
+        Ok(Self {
+            url,
+            backend,
+            prefix,
+            upload,
+        })
+    }
+
+    /// Return the base URL of the remote cache.
+    pub fn url(&self) -> &DisplaySafeUrl {
+        &self.url
+    }
+
+    /// Return the storage backend of the remote cache.
+    pub fn backend(&self) -> &RemoteCacheBackend {
+        &self.backend
+    }
+
+    /// Return the key prefix within the bucket or container, for object store backends.
+    ///
+    /// The prefix is empty for HTTP backends, and never includes a leading or trailing slash.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns `true` if locally built wheels should be uploaded to the remote cache.
+    pub fn upload(&self) -> bool {
+        self.upload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote(url: &str) -> Result<RemoteCache, RemoteCacheError> {
+        RemoteCache::new(DisplaySafeUrl::parse(url).unwrap(), false)
+    }
+
+    #[test]
+    fn backends() {
+        let cache = remote("https://cache.example.com/uv").unwrap();
+        assert_eq!(cache.backend(), &RemoteCacheBackend::Http);
+        assert_eq!(cache.prefix(), "");
+
+        let cache = remote("s3://bucket/ci/uv/").unwrap();
+        assert_eq!(
+            cache.backend(),
+            &RemoteCacheBackend::S3 {
+                bucket: "bucket".to_string()
+            }
+        );
+        assert_eq!(cache.prefix(), "ci/uv");
+
+        let cache = remote("gs://bucket").unwrap();
+        assert_eq!(
+            cache.backend(),
+            &RemoteCacheBackend::Gcs {
+                bucket: "bucket".to_string()
+            }
+        );
+        assert_eq!(cache.prefix(), "");
+
+        let cache = remote("az://account/container/uv").unwrap();
+        assert_eq!(
+            cache.backend(),
+            &RemoteCacheBackend::Azure {
+                account: "account".to_string(),
+                container: "container".to_string()
+            }
+        );
+        assert_eq!(cache.prefix(), "uv");
+
+        assert!(matches!(
+            remote("az://account"),
+            Err(RemoteCacheError::MissingContainer(_))
+        ));
+        assert!(matches!(
+            remote("ftp://cache.example.com"),
+            Err(RemoteCacheError::UnsupportedScheme(_))
+        ));
+    }
+}
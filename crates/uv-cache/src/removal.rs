@@ -3,7 +3,9 @@
 //! Source: <https://github.com/rust-lang/cargo/blob/e1ebce1035f9b53bb46a55bd4b0ecf51e24c6458/src/cargo/ops/cargo_clean.rs#L324>
 
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashSet;
 
 use crate::CleanReporter;
 
@@ -36,6 +38,64 @@ impl Remover {
     }
 }
 
+/// Removes entries from the cache on behalf of `uv cache prune`.
+///
+/// In a dry run, the cache is left untouched; instead, the [`Pruner`] records the entries that
+/// would've been removed, such that subsequent pruning steps can account for them.
+#[derive(Debug, Default)]
+pub struct Pruner {
+    dry_run: bool,
+    /// The entries that would've been removed, in a dry run.
+    removed: FxHashSet<PathBuf>,
+    /// The number of bytes that would've been removed, in a dry run.
+    pending_bytes: u64,
+}
+
+impl Pruner {
+    /// Create a [`Pruner`] that reports the entries it would remove, without removing them.
+    pub fn dry_run() -> Self {
+        Self {
+            dry_run: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` if the [`Pruner`] is performing a dry run.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Remove a file or directory and all its contents, returning a [`Removal`] with the number of
+    /// files and directories removed (or that would be removed, in a dry run), along with a total
+    /// byte count.
+    pub fn rm_rf(&mut self, path: impl AsRef<Path>) -> io::Result<Removal> {
+        let path = path.as_ref();
+        if !self.dry_run {
+            return rm_rf(path);
+        }
+        if self.is_removed(path) {
+            return Ok(Removal::default());
+        }
+        let removal = Removal::measure(path)?;
+        self.removed.insert(path.to_path_buf());
+        self.pending_bytes += removal.total_bytes;
+        Ok(removal)
+    }
+
+    /// Returns `true` if the path, or any of its parents, would've been removed in a dry run.
+    pub(crate) fn is_removed(&self, path: &Path) -> bool {
+        !self.removed.is_empty()
+            && path
+                .ancestors()
+                .any(|ancestor| self.removed.contains(ancestor))
+    }
+
+    /// Return the number of bytes that would've been removed in a dry run.
+    pub(crate) fn pending_bytes(&self) -> u64 {
+        self.pending_bytes
+    }
+}
+
 /// A removal operation with statistics on the number of files and directories removed.
 #[derive(Debug, Default)]
 pub struct Removal {
@@ -51,6 +111,34 @@ pub struct Removal {
 }
 
 impl Removal {
+    /// Count the files and directories that [`Removal::rm_rf`] would remove, without removing
+    /// them.
+    fn measure(path: &Path) -> io::Result<Self> {
+        let mut removal = Self::default();
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err)
+                    if err
+                        .io_error()
+                        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+                {
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            if entry.file_type().is_dir() {
+                removal.num_dirs += 1;
+            } else {
+                removal.num_files += 1;
+                if let Ok(metadata) = entry.metadata() {
+                    removal.total_bytes += metadata.len();
+                }
+            }
+        }
+        Ok(removal)
+    }
+
     /// Recursively remove a file or directory and all its contents.
     fn rm_rf(&mut self, path: &Path, reporter: Option<&dyn CleanReporter>) -> io::Result<()> {
         let metadata = match fs_err::symlink_metadata(path) {
@@ -0,0 +1,229 @@
+//! Routing of cache buckets to separate root directories, and relocation of the cache.
+//!
+//! By default, every bucket is stored under the cache root. Individual buckets can instead be
+//! routed to another directory (e.g., to keep the unpacked wheels on a fast disk while the source
+//! distributions live on a larger, slower one), in which case the bucket is stored under that
+//! directory with the same name it would have under the cache root.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use crate::removal::{Removal, rm_rf};
+use crate::{Cache, CacheBucket};
+
+/// The directories to which individual cache buckets are routed, in lieu of the cache root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CacheRoots {
+    /// The root for the unpacked wheels, which are linked into environments.
+    pub archive: Option<PathBuf>,
+    /// The root for the pre-built wheels downloaded from an index or URL.
+    pub wheels: Option<PathBuf>,
+    /// The root for source distributions, along with the wheels built from them.
+    pub source_distributions: Option<PathBuf>,
+    /// The root for Git repositories.
+    pub git: Option<PathBuf>,
+}
+
+impl CacheRoots {
+    /// Return the root to which the given bucket is routed, if any.
+    pub(crate) fn get(&self, bucket: CacheBucket) -> Option<&Path> {
+        match bucket {
+            CacheBucket::Archive => self.archive.as_deref(),
+            CacheBucket::Wheels => self.wheels.as_deref(),
+            CacheBucket::SourceDistributions => self.source_distributions.as_deref(),
+            CacheBucket::Git => self.git.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Return the buckets that are routed away from the cache root, along with their roots.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (CacheBucket, &Path)> {
+        [
+            CacheBucket::Archive,
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+            CacheBucket::Git,
+        ]
+        .into_iter()
+        .filter_map(|bucket| self.get(bucket).map(|root| (bucket, root)))
+    }
+
+    /// Resolve each root to an absolute path.
+    pub(crate) fn absolute(self) -> Result<Self, io::Error> {
+        let absolute = |root: Option<PathBuf>| root.map(std::path::absolute).transpose();
+        Ok(Self {
+            archive: absolute(self.archive)?,
+            wheels: absolute(self.wheels)?,
+            source_distributions: absolute(self.source_distributions)?,
+            git: absolute(self.git)?,
+        })
+    }
+}
+
+impl Cache {
+    /// Move the cache to the given directory, returning the relocated [`Cache`].
+    ///
+    /// The cached environments and in-progress builds are removed rather than moved, since they
+    /// embed absolute paths into the cache. Buckets that are routed to another root are left in
+    /// place, though any links from them into the moved buckets are updated.
+    ///
+    /// The target must either not exist, or be an empty directory. The cache should not be in use
+    /// by any other process while it's being moved.
+    pub fn relocate(&self, target: impl Into<PathBuf>) -> Result<(Self, Removal), io::Error> {
+        if self.is_temporary() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "A temporary cache can't be moved",
+            ));
+        }
+
+        let source = std::path::absolute(&self.root)?;
+        let target = std::path::absolute(target.into())?;
+        if target.starts_with(&source) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "The target directory must not be within the cache: `{}`",
+                    target.display()
+                ),
+            ));
+        }
+        match fs_err::read_dir(&target) {
+            Ok(mut entries) => {
+                if entries.next().is_some() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        format!(
+                            "The target directory already exists and is not empty: `{}`",
+                            target.display()
+                        ),
+                    ));
+                }
+                fs_err::remove_dir(&target)?;
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        let relocated = Self {
+            root: target.clone(),
+            roots: self.roots.clone().absolute()?,
+            ..self.clone()
+        };
+
+        let mut summary = Removal::default();
+        if source.exists() {
+            // Remove the cached environments, along with the archives they link to, and the
+            // ephemeral build environments.
+            match fs_err::read_dir(self.bucket(CacheBucket::Environments)) {
+                Ok(entries) => {
+                    for entry in entries {
+                        let entry = entry?;
+                        if let Ok(path) = fs_err::canonicalize(entry.path()) {
+                            debug!("Removing cached environment: {}", path.display());
+                            summary += rm_rf(path)?;
+                        }
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+            summary += rm_rf(self.bucket(CacheBucket::Environments))?;
+            summary += rm_rf(self.root.join(CacheBucket::Builds.to_str()))?;
+
+            // Move the cache root, falling back to a copy if the target is on another device.
+            fs_err::create_dir_all(target.parent().unwrap_or(&target))?;
+            match fs_err::rename(&source, &target) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+                    debug!(
+                        "Copying cache across devices: {} to {}",
+                        source.display(),
+                        target.display()
+                    );
+                    copy_tree(&source, &target)?;
+                    rm_rf(&source)?;
+                }
+                Err(err) => return Err(err),
+            }
+
+            // Update any links that point into the old cache root.
+            relocated.relink(&source)?;
+        }
+
+        Ok((relocated, summary))
+    }
+
+    /// Recreate any archive links that point into the given (former) cache root.
+    ///
+    /// On Windows, links are stored as archive IDs relative to the cache, and so don't need to be
+    /// updated.
+    #[cfg(unix)]
+    fn relink(&self, previous: &Path) -> Result<(), io::Error> {
+        // Links only point into the archive bucket, so the archive bucket itself can be skipped.
+        for bucket in CacheBucket::iter().filter(|bucket| *bucket != CacheBucket::Archive) {
+            for entry in walkdir::WalkDir::new(self.bucket(bucket)) {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err)
+                        if err
+                            .io_error()
+                            .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+                    {
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+                if !entry.path_is_symlink() {
+                    continue;
+                }
+                let link = fs_err::read_link(entry.path())?;
+                if !link.starts_with(previous) {
+                    continue;
+                }
+                let Some(id) = link.file_name().and_then(|id| id.to_str()) else {
+                    continue;
+                };
+                let id = id
+                    .parse::<crate::ArchiveId>()
+                    .expect("archive IDs are infallible");
+                debug!("Updating archive link: {}", entry.path().display());
+                self.create_link(&id, entry.path())?;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    #[allow(clippy::unnecessary_wraps)]
+    fn relink(&self, _previous: &Path) -> Result<(), io::Error> {
+        Ok(())
+    }
+}
+
+/// Recursively copy a directory, preserving (rather than following) any symbolic links.
+fn copy_tree(src: &Path, dst: &Path) -> Result<(), io::Error> {
+    for entry in walkdir::WalkDir::new(src) {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(src)
+            .expect("walked paths are within the source");
+        let target = dst.join(relative);
+        if entry.path_is_symlink() {
+            #[cfg(unix)]
+            fs_err::os::unix::fs::symlink(fs_err::read_link(entry.path())?, &target)?;
+            #[cfg(windows)]
+            fs_err::copy(entry.path(), &target)?;
+        } else if entry.file_type().is_dir() {
+            fs_err::create_dir_all(&target)?;
+        } else {
+            fs_err::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,325 @@
+//! Usage statistics for the cache, to inform the tuning of cache policies (e.g., the maximum size,
+//! or the interval at which to prune).
+//!
+//! Alongside the contents of the cache itself, uv records the number of distributions that were
+//! served from the cache (hits) or had to be downloaded or built (misses) by each installation, as
+//! one JSON line per run in the `stats` bucket.
+
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_fs::directories;
+use uv_normalize::PackageName;
+
+use crate::eviction::python_cache_dir;
+use crate::removal::Pruner;
+use crate::units::disk_usage;
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket, read_package_name};
+
+/// The name of the file in which runs are recorded.
+const RUNS: &str = "runs.jsonl";
+
+/// The number of recent runs to retain.
+const MAX_RUNS: usize = 100;
+
+/// The number of largest packages to report.
+const LARGEST_PACKAGES: usize = 10;
+
+/// The record of a single run that consulted the cache.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize)]
+pub struct CacheRun {
+    /// The time at which the run completed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The number of distributions that were served from the cache.
+    pub hits: u64,
+    /// The number of distributions that had to be downloaded or built.
+    pub misses: u64,
+}
+
+impl CacheRun {
+    /// Create a [`CacheRun`] for a run that completed now.
+    pub fn new(hits: u64, misses: u64) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_secs();
+        Self {
+            timestamp,
+            hits,
+            misses,
+        }
+    }
+}
+
+/// Statistics on the contents and usage of the cache.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct CacheStats {
+    /// The total size of the cache, in bytes.
+    pub total_bytes: u64,
+    /// The size and number of files in each bucket.
+    pub buckets: Vec<BucketStats>,
+    /// The hits and misses across recent runs.
+    pub runs: RunStats,
+    /// The packages that occupy the most space in the cache.
+    pub largest_packages: Vec<PackageStats>,
+    /// The distribution of cache entries by the time since they were last used.
+    pub last_used: Vec<AgeStats>,
+}
+
+/// Statistics for a single cache bucket.
+#[derive(Debug, serde::Serialize)]
+pub struct BucketStats {
+    /// The name of the bucket (e.g., `wheels-v5`).
+    pub name: String,
+    /// The size of the bucket, in bytes.
+    pub bytes: u64,
+    /// The number of files in the bucket.
+    pub files: u64,
+}
+
+/// The hits and misses across recent runs.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct RunStats {
+    /// The number of recorded runs.
+    pub count: u64,
+    /// The number of distributions that were served from the cache.
+    pub hits: u64,
+    /// The number of distributions that had to be downloaded or built.
+    pub misses: u64,
+    /// The fraction of distributions that were served from the cache, if any were requested.
+    pub hit_rate: Option<f64>,
+}
+
+/// The space occupied by a single package, across every index, URL, and path.
+#[derive(Debug, serde::Serialize)]
+pub struct PackageStats {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The size of the package's entries, including the unpacked wheels they reference, in bytes.
+    pub bytes: u64,
+    /// The number of entries for the package.
+    pub entries: u64,
+}
+
+/// The number and size of cache entries last used within an age range.
+#[derive(Debug, serde::Serialize)]
+pub struct AgeStats {
+    /// A label for the age range (e.g., `<1d`).
+    pub label: &'static str,
+    /// The number of entries.
+    pub entries: u64,
+    /// The size of the entries, including the unpacked wheels they reference, in bytes.
+    pub bytes: u64,
+}
+
+/// The upper bounds of the age ranges used to report the time since entries were last used.
+const AGES: [(&str, Option<Duration>); 5] = [
+    ("<1d", Some(Duration::from_secs(60 * 60 * 24))),
+    ("1d-7d", Some(Duration::from_secs(60 * 60 * 24 * 7))),
+    ("7d-30d", Some(Duration::from_secs(60 * 60 * 24 * 30))),
+    ("30d-90d", Some(Duration::from_secs(60 * 60 * 24 * 90))),
+    (">90d", None),
+];
+
+impl Cache {
+    /// Record the hits and misses of a run, retaining only the most recent runs.
+    ///
+    /// Records are appended to a shared file, such that concurrent runs may interleave, but never
+    /// corrupt, each other's records.
+    pub fn record_run(&self, run: CacheRun) -> Result<(), io::Error> {
+        if self.is_temporary() {
+            return Ok(());
+        }
+
+        let bucket = self.bucket(CacheBucket::Stats);
+        fs_err::create_dir_all(&bucket)?;
+        let path = bucket.join(RUNS);
+
+        let mut line = serde_json::to_vec(&run).map_err(io::Error::other)?;
+        line.push(b'\n');
+        fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?
+            .write_all(&line)?;
+
+        // Once the file has grown well beyond the retention limit, truncate it to the most recent
+        // runs.
+        let runs = read_runs(&path)?;
+        if runs.len() > MAX_RUNS * 2 {
+            debug!("Truncating cache run records: {}", path.display());
+            let mut contents = Vec::new();
+            for run in &runs[runs.len() - MAX_RUNS..] {
+                serde_json::to_writer(&mut contents, run).map_err(io::Error::other)?;
+                contents.push(b'\n');
+            }
+            uv_fs::write_atomic_sync(&path, contents)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compute statistics on the contents and usage of the cache.
+    pub fn stats(&self) -> Result<CacheStats, io::Error> {
+        let mut stats = CacheStats::default();
+
+        // Compute the size of each bucket.
+        for bucket in CacheBucket::iter() {
+            let (bytes, files) = bucket_usage(&self.bucket(bucket))?;
+            stats.total_bytes += bytes;
+            stats.buckets.push(BucketStats {
+                name: bucket.to_string(),
+                bytes,
+                files,
+            });
+        }
+        if let Some(python) = python_cache_dir() {
+            let (bytes, files) = bucket_usage(&python)?;
+            stats.total_bytes += bytes;
+            stats.buckets.push(BucketStats {
+                name: "python".to_string(),
+                bytes,
+                files,
+            });
+        }
+
+        // Summarize the recent runs.
+        let runs = read_runs(&self.bucket(CacheBucket::Stats).join(RUNS))?;
+        let runs = &runs[runs.len().saturating_sub(MAX_RUNS)..];
+        stats.runs.count = runs.len() as u64;
+        stats.runs.hits = runs.iter().map(|run| run.hits).sum();
+        stats.runs.misses = runs.iter().map(|run| run.misses).sum();
+        let requests = stats.runs.hits + stats.runs.misses;
+        if requests > 0 {
+            #[allow(clippy::cast_precision_loss)]
+            let hit_rate = stats.runs.hits as f64 / requests as f64;
+            stats.runs.hit_rate = Some(hit_rate);
+        }
+
+        // Attribute the size and last use of each entry (including the archives it references)
+        // to its package.
+        let now = SystemTime::now();
+        let usage = self.archive_usage(&Pruner::default())?;
+        let mut packages = FxHashMap::<PackageName, (u64, u64)>::default();
+        let mut ages = AGES.map(|(label, _)| AgeStats {
+            label,
+            entries: 0,
+            bytes: 0,
+        });
+        for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+            for unit in self.units(bucket)? {
+                let (size, last_used) = disk_usage(&unit)?;
+                let last_used = usage.last_used(&unit, last_used);
+                let size = size
+                    + usage
+                        .units
+                        .get(&unit)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|archive| usage.archives.get(archive))
+                        .map(|archive| archive.size)
+                        .sum::<u64>();
+
+                if let Some(name) = package_name(bucket, &unit) {
+                    let package = packages.entry(name).or_default();
+                    package.0 += size;
+                    package.1 += 1;
+                }
+
+                let age = now.duration_since(last_used).unwrap_or(Duration::ZERO);
+                let index = AGES
+                    .iter()
+                    .position(|(_, max)| max.is_none_or(|max| age < max))
+                    .expect("the last age range is unbounded");
+                ages[index].entries += 1;
+                ages[index].bytes += size;
+            }
+        }
+        stats.last_used = ages.into_iter().collect();
+
+        let mut packages = packages
+            .into_iter()
+            .map(|(name, (bytes, entries))| PackageStats {
+                name,
+                bytes,
+                entries,
+            })
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+        packages.truncate(LARGEST_PACKAGES);
+        stats.largest_packages = packages;
+
+        Ok(stats)
+    }
+}
+
+/// Read the recorded runs from the given file, skipping any malformed records.
+fn read_runs(path: &Path) -> Result<Vec<CacheRun>, io::Error> {
+    let contents = match fs_err::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CacheRun>(line).ok())
+        .collect())
+}
+
+/// Return the total size of the files in a bucket, in bytes, along with the number of files.
+///
+/// Symbolic links are not followed, such that archives are only counted once.
+fn bucket_usage(path: &Path) -> Result<(u64, u64), io::Error> {
+    let mut bytes = 0;
+    let mut files = 0;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if entry.file_type().is_file() {
+            bytes += entry.metadata()?.len();
+            files += 1;
+        }
+    }
+    Ok((bytes, files))
+}
+
+/// Return the name of the package to which a unit in the wheel or source distribution bucket
+/// belongs, if it can be determined.
+fn package_name(bucket: CacheBucket, unit: &Path) -> Option<PackageName> {
+    // Wheel entries, along with registry source distribution entries, are keyed by package name.
+    let registry = unit.parent().is_some_and(|parent| {
+        parent.ends_with(WheelCacheKind::Pypi)
+            || parent
+                .parent()
+                .is_some_and(|parent| parent.ends_with(WheelCacheKind::Index))
+    });
+    if bucket == CacheBucket::Wheels || registry {
+        return unit
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| PackageName::from_str(name).ok());
+    }
+
+    // Otherwise, read the name from the metadata of any revision.
+    read_package_name(unit).or_else(|| {
+        directories(unit)
+            .ok()?
+            .find_map(|revision| read_package_name(&revision))
+    })
+}
@@ -0,0 +1,496 @@
+//! Export and import of cache entries as a single archive, e.g., to warm up the cache of a
+//! continuous integration runner.
+//!
+//! An export is a gzipped tarball, beginning with a manifest that lists every file and archive
+//! link it contains. Cache entries are stored under `cache/`, relative to the cache root, while
+//! Python interpreter archives (as cached in `UV_PYTHON_CACHE_DIR`) are stored under `python/`.
+//!
+//! Archive links (i.e., the symlinks from the wheel and source distribution buckets into the
+//! archive bucket) are recorded in the manifest rather than in the tarball, since they're
+//! platform-specific and may embed the absolute path to the cache root.
+
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use uv_fs::PortablePath;
+use uv_normalize::PackageName;
+
+use crate::eviction::python_cache_dir;
+use crate::prune::{PruneBucket, is_package_entry};
+use crate::{ArchiveId, Cache, CacheBucket};
+
+/// The path of the manifest within an export.
+const MANIFEST: &str = "uv-cache.json";
+
+/// The version of the export format.
+const MANIFEST_VERSION: u32 = 1;
+
+/// The manifest of an export, listing its contents.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct Manifest {
+    version: u32,
+    /// The files in the export.
+    files: Vec<ManifestFile>,
+    /// The archive links to recreate on import.
+    links: Vec<ManifestLink>,
+}
+
+/// A file in an export, relative to the root of the tarball.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ManifestFile {
+    path: String,
+    size: u64,
+}
+
+/// A link from a cache entry to an archive, relative to the cache root.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ManifestLink {
+    path: String,
+    archive: ArchiveId,
+}
+
+/// A summary of an export.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExportSummary {
+    /// The number of files exported.
+    pub num_files: u64,
+    /// The number of archive links exported.
+    pub num_links: u64,
+    /// The total size of the exported files, in bytes (prior to compression).
+    pub total_bytes: u64,
+}
+
+/// A summary of an import.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImportSummary {
+    /// The number of files imported.
+    pub num_files: u64,
+    /// The number of files skipped, as they already existed in the cache.
+    pub num_skipped: u64,
+    /// The total size of the imported files, in bytes.
+    pub total_bytes: u64,
+}
+
+impl Cache {
+    /// Export the wheel and source distribution entries in the cache, along with the archives
+    /// they reference and any Python interpreter archives, as a gzipped tarball.
+    ///
+    /// If `packages` is provided, only entries for the given packages are exported.
+    pub fn export(
+        &self,
+        packages: Option<&FxHashSet<PackageName>>,
+        writer: impl Write,
+    ) -> Result<ExportSummary, io::Error> {
+        let mut manifest = Manifest {
+            version: MANIFEST_VERSION,
+            ..Manifest::default()
+        };
+        let mut sources = Vec::new();
+        let mut archives = FxHashSet::default();
+
+        // Invert the archive references, to identify the links within each entry.
+        let links = self
+            .find_archive_references()?
+            .into_iter()
+            .flat_map(|(archive, links)| links.into_iter().map(move |link| (link, archive.clone())))
+            .collect::<FxHashMap<_, _>>();
+
+        for (bucket, prune_bucket) in [
+            (CacheBucket::Wheels, PruneBucket::Wheels),
+            (CacheBucket::SourceDistributions, PruneBucket::Sdists),
+        ] {
+            for unit in self.units(bucket)? {
+                if let Some(packages) = packages {
+                    if !packages
+                        .iter()
+                        .any(|package| is_package_entry(prune_bucket, &unit, package))
+                    {
+                        continue;
+                    }
+                }
+
+                let walker = walkdir::WalkDir::new(&unit).into_iter();
+                for entry in walker.filter_entry(|entry| {
+                    // Omit the unpacked source trees, which are recreated on demand, along with
+                    // any lockfiles.
+                    !(entry.file_name() == "src"
+                        || entry.file_name() == ".lock"
+                        || entry
+                            .path()
+                            .extension()
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case("lock")))
+                }) {
+                    let entry = entry?;
+                    let relative = self.relative(entry.path())?;
+
+                    if let Some(archive) = links.get(entry.path()) {
+                        let Some(id) = archive.file_name().and_then(|id| id.to_str()) else {
+                            continue;
+                        };
+                        let id = ArchiveId::from_str(id).expect("archive IDs are infallible");
+                        manifest.links.push(ManifestLink {
+                            path: relative,
+                            archive: id.clone(),
+                        });
+                        archives.insert(id);
+                        continue;
+                    }
+
+                    if entry.file_type().is_file() {
+                        let size = entry.metadata()?.len();
+                        manifest.files.push(ManifestFile {
+                            path: format!("cache/{relative}"),
+                            size,
+                        });
+                        sources.push(entry.into_path());
+                    }
+                }
+            }
+        }
+
+        // Include every referenced archive.
+        for id in archives {
+            for entry in walkdir::WalkDir::new(self.archive(&id)) {
+                let entry = entry?;
+                if entry.file_type().is_file() {
+                    let relative = self.relative(entry.path())?;
+                    manifest.files.push(ManifestFile {
+                        path: format!("cache/{relative}"),
+                        size: entry.metadata()?.len(),
+                    });
+                    sources.push(entry.into_path());
+                }
+            }
+        }
+
+        // Include any Python interpreter archives.
+        if let Some(python) = python_cache_dir() {
+            match fs_err::read_dir(&python) {
+                Ok(entries) => {
+                    for entry in entries {
+                        let entry = entry?;
+                        let metadata = entry.metadata()?;
+                        if !metadata.is_file() {
+                            continue;
+                        }
+                        let Some(name) = entry.file_name().to_str().map(ToString::to_string) else {
+                            continue;
+                        };
+                        manifest.files.push(ManifestFile {
+                            path: format!("python/{name}"),
+                            size: metadata.len(),
+                        });
+                        sources.push(entry.path());
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let summary = ExportSummary {
+            num_files: manifest.files.len() as u64,
+            num_links: manifest.links.len() as u64,
+            total_bytes: manifest.files.iter().map(|file| file.size).sum(),
+        };
+
+        // Write the manifest, followed by each file.
+        let encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        builder.follow_symlinks(false);
+
+        let contents = serde_json::to_vec_pretty(&manifest).map_err(io::Error::other)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, MANIFEST, contents.as_slice())?;
+
+        for (file, source) in manifest.files.iter().zip(sources) {
+            debug!("Exporting cache file: {}", source.display());
+            builder.append_path_with_name(&source, &file.path)?;
+        }
+
+        builder.into_inner()?.finish()?.flush()?;
+
+        Ok(summary)
+    }
+
+    /// Import the entries from a gzipped tarball created by [`Cache::export`].
+    ///
+    /// Files that already exist in the cache are skipped, such that repeated imports only add the
+    /// missing entries. If every file and link in the export already exists, the tarball is not
+    /// decompressed beyond its manifest.
+    pub fn import(&self, reader: impl Read) -> Result<ImportSummary, io::Error> {
+        let decoder = flate2::read::GzDecoder::new(reader);
+        let mut archive = tar::Archive::new(decoder);
+        let mut entries = archive.entries()?;
+
+        // Read the manifest, which must be the first entry.
+        let manifest = match entries.next() {
+            Some(entry) => {
+                let mut entry = entry?;
+                if entry.path()?.as_ref() != Path::new(MANIFEST) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Expected the archive to begin with `{MANIFEST}`"),
+                    ));
+                }
+                let mut contents = Vec::new();
+                entry.read_to_end(&mut contents)?;
+                serde_json::from_slice::<Manifest>(&contents)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+            }
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "The archive is empty",
+                ));
+            }
+        };
+
+        if manifest.version != MANIFEST_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Unsupported cache export version: {} (expected {MANIFEST_VERSION})",
+                    manifest.version
+                ),
+            ));
+        }
+
+        let python = python_cache_dir();
+        let mut summary = ImportSummary::default();
+
+        // Determine the files that are missing from the cache.
+        let mut missing = FxHashSet::default();
+        for file in &manifest.files {
+            match self.import_target(&file.path, python.as_deref())? {
+                Some(target) if !target.exists() => {
+                    missing.insert(file.path.as_str());
+                }
+                Some(_) => summary.num_skipped += 1,
+                None => {
+                    debug!(
+                        "Skipping Python archive without `UV_PYTHON_CACHE_DIR`: {}",
+                        file.path
+                    );
+                    summary.num_skipped += 1;
+                }
+            }
+        }
+
+        // Unpack the missing files.
+        if !missing.is_empty() {
+            for entry in entries {
+                let mut entry = entry?;
+
+                // Exports only contain regular files (and, if repacked, directories); reject
+                // anything else, such as links, which could otherwise point outside the cache.
+                match entry.header().entry_type() {
+                    tar::EntryType::Regular => {}
+                    tar::EntryType::Directory => continue,
+                    entry_type => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "Unsupported entry type ({entry_type:?}) in cache export: `{}`",
+                                entry.path()?.display()
+                            ),
+                        ));
+                    }
+                }
+
+                let Some(path) = entry.path()?.to_str().map(ToString::to_string) else {
+                    continue;
+                };
+                if !missing.remove(path.as_str()) {
+                    continue;
+                }
+                let Some(target) = self.import_target(&path, python.as_deref())? else {
+                    continue;
+                };
+
+                debug!("Importing cache file: {}", target.display());
+                let parent = target.parent().expect("import targets have a parent");
+                fs_err::create_dir_all(parent)?;
+
+                // Unpack to a temporary file, then move it into place, such that concurrent
+                // readers never observe a partial file.
+                let temp_dir = tempfile::tempdir_in(parent)?;
+                let temp_file = temp_dir.path().join("file");
+                entry.unpack(&temp_file)?;
+                fs_err::rename(&temp_file, &target)?;
+
+                summary.num_files += 1;
+                summary.total_bytes += entry.size();
+
+                if missing.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        // Recreate the links to any archives.
+        for link in &manifest.links {
+            let target = self.locate(validate(&link.path)?);
+            if target.symlink_metadata().is_ok() {
+                continue;
+            }
+            if !self.archive(&link.archive).is_dir() {
+                debug!(
+                    "Skipping link to missing archive `{}`: {}",
+                    link.archive, link.path
+                );
+                continue;
+            }
+            fs_err::create_dir_all(target.parent().expect("links have a parent"))?;
+            self.create_link(&link.archive, &target)?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Return the path of the given cache entry relative to the cache root, with forward slashes.
+    ///
+    /// Entries in buckets that are routed to another root are treated as if they were stored under
+    /// the cache root, such that exports are independent of the routing.
+    fn relative(&self, path: &Path) -> Result<String, io::Error> {
+        let relative = CacheBucket::iter()
+            .find_map(|bucket| {
+                path.strip_prefix(self.bucket_root(bucket))
+                    .ok()
+                    .filter(|relative| relative.starts_with(bucket.to_str()))
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Path is not within the cache: `{}`", path.display()),
+                )
+            })?;
+        Ok(PortablePath::from(relative).to_string())
+    }
+
+    /// Return the absolute path of the cache entry at the given path relative to the cache root,
+    /// accounting for any buckets that are routed to another root.
+    fn locate(&self, relative: &Path) -> PathBuf {
+        CacheBucket::iter()
+            .find(|bucket| relative.starts_with(bucket.to_str()))
+            .map_or(self.root.as_path(), |bucket| self.bucket_root(bucket))
+            .join(relative)
+    }
+
+    /// Return the path at which to import the file at the given path within an export, or `None`
+    /// if the file is a Python archive and no Python cache directory is configured.
+    fn import_target(
+        &self,
+        path: &str,
+        python: Option<&Path>,
+    ) -> Result<Option<PathBuf>, io::Error> {
+        if let Some(relative) = path.strip_prefix("cache/") {
+            Ok(Some(self.locate(validate(relative)?)))
+        } else if let Some(relative) = path.strip_prefix("python/") {
+            Ok(python
+                .map(|python| validate(relative).map(|relative| python.join(relative)))
+                .transpose()?)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unexpected path in cache export: `{path}`"),
+            ))
+        }
+    }
+}
+
+/// Validate that a path within an export is relative and doesn't escape its parent directory.
+fn validate(path: &str) -> Result<&Path, io::Error> {
+    let path = Path::new(path);
+    if path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        Ok(path)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid path in cache export: `{}`", path.display()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::{MANIFEST, MANIFEST_VERSION, Manifest, ManifestFile};
+    use crate::Cache;
+
+    /// Create an export whose manifest lists a single file, which is stored in the tarball as an
+    /// entry of the given type pointing to `link`.
+    fn export_with_link(entry_type: tar::EntryType, path: &str, link: &str) -> Vec<u8> {
+        let manifest = Manifest {
+            version: MANIFEST_VERSION,
+            files: vec![ManifestFile {
+                path: path.to_string(),
+                size: 0,
+            }],
+            links: Vec::new(),
+        };
+
+        let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+
+        let contents = serde_json::to_vec(&manifest).unwrap();
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, MANIFEST, contents.as_slice())
+            .unwrap();
+
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(entry_type);
+        header.set_size(0);
+        header.set_mode(0o644);
+        builder.append_link(&mut header, path, link).unwrap();
+
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[test]
+    fn import_rejects_links() {
+        let temp = tempfile::tempdir().unwrap();
+        let outside = temp.path().join("outside");
+        fs_err::write(&outside, "secret").unwrap();
+        let cache = Cache::from_path(temp.path().join("cache"));
+
+        for entry_type in [tar::EntryType::Symlink, tar::EntryType::Link] {
+            let export = export_with_link(
+                entry_type,
+                "cache/wheels-v5/pypi/foo/foo.whl",
+                outside.to_str().unwrap(),
+            );
+            let err = cache.import(export.as_slice()).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            assert!(
+                err.to_string().starts_with("Unsupported entry type"),
+                "{err}"
+            );
+            assert!(
+                cache
+                    .root()
+                    .join("wheels-v5/pypi/foo/foo.whl")
+                    .symlink_metadata()
+                    .is_err()
+            );
+        }
+
+        assert_eq!(fs_err::read_to_string(&outside).unwrap(), "secret");
+    }
+}
@@ -0,0 +1,181 @@
+//! Helpers for grouping the wheel and source distribution buckets into units (e.g., all entries
+//! for a given package from a given index) that can be removed independently, as used by cache
+//! eviction and filtered pruning.
+
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use rustc_hash::FxHashMap;
+
+use crate::removal::Pruner;
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket};
+
+/// The size and last use of an archive in the cache, along with the number of units that
+/// reference it.
+#[derive(Debug)]
+pub(crate) struct Archive {
+    /// The size of the archive, in bytes.
+    pub(crate) size: u64,
+    /// The time at which any file in the archive was last accessed or modified.
+    pub(crate) last_used: SystemTime,
+    /// The number of remaining units that reference the archive.
+    pub(crate) references: usize,
+}
+
+/// The archives in the cache, along with the units that reference them.
+#[derive(Debug, Default)]
+pub(crate) struct ArchiveUsage {
+    /// The archives referenced by each unit.
+    pub(crate) units: FxHashMap<PathBuf, Vec<PathBuf>>,
+    /// The referenced archives.
+    pub(crate) archives: FxHashMap<PathBuf, Archive>,
+}
+
+impl ArchiveUsage {
+    /// Return the time at which the unit, or any archive it references, was last used.
+    pub(crate) fn last_used(&self, unit: &Path, last_used: SystemTime) -> SystemTime {
+        self.units
+            .get(unit)
+            .into_iter()
+            .flatten()
+            .filter_map(|archive| self.archives.get(archive))
+            .fold(last_used, |last_used, archive| {
+                last_used.max(archive.last_used)
+            })
+    }
+}
+
+impl Cache {
+    /// Attribute each archive reference in the cache to the unit that contains it.
+    ///
+    /// References within entries that the [`Pruner`] has already removed are ignored.
+    pub(crate) fn archive_usage(&self, pruner: &Pruner) -> Result<ArchiveUsage, io::Error> {
+        let mut usage = ArchiveUsage::default();
+        for (archive, links) in self.find_archive_references()? {
+            if pruner.is_removed(&archive) {
+                continue;
+            }
+            let (size, last_used) = disk_usage(&archive)?;
+            let mut entry = Archive {
+                size,
+                last_used,
+                references: 0,
+            };
+            for link in links {
+                if pruner.is_removed(&link) {
+                    continue;
+                }
+                if let Some(unit) = self.unit(&link) {
+                    let unit_archives = usage.units.entry(unit).or_default();
+                    if !unit_archives.contains(&archive) {
+                        unit_archives.push(archive.clone());
+                        entry.references += 1;
+                    }
+                } else {
+                    // If we can't attribute the reference, retain the archive.
+                    entry.references += 1;
+                }
+            }
+            usage.archives.insert(archive, entry);
+        }
+        Ok(usage)
+    }
+
+    /// Return the unit that contains the given path, if it's within the wheel or source
+    /// distribution bucket.
+    fn unit(&self, path: &Path) -> Option<PathBuf> {
+        [CacheBucket::Wheels, CacheBucket::SourceDistributions]
+            .into_iter()
+            .find_map(|bucket| {
+                let root = self.bucket(bucket);
+                let relative = path.strip_prefix(&root).ok()?;
+                let mut components = relative.components().map(Component::as_os_str);
+                let kind = components.next()?;
+                let mut unit = root.join(kind);
+                for _ in 1..unit_depth(bucket, kind) {
+                    unit.push(components.next()?);
+                }
+                Some(unit)
+            })
+    }
+
+    /// Return the units within the wheel or source distribution bucket.
+    pub(crate) fn units(&self, bucket: CacheBucket) -> Result<Vec<PathBuf>, io::Error> {
+        let mut units = Vec::new();
+        for kind in uv_fs::directories(self.bucket(bucket))? {
+            let Some(name) = kind.file_name() else {
+                continue;
+            };
+            let depth = unit_depth(bucket, name);
+            let mut parents = vec![kind];
+            for _ in 1..depth {
+                let mut children = Vec::new();
+                for parent in parents {
+                    children.extend(uv_fs::directories(parent)?);
+                }
+                parents = children;
+            }
+            units.extend(parents);
+        }
+        Ok(units)
+    }
+}
+
+/// Return the number of path components that make up a unit for entries of the given
+/// [`WheelCacheKind`] within a bucket.
+///
+/// Registry entries are grouped by package (e.g., `pypi/{package}` or
+/// `index/{digest}/{package}`). In the wheel bucket, entries for direct URLs and local paths are
+/// also grouped by package (e.g., `url/{digest}/{package}`), while in the source distribution
+/// bucket, they're grouped by URL or path (e.g., `url/{digest}`), or by commit for Git
+/// dependencies (e.g., `git/{digest}/{sha}`).
+pub(crate) fn unit_depth(bucket: CacheBucket, kind: &OsStr) -> usize {
+    if kind == WheelCacheKind::Pypi.to_str() {
+        2
+    } else if bucket == CacheBucket::Wheels
+        || kind == WheelCacheKind::Index.to_str()
+        || kind == WheelCacheKind::Git.to_str()
+    {
+        3
+    } else {
+        2
+    }
+}
+
+/// Return the total size of the files at the given path, in bytes, along with the time at which
+/// any of them was last accessed or modified.
+///
+/// Symbolic links are not followed. Directories are ignored when determining the time of last use,
+/// since their access times are updated by any traversal (including this one).
+pub(crate) fn disk_usage(path: &Path) -> Result<(u64, SystemTime), io::Error> {
+    let mut size = 0;
+    let mut last_used = SystemTime::UNIX_EPOCH;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        size += metadata.len();
+        for time in [metadata.accessed(), metadata.modified()]
+            .into_iter()
+            .flatten()
+        {
+            last_used = last_used.max(time);
+        }
+    }
+    Ok((size, last_used))
+}
@@ -1,18 +1,20 @@
 use std::ffi::OsString;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects, Style};
 use clap::{Args, Parser, Subcommand};
 
-use uv_cache::CacheArgs;
+use uv_cache::{CacheArgs, PruneBucket};
 use uv_configuration::{
-    ConfigSettingEntry, ConfigSettingPackageEntry, ExportFormat, IndexStrategy,
-    KeyringProviderType, PackageNameSpecifier, ProjectBuildBackend, TargetTriple, TrustedHost,
-    TrustedPublishing, VersionControlSystem,
+    ByteSize, BytecodeOptimizationLevel, ConfigSettingEntry, ConfigSettingPackageEntry,
+    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, ProjectBuildBackend,
+    TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
 };
 use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
@@ -47,6 +49,15 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheStatsFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -56,6 +67,24 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum BuildFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -68,6 +97,25 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ShowFormat {
+    /// Display the packages in the `pip show` format.
+    #[default]
+    Text,
+    /// Display the packages in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum TreeFormat {
+    /// Display the dependency tree as indented text.
+    #[default]
+    Text,
+    /// Display the dependency tree in a machine-readable JSON format, as a list of root packages
+    /// with nested dependencies.
+    Json,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -303,6 +351,22 @@ pub struct GlobalArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_PROGRESS, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// The maximum time to wait for a lock held by another uv process, in seconds.
+    ///
+    /// By default, uv waits indefinitely for locks held by other uv processes (e.g., on a virtual
+    /// environment or a cache entry). If the lock isn't released within the given time, uv exits
+    /// with an error identifying the process that holds the lock.
+    #[arg(global = true, long, env = EnvVars::UV_WAIT_TIMEOUT)]
+    pub wait_timeout: Option<u64>,
+
+    /// Record the HTTP requests made by uv to the given file.
+    ///
+    /// Each request is recorded with its method, URL, status, timing, size, and cache disposition.
+    /// If the file has a `.har` extension, the trace is written as an HTTP Archive (HAR);
+    /// otherwise, it's written as JSON Lines.
+    #[arg(global = true, long, env = EnvVars::UV_TRACE_HTTP)]
+    pub trace_http: Option<PathBuf>,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and `direct_url.json`) to site-packages `.dist-info` directories.
     #[arg(global = true, long, hide = true, env = EnvVars::UV_NO_INSTALLER_METADATA, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
@@ -480,7 +544,26 @@ pub enum Commands {
     )]
     Build(BuildArgs),
     /// Upload distributions to an index.
+    #[command(args_conflicts_with_subcommands = true)]
     Publish(PublishArgs),
+    /// Manage credentials for package indexes.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
+    /// Manage static package indexes.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
+    /// Mirror package artifacts for offline or air-gapped use.
+    #[command(
+        after_help = "Use `uv help mirror` for more details.",
+        after_long_help = ""
+    )]
+    Mirror(MirrorNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -496,6 +579,12 @@ pub enum Commands {
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Manage uv's store of unpacked wheels.
+    #[command(
+        after_help = "Use `uv help store` for more details.",
+        after_long_help = ""
+    )]
+    Store(StoreNamespace),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -549,7 +638,8 @@ pub struct VersionArgs {
 
     /// Don't write a new version to the `pyproject.toml`
     ///
-    /// Instead, the version will be displayed.
+    /// Instead, the version will be displayed, along with any requirements of other workspace
+    /// members that would be updated to the new version.
     #[arg(long)]
     pub dry_run: bool,
 
@@ -719,6 +809,55 @@ pub enum CacheCommand {
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
     Prune(PruneArgs),
+    /// Force the cached index metadata for specific packages to be revalidated.
+    ///
+    /// Marks the cached Simple API responses for the given packages, across all indexes, as stale,
+    /// such that the next command that reads them sends a conditional request to the index (with
+    /// `If-None-Match` or `If-Modified-Since`) rather than reusing them as-is. If the index
+    /// reports that the response is unchanged, the cached response is reused without downloading
+    /// it again.
+    ///
+    /// Unlike `uv cache clean`, the cached responses (and any cached distributions) are retained,
+    /// which avoids re-downloading large project pages from the index.
+    Refresh(CacheRefreshArgs),
+    /// Export cache entries to an archive, e.g., to warm up the cache on another machine.
+    ///
+    /// Exports the pre-built wheels, source distributions, and the wheels built from them, along
+    /// with any Python interpreter archives cached in `UV_PYTHON_CACHE_DIR`, as a gzipped tarball.
+    /// The archive can be restored with `uv cache import`.
+    Export(CacheExportArgs),
+    /// Import cache entries from an archive created by `uv cache export`.
+    ///
+    /// Entries that already exist in the cache are skipped, such that importing the same archive
+    /// repeatedly only adds the missing entries.
+    Import(CacheImportArgs),
+    /// Verify the integrity of the cache, detecting corrupted entries.
+    ///
+    /// Re-hashes the unpacked wheels in the cache against their `RECORD` files, and checks that
+    /// the cached pointers, metadata, and built wheels are readable and complete. Corrupted
+    /// entries are typically the result of a process that was killed mid-write (e.g., by the
+    /// out-of-memory killer).
+    ///
+    /// Exits with a non-zero status if any corrupted entries are found, unless `--repair` is
+    /// provided.
+    Verify(CacheVerifyArgs),
+    /// Show statistics on the contents and usage of the cache.
+    ///
+    /// Reports the size of each cache bucket, the cache hits and misses of recent installations,
+    /// the packages that occupy the most space, and the distribution of cache entries by the time
+    /// since they were last used.
+    Stats(CacheStatsArgs),
+    /// Move the cache to another directory.
+    ///
+    /// Relocates the existing cache entries, then updates the `cache-dir` setting in the user-level
+    /// configuration file (or the file provided via `--config-file`) to point to the new
+    /// directory. Cached environments are removed rather than moved, and are recreated on demand.
+    ///
+    /// Buckets that are routed elsewhere via the `cache-roots` setting are left in place.
+    ///
+    /// The target directory must either not exist, or be empty. No other uv processes should use
+    /// the cache while it's being moved.
+    Move(CacheMoveArgs),
     /// Show the cache directory.
     ///
     ///
@@ -742,6 +881,13 @@ pub struct CleanArgs {
     pub package: Vec<PackageName>,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheRefreshArgs {
+    /// The packages for which to revalidate the cached index metadata.
+    #[arg(required = true)]
+    pub package: Vec<PackageName>,
+}
+
 #[derive(Args, Debug)]
 pub struct PruneArgs {
     /// Optimize the cache for persistence in a continuous integration environment, like GitHub
@@ -759,6 +905,322 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Evict the least-recently-used entries from the cache until it's under the given size
+    /// (e.g., `10GB` or `500MiB`).
+    ///
+    /// Wheels, source distributions, and Python interpreter archives (as cached in
+    /// `UV_PYTHON_CACHE_DIR`) are evicted in order of their last use, as weighted by the
+    /// `cache-eviction-weights` setting.
+    ///
+    /// Defaults to the `cache-max-size` setting, if any.
+    #[arg(long, value_name = "SIZE")]
+    pub max_size: Option<ByteSize>,
+
+    /// Remove any cache entries that haven't been used within the given duration (e.g., `30d`,
+    /// `12h`, or `2w`), even if they're still referenced.
+    ///
+    /// An entry is considered used when any of its files were last accessed or modified.
+    ///
+    /// When combined with `--package` or `--bucket`, only matching entries are removed.
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub older_than: Option<Duration>,
+
+    /// Remove any cache entries for the given package, even if they're still referenced.
+    ///
+    /// When combined with `--older-than` or `--bucket`, only matching entries are removed.
+    #[arg(long, value_name = "PACKAGE")]
+    pub package: Vec<PackageName>,
+
+    /// Remove any cache entries in the given bucket, even if they're still referenced.
+    ///
+    /// When combined with `--older-than` or `--package`, only matching entries are removed.
+    #[arg(long, value_enum)]
+    pub bucket: Vec<PruneBucket>,
+
+    /// Report the number of files and bytes that would be removed, without modifying the cache.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The path to write the archive to (e.g., `uv-cache.tar.gz`).
+    pub output: PathBuf,
+
+    /// Only export the entries for packages in the given lockfile (e.g., `uv.lock`).
+    ///
+    /// By default, all wheel and source distribution entries in the cache are exported.
+    #[arg(long, value_name = "PATH")]
+    pub lockfile: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The path to the archive to import, as created by `uv cache export`.
+    pub input: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheVerifyArgs {
+    /// Remove any corrupted entries from the cache.
+    ///
+    /// Removed entries are downloaded or built again the next time they're needed.
+    #[arg(long)]
+    pub repair: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheStatsArgs {
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheStatsFormat::default())]
+    pub output_format: CacheStatsFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheMoveArgs {
+    /// The directory to move the cache to.
+    pub target: PathBuf,
+}
+
+#[derive(Args)]
+pub struct StoreNamespace {
+    #[command(subcommand)]
+    pub command: StoreCommand,
+}
+
+#[derive(Subcommand)]
+pub enum StoreCommand {
+    /// Remove unused wheels from the store.
+    ///
+    /// The store holds the unpacked contents of every wheel in the cache. Wheels with a known
+    /// SHA-256 digest are stored once, keyed by that digest, and shared across all the environments
+    /// they're installed into, via hardlinks or copy-on-write clones (see `--link-mode`).
+    ///
+    /// Removes any wheel in the store that is no longer referenced by the cache. Existing
+    /// environments are unaffected, unless they were installed with `--link-mode symlink`.
+    Gc(StoreGcArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct StoreGcArgs {
+    /// Check the integrity of each wheel in the store, removing any wheel with files that are
+    /// missing or don't match the hashes in its `RECORD`.
+    ///
+    /// Removed wheels are fetched again the next time they're needed.
+    #[arg(long)]
+    pub verify: bool,
+}
+
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for an index in uv's credential store.
+    ///
+    /// The credentials are used for any request to the given URL, or to any URL under it, along
+    /// with any request for a distribution hosted by an index with that URL. A host name (e.g.,
+    /// `pypi.example.com`) applies to any HTTPS URL on that host.
+    ///
+    /// By default, the password is stored in plain text in uv's credential store, which is only
+    /// readable by the current user. With `--keyring-provider native`, the password is stored in
+    /// the operating system's credential store instead (i.e., the Keychain on macOS, the
+    /// Credential Manager on Windows, or the Secret Service on Linux).
+    Login(AuthLoginArgs),
+    /// Remove credentials for an index from uv's credential store.
+    Logout(AuthLogoutArgs),
+    /// List the credentials in uv's credential store, with the passwords masked.
+    List,
+    /// Encrypt uv's credential store.
+    ///
+    /// By default, the store is encrypted with a passphrase, which is read from
+    /// `UV_CREDENTIALS_PASSPHRASE` or prompted for. uv then requires the passphrase to read the
+    /// store, via `UV_CREDENTIALS_PASSPHRASE` (or a prompt, for the `uv auth` commands).
+    ///
+    /// With `--keyring-provider native`, the store is instead encrypted with a key held in the
+    /// operating system's credential store, such that no passphrase is required.
+    Seal(AuthSealArgs),
+    /// Decrypt uv's credential store, such that it's stored in plain text.
+    Unseal,
+}
+
+#[derive(Args)]
+pub struct AuthLoginArgs {
+    /// The URL or host of the index (e.g., `https://pypi.example.com/simple` or
+    /// `pypi.example.com`).
+    pub service: String,
+
+    /// The username to store.
+    ///
+    /// If not provided, uv will prompt for the username.
+    #[arg(long, short)]
+    pub username: Option<String>,
+
+    /// The password to store.
+    ///
+    /// If not provided, uv will prompt for the password.
+    #[arg(long, short)]
+    pub password: Option<String>,
+
+    /// Store the password in the operating system's credential store, rather than in uv's
+    /// credential store.
+    ///
+    /// Only `native` is supported.
+    #[arg(long, value_enum)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args)]
+pub struct AuthSealArgs {
+    /// Encrypt the store with a key held in the operating system's credential store, rather than
+    /// with a passphrase.
+    ///
+    /// Only `native` is supported.
+    #[arg(long, value_enum)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args)]
+pub struct AuthLogoutArgs {
+    /// The URL or host of the index (e.g., `https://pypi.example.com/simple` or
+    /// `pypi.example.com`).
+    pub service: String,
+
+    /// The username for which to remove the credentials.
+    ///
+    /// Defaults to removing the credentials for all usernames.
+    #[arg(long, short)]
+    pub username: Option<String>,
+}
+
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Generate a static package index from a directory of distributions.
+    ///
+    /// Generates a Simple API index, in both the HTML (PEP 503) and JSON (PEP 691) formats, for
+    /// the wheels and source distributions in the given directory, such that the directory can be
+    /// served by a plain file server (or uploaded to an object store) and used as an index.
+    ///
+    /// Each file is listed with its SHA-256 hash. The metadata of each wheel is extracted alongside
+    /// the wheel, as a `.metadata` file (PEP 658), such that uv can resolve against the index
+    /// without downloading the wheels themselves.
+    ///
+    /// Each project page is written as an `index.html` and an `index.json` file, such that file
+    /// servers that serve `index.html` for directory requests serve the HTML format.
+    Build(IndexBuildArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexBuildArgs {
+    /// The directory containing the wheels and source distributions to index.
+    pub directory: PathBuf,
+
+    /// The directory to which the index should be written.
+    ///
+    /// Defaults to a `simple` directory within the given directory.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    /// The URL at which the distributions will be served.
+    ///
+    /// By default, the index links to the distributions by relative URL, such that the index and
+    /// the distributions must be served from the same location. When provided, the index links to
+    /// the distributions under the given URL instead.
+    #[arg(long)]
+    pub base_url: Option<DisplaySafeUrl>,
+}
+
+#[derive(Args)]
+pub struct MirrorNamespace {
+    #[command(subcommand)]
+    pub command: MirrorCommand,
+}
+
+#[derive(Subcommand)]
+pub enum MirrorCommand {
+    /// Download the artifacts for a lockfile or a list of packages into a directory.
+    ///
+    /// Given a list of packages, downloads every wheel and source distribution of every matching
+    /// version from the configured indexes. Otherwise, downloads every wheel and source
+    /// distribution from an index in the lockfile, for all platforms. Dependencies of the given
+    /// packages are not included; to mirror a complete environment, lock it first.
+    ///
+    /// Each artifact is verified against the hash declared by the lockfile or the index. Artifacts
+    /// that are already present in the output directory with a matching hash are skipped, such
+    /// that repeated syncs only download new artifacts.
+    ///
+    /// With `--build-index`, the output directory is then indexed as with `uv index build`, such
+    /// that it can be served as a static index. To republish the artifacts to a remote index
+    /// instead, upload the output directory with `uv publish`.
+    Sync(MirrorSyncArgs),
+}
+
+#[derive(Args)]
+pub struct MirrorSyncArgs {
+    /// The packages to mirror, as PEP 508 requirements (e.g., `flask>=3`).
+    ///
+    /// All versions of the package that satisfy the requirement are mirrored.
+    #[arg(value_name = "PACKAGE", conflicts_with = "lockfile")]
+    pub packages: Vec<Requirement<VerbatimParsedUrl>>,
+
+    /// The lockfile to mirror.
+    ///
+    /// Defaults to the `uv.lock` in the current directory, if no packages are provided.
+    #[arg(long, value_parser = parse_file_path)]
+    pub lockfile: Option<PathBuf>,
+
+    /// The directory to which the artifacts should be written.
+    #[arg(long, short)]
+    pub output: PathBuf,
+
+    /// Generate a static Simple API index for the output directory, as with `uv index build`.
+    #[arg(long)]
+    pub build_index: bool,
+
+    /// The URL at which the artifacts will be served, when generating an index.
+    ///
+    /// By default, the index links to the artifacts by relative URL.
+    #[arg(long, requires = "build_index")]
+    pub base_url: Option<DisplaySafeUrl>,
+
+    #[command(flatten)]
+    pub index_args: IndexArgs,
+
+    /// The strategy to use when searching for packages across multiple index URLs.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_INDEX_STRATEGY,
+        help_heading = "Index options"
+    )]
+    pub index_strategy: Option<IndexStrategy>,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_KEYRING_PROVIDER,
+        help_heading = "Index options"
+    )]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
 }
 
 #[derive(Args)]
@@ -796,6 +1258,19 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Install(PipInstallArgs),
+    /// Build or download wheels for packages and their dependencies into a directory.
+    ///
+    /// The requirements are resolved for the current environment, and a wheel is collected for
+    /// each package in the resolution: pre-built wheels are downloaded as-is, while source
+    /// distributions are built into wheels.
+    ///
+    /// The resulting directory can be used as a `--find-links` source, e.g., for offline
+    /// installation.
+    #[command(
+        after_help = "Use `uv help pip wheel` for more details.",
+        after_long_help = ""
+    )]
+    Wheel(PipWheelArgs),
     /// Uninstall packages from an environment.
     #[command(
         after_help = "Use `uv help pip uninstall` for more details.",
@@ -833,6 +1308,18 @@ pub enum PipCommand {
         after_long_help = ""
     )]
     Check(PipCheckArgs),
+    /// Scan packages in an environment for known vulnerabilities.
+    ///
+    /// The installed packages, or the packages pinned in a `requirements.txt` or `pylock.toml`
+    /// file, are checked against the OSV database, which includes the advisories of the Python
+    /// Packaging Advisory Database.
+    ///
+    /// Exits with a non-zero status if any vulnerabilities are found.
+    #[command(
+        after_help = "Use `uv help pip audit` for more details.",
+        after_long_help = ""
+    )]
+    Audit(PipAuditArgs),
 }
 
 #[derive(Subcommand)]
@@ -982,6 +1469,58 @@ pub enum ProjectCommand {
     Export(ExportArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Verify the integrity of the project environment.
+    ///
+    /// Checks that the installed packages match the lockfile, reporting any packages that are
+    /// missing, extraneous, or installed at a different version; and re-hashes the files of each
+    /// installed package against its `RECORD`, reporting any files that are missing or have been
+    /// modified since installation.
+    ///
+    /// The lockfile is used as-is, without checking if it is up-to-date.
+    ///
+    /// Use `--fix` to repair the environment.
+    #[command(
+        after_help = "Use `uv help verify` for more details.",
+        after_long_help = ""
+    )]
+    Verify(VerifyArgs),
+    /// Remove extraneous packages from the project environment.
+    ///
+    /// Any installed packages that aren't required by the project, given the selected extras and
+    /// dependency groups, are removed, along with an explanation of why each package isn't
+    /// required: for example, because it's only required by a dependency group that isn't
+    /// enabled, or because it isn't present in the lockfile at all.
+    ///
+    /// Unlike `uv sync`, missing or outdated packages are neither installed nor upgraded, which
+    /// makes `uv prune` useful alongside `uv sync --inexact`.
+    ///
+    /// The lockfile is used as-is, without checking if it is up-to-date.
+    #[command(
+        after_help = "Use `uv help prune` for more details.",
+        after_long_help = ""
+    )]
+    Prune(ProjectPruneArgs),
+    /// Populate the cache from the project's lockfile.
+    ///
+    /// Downloads (and, if necessary, builds) every distribution that `uv sync` would install for
+    /// the current platform, given the selected extras and dependency groups, without installing
+    /// anything, such that a subsequent `uv sync --offline` can succeed without network access.
+    ///
+    /// The lockfile is used as-is, without checking if it is up-to-date.
+    #[command(
+        after_help = "Use `uv help prefetch` for more details.",
+        after_long_help = ""
+    )]
+    Prefetch(PrefetchArgs),
+    /// Run the tasks defined in the project.
+    ///
+    /// Tasks are defined in the `[tool.uv.tasks]` table of the project's `pyproject.toml`, and are
+    /// run in the project environment.
+    #[command(
+        after_help = "Use `uv help task` for more details.",
+        after_long_help = ""
+    )]
+    Task(TaskNamespace),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -1141,6 +1680,53 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// Parse a duration (e.g., `30d`, `12h`, or `2w`), where days are treated as 24 hours.
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    let span = jiff::Span::from_str(input).map_err(|err| err.to_string())?;
+    let duration = span
+        .to_duration(jiff::SpanRelativeTo::days_are_24_hours())
+        .map_err(|err| err.to_string())?;
+    Duration::try_from(duration).map_err(|_| format!("duration must be positive: `{input}`"))
+}
+
+/// A request to re-pin the packages locked against one index to another, as in
+/// `old=https://pypi.org/simple,new=https://mirror.example.com/simple`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexMigration {
+    /// The index that packages are currently locked against.
+    pub old: IndexUrl,
+    /// The index that packages should be locked against.
+    pub new: IndexUrl,
+}
+
+/// Parse a `--migrate-index` argument into an [`IndexMigration`].
+fn parse_index_migration(input: &str) -> Result<IndexMigration, String> {
+    let mut old = None;
+    let mut new = None;
+    for pair in input.split(',') {
+        let Some((key, value)) = pair.split_once('=') else {
+            return Err(format!(
+                "expected `old=<url>,new=<url>`, but found no `=` in `{pair}`"
+            ));
+        };
+        let url = IndexUrl::from_str(value.trim()).map_err(|err| err.to_string())?;
+        match key.trim() {
+            "old" => old = Some(url),
+            "new" => new = Some(url),
+            key => {
+                return Err(format!(
+                    "unknown key `{key}`; expected `old=<url>,new=<url>`"
+                ));
+            }
+        }
+    }
+    match (old, new) {
+        (Some(old), Some(new)) => Ok(IndexMigration { old, new }),
+        (None, _) => Err("missing `old=<url>`".to_string()),
+        (_, None) => Err("missing `new=<url>`".to_string()),
+    }
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct PipCompileArgs {
@@ -1469,6 +2055,14 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
+    /// Include the line of the file that declared each requirement in the comment annotations
+    /// (e.g., `# via -r requirements.in:3` or `# via project (pyproject.toml:8)`).
+    #[arg(long, overrides_with("no_emit_source_lines"))]
+    pub emit_source_lines: bool,
+
+    #[arg(long, overrides_with("emit_source_lines"), hide = true)]
+    pub no_emit_source_lines: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -1496,6 +2090,9 @@ pub struct PipSyncArgs {
     /// extract the requirements for the relevant project.
     ///
     /// If `-` is provided, then requirements will be read from stdin.
+    ///
+    /// If multiple files are provided, they are layered in order: a package listed in a later file
+    /// replaces any requirements for the same package in earlier files.
     #[arg(required(true), value_parser = parse_file_path)]
     pub src_file: Vec<PathBuf>,
 
@@ -1643,7 +2240,15 @@ pub struct PipSyncArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub no_break_system_packages: bool,
 
-    /// Install packages into the specified directory, rather than into the virtual or system Python
+    /// Allow uv to replace or remove packages that were installed by Conda.
+    ///
+    /// By default, when the target environment is a Conda environment, uv refuses to modify
+    /// packages that are managed by Conda, as doing so would leave Conda's record of the
+    /// environment out of sync with its contents.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Install packages into the specified directory, rather than into the virtual or system Python
     /// environment. The packages will be installed at the top-level of the directory.
     #[arg(long, conflicts_with = "prefix")]
     pub target: Option<PathBuf>,
@@ -1658,6 +2263,15 @@ pub struct PipSyncArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Install exclusively from the distributions in the given directory, without accessing the
+    /// network.
+    ///
+    /// Implies `--no-index` and `--offline`, with the directory used as the sole `--find-links`
+    /// location. If any requirement can't be satisfied by a distribution in the directory, uv
+    /// will exit with an error listing every missing distribution.
+    #[arg(long, value_name = "DIR")]
+    pub find_links_only: Option<PathBuf>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
@@ -1781,6 +2395,9 @@ pub struct PipInstallArgs {
     /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
     /// requirements for the relevant project.
     ///
+    /// If a `uv.lock` file is provided, uv will install the locked packages for the current
+    /// platform, without updating the lockfile.
+    ///
     /// If `-` is provided, then requirements will be read from stdin.
     #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
     pub requirements: Vec<PathBuf>,
@@ -1953,6 +2570,14 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub no_break_system_packages: bool,
 
+    /// Allow uv to replace or remove packages that were installed by Conda.
+    ///
+    /// By default, when the target environment is a Conda environment, uv refuses to modify
+    /// packages that are managed by Conda, as doing so would leave Conda's record of the
+    /// environment out of sync with its contents.
+    #[arg(long)]
+    pub force: bool,
+
     /// Install packages into the specified directory, rather than into the virtual or system Python
     /// environment. The packages will be installed at the top-level of the directory.
     #[arg(long, conflicts_with = "prefix")]
@@ -2063,6 +2688,26 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Write a JSON report of the installation to the given path.
+    ///
+    /// The report describes each resolved distribution, including its version, the URL and hashes
+    /// of the chosen artifact, and whether it was downloaded, built, served from the cache, or
+    /// already installed. It follows the format of pip's installation report, with an additional
+    /// `source` field for each distribution.
+    ///
+    /// If `-` is provided, the report is written to stdout. When combined with `--dry-run`, the
+    /// report describes the installation that would be performed.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
+    /// Select the output format for a dry run.
+    ///
+    /// With `json`, the changes that would be made to the environment are written to stdout: each
+    /// package that would be installed, upgraded, downgraded, reinstalled, or removed, along with
+    /// the installed and target versions and the chosen artifact.
+    #[arg(long, value_enum, default_value_t = SyncFormat::default(), requires = "dry_run")]
+    pub output_format: SyncFormat,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2082,6 +2727,204 @@ pub struct PipInstallArgs {
     pub compat_args: compat::PipInstallCompatArgs,
 }
 
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipWheelArgs {
+    /// Collect wheels for all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
+    #[arg(group = "sources")]
+    pub package: Vec<String>,
+
+    /// Collect wheels for all packages listed in the given `requirements.txt` files.
+    ///
+    /// If a `pyproject.toml`, `setup.py`, or `setup.cfg` file is provided, uv will extract the
+    /// requirements for the relevant project.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long, short, alias = "constraint", env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    ///
+    /// While constraints are _additive_, in that they're combined with the requirements of the
+    /// constituent packages, overrides are _absolute_, in that they completely replace the
+    /// requirements of the constituent packages.
+    #[arg(long, alias = "override", env = EnvVars::UV_OVERRIDE, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    #[arg(long, short, alias = "build-constraint", env = EnvVars::UV_BUILD_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub build_constraints: Vec<Maybe<PathBuf>>,
+
+    /// Include optional dependencies from the specified extra name; may be provided more than once.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    ///
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// The directory into which the wheels should be written.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(long, short, value_name = "DIR")]
+    pub wheel_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Ignore package dependencies, instead only collecting wheels for those packages explicitly
+    /// listed on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash.
+    ///
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter for which wheels should be collected.
+    ///
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to the first Python found in the system `PATH`. Source distributions
+    /// are built with this interpreter.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Use the system Python to collect wheels.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Don't build source distributions.
+    ///
+    /// When enabled, resolving will not run arbitrary Python code. The cached wheels of
+    /// already-built source distributions will be reused, but operations that require building
+    /// distributions will exit with an error.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't use pre-built wheels.
+    ///
+    /// The given packages will be built from source. The resolver will still use pre-built wheels
+    /// to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only use pre-built wheels; don't build source distributions.
+    ///
+    /// When enabled, resolving will not run code from the given packages. The cached wheels of
+    /// already-built source distributions will be reused, but operations that require building
+    /// distributions will exit with an error.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct PipUninstallArgs {
@@ -2112,8 +2955,8 @@ pub struct PipUninstallArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2155,6 +2998,14 @@ pub struct PipUninstallArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub no_break_system_packages: bool,
 
+    /// Allow uv to remove packages that were installed by Conda.
+    ///
+    /// By default, when the target environment is a Conda environment, uv refuses to remove
+    /// packages that are managed by Conda, as doing so would leave Conda's record of the
+    /// environment out of sync with its contents.
+    #[arg(long)]
+    pub force: bool,
+
     /// Uninstall packages from the specified `--target` directory.
     #[arg(long, conflicts_with = "prefix")]
     pub target: Option<PathBuf>,
@@ -2177,6 +3028,19 @@ pub struct PipFreezeArgs {
     #[arg(long)]
     pub exclude_editable: bool,
 
+    /// Exclude the specified package(s) from the output.
+    #[arg(long)]
+    pub r#exclude: Vec<PackageName>,
+
+    /// Include the hashes of the installed distributions in the output, for use with
+    /// `--require-hashes`.
+    ///
+    /// Hashes are only available for distributions whose hashes were recorded at install time
+    /// (e.g., when installing with `--require-hashes`); a warning is shown for any distribution
+    /// without recorded hashes.
+    #[arg(long)]
+    pub hashes: bool,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2239,6 +3103,13 @@ pub struct PipListArgs {
     #[arg(long)]
     pub r#exclude: Vec<PackageName>,
 
+    /// Only include packages that were installed by uv.
+    ///
+    /// Packages installed by other tools (e.g., Conda or pip) are identified by the `INSTALLER`
+    /// file in their `.dist-info` directory, and omitted from the output.
+    #[arg(long)]
+    pub only_uv_managed: bool,
+
     /// Select the output format.
     #[arg(long, value_enum, default_value_t = ListFormat::default())]
     pub format: ListFormat,
@@ -2247,12 +3118,23 @@ pub struct PipListArgs {
     ///
     /// The latest version of each package will be shown alongside the installed version. Up-to-date
     /// packages will be omitted from the output.
+    ///
+    /// If the latest version doesn't satisfy the requirements of the other installed packages or
+    /// the `--constraints`, the latest version that does is shown as well.
     #[arg(long, overrides_with("no_outdated"))]
     pub outdated: bool,
 
     #[arg(long, overrides_with("outdated"), hide = true)]
     pub no_outdated: bool,
 
+    /// Constrain the upgrades shown by `--outdated` using the given requirements files.
+    ///
+    /// Along with the latest version, `--outdated` shows the latest version that satisfies the
+    /// requirements of the other installed packages and the given constraints, i.e., the version
+    /// that's available to upgrade to without breaking the environment.
+    #[arg(long, short, alias = "constraint", value_delimiter = ' ', value_parser = parse_maybe_file_path, requires = "outdated")]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2302,6 +3184,20 @@ pub struct PipListArgs {
 
 #[derive(Args)]
 pub struct PipCheckArgs {
+    /// Explain each incompatibility, showing the chain of installed packages that require the
+    /// package with the broken requirement, and the other packages that require the same
+    /// dependency.
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Install or replace the missing and incompatible dependencies, to restore the consistency of
+    /// the environment.
+    ///
+    /// Only the packages named in the broken requirements are changed, unless resolving them
+    /// requires further changes. The environment is checked again afterwards.
+    #[arg(long)]
+    pub fix: bool,
+
     /// The Python interpreter for which packages should be checked.
     ///
     /// By default, uv checks packages in a virtual environment but will check packages in a system
@@ -2336,26 +3232,29 @@ pub struct PipCheckArgs {
 }
 
 #[derive(Args)]
-pub struct PipShowArgs {
-    /// The package(s) to display.
-    pub package: Vec<PackageName>,
-
-    /// Validate the Python environment, to detect packages with missing dependencies and other
-    /// issues.
-    #[arg(long, overrides_with("no_strict"))]
-    pub strict: bool,
+pub struct PipAuditArgs {
+    /// Audit the packages in the given `requirements.txt` or `pylock.toml` files, instead of the
+    /// packages installed in an environment.
+    ///
+    /// Each package must be pinned to an exact version, as in the output of `uv pip compile` or
+    /// `uv export`.
+    #[arg(long, short, alias = "requirement", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
 
-    #[arg(long, overrides_with("strict"), hide = true)]
-    pub no_strict: bool,
+    /// Show the commands to upgrade the vulnerable packages to the nearest version with fixes for
+    /// all of their vulnerabilities.
+    #[arg(long)]
+    pub fix: bool,
 
-    /// Show the full list of installed files for each package.
-    #[arg(short, long)]
-    pub files: bool,
+    /// Ignore a vulnerability by its ID or any of its aliases, e.g., `PYSEC-2023-74` or
+    /// `CVE-2023-32681`.
+    #[arg(long, value_name = "ID")]
+    pub ignore_vuln: Vec<String>,
 
-    /// The Python interpreter to find the package in.
+    /// The Python interpreter for which packages should be audited.
     ///
-    /// By default, uv looks for packages in a virtual environment but will look for packages in a
-    /// system Python environment if no virtual environment is found.
+    /// By default, uv audits packages in a virtual environment but will audit packages in a system
+    /// Python environment if no virtual environment is found.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -2365,10 +3264,11 @@ pub struct PipShowArgs {
         verbatim_doc_comment,
         help_heading = "Python options",
         value_parser = parse_maybe_string,
+        conflicts_with = "requirements",
     )]
     pub python: Option<Maybe<String>>,
 
-    /// Show a package in the system Python environment.
+    /// Audit packages in the system Python environment.
     ///
     /// Disables discovery of virtual environments.
     ///
@@ -2383,18 +3283,89 @@ pub struct PipShowArgs {
 
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
-
-    #[command(flatten)]
-    pub compat_args: compat::PipGlobalCompatArgs,
 }
 
 #[derive(Args)]
-pub struct PipTreeArgs {
-    /// Show the version constraint(s) imposed on each package.
-    #[arg(long)]
-    pub show_version_specifiers: bool,
-
-    #[command(flatten)]
+pub struct PipShowArgs {
+    /// The package(s) to display.
+    pub package: Vec<PackageName>,
+
+    /// Validate the Python environment, to detect packages with missing dependencies and other
+    /// issues.
+    #[arg(long, overrides_with("no_strict"))]
+    pub strict: bool,
+
+    #[arg(long, overrides_with("strict"), hide = true)]
+    pub no_strict: bool,
+
+    /// Show the full list of installed files for each package.
+    #[arg(short, long)]
+    pub files: bool,
+
+    /// Show the entry points declared by each package, e.g., its console scripts.
+    #[arg(long)]
+    pub entry_points: bool,
+
+    /// Show all installed packages that depend on each package, directly or transitively.
+    ///
+    /// These are the packages that may break if the package is removed.
+    #[arg(long)]
+    pub reverse: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ShowFormat::default())]
+    pub output_format: ShowFormat,
+
+    /// The Python interpreter to find the package in.
+    ///
+    /// By default, uv looks for packages in a virtual environment but will look for packages in a
+    /// system Python environment if no virtual environment is found.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Show a package in the system Python environment.
+    ///
+    /// Disables discovery of virtual environments.
+    ///
+    /// See `uv help python` for details on Python discovery.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    #[command(flatten)]
+    pub compat_args: compat::PipGlobalCompatArgs,
+}
+
+#[derive(Args)]
+pub struct PipTreeArgs {
+    /// Show the version constraint(s) imposed on each package.
+    #[arg(long)]
+    pub show_version_specifiers: bool,
+
+    /// Select the output format.
+    ///
+    /// In the JSON format, each package includes the requirement that led to it, and packages
+    /// whose dependencies were already displayed are marked as `deduplicated`.
+    #[arg(long, value_enum, default_value_t = TreeFormat::default())]
+    pub output_format: TreeFormat,
+
+    #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
     /// Validate the Python environment, to detect packages with missing dependencies and other
@@ -2458,15 +3429,22 @@ pub struct BuildArgs {
     /// The workspace will be discovered from the provided source directory, or the current
     /// directory if no source directory is provided.
     ///
+    /// May be provided multiple times, in which case the packages are built in dependency order,
+    /// such that each package is built after the workspace members it depends on.
+    ///
     /// If the workspace member does not exist, uv will exit with an error.
     #[arg(long, conflicts_with("all_packages"))]
-    pub package: Option<PackageName>,
+    pub package: Vec<PackageName>,
 
     /// Builds all packages in the workspace.
     ///
     /// The workspace will be discovered from the provided source directory, or the current
     /// directory if no source directory is provided.
     ///
+    /// The packages are built in dependency order, such that each package is built after the
+    /// workspace members it depends on, into a shared output directory. Packages with the same
+    /// build requirements share the resolution of their build environment.
+    ///
     /// If the workspace member does not exist, uv will exit with an error.
     #[arg(long, alias = "all", conflicts_with("package"))]
     pub all_packages: bool,
@@ -2513,6 +3491,30 @@ pub struct BuildArgs {
     #[arg(long, conflicts_with = "list")]
     pub force_pep517: bool,
 
+    /// Build each distribution twice and check that the builds are identical.
+    ///
+    /// Fails if the digest of any distribution differs between the builds. Use `SOURCE_DATE_EPOCH`
+    /// to set the timestamps in the archives.
+    #[arg(long, conflicts_with = "list")]
+    pub reproducible_check: bool,
+
+    /// Build the wheel from the source distribution, and check that it contains all files of a
+    /// wheel built from the source tree.
+    ///
+    /// The source distribution is extracted into a temporary directory to build the wheel, such
+    /// that files missing from the source distribution are caught before they break users building
+    /// from the source distribution. Fails with a list of the files that are in the wheel built
+    /// from the source tree, but missing from the wheel built from the source distribution.
+    #[arg(long, conflicts_with_all = ["sdist", "wheel", "list"])]
+    pub from_sdist: bool,
+
+    /// Select the output format.
+    ///
+    /// The JSON output lists each built source distribution and wheel with its path, filename,
+    /// tags, size, and SHA-256 digest, along with the build backend that built it.
+    #[arg(long, value_enum, default_value_t = BuildFormat::default(), conflicts_with = "list")]
+    pub output_format: BuildFormat,
+
     /// Constrain build dependencies using the given requirements files when building distributions.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -2580,6 +3582,23 @@ pub struct BuildArgs {
     )]
     pub python: Option<Maybe<String>>,
 
+    /// The platform for which wheels should be built.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `aarch64-unknown-linux-gnu` or
+    /// `x86_64-apple-darwin`.
+    ///
+    /// The build environment is still resolved and installed for the current platform, as the
+    /// build requirements run on the current platform. The build backend is configured for a cross
+    /// build through `_PYTHON_HOST_PLATFORM`, `CARGO_BUILD_TARGET` and, for macOS targets,
+    /// `ARCHFLAGS` and `MACOSX_DEPLOYMENT_TARGET`. A cross compiler for extension modules must be
+    /// configured separately, e.g., with `CC="zig cc -target aarch64-linux-gnu"`.
+    ///
+    /// The uv build backend tags the wheel and names the extension modules for the target
+    /// platform.
+    #[arg(long, help_heading = "Python options")]
+    pub python_platform: Option<TargetTriple>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -2718,8 +3737,8 @@ pub struct VenvArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2873,6 +3892,20 @@ pub struct InitArgs {
     #[arg(long, conflicts_with_all=["app", "lib", "package", "build_backend", "description"])]
     pub r#script: bool,
 
+    /// Create the project from a template.
+    ///
+    /// Accepts the name of a built-in template (`cli`, `lib`, `fastapi`, or `maturin`), a Git URL
+    /// (e.g., `git+https://github.com/org/template@main`), or a path to a local directory.
+    ///
+    /// Occurrences of `{{ project_name }}`, `{{ module_name }}`, `{{ description }}`,
+    /// `{{ requires_python }}`, `{{ python_version }}`, and `{{ build_system }}` in the template's
+    /// file names and contents are replaced with the corresponding values for the new project.
+    #[arg(
+        long,
+        conflicts_with_all = ["script", "app", "lib", "bare", "package", "no_package", "no_description", "no_readme", "author_from"]
+    )]
+    pub template: Option<String>,
+
     /// Set the project description.
     #[arg(long, conflicts_with = "script", overrides_with = "no_description")]
     pub description: Option<String>,
@@ -3193,6 +4226,31 @@ pub struct RunArgs {
     #[arg(long, conflicts_with = "all_packages")]
     pub package: Option<PackageName>,
 
+    /// Run the command in each workspace member.
+    ///
+    /// The command runs once per workspace member, from the member's directory, in the workspace's
+    /// environment. The status of each run is reported as it completes; if the command fails in
+    /// any member, uv exits with the exit code of the first failing member.
+    ///
+    /// Implies `--all-packages`.
+    #[arg(long, conflicts_with_all = ["package", "no_project", "script", "gui_script"])]
+    pub each_package: bool,
+
+    /// Skip the given workspace member when running with `--each-package`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "each_package", value_name = "PACKAGE")]
+    pub skip_package: Vec<PackageName>,
+
+    /// The maximum number of workspace members to run the command in at once, when running with
+    /// `--each-package`.
+    ///
+    /// Defaults to 1, i.e., the command runs in each member sequentially. When running in multiple
+    /// members at once, the output of each member is captured and printed once the command
+    /// completes in that member, such that the output of concurrent runs isn't interleaved.
+    #[arg(long, requires = "each_package")]
+    pub jobs: Option<NonZeroUsize>,
+
     /// Avoid discovering the project or workspace.
     ///
     /// Instead of searching for projects in the current directory and parent directories, run in an
@@ -3250,6 +4308,10 @@ pub struct SyncArgs {
     pub extra: Option<Vec<ExtraName>>,
 
     /// Select the output format.
+    ///
+    /// When combined with `--dry-run`, the JSON output includes the install plan: each package that
+    /// would be installed, upgraded, downgraded, reinstalled, or removed, along with the chosen
+    /// artifact.
     #[arg(long, value_enum, default_value_t = SyncFormat::default())]
     pub output_format: SyncFormat,
 
@@ -3348,6 +4410,13 @@ pub struct SyncArgs {
     #[arg(long, overrides_with("inexact"), hide = true)]
     pub exact: bool,
 
+    /// Explain why each extraneous package is removed from the environment.
+    ///
+    /// For each package removed by an exact sync, reports whether the package is absent from the
+    /// lockfile, or only required by an extra or dependency group that isn't enabled.
+    #[arg(long, conflicts_with = "inexact")]
+    pub prune_explain: bool,
+
     /// Sync dependencies to the active virtual environment.
     ///
     /// Instead of creating or updating the virtual environment for the project or script, the
@@ -3362,6 +4431,50 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Install packages into the specified directory, rather than into the project environment.
+    /// The packages will be installed at the top-level of the directory.
+    ///
+    /// The project environment is neither created nor modified; instead, the project's Python
+    /// interpreter is used to install the locked packages into the directory, e.g., to build an
+    /// AWS Lambda deployment package.
+    #[arg(long, group = "install_layout", conflicts_with_all = ["active", "script"])]
+    pub target: Option<PathBuf>,
+
+    /// Install packages into `lib`, `bin`, and other top-level folders under the specified
+    /// directory, as if a virtual environment were present at that location.
+    ///
+    /// The project environment is neither created nor modified; instead, the project's Python
+    /// interpreter is used to install the locked packages into the directory. Scripts will
+    /// reference the installing interpreter, so use `--python` to select the interpreter that will
+    /// be present on the target system (e.g., `/usr/bin/python3`).
+    #[arg(long, group = "install_layout", conflicts_with_all = ["active", "script"])]
+    pub prefix: Option<PathBuf>,
+
+    /// Do not install entry points or other scripts into the `--target` or `--prefix` directory.
+    ///
+    /// By default, console scripts, GUI scripts, and any scripts included in a wheel's `.data`
+    /// directory are installed into the `bin` directory (`Scripts` on Windows) of the `--target`
+    /// or `--prefix` directory.
+    #[arg(long, requires = "install_layout")]
+    pub no_scripts: bool,
+
+    /// Expose the scripts installed in the project environment in the specified directory.
+    ///
+    /// After syncing, a launcher is created in the directory for each script installed in the
+    /// project environment (e.g., `pytest`), pointing into the environment, such that the scripts
+    /// can be invoked without activating the environment, as long as the directory is on the
+    /// `PATH`. Launchers for scripts that are no longer installed are removed.
+    #[arg(long, conflicts_with_all = ["install_layout", "script"])]
+    pub expose_scripts: Option<PathBuf>,
+
+    /// Refuse to sync if the total size of the distributions to be downloaded exceeds the given
+    /// size (e.g., `500MB` or `2GiB`).
+    ///
+    /// Sizes are estimated from the file sizes reported by the index; distributions for which the
+    /// index does not report a size are not counted.
+    #[arg(long, env = EnvVars::UV_MAX_DOWNLOAD_SIZE, value_name = "SIZE")]
+    pub max_download_size: Option<ByteSize>,
+
     /// Do not install the current project.
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -3390,6 +4503,16 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_install_package: Vec<PackageName>,
 
+    /// Reinstall only the given package from the lockfile, leaving the rest of the environment
+    /// untouched.
+    ///
+    /// The locked version of the package is reinstalled into the existing environment, even if
+    /// it's already installed; no other packages are installed, upgraded, or removed. This is
+    /// useful for quickly picking up changes to a single local dependency in a large project,
+    /// but can result in a broken environment if the package's dependencies are out of date.
+    #[arg(long, value_name = "PACKAGE", conflicts_with_all = ["script", "no_install_project", "no_install_workspace", "no_install_package"])]
+    pub only_package: Option<PackageName>,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
@@ -3406,6 +4529,18 @@ pub struct SyncArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Install exclusively from the distributions in the given directory, without accessing the
+    /// network.
+    ///
+    /// Every distribution in the lockfile that would otherwise be downloaded from a registry is
+    /// installed from the file of the same name in the directory (e.g., as populated by
+    /// `pip download`). If any distribution is missing, uv will exit with an error listing every
+    /// missing file.
+    ///
+    /// Implies `--frozen` and `--offline`. Any build dependencies are resolved from the directory.
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["locked", "upgrade"])]
+    pub find_links_only: Option<PathBuf>,
+
     /// Perform a dry run, without writing the lockfile or modifying the project environment.
     ///
     /// In dry-run mode, uv will resolve the project's dependencies and report on the resulting
@@ -3513,6 +4648,7 @@ pub struct SyncArgs {
 }
 
 #[derive(Args)]
+#[command(group = clap::ArgGroup::new("snapshot_action").multiple(false).conflicts_with_all(["check", "check_exists", "dry_run", "minimize", "migrate_index", "upgrade", "upgrade_package", "script"]))]
 pub struct LockArgs {
     /// Check if the lockfile is up-to-date.
     ///
@@ -3536,6 +4672,71 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "check_exists", conflicts_with = "check")]
     pub dry_run: bool,
 
+    /// Write the lockfile in the compact format.
+    ///
+    /// In the compact format, the URL prefixes shared across wheels and source distributions (e.g.,
+    /// `https://files.pythonhosted.org/packages`) are written once to a `[url-prefixes]` table and
+    /// referenced by name from each artifact, which substantially reduces the size of large
+    /// lockfiles. Likewise, lists of wheels shared by multiple packages are written once to a
+    /// `[wheel-lists]` table.
+    ///
+    /// Once a lockfile is written in the compact format, subsequent operations will preserve it.
+    /// Compact lockfiles use lockfile revision 3, and cannot be read by versions of uv that predate
+    /// the format.
+    #[arg(long, conflicts_with = "check_exists", conflicts_with = "check")]
+    pub minimize: bool,
+
+    /// Re-pin the packages locked against one index to another, without re-resolving.
+    ///
+    /// Accepts a pair of index URLs in the format `old=<url>,new=<url>` (e.g.,
+    /// `old=https://pypi.org/simple,new=https://mirror.example.com/simple`).
+    ///
+    /// The locked versions are left unchanged. Each wheel and source distribution locked against
+    /// the old index must be available on the new index, with a matching hash; the lockfile is then
+    /// rewritten to point to the new locations. If the new index doesn't report a hash with the
+    /// same algorithm as the lockfile, the artifact is downloaded to compute one.
+    ///
+    /// The project's index configuration should be updated to the new index URL separately, or
+    /// subsequent operations will re-resolve against the old index.
+    #[arg(
+        long,
+        value_parser = parse_index_migration,
+        conflicts_with_all = ["check_exists", "check", "upgrade", "upgrade_package"]
+    )]
+    pub migrate_index: Option<IndexMigration>,
+
+    /// Save a copy of the current lockfile as a named snapshot, without updating it.
+    ///
+    /// Snapshots are stored in the `.uv/snapshots` directory at the project root, and can be
+    /// restored with `--rollback`, e.g., to revert an experimental upgrade.
+    #[arg(long, value_name = "NAME", group = "snapshot_action")]
+    pub snapshot: Option<String>,
+
+    /// Restore the lockfile from a named snapshot.
+    ///
+    /// Replaces the lockfile with the snapshot previously saved via `--snapshot`. The project
+    /// environment is not updated; run `uv sync` to install the restored versions.
+    #[arg(long, value_name = "NAME", group = "snapshot_action")]
+    pub rollback: Option<String>,
+
+    /// List the saved lockfile snapshots, along with their creation time and package count.
+    #[arg(long, group = "snapshot_action")]
+    pub list_snapshots: bool,
+
+    /// Include links to the project metadata (e.g., home page and changelog) of changed packages.
+    ///
+    /// When packages are added, updated, or removed, uv will fetch the core metadata of the new
+    /// version (or, for removed packages, the previous version) from the package's index, and
+    /// display the project's home page and changelog URLs alongside the change. Links are only
+    /// available for packages with wheels on indexes that serve core metadata separately, as
+    /// described in PEP 658.
+    #[arg(long)]
+    pub show_links: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = LockFormat::default())]
+    pub output_format: LockFormat,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -3627,6 +4828,16 @@ pub struct AddArgs {
     )]
     pub group: Option<GroupName>,
 
+    /// Include another dependency group in the group specified by `--group`.
+    ///
+    /// Adds an `{ include-group = "..." }` entry to the group, such that the group contains all of
+    /// the requirements of the included group. The included group must exist, and the includes
+    /// must not form a cycle.
+    ///
+    /// May be provided more than once.
+    #[arg(long, group = "sources", requires = "group")]
+    pub include_group: Vec<GroupName>,
+
     /// Add the requirements as editable.
     #[arg(long, overrides_with = "no_editable")]
     pub editable: bool,
@@ -3666,6 +4877,14 @@ pub struct AddArgs {
     #[arg(long, value_enum)]
     pub bounds: Option<AddBoundsKind>,
 
+    /// Refuse to sync if the total size of the distributions to be downloaded exceeds the given
+    /// size (e.g., `500MB` or `2GiB`).
+    ///
+    /// Sizes are estimated from the file sizes reported by the index; distributions for which the
+    /// index does not report a size are not counted.
+    #[arg(long, env = EnvVars::UV_MAX_DOWNLOAD_SIZE, value_name = "SIZE")]
+    pub max_download_size: Option<ByteSize>,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
     pub rev: Option<String>,
@@ -3777,7 +4996,7 @@ pub struct AddArgs {
 #[derive(Args)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true)]
+    #[arg(required_unless_present = "include_group")]
     pub packages: Vec<Requirement<VerbatimParsedUrl>>,
 
     /// Remove the packages from the development dependency group.
@@ -3804,6 +5023,13 @@ pub struct RemoveArgs {
     )]
     pub group: Option<GroupName>,
 
+    /// Remove the `include-group` entry for another dependency group from the group specified by
+    /// `--group`.
+    ///
+    /// May be provided more than once.
+    #[arg(long, requires = "group")]
+    pub include_group: Vec<GroupName>,
+
     /// Avoid syncing the virtual environment after re-locking the project.
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
@@ -4034,13 +5260,386 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with = "all_packages")]
     pub package: Option<PackageName>,
 
-    /// Prune the given package from the dependency tree.
+    /// Prune the given package from the dependency tree.
+    ///
+    /// Pruned packages will be excluded from the exported requirements file, as will any
+    /// dependencies that are no longer required after the pruned package is removed.
+    #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE")]
+    pub prune: Vec<PackageName>,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Disable the development dependency group.
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long)]
+    pub no_default_groups: bool,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub all_groups: bool,
+
+    /// Exclude comment annotations indicating the source of each package.
+    #[arg(long, overrides_with("annotate"))]
+    pub no_annotate: bool,
+
+    #[arg(long, overrides_with("no_annotate"), hide = true)]
+    pub annotate: bool,
+
+    /// Exclude the comment header at the top of the generated output file.
+    #[arg(long, overrides_with("header"))]
+    pub no_header: bool,
+
+    #[arg(long, overrides_with("no_header"), hide = true)]
+    pub header: bool,
+
+    /// Export any editable dependencies, including the project and any workspace members, as
+    /// non-editable.
+    #[arg(long)]
+    pub no_editable: bool,
+
+    /// Include hashes for all dependencies.
+    #[arg(long, overrides_with("no_hashes"), hide = true)]
+    pub hashes: bool,
+
+    /// Omit hashes in the generated output.
+    #[arg(long, overrides_with("hashes"))]
+    pub no_hashes: bool,
+
+    /// Write the exported requirements to the given file.
+    #[arg(long, short)]
+    pub output_file: Option<PathBuf>,
+
+    /// Do not emit the current project.
+    ///
+    /// By default, the current project is included in the exported requirements file with all of
+    /// its dependencies. The `--no-emit-project` option allows the project to be excluded, but all
+    /// of its dependencies to remain included.
+    #[arg(long, alias = "no-install-project")]
+    pub no_emit_project: bool,
+
+    /// Do not emit any workspace members, including the root project.
+    ///
+    /// By default, all workspace members and their dependencies are included in the exported
+    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
+    /// exclusion of all the workspace members while retaining their dependencies.
+    #[arg(long, alias = "no-install-workspace")]
+    pub no_emit_workspace: bool,
+
+    /// Do not emit the given package(s).
+    ///
+    /// By default, all of the project's dependencies are included in the exported requirements
+    /// file. The `--no-emit-package` option allows exclusion of specific packages.
+    #[arg(long, alias = "no-install-package")]
+    pub no_emit_package: Vec<PackageName>,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Do not update the `uv.lock` before exporting.
+    ///
+    /// If a `uv.lock` does not exist, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Export the dependencies for the specified PEP 723 Python script, rather than the current
+    /// project.
+    ///
+    /// If provided, uv will resolve the dependencies based on its inline metadata table, in
+    /// adherence with PEP 723.
+    #[arg(long, conflicts_with_all = ["all_packages", "package", "no_emit_project", "no_emit_workspace"])]
+    pub script: Option<PathBuf>,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum Python version if
+    /// `requires-python` is not set.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Repair the environment.
+    ///
+    /// Reinstalls any packages with missing or modified files, installs any missing packages,
+    /// replaces packages installed at a different version than the lockfile, and removes
+    /// extraneous packages.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Disable the development dependency group.
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long)]
+    pub no_default_groups: bool,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub all_groups: bool,
+
+    /// Do not report (or, with `--fix`, remove) extraneous packages present in the environment.
+    #[arg(long, overrides_with("exact"), alias = "no-exact")]
+    pub inexact: bool,
+
+    /// Report extraneous packages present in the environment.
+    #[arg(long, overrides_with("inexact"), hide = true)]
+    pub exact: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+}
+
+#[derive(Args)]
+pub struct ProjectPruneArgs {
+    /// Perform a dry run, i.e., don't actually remove anything but print the packages that would be
+    /// removed.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Disable the development dependency group.
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "all_groups", "no_dev"])]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long)]
+    pub no_default_groups: bool,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, conflicts_with_all = ["group", "dev", "all_groups"])]
+    pub only_group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups.
     ///
-    /// Pruned packages will be excluded from the exported requirements file, as will any
-    /// dependencies that are no longer required after the pruned package is removed.
-    #[arg(long, conflicts_with = "all_packages", value_name = "PACKAGE")]
-    pub prune: Vec<PackageName>,
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
+    pub all_groups: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+}
 
+#[derive(Args)]
+pub struct PrefetchArgs {
     /// Include optional dependencies from the specified extra name.
     ///
     /// May be provided more than once.
@@ -4117,99 +5716,104 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with_all = ["only_group", "only_dev"])]
     pub all_groups: bool,
 
-    /// Exclude comment annotations indicating the source of each package.
-    #[arg(long, overrides_with("annotate"))]
-    pub no_annotate: bool,
-
-    #[arg(long, overrides_with("no_annotate"), hide = true)]
-    pub annotate: bool,
-
-    /// Exclude the comment header at the top of the generated output file.
-    #[arg(long, overrides_with("header"))]
-    pub no_header: bool,
-
-    #[arg(long, overrides_with("no_header"), hide = true)]
-    pub header: bool,
+    /// The Python interpreter for which to prefetch distributions.
+    ///
+    /// By default, the first interpreter that meets the project's `requires-python` constraint is
+    /// used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
 
-    /// Export any editable dependencies, including the project and any workspace members, as
-    /// non-editable.
+    /// The platform for which distributions should be prefetched.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Any distributions that are built from source will be built for the _current_ platform.
     #[arg(long)]
-    pub no_editable: bool,
+    pub python_platform: Option<TargetTriple>,
 
-    /// Include hashes for all dependencies.
-    #[arg(long, overrides_with("no_hashes"), hide = true)]
-    pub hashes: bool,
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
 
-    /// Omit hashes in the generated output.
-    #[arg(long, overrides_with("hashes"))]
-    pub no_hashes: bool,
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+}
 
-    /// Write the exported requirements to the given file.
-    #[arg(long, short)]
-    pub output_file: Option<PathBuf>,
+#[derive(Args)]
+pub struct TaskNamespace {
+    #[command(subcommand)]
+    pub command: TaskCommand,
+}
 
-    /// Do not emit the current project.
+#[derive(Subcommand)]
+pub enum TaskCommand {
+    /// Run one or more tasks.
     ///
-    /// By default, the current project is included in the exported requirements file with all of
-    /// its dependencies. The `--no-emit-project` option allows the project to be excluded, but all
-    /// of its dependencies to remain included.
-    #[arg(long, alias = "no-install-project")]
-    pub no_emit_project: bool,
-
-    /// Do not emit any workspace members, including the root project.
+    /// Each task runs after the tasks it depends on (via `depends-on`), and each task runs at most
+    /// once. If a task fails, the remaining tasks are skipped and uv exits with the task's exit
+    /// code.
     ///
-    /// By default, all workspace members and their dependencies are included in the exported
-    /// requirements file, with all of their dependencies. The `--no-emit-workspace` option allows
-    /// exclusion of all the workspace members while retaining their dependencies.
-    #[arg(long, alias = "no-install-workspace")]
-    pub no_emit_workspace: bool,
+    /// Before running any tasks, the project environment is synced, including the dependency
+    /// groups required by the tasks (via `groups`).
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    Run(TaskRunArgs),
+    /// List the tasks defined in the project.
+    List(TaskListArgs),
+}
 
-    /// Do not emit the given package(s).
+#[derive(Args)]
+pub struct TaskRunArgs {
+    /// The names of the tasks to run, in order.
+    #[arg(required = true, value_name = "TASK")]
+    pub tasks: Vec<String>,
+
+    /// Avoid syncing the virtual environment.
     ///
-    /// By default, all of the project's dependencies are included in the exported requirements
-    /// file. The `--no-emit-package` option allows exclusion of specific packages.
-    #[arg(long, alias = "no-install-package")]
-    pub no_emit_package: Vec<PackageName>,
+    /// Implies `--frozen`, as the project dependencies will be ignored (i.e., the lockfile will not
+    /// be updated, since the environment will not be synced regardless).
+    #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_sync: bool,
 
     /// Assert that the `uv.lock` will remain unchanged.
     ///
-    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
-    /// uv will exit with an error.
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
     #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
     pub locked: bool,
 
-    /// Do not update the `uv.lock` before exporting.
+    /// Run without updating the `uv.lock` file.
     ///
-    /// If a `uv.lock` does not exist, uv will exit with an error.
+    /// Instead of checking if the lockfile is up-to-date, uses the versions in the lockfile as the
+    /// source of truth. If the lockfile is missing, uv will exit with an error. If the
+    /// `pyproject.toml` includes changes to dependencies that have not been included in the
+    /// lockfile yet, they will not be present in the environment.
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
-    #[command(flatten)]
-    pub resolver: ResolverArgs,
-
-    #[command(flatten)]
-    pub build: BuildOptionsArgs,
-
-    #[command(flatten)]
-    pub refresh: RefreshArgs,
-
-    /// Export the dependencies for the specified PEP 723 Python script, rather than the current
-    /// project.
+    /// Run the tasks of a specific package in the workspace.
     ///
-    /// If provided, uv will resolve the dependencies based on its inline metadata table, in
-    /// adherence with PEP 723.
-    #[arg(long, conflicts_with_all = ["all_packages", "package", "no_emit_project", "no_emit_workspace"])]
-    pub script: Option<PathBuf>,
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
 
-    /// The Python interpreter to use during resolution.
-    ///
-    /// A Python interpreter is required for building source distributions to determine package
-    /// metadata when there are not wheels.
+    /// The Python interpreter to use for the project environment.
     ///
-    /// The interpreter is also used as the fallback value for the minimum Python version if
-    /// `requires-python` is not set.
+    /// If the interpreter request is satisfied by a discovered environment, the environment will be
+    /// used.
     ///
-    /// See `uv help python` for details on Python discovery and supported request formats.
+    /// See `uv help python` to view supported request formats.
     #[arg(
         long,
         short,
@@ -4219,6 +5823,24 @@ pub struct ExportArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+}
+
+#[derive(Args)]
+pub struct TaskListArgs {
+    /// List the tasks of a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
 }
 
 #[derive(Args)]
@@ -4652,8 +6274,8 @@ pub struct ToolUpgradeArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4801,6 +6423,31 @@ pub struct ToolUpgradeArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Level `1` removes `assert` statements and any code conditional on `__debug__`; level `2`
+    /// additionally removes docstrings. Optimized bytecode is only used when Python is run with
+    /// the corresponding `-O` flag (or `PYTHONOPTIMIZE` is set).
+    ///
+    /// Defaults to the interpreter's optimization level (typically, `0`).
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_COMPILE_BYTECODE_LEVEL,
+        help_heading = "Installer options"
+    )]
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+
+    /// Compile a specific package to bytecode after installation, even if `--compile-bytecode`
+    /// is not provided.
+    #[arg(long, help_heading = "Installer options")]
+    pub compile_bytecode_package: Vec<PackageName>,
+
+    /// Don't compile a specific package to bytecode after installation, even if
+    /// `--compile-bytecode` is provided.
+    #[arg(long, help_heading = "Installer options")]
+    pub no_compile_bytecode_package: Vec<PackageName>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any workspace, Git,
     /// URL, or local path sources.
@@ -5497,8 +7144,8 @@ pub struct InstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5594,6 +7241,31 @@ pub struct InstallerArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Level `1` removes `assert` statements and any code conditional on `__debug__`; level `2`
+    /// additionally removes docstrings. Optimized bytecode is only used when Python is run with
+    /// the corresponding `-O` flag (or `PYTHONOPTIMIZE` is set).
+    ///
+    /// Defaults to the interpreter's optimization level (typically, `0`).
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_COMPILE_BYTECODE_LEVEL,
+        help_heading = "Installer options"
+    )]
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+
+    /// Compile a specific package to bytecode after installation, even if `--compile-bytecode`
+    /// is not provided.
+    #[arg(long, help_heading = "Installer options")]
+    pub compile_bytecode_package: Vec<PackageName>,
+
+    /// Don't compile a specific package to bytecode after installation, even if
+    /// `--compile-bytecode` is provided.
+    #[arg(long, help_heading = "Installer options")]
+    pub no_compile_bytecode_package: Vec<PackageName>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any workspace, Git,
     /// URL, or local path sources.
@@ -5646,8 +7318,8 @@ pub struct ResolverArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5843,8 +7515,8 @@ pub struct ResolverInstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5992,6 +7664,31 @@ pub struct ResolverInstallerArgs {
     )]
     pub no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Level `1` removes `assert` statements and any code conditional on `__debug__`; level `2`
+    /// additionally removes docstrings. Optimized bytecode is only used when Python is run with
+    /// the corresponding `-O` flag (or `PYTHONOPTIMIZE` is set).
+    ///
+    /// Defaults to the interpreter's optimization level (typically, `0`).
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_COMPILE_BYTECODE_LEVEL,
+        help_heading = "Installer options"
+    )]
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+
+    /// Compile a specific package to bytecode after installation, even if `--compile-bytecode`
+    /// is not provided.
+    #[arg(long, help_heading = "Installer options")]
+    pub compile_bytecode_package: Vec<PackageName>,
+
+    /// Don't compile a specific package to bytecode after installation, even if
+    /// `--compile-bytecode` is provided.
+    #[arg(long, help_heading = "Installer options")]
+    pub no_compile_bytecode_package: Vec<PackageName>,
+
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any workspace, Git,
     /// URL, or local path sources.
@@ -6021,8 +7718,8 @@ pub struct FetchArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -6074,10 +7771,16 @@ pub struct DisplayTreeArgs {
 
 #[derive(Args, Debug)]
 pub struct PublishArgs {
+    #[command(subcommand)]
+    pub command: Option<PublishCommand>,
+
     /// Paths to the files to upload. Accepts glob expressions.
     ///
     /// Defaults to the `dist` directory. Selects only wheels and source distributions, while
     /// ignoring other files.
+    ///
+    /// Paths that match the name of a subcommand (e.g., `yank`) must be passed with a directory
+    /// (e.g., `./yank`) or after `--`.
     #[arg(default_value = "dist/*")]
     pub files: Vec<String>,
 
@@ -6100,14 +7803,19 @@ pub struct PublishArgs {
     /// uv publish --index pypi
     /// uv publish --publish-url https://upload.pypi.org/legacy/ --check-url https://pypi.org/simple
     /// ```
+    ///
+    /// Can be provided multiple times, to publish to each of the indexes in turn. If publishing
+    /// to an index fails, uv continues with the remaining indexes, and reports the result for
+    /// each index. Credentials provided on the command line are used for every index.
     #[arg(
         long,
         verbatim_doc_comment,
         env = EnvVars::UV_PUBLISH_INDEX,
+        value_delimiter = ' ',
         conflicts_with = "publish_url",
         conflicts_with = "check_url"
     )]
-    pub index: Option<String>,
+    pub index: Vec<String>,
 
     /// The username for the upload.
     #[arg(short, long, env = EnvVars::UV_PUBLISH_USERNAME)]
@@ -6138,10 +7846,77 @@ pub struct PublishArgs {
     #[arg(long)]
     pub trusted_publishing: Option<TrustedPublishing>,
 
+    /// Upload PEP 740 attestations alongside the files.
+    ///
+    /// Files without an attestation (i.e., a `<file>.publish.attestation` file next to them) are
+    /// signed with `pypi-attestations sign`, which is run via `uv tool run`. Signing uses the
+    /// ambient OIDC identity in CI (e.g., GitHub Actions or GitLab CI), and otherwise prompts to
+    /// sign in with an identity provider in the browser.
+    #[arg(long)]
+    pub attest: bool,
+
+    /// The `pypi-attestations` requirement to sign attestations with, e.g.,
+    /// `pypi-attestations==0.0.26`.
+    ///
+    /// Defaults to a version of `pypi-attestations` that's pinned by uv, such that a new release
+    /// can't change how files are signed.
+    #[arg(long, value_name = "REQUIREMENT", requires = "attest")]
+    pub attest_from: Option<String>,
+
+    /// Check the files for problems that registries reject, without uploading them.
+    ///
+    /// Checks the completeness of the core metadata, the rendering of the long description, the
+    /// presence of the declared license files, the wheel tags, and the consistency of the metadata
+    /// with the filename. Exits with an error if any file fails the checks.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Build and publish all workspace members, in dependency order.
+    ///
+    /// Each member is built with `uv build` and published before the members that depend on it.
+    /// Members that aren't packages (i.e., without a build system) and members marked as private
+    /// with the `Private :: Do Not Upload` classifier are skipped. If the index has a check URL
+    /// (e.g., with `--index` or `--check-url`), members whose version already exists on the index
+    /// are skipped.
+    #[arg(long, conflicts_with = "files")]
+    pub workspace: bool,
+
+    /// With `--workspace`, add version bounds to dependencies on other workspace members.
+    ///
+    /// Dependencies on workspace members without a version constraint are rewritten to require the
+    /// published version of the member at build time, e.g., `foo>=1.2.3` with `--bounds lower`.
+    #[arg(long, value_enum, requires = "workspace")]
+    pub bounds: Option<AddBoundsKind>,
+
+    /// After uploading, wait until the files are available on the index.
+    ///
+    /// Polls the index (i.e., the check URL, as set by `--index` or `--check-url`) until all
+    /// files are listed with matching hashes, such that downstream jobs can install the release
+    /// as soon as `uv publish` exits. When publishing to PyPI or TestPyPI, their index is used if
+    /// no check URL is set.
+    ///
+    /// Accepts the maximum time to wait, in seconds, which defaults to 600 seconds.
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "600",
+        conflicts_with = "check"
+    )]
+    pub wait: Option<u64>,
+
+    /// After the files are available on the index, download them from the index and verify their
+    /// hashes, as an installer would.
+    ///
+    /// Implies `--wait`.
+    #[arg(long, conflicts_with = "check")]
+    pub verify: bool,
+
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -6176,6 +7951,76 @@ pub struct PublishArgs {
     pub skip_existing: bool,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum PublishCommand {
+    /// Yank a release from an index.
+    ///
+    /// Installers skip yanked releases, unless they're pinned to the exact version
+    /// ([PEP 592](https://peps.python.org/pep-0592/)). The release is yanked with the credentials
+    /// and configuration used to publish to the index.
+    ///
+    /// Currently, yanking is supported for AWS CodeArtifact, for which yanked releases are
+    /// unlisted. PyPI doesn't provide an API for yanking, so releases must be yanked on the
+    /// website instead.
+    Yank(YankArgs),
+    /// Undo the yanking of a release from an index.
+    Unyank(UnyankArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct YankArgs {
+    /// The release to yank, as `<package>==<version>`.
+    pub release: String,
+
+    /// The reason for yanking the release, shown by installers that encounter it.
+    #[arg(long)]
+    pub reason: Option<String>,
+
+    /// The name of the index in the configuration to yank the release from.
+    #[arg(long, env = EnvVars::UV_PUBLISH_INDEX, conflicts_with = "publish_url")]
+    pub index: Option<String>,
+
+    /// The URL of the upload endpoint of the index to yank the release from.
+    ///
+    /// Defaults to the `publish-url` in the configuration, or PyPI's publish URL
+    /// (<https://upload.pypi.org/legacy/>).
+    #[arg(long, env = EnvVars::UV_PUBLISH_URL)]
+    pub publish_url: Option<DisplaySafeUrl>,
+
+    /// Show the release that would be yanked, without yanking it.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Yank the release without asking for confirmation.
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct UnyankArgs {
+    /// The release to restore, as `<package>==<version>`.
+    pub release: String,
+
+    /// The name of the index in the configuration to restore the release on.
+    #[arg(long, env = EnvVars::UV_PUBLISH_INDEX, conflicts_with = "publish_url")]
+    pub index: Option<String>,
+
+    /// The URL of the upload endpoint of the index to restore the release on.
+    ///
+    /// Defaults to the `publish-url` in the configuration, or PyPI's publish URL
+    /// (<https://upload.pypi.org/legacy/>).
+    #[arg(long, env = EnvVars::UV_PUBLISH_URL)]
+    pub publish_url: Option<DisplaySafeUrl>,
+
+    /// Show the release that would be restored, without restoring it.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Restore the release without asking for confirmation.
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
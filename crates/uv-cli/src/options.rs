@@ -1,14 +1,14 @@
 use anstream::eprintln;
 
 use uv_cache::Refresh;
-use uv_configuration::{ConfigSettings, PackageConfigSettings};
+use uv_configuration::{ConfigSettings, NoBuildIsolationPackages, PackageConfigSettings};
 use uv_resolver::PrereleaseMode;
 use uv_settings::{Combine, PipOptions, ResolverInstallerOptions, ResolverOptions};
 use uv_warnings::owo_colors::OwoColorize;
 
 use crate::{
-    BuildOptionsArgs, FetchArgs, IndexArgs, InstallerArgs, Maybe, RefreshArgs, ResolverArgs,
-    ResolverInstallerArgs,
+    BuildOptionsArgs, FetchArgs, IndexArgs, InstallerArgs, Maybe, MirrorSyncArgs, RefreshArgs,
+    ResolverArgs, ResolverInstallerArgs,
 };
 
 /// Given a boolean flag pair (like `--upgrade` and `--no-upgrade`), resolve the value of the flag.
@@ -91,7 +91,9 @@ impl From<ResolverArgs> for PipOptions {
                     .collect::<PackageConfigSettings>()
             }),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
-            no_build_isolation_package: Some(no_build_isolation_package),
+            no_build_isolation_package: Some(NoBuildIsolationPackages::from(
+                no_build_isolation_package,
+            )),
             exclude_newer,
             link_mode,
             no_sources: if no_sources { Some(true) } else { None },
@@ -117,6 +119,9 @@ impl From<InstallerArgs> for PipOptions {
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            compile_bytecode_level,
+            compile_bytecode_package,
+            no_compile_bytecode_package,
             no_sources,
         } = args;
 
@@ -136,6 +141,9 @@ impl From<InstallerArgs> for PipOptions {
             exclude_newer,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+            compile_bytecode_level,
+            compile_bytecode_package: Some(compile_bytecode_package),
+            no_compile_bytecode_package: Some(no_compile_bytecode_package),
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -167,6 +175,9 @@ impl From<ResolverInstallerArgs> for PipOptions {
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            compile_bytecode_level,
+            compile_bytecode_package,
+            no_compile_bytecode_package,
             no_sources,
         } = args;
 
@@ -192,10 +203,15 @@ impl From<ResolverInstallerArgs> for PipOptions {
                     .collect::<PackageConfigSettings>()
             }),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
-            no_build_isolation_package: Some(no_build_isolation_package),
+            no_build_isolation_package: Some(NoBuildIsolationPackages::from(
+                no_build_isolation_package,
+            )),
             exclude_newer,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+            compile_bytecode_level,
+            compile_bytecode_package: Some(compile_bytecode_package),
+            no_compile_bytecode_package: Some(no_compile_bytecode_package),
             no_sources: if no_sources { Some(true) } else { None },
             ..PipOptions::from(index_args)
         }
@@ -346,7 +362,9 @@ pub fn resolver_options(
                 .collect::<PackageConfigSettings>()
         }),
         no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
-        no_build_isolation_package: Some(no_build_isolation_package),
+        no_build_isolation_package: Some(NoBuildIsolationPackages::from(
+            no_build_isolation_package,
+        )),
         exclude_newer,
         link_mode,
         no_build: flag(no_build, build, "build"),
@@ -357,6 +375,68 @@ pub fn resolver_options(
     }
 }
 
+/// Construct the [`ResolverOptions`] from the [`MirrorSyncArgs`].
+pub fn mirror_options(args: &MirrorSyncArgs) -> ResolverOptions {
+    let MirrorSyncArgs {
+        index_args,
+        index_strategy,
+        keyring_provider,
+        build,
+        ..
+    } = args;
+
+    let BuildOptionsArgs {
+        no_build,
+        build,
+        no_build_package,
+        no_binary,
+        binary,
+        no_binary_package,
+    } = build;
+
+    ResolverOptions {
+        index: index_args
+            .default_index
+            .clone()
+            .and_then(Maybe::into_option)
+            .map(|default_index| vec![default_index])
+            .combine(index_args.index.as_ref().map(|index| {
+                index
+                    .iter()
+                    .flat_map(|v| v.clone())
+                    .filter_map(Maybe::into_option)
+                    .collect()
+            })),
+        index_url: index_args.index_url.clone().and_then(Maybe::into_option),
+        extra_index_url: index_args.extra_index_url.as_ref().map(|extra_index_url| {
+            extra_index_url
+                .iter()
+                .cloned()
+                .filter_map(Maybe::into_option)
+                .collect()
+        }),
+        no_index: if index_args.no_index {
+            Some(true)
+        } else {
+            None
+        },
+        find_links: index_args.find_links.as_ref().map(|find_links| {
+            find_links
+                .iter()
+                .cloned()
+                .filter_map(Maybe::into_option)
+                .collect()
+        }),
+        index_strategy: *index_strategy,
+        keyring_provider: *keyring_provider,
+        no_build: flag(*no_build, *build, "build"),
+        no_build_package: Some(no_build_package.clone()),
+        no_binary: flag(*no_binary, *binary, "binary"),
+        no_binary_package: Some(no_binary_package.clone()),
+        ..ResolverOptions::default()
+    }
+}
+
 /// Construct the [`ResolverInstallerOptions`] from the [`ResolverInstallerArgs`] and [`BuildOptionsArgs`].
 pub fn resolver_installer_options(
     resolver_installer_args: ResolverInstallerArgs,
@@ -385,6 +465,9 @@ pub fn resolver_installer_options(
         link_mode,
         compile_bytecode,
         no_compile_bytecode,
+        compile_bytecode_level,
+        compile_bytecode_package,
+        no_compile_bytecode_package,
         no_sources,
     } = resolver_installer_args;
 
@@ -462,11 +545,23 @@ pub fn resolver_installer_options(
         no_build_isolation_package: if no_build_isolation_package.is_empty() {
             None
         } else {
-            Some(no_build_isolation_package)
+            Some(NoBuildIsolationPackages::from(no_build_isolation_package))
         },
         exclude_newer,
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+        compile_bytecode_level,
+        compile_bytecode_package: if compile_bytecode_package.is_empty() {
+            None
+        } else {
+            Some(compile_bytecode_package)
+        },
+        no_compile_bytecode_package: if no_compile_bytecode_package.is_empty() {
+            None
+        } else {
+            Some(no_compile_bytecode_package)
+        },
+        install_hooks: None,
         no_build: flag(no_build, build, "build"),
         no_build_package: if no_build_package.is_empty() {
             None
@@ -0,0 +1,59 @@
+//! A process-wide cap on the bandwidth used for downloads.
+//!
+//! Downloads reserve a slot on a shared timeline for each chunk they receive, sized to the time it
+//! takes to transfer the chunk at the configured bandwidth, and wait until their slot has passed
+//! before reading further. Since a slow reader causes the server to back off, this bounds the
+//! combined bandwidth of all concurrent downloads.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use futures::{Stream, StreamExt};
+
+/// The process-wide download bandwidth, in bytes per second, or `0` if unbounded.
+static BANDWIDTH: AtomicU64 = AtomicU64::new(0);
+
+/// The time at which all bytes received so far will have been transferred at the configured
+/// bandwidth.
+static AVAILABLE_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Set the process-wide download bandwidth, in bytes per second, or `None` to leave the
+/// bandwidth unbounded.
+pub fn set_download_bandwidth(bytes_per_second: Option<u64>) {
+    BANDWIDTH.store(bytes_per_second.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Wait until the given number of bytes can be received without exceeding the download
+/// bandwidth.
+pub async fn consume_bandwidth(bytes: usize) {
+    let bandwidth = BANDWIDTH.load(Ordering::Relaxed);
+    if bandwidth == 0 {
+        return;
+    }
+    let deadline = {
+        let mut available_at = AVAILABLE_AT.lock().unwrap();
+        let now = Instant::now();
+        let start = available_at.map_or(now, |available_at| available_at.max(now));
+        #[allow(clippy::cast_precision_loss)]
+        let duration = Duration::from_secs_f64(bytes as f64 / bandwidth as f64);
+        let deadline = start + duration;
+        *available_at = Some(deadline);
+        deadline
+    };
+    tokio::time::sleep_until(deadline.into()).await;
+}
+
+/// Limit the rate at which a stream of bytes is consumed to the download bandwidth.
+pub fn throttle<S, T, E>(stream: S) -> impl Stream<Item = Result<T, E>> + Unpin
+where
+    S: Stream<Item = Result<T, E>>,
+    T: AsRef<[u8]>,
+{
+    Box::pin(stream.then(|chunk| async move {
+        if let Ok(chunk) = &chunk {
+            consume_bandwidth(chunk.as_ref().len()).await;
+        }
+        chunk
+    }))
+}
@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use std::fmt::Write;
 use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::Duration;
 use std::{env, io, iter};
 
@@ -19,16 +20,14 @@ use itertools::Itertools;
 use reqwest::{Client, ClientBuilder, IntoUrl, Proxy, Request, Response, multipart};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
-use reqwest_retry::{
-    DefaultRetryableStrategy, RetryTransientMiddleware, Retryable, RetryableStrategy,
-};
+use reqwest_retry::{DefaultRetryableStrategy, Retryable, RetryableStrategy};
 use tracing::{debug, trace};
 use url::ParseError;
 use url::Url;
 
 use uv_auth::Credentials;
 use uv_auth::{AuthMiddleware, Indexes};
-use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_configuration::{HttpConnectionOptions, KeyringProviderType, TrustedHost};
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -38,11 +37,19 @@ use uv_version::version;
 use uv_warnings::warn_user_once;
 
 use crate::Connectivity;
+use crate::connection;
+use crate::http_trace::{self, HttpTraceMiddleware};
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::oci::OciAuthMiddleware;
+use crate::proxy::{self, IndexProxy, ProxySettings};
+use crate::resolve::{IndexResolve, IndexResolver};
+use crate::retry::{HttpRetryPolicy, RetryAfterMiddleware, RetryMiddleware, is_download};
+use crate::tls::{IndexTls, read_certificates, read_identity, read_identity_with_key};
 
 pub const DEFAULT_RETRIES: u32 = 3;
+/// The default number of times interrupted downloads are resumed across all downloads.
+pub const DEFAULT_RESUME_BUDGET: u32 = 20;
 /// Maximum number of redirects to follow before giving up.
 ///
 /// This is the default used by [`reqwest`].
@@ -68,12 +75,20 @@ pub struct BaseClientBuilder<'a> {
     allow_insecure_host: Vec<TrustedHost>,
     native_tls: bool,
     built_in_root_certs: bool,
-    retries: u32,
+    retries: Option<u32>,
+    retry_policy: HttpRetryPolicy,
+    download_retry_policy: HttpRetryPolicy,
+    http_connection: HttpConnectionOptions,
     pub connectivity: Connectivity,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     indexes: Indexes,
+    index_tls: Vec<IndexTls>,
+    proxy_settings: ProxySettings,
+    index_proxies: Vec<IndexProxy>,
+    index_resolves: Vec<IndexResolve>,
+    oci_registries: Vec<DisplaySafeUrl>,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
@@ -130,11 +145,19 @@ impl BaseClientBuilder<'_> {
             native_tls: false,
             built_in_root_certs: false,
             connectivity: Connectivity::Online,
-            retries: DEFAULT_RETRIES,
+            retries: None,
+            retry_policy: HttpRetryPolicy::default(),
+            download_retry_policy: HttpRetryPolicy::default(),
+            http_connection: HttpConnectionOptions::default(),
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
             indexes: Indexes::new(),
+            index_tls: vec![],
+            proxy_settings: ProxySettings::default(),
+            index_proxies: vec![],
+            index_resolves: vec![],
+            oci_registries: vec![],
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
             proxies: vec![],
@@ -163,9 +186,29 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the number of retries for all requests, overriding the retry policies.
     #[must_use]
     pub fn retries(mut self, retries: u32) -> Self {
-        self.retries = retries;
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Configure the policies for retrying metadata requests and artifact downloads.
+    #[must_use]
+    pub fn retry_policies(
+        mut self,
+        retry_policy: HttpRetryPolicy,
+        download_retry_policy: HttpRetryPolicy,
+    ) -> Self {
+        self.retry_policy = retry_policy;
+        self.download_retry_policy = download_retry_policy;
+        self
+    }
+
+    /// Configure the tuning of the client's connections.
+    #[must_use]
+    pub fn http_connection(mut self, http_connection: HttpConnectionOptions) -> Self {
+        self.http_connection = http_connection;
         self
     }
 
@@ -224,6 +267,41 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Configure TLS settings for the hosts of specific indexes.
+    #[must_use]
+    pub fn index_tls(mut self, index_tls: Vec<IndexTls>) -> Self {
+        self.index_tls = index_tls;
+        self
+    }
+
+    /// Configure the proxies to use, in lieu of the proxy environment variables.
+    #[must_use]
+    pub fn proxy_settings(mut self, proxy_settings: ProxySettings) -> Self {
+        self.proxy_settings = proxy_settings;
+        self
+    }
+
+    /// Configure proxies for the hosts of specific indexes.
+    #[must_use]
+    pub fn index_proxies(mut self, index_proxies: Vec<IndexProxy>) -> Self {
+        self.index_proxies = index_proxies;
+        self
+    }
+
+    /// Configure the addresses to connect to for the hosts of specific indexes, in lieu of DNS.
+    #[must_use]
+    pub fn index_resolves(mut self, index_resolves: Vec<IndexResolve>) -> Self {
+        self.index_resolves = index_resolves;
+        self
+    }
+
+    /// Configure the OCI registries to authenticate against with the registry token flow.
+    #[must_use]
+    pub fn oci_registries(mut self, oci_registries: Vec<DisplaySafeUrl>) -> Self {
+        self.oci_registries = oci_registries;
+        self
+    }
+
     #[must_use]
     pub fn default_timeout(mut self, default_timeout: Duration) -> Self {
         self.default_timeout = default_timeout;
@@ -264,13 +342,16 @@ impl<'a> BaseClientBuilder<'a> {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
-    /// Create a [`RetryPolicy`] for the client.
-    fn retry_policy(&self) -> ExponentialBackoff {
-        let mut builder = ExponentialBackoff::builder();
-        if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
-            builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
+    /// The policies for retrying metadata requests and artifact downloads, with any explicit
+    /// number of retries applied.
+    fn retry_policies(&self) -> (HttpRetryPolicy, HttpRetryPolicy) {
+        let mut retry_policy = self.retry_policy.clone();
+        let mut download_retry_policy = self.download_retry_policy.clone();
+        if let Some(retries) = self.retries {
+            retry_policy.retries = retries;
+            download_retry_policy.retries = retries;
         }
-        builder.build_with_max_retries(self.retries)
+        (retry_policy, download_retry_policy)
     }
 
     pub fn build(&self) -> BaseClient {
@@ -314,12 +395,28 @@ impl<'a> BaseClientBuilder<'a> {
             .unwrap_or(self.default_timeout);
         debug!("Using request timeout of {}s", timeout.as_secs());
 
+        let (retry_policy, download_retry_policy) = self.retry_policies();
+        debug!("Using retry policy for metadata requests: {retry_policy}");
+        debug!("Using retry policy for downloads: {download_retry_policy}");
+
+        let resume_budget = env::var(EnvVars::UV_HTTP_RESUME_BUDGET)
+            .ok()
+            .and_then(|value| {
+                value.parse::<u32>()
+                    .inspect_err(|_| {
+                        warn_user_once!("Ignoring invalid value from environment for `UV_HTTP_RESUME_BUDGET`. Expected an integer, got \"{value}\".");
+                    })
+                    .ok()
+            })
+            .unwrap_or(DEFAULT_RESUME_BUDGET);
+
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
             &user_agent_string,
             timeout,
             ssl_cert_file_exists,
             Security::Secure,
+            None,
             self.redirect_policy,
         );
 
@@ -329,9 +426,27 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Insecure,
+            None,
             self.redirect_policy,
         );
 
+        // Create a secure client for each index with custom TLS settings.
+        let raw_index_clients = self
+            .index_tls
+            .iter()
+            .map(|tls| {
+                let raw_client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    Security::Secure,
+                    Some(tls),
+                    self.redirect_policy,
+                );
+                (tls.clone(), raw_client)
+            })
+            .collect::<Vec<_>>();
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
             client: self.apply_middleware(raw_client.clone()),
@@ -347,17 +462,22 @@ impl<'a> BaseClientBuilder<'a> {
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            retry_policy,
+            download_retry_policy,
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients: self.wrap_index_clients(raw_index_clients),
             timeout,
+            resume_budget: Arc::new(AtomicU32::new(resume_budget)),
         }
     }
 
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
+        let (retry_policy, download_retry_policy) = self.retry_policies();
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
             client: self.apply_middleware(existing.raw_client.clone()),
@@ -373,32 +493,61 @@ impl<'a> BaseClientBuilder<'a> {
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
-            retries: self.retries,
+            retry_policy,
+            download_retry_policy,
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients: self.wrap_index_clients(
+                existing.index_clients.iter().map(|index_client| {
+                    (index_client.tls.clone(), index_client.raw_client.clone())
+                }),
+            ),
             timeout: existing.timeout,
+            resume_budget: existing.resume_budget.clone(),
         }
     }
 
+    /// Wrap the clients for indexes with custom TLS settings in any relevant middleware.
+    fn wrap_index_clients(
+        &self,
+        raw_index_clients: impl IntoIterator<Item = (IndexTls, Client)>,
+    ) -> Vec<IndexClient> {
+        raw_index_clients
+            .into_iter()
+            .map(|(tls, raw_client)| IndexClient {
+                tls,
+                client: RedirectClientWithMiddleware {
+                    client: self.apply_middleware(raw_client.clone()),
+                    redirect_policy: self.redirect_policy,
+                    cross_origin_credentials_policy: self.cross_origin_credential_policy,
+                },
+                raw_client,
+            })
+            .collect()
+    }
+
     fn create_client(
         &self,
         user_agent: &str,
         timeout: Duration,
         ssl_cert_file_exists: bool,
         security: Security,
+        tls: Option<&IndexTls>,
         redirect_policy: RedirectPolicy,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
             .http1_title_case_headers()
             .user_agent(user_agent)
-            .pool_max_idle_per_host(20)
             .read_timeout(timeout)
             .tls_built_in_root_certs(self.built_in_root_certs)
             .redirect(redirect_policy.reqwest_policy());
 
+        // Tune the connections.
+        let client_builder = connection::configure(client_builder, &self.http_connection);
+
         // If necessary, accept invalid certificates.
         let client_builder = match security {
             Security::Secure => client_builder,
@@ -411,24 +560,73 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
-            match read_identity(&ssl_client_cert) {
-                Ok(identity) => client_builder.identity(identity),
+        // Trust any certificate authorities configured for the index.
+        let mut client_builder = client_builder;
+        if let Some(ssl_ca_cert) = tls.and_then(|tls| tls.ca_cert.as_deref()) {
+            match read_certificates(ssl_ca_cert) {
+                Ok(certificates) => {
+                    for certificate in certificates {
+                        client_builder = client_builder.add_root_certificate(certificate);
+                    }
+                }
                 Err(err) => {
-                    warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
-                    client_builder
+                    warn_user_once!(
+                        "Ignoring invalid `ssl-ca-cert` ({}): {err}",
+                        ssl_ca_cert.simplified_display().cyan()
+                    );
                 }
             }
-        } else {
-            client_builder
-        };
+        }
 
-        // apply proxies
+        // Configure mTLS, preferring the client certificate configured for the index.
+        let client_builder =
+            if let Some(ssl_client_cert) = tls.and_then(|tls| tls.client_cert.as_deref()) {
+                let identity =
+                    if let Some(ssl_client_key) = tls.and_then(|tls| tls.client_key.as_deref()) {
+                        read_identity_with_key(ssl_client_cert, ssl_client_key)
+                    } else {
+                        read_identity(ssl_client_cert.as_os_str())
+                    };
+                match identity {
+                    Ok(identity) => client_builder.identity(identity),
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring invalid `ssl-client-cert` ({}): {err}",
+                            ssl_client_cert.simplified_display().cyan()
+                        );
+                        client_builder
+                    }
+                }
+            } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+                match read_identity(&ssl_client_cert) {
+                    Ok(identity) => client_builder.identity(identity),
+                    Err(err) => {
+                        warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
+                        client_builder
+                    }
+                }
+            } else {
+                client_builder
+            };
+
+        // Apply any configured proxies, followed by the proxies from the environment.
         let mut client_builder = client_builder;
+        if let Some(proxies) = proxy::proxies(&self.proxy_settings, &self.index_proxies) {
+            for proxy in proxies {
+                client_builder = client_builder.proxy(proxy);
+            }
+        }
+
+        // apply proxies
         for p in &self.proxies {
             client_builder = client_builder.proxy(p.clone());
         }
+
+        // Override the resolution of any index hosts, falling back to the system resolver.
+        if !self.index_resolves.is_empty() {
+            client_builder = client_builder
+                .dns_resolver(Arc::new(IndexResolver::new(self.index_resolves.clone())));
+        }
         let client_builder = client_builder;
 
         client_builder
@@ -442,13 +640,21 @@ impl<'a> BaseClientBuilder<'a> {
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
 
                 // Avoid uncloneable errors with a streaming body during publish.
-                if self.retries > 0 {
+                let (retry_policy, download_retry_policy) = self.retry_policies();
+                if retry_policy.retries > 0 || download_retry_policy.retries > 0 {
                     // Initialize the retry strategy.
-                    let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
-                        UvRetryableStrategy,
-                    );
-                    client = client.with(retry_strategy);
+                    client =
+                        client.with(RetryMiddleware::new(&retry_policy, &download_retry_policy));
+                    if let Some(retry_after) =
+                        RetryAfterMiddleware::new(&retry_policy, &download_retry_policy)
+                    {
+                        client = client.with(retry_after);
+                    }
+                }
+
+                // Exchange credentials for registry tokens, for any OCI registries.
+                if !self.oci_registries.is_empty() {
+                    client = client.with(OciAuthMiddleware::new(self.oci_registries.clone()));
                 }
 
                 // Initialize the authentication middleware to set headers.
@@ -479,6 +685,11 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Record each request that reaches the network, if tracing.
+                if http_trace::is_enabled() {
+                    client = client.with(HttpTraceMiddleware);
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -499,14 +710,32 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for indexes with custom TLS settings.
+    index_clients: Vec<IndexClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
-    /// The number of retries to attempt on transient errors.
-    retries: u32,
+    /// The policy for retrying metadata requests on transient errors.
+    retry_policy: HttpRetryPolicy,
+    /// The policy for retrying artifact downloads on transient errors.
+    download_retry_policy: HttpRetryPolicy,
+    /// The number of times interrupted downloads may still be resumed, shared across all
+    /// downloads.
+    resume_budget: Arc<AtomicU32>,
+}
+
+/// An HTTP client with the TLS settings for an index's host.
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The TLS settings of the index.
+    tls: IndexTls,
+    /// The underlying HTTP client.
+    client: RedirectClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -518,10 +747,17 @@ enum Security {
 }
 
 impl BaseClient {
-    /// Selects the appropriate client based on the host's trustworthiness.
+    /// Selects the appropriate client based on the host's trustworthiness and any TLS settings
+    /// configured for the host's index.
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
         if self.disable_ssl(url) {
             &self.dangerous_client
+        } else if let Some(index_client) = self
+            .index_clients
+            .iter()
+            .find(|index_client| index_client.tls.matches(url))
+        {
+            &index_client.client
         } else {
             &self.client
         }
@@ -550,9 +786,33 @@ impl BaseClient {
         self.connectivity
     }
 
-    /// The [`RetryPolicy`] for the client.
+    /// The [`RetryPolicy`] for metadata requests.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+        self.retry_policy.backoff()
+    }
+
+    /// The [`RetryPolicy`] for artifact downloads.
+    pub fn download_retry_policy(&self) -> ExponentialBackoff {
+        self.download_retry_policy.backoff()
+    }
+
+    /// The [`RetryPolicy`] for a request to the given URL.
+    pub fn retry_policy_for(&self, url: &Url) -> ExponentialBackoff {
+        if is_download(url) {
+            self.download_retry_policy()
+        } else {
+            self.retry_policy()
+        }
+    }
+
+    /// Consume one unit of the budget for resuming interrupted downloads, returning `false` if the
+    /// budget is exhausted.
+    pub(crate) fn consume_resume_budget(&self) -> bool {
+        self.resume_budget
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |budget| {
+                budget.checked_sub(1)
+            })
+            .is_ok()
     }
 }
 
@@ -580,6 +840,11 @@ impl RedirectClientWithMiddleware {
         RequestBuilder::new(self.client.post(url), self)
     }
 
+    /// Convenience method to make a `PUT` request to a URL.
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        RequestBuilder::new(self.client.put(url), self)
+    }
+
     /// Convenience method to make a `HEAD` request to a URL.
     pub fn head<U: IntoUrl>(&self, url: U) -> RequestBuilder {
         RequestBuilder::new(self.client.head(url), self)
@@ -854,6 +1119,12 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Set the request body.
+    pub fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.builder = self.builder.body(body);
+        self
+    }
+
     #[cfg_attr(docsrs, doc(cfg(feature = "multipart")))]
     pub fn multipart(mut self, multipart: multipart::Form) -> Self {
         self.builder = self.builder.multipart(multipart);
@@ -16,7 +16,7 @@ use uv_redacted::DisplaySafeUrl;
 use crate::BaseClient;
 use crate::base_client::is_extended_transient_error;
 use crate::{
-    Error, ErrorKind,
+    Error, ErrorKind, http_trace,
     httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
     rkyvutil::OwnedArchive,
 };
@@ -504,6 +504,7 @@ impl CachedClient {
         Ok(match cached.cache_policy.before_request(&mut req) {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
+                http_trace::record_cache_hit(req.method(), req.url());
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
@@ -519,6 +520,7 @@ impl CachedClient {
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
+                    http_trace::record_cache_hit(req.method(), req.url());
                     CachedResponse::FreshCache(cached)
                 }
             },
@@ -673,7 +675,7 @@ impl CachedClient {
     ) -> Result<Payload::Target, CachedClientError<CallBackError>> {
         let mut past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_policy = self.uncached().retry_policy_for(req.url());
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
@@ -731,7 +733,7 @@ impl CachedClient {
     ) -> Result<Payload, CachedClientError<CallBackError>> {
         let mut past_retries = 0;
         let start_time = SystemTime::now();
-        let retry_policy = self.uncached().retry_policy();
+        let retry_policy = self.uncached().retry_policy_for(req.url());
         loop {
             let fresh_req = req.try_clone().expect("HTTP request must be cloneable");
             let result = self
@@ -886,6 +888,24 @@ impl DataWithCachePolicy {
         Self::from_reader(file)
     }
 
+    /// Marks the cached response at the given file path as requiring
+    /// revalidation, such that the next request for it is sent to the server
+    /// as a conditional request (e.g., with `If-None-Match`), rather than
+    /// served from the cache. The cached data itself is retained.
+    ///
+    /// # Errors
+    ///
+    /// If the file could not be read, is not in a valid format, or could not
+    /// be rewritten, then this returns an error.
+    pub fn require_revalidation(path: &Path) -> Result<(), Error> {
+        let cached = Self::from_path_sync(path)?;
+        let mut cache_policy = OwnedArchive::deserialize(&cached.cache_policy);
+        cache_policy.require_revalidation();
+        let bytes = Self::serialize(&cache_policy, &cached.data)?;
+        uv_fs::write_atomic_sync(path, bytes).map_err(ErrorKind::CacheWrite)?;
+        Ok(())
+    }
+
     /// Loads cached data and its associated HTTP cache policy from the given
     /// reader.
     ///
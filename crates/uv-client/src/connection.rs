@@ -0,0 +1,79 @@
+//! Tuning of the HTTP client's connection pool, keep-alive, and HTTP/2 flow control.
+
+use reqwest::ClientBuilder;
+#[cfg(feature = "http3")]
+use tracing::debug;
+
+use uv_configuration::HttpConnectionOptions;
+#[cfg(not(feature = "http3"))]
+use uv_warnings::warn_user_once;
+
+use crate::retry::duration;
+
+/// The default maximum number of idle connections to keep open for each host.
+const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 20;
+
+/// Apply the connection tuning to the [`ClientBuilder`].
+pub(crate) fn configure(
+    client_builder: ClientBuilder,
+    options: &HttpConnectionOptions,
+) -> ClientBuilder {
+    let mut client_builder = client_builder.pool_max_idle_per_host(
+        options
+            .pool_max_idle_per_host
+            .unwrap_or(DEFAULT_POOL_MAX_IDLE_PER_HOST),
+    );
+
+    if let Some(timeout) = options
+        .pool_idle_timeout
+        .and_then(|seconds| duration("pool-idle-timeout", seconds))
+    {
+        client_builder = client_builder.pool_idle_timeout(timeout);
+    }
+    if let Some(interval) = options
+        .tcp_keepalive
+        .and_then(|seconds| duration("tcp-keepalive", seconds))
+    {
+        client_builder = client_builder.tcp_keepalive(interval);
+    }
+    if let Some(interval) = options
+        .http2_keep_alive_interval
+        .and_then(|seconds| duration("http2-keep-alive-interval", seconds))
+    {
+        // Keep idle connections alive too, since they're pooled for reuse.
+        client_builder = client_builder
+            .http2_keep_alive_interval(interval)
+            .http2_keep_alive_while_idle(true);
+    }
+    if let Some(timeout) = options
+        .http2_keep_alive_timeout
+        .and_then(|seconds| duration("http2-keep-alive-timeout", seconds))
+    {
+        client_builder = client_builder.http2_keep_alive_timeout(timeout);
+    }
+    if let Some(size) = options.http2_initial_stream_window_size {
+        client_builder = client_builder.http2_initial_stream_window_size(size);
+    }
+    if let Some(size) = options.http2_initial_connection_window_size {
+        client_builder = client_builder.http2_initial_connection_window_size(size);
+    }
+    if let Some(adaptive) = options.http2_adaptive_window {
+        client_builder = client_builder.http2_adaptive_window(adaptive);
+    }
+
+    if options.http3 == Some(true) {
+        #[cfg(feature = "http3")]
+        {
+            debug!("Using HTTP/3 for all requests");
+            client_builder = client_builder.http3_prior_knowledge();
+        }
+        #[cfg(not(feature = "http3"))]
+        {
+            warn_user_once!(
+                "Ignoring `http3`, since this build of uv does not support HTTP/3. Rebuild uv with the `http3` feature to enable it."
+            );
+        }
+    }
+
+    client_builder
+}
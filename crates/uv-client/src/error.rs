@@ -43,6 +43,15 @@ impl Error {
         matches!(&*self.kind, ErrorKind::Offline(_))
     }
 
+    /// Returns the URL that couldn't be fetched because network connectivity is disabled, if this
+    /// error corresponds to an offline error.
+    pub fn offline_url(&self) -> Option<&str> {
+        match &*self.kind {
+            ErrorKind::Offline(url) => Some(url),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if this error corresponds to an I/O "not found" error.
     pub(crate) fn is_file_not_exists(&self) -> bool {
         let ErrorKind::Io(err) = &*self.kind else {
@@ -51,6 +60,27 @@ impl Error {
         matches!(err.kind(), std::io::ErrorKind::NotFound)
     }
 
+    /// Returns `true` if the error indicates that the server is unavailable (i.e., the request
+    /// timed out, the connection failed, or the server returned a `5xx` status code), such that
+    /// another server may be able to serve the request.
+    pub fn is_server_unavailable(&self) -> bool {
+        fn is_server_unavailable(kind: &ErrorKind) -> bool {
+            match kind {
+                ErrorKind::WrappedReqwestError(_, err) => {
+                    err.status().is_some_and(|status| status.is_server_error())
+                        || err.inner().is_some_and(|err| {
+                            err.is_timeout() || err.is_connect() || err.is_request()
+                        })
+                }
+                // The request may have been retried before giving up.
+                ErrorKind::RequestWithRetries { source, .. } => is_server_unavailable(source),
+                ErrorKind::Io(err) => err.kind() == std::io::ErrorKind::TimedOut,
+                _ => false,
+            }
+        }
+        is_server_unavailable(&self.kind)
+    }
+
     /// Returns `true` if the error is due to an SSL error.
     pub fn is_ssl(&self) -> bool {
         matches!(&*self.kind, ErrorKind::WrappedReqwestError(.., err) if err.is_ssl())
@@ -246,7 +276,9 @@ pub enum ErrorKind {
     #[error("Invalid `Content-Type` header for {0}")]
     InvalidContentTypeHeader(DisplaySafeUrl, #[source] http::header::ToStrError),
 
-    #[error("Unsupported `Content-Type` \"{1}\" for {0}. Expected JSON or HTML.")]
+    #[error(
+        "Unsupported `Content-Type` \"{1}\" for {0}. Expected JSON or HTML. If the index responds to JSON requests with a different API, set `simple-api = \"html\"` for the index."
+    )]
     UnsupportedMediaType(DisplaySafeUrl, String),
 
     #[error("Reading from cache archive failed: {0}")]
@@ -0,0 +1,358 @@
+//! A process-wide trace of the HTTP requests made by uv, as requested via `--trace-http`.
+//!
+//! Each request that reaches the network is recorded with its method, URL, status, timing, and
+//! size, along with its cache disposition; responses served from the cache without a request are
+//! recorded too. Traces are written as [HAR](https://w3c.github.io/web-performance/specs/HAR/Overview.html)
+//! if the file has a `.har` extension, and as JSON Lines otherwise. JSON Lines traces are written
+//! as requests complete, while HAR traces are written when the trace is finished.
+
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use http::header::{
+    AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+    PROXY_AUTHORIZATION, SET_COOKIE,
+};
+use http::{Extensions, HeaderMap, Method, StatusCode};
+use jiff::Timestamp;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use serde::Serialize;
+use serde_json::json;
+use tracing::warn;
+use url::Url;
+
+use uv_redacted::DisplaySafeUrl;
+
+/// Whether HTTP requests are being traced.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The active trace, if any.
+static TRACE: Mutex<Option<HttpTrace>> = Mutex::new(None);
+
+/// Start tracing HTTP requests to the given file, which is truncated if it exists.
+///
+/// The trace is finished when the returned guard is dropped.
+pub fn start_http_trace(path: &Path) -> io::Result<HttpTraceGuard> {
+    let format = if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("har"))
+    {
+        HttpTraceFormat::Har
+    } else {
+        HttpTraceFormat::JsonLines
+    };
+    let writer = BufWriter::new(fs_err::File::create(path)?);
+    *TRACE.lock().unwrap() = Some(HttpTrace {
+        path: path.to_path_buf(),
+        format,
+        writer,
+        entries: Vec::new(),
+    });
+    ENABLED.store(true, Ordering::Relaxed);
+    Ok(HttpTraceGuard(()))
+}
+
+/// Returns `true` if HTTP requests are being traced.
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// A guard that finishes the HTTP trace when dropped.
+#[must_use]
+pub struct HttpTraceGuard(());
+
+impl Drop for HttpTraceGuard {
+    fn drop(&mut self) {
+        ENABLED.store(false, Ordering::Relaxed);
+        let Some(mut trace) = TRACE.lock().unwrap().take() else {
+            return;
+        };
+        if let Err(err) = trace.finish() {
+            warn!(
+                "Failed to write HTTP trace to `{}`: {err}",
+                trace.path.display()
+            );
+        }
+    }
+}
+
+/// The format in which a trace is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HttpTraceFormat {
+    /// An HTTP Archive, written when the trace is finished.
+    Har,
+    /// A JSON object per line, written as each request completes.
+    JsonLines,
+}
+
+#[derive(Debug)]
+struct HttpTrace {
+    path: PathBuf,
+    format: HttpTraceFormat,
+    writer: BufWriter<fs_err::File>,
+    /// The entries recorded so far, for HAR traces.
+    entries: Vec<HttpTraceEntry>,
+}
+
+impl HttpTrace {
+    fn record(&mut self, entry: HttpTraceEntry) -> io::Result<()> {
+        match self.format {
+            HttpTraceFormat::Har => {
+                self.entries.push(entry);
+                Ok(())
+            }
+            HttpTraceFormat::JsonLines => {
+                serde_json::to_writer(&mut self.writer, &entry)?;
+                self.writer.write_all(b"\n")?;
+                self.writer.flush()
+            }
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if self.format == HttpTraceFormat::Har {
+            let entries = self
+                .entries
+                .iter()
+                .map(HttpTraceEntry::to_har)
+                .collect::<Vec<_>>();
+            let har = json!({
+                "log": {
+                    "version": "1.2",
+                    "creator": { "name": "uv", "version": uv_version::version() },
+                    "entries": entries,
+                }
+            });
+            serde_json::to_writer_pretty(&mut self.writer, &har)?;
+            self.writer.write_all(b"\n")?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// How a request was served with respect to the HTTP cache.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum CacheDisposition {
+    /// The response was served from the cache, without a request.
+    Hit,
+    /// A cached response was revalidated, and found to be unmodified.
+    Revalidated,
+    /// A cached response was revalidated, but couldn't be reused.
+    Modified,
+    /// The response was fetched without consulting the cache.
+    Miss,
+}
+
+impl CacheDisposition {
+    /// Determine the disposition of a request from whether it was conditional (i.e., revalidated a
+    /// cached response) and the status of its response, if any.
+    fn from_response(conditional: bool, status: Option<StatusCode>) -> Self {
+        if !conditional {
+            Self::Miss
+        } else if status == Some(StatusCode::NOT_MODIFIED) {
+            Self::Revalidated
+        } else {
+            Self::Modified
+        }
+    }
+}
+
+/// A single request in a trace.
+#[derive(Debug, Serialize)]
+struct HttpTraceEntry {
+    /// The time at which the request was sent.
+    started: Timestamp,
+    method: String,
+    url: String,
+    /// The status of the response, or `None` if no response was received.
+    status: Option<u16>,
+    /// The time until the response headers were received, in milliseconds.
+    time: f64,
+    /// The size of the response body, if known.
+    bytes: Option<u64>,
+    cache: CacheDisposition,
+    /// The error with which the request failed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip)]
+    request_headers: Vec<(String, String)>,
+    #[serde(skip)]
+    response_headers: Vec<(String, String)>,
+    #[serde(skip)]
+    mime_type: Option<String>,
+}
+
+impl HttpTraceEntry {
+    /// Convert the entry to an entry in an HTTP Archive.
+    fn to_har(&self) -> serde_json::Value {
+        let headers = |headers: &[(String, String)]| {
+            headers
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect::<Vec<_>>()
+        };
+        let size = self.bytes.and_then(|bytes| i64::try_from(bytes).ok());
+        json!({
+            "startedDateTime": self.started.to_string(),
+            "time": self.time,
+            "request": {
+                "method": self.method,
+                "url": self.url,
+                "httpVersion": "",
+                "cookies": [],
+                "headers": headers(&self.request_headers),
+                "queryString": [],
+                "headersSize": -1,
+                "bodySize": -1,
+            },
+            "response": {
+                "status": self.status.unwrap_or(0),
+                "statusText": self
+                    .status
+                    .and_then(|status| StatusCode::from_u16(status).ok())
+                    .and_then(|status| status.canonical_reason())
+                    .unwrap_or_default(),
+                "httpVersion": "",
+                "cookies": [],
+                "headers": headers(&self.response_headers),
+                "content": {
+                    "size": size.unwrap_or(-1),
+                    "mimeType": self.mime_type.as_deref().unwrap_or_default(),
+                },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": size.unwrap_or(-1),
+                "_error": self.error,
+            },
+            "cache": {},
+            "timings": {
+                "send": 0,
+                "wait": self.time,
+                "receive": 0,
+            },
+            "_cache": self.cache,
+        })
+    }
+}
+
+/// Record an entry in the active trace, if any.
+fn record(entry: HttpTraceEntry) {
+    let mut trace = TRACE.lock().unwrap();
+    let Some(trace) = trace.as_mut() else {
+        return;
+    };
+    if let Err(err) = trace.record(entry) {
+        warn!(
+            "Failed to write HTTP trace to `{}`: {err}",
+            trace.path.display()
+        );
+    }
+}
+
+/// Record a response that was served from the cache, without a request.
+pub(crate) fn record_cache_hit(method: &Method, url: &Url) {
+    if !is_enabled() {
+        return;
+    }
+    record(HttpTraceEntry {
+        started: Timestamp::now(),
+        method: method.to_string(),
+        url: DisplaySafeUrl::ref_cast(url).to_string(),
+        status: None,
+        time: 0.0,
+        bytes: None,
+        cache: CacheDisposition::Hit,
+        error: None,
+        request_headers: Vec::new(),
+        response_headers: Vec::new(),
+        mime_type: None,
+    });
+}
+
+/// Return the headers to include in a trace, omitting any credentials or cookies.
+fn traced_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| {
+            ![AUTHORIZATION, PROXY_AUTHORIZATION, COOKIE, SET_COOKIE].contains(name)
+        })
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect()
+}
+
+/// A middleware that records each request that reaches the network in the active trace.
+///
+/// The middleware is the innermost in the stack, such that each attempt (e.g., each retry, or each
+/// request with credentials) is recorded separately.
+pub(crate) struct HttpTraceMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for HttpTraceMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let started = Timestamp::now();
+        let start = Instant::now();
+        let method = req.method().to_string();
+        let url = DisplaySafeUrl::ref_cast(req.url()).to_string();
+        let request_headers = traced_headers(req.headers());
+        let conditional = req.headers().contains_key(IF_NONE_MATCH)
+            || req.headers().contains_key(IF_MODIFIED_SINCE);
+
+        let result = next.run(req, extensions).await;
+        let time = duration_millis(start.elapsed());
+
+        let entry = match &result {
+            Ok(response) => HttpTraceEntry {
+                started,
+                method,
+                url,
+                status: Some(response.status().as_u16()),
+                time,
+                bytes: response
+                    .headers()
+                    .get(CONTENT_LENGTH)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse().ok()),
+                cache: CacheDisposition::from_response(conditional, Some(response.status())),
+                error: None,
+                request_headers,
+                response_headers: traced_headers(response.headers()),
+                mime_type: response
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .map(ToString::to_string),
+            },
+            Err(err) => HttpTraceEntry {
+                started,
+                method,
+                url,
+                status: None,
+                time,
+                bytes: None,
+                cache: CacheDisposition::from_response(conditional, None),
+                error: Some(err.to_string()),
+                request_headers,
+                response_headers: Vec::new(),
+                mime_type: None,
+            },
+        };
+        record(entry);
+
+        result
+    }
+}
+
+/// Return the duration in milliseconds, with microsecond precision.
+fn duration_millis(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1_000_000.0).round() / 1000.0
+}
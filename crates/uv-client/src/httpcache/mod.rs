@@ -273,6 +273,15 @@ impl CachePolicy {
         // There's no way (other than OOM) for serializing this type to fail.
         OwnedArchive::from_unarchived(self).expect("all possible values can be archived")
     }
+
+    /// Require the cached response to be revalidated before it's reused, as
+    /// if the server had sent it with a `no-cache` directive.
+    ///
+    /// The validators (i.e., the `ETag` and `Last-Modified` headers) are
+    /// retained, such that the revalidation request is conditional.
+    pub fn require_revalidation(&mut self) {
+        self.response.headers.cc.no_cache = true;
+    }
 }
 
 impl ArchivedCachePolicy {
@@ -1,26 +1,41 @@
+pub use bandwidth::{set_download_bandwidth, throttle};
 pub use base_client::{
-    AuthIntegration, BaseClient, BaseClientBuilder, DEFAULT_RETRIES, ExtraMiddleware,
-    RedirectClientWithMiddleware, RequestBuilder, UvRetryableStrategy, is_extended_transient_error,
+    AuthIntegration, BaseClient, BaseClientBuilder, DEFAULT_RESUME_BUDGET, DEFAULT_RETRIES,
+    ExtraMiddleware, RedirectClientWithMiddleware, RequestBuilder, UvRetryableStrategy,
+    is_extended_transient_error,
 };
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
+pub use http_trace::{HttpTraceGuard, start_http_trace};
 pub use linehaul::LineHaul;
+pub use proxy::{IndexProxy, ProxySettings};
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleMetadata,
     SimpleMetadatum, VersionFiles,
 };
+pub use resolve::IndexResolve;
+pub use retry::HttpRetryPolicy;
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use tls::IndexTls;
 
+mod bandwidth;
 mod base_client;
 mod cached_client;
+mod connection;
 mod error;
 mod flat_index;
 mod html;
+mod http_trace;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod oci;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
+mod resolve;
+mod resume;
+mod retry;
 mod rkyvutil;
 mod tls;
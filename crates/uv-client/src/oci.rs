@@ -0,0 +1,432 @@
+//! Package indexes served from an OCI registry (e.g., `oci://ghcr.io/org/pypi`), via the OCI
+//! distribution API.
+//!
+//! Each package is stored as a repository within the index's namespace (e.g., `org/pypi/anyio`),
+//! with a tag per version. The layers of each tagged manifest are the distributions for that
+//! version, named by their `org.opencontainers.image.title` annotation. Distributions are fetched
+//! as blobs, by digest, such that the URLs recorded in the lockfile are pinned to their content.
+
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use http::{Extensions, HeaderValue, Method, StatusCode};
+use reqwest::header::{AUTHORIZATION, LINK, WWW_AUTHENTICATE};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use tracing::{debug, trace};
+use url::Url;
+
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifiers;
+use uv_pypi_types::{File, Hashes};
+use uv_redacted::DisplaySafeUrl;
+use uv_small_str::SmallString;
+
+/// The media types accepted for manifests: an image manifest, or an index of image manifests.
+pub(crate) const MANIFEST_MEDIA_TYPES: &str =
+    "application/vnd.oci.image.manifest.v1+json, application/vnd.oci.image.index.v1+json";
+
+/// The annotation containing the filename of a layer.
+const TITLE_ANNOTATION: &str = "org.opencontainers.image.title";
+
+/// The annotation containing the `Requires-Python` specifiers of a distribution, if any.
+const REQUIRES_PYTHON_ANNOTATION: &str = "org.python.requires-python";
+
+/// The response to a `tags/list` request.
+#[derive(Debug, Deserialize)]
+pub(crate) struct OciTagList {
+    #[serde(default)]
+    pub(crate) tags: Option<Vec<String>>,
+}
+
+/// An image manifest, or an index of image manifests.
+#[derive(Debug, Deserialize)]
+pub(crate) struct OciManifest {
+    /// The layers of an image manifest.
+    #[serde(default)]
+    pub(crate) layers: Vec<OciDescriptor>,
+    /// The manifests referenced by an image index.
+    #[serde(default)]
+    pub(crate) manifests: Vec<OciDescriptor>,
+}
+
+/// A content descriptor, referencing a blob or manifest by digest.
+#[derive(Debug, Deserialize)]
+pub(crate) struct OciDescriptor {
+    pub(crate) digest: String,
+    #[serde(default)]
+    pub(crate) size: Option<u64>,
+    #[serde(default)]
+    pub(crate) annotations: FxHashMap<String, String>,
+}
+
+impl OciDescriptor {
+    /// Convert a layer into a [`File`], if it's named by a title annotation.
+    ///
+    /// The file is referenced by the URL of its blob, and hashed by its digest.
+    pub(crate) fn into_file(self, repository: &DisplaySafeUrl) -> Option<File> {
+        let filename = self.annotations.get(TITLE_ANNOTATION)?;
+        let (algorithm, digest) = self.digest.split_once(':')?;
+
+        let mut hashes = Hashes::default();
+        match algorithm {
+            "sha256" => hashes.sha256 = Some(SmallString::from(digest)),
+            "sha512" => hashes.sha512 = Some(SmallString::from(digest)),
+            _ => {}
+        }
+
+        let mut url = repository.clone();
+        url.path_segments_mut()
+            .ok()?
+            .pop_if_empty()
+            .push("blobs")
+            .push(&self.digest);
+
+        Some(File {
+            core_metadata: None,
+            filename: SmallString::from(filename.as_str()),
+            hashes,
+            requires_python: self
+                .annotations
+                .get(REQUIRES_PYTHON_ANNOTATION)
+                .map(|specifiers| VersionSpecifiers::from_str(specifiers)),
+            size: self.size,
+            upload_time: None,
+            url: SmallString::from(url.as_str()),
+            yanked: None,
+        })
+    }
+}
+
+/// Return the URL of the repository for a package in an OCI registry, e.g.,
+/// `https://ghcr.io/v2/org/pypi/anyio/` for `anyio` in `oci://ghcr.io/org/pypi`.
+pub(crate) fn repository_url(
+    index: &IndexUrl,
+    package_name: &PackageName,
+) -> Option<DisplaySafeUrl> {
+    let mut url = index.oci_registry()?;
+    let namespace = index.oci_namespace()?;
+    url.path_segments_mut()
+        .ok()?
+        .pop_if_empty()
+        .push("v2")
+        .extend(namespace.split('/').filter(|segment| !segment.is_empty()))
+        .push(package_name.as_ref())
+        .push("");
+    Some(url)
+}
+
+/// Return the URL of a path within a repository, e.g., `tags/list`.
+pub(crate) fn repository_path(repository: &DisplaySafeUrl, path: &[&str]) -> DisplaySafeUrl {
+    let mut url = repository.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().extend(path);
+    }
+    url
+}
+
+/// Return the URL of the next page of a paginated response, from its `Link` header (as in
+/// `</v2/org/pypi/anyio/tags/list?n=100&last=1.0.0>; rel="next"`).
+pub(crate) fn next_page(response: &Response) -> Option<Url> {
+    let link = response.headers().get(LINK)?.to_str().ok()?;
+    let (target, params) = link.split_once(';')?;
+    if !params
+        .split(';')
+        .any(|param| param.trim().trim_start_matches("rel=").trim_matches('"') == "next")
+    {
+        return None;
+    }
+    let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+    response.url().join(target).ok()
+}
+
+/// A `Bearer` challenge from a `WWW-Authenticate` header, as in
+/// `Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:org/pypi/anyio:pull"`.
+#[derive(Debug, PartialEq, Eq)]
+struct BearerChallenge {
+    realm: String,
+    service: Option<String>,
+    scope: Option<String>,
+}
+
+impl BearerChallenge {
+    /// Parse a `Bearer` challenge from the value of a `WWW-Authenticate` header.
+    fn parse(header: &str) -> Option<Self> {
+        let (scheme, params) = header.trim().split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+
+        let mut realm = None;
+        let mut service = None;
+        let mut scope = None;
+        let mut rest = params.trim();
+        while !rest.is_empty() {
+            let (key, value) = rest.split_once('=')?;
+            let key = key.trim().trim_start_matches(',').trim();
+            let value = value.trim_start();
+            // Values may be quoted, in which case they may contain commas (e.g., in a scope).
+            let (value, remainder) = if let Some(value) = value.strip_prefix('"') {
+                let end = value.find('"')?;
+                (&value[..end], &value[end + 1..])
+            } else {
+                let end = value.find(',').unwrap_or(value.len());
+                (&value[..end], &value[end..])
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "realm" => realm = Some(value.to_string()),
+                "service" => service = Some(value.to_string()),
+                "scope" => scope = Some(value.to_string()),
+                _ => {}
+            }
+            rest = remainder.trim_start().trim_start_matches(',').trim_start();
+        }
+
+        Some(Self {
+            realm: realm?,
+            service,
+            scope,
+        })
+    }
+
+    /// Return the URL from which to request a token.
+    fn token_url(&self) -> Option<Url> {
+        let mut url = Url::parse(&self.realm).ok()?;
+        {
+            let mut query = url.query_pairs_mut();
+            if let Some(service) = &self.service {
+                query.append_pair("service", service);
+            }
+            if let Some(scope) = &self.scope {
+                query.append_pair("scope", scope);
+            }
+        }
+        Some(url)
+    }
+}
+
+/// The response to a token request.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// A middleware that authenticates requests to OCI registries with the token flow of the
+/// distribution API.
+///
+/// When a registry responds with a `401` and a `Bearer` challenge, a token is requested from the
+/// realm named in the challenge, and the request is retried with the token. The token request
+/// passes through the rest of the middleware stack, such that any credentials configured for the
+/// registry are used to obtain the token. Tokens are reused for subsequent requests to the same
+/// repository.
+pub(crate) struct OciAuthMiddleware {
+    /// The base URLs of the registries' HTTP APIs.
+    registries: Vec<DisplaySafeUrl>,
+    /// The tokens obtained so far, by registry and repository.
+    tokens: Mutex<FxHashMap<String, String>>,
+}
+
+impl OciAuthMiddleware {
+    pub(crate) fn new(registries: Vec<DisplaySafeUrl>) -> Self {
+        Self {
+            registries,
+            tokens: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Return the key under which to store the token for a request, i.e., the registry and
+    /// repository, if the request is to the API of a known registry.
+    fn token_key(&self, url: &Url) -> Option<String> {
+        let registry = self.registries.iter().find(|registry| {
+            registry.scheme() == url.scheme()
+                && registry.host_str() == url.host_str()
+                && registry.port_or_known_default() == url.port_or_known_default()
+        })?;
+        let path = url.path().strip_prefix("/v2/")?;
+        let repository = ["/tags/", "/manifests/", "/blobs/"]
+            .iter()
+            .find_map(|endpoint| path.rfind(endpoint).map(|index| &path[..index]))?;
+        Some(format!("{registry}{repository}"))
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for OciAuthMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(key) = self.token_key(req.url()) else {
+            return next.run(req, extensions).await;
+        };
+
+        // Reuse any token obtained for the repository.
+        let token = self.tokens.lock().unwrap().get(&key).cloned();
+        if let Some(token) = token {
+            if let Ok(value) = HeaderValue::from_str(&format!("Bearer {token}")) {
+                req.headers_mut().insert(AUTHORIZATION, value);
+            }
+        }
+
+        let retry_request = req.try_clone();
+        let response = next.clone().run(req, extensions).await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        let Some(mut retry_request) = retry_request else {
+            return Ok(response);
+        };
+        let Some(challenge) = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(BearerChallenge::parse)
+        else {
+            return Ok(response);
+        };
+        let Some(token_url) = challenge.token_url() else {
+            return Ok(response);
+        };
+
+        // Request a token for the repository.
+        trace!(
+            "Requesting a token for {} from {}",
+            DisplaySafeUrl::ref_cast(retry_request.url()),
+            DisplaySafeUrl::ref_cast(&token_url)
+        );
+        let token_response = next
+            .clone()
+            .run(Request::new(Method::GET, token_url), extensions)
+            .await?;
+        if !token_response.status().is_success() {
+            debug!(
+                "Failed to obtain a token for {}: {}",
+                DisplaySafeUrl::ref_cast(retry_request.url()),
+                token_response.status()
+            );
+            return Ok(response);
+        }
+        let token = match token_response.json::<TokenResponse>().await {
+            Ok(
+                TokenResponse {
+                    token: Some(token), ..
+                }
+                | TokenResponse {
+                    access_token: Some(token),
+                    ..
+                },
+            ) => token,
+            Ok(_) => {
+                debug!("Token response did not include a token");
+                return Ok(response);
+            }
+            Err(err) => {
+                debug!("Failed to parse token response: {err}");
+                return Ok(response);
+            }
+        };
+        let Ok(value) = HeaderValue::from_str(&format!("Bearer {token}")) else {
+            return Ok(response);
+        };
+        self.tokens.lock().unwrap().insert(key, token);
+
+        retry_request.headers_mut().insert(AUTHORIZATION, value);
+        next.run(retry_request, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_challenge() {
+        let challenge = BearerChallenge::parse(
+            r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:org/pypi/anyio:pull,push""#,
+        )
+        .unwrap();
+        assert_eq!(
+            challenge,
+            BearerChallenge {
+                realm: "https://ghcr.io/token".to_string(),
+                service: Some("ghcr.io".to_string()),
+                scope: Some("repository:org/pypi/anyio:pull,push".to_string()),
+            }
+        );
+        assert_eq!(
+            challenge.token_url().unwrap().as_str(),
+            "https://ghcr.io/token?service=ghcr.io&scope=repository%3Aorg%2Fpypi%2Fanyio%3Apull%2Cpush"
+        );
+
+        assert!(BearerChallenge::parse(r#"Basic realm="registry""#).is_none());
+        assert!(BearerChallenge::parse(r#"Bearer service="ghcr.io""#).is_none());
+    }
+
+    #[test]
+    fn token_key() {
+        let middleware =
+            OciAuthMiddleware::new(vec![DisplaySafeUrl::parse("https://ghcr.io/").unwrap()]);
+        assert_eq!(
+            middleware
+                .token_key(&Url::parse("https://ghcr.io/v2/org/pypi/anyio/tags/list").unwrap())
+                .as_deref(),
+            Some("https://ghcr.io/org/pypi/anyio")
+        );
+        assert_eq!(
+            middleware
+                .token_key(
+                    &Url::parse("https://ghcr.io/v2/org/pypi/anyio/blobs/sha256:abc").unwrap()
+                )
+                .as_deref(),
+            Some("https://ghcr.io/org/pypi/anyio")
+        );
+        assert_eq!(
+            middleware.token_key(&Url::parse("https://ghcr.io/token").unwrap()),
+            None
+        );
+        assert_eq!(
+            middleware.token_key(&Url::parse("https://example.com/v2/org/tags/list").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn layer_into_file() {
+        let repository = DisplaySafeUrl::parse("https://ghcr.io/v2/org/pypi/anyio/").unwrap();
+        let layer: OciDescriptor = serde_json::from_str(
+            r#"{
+                "mediaType": "application/vnd.pypa.wheel",
+                "digest": "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+                "size": 89631,
+                "annotations": {
+                    "org.opencontainers.image.title": "anyio-4.3.0-py3-none-any.whl",
+                    "org.python.requires-python": ">=3.8"
+                }
+            }"#,
+        )
+        .unwrap();
+        let file = layer.into_file(&repository).unwrap();
+        assert_eq!(file.filename.as_ref(), "anyio-4.3.0-py3-none-any.whl");
+        assert_eq!(
+            file.url.as_ref(),
+            "https://ghcr.io/v2/org/pypi/anyio/blobs/sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"
+        );
+        assert_eq!(
+            file.hashes.sha256.as_deref(),
+            Some("cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")
+        );
+        assert_eq!(file.size, Some(89631));
+        assert!(file.requires_python.is_some_and(|result| result.is_ok()));
+
+        // Layers without a title aren't distributions.
+        let layer: OciDescriptor =
+            serde_json::from_str(r#"{"digest": "sha256:abc", "size": 2}"#).unwrap();
+        assert!(layer.into_file(&repository).is_none());
+    }
+}
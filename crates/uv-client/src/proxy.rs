@@ -0,0 +1,128 @@
+//! Explicit proxy configuration, globally and per index.
+//!
+//! By default, the HTTP client uses the proxies from the environment (`HTTP_PROXY`, `HTTPS_PROXY`,
+//! `ALL_PROXY`, and `NO_PROXY`). Once any proxy is configured explicitly, the client no longer
+//! discovers the system proxies on its own, so the environment variables are applied here instead,
+//! with a lower precedence than the explicit configuration.
+
+use std::env;
+
+use reqwest::{NoProxy, Proxy};
+use url::Url;
+
+use uv_distribution_types::{Index, ProxyUrl};
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+/// The proxies configured explicitly, rather than via the environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxySettings {
+    /// The proxy to use for `http` requests.
+    pub http_proxy: Option<ProxyUrl>,
+    /// The proxy to use for `https` requests.
+    pub https_proxy: Option<ProxyUrl>,
+    /// The hosts for which the proxies should be bypassed.
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxySettings {
+    /// Returns `true` if no proxy settings are configured explicitly.
+    pub fn is_empty(&self) -> bool {
+        self.http_proxy.is_none() && self.https_proxy.is_none() && self.no_proxy.is_empty()
+    }
+}
+
+/// The proxy to use for the requests to an index's host.
+///
+/// Applies to any request to the same scheme, host, and port as the index URL, including requests
+/// for artifacts hosted alongside the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexProxy {
+    /// The URL of the index, or of the registry's HTTP API for an OCI registry.
+    pub url: DisplaySafeUrl,
+    /// The proxy to use for the index.
+    pub proxy: ProxyUrl,
+}
+
+impl IndexProxy {
+    /// Extract the proxy from an [`Index`], if it defines one.
+    pub fn from_index(index: &Index) -> Option<Self> {
+        Some(Self {
+            url: index
+                .url()
+                .oci_registry()
+                .unwrap_or_else(|| index.raw_url().clone()),
+            proxy: index.proxy.clone()?,
+        })
+    }
+
+    /// Returns `true` if the proxy applies to requests to the given URL.
+    fn matches(&self, url: &Url) -> bool {
+        self.url.scheme() == url.scheme()
+            && self.url.host_str() == url.host_str()
+            && self.url.port_or_known_default() == url.port_or_known_default()
+    }
+}
+
+/// Return the proxies to configure on the client, in order of precedence, or `None` if the client
+/// should discover the system proxies on its own.
+pub(crate) fn proxies(
+    settings: &ProxySettings,
+    index_proxies: &[IndexProxy],
+) -> Option<Vec<Proxy>> {
+    if settings.is_empty() && index_proxies.is_empty() {
+        return None;
+    }
+
+    let mut proxies = Vec::new();
+
+    // Per-index proxies take precedence over everything else.
+    for index_proxy in index_proxies {
+        let index_proxy = index_proxy.clone();
+        proxies.push(Proxy::custom(move |url| {
+            index_proxy
+                .matches(url)
+                .then(|| Url::from(index_proxy.proxy.url().clone()))
+        }));
+    }
+
+    // Apply the explicit proxies, falling back to the environment.
+    let no_proxy = if settings.no_proxy.is_empty() {
+        NoProxy::from_env()
+    } else {
+        NoProxy::from_string(&settings.no_proxy.join(","))
+    };
+    let http = settings
+        .http_proxy
+        .as_ref()
+        .map(|proxy| Url::from(proxy.url().clone()).to_string())
+        .or_else(|| env_proxy(EnvVars::HTTP_PROXY));
+    let https = settings
+        .https_proxy
+        .as_ref()
+        .map(|proxy| Url::from(proxy.url().clone()).to_string())
+        .or_else(|| env_proxy(EnvVars::HTTPS_PROXY));
+    let all = env_proxy(EnvVars::ALL_PROXY);
+    for (proxy, name) in [
+        (http.map(Proxy::http), EnvVars::HTTP_PROXY),
+        (https.map(Proxy::https), EnvVars::HTTPS_PROXY),
+        (all.map(Proxy::all), EnvVars::ALL_PROXY),
+    ] {
+        match proxy {
+            Some(Ok(proxy)) => proxies.push(proxy.no_proxy(no_proxy.clone())),
+            Some(Err(err)) => warn_user_once!("Ignoring invalid proxy from `{name}`: {err}"),
+            None => {}
+        }
+    }
+
+    Some(proxies)
+}
+
+/// Read a proxy from the environment, accepting both the upper- and lowercase variable names.
+fn env_proxy(name: &str) -> Option<String> {
+    env::var(name)
+        .or_else(|_| env::var(name.to_ascii_lowercase()))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
@@ -18,11 +18,11 @@ use url::Url;
 use uv_auth::Indexes;
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_configuration::KeyringProviderType;
-use uv_configuration::{IndexStrategy, TrustedHost};
+use uv_configuration::{HttpConnectionOptions, IndexStrategy, TrustedHost};
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, File, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
-    IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, Name,
+    BuiltDist, File, Index, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
+    IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, IndexUrls, Name, SimpleApiFormat,
 };
 use uv_metadata::{read_metadata_async_seek, read_metadata_async_stream};
 use uv_normalize::PackageName;
@@ -33,13 +33,19 @@ use uv_pypi_types::{ResolutionMetadata, SimpleJson};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_torch::TorchStrategy;
+use uv_warnings::warn_user_once;
 
+use crate::bandwidth::throttle;
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware, RedirectPolicy};
 use crate::cached_client::CacheControl;
 use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleHtml;
+use crate::oci::{self, OciDescriptor, OciManifest, OciTagList};
+use crate::proxy::{IndexProxy, ProxySettings};
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
+use crate::resolve::IndexResolve;
 use crate::rkyvutil::OwnedArchive;
+use crate::tls::IndexTls;
 use crate::{
     BaseClient, CachedClient, Error, ErrorKind, FlatIndexClient, FlatIndexEntries,
     RedirectClientWithMiddleware,
@@ -73,7 +79,35 @@ impl<'a> RegistryClientBuilder<'a> {
         self.index_urls = index_locations.index_urls();
         self.base_client_builder = self
             .base_client_builder
-            .indexes(Indexes::from(index_locations));
+            .indexes(Indexes::from(index_locations))
+            .index_tls(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(IndexTls::from_index)
+                    .collect(),
+            )
+            .index_proxies(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(IndexProxy::from_index)
+                    .collect(),
+            )
+            .index_resolves(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(IndexResolve::from_index)
+                    .collect(),
+            )
+            .oci_registries(
+                index_locations
+                    .allowed_indexes()
+                    .into_iter()
+                    .filter_map(|index| index.url().oci_registry())
+                    .collect(),
+            );
         self
     }
 
@@ -158,6 +192,18 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn proxy_settings(mut self, proxy_settings: ProxySettings) -> Self {
+        self.base_client_builder = self.base_client_builder.proxy_settings(proxy_settings);
+        self
+    }
+
+    #[must_use]
+    pub fn http_connection(mut self, http_connection: HttpConnectionOptions) -> Self {
+        self.base_client_builder = self.base_client_builder.http_connection(http_connection);
+        self
+    }
+
     #[must_use]
     pub fn proxy(mut self, proxy: Proxy) -> Self {
         self.base_client_builder = self.base_client_builder.proxy(proxy);
@@ -358,19 +404,17 @@ impl RegistryClient {
                     let _permit = download_concurrency.acquire().await;
                     match index.format {
                         IndexFormat::Simple => {
-                            let status_code_strategy =
-                                self.index_urls.status_code_strategy_for(index.url);
-                            match self
-                                .simple_single_index(
+                            let (index_url, outcome) = self
+                                .simple_single_index_with_fallback(
                                     package_name,
                                     index.url,
                                     capabilities,
-                                    &status_code_strategy,
+                                    |url| self.index_urls.status_code_strategy_for(url),
                                 )
-                                .await?
-                            {
+                                .await?;
+                            match outcome {
                                 SimpleMetadataSearchOutcome::Found(metadata) => {
-                                    results.push((index.url, MetadataFormat::Simple(metadata)));
+                                    results.push((index_url, MetadataFormat::Simple(metadata)));
                                     break;
                                 }
                                 // Package not found, so we will continue on to the next index (if there is one)
@@ -404,21 +448,19 @@ impl RegistryClient {
                         match index.format {
                             IndexFormat::Simple => {
                                 // For unsafe matches, ignore authentication failures.
-                                let status_code_strategy =
-                                    IndexStatusCodeStrategy::ignore_authentication_error_codes();
-                                let metadata = match self
-                                    .simple_single_index(
+                                let (index_url, outcome) = self
+                                    .simple_single_index_with_fallback(
                                         package_name,
                                         index.url,
                                         capabilities,
-                                        &status_code_strategy,
+                                        |_| IndexStatusCodeStrategy::ignore_authentication_error_codes(),
                                     )
-                                    .await?
-                                {
+                                    .await?;
+                                let metadata = match outcome {
                                     SimpleMetadataSearchOutcome::Found(metadata) => Some(metadata),
                                     _ => None,
                                 };
-                                Ok((index.url, metadata.map(MetadataFormat::Simple)))
+                                Ok((index_url, metadata.map(MetadataFormat::Simple)))
                             }
                             IndexFormat::Flat => {
                                 let entries =
@@ -450,6 +492,71 @@ impl RegistryClient {
         Ok(results)
     }
 
+    /// Fetch the [`SimpleMetadata`] from a single index for a given package, failing over to the
+    /// index's fallbacks (as in `fallback-of`) if the index is unavailable.
+    ///
+    /// Returns the [`IndexUrl`] of the index that served the metadata, along with the outcome.
+    /// Indexes that were previously unavailable are only searched once their alternatives have
+    /// been exhausted.
+    async fn simple_single_index_with_fallback<'index>(
+        &'index self,
+        package_name: &PackageName,
+        index: &'index IndexUrl,
+        capabilities: &IndexCapabilities,
+        status_code_strategy: impl Fn(&IndexUrl) -> IndexStatusCodeStrategy,
+    ) -> Result<(&'index IndexUrl, SimpleMetadataSearchOutcome), Error> {
+        let fallbacks = self.index_urls.fallbacks_for(index);
+        if fallbacks.is_empty() {
+            let outcome = self
+                .simple_single_index(
+                    package_name,
+                    index,
+                    capabilities,
+                    &status_code_strategy(index),
+                )
+                .await?;
+            return Ok((index, outcome));
+        }
+
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = std::iter::once(index)
+            .chain(
+                fallbacks
+                    .into_iter()
+                    .filter(|fallback| fallback.format == IndexFormat::Simple)
+                    .map(Index::url),
+            )
+            .partition(|url| !capabilities.unhealthy(url));
+        let mut candidates = healthy.into_iter().chain(unhealthy).peekable();
+
+        while let Some(url) = candidates.next() {
+            let result = self
+                .simple_single_index(package_name, url, capabilities, &status_code_strategy(url))
+                .await;
+            match result {
+                Ok(outcome) => {
+                    if url != index {
+                        debug!("Fetched metadata for `{package_name}` from fallback index: {url}");
+                    }
+                    return Ok((url, outcome));
+                }
+                Err(err) if err.is_server_unavailable() => {
+                    capabilities.set_unhealthy(url.clone());
+                    let Some(next) = candidates.peek() else {
+                        return Err(err);
+                    };
+                    warn_user_once!(
+                        "Index `{}` is unavailable ({err}); falling back to `{}`",
+                        url.without_credentials(),
+                        next.without_credentials()
+                    );
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        unreachable!("the index itself is always a candidate")
+    }
+
     /// Fetch the [`FlatIndexEntry`] entries for a given package from a single `--find-links` index.
     async fn flat_single_index(
         &self,
@@ -518,16 +625,36 @@ impl RegistryClient {
             WheelCache::Index(index).root(),
             format!("{package_name}.rkyv"),
         );
+
+        // If the index is known not to serve the package, avoid requesting it again.
+        let not_found_entry = cache_entry.with_file(format!("{package_name}.not-found"));
+        let not_found_ttl = self
+            .index_urls
+            .not_found_cache_ttl_for(index)
+            .filter(|_| self.connectivity.is_online() && !matches!(index, IndexUrl::Path(_)));
+        if let Some(ttl) = not_found_ttl {
+            if self.is_cached_not_found(package_name, &not_found_entry, ttl)? {
+                debug!("Skipping `{package_name}` on {index}, which was recently not found");
+                return Ok(SimpleMetadataSearchOutcome::NotFound);
+            }
+        }
+
         let cache_control = match self.connectivity {
             Connectivity::Online => {
-                if let Some(header) = self.index_urls.simple_api_cache_control_for(index) {
+                let freshness = self
+                    .cache
+                    .freshness(&cache_entry, Some(package_name), None)
+                    .map_err(ErrorKind::Io)?;
+                // A refresh (e.g., `--refresh-package`) takes precedence over the index's
+                // `Cache-Control` override.
+                if let Some(header) = self
+                    .index_urls
+                    .simple_api_cache_control_for(index)
+                    .filter(|_| !freshness.is_stale())
+                {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
-                        self.cache
-                            .freshness(&cache_entry, Some(package_name), None)
-                            .map_err(ErrorKind::Io)?,
-                    )
+                    CacheControl::from(freshness)
                 }
             }
             Connectivity::Offline => CacheControl::AllowStale,
@@ -542,8 +669,12 @@ impl RegistryClient {
 
         let result = if matches!(index, IndexUrl::Path(_)) {
             self.fetch_local_index(package_name, &url).await
+        } else if let Some(repository) = oci::repository_url(index, package_name) {
+            self.fetch_oci_index(package_name, &repository, &cache_entry, cache_control)
+                .await
         } else {
-            self.fetch_remote_index(package_name, &url, &cache_entry, cache_control)
+            let accept = MediaType::accepts(self.index_urls.simple_api_format_for(index));
+            self.fetch_remote_index(package_name, &url, accept, &cache_entry, cache_control)
                 .await
         };
 
@@ -565,6 +696,17 @@ impl RegistryClient {
                             return Err(ErrorKind::WrappedReqwestError(url, err).into());
                         }
                     }
+                    if status_code == StatusCode::NOT_FOUND
+                        && matches!(decision, IndexStatusCodeDecision::Ignore)
+                        && not_found_ttl.is_some()
+                    {
+                        fs_err::tokio::create_dir_all(not_found_entry.dir())
+                            .await
+                            .map_err(ErrorKind::CacheWrite)?;
+                        fs_err::tokio::write(not_found_entry.path(), b"")
+                            .await
+                            .map_err(ErrorKind::CacheWrite)?;
+                    }
                     Ok(SimpleMetadataSearchOutcome::from(decision))
                 }
 
@@ -579,11 +721,152 @@ impl RegistryClient {
         }
     }
 
+    /// Returns `true` if the index was found not to serve the package within the given
+    /// time-to-live, and the cached result hasn't been invalidated by a refresh.
+    fn is_cached_not_found(
+        &self,
+        package_name: &PackageName,
+        cache_entry: &CacheEntry,
+        ttl: Duration,
+    ) -> Result<bool, Error> {
+        let modified = match fs_err::metadata(cache_entry.path()) {
+            Ok(metadata) => metadata.modified().map_err(ErrorKind::Io)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(err) => return Err(ErrorKind::Io(err).into()),
+        };
+        if !modified.elapsed().is_ok_and(|age| age < ttl) {
+            return Ok(false);
+        }
+        Ok(self
+            .cache
+            .freshness(cache_entry, Some(package_name), None)
+            .map_err(ErrorKind::Io)?
+            .is_fresh())
+    }
+
+    /// Fetch the [`SimpleMetadata`] from an OCI registry, by listing the tags of the package's
+    /// repository and reading the layers of each tagged manifest.
+    async fn fetch_oci_index(
+        &self,
+        package_name: &PackageName,
+        repository: &DisplaySafeUrl,
+        cache_entry: &CacheEntry,
+        cache_control: CacheControl<'_>,
+    ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
+        let url = oci::repository_path(repository, &["tags", "list"]);
+        let tags_request = self
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", "application/json")
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        let parse_tags_response = |response: Response| {
+            async {
+                // Collect the tags, following any pagination.
+                let mut tags = Vec::new();
+                let mut response = response;
+                loop {
+                    let url = DisplaySafeUrl::from(response.url().clone());
+                    let next = oci::next_page(&response);
+                    let bytes = response
+                        .bytes()
+                        .await
+                        .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+                    let data: OciTagList = serde_json::from_slice(bytes.as_ref())
+                        .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                    tags.extend(data.tags.unwrap_or_default());
+                    let Some(next) = next else {
+                        break;
+                    };
+                    response = self
+                        .fetch_oci(DisplaySafeUrl::from(next), "application/json")
+                        .await?;
+                }
+
+                // Each version is stored under its own tag. Since tags can't contain a `+`, local
+                // versions are tagged with an `_` instead. Any other tags (like `latest`) are
+                // ignored.
+                let tags = tags
+                    .into_iter()
+                    .filter(|tag| Version::from_str(&tag.replace('_', "+")).is_ok())
+                    .collect::<Vec<_>>();
+
+                let files = futures::stream::iter(tags)
+                    .map(|tag| self.fetch_oci_layers(repository, tag))
+                    .buffer_unordered(50)
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|layer| layer.into_file(repository))
+                    .collect();
+
+                let unarchived = SimpleMetadata::from_files(files, package_name, repository);
+                OwnedArchive::from_unarchived(&unarchived)
+            }
+            .boxed_local()
+            .instrument(info_span!("parse_oci_index", package = %package_name))
+        };
+        let simple = self
+            .cached_client()
+            .get_cacheable_with_retry(
+                tags_request,
+                cache_entry,
+                cache_control,
+                parse_tags_response,
+            )
+            .await?;
+        Ok(simple)
+    }
+
+    /// Fetch the layers of the manifest for a tag in an OCI repository, following an image index
+    /// to the manifests that it references.
+    async fn fetch_oci_layers(
+        &self,
+        repository: &DisplaySafeUrl,
+        tag: String,
+    ) -> Result<Vec<OciDescriptor>, Error> {
+        let url = oci::repository_path(repository, &["manifests", &tag]);
+        let manifest = self.fetch_oci_manifest(url).await?;
+
+        let mut layers = manifest.layers;
+        for descriptor in manifest.manifests {
+            let url = oci::repository_path(repository, &["manifests", &descriptor.digest]);
+            layers.extend(self.fetch_oci_manifest(url).await?.layers);
+        }
+        Ok(layers)
+    }
+
+    /// Fetch and parse a manifest from an OCI repository.
+    async fn fetch_oci_manifest(&self, url: DisplaySafeUrl) -> Result<OciManifest, Error> {
+        let response = self
+            .fetch_oci(url.clone(), oci::MANIFEST_MEDIA_TYPES)
+            .await?;
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        serde_json::from_slice(bytes.as_ref()).map_err(|err| Error::from_json_err(err, url))
+    }
+
+    /// Perform a `GET` request against the API of an OCI registry.
+    async fn fetch_oci(&self, url: DisplaySafeUrl, accept: &str) -> Result<Response, Error> {
+        self.uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", accept)
+            .send()
+            .await
+            .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
+            .error_for_status()
+            .map_err(|err| ErrorKind::from_reqwest(url, err).into())
+    }
+
     /// Fetch the [`SimpleMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
     async fn fetch_remote_index(
         &self,
         package_name: &PackageName,
         url: &DisplaySafeUrl,
+        accept: &'static str,
         cache_entry: &CacheEntry,
         cache_control: CacheControl<'_>,
     ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
@@ -591,7 +874,7 @@ impl RegistryClient {
             .uncached_client(url)
             .get(Url::from(url.clone()))
             .header("Accept-Encoding", "gzip, deflate, zstd")
-            .header("Accept", MediaType::accepts())
+            .header("Accept", accept)
             .build()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
         let parse_simple_response = |response: Response| {
@@ -786,7 +1069,7 @@ impl RegistryClient {
     ) -> Result<ResolutionMetadata, Error> {
         // If the metadata file is available at its own url (PEP 658), download it from there.
         let filename = WheelFilename::from_str(&file.filename).map_err(ErrorKind::WheelFilename)?;
-        if file.dist_info_metadata {
+        if file.dist_info_metadata && self.index_urls.core_metadata_for(index) {
             let mut url = url.clone();
             let path = format!("{}.metadata", url.path());
             url.set_path(&path);
@@ -903,7 +1186,9 @@ impl RegistryClient {
         };
 
         // Attempt to fetch via a range request.
-        if index.is_none_or(|index| capabilities.supports_range_requests(index)) {
+        if index.is_none_or(|index| {
+            capabilities.supports_range_requests(index) && self.index_urls.range_requests_for(index)
+        }) {
             let req = self
                 .uncached_client(url)
                 .head(Url::from(url.clone()))
@@ -993,8 +1278,7 @@ impl RegistryClient {
         // Stream the file, searching for the METADATA.
         let read_metadata_stream = |response: Response| {
             async {
-                let reader = response
-                    .bytes_stream()
+                let reader = throttle(response.bytes_stream())
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -1222,11 +1506,18 @@ impl MediaType {
         }
     }
 
-    /// Return the `Accept` header value for all supported media types.
+    /// Return the `Accept` header value for the given Simple API format, or for all supported
+    /// media types if the format isn't forced.
     #[inline]
-    const fn accepts() -> &'static str {
+    const fn accepts(format: Option<SimpleApiFormat>) -> &'static str {
         // See: https://peps.python.org/pep-0691/#version-format-selection
-        "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01"
+        match format {
+            None => {
+                "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01"
+            }
+            Some(SimpleApiFormat::Json) => "application/vnd.pypi.simple.v1+json",
+            Some(SimpleApiFormat::Html) => "application/vnd.pypi.simple.v1+html, text/html;q=0.01",
+        }
     }
 }
 
@@ -0,0 +1,106 @@
+use std::net::{SocketAddr, ToSocketAddrs};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use uv_distribution_types::{Index, ResolveTarget};
+use uv_redacted::DisplaySafeUrl;
+
+/// The address to which an index's host should be resolved, in lieu of DNS.
+///
+/// Since DNS resolution is per host, the override applies to any request to the index's host,
+/// regardless of scheme or port, including requests for artifacts hosted alongside the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexResolve {
+    /// The URL of the index, or of the registry's HTTP API for an OCI registry.
+    pub url: DisplaySafeUrl,
+    /// The IP address or host name to connect to instead.
+    pub target: ResolveTarget,
+}
+
+impl IndexResolve {
+    /// Extract the resolution override from an [`Index`], if it defines one.
+    pub fn from_index(index: &Index) -> Option<Self> {
+        Some(Self {
+            url: index
+                .url()
+                .oci_registry()
+                .unwrap_or_else(|| index.raw_url().clone()),
+            target: index.resolve.clone()?,
+        })
+    }
+
+    /// Returns `true` if the override applies to the given host name.
+    fn matches(&self, host: &str) -> bool {
+        self.url
+            .host_str()
+            .is_some_and(|url_host| url_host.eq_ignore_ascii_case(host))
+    }
+}
+
+/// A DNS resolver that applies the resolution overrides configured for each index, and resolves
+/// all other host names via the system resolver.
+///
+/// The request URL is left unchanged, such that the `Host` header and the TLS server name (SNI)
+/// continue to reflect the index's host.
+#[derive(Debug)]
+pub(crate) struct IndexResolver {
+    overrides: Vec<IndexResolve>,
+}
+
+impl IndexResolver {
+    pub(crate) fn new(overrides: Vec<IndexResolve>) -> Self {
+        Self { overrides }
+    }
+}
+
+impl Resolve for IndexResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = match self
+            .overrides
+            .iter()
+            .find(|index_resolve| index_resolve.matches(name.as_str()))
+            .map(|index_resolve| &index_resolve.target)
+        {
+            Some(ResolveTarget::Ip(ip)) => {
+                // The port is set by the connector, based on the request URL.
+                let addrs: Addrs = Box::new(std::iter::once(SocketAddr::new(*ip, 0)));
+                return Box::pin(std::future::ready(Ok(addrs)));
+            }
+            Some(ResolveTarget::Host(host)) => host.clone(),
+            None => name.as_str().to_string(),
+        };
+        Box::pin(async move {
+            // Mirror the default resolver, which performs a blocking `getaddrinfo` call.
+            let addrs =
+                tokio::task::spawn_blocking(move || (host.as_str(), 0).to_socket_addrs()).await??;
+            Ok(Box::new(addrs) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn resolve_override() {
+        let resolver = IndexResolver::new(vec![IndexResolve {
+            url: DisplaySafeUrl::parse("https://pypi.example.com/simple").unwrap(),
+            target: ResolveTarget::Ip("10.0.0.5".parse().unwrap()),
+        }]);
+
+        let addrs = resolver
+            .resolve("PyPI.example.com".parse().unwrap())
+            .await
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(addrs, vec!["10.0.0.5:0".parse().unwrap()]);
+
+        let addrs = resolver
+            .resolve("localhost".parse().unwrap())
+            .await
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert!(addrs.iter().all(|addr| addr.ip().is_loopback()));
+    }
+}
@@ -0,0 +1,341 @@
+//! Resumption of interrupted downloads via HTTP range requests.
+//!
+//! When the body of a response is interrupted by a transient error, the download is resumed from
+//! the last byte written with a `Range` request. The request is guarded by an `If-Range`
+//! validator, such that the server responds with the full body if the resource changed in the
+//! meantime. Servers that don't support range requests, or that don't provide a validator, are
+//! handled by restarting the download from scratch.
+
+use std::io;
+use std::time::{Duration, SystemTime};
+
+use futures::StreamExt;
+use http::header::{CONTENT_LENGTH, CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE};
+use http::{HeaderValue, StatusCode};
+use reqwest::{Request, Response};
+use reqwest_retry::{RetryDecision, RetryPolicy};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tracing::{debug, warn};
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::BaseClient;
+use crate::bandwidth::consume_bandwidth;
+use crate::base_client::is_extended_transient_error;
+
+impl BaseClient {
+    /// Write the body of a [`Response`] to a file, resuming the download if the body is
+    /// interrupted by a transient error.
+    ///
+    /// Each download is resumed at most as many times as the client retries downloads, and all
+    /// downloads share a total budget (see `UV_HTTP_RESUME_BUDGET`). Since resumed content is
+    /// appended to the file, any hashes should be computed from the file once the download
+    /// completes, rather than from the response.
+    ///
+    /// The `request` must be the request that produced the `response`, and is used as a template
+    /// for any range requests. The file is expected to be empty. Returns the number of bytes
+    /// written.
+    pub async fn download_to_file(
+        &self,
+        request: &Request,
+        response: Response,
+        file: &mut tokio::fs::File,
+        mut on_progress: impl FnMut(u64),
+    ) -> io::Result<u64> {
+        let url = DisplaySafeUrl::from(response.url().clone());
+        let validator = validator(&response);
+        let mut expected = content_length(&response);
+        let mut response = response;
+        let mut written = 0;
+
+        let start_time = SystemTime::now();
+        let retry_policy = self.download_retry_policy();
+        let mut past_retries = 0;
+        loop {
+            let err = match self
+                .write_body(response, file, &mut written, &mut on_progress)
+                .await
+            {
+                Ok(()) => match expected {
+                    Some(expected) if written < expected => io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("The response body ended after {written} of {expected} bytes"),
+                    ),
+                    _ => {
+                        file.flush().await?;
+                        return Ok(written);
+                    }
+                },
+                Err(err) => err,
+            };
+
+            if !is_resumable(&err) {
+                return Err(err);
+            }
+            let RetryDecision::Retry { execute_after } =
+                retry_policy.should_retry(start_time, past_retries)
+            else {
+                return Err(err);
+            };
+            if !self.consume_resume_budget() {
+                debug!("Not resuming download of {url}: the resume budget is exhausted");
+                return Err(err);
+            }
+            past_retries += 1;
+
+            let duration = execute_after
+                .duration_since(SystemTime::now())
+                .unwrap_or_else(|_| Duration::default());
+            tokio::time::sleep(duration).await;
+
+            // Request the remainder of the body, if the server can be trusted to serve the same
+            // resource; otherwise, request the body from scratch.
+            let mut request = request.try_clone().expect("HTTP request must be cloneable");
+            let range = validator.as_ref().filter(|_| written > 0);
+            if let Some(validator) = range {
+                warn!(
+                    "Download of {url} was interrupted after {written} bytes ({err}); resuming (attempt {past_retries})"
+                );
+                request.headers_mut().insert(
+                    RANGE,
+                    HeaderValue::from_str(&format!("bytes={written}-"))
+                        .expect("range is a valid header value"),
+                );
+                request.headers_mut().insert(IF_RANGE, validator.clone());
+            } else {
+                warn!(
+                    "Download of {url} was interrupted after {written} bytes ({err}); restarting (attempt {past_retries})"
+                );
+            }
+            response = self
+                .execute(request)
+                .await
+                .and_then(|response| Ok(response.error_for_status()?))
+                .map_err(io::Error::other)?;
+
+            match response.status() {
+                StatusCode::PARTIAL_CONTENT if range.is_some() => {
+                    let Some((first, total)) = content_range(&response) else {
+                        return Err(io::Error::other(format!(
+                            "Received an invalid `Content-Range` while resuming the download of {url}"
+                        )));
+                    };
+                    if first != written {
+                        return Err(io::Error::other(format!(
+                            "Received a range starting at byte {first} while resuming the download of {url} at byte {written}"
+                        )));
+                    }
+                    debug!("Resuming download of {url} at byte {written}");
+                    expected = total.or(expected);
+                }
+                StatusCode::OK => {
+                    // The server ignored the range (or the resource changed), so discard the
+                    // partial content.
+                    if written > 0 {
+                        debug!("Restarting download of {url} from scratch");
+                        file.set_len(0).await?;
+                        file.seek(io::SeekFrom::Start(0)).await?;
+                        written = 0;
+                    }
+                    expected = content_length(&response);
+                }
+                status => {
+                    return Err(io::Error::other(format!(
+                        "Received an unexpected status code ({status}) while resuming the download of {url}"
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Append the body of a [`Response`] to a file, tracking the number of bytes written.
+    async fn write_body(
+        &self,
+        response: Response,
+        file: &mut tokio::fs::File,
+        written: &mut u64,
+        on_progress: &mut impl FnMut(u64),
+    ) -> io::Result<()> {
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|err| {
+                if err.is_timeout() {
+                    io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!(
+                            "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).",
+                            self.timeout().as_secs()
+                        ),
+                    )
+                } else {
+                    io::Error::other(err)
+                }
+            })?;
+            consume_bandwidth(chunk.len()).await;
+            file.write_all(&chunk).await?;
+            *written += chunk.len() as u64;
+            on_progress(chunk.len() as u64);
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if a download that failed with the given error can be resumed.
+fn is_resumable(err: &io::Error) -> bool {
+    if matches!(
+        err.kind(),
+        io::ErrorKind::TimedOut | io::ErrorKind::UnexpectedEof
+    ) {
+        return true;
+    }
+    if err
+        .get_ref()
+        .and_then(|err| err.downcast_ref::<reqwest::Error>())
+        .is_some_and(reqwest::Error::is_body)
+    {
+        return true;
+    }
+    is_extended_transient_error(err)
+}
+
+/// Returns the validator with which a range request for the resource can be made conditional,
+/// if any.
+///
+/// Weak entity tags can't be used for range requests, in which case the modification date is used
+/// instead.
+fn validator(response: &Response) -> Option<HeaderValue> {
+    response
+        .headers()
+        .get(ETAG)
+        .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+        .or_else(|| response.headers().get(LAST_MODIFIED))
+        .cloned()
+}
+
+/// Returns the value of the `Content-Length` header from the [`Response`], if present.
+fn content_length(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.parse::<u64>().ok())
+}
+
+/// Returns the first byte and the complete length from the `Content-Range` header of a partial
+/// [`Response`], as in `bytes 100-199/200`.
+fn content_range(response: &Response) -> Option<(u64, Option<u64>)> {
+    let value = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let (first, _) = range.split_once('-')?;
+    let first = first.trim().parse::<u64>().ok()?;
+    let total = total.trim().parse::<u64>().ok();
+    Some((first, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use anyhow::Result;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use uv_redacted::DisplaySafeUrl;
+
+    use crate::BaseClientBuilder;
+
+    const BODY: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    /// Serve [`BODY`] over HTTP, dropping the connection halfway through the first response, and
+    /// honoring range requests thereafter.
+    async fn spawn_flaky_server(requests: Arc<AtomicUsize>) -> Result<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let requests = requests.clone();
+                tokio::spawn(async move {
+                    let mut buffer = vec![0; 4096];
+                    let Ok(n) = stream.read(&mut buffer).await else {
+                        return;
+                    };
+                    let request = String::from_utf8_lossy(&buffer[..n]).to_lowercase();
+                    let first = request
+                        .lines()
+                        .find_map(|line| line.strip_prefix("range: bytes="))
+                        .and_then(|range| range.trim_end_matches('-').parse::<usize>().ok());
+
+                    let response = if requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                        // Advertise the full body, but only send half of it.
+                        let mut response = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nETag: \"v1\"\r\n\r\n",
+                            BODY.len()
+                        )
+                        .into_bytes();
+                        response.extend_from_slice(&BODY[..BODY.len() / 2]);
+                        response
+                    } else if let Some(first) = first {
+                        let mut response = format!(
+                            "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {first}-{}/{}\r\nETag: \"v1\"\r\n\r\n",
+                            BODY.len() - first,
+                            BODY.len() - 1,
+                            BODY.len()
+                        )
+                        .into_bytes();
+                        response.extend_from_slice(&BODY[first..]);
+                        response
+                    } else {
+                        let mut response = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nETag: \"v1\"\r\n\r\n",
+                            BODY.len()
+                        )
+                        .into_bytes();
+                        response.extend_from_slice(BODY);
+                        response
+                    };
+                    let _ = stream.write_all(&response).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+        Ok(format!("http://{addr}/file.whl"))
+    }
+
+    #[tokio::test]
+    async fn resume_interrupted_download() -> Result<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let url = spawn_flaky_server(requests.clone()).await?;
+
+        let client = BaseClientBuilder::new().build();
+        let request = client
+            .for_host(&DisplaySafeUrl::parse(&url)?)
+            .get(&url)
+            .build()?;
+        let response = client
+            .execute(request.try_clone().unwrap())
+            .await?
+            .error_for_status()?;
+
+        let temp_dir = tempfile::tempdir()?;
+        let mut file = tokio::fs::File::create(temp_dir.path().join("file.whl")).await?;
+        let mut progress = 0;
+        let written = client
+            .download_to_file(&request, response, &mut file, |n| progress += n)
+            .await?;
+        drop(file);
+
+        assert_eq!(written, BODY.len() as u64);
+        assert_eq!(progress, BODY.len() as u64);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            fs_err::tokio::read(temp_dir.path().join("file.whl")).await?,
+            BODY
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+//! The policies for retrying HTTP requests that failed with a transient error.
+//!
+//! Requests for artifacts (i.e., wheels, source distributions, and Python installations) and all
+//! other requests (e.g., for the Simple API or for distribution metadata) can be retried under
+//! separate policies, since artifacts tend to be larger and are often served by different hosts.
+
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, SystemTime};
+
+use http::{Extensions, StatusCode, header::RETRY_AFTER};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::policies::ExponentialBackoff;
+use reqwest_retry::{Jitter, RetryTransientMiddleware, Retryable, RetryableStrategy};
+use tracing::debug;
+use url::Url;
+
+use uv_configuration::{RetryOn, RetryPolicyOptions};
+use uv_distribution_filename::DistExtension;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::base_client::{DEFAULT_RETRIES, UvRetryableStrategy};
+
+/// The policy for retrying HTTP requests that failed with a transient error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRetryPolicy {
+    /// The number of times a failed request is retried.
+    pub retries: u32,
+    /// The delay before the first retry, which doubles with each subsequent retry.
+    pub backoff_base: Duration,
+    /// The maximum delay between retries.
+    pub backoff_max: Duration,
+    /// Whether to randomize the delay between retries.
+    pub jitter: bool,
+    /// Whether to wait for the delay requested via a `Retry-After` header before retrying.
+    pub retry_after: bool,
+    /// The classes of failures on which requests are retried.
+    pub retry_on: Vec<RetryOn>,
+}
+
+impl Default for HttpRetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RETRIES,
+            backoff_base: Duration::from_secs(1),
+            backoff_max: Duration::from_secs(30 * 60),
+            jitter: true,
+            retry_after: false,
+            retry_on: RetryOn::ALL.to_vec(),
+        }
+    }
+}
+
+impl HttpRetryPolicy {
+    /// Resolve the [`HttpRetryPolicy`] from the user-provided options, falling back to the given
+    /// policy for any unset fields.
+    pub fn from_options(options: &RetryPolicyOptions, fallback: &Self) -> Self {
+        let backoff_base = options
+            .backoff_base
+            .and_then(|seconds| duration("backoff-base", seconds))
+            .unwrap_or(fallback.backoff_base);
+        let backoff_max = options
+            .backoff_max
+            .and_then(|seconds| duration("backoff-max", seconds))
+            .unwrap_or(fallback.backoff_max)
+            .max(backoff_base);
+        Self {
+            retries: options.retries.unwrap_or(fallback.retries),
+            backoff_base,
+            backoff_max,
+            jitter: options.jitter.unwrap_or(fallback.jitter),
+            retry_after: options.retry_after.unwrap_or(fallback.retry_after),
+            retry_on: options
+                .retry_on
+                .clone()
+                .unwrap_or_else(|| fallback.retry_on.clone()),
+        }
+    }
+
+    /// Create the [`ExponentialBackoff`] that implements the policy.
+    pub fn backoff(&self) -> ExponentialBackoff {
+        let mut builder = ExponentialBackoff::builder()
+            .retry_bounds(self.backoff_base, self.backoff_max)
+            .jitter(if self.jitter {
+                Jitter::Full
+            } else {
+                Jitter::None
+            });
+        if std::env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
+            builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
+        }
+        builder.build_with_max_retries(self.retries)
+    }
+}
+
+impl HttpRetryPolicy {
+    /// Returns `true` if the request failed with a transient error that the policy retries on.
+    ///
+    /// For requests that can't go through the retry middleware, such as uploads with a streaming
+    /// body, which retry manually instead.
+    pub fn is_transient(&self, result: &Result<Response, reqwest_middleware::Error>) -> bool {
+        let strategy = RetryOnStrategy {
+            retry_on: self.retry_on.clone(),
+        };
+        strategy.handle(result) == Some(Retryable::Transient)
+    }
+
+    /// The delay requested via the `Retry-After` header of an HTTP 429 or 503 response, if the
+    /// policy honors it.
+    pub fn retry_after(&self, response: &Response) -> Option<Duration> {
+        if !self.retry_after
+            || !matches!(
+                response.status(),
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            )
+        {
+            return None;
+        }
+        retry_after(response).map(|delay| delay.min(self.backoff_max))
+    }
+}
+
+impl Display for HttpRetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} retries, with a backoff from {}s to {}s{}",
+            self.retries,
+            self.backoff_base.as_secs_f64(),
+            self.backoff_max.as_secs_f64(),
+            if self.jitter { " (with jitter)" } else { "" },
+        )?;
+        if self.retry_on.is_empty() {
+            f.write_str(", on no failures")?;
+        } else {
+            write!(
+                f,
+                ", on {}",
+                self.retry_on
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        if self.retry_after {
+            f.write_str(", honoring `Retry-After`")?;
+        }
+        Ok(())
+    }
+}
+
+/// Convert a number of seconds from the user-provided options into a [`Duration`].
+pub(crate) fn duration(name: &str, seconds: f64) -> Option<Duration> {
+    Duration::try_from_secs_f64(seconds)
+        .inspect_err(|_| {
+            warn_user_once!(
+                "Ignoring invalid value for `{name}`. Expected a non-negative number of seconds, got `{seconds}`."
+            );
+        })
+        .ok()
+}
+
+/// Returns `true` if the URL refers to an artifact, rather than to metadata.
+pub(crate) fn is_download(url: &Url) -> bool {
+    DistExtension::from_path(url.path()).is_ok()
+}
+
+/// A middleware that retries transient failures under the policy for the kind of request.
+pub(crate) struct RetryMiddleware {
+    metadata: Option<RetryTransientMiddleware<ExponentialBackoff, RetryOnStrategy>>,
+    downloads: Option<RetryTransientMiddleware<ExponentialBackoff, RetryOnStrategy>>,
+}
+
+impl RetryMiddleware {
+    pub(crate) fn new(metadata: &HttpRetryPolicy, downloads: &HttpRetryPolicy) -> Self {
+        Self {
+            metadata: Self::middleware(metadata),
+            downloads: Self::middleware(downloads),
+        }
+    }
+
+    /// Create the retry middleware for a policy, or `None` if the policy disables retries.
+    ///
+    /// Requests that can't be retried never go through the middleware, as it requires the request
+    /// to be cloneable, which isn't the case with a streaming body (e.g., during publish).
+    fn middleware(
+        policy: &HttpRetryPolicy,
+    ) -> Option<RetryTransientMiddleware<ExponentialBackoff, RetryOnStrategy>> {
+        (policy.retries > 0).then(|| {
+            RetryTransientMiddleware::new_with_policy_and_strategy(
+                policy.backoff(),
+                RetryOnStrategy {
+                    retry_on: policy.retry_on.clone(),
+                },
+            )
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let middleware = if is_download(req.url()) {
+            self.downloads.as_ref()
+        } else {
+            self.metadata.as_ref()
+        };
+        match middleware {
+            Some(middleware) => middleware.handle(req, extensions, next).await,
+            None => next.run(req, extensions).await,
+        }
+    }
+}
+
+/// The number of attempts made for a request so far, shared across retries via the request
+/// [`Extensions`].
+#[derive(Debug, Clone, Copy)]
+struct Attempts(u32);
+
+/// A middleware that waits for the delay requested via the `Retry-After` header of an HTTP 429 or
+/// 503 response before the request is retried.
+///
+/// Must be applied within the [`RetryMiddleware`], which then waits for its own backoff in
+/// addition to the requested delay.
+pub(crate) struct RetryAfterMiddleware {
+    metadata: HttpRetryPolicy,
+    downloads: HttpRetryPolicy,
+}
+
+impl RetryAfterMiddleware {
+    /// Create the middleware, or `None` if neither policy honors the `Retry-After` header.
+    pub(crate) fn new(metadata: &HttpRetryPolicy, downloads: &HttpRetryPolicy) -> Option<Self> {
+        (metadata.retry_after || downloads.retry_after).then(|| Self {
+            metadata: metadata.clone(),
+            downloads: downloads.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let policy = if is_download(req.url()) {
+            &self.downloads
+        } else {
+            &self.metadata
+        };
+
+        // Count the attempts, to avoid waiting after the last one.
+        let attempts = extensions
+            .get::<Attempts>()
+            .map_or(0, |attempts| attempts.0)
+            + 1;
+        extensions.insert(Attempts(attempts));
+
+        let response = next.run(req, extensions).await?;
+        if !policy.retry_after || attempts > policy.retries {
+            return Ok(response);
+        }
+        let class = match response.status() {
+            StatusCode::TOO_MANY_REQUESTS => RetryOn::RateLimits,
+            StatusCode::SERVICE_UNAVAILABLE => RetryOn::ServerErrors,
+            _ => return Ok(response),
+        };
+        if !policy.retry_on.contains(&class) {
+            return Ok(response);
+        }
+        if let Some(delay) = retry_after(&response) {
+            let delay = delay.min(policy.backoff_max);
+            debug!(
+                "Waiting {}s before retrying {}, as requested by the server",
+                delay.as_secs_f64(),
+                response.url()
+            );
+            tokio::time::sleep(delay).await;
+        }
+        Ok(response)
+    }
+}
+
+/// Parse the delay requested via the `Retry-After` header, as either a number of seconds or an
+/// HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let timestamp = jiff::fmt::rfc2822::DateTimeParser::new()
+        .parse_timestamp(value)
+        .ok()?;
+    Some(
+        SystemTime::from(timestamp)
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Extends [`UvRetryableStrategy`] to only retry on the configured classes of failures.
+pub(crate) struct RetryOnStrategy {
+    retry_on: Vec<RetryOn>,
+}
+
+impl RetryableStrategy for RetryOnStrategy {
+    fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        let class = match res {
+            Ok(response) => match response.status() {
+                StatusCode::REQUEST_TIMEOUT => Some(RetryOn::Network),
+                StatusCode::TOO_MANY_REQUESTS => Some(RetryOn::RateLimits),
+                status if status.is_server_error() => Some(RetryOn::ServerErrors),
+                _ => None,
+            },
+            Err(_) => Some(RetryOn::Network),
+        };
+        if class.is_some_and(|class| !self.retry_on.contains(&class)) {
+            return Some(Retryable::Fatal);
+        }
+        UvRetryableStrategy.handle(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use url::Url;
+
+    use uv_configuration::{RetryOn, RetryPolicyOptions};
+
+    use super::{HttpRetryPolicy, is_download};
+
+    #[test]
+    fn resolve_policy() {
+        let metadata = HttpRetryPolicy::from_options(
+            &RetryPolicyOptions {
+                retries: Some(5),
+                backoff_base: Some(0.5),
+                retry_on: Some(vec![RetryOn::Network]),
+                ..RetryPolicyOptions::default()
+            },
+            &HttpRetryPolicy::default(),
+        );
+        assert_eq!(metadata.retries, 5);
+        assert_eq!(metadata.backoff_base, Duration::from_millis(500));
+        assert_eq!(metadata.backoff_max, Duration::from_secs(30 * 60));
+        assert_eq!(metadata.retry_on, vec![RetryOn::Network]);
+
+        // Downloads inherit unset fields from the metadata policy.
+        let downloads = HttpRetryPolicy::from_options(
+            &RetryPolicyOptions {
+                backoff_max: Some(0.25),
+                retry_after: Some(true),
+                ..RetryPolicyOptions::default()
+            },
+            &metadata,
+        );
+        assert_eq!(downloads.retries, 5);
+        assert_eq!(downloads.backoff_max, Duration::from_millis(500));
+        assert!(downloads.retry_after);
+        assert_eq!(downloads.retry_on, vec![RetryOn::Network]);
+    }
+
+    #[test]
+    fn retry_after() {
+        let response = |status: u16| {
+            reqwest::Response::from(
+                http::Response::builder()
+                    .status(status)
+                    .header(http::header::RETRY_AFTER, "120")
+                    .body("")
+                    .unwrap(),
+            )
+        };
+        let policy = HttpRetryPolicy {
+            retry_after: true,
+            backoff_max: Duration::from_secs(60),
+            ..HttpRetryPolicy::default()
+        };
+        assert_eq!(
+            policy.retry_after(&response(429)),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(policy.retry_after(&response(500)), None);
+        assert!(policy.is_transient(&Ok(response(503))));
+        assert!(!policy.is_transient(&Ok(response(400))));
+
+        // The header is ignored unless the policy honors it.
+        assert_eq!(HttpRetryPolicy::default().retry_after(&response(429)), None);
+    }
+
+    #[test]
+    fn classify_downloads() {
+        for (url, expected) in [
+            ("https://pypi.org/simple/flask/", false),
+            (
+                "https://files.pythonhosted.org/packages/flask-3.0.0-py3-none-any.whl",
+                true,
+            ),
+            (
+                "https://files.pythonhosted.org/packages/flask-3.0.0-py3-none-any.whl.metadata",
+                false,
+            ),
+            (
+                "https://files.pythonhosted.org/packages/flask-3.0.0.tar.gz",
+                true,
+            ),
+            ("https://pypi.org/pypi/flask/json", false),
+        ] {
+            assert_eq!(is_download(&Url::parse(url).unwrap()), expected, "{url}");
+        }
+    }
+}
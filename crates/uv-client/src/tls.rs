@@ -1,6 +1,12 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use url::Url;
+
+use uv_distribution_types::Index;
+use uv_redacted::DisplaySafeUrl;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -19,3 +25,66 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the `Identity` from the provided certificate and (separate) private key files.
+pub(crate) fn read_identity_with_key(
+    ssl_client_cert: &Path,
+    ssl_client_key: &Path,
+) -> Result<Identity, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ssl_client_cert)?.read_to_end(&mut buf)?;
+    buf.push(b'\n');
+    fs_err::File::open(ssl_client_key)?.read_to_end(&mut buf)?;
+    Identity::from_pem(&buf).map_err(|tls_err| {
+        debug_assert!(tls_err.is_builder(), "must be a rustls::Error internally");
+        CertificateError::Reqwest(tls_err)
+    })
+}
+
+/// Return the `Certificate`s from the provided bundle of certificate authorities.
+pub(crate) fn read_certificates(ssl_ca_cert: &Path) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ssl_ca_cert)?.read_to_end(&mut buf)?;
+    Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)
+}
+
+/// TLS settings that apply to the connections to an index's host.
+///
+/// Since TLS is negotiated per connection, the settings apply to any request to the same scheme,
+/// host, and port as the index URL, including requests for artifacts hosted alongside the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexTls {
+    /// The URL of the index, or of the registry's HTTP API for an OCI registry.
+    pub url: DisplaySafeUrl,
+    /// A bundle of certificate authorities to trust, in addition to the default trust store.
+    pub ca_cert: Option<PathBuf>,
+    /// A client certificate to present for mTLS, optionally including the private key.
+    pub client_cert: Option<PathBuf>,
+    /// The private key for the client certificate, if not included in the certificate file.
+    pub client_key: Option<PathBuf>,
+}
+
+impl IndexTls {
+    /// Extract the TLS settings from an [`Index`], if it defines any.
+    pub fn from_index(index: &Index) -> Option<Self> {
+        if !index.has_tls_settings() {
+            return None;
+        }
+        Some(Self {
+            url: index
+                .url()
+                .oci_registry()
+                .unwrap_or_else(|| index.raw_url().clone()),
+            ca_cert: index.ssl_ca_cert.clone(),
+            client_cert: index.ssl_client_cert.clone(),
+            client_key: index.ssl_client_key.clone(),
+        })
+    }
+
+    /// Returns `true` if the settings apply to requests to the given URL.
+    pub(crate) fn matches(&self, url: &Url) -> bool {
+        self.url.scheme() == url.scheme()
+            && self.url.host_str() == url.host_str()
+            && self.url.port_or_known_default() == url.port_or_known_default()
+    }
+}
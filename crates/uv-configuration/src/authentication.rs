@@ -11,6 +11,9 @@ pub enum KeyringProviderType {
     Disabled,
     /// Use the `keyring` command for credential lookup.
     Subprocess,
+    /// Use the operating system's credential store for credential lookup (i.e., the Keychain on
+    /// macOS, the Credential Manager on Windows, or the Secret Service on Linux).
+    Native,
     // /// Not yet implemented
     // Auto,
     // /// Not implemented yet. Maybe use <https://docs.rs/keyring/latest/keyring/> for this?
@@ -23,6 +26,7 @@ impl KeyringProviderType {
         match self {
             Self::Disabled => None,
             Self::Subprocess => Some(KeyringProvider::subprocess()),
+            Self::Native => Some(KeyringProvider::native()),
         }
     }
 }
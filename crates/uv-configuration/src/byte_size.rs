@@ -0,0 +1,168 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::{fmt::Formatter, str::FromStr};
+
+/// A size in bytes, as used for download and cache size limits (e.g., `500MB` or `2GiB`).
+///
+/// Decimal units (`kB`, `MB`, `GB`, `TB`) are powers of 1000, while binary units (`KiB`, `MiB`,
+/// `GiB`, `TiB`) are powers of 1024. A number without a unit is interpreted as a count of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Create a [`ByteSize`] from a number of bytes.
+    pub fn from_bytes(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the size in bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ByteSizeError {
+    #[error(
+        "Invalid size `{0}`: expected a number of bytes, optionally followed by a unit (e.g., `500MB` or `2GiB`)"
+    )]
+    Invalid(String),
+    #[error(
+        "Invalid size `{0}`: unknown unit `{1}` (expected one of `B`, `kB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`)"
+    )]
+    UnknownUnit(String, String),
+    #[error("Invalid size `{0}`: value is too large")]
+    Overflow(String),
+}
+
+impl FromStr for ByteSize {
+    type Err = ByteSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+        let unit = unit.trim();
+
+        let multiplier: u64 = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1000,
+            "mb" => 1000u64.pow(2),
+            "gb" => 1000u64.pow(3),
+            "tb" => 1000u64.pow(4),
+            "kib" => 1024,
+            "mib" => 1024u64.pow(2),
+            "gib" => 1024u64.pow(3),
+            "tib" => 1024u64.pow(4),
+            _ => {
+                return Err(ByteSizeError::UnknownUnit(s.to_string(), unit.to_string()));
+            }
+        };
+
+        if number.is_empty() {
+            return Err(ByteSizeError::Invalid(s.to_string()));
+        }
+
+        // Parse integral values exactly, and fall back to floating-point for fractional values
+        // (e.g., `1.5GB`).
+        let bytes = if let Ok(number) = number.parse::<u64>() {
+            number
+                .checked_mul(multiplier)
+                .ok_or_else(|| ByteSizeError::Overflow(s.to_string()))?
+        } else {
+            let number = number
+                .parse::<f64>()
+                .map_err(|_| ByteSizeError::Invalid(s.to_string()))?;
+            #[allow(clippy::cast_precision_loss)]
+            let bytes = (number * multiplier as f64).round();
+            #[allow(clippy::cast_precision_loss)]
+            if !bytes.is_finite() || bytes >= u64::MAX as f64 {
+                return Err(ByteSizeError::Overflow(s.to_string()));
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let bytes = bytes as u64;
+            bytes
+        };
+
+        Ok(Self(bytes))
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ByteSize {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ByteSize")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": ["string", "integer"],
+            "description": "A size in bytes, optionally followed by a unit, e.g. `500MB` or `2GiB`."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ByteSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = ByteSize;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a size in bytes, optionally followed by a unit (e.g., `500MB`)")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(ByteSize(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u64::try_from(v)
+                    .map(ByteSize)
+                    .map_err(|_| serde::de::Error::custom("size must be non-negative"))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                ByteSize::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ByteSize;
+
+    #[test]
+    fn parse() {
+        let bytes = |s: &str| ByteSize::from_str(s).map(ByteSize::bytes).ok();
+        assert_eq!(bytes("0"), Some(0));
+        assert_eq!(bytes("1024"), Some(1024));
+        assert_eq!(bytes("10B"), Some(10));
+        assert_eq!(bytes("500MB"), Some(500_000_000));
+        assert_eq!(bytes("500 mb"), Some(500_000_000));
+        assert_eq!(bytes("2GiB"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(bytes("1.5kB"), Some(1500));
+        assert_eq!(bytes("0.5KiB"), Some(512));
+        assert_eq!(bytes("1TB"), Some(1_000_000_000_000));
+        assert_eq!(bytes(""), None);
+        assert_eq!(bytes("MB"), None);
+        assert_eq!(bytes("1.2.3MB"), None);
+        assert_eq!(bytes("10 parsecs"), None);
+        assert_eq!(bytes("-1"), None);
+        assert_eq!(bytes("99999999999TiB"), None);
+    }
+}
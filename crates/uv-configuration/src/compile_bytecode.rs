@@ -0,0 +1,118 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+
+use uv_pep508::PackageName;
+
+/// The optimization level to use when compiling Python files to bytecode, equivalent to the
+/// interpreter's `-O` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum BytecodeOptimizationLevel {
+    /// Don't apply any optimizations.
+    #[cfg_attr(feature = "clap", value(name = "0"))]
+    Zero,
+    /// Remove `assert` statements and any code conditional on `__debug__`.
+    #[cfg_attr(feature = "clap", value(name = "1"))]
+    One,
+    /// Remove `assert` statements, any code conditional on `__debug__`, and docstrings.
+    #[cfg_attr(feature = "clap", value(name = "2"))]
+    Two,
+}
+
+impl TryFrom<u8> for BytecodeOptimizationLevel {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Zero),
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => Err(format!(
+                "invalid bytecode optimization level `{value}`; expected 0, 1, or 2"
+            )),
+        }
+    }
+}
+
+impl From<BytecodeOptimizationLevel> for u8 {
+    fn from(value: BytecodeOptimizationLevel) -> Self {
+        match value {
+            BytecodeOptimizationLevel::Zero => 0,
+            BytecodeOptimizationLevel::One => 1,
+            BytecodeOptimizationLevel::Two => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for BytecodeOptimizationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for BytecodeOptimizationLevel {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("BytecodeOptimizationLevel")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 2,
+            "description": "The optimization level to use when compiling Python files to bytecode."
+        })
+    }
+}
+
+/// Which packages to compile to bytecode after installation, and how.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CompileBytecode {
+    /// Whether to compile all packages in the environment.
+    all: bool,
+    /// Packages to compile, even if `all` is disabled.
+    include: Vec<PackageName>,
+    /// Packages to skip, even if `all` is enabled.
+    exclude: Vec<PackageName>,
+    /// The optimization level to use, or `None` to use the interpreter default.
+    level: Option<BytecodeOptimizationLevel>,
+}
+
+impl CompileBytecode {
+    /// Determine the bytecode compilation strategy to use.
+    pub fn from_args(
+        compile_bytecode: Option<bool>,
+        compile_bytecode_package: Vec<PackageName>,
+        no_compile_bytecode_package: Vec<PackageName>,
+        compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+    ) -> Self {
+        Self {
+            all: compile_bytecode.unwrap_or_default(),
+            include: compile_bytecode_package,
+            exclude: no_compile_bytecode_package,
+            level: compile_bytecode_level,
+        }
+    }
+
+    /// Returns `true` if any packages should be compiled.
+    pub fn is_enabled(&self) -> bool {
+        self.all || !self.include.is_empty()
+    }
+
+    /// Returns `true` if every package in the environment should be compiled.
+    pub fn is_all(&self) -> bool {
+        self.all && self.exclude.is_empty()
+    }
+
+    /// Returns `true` if the specified package should be compiled.
+    pub fn contains(&self, package_name: &PackageName) -> bool {
+        !self.exclude.contains(package_name) && (self.all || self.include.contains(package_name))
+    }
+
+    /// Return the optimization level to use, if set.
+    pub fn level(&self) -> Option<BytecodeOptimizationLevel> {
+        self.level
+    }
+}
@@ -7,6 +7,10 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub downloads: usize,
+    /// The maximum number of concurrent downloads from any one host.
+    ///
+    /// Note this value must be non-zero.
+    pub downloads_per_host: usize,
     /// The maximum number of concurrent builds.
     ///
     /// Note this value must be non-zero.
@@ -15,14 +19,20 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of concurrent bytecode compilation workers.
+    ///
+    /// Note this value must be non-zero.
+    pub compiles: usize,
 }
 
 impl Default for Concurrency {
     fn default() -> Self {
         Concurrency {
             downloads: Concurrency::DEFAULT_DOWNLOADS,
+            downloads_per_host: Concurrency::DEFAULT_DOWNLOADS,
             builds: Concurrency::threads(),
             installs: Concurrency::threads(),
+            compiles: Concurrency::threads(),
         }
     }
 }
@@ -31,7 +41,7 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
-    // The default concurrent builds and install limit.
+    // The default concurrent builds, install, and compile limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
             .map(NonZeroUsize::get)
@@ -0,0 +1,18 @@
+use crate::ByteSize;
+
+/// Limits on the total size of the distributions downloaded during an installation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadLimits {
+    /// Report the total download size, and request confirmation when running interactively, if
+    /// it exceeds this size.
+    pub confirm: Option<ByteSize>,
+    /// Refuse to proceed if the total download size exceeds this size.
+    pub max: Option<ByteSize>,
+}
+
+impl DownloadLimits {
+    /// Returns `true` if no limits are configured.
+    pub fn is_empty(&self) -> bool {
+        self.confirm.is_none() && self.max.is_none()
+    }
+}
@@ -0,0 +1,61 @@
+/// The tuning of the HTTP client's connections.
+///
+/// Unset fields fall back to the defaults.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HttpConnectionOptions {
+    /// The maximum number of idle connections to keep open for each host.
+    ///
+    /// Defaults to `20`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// The time after which an idle connection is closed, in seconds.
+    ///
+    /// Defaults to `90`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_idle_timeout: Option<f64>,
+    /// The interval at which TCP keep-alive probes are sent on open connections, in seconds.
+    ///
+    /// Defaults to the operating system's setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_keepalive: Option<f64>,
+    /// The interval at which HTTP/2 `PING` frames are sent to keep connections alive, in seconds.
+    ///
+    /// Defaults to disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_keep_alive_interval: Option<f64>,
+    /// The time to wait for the acknowledgement of an HTTP/2 `PING` frame before closing the
+    /// connection, in seconds.
+    ///
+    /// Defaults to `20`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_keep_alive_timeout: Option<f64>,
+    /// The initial flow-control window size of each HTTP/2 stream, in bytes, which bounds the data
+    /// in flight for a single multiplexed request.
+    ///
+    /// Defaults to `65535`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_initial_stream_window_size: Option<u32>,
+    /// The initial flow-control window size of each HTTP/2 connection, in bytes, which bounds the
+    /// data in flight across all multiplexed requests on the connection.
+    ///
+    /// Defaults to `65535`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_initial_connection_window_size: Option<u32>,
+    /// Whether to adjust the HTTP/2 flow-control windows dynamically, based on the estimated
+    /// bandwidth-delay product of the connection. Overrides the initial window sizes.
+    ///
+    /// Defaults to `false`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_adaptive_window: Option<bool>,
+    /// Whether to use HTTP/3 (over QUIC) for all requests, rather than negotiating HTTP/1.1 or
+    /// HTTP/2.
+    ///
+    /// Experimental: requests to hosts that don't support HTTP/3 will fail. Requires a build of uv
+    /// with the `http3` feature enabled.
+    ///
+    /// Defaults to `false`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http3: Option<bool>,
+}
@@ -0,0 +1,25 @@
+/// Commands to invoke around the installation of each distribution into an environment.
+///
+/// Each command is provided as a list of arguments, the first of which is the executable to run.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InstallHooks {
+    /// A command to invoke just before each distribution is linked into the environment.
+    ///
+    /// If the command exits with a non-zero status, the installation is aborted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_install: Vec<String>,
+    /// A command to invoke after each distribution is installed into the environment.
+    ///
+    /// If the command exits with a non-zero status, the installation is aborted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_install: Vec<String>,
+}
+
+impl InstallHooks {
+    /// Returns `true` if no hooks are configured.
+    pub fn is_empty(&self) -> bool {
+        self.pre_install.is_empty() && self.post_install.is_empty()
+    }
+}
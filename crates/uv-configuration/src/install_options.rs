@@ -12,6 +12,8 @@ pub struct InstallOptions {
     pub no_install_workspace: bool,
     /// Omit the specified packages from the resolution.
     pub no_install_package: Vec<PackageName>,
+    /// Omit all packages other than the specified package from the resolution.
+    pub only_install_package: Option<PackageName>,
 }
 
 impl InstallOptions {
@@ -19,11 +21,13 @@ impl InstallOptions {
         no_install_project: bool,
         no_install_workspace: bool,
         no_install_package: Vec<PackageName>,
+        only_install_package: Option<PackageName>,
     ) -> Self {
         Self {
             no_install_project,
             no_install_workspace,
             no_install_package,
+            only_install_package,
         }
     }
 
@@ -34,6 +38,14 @@ impl InstallOptions {
         project_name: Option<&PackageName>,
         members: &BTreeSet<PackageName>,
     ) -> bool {
+        // If `--only-package` is provided, remove all other packages.
+        if let Some(only_install_package) = &self.only_install_package {
+            if package != only_install_package {
+                debug!("Omitting `{package}` from resolution due to `--only-package`");
+                return false;
+            }
+        }
+
         // If `--no-install-project` is set, remove the project itself.
         if self.no_install_project {
             if let Some(project_name) = project_name {
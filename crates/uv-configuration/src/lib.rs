@@ -1,21 +1,28 @@
 pub use authentication::*;
 pub use build_options::*;
+pub use byte_size::*;
+pub use compile_bytecode::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use dependency_groups::*;
+pub use download_size::*;
 pub use dry_run::*;
 pub use editable::*;
 pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
+pub use http_connection::*;
+pub use install_hooks::*;
 pub use install_options::*;
 pub use name_specifiers::*;
+pub use no_build_isolation::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use preview::*;
 pub use project_build_backend::*;
 pub use required_version::*;
+pub use retry_policy::*;
 pub use sources::*;
 pub use target_triple::*;
 pub use threading::*;
@@ -25,22 +32,29 @@ pub use vcs::*;
 
 mod authentication;
 mod build_options;
+mod byte_size;
+mod compile_bytecode;
 mod concurrency;
 mod config_settings;
 mod constraints;
 mod dependency_groups;
+mod download_size;
 mod dry_run;
 mod editable;
 mod export_format;
 mod extras;
 mod hash;
+mod http_connection;
+mod install_hooks;
 mod install_options;
 mod name_specifiers;
+mod no_build_isolation;
 mod overrides;
 mod package_options;
 mod preview;
 mod project_build_backend;
 mod required_version;
+mod retry_policy;
 mod sources;
 mod target_triple;
 mod threading;
@@ -0,0 +1,140 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use uv_normalize::PackageName;
+
+/// The packages from `no-build-isolation-package`.
+///
+/// As a list, the packages are built without build isolation, in the environment itself. As a
+/// table, the packages are built in an isolated environment, into which the listed build
+/// requirements are shared from the environment instead of being installed from the index, e.g.,
+/// `{ flash-attn = ["torch"] }` to build `flash-attn` against a locally built `torch`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum NoBuildIsolationPackages {
+    /// Build the packages without build isolation.
+    Packages(Vec<PackageName>),
+    /// Build the packages in isolation, sharing the listed build requirements from the
+    /// environment.
+    Shared(BTreeMap<PackageName, Vec<PackageName>>),
+}
+
+impl Default for NoBuildIsolationPackages {
+    fn default() -> Self {
+        Self::Packages(Vec::new())
+    }
+}
+
+impl From<Vec<PackageName>> for NoBuildIsolationPackages {
+    fn from(packages: Vec<PackageName>) -> Self {
+        Self::Packages(packages)
+    }
+}
+
+impl NoBuildIsolationPackages {
+    /// Returns `true` if all packages are built with build isolation.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Packages(packages) => packages.is_empty(),
+            Self::Shared(shared) => shared.is_empty(),
+        }
+    }
+
+    /// Returns `true` if the package is built without build isolation.
+    pub fn contains(&self, package: &PackageName) -> bool {
+        match self {
+            Self::Packages(packages) => packages.contains(package),
+            Self::Shared(_) => false,
+        }
+    }
+
+    /// The build requirements to share from the environment into the isolated build environment
+    /// of the package.
+    pub fn shared(&self, package: &PackageName) -> &[PackageName] {
+        match self {
+            Self::Packages(_) => &[],
+            Self::Shared(shared) => shared.get(package).map(Vec::as_slice).unwrap_or_default(),
+        }
+    }
+
+    /// Merge the packages with lower precedence packages.
+    ///
+    /// Two lists are extended and two tables are merged, while a list and a table can't be merged,
+    /// in which case `self` takes precedence.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Packages(mut packages), Self::Packages(other)) => {
+                packages.extend(other);
+                Self::Packages(packages)
+            }
+            (Self::Shared(mut shared), Self::Shared(other)) => {
+                for (package, requirements) in other {
+                    shared.entry(package).or_insert(requirements);
+                }
+                Self::Shared(shared)
+            }
+            (packages, _) => packages,
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NoBuildIsolationPackages {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .seq(|seq| seq.deserialize().map(Self::Packages))
+            .map(|map| map.deserialize().map(Self::Shared))
+            .deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for NoBuildIsolationPackages {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NoBuildIsolationPackages")
+    }
+
+    fn json_schema(generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        let package_name = generator.subschema_for::<PackageName>();
+        schemars::json_schema!({
+            "anyOf": [
+                {
+                    "description": "Build the packages without build isolation.",
+                    "type": "array",
+                    "items": package_name
+                },
+                {
+                    "description": "Build the packages in isolation, sharing the listed build requirements from the environment.",
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "array",
+                        "items": package_name
+                    }
+                }
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let name = |name: &str| PackageName::from_str(name).unwrap();
+
+        let packages: NoBuildIsolationPackages = serde_json::from_str(r#"["flash-attn"]"#).unwrap();
+        assert!(packages.contains(&name("flash-attn")));
+        assert!(packages.shared(&name("flash-attn")).is_empty());
+
+        let packages: NoBuildIsolationPackages =
+            serde_json::from_str(r#"{"flash-attn": ["torch"]}"#).unwrap();
+        assert!(!packages.contains(&name("flash-attn")));
+        assert_eq!(packages.shared(&name("flash-attn")), [name("torch")]);
+        assert!(packages.shared(&name("deepspeed")).is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+/// The classes of failures on which HTTP requests are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RetryOn {
+    /// Connection failures, timeouts, and other network errors, along with HTTP 408 responses.
+    Network,
+    /// HTTP 5xx responses.
+    ServerErrors,
+    /// HTTP 429 responses.
+    RateLimits,
+}
+
+impl RetryOn {
+    /// All classes of retryable failures.
+    pub const ALL: [Self; 3] = [Self::Network, Self::ServerErrors, Self::RateLimits];
+}
+
+impl std::fmt::Display for RetryOn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network => f.write_str("network errors"),
+            Self::ServerErrors => f.write_str("server errors"),
+            Self::RateLimits => f.write_str("rate limits"),
+        }
+    }
+}
+
+/// The policy for retrying HTTP requests that failed with a transient error.
+///
+/// Unset fields fall back to the defaults, or, for downloads, to the policy for metadata requests.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RetryPolicyOptions {
+    /// The number of times a failed request is retried.
+    ///
+    /// Defaults to `3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+    /// The delay before the first retry, in seconds, which doubles with each subsequent retry.
+    ///
+    /// Defaults to `1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backoff_base: Option<f64>,
+    /// The maximum delay between retries, in seconds.
+    ///
+    /// Defaults to `1800`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backoff_max: Option<f64>,
+    /// Whether to randomize the delay between retries, to avoid retrying in lockstep with other
+    /// clients.
+    ///
+    /// Defaults to `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jitter: Option<bool>,
+    /// Whether to wait for the delay requested by the server via the `Retry-After` header of an
+    /// HTTP 429 or 503 response, up to `backoff-max`, before retrying.
+    ///
+    /// Defaults to `false`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<bool>,
+    /// The classes of failures on which requests are retried: `network`, for connection failures
+    /// and timeouts, `server-errors`, for HTTP 5xx responses, and `rate-limits`, for HTTP 429
+    /// responses.
+    ///
+    /// Defaults to all classes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<Vec<RetryOn>>,
+}
@@ -756,6 +756,79 @@ impl TargetTriple {
         }
     }
 
+    /// Return the `sysconfig.get_platform()` value for the target, as set through
+    /// `_PYTHON_HOST_PLATFORM` for cross builds, e.g., `manylinux_2_28-aarch64` or
+    /// `macosx-13.0-arm64`.
+    ///
+    /// Build backends derive the platform tag of the wheel from it by replacing `-` and `.` with
+    /// `_`.
+    pub fn host_platform(self) -> String {
+        let platform = self.platform();
+        let arch = platform.arch();
+        match platform.os() {
+            Os::Manylinux { major, minor } => format!("manylinux_{major}_{minor}-{arch}"),
+            Os::Musllinux { major, minor } => format!("musllinux_{major}_{minor}-{arch}"),
+            Os::Macos { major, minor } => {
+                let arch = if arch == Arch::Aarch64 {
+                    "arm64"
+                } else {
+                    arch.name()
+                };
+                format!("macosx-{major}.{minor}-{arch}")
+            }
+            Os::Windows => match arch {
+                Arch::X86 => "win32".to_string(),
+                Arch::Aarch64 => "win-arm64".to_string(),
+                _ => "win-amd64".to_string(),
+            },
+            Os::Pyodide { major, minor } => format!("pyodide_{major}_{minor}-{arch}"),
+            os => format!("{os}-{arch}"),
+        }
+    }
+
+    /// Return the Rust target triple to compile extension modules for the target, e.g.,
+    /// `aarch64-unknown-linux-gnu` for `aarch64-manylinux_2_28`.
+    pub fn rust_target(self) -> &'static str {
+        match self {
+            Self::Windows | Self::X8664PcWindowsMsvc => "x86_64-pc-windows-msvc",
+            Self::I686PcWindowsMsvc => "i686-pc-windows-msvc",
+            Self::Macos | Self::Aarch64AppleDarwin => "aarch64-apple-darwin",
+            Self::X8664AppleDarwin => "x86_64-apple-darwin",
+            Self::Aarch64UnknownLinuxMusl => "aarch64-unknown-linux-musl",
+            Self::X8664UnknownLinuxMusl => "x86_64-unknown-linux-musl",
+            Self::Wasm32Pyodide2024 => "wasm32-unknown-emscripten",
+            Self::Linux
+            | Self::X8664UnknownLinuxGnu
+            | Self::X8664Manylinux2014
+            | Self::X8664Manylinux217
+            | Self::X8664Manylinux228
+            | Self::X8664Manylinux231
+            | Self::X8664Manylinux232
+            | Self::X8664Manylinux233
+            | Self::X8664Manylinux234
+            | Self::X8664Manylinux235
+            | Self::X8664Manylinux236
+            | Self::X8664Manylinux237
+            | Self::X8664Manylinux238
+            | Self::X8664Manylinux239
+            | Self::X8664Manylinux240 => "x86_64-unknown-linux-gnu",
+            Self::Aarch64UnknownLinuxGnu
+            | Self::Aarch64Manylinux2014
+            | Self::Aarch64Manylinux217
+            | Self::Aarch64Manylinux228
+            | Self::Aarch64Manylinux231
+            | Self::Aarch64Manylinux232
+            | Self::Aarch64Manylinux233
+            | Self::Aarch64Manylinux234
+            | Self::Aarch64Manylinux235
+            | Self::Aarch64Manylinux236
+            | Self::Aarch64Manylinux237
+            | Self::Aarch64Manylinux238
+            | Self::Aarch64Manylinux239
+            | Self::Aarch64Manylinux240 => "aarch64-unknown-linux-gnu",
+        }
+    }
+
     /// Return a [`MarkerEnvironment`] compatible with the given [`TargetTriple`], based on
     /// a base [`MarkerEnvironment`].
     ///
@@ -785,3 +858,29 @@ fn macos_deployment_target() -> Option<(u16, u16)> {
 
     Some((major, minor))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_platform() {
+        assert_eq!(
+            TargetTriple::Aarch64UnknownLinuxGnu.host_platform(),
+            "manylinux_2_28-aarch64"
+        );
+        assert_eq!(
+            TargetTriple::X8664UnknownLinuxMusl.host_platform(),
+            "musllinux_1_2-x86_64"
+        );
+        assert_eq!(TargetTriple::I686PcWindowsMsvc.host_platform(), "win32");
+        assert_eq!(
+            TargetTriple::Wasm32Pyodide2024.host_platform(),
+            "pyodide_2024_0-wasm32"
+        );
+        assert_eq!(
+            TargetTriple::Aarch64Manylinux2014.rust_target(),
+            "aarch64-unknown-linux-gnu"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use clap::Parser;
@@ -37,6 +38,8 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         &interpreter,
         &Concurrency::default(),
         cache.root(),
+        None,
+        &HashSet::default(),
     )
     .await?;
     info!("Compiled {files} files");
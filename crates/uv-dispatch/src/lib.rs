@@ -25,10 +25,11 @@ use uv_distribution::DistributionDatabase;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
     CachedDist, DependencyMetadata, Identifier, IndexCapabilities, IndexLocations,
-    IsBuildBackendError, Name, Requirement, Resolution, SourceDist, VersionOrUrlRef,
+    IsBuildBackendError, Name, Requirement, Resolution, SourceDist, SourcePatches, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_installer::{Installer, Plan, Planner, Preparer, SitePackages};
+use uv_normalize::PackageName;
 use uv_pypi_types::Conflicts;
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_resolver::{
@@ -81,6 +82,8 @@ pub struct BuildDispatch<'a> {
     client: &'a RegistryClient,
     cache: &'a Cache,
     constraints: Constraints,
+    constraints_package: FxHashMap<PackageName, Constraints>,
+    patches: SourcePatches,
     interpreter: &'a Interpreter,
     index_locations: &'a IndexLocations,
     index_strategy: IndexStrategy,
@@ -129,6 +132,8 @@ impl<'a> BuildDispatch<'a> {
             client,
             cache,
             constraints,
+            constraints_package: FxHashMap::default(),
+            patches: SourcePatches::default(),
             interpreter,
             index_locations,
             flat_index,
@@ -151,6 +156,42 @@ impl<'a> BuildDispatch<'a> {
         }
     }
 
+    /// Share the resolved build requirements with other dispatches, e.g., to reuse them across
+    /// the builds of workspace members.
+    ///
+    /// The dispatches must use the same interpreter.
+    #[must_use]
+    pub fn with_source_build_context(mut self, source_build_context: SourceBuildContext) -> Self {
+        self.source_build_context = source_build_context;
+        self
+    }
+
+    /// Apply additional build constraints when resolving the build requirements of specific
+    /// packages, on top of the global build constraints.
+    #[must_use]
+    pub fn with_build_constraints_package(
+        mut self,
+        constraints_package: impl IntoIterator<Item = (PackageName, Vec<Requirement>)>,
+    ) -> Self {
+        self.constraints_package = constraints_package
+            .into_iter()
+            .map(|(package, requirements)| {
+                let constraints = Constraints::from_requirements(
+                    self.constraints.requirements().cloned().chain(requirements),
+                );
+                (package, constraints)
+            })
+            .collect();
+        self
+    }
+
+    /// Set the patches to apply to source distributions before building them.
+    #[must_use]
+    pub fn with_patches(mut self, patches: SourcePatches) -> Self {
+        self.patches = patches;
+        self
+    }
+
     /// Set the environment variables to be used when building a source distribution.
     #[must_use]
     pub fn with_build_extra_env_vars<I, K, V>(mut self, sdist_build_env_variables: I) -> Self
@@ -207,6 +248,18 @@ impl BuildContext for BuildDispatch<'_> {
         self.config_settings_package
     }
 
+    fn build_constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    fn build_constraints_package(&self, package: &PackageName) -> Option<&Constraints> {
+        self.constraints_package.get(package)
+    }
+
+    fn patches(&self) -> &SourcePatches {
+        &self.patches
+    }
+
     fn sources(&self) -> SourceStrategy {
         self.sources
     }
@@ -222,14 +275,18 @@ impl BuildContext for BuildDispatch<'_> {
     async fn resolve<'data>(
         &'data self,
         requirements: &'data [Requirement],
+        package: Option<&'data PackageName>,
         build_stack: &'data BuildStack,
     ) -> Result<Resolution, BuildDispatchError> {
         let python_requirement = PythonRequirement::from_interpreter(self.interpreter);
         let marker_env = self.interpreter.resolver_marker_environment();
         let tags = self.interpreter.tags()?;
+        let constraints = package
+            .and_then(|package| self.build_constraints_package(package))
+            .unwrap_or(&self.constraints);
 
         let resolver = Resolver::new(
-            Manifest::simple(requirements.to_vec()).with_constraints(self.constraints.clone()),
+            Manifest::simple(requirements.to_vec()).with_constraints(constraints.clone()),
             OptionsBuilder::new()
                 .exclude_newer(self.exclude_newer)
                 .index_strategy(self.index_strategy)
@@ -247,7 +304,7 @@ impl BuildContext for BuildDispatch<'_> {
             self.hasher,
             self,
             EmptyInstalledPackages,
-            DistributionDatabase::new(self.client, self, self.concurrency.downloads)
+            DistributionDatabase::new(self.client, self, self.concurrency)
                 .with_build_stack(build_stack),
         )?;
         let resolution = Resolution::from(resolver.resolve().await.with_context(|| {
@@ -333,7 +390,7 @@ impl BuildContext for BuildDispatch<'_> {
                 tags,
                 self.hasher,
                 self.build_options,
-                DistributionDatabase::new(self.client, self, self.concurrency.downloads)
+                DistributionDatabase::new(self.client, self, self.concurrency)
                     .with_build_stack(build_stack),
             );
 
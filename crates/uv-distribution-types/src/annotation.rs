@@ -1,51 +1,57 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashMap;
 
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_pep508::RequirementOrigin;
 
 /// Source of a dependency, e.g., a `-r requirements.txt` file.
+///
+/// Each source includes the line within the file at which the dependency was declared, if known.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SourceAnnotation {
     /// A `-c constraints.txt` file.
-    Constraint(RequirementOrigin),
+    Constraint(RequirementOrigin, Option<usize>),
     /// An `--override overrides.txt` file.
-    Override(RequirementOrigin),
+    Override(RequirementOrigin, Option<usize>),
     /// A `-r requirements.txt` file.
-    Requirement(RequirementOrigin),
+    Requirement(RequirementOrigin, Option<usize>),
 }
 
 impl std::fmt::Display for SourceAnnotation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Requirement(origin) => match origin {
+            Self::Requirement(origin, line) => match origin {
                 RequirementOrigin::File(path) => {
-                    write!(f, "-r {}", path.portable_display())
+                    write!(f, "-r {}", Location(path, *line))
                 }
                 RequirementOrigin::Project(path, project_name) => {
-                    write!(f, "{project_name} ({})", path.portable_display())
+                    write!(f, "{project_name} ({})", Location(path, *line))
                 }
                 RequirementOrigin::Group(path, project_name, group) => {
                     if let Some(project_name) = project_name {
-                        write!(f, "{project_name} ({}:{group})", path.portable_display())
+                        write!(f, "{project_name} ({}:{group})", Location(path, *line))
                     } else {
-                        write!(f, "({}:{group})", path.portable_display())
+                        write!(f, "({}:{group})", Location(path, *line))
                     }
                 }
                 RequirementOrigin::Workspace => {
                     write!(f, "(workspace)")
                 }
             },
-            Self::Constraint(origin) => {
-                write!(f, "-c {}", origin.path().portable_display())
+            Self::Constraint(origin, line) => {
+                write!(f, "-c {}", Location(origin.path(), *line))
             }
-            Self::Override(origin) => match origin {
+            Self::Override(origin, line) => match origin {
                 RequirementOrigin::File(path) => {
-                    write!(f, "--override {}", path.portable_display())
+                    write!(f, "--override {}", Location(path, *line))
                 }
                 RequirementOrigin::Project(path, project_name) => {
                     // Project is not used for override
-                    write!(f, "--override {project_name} ({})", path.portable_display())
+                    write!(f, "--override {project_name} ({})", Location(path, *line))
                 }
                 RequirementOrigin::Group(path, project_name, group) => {
                     // Group is not used for override
@@ -53,10 +59,10 @@ impl std::fmt::Display for SourceAnnotation {
                         write!(
                             f,
                             "--override {project_name} ({}:{group})",
-                            path.portable_display()
+                            Location(path, *line)
                         )
                     } else {
-                        write!(f, "--override ({}:{group})", path.portable_display())
+                        write!(f, "--override ({}:{group})", Location(path, *line))
                     }
                 }
                 RequirementOrigin::Workspace => {
@@ -67,6 +73,20 @@ impl std::fmt::Display for SourceAnnotation {
     }
 }
 
+/// A path to a source file, followed by a line within the file, if known (e.g.,
+/// `requirements.in:3`).
+struct Location<'a>(&'a Path, Option<usize>);
+
+impl std::fmt::Display for Location<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.portable_display())?;
+        if let Some(line) = self.1 {
+            write!(f, ":{line}")?;
+        }
+        Ok(())
+    }
+}
+
 /// A collection of source annotations.
 #[derive(Default, Debug, Clone)]
 pub struct SourceAnnotations(BTreeMap<PackageName, BTreeSet<SourceAnnotation>>);
@@ -85,3 +105,53 @@ impl SourceAnnotations {
         self.0.get(package)
     }
 }
+
+/// The lines at which each package is declared in a set of source files (e.g., a
+/// `requirements.in` or `pyproject.toml` file), used to annotate each source with its line.
+#[derive(Default, Debug, Clone)]
+pub struct SourceLines(FxHashMap<PathBuf, FxHashMap<PackageName, usize>>);
+
+impl SourceLines {
+    /// Record the lines at which packages are declared in the given source file.
+    ///
+    /// For `.toml` files, packages are read from the quoted strings on each line (e.g.,
+    /// `"anyio>=4"`); for all other files, from the start of each line, as in a `requirements.txt`
+    /// file. If a package is declared more than once, the first declaration wins.
+    pub fn insert(&mut self, path: PathBuf, contents: &str) {
+        let is_toml = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+        let lines = self.0.entry(path).or_default();
+        for (index, line) in contents.lines().enumerate() {
+            if is_toml {
+                for candidate in line.split(['"', '\'']).skip(1).step_by(2) {
+                    if let Some(name) = leading_package_name(candidate) {
+                        lines.entry(name).or_insert(index + 1);
+                    }
+                }
+            } else {
+                let line = line.trim_start();
+                if line.starts_with('#') || line.starts_with('-') {
+                    continue;
+                }
+                if let Some(name) = leading_package_name(line) {
+                    lines.entry(name).or_insert(index + 1);
+                }
+            }
+        }
+    }
+
+    /// Return the line at which the package is declared in the given source file, if known.
+    pub fn get(&self, path: &Path, package: &PackageName) -> Option<usize> {
+        self.0.get(path)?.get(package).copied()
+    }
+}
+
+/// Parse the package name at the start of a PEP 508 requirement (e.g., `anyio` in
+/// `anyio[trio]>=4`).
+fn leading_package_name(requirement: &str) -> Option<PackageName> {
+    let end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    PackageName::from_str(&requirement[..end]).ok()
+}
@@ -1,16 +1,20 @@
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use uv_auth::{AuthPolicy, Credentials};
+use uv_auth::{AuthPolicy, AzureIdentity, Credentials, TokenExchange};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
-use crate::{IndexStatusCodeStrategy, IndexUrl, IndexUrlError, SerializableStatusCode};
+use crate::{
+    IndexStatusCodeStrategy, IndexUrl, IndexUrlError, ProxyUrl, ResolveTarget,
+    SerializableStatusCode,
+};
 
 /// Cache control configuration for an index.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Default)]
@@ -129,6 +133,246 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub cache_control: Option<IndexCacheControl>,
+    /// Mark the index as a fallback for another index, by name.
+    ///
+    /// Fallback indexes are not searched on their own. Instead, when the named index is
+    /// unavailable (e.g., due to a timeout, a connection failure, or a server error), requests are
+    /// retried against its fallbacks, in the order in which they're defined:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "primary"
+    /// url = "https://<omitted>/simple"
+    ///
+    /// [[tool.uv.index]]
+    /// name = "mirror"
+    /// url = "https://<omitted>/simple"
+    /// fallback-of = "primary"
+    /// ```
+    #[serde(default)]
+    pub fallback_of: Option<IndexName>,
+    /// The path to a bundle of certificate authorities to trust when connecting to the index, in
+    /// PEM format.
+    ///
+    /// The certificates are trusted in addition to the system (or built-in) trust store, and only
+    /// for connections to the index's host; other hosts (e.g., PyPI) are unaffected.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// ssl-ca-cert = "certs/internal-ca.pem"
+    /// ```
+    #[serde(default)]
+    pub ssl_ca_cert: Option<PathBuf>,
+    /// The path to a client certificate to present when connecting to the index, in PEM format,
+    /// for mTLS authentication.
+    ///
+    /// If `ssl-client-key` is not provided, the file must contain both the certificate and the
+    /// private key. Takes precedence over `SSL_CLIENT_CERT` for connections to the index's host.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// ssl-client-cert = "certs/client.pem"
+    /// ssl-client-key = "certs/client.key"
+    /// ```
+    #[serde(default)]
+    pub ssl_client_cert: Option<PathBuf>,
+    /// The path to the private key for `ssl-client-cert`, in PEM format.
+    #[serde(default)]
+    pub ssl_client_key: Option<PathBuf>,
+    /// The proxy to use for requests to the index's host.
+    ///
+    /// Supports `http`, `https`, `socks5`, and `socks5h` proxies. Takes precedence over the global
+    /// `http-proxy` and `https-proxy` settings, along with the `HTTP_PROXY`, `HTTPS_PROXY`, and
+    /// `ALL_PROXY` environment variables.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// proxy = "socks5h://localhost:1080"
+    /// ```
+    #[serde(default)]
+    pub proxy: Option<ProxyUrl>,
+    /// The IP address or host name to connect to in lieu of resolving the index's host via DNS.
+    ///
+    /// Requests are still sent to the index's host name, such that the `Host` header and the TLS
+    /// server name (SNI) are preserved; only the address to which uv connects is overridden.
+    /// Useful in split-horizon DNS environments, in which the index's public host name can't be
+    /// resolved (e.g., from within a Kubernetes cluster).
+    ///
+    /// Applies to any request to the index's host, including requests for artifacts hosted
+    /// alongside the index.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.example.com/simple"
+    /// resolve = "pypi.internal.svc.cluster.local"
+    /// ```
+    #[serde(default)]
+    pub resolve: Option<ResolveTarget>,
+    /// The format in which to request the Simple API from the index: `html` or `json`.
+    ///
+    /// By default, uv requests the JSON format ([PEP 691](https://peps.python.org/pep-0691/)),
+    /// falling back to HTML for indexes that don't support it. Forcing a format is useful for
+    /// indexes that respond to JSON requests with a nonconformant response.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// simple-api = "html"
+    /// ```
+    #[serde(default)]
+    pub simple_api: Option<SimpleApiFormat>,
+    /// Whether to fetch the metadata files ([PEP 658](https://peps.python.org/pep-0658/))
+    /// advertised by the index.
+    ///
+    /// When disabled, uv reads the metadata from the wheels themselves, as for indexes that don't
+    /// advertise metadata files. Useful for indexes that advertise metadata files that they fail to
+    /// serve.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// core-metadata = false
+    /// ```
+    #[serde(default)]
+    pub core_metadata: Option<bool>,
+    /// Work around known deviations from the standards in the index server's responses and
+    /// upload API: `artifactory`, `nexus`, `devpi`, `gitlab`, `github`, or `codeartifact`.
+    ///
+    /// - `artifactory`: Ignore the metadata files advertised by the index, which may fail to be
+    ///   served for packages proxied from a remote repository.
+    /// - `nexus`: Request the HTML Simple API, and avoid HTTP range requests, which are ignored for
+    ///   packages proxied from a remote repository.
+    /// - `devpi`: Request the HTML Simple API, since devpi responds to JSON requests with its own
+    ///   API, rather than the JSON Simple API.
+    /// - `gitlab`: The GitLab package registry. In GitLab CI, `uv publish` authenticates with the
+    ///   `CI_JOB_TOKEN` of the job.
+    /// - `github`: A proxy serving Python packages from GitHub Packages. In GitHub Actions,
+    ///   `uv publish` authenticates with the `GITHUB_TOKEN` of the workflow.
+    /// - `codeartifact`: AWS CodeArtifact, for which `uv publish` relies on the automatic
+    ///   authentication, rather than prompting for credentials.
+    ///
+    /// For all of them, `uv publish` derives the upload URL from the index URL if `publish-url` is
+    /// not set, and omits the upload fields that the server rejects, such as attestations.
+    ///
+    /// The `simple-api` and `core-metadata` settings take precedence over the quirks mode.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://devpi.example.com/root/pypi/+simple"
+    /// quirks = "devpi"
+    /// ```
+    #[serde(default)]
+    pub quirks: Option<IndexQuirks>,
+    /// Refuse to install any distribution from the index, unless the index declares a hash for
+    /// the distribution, and the downloaded distribution matches it.
+    ///
+    /// Applies independently of hash-checking against the lockfile (or `--require-hashes`), such
+    /// that distributions are verified even when resolving for the first time. Protects against
+    /// tampered mirrors that serve modified distributions, or that omit the hashes altogether.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "mirror"
+    /// url = "https://mirror.example.com/simple"
+    /// require-hashes-from-index = true
+    /// ```
+    #[serde(default)]
+    pub require_hashes_from_index: bool,
+    /// The time for which to cache the absence of a package from the index, in seconds.
+    ///
+    /// By default, a package that the index doesn't serve (i.e., for which it returns a `404 Not
+    /// Found`) is looked up again on every resolution. When set, the absence is cached for the
+    /// given duration, such that resolutions that search many indexes for packages that only
+    /// exist on some of them (e.g., internal packages, with `index-strategy = "unsafe-best-match"`)
+    /// avoid repeating the same requests. `--refresh` bypasses the cached result.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// not-found-cache-ttl = 600
+    /// ```
+    #[serde(default)]
+    pub not_found_cache_ttl: Option<u64>,
+    /// The identity with which to acquire Microsoft Entra ID tokens for an Azure Artifacts feed.
+    ///
+    /// When set, uv acquires a token for Azure DevOps with the given flow, and refreshes it as it
+    /// nears expiry, such that long-running operations don't fail partway through:
+    ///
+    /// - `managed-identity`: the managed identity of the Azure resource on which uv is running.
+    /// - `service-principal`: the service principal configured by the `AZURE_TENANT_ID`,
+    ///   `AZURE_CLIENT_ID`, and `AZURE_CLIENT_SECRET` environment variables.
+    /// - `device-code`: an interactive sign-in, by entering a code in a browser.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "azure"
+    /// url = "https://pkgs.dev.azure.com/<ORGANIZATION>/<PROJECT>/_packaging/<FEED>/pypi/simple/"
+    /// azure-identity = "managed-identity"
+    /// ```
+    #[serde(default)]
+    pub azure_identity: Option<AzureIdentity>,
+    /// The command of a credential helper from which to fetch credentials for the index.
+    ///
+    /// uv runs the command with a `get` argument, writes `url=<index URL>` and a blank line to
+    /// its standard input, and reads `key=value` lines from its standard output: `username` and
+    /// `password` for "Basic" authentication, or `token` for "Bearer" authentication, along with
+    /// an optional `expiry` (in seconds since the Unix epoch), after which the helper is run again.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// credential-helper = "my-sso-helper"
+    /// ```
+    #[serde(default)]
+    pub credential_helper: Option<String>,
+    /// Additional headers to send with each request to the index.
+    ///
+    /// Useful for indexes (or proxies in front of them) that authenticate via headers other than
+    /// `Authorization`. Values may reference environment variables as `${VAR}`, such that secrets
+    /// needn't be stored in configuration files; if a referenced variable is unset, the header is
+    /// omitted.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "artifactory"
+    /// url = "https://example.jfrog.io/artifactory/api/pypi/pypi/simple"
+    /// headers = { X-JFrog-Art-Api = "${ARTIFACTORY_API_KEY}" }
+    /// ```
+    #[serde(default)]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// An endpoint at which to exchange the OIDC identity token of a CI job for a short-lived
+    /// index token, as for trusted publishing, such that CI jobs don't require long-lived
+    /// credentials for the index.
+    ///
+    /// uv requests an OIDC token for the `audience` (by default, the audience returned by
+    /// `/_/oidc/audience` on the endpoint's host), and posts it to the `url` as
+    /// `{"token": "<OIDC token>"}`. The endpoint responds with `{"token": "<index token>"}`, and
+    /// optionally an `expires` timestamp and a `username`. Currently, OIDC tokens are only
+    /// available in GitHub Actions, with the `id-token: write` permission; elsewhere, the setting
+    /// is ignored.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// token-exchange = { url = "https://internal.example.com/_/oidc/mint-token", audience = "internal" }
+    /// ```
+    #[serde(default)]
+    pub token_exchange: Option<TokenExchange>,
 }
 
 #[derive(
@@ -154,6 +398,120 @@ pub enum IndexFormat {
     Flat,
 }
 
+/// The format in which to request the Simple API from an index.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SimpleApiFormat {
+    /// The HTML format ([PEP 503](https://peps.python.org/pep-0503/)).
+    Html,
+    /// The JSON format ([PEP 691](https://peps.python.org/pep-0691/)).
+    Json,
+}
+
+/// A mode for working around the nonconformant responses of a specific index server.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexQuirks {
+    /// JFrog Artifactory, which may advertise metadata files that it fails to serve.
+    Artifactory,
+    /// Sonatype Nexus Repository, which doesn't support the JSON Simple API, and ignores HTTP
+    /// range requests for proxied packages.
+    Nexus,
+    /// devpi, which responds to JSON requests with its own API.
+    Devpi,
+    /// The GitLab package registry.
+    #[serde(rename = "gitlab")]
+    GitLab,
+    /// A proxy serving Python packages from GitHub Packages, which doesn't support Python
+    /// packages natively.
+    #[serde(rename = "github")]
+    GitHub,
+    /// AWS CodeArtifact.
+    #[serde(rename = "codeartifact")]
+    CodeArtifact,
+}
+
+impl IndexQuirks {
+    /// The format in which to request the Simple API, if it must be forced.
+    fn simple_api(self) -> Option<SimpleApiFormat> {
+        match self {
+            Self::Nexus | Self::Devpi => Some(SimpleApiFormat::Html),
+            Self::Artifactory | Self::GitLab | Self::GitHub | Self::CodeArtifact => None,
+        }
+    }
+
+    /// Whether the metadata files advertised by the index can be relied upon.
+    fn core_metadata(self) -> bool {
+        match self {
+            Self::Artifactory => false,
+            Self::Nexus | Self::Devpi | Self::GitLab | Self::GitHub | Self::CodeArtifact => true,
+        }
+    }
+
+    /// Whether the index supports HTTP range requests.
+    fn range_requests(self) -> bool {
+        match self {
+            Self::Nexus => false,
+            Self::Artifactory | Self::Devpi | Self::GitLab | Self::GitHub | Self::CodeArtifact => {
+                true
+            }
+        }
+    }
+
+    /// Derive the upload URL from the URL of the Simple API, by removing the trailing `simple`
+    /// path segment (or `+simple`, for devpi).
+    ///
+    /// For example, the GitLab index
+    /// `https://gitlab.example.com/api/v4/projects/<PROJECT_ID>/packages/pypi/simple` accepts
+    /// uploads at `https://gitlab.example.com/api/v4/projects/<PROJECT_ID>/packages/pypi`.
+    ///
+    /// Returns `None` if the URL doesn't end with the expected segment.
+    pub fn publish_url(self, url: &DisplaySafeUrl) -> Option<DisplaySafeUrl> {
+        let suffix = match self {
+            Self::Devpi => "+simple",
+            Self::Artifactory | Self::Nexus | Self::GitLab | Self::GitHub | Self::CodeArtifact => {
+                "simple"
+            }
+        };
+        let path = url.path().trim_end_matches('/');
+        let base = path.strip_suffix(suffix)?;
+        if !base.ends_with('/') {
+            return None;
+        }
+        let mut publish_url = url.clone();
+        match self {
+            // GitLab and Artifactory expect the upload URL without a trailing slash.
+            Self::GitLab | Self::Artifactory => {
+                publish_url.set_path(base.trim_end_matches('/'));
+            }
+            Self::Nexus | Self::Devpi | Self::GitHub | Self::CodeArtifact => {
+                publish_url.set_path(base);
+            }
+        }
+        publish_url.set_query(None);
+        Some(publish_url)
+    }
+
+    /// The upload fields that the server rejects, and which are therefore omitted.
+    ///
+    /// None of the servers support attestations ([PEP 740](https://peps.python.org/pep-0740/)),
+    /// and Nexus and Artifactory validate the upload against older versions of the core metadata
+    /// specification, rejecting the fields introduced by Metadata 2.2 and 2.4.
+    pub fn omitted_upload_fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Artifactory | Self::Nexus => &[
+                "attestations",
+                "dynamic",
+                "license_expression",
+                "license_file",
+            ],
+            Self::Devpi | Self::GitLab | Self::GitHub | Self::CodeArtifact => &["attestations"],
+        }
+    }
+}
+
 impl Index {
     /// Initialize an [`Index`] from a pip-style `--index-url`.
     pub fn from_index_url(url: IndexUrl) -> Self {
@@ -168,6 +526,21 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            fallback_of: None,
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            proxy: None,
+            resolve: None,
+            simple_api: None,
+            core_metadata: None,
+            quirks: None,
+            require_hashes_from_index: false,
+            not_found_cache_ttl: None,
+            azure_identity: None,
+            credential_helper: None,
+            headers: None,
+            token_exchange: None,
         }
     }
 
@@ -184,6 +557,21 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            fallback_of: None,
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            proxy: None,
+            resolve: None,
+            simple_api: None,
+            core_metadata: None,
+            quirks: None,
+            require_hashes_from_index: false,
+            not_found_cache_ttl: None,
+            azure_identity: None,
+            credential_helper: None,
+            headers: None,
+            token_exchange: None,
         }
     }
 
@@ -200,6 +588,21 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            fallback_of: None,
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            proxy: None,
+            resolve: None,
+            simple_api: None,
+            core_metadata: None,
+            quirks: None,
+            require_hashes_from_index: false,
+            not_found_cache_ttl: None,
+            azure_identity: None,
+            credential_helper: None,
+            headers: None,
+            token_exchange: None,
         }
     }
 
@@ -233,6 +636,23 @@ impl Index {
         self.url.root()
     }
 
+    /// Return the format in which to request the Simple API from the index, if it must be forced.
+    pub fn simple_api_format(&self) -> Option<SimpleApiFormat> {
+        self.simple_api
+            .or_else(|| self.quirks.and_then(IndexQuirks::simple_api))
+    }
+
+    /// Returns `true` if the metadata files advertised by the index should be used.
+    pub fn core_metadata(&self) -> bool {
+        self.core_metadata
+            .unwrap_or_else(|| self.quirks.is_none_or(IndexQuirks::core_metadata))
+    }
+
+    /// Returns `true` if HTTP range requests should be attempted against the index.
+    pub fn range_requests(&self) -> bool {
+        self.quirks.is_none_or(IndexQuirks::range_requests)
+    }
+
     /// Retrieve the credentials for the index, either from the environment, or from the URL itself.
     pub fn credentials(&self) -> Option<Credentials> {
         // If the index is named, and credentials are provided via the environment, prefer those.
@@ -247,15 +667,32 @@ impl Index {
     }
 
     /// Resolve the index relative to the given root directory.
+    ///
+    /// Relative paths to TLS certificates and keys are also resolved against the root directory.
     pub fn relative_to(mut self, root_dir: &Path) -> Result<Self, IndexUrlError> {
         if let IndexUrl::Path(ref url) = self.url {
             if let Some(given) = url.given() {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        for path in [
+            &mut self.ssl_ca_cert,
+            &mut self.ssl_client_cert,
+            &mut self.ssl_client_key,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = root_dir.join(&*path);
+        }
         Ok(self)
     }
 
+    /// Returns `true` if the index defines any TLS settings.
+    pub fn has_tls_settings(&self) -> bool {
+        self.ssl_ca_cert.is_some() || self.ssl_client_cert.is_some()
+    }
+
     /// Return the [`IndexStatusCodeStrategy`] for this index.
     pub fn status_code_strategy(&self) -> IndexStatusCodeStrategy {
         if let Some(ignore_error_codes) = &self.ignore_error_codes {
@@ -279,6 +716,21 @@ impl From<IndexUrl> for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            fallback_of: None,
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            proxy: None,
+            resolve: None,
+            simple_api: None,
+            core_metadata: None,
+            quirks: None,
+            require_hashes_from_index: false,
+            not_found_cache_ttl: None,
+            azure_identity: None,
+            credential_helper: None,
+            headers: None,
+            token_exchange: None,
         }
     }
 }
@@ -303,6 +755,21 @@ impl FromStr for Index {
                     authenticate: AuthPolicy::default(),
                     ignore_error_codes: None,
                     cache_control: None,
+                    fallback_of: None,
+                    ssl_ca_cert: None,
+                    ssl_client_cert: None,
+                    ssl_client_key: None,
+                    proxy: None,
+                    resolve: None,
+                    simple_api: None,
+                    core_metadata: None,
+                    quirks: None,
+                    require_hashes_from_index: false,
+                    not_found_cache_ttl: None,
+                    azure_identity: None,
+                    credential_helper: None,
+                    headers: None,
+                    token_exchange: None,
                 });
             }
         }
@@ -320,6 +787,21 @@ impl FromStr for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            fallback_of: None,
+            ssl_ca_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+            proxy: None,
+            resolve: None,
+            simple_api: None,
+            core_metadata: None,
+            quirks: None,
+            require_hashes_from_index: false,
+            not_found_cache_ttl: None,
+            azure_identity: None,
+            credential_helper: None,
+            headers: None,
+            token_exchange: None,
         })
     }
 }
@@ -466,4 +948,83 @@ mod tests {
         assert_eq!(cache_control.api.as_deref(), Some("max-age=300"));
         assert_eq!(cache_control.files, None);
     }
+
+    #[test]
+    fn test_index_tls_settings() {
+        // Test that TLS paths are resolved relative to the configuration file
+        let toml_str = r#"
+            name = "test-index"
+            url = "https://test.example.com/simple"
+            ssl-ca-cert = "certs/ca.pem"
+            ssl-client-cert = "/etc/certs/client.pem"
+        "#;
+
+        let index: Index = toml::from_str(toml_str).unwrap();
+        assert!(index.has_tls_settings());
+
+        let root_dir = Path::new("/project");
+        let index = index.relative_to(root_dir).unwrap();
+        assert_eq!(
+            index.ssl_ca_cert.as_deref(),
+            Some(root_dir.join("certs/ca.pem").as_path())
+        );
+        assert_eq!(
+            index.ssl_client_cert.as_deref(),
+            Some(Path::new("/etc/certs/client.pem"))
+        );
+        assert_eq!(index.ssl_client_key, None);
+    }
+
+    #[test]
+    fn test_quirks_publish_url() {
+        let publish_url = |quirks: IndexQuirks, url: &str| {
+            quirks
+                .publish_url(&DisplaySafeUrl::parse(url).unwrap())
+                .map(|url| url.to_string())
+        };
+        assert_eq!(
+            publish_url(
+                IndexQuirks::GitLab,
+                "https://gitlab.example.com/api/v4/projects/42/packages/pypi/simple"
+            )
+            .as_deref(),
+            Some("https://gitlab.example.com/api/v4/projects/42/packages/pypi")
+        );
+        assert_eq!(
+            publish_url(
+                IndexQuirks::Artifactory,
+                "https://example.jfrog.io/artifactory/api/pypi/pypi-local/simple/"
+            )
+            .as_deref(),
+            Some("https://example.jfrog.io/artifactory/api/pypi/pypi-local")
+        );
+        assert_eq!(
+            publish_url(
+                IndexQuirks::Nexus,
+                "https://nexus.example.com/repository/pypi-internal/simple"
+            )
+            .as_deref(),
+            Some("https://nexus.example.com/repository/pypi-internal/")
+        );
+        assert_eq!(
+            publish_url(
+                IndexQuirks::CodeArtifact,
+                "https://domain-123456789012.d.codeartifact.us-east-1.amazonaws.com/pypi/repo/simple/"
+            )
+            .as_deref(),
+            Some("https://domain-123456789012.d.codeartifact.us-east-1.amazonaws.com/pypi/repo/")
+        );
+        assert_eq!(
+            publish_url(
+                IndexQuirks::Devpi,
+                "https://devpi.example.com/root/dev/+simple/"
+            )
+            .as_deref(),
+            Some("https://devpi.example.com/root/dev/")
+        );
+        assert_eq!(
+            publish_url(IndexQuirks::GitHub, "https://pypi.example.com/notsimple").as_deref(),
+            None
+        );
+    }
 }
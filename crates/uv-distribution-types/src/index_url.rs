@@ -1,9 +1,11 @@
 use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
 
 use itertools::Either;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -14,7 +16,7 @@ use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
-use crate::{Index, IndexStatusCodeStrategy, Verbatim};
+use crate::{Index, IndexStatusCodeStrategy, SimpleApiFormat, Verbatim};
 
 static PYPI_URL: LazyLock<DisplaySafeUrl> =
     LazyLock::new(|| DisplaySafeUrl::parse("https://pypi.org/simple").unwrap());
@@ -48,6 +50,12 @@ impl IndexUrl {
     /// For indexes with a `/simple` endpoint, this is simply the URL with the final segment
     /// removed. This is useful, e.g., for credential propagation to other endpoints on the index.
     pub fn root(&self) -> Option<DisplaySafeUrl> {
+        // For OCI registries, credentials apply to the entire registry, including its token
+        // endpoint.
+        if self.url().scheme() == "oci" {
+            return self.oci_registry();
+        }
+
         let mut segments = self.url().path_segments()?;
         let last = match segments.next_back()? {
             // If the last segment is empty due to a trailing `/`, skip it (as in `pop_if_empty`)
@@ -99,6 +107,42 @@ impl IndexUrl {
         }
     }
 
+    /// Return the base URL of the registry's HTTP API, if the index is an OCI registry (i.e., uses
+    /// the `oci://` scheme, as in `oci://ghcr.io/org/pypi`).
+    ///
+    /// Registries are accessed over HTTPS, except for registries on the loopback interface, which
+    /// are accessed over HTTP.
+    pub fn oci_registry(&self) -> Option<DisplaySafeUrl> {
+        let url = self.url();
+        if url.scheme() != "oci" {
+            return None;
+        }
+        let scheme = match url.host()? {
+            url::Host::Domain(domain) if domain == "localhost" => "http",
+            url::Host::Ipv4(ip) if ip.is_loopback() => "http",
+            url::Host::Ipv6(ip) if ip.is_loopback() => "http",
+            _ => "https",
+        };
+        let host = url.host_str()?;
+        let registry = match url.port() {
+            Some(port) => format!("{scheme}://{host}:{port}/"),
+            None => format!("{scheme}://{host}/"),
+        };
+        DisplaySafeUrl::parse(&registry).ok()
+    }
+
+    /// Return the namespace under which the packages are stored, if the index is an OCI registry
+    /// (e.g., `org/pypi` for `oci://ghcr.io/org/pypi`).
+    ///
+    /// Each package is stored as a repository within the namespace, e.g., `org/pypi/anyio`.
+    pub fn oci_namespace(&self) -> Option<&str> {
+        let url = self.url();
+        if url.scheme() != "oci" {
+            return None;
+        }
+        Some(url.path().trim_matches('/'))
+    }
+
     /// Return the redacted URL for the index, omitting any sensitive credentials.
     pub fn without_credentials(&self) -> Cow<'_, DisplaySafeUrl> {
         let url = self.url();
@@ -488,6 +532,15 @@ impl<'a> IndexLocations {
         }
         None
     }
+
+    /// Returns `true` if distributions from an [`IndexUrl`] must match a hash declared by the
+    /// index.
+    pub fn require_hashes_from_index_for(&self, url: &IndexUrl) -> bool {
+        self.indexes
+            .iter()
+            .chain(&self.flat_index)
+            .any(|index| index.url() == url && index.require_hashes_from_index)
+    }
 }
 
 impl From<&IndexLocations> for uv_auth::Indexes {
@@ -503,6 +556,15 @@ impl From<&IndexLocations> for uv_auth::Indexes {
                 url,
                 root_url,
                 auth_policy: index.authenticate,
+                azure_identity: index.azure_identity,
+                credential_helper: index.credential_helper.clone(),
+                headers: index
+                    .headers
+                    .iter()
+                    .flatten()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect(),
+                token_exchange: index.token_exchange.clone(),
             }
         }))
     }
@@ -566,6 +628,9 @@ impl<'a> IndexUrls {
     /// Prioritizes the `[tool.uv.index]` definitions over the `--extra-index-url` definitions
     /// over the `--index-url` definition.
     ///
+    /// Fallback indexes are excluded, as they're only searched when the index they fall back
+    /// for is unavailable (see [`IndexUrls::fallbacks_for`]).
+    ///
     /// If `no_index` was enabled, then this always returns an empty
     /// iterator.
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
@@ -573,9 +638,49 @@ impl<'a> IndexUrls {
         self.implicit_indexes()
             .chain(self.default_index())
             .filter(|index| !index.explicit)
+            .filter(|index| index.fallback_of.is_none())
             .filter(move |index| seen.insert(index.raw_url())) // Filter out redundant raw URLs
     }
 
+    /// Return the fallbacks for an [`IndexUrl`], in the order in which they should be searched.
+    ///
+    /// Includes the fallbacks of each fallback, such that mirrors can be chained.
+    pub fn fallbacks_for(&'a self, url: &IndexUrl) -> Vec<&'a Index> {
+        if self.no_index {
+            return Vec::new();
+        }
+
+        let mut fallbacks: Vec<&'a Index> = Vec::new();
+        let mut names = self
+            .indexes
+            .iter()
+            .find(|index| index.url() == url)
+            .and_then(|index| index.name.as_ref())
+            .into_iter()
+            .collect::<VecDeque<_>>();
+        let mut seen = names.iter().copied().collect::<FxHashSet<_>>();
+        while let Some(name) = names.pop_front() {
+            for index in &self.indexes {
+                if index.fallback_of.as_ref() != Some(name) || index.url() == url {
+                    continue;
+                }
+                if fallbacks
+                    .iter()
+                    .any(|fallback| fallback.url() == index.url())
+                {
+                    continue;
+                }
+                fallbacks.push(index);
+                if let Some(name) = index.name.as_ref() {
+                    if seen.insert(name) {
+                        names.push_back(name);
+                    }
+                }
+            }
+        }
+        fallbacks
+    }
+
     /// Return an iterator over all user-defined [`Index`] entries in order.
     ///
     /// Prioritizes the `[tool.uv.index]` definitions over the `--extra-index-url` definitions
@@ -641,6 +746,48 @@ impl<'a> IndexUrls {
         }
         None
     }
+
+    /// Return the format in which to request the Simple API from an [`IndexUrl`], if it must be
+    /// forced.
+    pub fn simple_api_format_for(&self, url: &IndexUrl) -> Option<SimpleApiFormat> {
+        for index in &self.indexes {
+            if index.url() == url {
+                return index.simple_api_format();
+            }
+        }
+        None
+    }
+
+    /// Returns `true` if the metadata files advertised by an [`IndexUrl`] should be used.
+    pub fn core_metadata_for(&self, url: &IndexUrl) -> bool {
+        for index in &self.indexes {
+            if index.url() == url {
+                return index.core_metadata();
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if HTTP range requests should be attempted against an [`IndexUrl`].
+    pub fn range_requests_for(&self, url: &IndexUrl) -> bool {
+        for index in &self.indexes {
+            if index.url() == url {
+                return index.range_requests();
+            }
+        }
+        true
+    }
+
+    /// Return the time for which to cache the absence of a package from an [`IndexUrl`], if
+    /// configured.
+    pub fn not_found_cache_ttl_for(&self, url: &IndexUrl) -> Option<Duration> {
+        for index in &self.indexes {
+            if index.url() == url {
+                return index.not_found_cache_ttl.map(Duration::from_secs);
+            }
+        }
+        None
+    }
 }
 
 bitflags::bitflags! {
@@ -652,6 +799,8 @@ bitflags::bitflags! {
         const UNAUTHORIZED      = 1 << 2;
         /// Whether the index returned a `403 Forbidden` status code.
         const FORBIDDEN         = 1 << 1;
+        /// Whether the index was unavailable (e.g., timed out or returned a server error).
+        const UNHEALTHY         = 1 << 3;
     }
 }
 
@@ -721,6 +870,25 @@ impl IndexCapabilities {
             .or_insert(Flags::empty())
             .insert(Flags::FORBIDDEN);
     }
+
+    /// Returns `true` if the given [`IndexUrl`] was unavailable.
+    pub fn unhealthy(&self, index_url: &IndexUrl) -> bool {
+        self.0
+            .read()
+            .unwrap()
+            .get(index_url)
+            .is_some_and(|flags| flags.intersects(Flags::UNHEALTHY))
+    }
+
+    /// Mark an [`IndexUrl`] as unavailable, such that its fallbacks are preferred.
+    pub fn set_unhealthy(&self, index_url: IndexUrl) {
+        self.0
+            .write()
+            .unwrap()
+            .entry(index_url)
+            .or_insert(Flags::empty())
+            .insert(Flags::UNHEALTHY);
+    }
 }
 
 #[cfg(test)]
@@ -760,6 +928,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_oci_registry() {
+        let index = IndexUrl::from_str("oci://ghcr.io/org/pypi").unwrap();
+        assert!(matches!(index, IndexUrl::Url(_)));
+        assert_eq!(index.oci_registry().unwrap().as_str(), "https://ghcr.io/");
+        assert_eq!(index.oci_namespace(), Some("org/pypi"));
+        assert_eq!(index.root().unwrap().as_str(), "https://ghcr.io/");
+
+        let index = IndexUrl::from_str("oci://localhost:5000/pypi/").unwrap();
+        assert_eq!(
+            index.oci_registry().unwrap().as_str(),
+            "http://localhost:5000/"
+        );
+        assert_eq!(index.oci_namespace(), Some("pypi"));
+
+        let index = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        assert_eq!(index.oci_registry(), None);
+        assert_eq!(index.oci_namespace(), None);
+    }
+
     #[test]
     fn test_cache_control_lookup() {
         use std::str::FromStr;
@@ -784,6 +972,21 @@ mod tests {
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
+                fallback_of: None,
+                ssl_ca_cert: None,
+                ssl_client_cert: None,
+                ssl_client_key: None,
+                proxy: None,
+                resolve: None,
+                simple_api: None,
+                core_metadata: None,
+                quirks: None,
+                require_hashes_from_index: false,
+                not_found_cache_ttl: None,
+                azure_identity: None,
+                credential_helper: None,
+                headers: None,
+                token_exchange: None,
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
@@ -796,6 +999,21 @@ mod tests {
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
+                fallback_of: None,
+                ssl_ca_cert: None,
+                ssl_client_cert: None,
+                ssl_client_key: None,
+                proxy: None,
+                resolve: None,
+                simple_api: None,
+                core_metadata: None,
+                quirks: None,
+                require_hashes_from_index: false,
+                not_found_cache_ttl: None,
+                azure_identity: None,
+                credential_helper: None,
+                headers: None,
+                token_exchange: None,
             },
         ];
 
@@ -819,4 +1037,224 @@ mod tests {
         assert_eq!(index_urls.simple_api_cache_control_for(&url3), None);
         assert_eq!(index_urls.artifact_cache_control_for(&url3), None);
     }
+
+    #[test]
+    fn test_quirks_lookup() {
+        let index = |toml: &str| toml::from_str::<Index>(toml).unwrap();
+        let index_urls = IndexUrls::from_indexes(vec![
+            index(
+                r#"
+                name = "devpi"
+                url = "https://devpi.example.com/root/pypi/+simple"
+                quirks = "devpi"
+            "#,
+            ),
+            index(
+                r#"
+                name = "nexus"
+                url = "https://nexus.example.com/simple"
+                quirks = "nexus"
+                simple-api = "json"
+            "#,
+            ),
+            index(
+                r#"
+                name = "artifactory"
+                url = "https://artifactory.example.com/simple"
+                quirks = "artifactory"
+            "#,
+            ),
+        ]);
+
+        let devpi = IndexUrl::from_str("https://devpi.example.com/root/pypi/+simple").unwrap();
+        assert_eq!(
+            index_urls.simple_api_format_for(&devpi),
+            Some(SimpleApiFormat::Html)
+        );
+        assert!(index_urls.core_metadata_for(&devpi));
+        assert!(index_urls.range_requests_for(&devpi));
+
+        // Explicit settings take precedence over the quirks mode.
+        let nexus = IndexUrl::from_str("https://nexus.example.com/simple").unwrap();
+        assert_eq!(
+            index_urls.simple_api_format_for(&nexus),
+            Some(SimpleApiFormat::Json)
+        );
+        assert!(!index_urls.range_requests_for(&nexus));
+
+        let artifactory = IndexUrl::from_str("https://artifactory.example.com/simple").unwrap();
+        assert_eq!(index_urls.simple_api_format_for(&artifactory), None);
+        assert!(!index_urls.core_metadata_for(&artifactory));
+
+        let pypi = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        assert_eq!(index_urls.simple_api_format_for(&pypi), None);
+        assert!(index_urls.core_metadata_for(&pypi));
+        assert!(index_urls.range_requests_for(&pypi));
+    }
+
+    #[test]
+    fn test_fallback_lookup() {
+        let index = |toml: &str| toml::from_str::<Index>(toml).unwrap();
+        let index_urls = IndexUrls::from_indexes(vec![
+            index(
+                r#"
+                name = "primary"
+                url = "https://primary.example.com/simple"
+            "#,
+            ),
+            index(
+                r#"
+                name = "mirror"
+                url = "https://mirror.example.com/simple"
+                fallback-of = "primary"
+            "#,
+            ),
+            index(
+                r#"
+                name = "backup"
+                url = "https://backup.example.com/simple"
+                fallback-of = "mirror"
+            "#,
+            ),
+        ]);
+
+        // Fallbacks aren't searched on their own.
+        assert_eq!(
+            index_urls
+                .indexes()
+                .map(|index| index.url().to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "https://primary.example.com/simple",
+                "https://pypi.org/simple"
+            ]
+        );
+
+        // Fallbacks are chained, in order.
+        let primary = IndexUrl::from_str("https://primary.example.com/simple").unwrap();
+        assert_eq!(
+            index_urls
+                .fallbacks_for(&primary)
+                .into_iter()
+                .map(|index| index.url().to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "https://mirror.example.com/simple",
+                "https://backup.example.com/simple"
+            ]
+        );
+
+        let pypi = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        assert!(index_urls.fallbacks_for(&pypi).is_empty());
+    }
+
+    #[test]
+    fn test_require_hashes_from_index_lookup() {
+        let index = |toml: &str| toml::from_str::<Index>(toml).unwrap();
+        let index_locations = IndexLocations::new(
+            vec![
+                index(
+                    r#"
+                    name = "mirror"
+                    url = "https://mirror.example.com/simple"
+                    require-hashes-from-index = true
+                "#,
+                ),
+                index(
+                    r#"
+                    name = "internal"
+                    url = "https://internal.example.com/simple"
+                "#,
+                ),
+            ],
+            vec![index(
+                r#"
+                name = "flat"
+                url = "https://flat.example.com/wheels"
+                format = "flat"
+                require-hashes-from-index = true
+            "#,
+            )],
+            false,
+        );
+
+        let mirror = IndexUrl::from_str("https://mirror.example.com/simple").unwrap();
+        assert!(index_locations.require_hashes_from_index_for(&mirror));
+
+        let flat = IndexUrl::from_str("https://flat.example.com/wheels").unwrap();
+        assert!(index_locations.require_hashes_from_index_for(&flat));
+
+        let internal = IndexUrl::from_str("https://internal.example.com/simple").unwrap();
+        assert!(!index_locations.require_hashes_from_index_for(&internal));
+
+        let pypi = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        assert!(!index_locations.require_hashes_from_index_for(&pypi));
+    }
+
+    #[test]
+    fn test_not_found_cache_ttl_lookup() {
+        let index = |toml: &str| toml::from_str::<Index>(toml).unwrap();
+        let index_urls = IndexUrls::from_indexes(vec![
+            index(
+                r#"
+                name = "internal"
+                url = "https://internal.example.com/simple"
+            "#,
+            ),
+            index(
+                r#"
+                name = "pypi"
+                url = "https://pypi.org/simple"
+                not-found-cache-ttl = 600
+            "#,
+            ),
+        ]);
+
+        let internal = IndexUrl::from_str("https://internal.example.com/simple").unwrap();
+        assert_eq!(index_urls.not_found_cache_ttl_for(&internal), None);
+
+        let pypi = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        assert_eq!(
+            index_urls.not_found_cache_ttl_for(&pypi),
+            Some(Duration::from_secs(600))
+        );
+    }
+
+    #[test]
+    fn test_azure_identity_lookup() {
+        let index = |toml: &str| toml::from_str::<Index>(toml).unwrap();
+        let index_locations = IndexLocations::new(
+            vec![
+                index(
+                    r#"
+                    name = "azure"
+                    url = "https://pkgs.dev.azure.com/org/project/_packaging/feed/pypi/simple/"
+                    azure-identity = "service-principal"
+                "#,
+                ),
+                index(
+                    r#"
+                    name = "pypi"
+                    url = "https://pypi.org/simple"
+                "#,
+                ),
+            ],
+            vec![],
+            false,
+        );
+        let indexes = uv_auth::Indexes::from(&index_locations);
+
+        // The identity applies to the index, and to the distributions it serves.
+        let url = Url::parse(
+            "https://pkgs.dev.azure.com/org/project/_packaging/feed/pypi/download/flask/3.0.0/flask-3.0.0-py3-none-any.whl",
+        )
+        .unwrap();
+        assert_eq!(
+            indexes.azure_identity_for(&url),
+            Some(uv_auth::AzureIdentity::ServicePrincipal)
+        );
+
+        let url = Url::parse("https://pypi.org/simple/flask/").unwrap();
+        assert_eq!(indexes.azure_identity_for(&url), None);
+    }
 }
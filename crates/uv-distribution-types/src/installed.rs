@@ -13,7 +13,7 @@ use uv_distribution_filename::EggInfoFilename;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{DirectUrl, MetadataError};
+use uv_pypi_types::{DirectUrl, HashDigest, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::{DistributionMetadata, InstalledMetadata, InstalledVersion, Name, VersionOrUrlRef};
@@ -329,6 +329,21 @@ impl InstalledDist {
         Ok(Some(cache_info))
     }
 
+    /// Read the `uv_hashes.json` file from a `.dist-info` directory, which records the hashes of
+    /// the archive from which the distribution was installed.
+    pub fn hashes(path: &Path) -> Result<Option<Vec<HashDigest>>, InstalledDistError> {
+        let path = path.join("uv_hashes.json");
+        let file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let hashes = serde_json::from_reader::<BufReader<fs_err::File>, Vec<HashDigest>>(
+            BufReader::new(file),
+        )?;
+        Ok(Some(hashes))
+    }
+
     /// Read the `METADATA` file from a `.dist-info` directory.
     pub fn metadata(&self) -> Result<uv_pypi_types::ResolutionMetadata, InstalledDistError> {
         match self {
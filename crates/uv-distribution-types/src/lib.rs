@@ -69,12 +69,15 @@ pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::known_platform::*;
 pub use crate::origin::*;
+pub use crate::patches::*;
 pub use crate::pip_index::*;
 pub use crate::prioritized_distribution::*;
+pub use crate::proxy_url::*;
 pub use crate::requested::*;
 pub use crate::requirement::*;
 pub use crate::requires_python::*;
 pub use crate::resolution::*;
+pub use crate::resolve_target::*;
 pub use crate::resolved::*;
 pub use crate::specified_requirement::*;
 pub use crate::status_code_strategy::*;
@@ -97,12 +100,15 @@ mod index_url;
 mod installed;
 mod known_platform;
 mod origin;
+mod patches;
 mod pip_index;
 mod prioritized_distribution;
+mod proxy_url;
 mod requested;
 mod requirement;
 mod requires_python;
 mod resolution;
+mod resolve_target;
 mod resolved;
 mod specified_requirement;
 mod status_code_strategy;
@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::HashDigest;
+
+/// Pre-defined [`SourcePatch`] entries, indexed by [`PackageName`] and [`Version`].
+#[derive(Debug, Clone, Default)]
+pub struct SourcePatches {
+    /// The directory against which the patch paths are resolved (i.e., the workspace root).
+    root: PathBuf,
+    /// The patches, indexed by package name.
+    patches: FxHashMap<PackageName, Vec<SourcePatch>>,
+}
+
+impl SourcePatches {
+    /// Index a set of [`SourcePatch`] entries by [`PackageName`] and [`Version`], with paths
+    /// relative to the given root.
+    pub fn from_entries(root: PathBuf, entries: impl IntoIterator<Item = SourcePatch>) -> Self {
+        let mut patches: FxHashMap<PackageName, Vec<SourcePatch>> = FxHashMap::default();
+        for entry in entries {
+            patches.entry(entry.name.clone()).or_default().push(entry);
+        }
+        Self { root, patches }
+    }
+
+    /// Retrieve the [`SourcePatch`] for a given [`PackageName`] and [`Version`].
+    pub fn get(&self, package: &PackageName, version: &Version) -> Option<&SourcePatch> {
+        self.patches
+            .get(package)?
+            .iter()
+            .find(|patch| patch.version == *version)
+    }
+
+    /// Return the absolute path to the patch file for a [`SourcePatch`].
+    pub fn path(&self, patch: &SourcePatch) -> PathBuf {
+        self.root.join(&patch.path)
+    }
+
+    /// Return the directory against which the patch paths are resolved.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns `true` if no patches are defined.
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    /// Retrieve all [`SourcePatch`] entries.
+    pub fn values(&self) -> impl Iterator<Item = &SourcePatch> {
+        self.patches.values().flatten()
+    }
+}
+
+/// A patch file to apply to the source distribution of a specific package version before building
+/// it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SourcePatch {
+    /// The name of the package to patch.
+    pub name: PackageName,
+    /// The version of the package to patch.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "String",
+            description = "PEP 440-style package version, e.g., `1.2.3`"
+        )
+    )]
+    pub version: Version,
+    /// The path to the patch file, relative to the workspace root.
+    pub path: PathBuf,
+    /// The hash of the patch file, e.g., `sha256:...`.
+    #[serde(
+        serialize_with = "serialize_hash",
+        deserialize_with = "deserialize_hash"
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "String",
+            description = "The hash of the patch file, e.g., `sha256:...`"
+        )
+    )]
+    pub hash: HashDigest,
+}
+
+fn serialize_hash<S: Serializer>(hash: &HashDigest, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(hash)
+}
+
+fn deserialize_hash<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashDigest, D::Error> {
+    let hash = String::deserialize(deserializer)?;
+    HashDigest::from_str(&hash).map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,114 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use thiserror::Error;
+use url::Host;
+
+/// The address to which an index's host name should be resolved, in lieu of DNS: either an IP
+/// address, or an alternate host name to resolve instead.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ResolveTarget {
+    /// Connect to the given IP address.
+    Ip(IpAddr),
+    /// Resolve the given host name, and connect to the resulting addresses.
+    Host(String),
+}
+
+impl FromStr for ResolveTarget {
+    type Err = ResolveTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ResolveTargetError::Empty);
+        }
+        // Accept IPv6 addresses with or without brackets.
+        if let Ok(ip) = IpAddr::from_str(s) {
+            return Ok(Self::Ip(ip));
+        }
+        match Host::parse(s).map_err(|err| ResolveTargetError::Invalid(s.to_string(), err))? {
+            Host::Ipv4(ip) => Ok(Self::Ip(IpAddr::V4(ip))),
+            Host::Ipv6(ip) => Ok(Self::Ip(IpAddr::V6(ip))),
+            Host::Domain(domain) => Ok(Self::Host(domain)),
+        }
+    }
+}
+
+impl Display for ResolveTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ip(ip) => Display::fmt(ip, f),
+            Self::Host(host) => Display::fmt(host, f),
+        }
+    }
+}
+
+impl serde::ser::Serialize for ResolveTarget {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for ResolveTarget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = Cow::<'_, str>::deserialize(deserializer)?;
+        ResolveTarget::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ResolveTarget {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ResolveTarget")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "An IP address (e.g., `10.0.0.5`) or host name (e.g., `pypi.internal.svc.cluster.local`) to connect to in lieu of the index's host."
+        })
+    }
+}
+
+/// An error that can occur when parsing a [`ResolveTarget`].
+#[derive(Error, Debug)]
+pub enum ResolveTargetError {
+    #[error("Expected an IP address or host name, but received an empty string")]
+    Empty,
+    #[error("Failed to parse IP address or host name: `{0}`")]
+    Invalid(String, #[source] url::ParseError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resolve_target() {
+        assert_eq!(
+            ResolveTarget::from_str("10.0.0.5").unwrap(),
+            ResolveTarget::Ip("10.0.0.5".parse().unwrap())
+        );
+        assert_eq!(
+            ResolveTarget::from_str("[::1]").unwrap(),
+            ResolveTarget::Ip("::1".parse().unwrap())
+        );
+        assert_eq!(
+            ResolveTarget::from_str("::1").unwrap(),
+            ResolveTarget::Ip("::1".parse().unwrap())
+        );
+        assert_eq!(
+            ResolveTarget::from_str("pypi.internal.svc.cluster.local").unwrap(),
+            ResolveTarget::Host("pypi.internal.svc.cluster.local".to_string())
+        );
+        assert!(ResolveTarget::from_str("").is_err());
+        assert!(ResolveTarget::from_str("pypi internal").is_err());
+    }
+}
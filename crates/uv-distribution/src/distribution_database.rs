@@ -1,16 +1,17 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
 use futures::{FutureExt, TryStreamExt};
+use rustc_hash::FxHashMap;
 use tempfile::TempDir;
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
@@ -18,6 +19,7 @@ use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
 };
+use uv_configuration::Concurrency;
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuildableSource, BuiltDist, Dist, HashPolicy, Hashed, IndexUrl, InstalledDist, Name, SourceDist,
@@ -25,7 +27,7 @@ use uv_distribution_types::{
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, HashDigests};
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
 
@@ -57,12 +59,16 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     pub fn new(
         client: &'a RegistryClient,
         build_context: &'a Context,
-        concurrent_downloads: usize,
+        concurrency: Concurrency,
     ) -> Self {
         Self {
             build_context,
             builder: SourceDistributionBuilder::new(build_context),
-            client: ManagedClient::new(client, concurrent_downloads),
+            client: ManagedClient::new(
+                client,
+                concurrency.downloads,
+                concurrency.downloads_per_host,
+            ),
             reporter: None,
         }
     }
@@ -114,9 +120,176 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<LocalWheel, Error> {
-        match dist {
-            Dist::Built(built) => self.get_wheel(built, hashes).await,
-            Dist::Source(source) => self.build_wheel(source, tags, hashes).await,
+        // If the index requires hashes, validate against the hashes it declared, independent of
+        // any hashes provided by the caller.
+        let index_hashes = self.index_hashes(dist)?;
+        let hashes = match index_hashes {
+            Some(index_hashes) if !hashes.is_validate() => HashPolicy::Validate(index_hashes),
+            _ => hashes,
+        };
+
+        let wheel = match dist {
+            Dist::Built(built) => self.get_wheel(built, hashes).await?,
+            Dist::Source(source) => self.build_wheel(source, tags, hashes).await?,
+        };
+
+        if let Some(index_hashes) = index_hashes {
+            if !wheel.satisfies(HashPolicy::Validate(index_hashes)) {
+                return Err(Error::hash_mismatch(
+                    dist.to_string(),
+                    index_hashes,
+                    wheel.hashes(),
+                ));
+            }
+        }
+
+        Ok(wheel)
+    }
+
+    /// Either download the wheel or build the source distribution, and write the wheel file to
+    /// the given directory.
+    ///
+    /// Unlike [`DistributionDatabase::get_or_build_wheel`], the wheel file is retained as-is,
+    /// rather than unzipped into the cache. Wheels built from source distributions are reused
+    /// from the cache.
+    #[instrument(skip_all, fields(%dist))]
+    pub async fn get_or_build_wheel_file(
+        &self,
+        dist: &Dist,
+        tags: &Tags,
+        hashes: HashPolicy<'_>,
+        directory: &Path,
+    ) -> Result<PathBuf, Error> {
+        let (url, filename) = match dist {
+            Dist::Source(source) => {
+                let built_wheel = self
+                    .builder
+                    .download_and_build(&BuildableSource::Dist(source), tags, hashes, &self.client)
+                    .boxed_local()
+                    .await?;
+                let target = directory.join(built_wheel.filename.to_string());
+                fs_err::tokio::copy(&built_wheel.path, &target)
+                    .await
+                    .map_err(Error::CacheRead)?;
+                return Ok(target);
+            }
+            Dist::Built(BuiltDist::Path(wheel)) => {
+                let target = directory.join(wheel.filename.to_string());
+                fs_err::tokio::copy(&wheel.install_path, &target)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                self.validate_wheel_file(dist, &target, hashes).await?;
+                return Ok(target);
+            }
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (wheel.file.url.to_url()?, &wheel.filename)
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+                ((*wheel.location).clone(), &wheel.filename)
+            }
+        };
+
+        let target = directory.join(filename.to_string());
+        if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| Error::NonFileUrl(url.clone()))?;
+            fs_err::tokio::copy(&path, &target)
+                .await
+                .map_err(Error::CacheWrite)?;
+        } else {
+            let response = {
+                let req = self.request(url.clone())?;
+                self.client
+                    .managed_for(&url, |client| client.uncached_client(&url).execute(req))
+                    .await?
+                    .error_for_status()?
+            };
+
+            let size = content_length(&response);
+            let progress = self
+                .reporter
+                .as_ref()
+                .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
+            let mut file = tokio::fs::File::create(&target)
+                .await
+                .map_err(Error::CacheWrite)?;
+            self.client
+                .unmanaged
+                .cached_client()
+                .uncached()
+                .download_to_file(&self.request(url.clone())?, response, &mut file, |bytes| {
+                    if let Some((reporter, progress)) = progress {
+                        reporter.on_download_progress(progress, bytes);
+                    }
+                })
+                .await
+                .map_err(Error::CacheWrite)?;
+            if let Some((reporter, progress)) = progress {
+                reporter.on_download_complete(dist.name(), progress);
+            }
+        }
+
+        self.validate_wheel_file(dist, &target, hashes).await?;
+        Ok(target)
+    }
+
+    /// Validate a wheel file against the hash policy, removing it if the hashes don't match.
+    async fn validate_wheel_file(
+        &self,
+        dist: &Dist,
+        path: &Path,
+        hashes: HashPolicy<'_>,
+    ) -> Result<(), Error> {
+        let HashPolicy::Validate(expected) = hashes else {
+            return Ok(());
+        };
+
+        let file = fs_err::tokio::File::open(path)
+            .await
+            .map_err(Error::CacheRead)?;
+        let mut hashers = hashes
+            .algorithms()
+            .into_iter()
+            .map(Hasher::from)
+            .collect::<Vec<_>>();
+        let mut reader = uv_extract::hash::HashReader::new(file, &mut hashers);
+        reader.finish().await.map_err(Error::HashExhaustion)?;
+        let actual = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+
+        if actual.iter().any(|digest| expected.contains(digest)) {
+            Ok(())
+        } else {
+            fs_err::tokio::remove_file(path)
+                .await
+                .map_err(Error::CacheWrite)?;
+            Err(Error::hash_mismatch(dist.to_string(), expected, &actual))
+        }
+    }
+
+    /// Return the hashes declared by the index for the given distribution, if the index requires
+    /// that its distributions match the declared hashes.
+    fn index_hashes<'dist>(&self, dist: &'dist Dist) -> Result<Option<&'dist [HashDigest]>, Error> {
+        let Some(index) = dist.index() else {
+            return Ok(None);
+        };
+        if !self
+            .build_context
+            .locations()
+            .require_hashes_from_index_for(index)
+        {
+            return Ok(None);
+        }
+        match dist.file() {
+            Some(file) if !file.hashes.is_empty() => Ok(Some(file.hashes.as_slice())),
+            _ => Err(Error::MissingIndexHashes {
+                distribution: dist.to_string(),
+                index: index.to_string(),
+            }),
         }
     }
 
@@ -197,20 +370,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         .await;
                 }
 
-                // Download and unzip.
-                match self
-                    .stream_wheel(
-                        url.clone(),
-                        dist.index(),
-                        &wheel.filename,
-                        wheel.file.size,
-                        &wheel_entry,
-                        dist,
-                        hashes,
-                    )
-                    .await
-                {
-                    Ok(archive) => Ok(LocalWheel {
+                // If an identical wheel was already fetched (e.g., from another index), reuse it.
+                if let Some(archive) = self.find_archive(
+                    &wheel.filename,
+                    &wheel.file.hashes,
+                    &wheel_entry,
+                    dist.index(),
+                    hashes,
+                )? {
+                    return Ok(LocalWheel {
                         dist: Dist::Built(dist.clone()),
                         archive: self
                             .build_context
@@ -220,22 +388,56 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                         hashes: archive.hashes,
                         filename: wheel.filename.clone(),
                         cache: CacheInfo::default(),
-                    }),
-                    Err(Error::Extract(name, err)) => {
-                        if err.is_http_streaming_unsupported() {
-                            warn!(
-                                "Streaming unsupported for {dist}; downloading wheel to disk ({err})"
-                            );
-                        } else if err.is_http_streaming_failed() {
-                            warn!("Streaming failed for {dist}; downloading wheel to disk ({err})");
-                        } else {
-                            return Err(Error::Extract(name, err));
-                        }
+                    });
+                }
 
-                        // If the request failed because streaming is unsupported, download the
-                        // wheel directly.
-                        let archive = self
-                            .download_wheel(
+                // Download and unzip. Large wheels are downloaded to disk rather than streamed,
+                // such that an interrupted download can be resumed rather than restarted.
+                let archive = if wheel
+                    .file
+                    .size
+                    .is_some_and(|size| size >= RESUMABLE_WHEEL_SIZE)
+                {
+                    self.download_wheel(
+                        url,
+                        dist.index(),
+                        &wheel.filename,
+                        wheel.file.size,
+                        &wheel_entry,
+                        dist,
+                        hashes,
+                    )
+                    .await?
+                } else {
+                    match self
+                        .stream_wheel(
+                            url.clone(),
+                            dist.index(),
+                            &wheel.filename,
+                            wheel.file.size,
+                            &wheel_entry,
+                            dist,
+                            hashes,
+                        )
+                        .await
+                    {
+                        Ok(archive) => archive,
+                        Err(Error::Extract(name, err)) => {
+                            if err.is_http_streaming_unsupported() {
+                                warn!(
+                                    "Streaming unsupported for {dist}; downloading wheel to disk ({err})"
+                                );
+                            } else if err.is_http_streaming_failed() {
+                                warn!(
+                                    "Streaming failed for {dist}; downloading wheel to disk ({err})"
+                                );
+                            } else {
+                                return Err(Error::Extract(name, err));
+                            }
+
+                            // If the request failed because streaming is unsupported, download the
+                            // wheel directly.
+                            self.download_wheel(
                                 url,
                                 dist.index(),
                                 &wheel.filename,
@@ -244,22 +446,23 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                                 dist,
                                 hashes,
                             )
-                            .await?;
-
-                        Ok(LocalWheel {
-                            dist: Dist::Built(dist.clone()),
-                            archive: self
-                                .build_context
-                                .cache()
-                                .archive(&archive.id)
-                                .into_boxed_path(),
-                            hashes: archive.hashes,
-                            filename: wheel.filename.clone(),
-                            cache: CacheInfo::default(),
-                        })
+                            .await?
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => Err(err),
-                }
+                };
+
+                Ok(LocalWheel {
+                    dist: Dist::Built(dist.clone()),
+                    archive: self
+                        .build_context
+                        .cache()
+                        .archive(&archive.id)
+                        .into_boxed_path(),
+                    hashes: archive.hashes,
+                    filename: wheel.filename.clone(),
+                    cache: CacheInfo::default(),
+                })
             }
 
             BuiltDist::DirectUrl(wheel) => {
@@ -270,6 +473,23 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     wheel.filename.cache_key(),
                 );
 
+                // If an identical wheel was already fetched (e.g., from an index), reuse it.
+                if let Some(archive) =
+                    self.find_archive(&wheel.filename, &[], &wheel_entry, None, hashes)?
+                {
+                    return Ok(LocalWheel {
+                        dist: Dist::Built(dist.clone()),
+                        archive: self
+                            .build_context
+                            .cache()
+                            .archive(&archive.id)
+                            .into_boxed_path(),
+                        hashes: archive.hashes,
+                        filename: wheel.filename.clone(),
+                        cache: CacheInfo::default(),
+                    });
+                }
+
                 // Download and unzip.
                 match self
                     .stream_wheel(
@@ -564,8 +784,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
+                let reader = uv_client::throttle(response.bytes_stream())
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
@@ -575,8 +794,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
 
                 // Download and unzip the wheel to a temporary directory.
-                let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
+                let temp_dir = tempfile::tempdir_in(
+                    self.build_context.cache().bucket_root(CacheBucket::Archive),
+                )
+                .map_err(Error::CacheWrite)?;
 
                 match progress {
                     Some((reporter, progress)) => {
@@ -597,11 +818,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     hasher.finish().await.map_err(Error::HashExhaustion)?;
                 }
 
+                let hashes = hashers.into_iter().map(HashDigest::from).collect();
+
                 // Persist the temporary directory to the directory store.
                 let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
+                    .persist_archive(temp_dir, wheel_entry.path(), &hashes, index)
                     .await
                     .map_err(Error::CacheRead)?;
 
@@ -609,11 +830,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     reporter.on_download_complete(dist.name(), progress);
                 }
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                    filename.clone(),
-                ))
+                Ok(Archive::new(id, hashes, filename.clone()))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
@@ -644,7 +861,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let archive = self
             .client
-            .managed(|client| {
+            .managed_for(&url, |client| {
                 client.cached_client().get_serde_with_retry(
                     req,
                     &http_entry,
@@ -667,11 +884,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             archive
         } else {
             self.client
-                .managed(async |client| {
+                .managed_for(&url, async |client| {
                     client
                         .cached_client()
                         .skip_cache_with_retry(
-                            self.request(url)?,
+                            self.request(url.clone())?,
                             &http_entry,
                             cache_control,
                             download,
@@ -718,39 +935,31 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .as_ref()
                     .map(|reporter| (reporter, reporter.on_download_start(dist.name(), size)));
 
-                let reader = response
-                    .bytes_stream()
-                    .map_err(|err| self.handle_response_errors(err))
-                    .into_async_read();
-
-                // Download the wheel to a temporary file.
+                // Download the wheel to a temporary file, resuming the download if it's
+                // interrupted. The hashes are computed from the file (rather than the response),
+                // such that they cover any resumed content.
                 let temp_file = tempfile::tempfile_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
-                let mut writer = tokio::io::BufWriter::new(tokio::fs::File::from_std(temp_file));
-
-                match progress {
-                    Some((reporter, progress)) => {
-                        // Wrap the reader in a progress reporter. This will report 100% progress
-                        // after the download is complete, even if we still have to unzip and hash
-                        // part of the file.
-                        let mut reader =
-                            ProgressReader::new(reader.compat(), progress, &**reporter);
-
-                        tokio::io::copy(&mut reader, &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                    None => {
-                        tokio::io::copy(&mut reader.compat(), &mut writer)
-                            .await
-                            .map_err(Error::CacheWrite)?;
-                    }
-                }
+                let mut file = tokio::fs::File::from_std(temp_file);
+                self.client
+                    .unmanaged
+                    .cached_client()
+                    .uncached()
+                    .download_to_file(&self.request(url.clone())?, response, &mut file, |bytes| {
+                        // This will report 100% progress after the download is complete, even if
+                        // we still have to unzip and hash part of the file.
+                        if let Some((reporter, progress)) = progress {
+                            reporter.on_download_progress(progress, bytes);
+                        }
+                    })
+                    .await
+                    .map_err(Error::CacheWrite)?;
 
                 // Unzip the wheel to a temporary directory.
-                let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
-                    .map_err(Error::CacheWrite)?;
-                let mut file = writer.into_inner();
+                let temp_dir = tempfile::tempdir_in(
+                    self.build_context.cache().bucket_root(CacheBucket::Archive),
+                )
+                .map_err(Error::CacheWrite)?;
                 file.seek(io::SeekFrom::Start(0))
                     .await
                     .map_err(Error::CacheWrite)?;
@@ -787,9 +996,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
                 // Persist the temporary directory to the directory store.
                 let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
+                    .persist_archive(temp_dir, wheel_entry.path(), &hashes, index)
                     .await
                     .map_err(Error::CacheRead)?;
 
@@ -828,7 +1035,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let archive = self
             .client
-            .managed(|client| {
+            .managed_for(&url, |client| {
                 client.cached_client().get_serde_with_retry(
                     req,
                     &http_entry,
@@ -851,11 +1058,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             archive
         } else {
             self.client
-                .managed(async |client| {
+                .managed_for(&url, async |client| {
                     client
                         .cached_client()
                         .skip_cache_with_retry(
-                            self.request(url)?,
+                            self.request(url.clone())?,
                             &http_entry,
                             cache_control,
                             download,
@@ -898,7 +1105,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let archive = pointer
             .filter(|pointer| pointer.is_up_to_date(modified))
             .map(LocalArchivePointer::into_archive)
-            .filter(|archive| archive.has_digests(hashes));
+            .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| archive.exists(self.build_context.cache()));
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
@@ -944,8 +1152,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             let file = fs_err::tokio::File::open(path)
                 .await
                 .map_err(Error::CacheRead)?;
-            let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
-                .map_err(Error::CacheWrite)?;
+            let temp_dir =
+                tempfile::tempdir_in(self.build_context.cache().bucket_root(CacheBucket::Archive))
+                    .map_err(Error::CacheWrite)?;
 
             // Create a hasher for each hash algorithm.
             let algorithms = hashes.algorithms();
@@ -964,9 +1173,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
             // Persist the temporary directory to the directory store.
             let id = self
-                .build_context
-                .cache()
-                .persist(temp_dir.keep(), wheel_entry.path())
+                .persist_archive(temp_dir, wheel_entry.path(), &hashes, dist.index())
                 .await
                 .map_err(Error::CacheWrite)?;
 
@@ -994,11 +1201,93 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         }
     }
 
+    /// Find an archive in the store that was unpacked from a wheel with the same SHA-256 digest as
+    /// the given wheel, such that identical wheels are only downloaded once, regardless of the
+    /// index or URL from which they're fetched.
+    ///
+    /// The digest is taken from the hash policy, if it includes one, and otherwise from the
+    /// `digests` reported by the index. If a matching archive is found, it's linked from the
+    /// wheel's own cache entry.
+    fn find_archive(
+        &self,
+        filename: &WheelFilename,
+        digests: &[HashDigest],
+        wheel_entry: &CacheEntry,
+        index: Option<&IndexUrl>,
+        hashes: HashPolicy<'_>,
+    ) -> Result<Option<Archive>, Error> {
+        let cache = self.build_context.cache();
+        if cache.must_revalidate_package(&filename.name) {
+            return Ok(None);
+        }
+
+        let Some(digest) = hashes
+            .digests()
+            .iter()
+            .chain(digests)
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        else {
+            return Ok(None);
+        };
+        let Some(id) = cache.find_content_addressed(&digest.digest, index) else {
+            return Ok(None);
+        };
+
+        let archive = Archive::new(
+            id,
+            std::iter::once(digest.clone()).collect(),
+            filename.clone(),
+        );
+        if !archive.satisfies(hashes) {
+            return Ok(None);
+        }
+
+        debug!(
+            "Reusing archive for {filename} with matching digest: {}",
+            archive.id
+        );
+        fs_err::create_dir_all(wheel_entry.dir()).map_err(Error::CacheWrite)?;
+        cache
+            .create_link(&archive.id, wheel_entry.path())
+            .map_err(Error::CacheWrite)?;
+
+        Ok(Some(archive))
+    }
+
+    /// Persist an unzipped wheel to the directory store.
+    ///
+    /// If the SHA-256 digest of the wheel is known, the archive is content-addressed, such that
+    /// identical wheels share a single entry in the store.
+    async fn persist_archive(
+        &self,
+        temp_dir: TempDir,
+        path: &Path,
+        hashes: &HashDigests,
+        index: Option<&IndexUrl>,
+    ) -> io::Result<ArchiveId> {
+        let cache = self.build_context.cache();
+        match hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+        {
+            Some(digest) => {
+                cache
+                    .persist_content_addressed(temp_dir.keep(), path, &digest.digest, index)
+                    .await
+            }
+            None => cache.persist(temp_dir.keep(), path).await,
+        }
+    }
+
     /// Unzip a wheel into the cache, returning the path to the unzipped directory.
     async fn unzip_wheel(&self, path: &Path, target: &Path) -> Result<ArchiveId, Error> {
         let temp_dir = tokio::task::spawn_blocking({
             let path = path.to_owned();
-            let root = self.build_context.cache().root().to_path_buf();
+            let root = self
+                .build_context
+                .cache()
+                .bucket_root(CacheBucket::Archive)
+                .to_path_buf();
             move || -> Result<TempDir, Error> {
                 // Unzip the wheel into a temporary directory.
                 let temp_dir = tempfile::tempdir_in(root).map_err(Error::CacheWrite)?;
@@ -1047,14 +1336,20 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 pub struct ManagedClient<'a> {
     pub unmanaged: &'a RegistryClient,
     control: Semaphore,
+    /// The concurrency limit for requests to any one host.
+    per_host: usize,
+    /// The concurrency limit for each host, created on first use.
+    hosts: Mutex<FxHashMap<String, Arc<Semaphore>>>,
 }
 
 impl<'a> ManagedClient<'a> {
-    /// Create a new `ManagedClient` using the given client and concurrency limit.
-    fn new(client: &'a RegistryClient, concurrency: usize) -> ManagedClient<'a> {
+    /// Create a new `ManagedClient` using the given client and concurrency limits.
+    fn new(client: &'a RegistryClient, concurrency: usize, per_host: usize) -> ManagedClient<'a> {
         ManagedClient {
             unmanaged: client,
             control: Semaphore::new(concurrency),
+            per_host,
+            hosts: Mutex::new(FxHashMap::default()),
         }
     }
 
@@ -1070,6 +1365,35 @@ impl<'a> ManagedClient<'a> {
         f(self.unmanaged).await
     }
 
+    /// Perform a request to the given URL using the client, respecting both the overall and the
+    /// per-host concurrency limits.
+    pub async fn managed_for<F, T>(&self, url: &Url, f: impl FnOnce(&'a RegistryClient) -> F) -> T
+    where
+        F: Future<Output = T>,
+    {
+        // Wait on the host before the overall limit, such that requests that are blocked on a busy
+        // host don't prevent requests to other hosts.
+        let host = self.host(url);
+        let _host_permit = host.acquire().await.unwrap();
+        let _permit = self.control.acquire().await.unwrap();
+        f(self.unmanaged).await
+    }
+
+    /// Return the concurrency limit for the host of the given URL.
+    fn host(&self, url: &Url) -> Arc<Semaphore> {
+        let key = format!(
+            "{}:{}",
+            url.host_str().unwrap_or_default(),
+            url.port_or_known_default().unwrap_or_default()
+        );
+        self.hosts
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_insert_with(|| Arc::new(Semaphore::new(self.per_host)))
+            .clone()
+    }
+
     /// Perform a request using a client that internally manages the concurrency limit.
     ///
     /// The callback is passed the client and a semaphore. It must acquire the semaphore before
@@ -1085,6 +1409,10 @@ impl<'a> ManagedClient<'a> {
     }
 }
 
+/// The size (in bytes) above which wheels are downloaded to disk rather than streamed, such that an
+/// interrupted download can be resumed.
+const RESUMABLE_WHEEL_SIZE: u64 = 64 * 1024 * 1024;
+
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
 fn content_length(response: &reqwest::Response) -> Option<u64> {
     response
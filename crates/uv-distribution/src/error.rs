@@ -5,6 +5,7 @@ use tokio::task::JoinError;
 use zip::result::ZipError;
 
 use crate::metadata::MetadataError;
+use crate::source::PatchError;
 use uv_client::WrappedReqwestError;
 use uv_distribution_filename::WheelFilenameError;
 use uv_distribution_types::{InstalledDist, InstalledDistError, IsBuildBackendError};
@@ -109,6 +110,18 @@ pub enum Error {
     #[error("Failed to identify base Python interpreter")]
     BaseInterpreter(#[source] std::io::Error),
 
+    // Patch error
+    #[error("Failed to read patch file: `{}`", _0.user_display())]
+    ReadPatch(PathBuf, #[source] std::io::Error),
+    #[error("Hash mismatch for patch file `{}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}", path.user_display())]
+    MismatchedPatchHash {
+        path: PathBuf,
+        expected: HashDigest,
+        actual: HashDigest,
+    },
+    #[error("Failed to apply patch `{}` to `{}`", _0.user_display(), _1)]
+    Patch(PathBuf, String, #[source] PatchError),
+
     /// A generic request middleware error happened while making a request.
     /// Refer to the error message for more details.
     #[error(transparent)]
@@ -150,6 +163,9 @@ pub enum Error {
         actual: String,
     },
 
+    #[error("The index `{index}` requires hashes, but none were provided for: `{distribution}`")]
+    MissingIndexHashes { distribution: String, index: String },
+
     #[error("Hash-checking is not supported for local directories: `{0}`")]
     HashesNotSupportedSourceTree(String),
 
@@ -237,4 +253,25 @@ impl Error {
             }
         }
     }
+
+    /// Returns `true` if the error was caused by a request that couldn't be made because network
+    /// connectivity is disabled, i.e., because the distribution is missing from the cache.
+    pub fn is_offline(&self) -> bool {
+        match self {
+            Self::Client(err) => err.offline_url().is_some(),
+            Self::Git(uv_git::GitResolverError::Git(err)) => matches!(
+                err.downcast_ref::<uv_git::GitError>(),
+                Some(uv_git::GitError::TransportNotAllowed)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Returns the URL that couldn't be fetched because network connectivity is disabled, if any.
+    pub fn offline_url(&self) -> Option<&str> {
+        match self {
+            Self::Client(err) => err.offline_url(),
+            _ => None,
+        }
+    }
 }
@@ -7,7 +7,8 @@ pub use metadata::{
     MetadataError, RequiresDist, SourcedDependencyGroups,
 };
 pub use reporter::Reporter;
-pub use source::prune;
+pub use source::{PatchError, prune};
+pub use verify::{CorruptEntry, Verification, verify};
 
 mod archive;
 mod distribution_database;
@@ -17,3 +18,4 @@ mod index;
 mod metadata;
 mod reporter;
 mod source;
+mod verify;
@@ -0,0 +1,177 @@
+//! The fingerprint of the environment in which a source distribution is built.
+//!
+//! Wheels built from a source distribution depend not only on the source itself, but on the
+//! environment in which it was built: the build settings, the versions of the build backend and
+//! its dependencies, the target interpreter, and the compiler toolchain. The fingerprint captures
+//! each of these inputs, such that built wheels are only reused when the inputs match.
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use std::time::UNIX_EPOCH;
+
+use uv_cache_key::{CacheKey, CacheKeyHasher, cache_digest};
+use uv_configuration::ConfigSettings;
+use uv_normalize::PackageName;
+use uv_static::EnvVars;
+use uv_types::BuildContext;
+
+/// The environment variables that are read by common build backends and compilers, and so may
+/// affect the contents of a built wheel.
+const BUILD_VARIABLES: &[&str] = &[
+    EnvVars::CC,
+    EnvVars::CXX,
+    EnvVars::CFLAGS,
+    EnvVars::CXXFLAGS,
+    EnvVars::CPPFLAGS,
+    EnvVars::LDFLAGS,
+    EnvVars::ARCHFLAGS,
+    EnvVars::MACOSX_DEPLOYMENT_TARGET,
+    EnvVars::RUSTFLAGS,
+];
+
+/// The compiler toolchain of the current process, which is fixed for its lifetime.
+static TOOLCHAIN: LazyLock<Toolchain> = LazyLock::new(Toolchain::from_env);
+
+/// The fingerprint of the environment in which a source distribution is built.
+#[derive(Debug)]
+pub(crate) struct BuildFingerprint {
+    /// The build settings for the package.
+    config_settings: ConfigSettings,
+    /// The resolved build requirements (e.g., `setuptools==80.9.0`), i.e., the versions of the
+    /// build backend and its dependencies that are installed into the build environment.
+    build_requirements: Vec<String>,
+    /// The constraints applied to the build requirements.
+    build_constraints: Vec<String>,
+    /// The identity of the target interpreter, as its most specific compatibility tags.
+    interpreter: String,
+    /// The compiler toolchain.
+    toolchain: &'static Toolchain,
+}
+
+impl BuildFingerprint {
+    /// Compute the [`BuildFingerprint`] for a build of the package with the given
+    /// [`BuildContext`], settings, and resolved build requirements.
+    pub(crate) fn new(
+        build_context: &impl BuildContext,
+        package: Option<&PackageName>,
+        config_settings: ConfigSettings,
+        mut build_requirements: Vec<String>,
+    ) -> Self {
+        build_requirements.sort_unstable();
+
+        let mut build_constraints = package
+            .and_then(|package| build_context.build_constraints_package(package))
+            .unwrap_or_else(|| build_context.build_constraints())
+            .requirements()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        build_constraints.sort_unstable();
+
+        let interpreter = build_context.interpreter();
+        let interpreter = interpreter
+            .tags()
+            .ok()
+            .and_then(|tags| {
+                let (Some(python), Some(abi), Some(platform)) =
+                    (tags.python_tag(), tags.abi_tag(), tags.platform_tag())
+                else {
+                    return None;
+                };
+                Some(format!("{python}-{abi}-{platform}"))
+            })
+            .unwrap_or_else(|| {
+                format!(
+                    "{}-{}",
+                    interpreter.implementation_name(),
+                    interpreter.python_version()
+                )
+            });
+
+        Self {
+            config_settings,
+            build_requirements,
+            build_constraints,
+            interpreter,
+            toolchain: &TOOLCHAIN,
+        }
+    }
+
+    /// Return a digest of the fingerprint, used to scope built wheels in the local cache.
+    pub(crate) fn digest(&self) -> String {
+        cache_digest(self)
+    }
+
+    /// Return a digest of the fingerprint that omits the identity of the compilers, which is
+    /// specific to the current machine, used to share built wheels via the remote cache.
+    pub(crate) fn portable_digest(&self) -> String {
+        cache_digest(&(
+            &self.config_settings,
+            &self.build_requirements,
+            &self.build_constraints,
+            &self.interpreter,
+            &self.toolchain.variables,
+        ))
+    }
+}
+
+impl CacheKey for BuildFingerprint {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        self.config_settings.cache_key(state);
+        self.build_requirements.cache_key(state);
+        self.build_constraints.cache_key(state);
+        self.interpreter.cache_key(state);
+        self.toolchain.variables.cache_key(state);
+        self.toolchain.compilers.cache_key(state);
+    }
+}
+
+/// The compiler toolchain used to build extension modules.
+#[derive(Debug, Default)]
+struct Toolchain {
+    /// The values of the [`BUILD_VARIABLES`] that are set in the environment.
+    variables: BTreeMap<String, String>,
+    /// The identity of the C and C++ compilers, if present.
+    compilers: Vec<String>,
+}
+
+impl Toolchain {
+    /// Detect the [`Toolchain`] from the environment of the current process.
+    fn from_env() -> Self {
+        let variables = BUILD_VARIABLES
+            .iter()
+            .filter_map(|name| {
+                let value = std::env::var(name).ok()?;
+                Some(((*name).to_string(), value))
+            })
+            .collect();
+        let compilers = [(EnvVars::CC, "cc"), (EnvVars::CXX, "c++")]
+            .into_iter()
+            .filter_map(|(name, default)| compiler_identity(name, default))
+            .collect();
+        Self {
+            variables,
+            compilers,
+        }
+    }
+}
+
+/// Return the identity of the compiler set by the given environment variable (or the given
+/// default), as its resolved path, size, and modification time, such that upgrading the compiler
+/// in place invalidates any wheels built with it.
+fn compiler_identity(name: &str, default: &str) -> Option<String> {
+    let command = std::env::var(name).ok();
+    let program = command
+        .as_deref()
+        .and_then(|command| command.split_whitespace().next())
+        .unwrap_or(default);
+    let path = which::which(program).ok()?;
+    let path = fs_err::canonicalize(&path).unwrap_or(path);
+    let metadata = fs_err::metadata(&path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_nanos();
+    Some(format!("{}:{}:{modified}", path.display(), metadata.len()))
+}
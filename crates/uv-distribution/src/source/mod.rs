@@ -10,20 +10,20 @@
 
 use std::borrow::Cow;
 use std::ops::Bound;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use fs_err::tokio as fs;
-use futures::{FutureExt, TryStreamExt};
+use futures::FutureExt;
 use reqwest::{Response, StatusCode};
-use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tokio::io::AsyncSeekExt;
 use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 use uv_redacted::DisplaySafeUrl;
 use zip::ZipArchive;
 
-use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Removal, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Pruner, Removal, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_cache_key::cache_digest;
 use uv_client::{
@@ -32,8 +32,8 @@ use uv_client::{
 use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
-    BuildableSource, DirectorySourceUrl, GitSourceUrl, HashPolicy, Hashed, IndexUrl, PathSourceUrl,
-    SourceDist, SourceUrl,
+    BuildableSource, DirectorySourceUrl, GitSourceUrl, HashPolicy, Hashed, Identifier, IndexUrl,
+    PathSourceUrl, Requirement, SourceDist, SourcePatch, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic};
@@ -42,7 +42,9 @@ use uv_metadata::read_archive_metadata;
 use uv_normalize::PackageName;
 use uv_pep440::{Version, release_specifiers_to_ranges};
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, ResolutionMetadata};
+use uv_pypi_types::{
+    HashAlgorithm, HashDigest, HashDigests, PyProjectToml, ResolutionMetadata, VerbatimParsedUrl,
+};
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
 use uv_workspace::pyproject::ToolUvSources;
 
@@ -50,10 +52,17 @@ use crate::distribution_database::ManagedClient;
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
+use crate::source::fingerprint::BuildFingerprint;
+use crate::source::remote::{RemoteBuild, RemoteEntry, RemoteSource};
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
+pub use crate::source::patch::PatchError;
+
 mod built_wheel_metadata;
+mod fingerprint;
+mod patch;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -75,6 +84,10 @@ pub(crate) const METADATA: &str = "metadata.msgpack";
 /// The directory within each entry under which to store the unpacked source distribution.
 pub(crate) const SOURCE: &str = "src";
 
+/// The name of the file that contains the build requirements declared by a source distribution,
+/// encoded via `MsgPack`.
+pub(crate) const BUILD_REQUIRES: &str = "build-requires.msgpack";
+
 impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     /// Initialize a [`SourceDistributionBuilder`] from a [`BuildContext`].
     pub(crate) fn new(build_context: &'a T) -> Self {
@@ -404,6 +417,196 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
     }
 
+    /// Scope the given cache shard to the environment in which the source distribution is built,
+    /// such that changing any input to the build (e.g., the build settings, the resolved build
+    /// requirements, the interpreter, or the compiler) invalidates the built wheels and metadata
+    /// within it.
+    ///
+    /// `build_requires` are the build requirements declared by the source distribution, as
+    /// returned by [`read_build_requires`].
+    async fn build_shard(
+        &self,
+        source: &BuildableSource<'_>,
+        build_requires: &[String],
+        cache_shard: &CacheShard,
+    ) -> CacheShard {
+        let fingerprint = self.build_fingerprint(source, build_requires).await;
+        cache_shard.shard(fingerprint.digest())
+    }
+
+    /// Compute the [`BuildFingerprint`] for a build of the given source distribution.
+    async fn build_fingerprint(
+        &self,
+        source: &BuildableSource<'_>,
+        build_requires: &[String],
+    ) -> BuildFingerprint {
+        let config_settings = self.config_settings_for(source.name()).into_owned();
+        let build_requirements = self.resolve_build_requires(source, build_requires).await;
+        BuildFingerprint::new(
+            self.build_context,
+            source.name(),
+            config_settings,
+            build_requirements,
+        )
+    }
+
+    /// Resolve the build requirements declared by a source distribution, returning the versions
+    /// of the build backend and its dependencies that a build would install (e.g.,
+    /// `setuptools==80.9.0`).
+    ///
+    /// If the requirements can't be resolved (e.g., when offline), the declared requirements are
+    /// returned instead; the build itself surfaces the error, if any.
+    async fn resolve_build_requires(
+        &self,
+        source: &BuildableSource<'_>,
+        build_requires: &[String],
+    ) -> Vec<String> {
+        let requirements = build_requires
+            .iter()
+            .filter_map(|requirement| {
+                uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement).ok()
+            })
+            .map(Requirement::from)
+            .collect::<Vec<_>>();
+
+        // Push the current distribution onto the build stack, as in the build itself.
+        let mut build_stack = self.build_stack.cloned().unwrap_or_default();
+        if let Some(dist) = source.as_dist() {
+            build_stack.insert(dist.distribution_id());
+        }
+
+        match self
+            .build_context
+            .resolve(&requirements, source.name(), &build_stack)
+            .await
+        {
+            Ok(resolution) => resolution
+                .distributions()
+                .map(ToString::to_string)
+                .collect(),
+            Err(err) => {
+                debug!("Failed to resolve build requirements for {source}: {err}");
+                build_requires.to_vec()
+            }
+        }
+    }
+
+    /// Return the build requirements declared by the source tree at `source_root`, recording them
+    /// in the given cache entry, such that they remain available if the source tree is pruned.
+    async fn record_build_requires(
+        source_root: &Path,
+        subdirectory: Option<&Path>,
+        entry: &CacheEntry,
+    ) -> Result<Vec<String>, Error> {
+        let build_requires = read_build_requires(source_root, subdirectory).await;
+        fs::create_dir_all(entry.dir())
+            .await
+            .map_err(Error::CacheWrite)?;
+        write_atomic(entry.path(), rmp_serde::to_vec(&build_requires)?)
+            .await
+            .map_err(Error::CacheWrite)?;
+        Ok(build_requires)
+    }
+
+    /// Return the build requirements recorded by [`Self::record_build_requires`], if any.
+    async fn recorded_build_requires(entry: &CacheEntry) -> Option<Vec<String>> {
+        let contents = fs::read(entry.path()).await.ok()?;
+        rmp_serde::from_slice(&contents).ok()
+    }
+
+    /// Return the [`SourcePatch`] to apply to the source distribution, if any.
+    fn patch_for(&self, source: &BuildableSource<'_>) -> Option<&'a SourcePatch> {
+        let name = source.name()?;
+        let version = source.version()?;
+        self.build_context.patches().get(name, version)
+    }
+
+    /// Scope the given cache shard to the [`SourcePatch`], if any, such that the patched source
+    /// tree, and the wheels and metadata built from it, are stored separately from those of the
+    /// unpatched source distribution.
+    fn patch_shard(patch: Option<&SourcePatch>, cache_shard: CacheShard) -> CacheShard {
+        match patch {
+            Some(patch) => cache_shard.shard(cache_digest(&patch.hash.to_string())),
+            None => cache_shard,
+        }
+    }
+
+    /// Apply a [`SourcePatch`] to the unpacked source distribution at `source_root`, returning the
+    /// path to the patched source tree within the given (patch-scoped) cache shard.
+    async fn apply_patch(
+        &self,
+        source: &BuildableSource<'_>,
+        patch: &SourcePatch,
+        source_root: &Path,
+        cache_shard: &CacheShard,
+    ) -> Result<PathBuf, Error> {
+        // Verify the hash of the patch file, even if the patched source tree is cached, since the
+        // hash is only declared (rather than computed).
+        let path = self.build_context.patches().path(patch);
+        let contents = fs::read(&path)
+            .await
+            .map_err(|err| Error::ReadPatch(path.clone(), err))?;
+        let mut hasher = Hasher::from(patch.hash.algorithm());
+        hasher.update(&contents);
+        let actual = HashDigest::from(hasher);
+        if actual != patch.hash {
+            return Err(Error::MismatchedPatchHash {
+                path,
+                expected: patch.hash.clone(),
+                actual,
+            });
+        }
+
+        let target = cache_shard.entry(SOURCE).into_path_buf();
+        if target.is_dir() {
+            return Ok(target);
+        }
+
+        let contents = String::from_utf8(contents).map_err(|err| {
+            Error::ReadPatch(
+                path.clone(),
+                std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+            )
+        })?;
+
+        debug!("Applying patch for {source}: {}", path.display());
+
+        // Copy the source tree into a temporary directory, and apply the patch there.
+        let temp_dir = tempfile::tempdir_in(
+            self.build_context
+                .cache()
+                .bucket(CacheBucket::SourceDistributions),
+        )
+        .map_err(Error::CacheWrite)?;
+        let patched = temp_dir.path().join(SOURCE);
+        {
+            let source_root = source_root.to_path_buf();
+            let patched = patched.clone();
+            let source = source.to_string();
+            tokio::task::spawn_blocking(move || {
+                uv_fs::copy_dir_all(&source_root, &patched).map_err(Error::CacheWrite)?;
+                crate::source::patch::apply(&contents, &patched)
+                    .map_err(|err| Error::Patch(path, source, err))
+            })
+            .await??;
+        }
+
+        // Persist it to the cache.
+        fs::create_dir_all(cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        if let Err(err) = rename_with_retry(&patched, &target).await {
+            // If the directory already exists, accept it.
+            if err.kind() == std::io::ErrorKind::AlreadyExists {
+                warn!("Directory already exists: {}", target.display());
+            } else {
+                return Err(Error::CacheWrite(err));
+            }
+        }
+
+        Ok(target)
+    }
+
     /// Build a source distribution from a remote URL.
     async fn url<'data>(
         &self,
@@ -417,7 +620,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -438,48 +641,82 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_dist_entry = cache_shard.entry(SOURCE);
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&&config_settings))
-        };
+        // If the source distribution is patched, scope to the patch.
+        let patch = self.patch_for(source);
+        let patch_shard = Self::patch_shard(patch, cache_shard);
+
+        // Read the declared build requirements, which are recorded alongside the source tree, such
+        // that they remain available if the source tree is pruned.
+        let build_requires_entry = patch_shard.entry(BUILD_REQUIRES);
+        let (revision, source_root, build_requires) =
+            match Self::recorded_build_requires(&build_requires_entry).await {
+                Some(build_requires) => (revision, None, build_requires),
+                None => {
+                    let (revision, source_root) = self
+                        .url_source_root(
+                            source,
+                            ext,
+                            url,
+                            index,
+                            &source_dist_entry,
+                            subdirectory,
+                            patch,
+                            &patch_shard,
+                            revision,
+                            hashes,
+                            client,
+                        )
+                        .await?;
+                    let build_requires = Self::record_build_requires(
+                        &source_root,
+                        subdirectory,
+                        &build_requires_entry,
+                    )
+                    .await?;
+                    (revision, Some(source_root), build_requires)
+                }
+            };
+
+        // Scope to the environment in which the source distribution is built.
+        let cache_shard = self
+            .build_shard(source, &build_requires, &patch_shard)
+            .await;
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .ok()
-            .flatten()
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
-        let revision = if source_dist_entry.path().is_dir() {
-            revision
-        } else {
-            self.heal_url_revision(
-                source,
-                ext,
-                url,
-                index,
-                &source_dist_entry,
-                revision,
-                hashes,
-                client,
-            )
-            .await?
+        let (revision, source_root) = match source_root {
+            Some(source_root) => (revision, source_root),
+            None => {
+                self.url_source_root(
+                    source,
+                    ext,
+                    url,
+                    index,
+                    &source_dist_entry,
+                    subdirectory,
+                    patch,
+                    &patch_shard,
+                    revision,
+                    hashes,
+                    client,
+                )
+                .await?
+            }
         };
 
-        // Validate that the subdirectory exists.
-        if let Some(subdirectory) = subdirectory {
-            if !source_dist_entry.path().join(subdirectory).is_dir() {
-                return Err(Error::MissingSubdirectory(
-                    url.clone(),
-                    subdirectory.to_path_buf(),
-                ));
-            }
+        // Release the lock on the source distribution, and instead lock the build environment, such
+        // that builds of the same source distribution in other environments can proceed
+        // concurrently.
+        drop(lock);
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+
+        // If another process built a compatible wheel while we were waiting, return it.
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
+            return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
         let task = self
@@ -487,14 +724,21 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
-        // Build the source distribution.
+        // Build the source distribution. Patched builds aren't shared via the remote cache.
         let (disk_filename, wheel_filename, metadata) = self
             .build_distribution(
                 source,
-                source_dist_entry.path(),
+                &source_root,
                 subdirectory,
                 &cache_shard,
                 SourceStrategy::Disabled,
+                patch.is_none().then_some(RemoteBuild {
+                    source: RemoteSource::Url {
+                        url,
+                        hashes: revision.hashes(),
+                    },
+                    client,
+                }),
             )
             .await?;
 
@@ -519,6 +763,60 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         })
     }
 
+    /// Ensure that the source tree for a remote source distribution is present in the cache,
+    /// returning the (possibly healed) revision and the root of the (possibly patched) source tree.
+    async fn url_source_root(
+        &self,
+        source: &BuildableSource<'_>,
+        ext: SourceDistExtension,
+        url: &DisplaySafeUrl,
+        index: Option<&IndexUrl>,
+        source_dist_entry: &CacheEntry,
+        subdirectory: Option<&Path>,
+        patch: Option<&SourcePatch>,
+        patch_shard: &CacheShard,
+        revision: Revision,
+        hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<(Revision, PathBuf), Error> {
+        let revision = if source_dist_entry.path().is_dir() {
+            revision
+        } else {
+            self.heal_url_revision(
+                source,
+                ext,
+                url,
+                index,
+                source_dist_entry,
+                revision,
+                hashes,
+                client,
+            )
+            .await?
+        };
+
+        // Validate that the subdirectory exists.
+        if let Some(subdirectory) = subdirectory {
+            if !source_dist_entry.path().join(subdirectory).is_dir() {
+                return Err(Error::MissingSubdirectory(
+                    url.clone(),
+                    subdirectory.to_path_buf(),
+                ));
+            }
+        }
+
+        // If necessary, apply the patch.
+        let source_root = match patch {
+            Some(patch) => {
+                self.apply_patch(source, patch, source_dist_entry.path(), patch_shard)
+                    .await?
+            }
+            None => source_dist_entry.path().to_path_buf(),
+        };
+
+        Ok((revision, source_root))
+    }
+
     /// Build the source distribution's metadata from a local path.
     ///
     /// If the build backend supports `prepare_metadata_for_build_wheel`, this method will avoid
@@ -555,8 +853,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_dist_entry = cache_shard.entry(SOURCE);
 
-        // If the metadata is static, return it.
-        let dynamic =
+        // If the source distribution is patched, scope to the patch.
+        let patch = self.patch_for(source);
+        let cache_shard = Self::patch_shard(patch, cache_shard);
+
+        // If the metadata is static, return it. Since a patch may change the metadata, it's always
+        // built for patched source distributions.
+        let dynamic = if patch.is_some() {
+            false
+        } else {
             match StaticMetadata::read(source, source_dist_entry.path(), subdirectory).await? {
                 StaticMetadata::Some(metadata) => {
                     return Ok(ArchiveMetadata {
@@ -566,7 +871,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }
                 StaticMetadata::Dynamic => true,
                 StaticMetadata::None => false,
-            };
+            }
+        };
 
         // If the cache contains compatible metadata, return it.
         let metadata_entry = cache_shard.entry(METADATA);
@@ -614,23 +920,30 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
+        // If necessary, apply the patch.
+        let source_root = match patch {
+            Some(patch) => {
+                self.apply_patch(source, patch, source_dist_entry.path(), &cache_shard)
+                    .await?
+            }
+            None => source_dist_entry.into_path_buf(),
         };
 
+        // Scope to the environment in which the source distribution is built.
+        let build_requires = Self::record_build_requires(
+            &source_root,
+            subdirectory,
+            &cache_shard.entry(BUILD_REQUIRES),
+        )
+        .await?;
+        let cache_shard = self
+            .build_shard(source, &build_requires, &cache_shard)
+            .await;
+
         // Otherwise, we either need to build the metadata.
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(
-                source,
-                source_dist_entry.path(),
-                subdirectory,
-                SourceStrategy::Disabled,
-            )
+            .build_metadata(source, &source_root, subdirectory, SourceStrategy::Disabled)
             .boxed_local()
             .await?
         {
@@ -663,14 +976,21 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
-        // Build the source distribution.
+        // Build the source distribution. Patched builds aren't shared via the remote cache.
         let (_disk_filename, _wheel_filename, metadata) = self
             .build_distribution(
                 source,
-                source_dist_entry.path(),
+                &source_root,
                 subdirectory,
                 &cache_shard,
                 SourceStrategy::Disabled,
+                patch.is_none().then_some(RemoteBuild {
+                    source: RemoteSource::Url {
+                        url: DisplaySafeUrl::ref_cast(url),
+                        hashes: revision.hashes(),
+                    },
+                    client,
+                }),
             )
             .await?;
 
@@ -746,7 +1066,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 let entry = cache_shard.shard(revision.id()).entry(SOURCE);
                 let algorithms = hashes.algorithms();
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        url,
+                        client.unmanaged,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                    )
                     .await?;
 
                 Ok(revision.with_hashes(HashDigests::from(hashes)))
@@ -756,7 +1084,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         };
         let req = Self::request(DisplaySafeUrl::from(url.clone()), client.unmanaged)?;
         let revision = client
-            .managed(|client| {
+            .managed_for(url, |client| {
                 client.cached_client().get_serde_with_retry(
                     req,
                     &cache_entry,
@@ -775,7 +1103,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             Ok(revision)
         } else {
             client
-                .managed(async |client| {
+                .managed_for(url, async |client| {
                     client
                         .cached_client()
                         .skip_cache_with_retry(
@@ -803,7 +1131,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -827,31 +1155,73 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_entry = cache_shard.entry(SOURCE);
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
-        };
+        // If the source distribution is patched, scope to the patch.
+        let patch = self.patch_for(source);
+        let patch_shard = Self::patch_shard(patch, cache_shard);
+
+        // Read the declared build requirements, which are recorded alongside the source tree, such
+        // that they remain available if the source tree is pruned.
+        let build_requires_entry = patch_shard.entry(BUILD_REQUIRES);
+        let (revision, source_root, build_requires) =
+            match Self::recorded_build_requires(&build_requires_entry).await {
+                Some(build_requires) => (revision, None, build_requires),
+                None => {
+                    let (revision, source_root) = self
+                        .archive_source_root(
+                            source,
+                            resource,
+                            &source_entry,
+                            patch,
+                            &patch_shard,
+                            revision,
+                            hashes,
+                        )
+                        .await?;
+                    let build_requires =
+                        Self::record_build_requires(&source_root, None, &build_requires_entry)
+                            .await?;
+                    (revision, Some(source_root), build_requires)
+                }
+            };
+
+        // Scope to the environment in which the source distribution is built.
+        let cache_shard = self
+            .build_shard(source, &build_requires, &patch_shard)
+            .await;
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .ok()
-            .flatten()
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
             return Ok(built_wheel);
         }
 
         // Otherwise, we need to build a wheel, which requires a source distribution.
-        let revision = if source_entry.path().is_dir() {
-            revision
-        } else {
-            self.heal_archive_revision(source, resource, &source_entry, revision, hashes)
+        let (revision, source_root) = match source_root {
+            Some(source_root) => (revision, source_root),
+            None => {
+                self.archive_source_root(
+                    source,
+                    resource,
+                    &source_entry,
+                    patch,
+                    &patch_shard,
+                    revision,
+                    hashes,
+                )
                 .await?
+            }
         };
 
+        // Release the lock on the source distribution, and instead lock the build environment, such
+        // that builds of the same source distribution in other environments can proceed
+        // concurrently.
+        drop(lock);
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+
+        // If another process built a compatible wheel while we were waiting, return it.
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
+            return Ok(built_wheel);
+        }
+
         let task = self
             .reporter
             .as_ref()
@@ -860,10 +1230,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let (disk_filename, filename, metadata) = self
             .build_distribution(
                 source,
-                source_entry.path(),
+                &source_root,
                 None,
                 &cache_shard,
                 SourceStrategy::Disabled,
+                None,
             )
             .await?;
 
@@ -888,6 +1259,38 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         })
     }
 
+    /// Ensure that the source tree for a local source distribution archive is present in the
+    /// cache, returning the (possibly healed) revision and the root of the (possibly patched)
+    /// source tree.
+    async fn archive_source_root(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &PathSourceUrl<'_>,
+        source_entry: &CacheEntry,
+        patch: Option<&SourcePatch>,
+        patch_shard: &CacheShard,
+        revision: Revision,
+        hashes: HashPolicy<'_>,
+    ) -> Result<(Revision, PathBuf), Error> {
+        let revision = if source_entry.path().is_dir() {
+            revision
+        } else {
+            self.heal_archive_revision(source, resource, source_entry, revision, hashes)
+                .await?
+        };
+
+        // If necessary, apply the patch.
+        let source_root = match patch {
+            Some(patch) => {
+                self.apply_patch(source, patch, source_entry.path(), patch_shard)
+                    .await?
+            }
+            None => source_entry.path().to_path_buf(),
+        };
+
+        Ok((revision, source_root))
+    }
+
     /// Build the source distribution's metadata from a local archive (e.g., `.tar.gz` or `.zip`).
     ///
     /// If the build backend supports `prepare_metadata_for_build_wheel`, this method will avoid
@@ -920,16 +1323,25 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_entry = cache_shard.entry(SOURCE);
 
-        // If the metadata is static, return it.
-        let dynamic = match StaticMetadata::read(source, source_entry.path(), None).await? {
-            StaticMetadata::Some(metadata) => {
-                return Ok(ArchiveMetadata {
-                    metadata: Metadata::from_metadata23(metadata),
-                    hashes: revision.into_hashes(),
-                });
+        // If the source distribution is patched, scope to the patch.
+        let patch = self.patch_for(source);
+        let cache_shard = Self::patch_shard(patch, cache_shard);
+
+        // If the metadata is static, return it. Since a patch may change the metadata, it's always
+        // built for patched source distributions.
+        let dynamic = if patch.is_some() {
+            false
+        } else {
+            match StaticMetadata::read(source, source_entry.path(), None).await? {
+                StaticMetadata::Some(metadata) => {
+                    return Ok(ArchiveMetadata {
+                        metadata: Metadata::from_metadata23(metadata),
+                        hashes: revision.into_hashes(),
+                    });
+                }
+                StaticMetadata::Dynamic => true,
+                StaticMetadata::None => false,
             }
-            StaticMetadata::Dynamic => true,
-            StaticMetadata::None => false,
         };
 
         // If the cache contains compatible metadata, return it.
@@ -959,9 +1371,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
         };
 
+        // If necessary, apply the patch.
+        let source_root = match patch {
+            Some(patch) => {
+                self.apply_patch(source, patch, source_entry.path(), &cache_shard)
+                    .await?
+            }
+            None => source_entry.into_path_buf(),
+        };
+
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_entry.path(), None, SourceStrategy::Disabled)
+            .build_metadata(source, &source_root, None, SourceStrategy::Disabled)
             .boxed_local()
             .await?
         {
@@ -989,13 +1410,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             });
         }
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
-        };
+        // Scope to the environment in which the source distribution is built.
+        let build_requires =
+            Self::record_build_requires(&source_root, None, &cache_shard.entry(BUILD_REQUIRES))
+                .await?;
+        let cache_shard = self
+            .build_shard(source, &build_requires, &cache_shard)
+            .await;
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -1006,10 +1427,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let (_disk_filename, _filename, metadata) = self
             .build_distribution(
                 source,
-                source_entry.path(),
+                &source_root,
                 None,
                 &cache_shard,
                 SourceStrategy::Disabled,
+                None,
             )
             .await?;
 
@@ -1117,7 +1539,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        let lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -1131,24 +1553,28 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // freshness, since entries have to be fresher than the revision itself.
         let cache_shard = cache_shard.shard(revision.id());
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
-        };
+        // Scope to the environment in which the source distribution is built.
+        let build_requires = read_build_requires(&resource.install_path, None).await;
+        let cache_shard = self
+            .build_shard(source, &build_requires, &cache_shard)
+            .await;
 
         // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .ok()
-            .flatten()
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
+            return Ok(built_wheel);
+        }
+
+        // Otherwise, we need to build a wheel. Release the lock on the source distribution, and
+        // instead lock the build environment, such that builds of the same source distribution in
+        // other environments can proceed concurrently.
+        drop(lock);
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+
+        // If another process built a compatible wheel while we were waiting, return it.
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
             return Ok(built_wheel);
         }
 
-        // Otherwise, we need to build a wheel.
         let task = self
             .reporter
             .as_ref()
@@ -1161,6 +1587,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 self.build_context.sources(),
+                None,
             )
             .await?;
 
@@ -1319,13 +1746,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
-        };
+        // Scope to the environment in which the source distribution is built.
+        let build_requires = read_build_requires(&resource.install_path, None).await;
+        let cache_shard = self
+            .build_shard(source, &build_requires, &cache_shard)
+            .await;
 
         // Otherwise, we need to build a wheel.
         let task = self
@@ -1340,6 +1765,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 self.build_context.sources(),
+                None,
             )
             .await?;
 
@@ -1519,25 +1945,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             CacheBucket::SourceDistributions,
             WheelCache::Git(resource.url, git_sha.as_short_str()).root(),
         );
-        let metadata_entry = cache_shard.entry(METADATA);
 
-        // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        // Scope to the environment in which the source distribution is built.
+        let build_requires = read_build_requires(fetch.path(), resource.subdirectory).await;
+        let cache_shard = self
+            .build_shard(source, &build_requires, &cache_shard)
+            .await;
+        let metadata_entry = cache_shard.entry(METADATA);
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
-        };
+        // If the cache contains a compatible wheel, return it. Since the commit is immutable, and
+        // built wheels are persisted atomically, there's no need to acquire a lock to read them.
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
+            return Ok(built_wheel);
+        }
 
-        // If the cache contains a compatible wheel, return it.
-        if let Some(built_wheel) = BuiltWheelMetadata::find_in_cache(tags, &cache_shard)
-            .ok()
-            .flatten()
-            .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
-        {
+        // Otherwise, acquire the advisory lock on the build environment, and check again, in case
+        // another process built a compatible wheel while we were waiting.
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+        if let Some(built_wheel) = find_built_wheel(source, tags, &cache_shard) {
             return Ok(built_wheel);
         }
 
@@ -1553,6 +1978,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 resource.subdirectory,
                 &cache_shard,
                 self.build_context.sources(),
+                Some(RemoteBuild {
+                    source: RemoteSource::Git {
+                        repository: resource.git.repository(),
+                        precise: git_sha,
+                    },
+                    client,
+                }),
             )
             .await?;
 
@@ -1709,7 +2141,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             CacheBucket::SourceDistributions,
             WheelCache::Git(resource.url, git_sha.as_short_str()).root(),
         );
-        let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
         let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
@@ -1745,6 +2176,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 StaticMetadata::None => false,
             };
 
+        // Scope to the environment in which the source distribution is built.
+        let build_requires = read_build_requires(fetch.path(), resource.subdirectory).await;
+        let cache_shard = self
+            .build_shard(source, &build_requires, &cache_shard)
+            .await;
+        let metadata_entry = cache_shard.entry(METADATA);
+
         // If the cache contains compatible metadata, return it.
         if self
             .build_context
@@ -1827,14 +2265,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
-        // If there are build settings, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let cache_shard = if config_settings.is_empty() {
-            cache_shard
-        } else {
-            cache_shard.shard(cache_digest(&config_settings))
-        };
-
         // Otherwise, we need to build a wheel.
         let task = self
             .reporter
@@ -1848,6 +2278,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 resource.subdirectory,
                 &cache_shard,
                 self.build_context.sources(),
+                Some(RemoteBuild {
+                    source: RemoteSource::Git {
+                        repository: resource.git.repository(),
+                        precise: git_sha,
+                    },
+                    client,
+                }),
             )
             .await?;
 
@@ -2132,7 +2569,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 };
 
                 let hashes = self
-                    .download_archive(response, source, ext, entry.path(), &algorithms)
+                    .download_archive(
+                        response,
+                        url,
+                        client.unmanaged,
+                        source,
+                        ext,
+                        entry.path(),
+                        &algorithms,
+                    )
                     .await?;
                 for existing in revision.hashes() {
                     if !hashes.contains(existing) {
@@ -2145,7 +2590,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .instrument(info_span!("download", source_dist = %source))
         };
         client
-            .managed(async |client| {
+            .managed_for(url, async |client| {
                 client
                     .cached_client()
                     .skip_cache_with_retry(
@@ -2167,6 +2612,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     async fn download_archive(
         &self,
         response: Response,
+        url: &Url,
+        client: &RegistryClient,
         source: &BuildableSource<'_>,
         ext: SourceDistExtension,
         target: &Path,
@@ -2178,10 +2625,33 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .bucket(CacheBucket::SourceDistributions),
         )
         .map_err(Error::CacheWrite)?;
-        let reader = response
-            .bytes_stream()
-            .map_err(std::io::Error::other)
-            .into_async_read();
+
+        // Download the source distribution to a temporary file, resuming the download if it's
+        // interrupted. The hashes are computed from the file (rather than the response), such that
+        // they cover any resumed content.
+        let span = info_span!("download_source_dist", source_dist = %source);
+        let temp_file = tempfile::tempfile_in(
+            self.build_context
+                .cache()
+                .bucket(CacheBucket::SourceDistributions),
+        )
+        .map_err(Error::CacheWrite)?;
+        let mut file = tokio::fs::File::from_std(temp_file);
+        client
+            .cached_client()
+            .uncached()
+            .download_to_file(
+                &Self::request(DisplaySafeUrl::from(url.clone()), client)?,
+                response,
+                &mut file,
+                |_| {},
+            )
+            .await
+            .map_err(Error::CacheWrite)?;
+        file.seek(std::io::SeekFrom::Start(0))
+            .await
+            .map_err(Error::CacheWrite)?;
+        drop(span);
 
         // Create a hasher for each hash algorithm.
         let mut hashers = algorithms
@@ -2189,14 +2659,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .copied()
             .map(Hasher::from)
             .collect::<Vec<_>>();
-        let mut hasher = uv_extract::hash::HashReader::new(reader.compat(), &mut hashers);
+        let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
 
-        // Download and unzip the source distribution into a temporary directory.
-        let span = info_span!("download_source_dist", source_dist = %source);
+        // Unzip the source distribution into a temporary directory.
         uv_extract::stream::archive(&mut hasher, ext, temp_dir.path())
             .await
             .map_err(|err| Error::Extract(source.to_string(), err))?;
-        drop(span);
 
         // If necessary, exhaust the reader to compute the hash.
         if !algorithms.is_empty() {
@@ -2301,6 +2769,41 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(hashes)
     }
 
+    /// Return the [`RemoteEntry`] for the wheel built from the given source, if a remote cache is
+    /// configured and the source can be shared.
+    async fn remote_entry<'remote>(
+        &'remote self,
+        source: &BuildableSource<'_>,
+        remote: Option<RemoteBuild<'remote>>,
+        source_root: &Path,
+        subdirectory: Option<&Path>,
+    ) -> Option<RemoteEntry<'remote>> {
+        let remote = remote?;
+        let cache = self.build_context.cache().remote()?;
+
+        // Editable builds reference the local source tree, and so can't be shared.
+        if source.is_editable() {
+            return None;
+        }
+
+        if remote.client.unmanaged.connectivity().is_offline() {
+            return None;
+        }
+
+        // With index isolation, scope the entry to the index that provided the source
+        // distribution.
+        let index = if self.build_context.cache().index_isolation() {
+            source.as_dist().and_then(SourceDist::index)
+        } else {
+            None
+        };
+
+        let tags = self.build_context.interpreter().tags().ok()?;
+        let build_requires = read_build_requires(source_root, subdirectory).await;
+        let fingerprint = self.build_fingerprint(source, &build_requires).await;
+        RemoteEntry::new(cache, remote, subdirectory, &fingerprint, tags, index)
+    }
+
     /// Build a source distribution, storing the built wheel in the cache.
     ///
     /// Returns the un-normalized disk filename, the parsed, normalized filename and the metadata
@@ -2312,6 +2815,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         subdirectory: Option<&Path>,
         cache_shard: &CacheShard,
         source_strategy: SourceStrategy,
+        remote: Option<RemoteBuild<'_>>,
     ) -> Result<(String, WheelFilename, ResolutionMetadata), Error> {
         debug!("Building: {source}");
 
@@ -2340,8 +2844,31 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // If a remote cache is configured, fetch the wheel from it instead of building.
+        let remote = self
+            .remote_entry(source, remote, source_root, subdirectory)
+            .await;
+        let fetched = if let Some(remote) = remote.as_ref() {
+            match remote.fetch(temp_dir.path()).await {
+                Ok(Some(disk_filename)) => {
+                    debug!("Fetched `{disk_filename}` from the remote cache for: {source}");
+                    Some(disk_filename)
+                }
+                Ok(None) => None,
+                Err(err) => {
+                    warn!("Failed to fetch {source} from the remote cache: {err}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let uploadable = fetched.is_none() && remote.as_ref().is_some_and(RemoteEntry::upload);
+
         // Try a direct build if that isn't disabled and the uv build backend is used.
-        let disk_filename = if let Some(name) = self
+        let disk_filename = if let Some(disk_filename) = fetched {
+            disk_filename
+        } else if let Some(name) = self
             .build_context
             .direct_build(
                 source_root,
@@ -2437,6 +2964,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         validate_metadata(source, &metadata)?;
         validate_filename(&filename, &metadata)?;
 
+        // Share the wheel via the remote cache.
+        if uploadable {
+            if let Some(remote) = remote.as_ref() {
+                if let Err(err) = remote
+                    .store(&temp_dir.path().join(&disk_filename), &disk_filename)
+                    .await
+                {
+                    warn!("Failed to upload {source} to the remote cache: {err}");
+                }
+            }
+        }
+
         // Move the wheel to the cache.
         rename_with_retry(
             temp_dir.path().join(&disk_filename),
@@ -2572,7 +3111,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 }
 
 /// Prune any unused source distributions from the cache.
-pub fn prune(cache: &Cache) -> Result<Removal, Error> {
+pub fn prune(cache: &Cache, pruner: &mut Pruner) -> Result<Removal, Error> {
     let mut removal = Removal::default();
 
     let bucket = cache.bucket(CacheBucket::SourceDistributions);
@@ -2600,7 +3139,7 @@ pub fn prune(cache: &Cache) -> Result<Removal, Error> {
                                     sibling.path().display()
                                 );
                                 removal +=
-                                    uv_cache::rm_rf(sibling.path()).map_err(Error::CacheWrite)?;
+                                    pruner.rm_rf(sibling.path()).map_err(Error::CacheWrite)?;
                             }
                         }
                     }
@@ -2623,7 +3162,7 @@ pub fn prune(cache: &Cache) -> Result<Removal, Error> {
                                     sibling.path().display()
                                 );
                                 removal +=
-                                    uv_cache::rm_rf(sibling.path()).map_err(Error::CacheWrite)?;
+                                    pruner.rm_rf(sibling.path()).map_err(Error::CacheWrite)?;
                             }
                         }
                     }
@@ -2780,6 +3319,47 @@ fn has_sources(content: &str) -> Result<bool, toml::de::Error> {
     Ok(false)
 }
 
+/// Return the build requirements declared in the `build-system.requires` table of the source
+/// tree's `pyproject.toml`.
+///
+/// As in the build frontend, a source tree without a `pyproject.toml` (or without a
+/// `build-system` table) falls back to `setuptools`.
+async fn read_build_requires(source_tree: &Path, subdirectory: Option<&Path>) -> Vec<String> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct PyProjectToml {
+        build_system: Option<BuildSystem>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct BuildSystem {
+        requires: Vec<String>,
+    }
+
+    let pyproject_toml = match subdirectory {
+        Some(subdirectory) => source_tree.join(subdirectory).join("pyproject.toml"),
+        None => source_tree.join("pyproject.toml"),
+    };
+    let build_system = match fs::read_to_string(&pyproject_toml).await {
+        Ok(content) => match toml::from_str::<PyProjectToml>(&content) {
+            Ok(pyproject_toml) => pyproject_toml.build_system,
+            Err(err) => {
+                debug!(
+                    "Failed to read build requirements from: {} ({err})",
+                    pyproject_toml.display()
+                );
+                None
+            }
+        },
+        Err(_) => None,
+    };
+
+    match build_system {
+        Some(build_system) => build_system.requires,
+        None => vec!["setuptools >= 40.8.0".to_string()],
+    }
+}
+
 /// Validate that the source distribution matches the built metadata.
 fn validate_metadata(
     source: &BuildableSource<'_>,
@@ -2900,6 +3480,19 @@ impl LocalRevisionPointer {
     }
 }
 
+/// Return the compatible wheel built from the given source distribution in the cache shard, if
+/// any.
+fn find_built_wheel(
+    source: &BuildableSource<'_>,
+    tags: &Tags,
+    cache_shard: &CacheShard,
+) -> Option<BuiltWheelMetadata> {
+    BuiltWheelMetadata::find_in_cache(tags, cache_shard)
+        .ok()
+        .flatten()
+        .filter(|built_wheel| built_wheel.matches(source.name(), source.version()))
+}
+
 /// Read the [`ResolutionMetadata`] from a source distribution's `PKG-INFO` file, if it uses Metadata 2.2
 /// or later _and_ none of the required fields (`Requires-Python`, `Requires-Dist`, and
 /// `Provides-Extra`) are marked as dynamic.
@@ -0,0 +1,478 @@
+//! Apply patch files to unpacked source distributions.
+//!
+//! Patches are expected in the unified diff format, as produced by `diff -u` or `git diff`. As with
+//! `patch -p1`, the first component of each path in the patch is stripped, such that the remaining
+//! path is relative to the root of the source distribution.
+
+use std::path::{Component, Path, PathBuf};
+
+use uv_fs::Simplified;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PatchError {
+    #[error("Malformed patch at line {0}: {1}")]
+    Malformed(usize, String),
+    #[error("The patch does not modify any files")]
+    Empty,
+    #[error("The patch refers to a path outside of the source distribution: `{0}`")]
+    InvalidPath(String),
+    #[error("Hunk #{hunk} does not apply to `{path}`")]
+    HunkMismatch { path: String, hunk: usize },
+    #[error("Failed to read `{}`", _0.user_display())]
+    Read(PathBuf, #[source] std::io::Error),
+    #[error("Failed to write `{}`", _0.user_display())]
+    Write(PathBuf, #[source] std::io::Error),
+}
+
+/// A line within a [`Hunk`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Line<'a> {
+    /// A line that's present before and after the change.
+    Context(&'a str),
+    /// A line that's removed by the change.
+    Remove(&'a str),
+    /// A line that's added by the change.
+    Add(&'a str),
+}
+
+/// A contiguous change to a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hunk<'a> {
+    /// The (one-based) line in the original file at which the hunk starts or, for a hunk that only
+    /// adds lines, the line after which the lines are inserted (as in `@@ -5,0 +6,2 @@`).
+    start: usize,
+    /// The lines of the hunk.
+    lines: Vec<Line<'a>>,
+}
+
+impl Hunk<'_> {
+    /// The lines expected in the original file.
+    fn old_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Context(line) | Line::Remove(line) => Some(*line),
+            Line::Add(_) => None,
+        })
+    }
+
+    /// The lines present in the modified file.
+    fn new_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Context(line) | Line::Add(line) => Some(*line),
+            Line::Remove(_) => None,
+        })
+    }
+}
+
+/// The changes to a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FilePatch<'a> {
+    /// The path of the original file, or `None` if the file is created.
+    old: Option<&'a str>,
+    /// The path of the modified file, or `None` if the file is deleted.
+    new: Option<&'a str>,
+    /// The hunks to apply to the file.
+    hunks: Vec<Hunk<'a>>,
+}
+
+/// Apply a patch in the unified diff format to the source tree at the given root.
+pub(crate) fn apply(patch: &str, root: &Path) -> Result<(), PatchError> {
+    for file in parse(patch)? {
+        apply_file(&file, root)?;
+    }
+    Ok(())
+}
+
+/// Parse a patch in the unified diff format.
+fn parse(patch: &str) -> Result<Vec<FilePatch<'_>>, PatchError> {
+    let lines = patch.lines().collect::<Vec<_>>();
+    let mut files = Vec::new();
+
+    let mut index = 0;
+    while index < lines.len() {
+        // Skip any lines preceding the file header (e.g., `diff --git` or `index` lines).
+        let Some(old) = lines[index].strip_prefix("--- ") else {
+            index += 1;
+            continue;
+        };
+        let Some(new) = lines
+            .get(index + 1)
+            .and_then(|line| line.strip_prefix("+++ "))
+        else {
+            return Err(PatchError::Malformed(
+                index + 2,
+                "expected a `+++` line after the `---` line".to_string(),
+            ));
+        };
+        index += 2;
+
+        let mut hunks = Vec::new();
+        while let Some(header) = lines.get(index).and_then(|line| line.strip_prefix("@@ ")) {
+            let (start, mut old_count, mut new_count) =
+                parse_hunk_header(header).ok_or_else(|| {
+                    PatchError::Malformed(index + 1, format!("invalid hunk header: `@@ {header}`"))
+                })?;
+            index += 1;
+
+            let mut hunk_lines = Vec::new();
+            while old_count > 0 || new_count > 0 {
+                let Some(line) = lines.get(index) else {
+                    return Err(PatchError::Malformed(
+                        index + 1,
+                        "unexpected end of hunk".to_string(),
+                    ));
+                };
+                // Some editors strip the trailing whitespace from empty context lines.
+                let line: &str = if line.is_empty() { " " } else { line };
+                let mut chars = line.chars();
+                let marker = chars.next();
+                let content = chars.as_str();
+                match marker {
+                    Some(' ') if old_count > 0 && new_count > 0 => {
+                        old_count -= 1;
+                        new_count -= 1;
+                        hunk_lines.push(Line::Context(content));
+                    }
+                    Some('-') if old_count > 0 => {
+                        old_count -= 1;
+                        hunk_lines.push(Line::Remove(content));
+                    }
+                    Some('+') if new_count > 0 => {
+                        new_count -= 1;
+                        hunk_lines.push(Line::Add(content));
+                    }
+                    // Ignore `\ No newline at end of file` markers.
+                    Some('\\') => {}
+                    _ => {
+                        return Err(PatchError::Malformed(
+                            index + 1,
+                            format!("unexpected line in hunk: `{line}`"),
+                        ));
+                    }
+                }
+                index += 1;
+            }
+
+            // Skip a trailing `\ No newline at end of file` marker.
+            if lines.get(index).is_some_and(|line| line.starts_with('\\')) {
+                index += 1;
+            }
+
+            hunks.push(Hunk {
+                start,
+                lines: hunk_lines,
+            });
+        }
+
+        files.push(FilePatch {
+            old: parse_path(old),
+            new: parse_path(new),
+            hunks,
+        });
+    }
+
+    if files.is_empty() {
+        return Err(PatchError::Empty);
+    }
+
+    Ok(files)
+}
+
+/// Parse a hunk header (e.g., `-1,3 +1,4 @@`), returning the starting line in the original file,
+/// and the number of lines in the original and modified file.
+fn parse_hunk_header(header: &str) -> Option<(usize, usize, usize)> {
+    let (ranges, _) = header.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let (start, old_count) = parse_range(old.strip_prefix('-')?)?;
+    let (_, new_count) = parse_range(new.strip_prefix('+')?)?;
+    Some((start, old_count, new_count))
+}
+
+/// Parse a hunk range (e.g., `1,3` or `1`), returning the start and the number of lines.
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// Parse a path from a file header, stripping any timestamp and the first path component (as in
+/// `patch -p1`).
+fn parse_path(header: &str) -> Option<&str> {
+    let path = header.split('\t').next().unwrap_or(header).trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(path.split_once('/').map_or(path, |(_, path)| path))
+}
+
+/// Resolve a path from the patch against the root of the source tree, rejecting any paths that
+/// would escape it.
+fn resolve(root: &Path, path: &str) -> Result<PathBuf, PatchError> {
+    let relative = Path::new(path);
+    if relative.as_os_str().is_empty()
+        || !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(PatchError::InvalidPath(path.to_string()));
+    }
+    Ok(root.join(relative))
+}
+
+/// Apply the changes for a single file to the source tree at the given root.
+fn apply_file(file: &FilePatch, root: &Path) -> Result<(), PatchError> {
+    let (contents, crlf, trailing_newline) = if let Some(old) = file.old {
+        let path = resolve(root, old)?;
+        let contents =
+            fs_err::read_to_string(&path).map_err(|err| PatchError::Read(path.clone(), err))?;
+        let crlf = contents.contains("\r\n");
+        let trailing_newline = contents.is_empty() || contents.ends_with('\n');
+        (contents, crlf, trailing_newline)
+    } else {
+        (String::new(), false, true)
+    };
+
+    let mut lines = contents.lines().collect::<Vec<_>>();
+
+    // Apply each hunk, tracking the offset introduced by the preceding hunks, and allowing the
+    // hunk to match at a different line than the one recorded in the patch (e.g., if the file
+    // changed slightly since the patch was created).
+    let mut added = 0;
+    let mut removed = 0;
+    let mut minimum = 0;
+    for (number, hunk) in file.hunks.iter().enumerate() {
+        let old = hunk.old_lines().collect::<Vec<_>>();
+        let new = hunk.new_lines().collect::<Vec<_>>();
+
+        // A hunk that only adds lines records the line after which they're inserted, rather than
+        // the first line of the hunk.
+        let start = if old.is_empty() {
+            hunk.start
+        } else {
+            hunk.start.saturating_sub(1)
+        };
+        let expected = (start + added).saturating_sub(removed);
+        let position =
+            find(&lines, &old, expected, minimum).ok_or_else(|| PatchError::HunkMismatch {
+                path: file.old.or(file.new).unwrap_or_default().to_string(),
+                hunk: number + 1,
+            })?;
+
+        lines.splice(position..position + old.len(), new.iter().copied());
+
+        added += new.len();
+        removed += old.len();
+        minimum = position + new.len();
+    }
+
+    // If the file was deleted, remove it.
+    let Some(new) = file.new else {
+        if let Some(old) = file.old {
+            let path = resolve(root, old)?;
+            fs_err::remove_file(&path).map_err(|err| PatchError::Write(path.clone(), err))?;
+        }
+        return Ok(());
+    };
+
+    let separator = if crlf { "\r\n" } else { "\n" };
+    let mut contents = lines.join(separator);
+    if trailing_newline && !lines.is_empty() {
+        contents.push_str(separator);
+    }
+
+    let path = resolve(root, new)?;
+    if let Some(parent) = path.parent() {
+        fs_err::create_dir_all(parent).map_err(|err| PatchError::Write(path.clone(), err))?;
+    }
+    fs_err::write(&path, contents).map_err(|err| PatchError::Write(path.clone(), err))?;
+
+    // If the file was renamed, remove the original.
+    if let Some(old) = file.old {
+        if old != new {
+            let path = resolve(root, old)?;
+            fs_err::remove_file(&path).map_err(|err| PatchError::Write(path.clone(), err))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the position at which the given lines appear in the file, searching outwards from the
+/// expected position, and never before the minimum position.
+fn find(lines: &[&str], needle: &[&str], expected: usize, minimum: usize) -> Option<usize> {
+    let matches = |position: usize| {
+        position >= minimum
+            && lines
+                .get(position..position + needle.len())
+                .is_some_and(|window| {
+                    window
+                        .iter()
+                        .zip(needle)
+                        .all(|(line, expected)| line.trim_end_matches('\r') == *expected)
+                })
+    };
+
+    let expected = expected.clamp(minimum, lines.len());
+    for distance in 0..=lines.len() {
+        if matches(expected + distance) {
+            return Some(expected + distance);
+        }
+        if let Some(position) = expected.checked_sub(distance) {
+            if distance > 0 && matches(position) {
+                return Some(position);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Result;
+
+    #[test]
+    fn modify() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::create_dir_all(temp_dir.path().join("src"))?;
+        fs_err::write(
+            temp_dir.path().join("src").join("module.py"),
+            "import os\n\n\ndef main():\n    return 1\n",
+        )?;
+
+        let patch = indoc::indoc! {r"
+            diff --git a/src/module.py b/src/module.py
+            index 1234567..89abcde 100644
+            --- a/src/module.py
+            +++ b/src/module.py
+            @@ -2,4 +2,4 @@ import os
+
+
+             def main():
+            -    return 1
+            +    return 2
+        "};
+        apply(patch, temp_dir.path())?;
+
+        let contents = fs_err::read_to_string(temp_dir.path().join("src").join("module.py"))?;
+        assert_eq!(contents, "import os\n\n\ndef main():\n    return 2\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::write(temp_dir.path().join("setup.py"), "a\nb\nc\nd\ne\n")?;
+
+        // The hunk is recorded at the wrong line, but still applies.
+        let patch = indoc::indoc! {r"
+            --- foo-1.0.0/setup.py
+            +++ foo-1.0.0.patched/setup.py
+            @@ -1,2 +1,3 @@
+             c
+            +c2
+             d
+        "};
+        apply(patch, temp_dir.path())?;
+
+        let contents = fs_err::read_to_string(temp_dir.path().join("setup.py"))?;
+        assert_eq!(contents, "a\nb\nc\nc2\nd\ne\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::write(temp_dir.path().join("setup.py"), "a\nb\nc\nd\ne\nf\n")?;
+
+        // A hunk without context inserts the lines after the recorded line.
+        let patch = indoc::indoc! {r"
+            --- a/setup.py
+            +++ b/setup.py
+            @@ -5,0 +6,2 @@
+            +e2
+            +e3
+        "};
+        apply(patch, temp_dir.path())?;
+
+        let contents = fs_err::read_to_string(temp_dir.path().join("setup.py"))?;
+        assert_eq!(contents, "a\nb\nc\nd\ne\ne2\ne3\nf\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_and_delete() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::write(temp_dir.path().join("old.txt"), "old\n")?;
+
+        let patch = indoc::indoc! {r"
+            --- /dev/null
+            +++ b/pkg/new.txt
+            @@ -0,0 +1,2 @@
+            +hello
+            +world
+            --- a/old.txt
+            +++ /dev/null
+            @@ -1 +0,0 @@
+            -old
+        "};
+        apply(patch, temp_dir.path())?;
+
+        let contents = fs_err::read_to_string(temp_dir.path().join("pkg").join("new.txt"))?;
+        assert_eq!(contents, "hello\nworld\n");
+        assert!(!temp_dir.path().join("old.txt").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatch() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::write(temp_dir.path().join("setup.py"), "a\nb\n")?;
+
+        let patch = indoc::indoc! {r"
+            --- a/setup.py
+            +++ b/setup.py
+            @@ -1,2 +1,2 @@
+             a
+            -c
+            +d
+        "};
+        let err = apply(patch, temp_dir.path()).unwrap_err();
+        assert_eq!(err.to_string(), "Hunk #1 does not apply to `setup.py`");
+
+        // The file is left untouched.
+        let contents = fs_err::read_to_string(temp_dir.path().join("setup.py"))?;
+        assert_eq!(contents, "a\nb\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn escape() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let patch = indoc::indoc! {r"
+            --- /dev/null
+            +++ b/../escape.txt
+            @@ -0,0 +1 @@
+            +escape
+        "};
+        let err = apply(patch, temp_dir.path()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The patch refers to a path outside of the source distribution: `../escape.txt`"
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let err = apply("Not a patch\n", temp_dir.path()).unwrap_err();
+        assert_eq!(err.to_string(), "The patch does not modify any files");
+    }
+}
@@ -0,0 +1,298 @@
+//! A remote cache for wheels built from source distributions, shared across machines.
+//!
+//! Each built wheel is stored under a key derived from the immutable identity of its source
+//! distribution and the fingerprint of its build environment (i.e., the build settings, build
+//! constraints, compiler flags, and target interpreter), as a pair of files:
+//!
+//! - `{url}/v1/{key}.json`: a manifest containing the wheel's filename and SHA-256 digest.
+//! - `{url}/v1/{key}/{filename}`: the wheel itself.
+//!
+//! For object store backends (i.e., S3, GCS, and Azure), wheels are instead content-addressed, at
+//! `{prefix}/v1/sha256/{digest}/{filename}`, and all writes are conditional on the object not
+//! already existing, such that concurrent writers never clobber each other.
+//!
+//! The manifest is uploaded after the wheel, such that readers never observe a manifest for a
+//! wheel that's missing.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, bail};
+use fs_err::tokio as fs;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cache::RemoteCache;
+use uv_cache_key::{CanonicalUrl, RepositoryUrl};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::IndexUrl;
+use uv_extract::hash::Hasher;
+use uv_fs::PortablePath;
+use uv_git_types::GitOid;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::distribution_database::ManagedClient;
+use crate::source::fingerprint::BuildFingerprint;
+
+mod store;
+
+/// The version of the remote cache layout.
+const REMOTE_VERSION: &str = "v1";
+
+/// The immutable identity of a source distribution, such that the wheels built from it can be
+/// shared via a remote cache.
+///
+/// Local source distributions (i.e., paths and directories) can change without notice, and so
+/// are never shared.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RemoteSource<'a> {
+    /// A source distribution archive, identified by its SHA-256 digest (if known) or its URL.
+    Url {
+        url: &'a DisplaySafeUrl,
+        hashes: &'a [HashDigest],
+    },
+    /// A Git repository, identified by its URL and the resolved commit.
+    Git {
+        repository: &'a DisplaySafeUrl,
+        precise: GitOid,
+    },
+}
+
+impl RemoteSource<'_> {
+    /// Return the canonical representation of the source, for use in a remote cache key.
+    fn identity(&self) -> String {
+        match self {
+            Self::Url { url, hashes } => {
+                if let Some(digest) = hashes
+                    .iter()
+                    .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+                {
+                    format!("sha256:{}", digest.digest)
+                } else {
+                    format!("url:{}", CanonicalUrl::new(url))
+                }
+            }
+            Self::Git {
+                repository,
+                precise,
+            } => format!("git:{}@{precise}", RepositoryUrl::new(repository)),
+        }
+    }
+}
+
+/// A request to consult the remote cache when building a source distribution.
+pub(crate) struct RemoteBuild<'a> {
+    pub(crate) source: RemoteSource<'a>,
+    pub(crate) client: &'a ManagedClient<'a>,
+}
+
+/// The manifest for a wheel stored in the remote cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    /// The filename of the wheel.
+    filename: String,
+    /// The SHA-256 digest of the wheel.
+    sha256: String,
+}
+
+/// An entry in the remote cache, for the wheel built from a single source distribution.
+pub(crate) struct RemoteEntry<'a> {
+    cache: &'a RemoteCache,
+    client: &'a ManagedClient<'a>,
+    key: String,
+}
+
+impl<'a> RemoteEntry<'a> {
+    /// Compute the [`RemoteEntry`] for the wheel built from the given source, in the environment
+    /// with the given [`BuildFingerprint`], for the interpreter with the given [`Tags`].
+    ///
+    /// If an index is provided (i.e., with index isolation enabled), the entry is scoped to that
+    /// index, such that wheels built from its source distributions are never shared with other
+    /// indexes.
+    ///
+    /// Returns `None` if the interpreter isn't associated with any tags.
+    pub(crate) fn new(
+        cache: &'a RemoteCache,
+        build: RemoteBuild<'a>,
+        subdirectory: Option<&Path>,
+        fingerprint: &BuildFingerprint,
+        tags: &Tags,
+        index: Option<&IndexUrl>,
+    ) -> Option<Self> {
+        let (Some(python), Some(abi), Some(platform)) =
+            (tags.python_tag(), tags.abi_tag(), tags.platform_tag())
+        else {
+            return None;
+        };
+
+        let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+        for component in [
+            build.source.identity(),
+            subdirectory
+                .map(|subdirectory| PortablePath::from(subdirectory).to_string())
+                .unwrap_or_default(),
+            fingerprint.portable_digest(),
+            format!("{python}-{abi}-{platform}"),
+        ] {
+            hasher.update(component.as_bytes());
+            hasher.update(b"\n");
+        }
+        if let Some(index) = index {
+            hasher.update(format!("index:{}", CanonicalUrl::new(index.url())).as_bytes());
+            hasher.update(b"\n");
+        }
+        let key = HashDigest::from(hasher).digest.to_string();
+
+        Some(Self {
+            cache,
+            client: build.client,
+            key,
+        })
+    }
+
+    /// Returns `true` if locally built wheels should be uploaded to the remote cache.
+    pub(crate) fn upload(&self) -> bool {
+        self.cache.upload()
+    }
+
+    /// Return the URL of the file at the given path, relative to the remote cache root.
+    fn url(&self, path: &str) -> Result<DisplaySafeUrl, anyhow::Error> {
+        store::object_url(self.cache, &format!("{REMOTE_VERSION}/{path}"))
+    }
+
+    /// Return the URL of the manifest for this entry.
+    fn manifest_url(&self) -> Result<DisplaySafeUrl, anyhow::Error> {
+        self.url(&format!("{}.json", self.key))
+    }
+
+    /// Return the URL of the wheel with the given filename and SHA-256 digest.
+    fn wheel_url(&self, filename: &str, sha256: &str) -> Result<DisplaySafeUrl, anyhow::Error> {
+        if self.cache.backend().is_object_store() {
+            self.url(&format!("sha256/{sha256}/{filename}"))
+        } else {
+            self.url(&format!("{}/{filename}", self.key))
+        }
+    }
+
+    /// Fetch the wheel from the remote cache into the given directory.
+    ///
+    /// Returns the filename of the wheel, or `None` if the wheel isn't present in the cache.
+    pub(crate) async fn fetch(&self, target: &Path) -> Result<Option<String>, anyhow::Error> {
+        let manifest_url = self.manifest_url()?;
+
+        let manifest = self
+            .client
+            .managed(|client| async move {
+                let response = store::get(
+                    client.uncached_client(&manifest_url),
+                    self.cache,
+                    &manifest_url,
+                )
+                .await
+                .send()
+                .await?;
+                if response.status() == StatusCode::NOT_FOUND {
+                    return Ok(None);
+                }
+                let bytes = response.error_for_status()?.bytes().await?;
+                let manifest = serde_json::from_slice::<Manifest>(&bytes)
+                    .with_context(|| format!("Invalid remote cache manifest at: {manifest_url}"))?;
+                Ok::<_, anyhow::Error>(Some(manifest))
+            })
+            .await?;
+
+        let Some(manifest) = manifest else {
+            debug!("No entry in the remote cache for key: {}", self.key);
+            return Ok(None);
+        };
+
+        // Validate the filename, to avoid writing outside the target directory.
+        WheelFilename::from_str(&manifest.filename)?;
+
+        let wheel_url = self.wheel_url(&manifest.filename, &manifest.sha256)?;
+        let bytes = self
+            .client
+            .managed(|client| async move {
+                let response =
+                    store::get(client.uncached_client(&wheel_url), self.cache, &wheel_url)
+                        .await
+                        .send()
+                        .await?;
+                Ok::<_, anyhow::Error>(response.error_for_status()?.bytes().await?)
+            })
+            .await?;
+
+        let digest = sha256(&bytes);
+        if digest != manifest.sha256 {
+            bail!(
+                "Hash mismatch for `{}` in the remote cache: expected `{}`, found `{digest}`",
+                manifest.filename,
+                manifest.sha256
+            );
+        }
+
+        fs::write(target.join(&manifest.filename), &bytes).await?;
+
+        Ok(Some(manifest.filename))
+    }
+
+    /// Upload the given wheel to the remote cache.
+    pub(crate) async fn store(&self, wheel: &Path, filename: &str) -> Result<(), anyhow::Error> {
+        let bytes = fs::read(wheel).await?;
+        let manifest = Manifest {
+            filename: filename.to_string(),
+            sha256: sha256(&bytes),
+        };
+
+        let wheel_url = self.wheel_url(filename, &manifest.sha256)?;
+        let manifest_url = self.manifest_url()?;
+        let manifest = serde_json::to_vec(&manifest)?;
+
+        self.client
+            .managed(|client| async move {
+                // Upload the wheel prior to the manifest that references it.
+                let response =
+                    store::put(client.uncached_client(&wheel_url), self.cache, &wheel_url)
+                        .await
+                        .body(bytes)
+                        .send()
+                        .await?;
+                if response.status() == StatusCode::PRECONDITION_FAILED {
+                    debug!("Wheel already exists in the remote cache: {wheel_url}");
+                } else {
+                    response.error_for_status()?;
+                }
+
+                let response = store::put(
+                    client.uncached_client(&manifest_url),
+                    self.cache,
+                    &manifest_url,
+                )
+                .await
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(manifest)
+                .send()
+                .await?;
+                if response.status() == StatusCode::PRECONDITION_FAILED {
+                    debug!("Manifest already exists in the remote cache: {manifest_url}");
+                } else {
+                    response.error_for_status()?;
+                }
+                Ok::<_, anyhow::Error>(())
+            })
+            .await?;
+
+        debug!("Uploaded `{filename}` to the remote cache");
+        Ok(())
+    }
+}
+
+/// Compute the hex-encoded SHA-256 digest of the given bytes.
+fn sha256(bytes: &[u8]) -> String {
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    hasher.update(bytes);
+    HashDigest::from(hasher).digest.to_string()
+}
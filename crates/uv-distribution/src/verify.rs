@@ -0,0 +1,143 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::DataWithCachePolicy;
+use uv_pypi_types::ResolutionMetadata;
+
+use crate::source::{
+    HTTP_REVISION, HttpRevisionPointer, LOCAL_REVISION, LocalRevisionPointer, METADATA,
+};
+use crate::{Error, HttpArchivePointer, LocalArchivePointer};
+
+/// A corrupted entry in the cache.
+#[derive(Debug)]
+pub struct CorruptEntry {
+    /// The path to the corrupted file.
+    pub path: PathBuf,
+    /// The paths to remove to repair the cache, such that the entry is fetched or built again the
+    /// next time it's needed.
+    pub remove: Vec<PathBuf>,
+    /// A description of the corruption.
+    pub reason: String,
+}
+
+/// The result of verifying the wheel and source distribution buckets.
+#[derive(Debug, Default)]
+pub struct Verification {
+    /// The number of files that were verified.
+    pub verified: usize,
+    /// The corrupted entries.
+    pub corrupted: Vec<CorruptEntry>,
+}
+
+/// Verify the integrity of the pointers, metadata, and built wheels in the wheel and source
+/// distribution buckets.
+///
+/// Detects files that can't be deserialized (e.g., truncated writes after the process was killed)
+/// and built wheels with missing or mismatched entries.
+pub fn verify(cache: &Cache) -> Result<Verification, Error> {
+    let mut verification = Verification::default();
+
+    for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+        let root = cache.bucket(bucket);
+        if !root.is_dir() {
+            continue;
+        }
+
+        let walker = walkdir::WalkDir::new(&root).into_iter();
+        for entry in walker.filter_entry(|entry| entry.file_name() != "src") {
+            let entry = entry.map_err(Error::CacheWalk)?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let path = entry.path();
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            let result = if file_name == HTTP_REVISION {
+                HttpRevisionPointer::read_from(path).map(|_| ())
+            } else if file_name == LOCAL_REVISION {
+                LocalRevisionPointer::read_from(path).map(|_| ())
+            } else if file_name == METADATA {
+                fs_err::read(path)
+                    .map_err(Error::CacheRead)
+                    .and_then(|data| Ok(rmp_serde::from_slice::<ResolutionMetadata>(&data)?))
+                    .map(|_| ())
+            } else if has_extension(path, "msgpack") {
+                // Wheel metadata fetched from a registry, wrapped with its cache policy.
+                fs_err::File::open(path)
+                    .map_err(Error::CacheRead)
+                    .and_then(|file| Ok(DataWithCachePolicy::from_reader(file)?.data))
+                    .and_then(|data| Ok(rmp_serde::from_slice::<ResolutionMetadata>(&data)?))
+                    .map(|_| ())
+            } else if has_extension(path, "http") {
+                HttpArchivePointer::read_from(path).map(|_| ())
+            } else if has_extension(path, "rev") {
+                LocalArchivePointer::read_from(path).map(|_| ())
+            } else if has_extension(path, "whl") {
+                verify_zip(path)
+            } else {
+                continue;
+            };
+
+            verification.verified += 1;
+
+            let Err(err) = result else {
+                continue;
+            };
+            debug!("Found corrupted cache entry at {}: {err}", path.display());
+
+            // For built wheels, also remove the unzipped wheel, which may have been unzipped from
+            // the corrupted archive.
+            let mut remove = vec![path.to_path_buf()];
+            if has_extension(path, "whl") {
+                if let Some(stem) = path.file_stem() {
+                    let unzipped = path.with_file_name(stem);
+                    if unzipped.symlink_metadata().is_ok() {
+                        remove.push(unzipped);
+                    }
+                }
+            }
+
+            verification.corrupted.push(CorruptEntry {
+                path: path.to_path_buf(),
+                remove,
+                reason: reason(&err),
+            });
+        }
+    }
+
+    Ok(verification)
+}
+
+/// Read every entry in a zip archive, validating its CRC-32 checksum.
+fn verify_zip(path: &Path) -> Result<(), Error> {
+    let file = fs_err::File::open(path).map_err(Error::CacheRead)?;
+    let mut archive = zip::ZipArchive::new(io::BufReader::new(file))?;
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        io::copy(&mut entry, &mut io::sink()).map_err(Error::CacheRead)?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the path has the given extension.
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
+/// Return a short description of a verification error.
+fn reason(err: &Error) -> String {
+    match err {
+        Error::Zip(_) => "invalid or truncated wheel".to_string(),
+        Error::CacheRead(err) if err.kind() == io::ErrorKind::InvalidData => {
+            "checksum mismatch".to_string()
+        }
+        _ => "unreadable or truncated file".to_string(),
+    }
+}
@@ -1,15 +1,21 @@
 use std::fmt::Display;
 use std::io;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use fs2::FileExt;
 use tempfile::NamedTempFile;
 use tracing::{debug, error, info, trace, warn};
 
 pub use crate::path::*;
+pub use crate::robustness::*;
 
 pub mod cachedir;
 mod path;
+mod robustness;
 pub mod which;
 
 /// Attempt to check if the two paths refer to the same file.
@@ -262,13 +268,21 @@ pub fn copy_atomic_sync(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io
 #[cfg(windows)]
 fn backoff_file_move() -> backon::ExponentialBackoff {
     use backon::BackoffBuilder;
-    // This amounts to 10 total seconds of trying the operation.
+    // With the default strategy, this amounts to 10 total seconds of trying the operation.
     // We start at 10 milliseconds and try 9 times, doubling each time, so the last try will take
     // about 10*(2^9) milliseconds ~= 5 seconds. All other attempts combined should equal
     // the length of the last attempt (because it's a sum of powers of 2), so 10 seconds overall.
+    //
+    // With the robust strategy, we try 11 times, for about 40 seconds overall.
+    let max_times = match windows_install_robustness() {
+        WindowsInstallRobustness::None => 0,
+        WindowsInstallRobustness::Retry => 9,
+        WindowsInstallRobustness::Robust => 11,
+    };
     backon::ExponentialBuilder::default()
         .with_min_delay(std::time::Duration::from_millis(10))
-        .with_max_times(9)
+        .with_max_delay(std::time::Duration::from_secs(30))
+        .with_max_times(max_times)
         .build()
 }
 
@@ -294,7 +308,7 @@ pub async fn rename_with_retry(
         rename
             .retry(backoff_file_move())
             .sleep(tokio::time::sleep)
-            .when(|e| e.kind() == std::io::ErrorKind::PermissionDenied)
+            .when(is_transient_error)
             .notify(|err, _dur| {
                 warn!(
                     "Retrying rename from {} to {} due to transient error: {}",
@@ -334,7 +348,7 @@ pub fn with_retry_sync(
         operation
             .retry(backoff_file_move())
             .sleep(std::thread::sleep)
-            .when(|err| err.kind() == std::io::ErrorKind::PermissionDenied)
+            .when(is_transient_error)
             .notify(|err, _dur| {
                 warn!(
                     "Retrying {} from {} to {} due to transient error: {}",
@@ -361,6 +375,43 @@ pub fn with_retry_sync(
     }
 }
 
+/// Run a file system operation on the given path, retrying (on Windows) if it fails due to
+/// transient operating system errors, in a synchronous context.
+///
+/// Unlike [`with_retry_sync`], the error from the final attempt is returned unchanged, such that
+/// callers can continue to match on its kind (e.g., [`io::ErrorKind::AlreadyExists`]).
+#[cfg_attr(not(windows), allow(unused_variables))]
+pub fn retry_transient_sync<T>(
+    path: &Path,
+    operation_name: &str,
+    mut operation: impl FnMut() -> io::Result<T>,
+) -> io::Result<T> {
+    #[cfg(windows)]
+    {
+        use backon::BlockingRetryable;
+        // On Windows, antivirus software can lock files temporarily, making them inaccessible.
+        //
+        // See: <https://github.com/astral-sh/uv/issues/1491> & <https://github.com/astral-sh/uv/issues/9531>
+        (|| operation())
+            .retry(backoff_file_move())
+            .sleep(std::thread::sleep)
+            .when(is_transient_error)
+            .notify(|err, _dur| {
+                warn!(
+                    "Retrying {} of {} due to transient error: {}",
+                    operation_name,
+                    path.display(),
+                    err
+                );
+            })
+            .call()
+    }
+    #[cfg(not(windows))]
+    {
+        operation()
+    }
+}
+
 /// Why a file persist failed
 #[cfg(windows)]
 enum PersistRetryError {
@@ -633,6 +684,37 @@ pub fn is_virtualenv_base(path: impl AsRef<Path>) -> bool {
     path.as_ref().join("pyvenv.cfg").is_file()
 }
 
+/// The process-wide timeout for acquiring a [`LockedFile`], in milliseconds, or [`u64::MAX`] to
+/// wait indefinitely.
+static LOCK_TIMEOUT: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Set the process-wide timeout for acquiring a [`LockedFile`] held by another process, or `None`
+/// to wait indefinitely.
+pub fn set_lock_timeout(timeout: Option<Duration>) {
+    let millis = timeout.map_or(u64::MAX, |timeout| {
+        u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX - 1)
+    });
+    LOCK_TIMEOUT.store(millis, Ordering::Relaxed);
+}
+
+/// Return the process-wide timeout for acquiring a [`LockedFile`], if any.
+fn lock_timeout() -> Option<Duration> {
+    match LOCK_TIMEOUT.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        millis => Some(Duration::from_millis(millis)),
+    }
+}
+
+/// A description of the current process, written to each lock file that it holds, such that
+/// other processes waiting on the lock can report its owner.
+static LOCK_OWNER: LazyLock<String> = LazyLock::new(|| {
+    let command = std::env::args_os()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("{} {command}", std::process::id())
+});
+
 /// A file lock that is automatically released when dropped.
 #[derive(Debug)]
 #[must_use]
@@ -648,32 +730,116 @@ impl LockedFile {
         match file.file().try_lock_exclusive() {
             Ok(()) => {
                 debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+                Ok(Self::with_owner(file))
             }
             Err(err) => {
                 // Log error code and enum kind to help debugging more exotic failures.
-                if err.kind() != std::io::ErrorKind::WouldBlock {
+                if !is_contended(&err) {
                     debug!("Try lock error: {err:?}");
                 }
+                let owner = Self::owner(&file);
                 info!(
-                    "Waiting to acquire lock for `{resource}` at `{}`",
+                    "Waiting to acquire lock for `{resource}` at `{}`{}",
                     file.path().user_display(),
+                    owner
+                        .as_ref()
+                        .map(|owner| format!(" (held by {owner})"))
+                        .unwrap_or_default(),
                 );
-                file.file().lock_exclusive().map_err(|err| {
-                    // Not an fs_err method, we need to build our own path context
-                    std::io::Error::other(format!(
+
+                if let Some(timeout) = lock_timeout() {
+                    Self::lock_with_timeout(&file, resource, timeout, owner)?;
+                } else {
+                    file.file().lock_exclusive().map_err(|err| {
+                        // Not an fs_err method, we need to build our own path context
+                        std::io::Error::other(format!(
+                            "Could not acquire lock for `{resource}` at `{}`: {}",
+                            file.path().user_display(),
+                            err
+                        ))
+                    })?;
+                }
+
+                debug!("Acquired lock for `{resource}`");
+                Ok(Self::with_owner(file))
+            }
+        }
+    }
+
+    /// Poll the lock until it's acquired, or until the timeout elapses.
+    fn lock_with_timeout(
+        file: &fs_err::File,
+        resource: &str,
+        timeout: Duration,
+        owner: Option<LockOwner>,
+    ) -> Result<(), std::io::Error> {
+        // If the timeout is too large to represent, wait indefinitely.
+        let deadline = Instant::now().checked_add(timeout);
+        let mut delay = Duration::from_millis(10);
+        loop {
+            match file.file().try_lock_exclusive() {
+                Ok(()) => return Ok(()),
+                Err(err) if is_contended(&err) => {}
+                Err(err) => {
+                    return Err(std::io::Error::other(format!(
                         "Could not acquire lock for `{resource}` at `{}`: {}",
                         file.path().user_display(),
                         err
-                    ))
-                })?;
+                    )));
+                }
+            }
 
-                debug!("Acquired lock for `{resource}`");
-                Ok(Self(file))
+            let now = Instant::now();
+            if deadline.is_some_and(|deadline| now >= deadline) {
+                // Re-read the owner, in case the lock changed hands while we were waiting.
+                let owner = Self::owner(file).or(owner);
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!(
+                        "Timed out after {}s waiting to acquire lock for `{resource}` at `{}`{}",
+                        timeout.as_secs(),
+                        file.path().user_display(),
+                        owner
+                            .map(|owner| format!(", which is held by {owner}"))
+                            .unwrap_or_default(),
+                    ),
+                ));
             }
+            std::thread::sleep(
+                deadline.map_or(delay, |deadline| delay.min(deadline.duration_since(now))),
+            );
+            delay = (delay * 2).min(Duration::from_millis(500));
         }
     }
 
+    /// Record the current process as the owner of the lock, on a best-effort basis.
+    fn with_owner(mut file: fs_err::File) -> Self {
+        let result = file
+            .set_len(0)
+            .and_then(|()| file.write_all(LOCK_OWNER.as_bytes()))
+            .and_then(|()| file.flush());
+        if let Err(err) = result {
+            trace!(
+                "Failed to record lock owner at `{}`: {err}",
+                file.path().user_display()
+            );
+        }
+        Self(file)
+    }
+
+    /// Read the owner of a lock held by another process, if it was recorded.
+    ///
+    /// On Windows, the contents of a locked file can't be read by other processes, so the owner is
+    /// never known.
+    fn owner(file: &fs_err::File) -> Option<LockOwner> {
+        let contents = fs_err::read_to_string(file.path()).ok()?;
+        let (pid, command) = contents.trim().split_once(' ')?;
+        Some(LockOwner {
+            pid: pid.parse().ok()?,
+            command: command.to_string(),
+        })
+    }
+
     /// The same as [`LockedFile::acquire`], but for synchronous contexts. Do not use from an async
     /// context, as this can block the runtime while waiting for another process to release the
     /// lock.
@@ -750,6 +916,27 @@ impl LockedFile {
     }
 }
 
+/// The process that holds a [`LockedFile`].
+#[derive(Debug)]
+struct LockOwner {
+    /// The process ID.
+    pid: u32,
+    /// The command line of the process.
+    command: String,
+}
+
+impl Display for LockOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "process {} (`{}`)", self.pid, self.command)
+    }
+}
+
+/// Returns `true` if the error indicates that the lock is held by another process.
+fn is_contended(err: &std::io::Error) -> bool {
+    err.kind() == std::io::ErrorKind::WouldBlock
+        || err.raw_os_error() == fs2::lock_contended_error().raw_os_error()
+}
+
 impl Drop for LockedFile {
     fn drop(&mut self) {
         if let Err(err) = fs2::FileExt::unlock(self.0.file()) {
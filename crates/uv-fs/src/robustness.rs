@@ -0,0 +1,181 @@
+use std::borrow::Cow;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The strategy used to work around transient file system errors on Windows, like files that are
+/// temporarily locked by antivirus software or search indexers, or paths that exceed `MAX_PATH`.
+///
+/// Has no effect on other platforms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(deny_unknown_fields, rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WindowsInstallRobustness {
+    /// Fail immediately on any file system error.
+    None,
+    /// Retry renames and copies that fail with `Access is denied` with exponential backoff, for
+    /// up to ten seconds.
+    #[default]
+    Retry,
+    /// Retry failing file operations, including sharing and lock violations, for up to forty
+    /// seconds; write files to a temporary path before renaming them into place; and use extended
+    /// length (`\\?\`) paths when installing into `site-packages`, to avoid the `MAX_PATH` limit.
+    Robust,
+}
+
+impl WindowsInstallRobustness {
+    /// Returns `true` if failing file operations should be retried.
+    pub fn retries(self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Returns `true` if files should be written to a temporary path and renamed into place.
+    pub fn rename_through_temp(self) -> bool {
+        cfg!(windows) && matches!(self, Self::Robust)
+    }
+
+    /// Returns `true` if long paths should be converted to extended-length paths.
+    pub fn long_paths(self) -> bool {
+        cfg!(windows) && matches!(self, Self::Robust)
+    }
+}
+
+impl FromStr for WindowsInstallRobustness {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "retry" => Ok(Self::Retry),
+            "robust" => Ok(Self::Robust),
+            _ => Err(format!(
+                "Invalid Windows install robustness: `{s}` (expected one of `none`, `retry`, or `robust`)"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for WindowsInstallRobustness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Retry => f.write_str("retry"),
+            Self::Robust => f.write_str("robust"),
+        }
+    }
+}
+
+/// The process-wide [`WindowsInstallRobustness`], as set by the `uv` crate from the user settings.
+static WINDOWS_INSTALL_ROBUSTNESS: AtomicU8 = AtomicU8::new(1);
+
+/// Set the process-wide [`WindowsInstallRobustness`].
+pub fn set_windows_install_robustness(robustness: WindowsInstallRobustness) {
+    let value = match robustness {
+        WindowsInstallRobustness::None => 0,
+        WindowsInstallRobustness::Retry => 1,
+        WindowsInstallRobustness::Robust => 2,
+    };
+    WINDOWS_INSTALL_ROBUSTNESS.store(value, Ordering::Relaxed);
+}
+
+/// Return the process-wide [`WindowsInstallRobustness`].
+pub fn windows_install_robustness() -> WindowsInstallRobustness {
+    match WINDOWS_INSTALL_ROBUSTNESS.load(Ordering::Relaxed) {
+        0 => WindowsInstallRobustness::None,
+        2 => WindowsInstallRobustness::Robust,
+        _ => WindowsInstallRobustness::Retry,
+    }
+}
+
+/// Returns `true` if the error is likely to be transient, such that the operation should be
+/// retried under the current [`WindowsInstallRobustness`].
+pub fn is_transient_error(err: &std::io::Error) -> bool {
+    /// `ERROR_SHARING_VIOLATION`
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+    /// `ERROR_LOCK_VIOLATION`
+    const ERROR_LOCK_VIOLATION: i32 = 33;
+
+    match windows_install_robustness() {
+        WindowsInstallRobustness::None => false,
+        WindowsInstallRobustness::Retry => err.kind() == std::io::ErrorKind::PermissionDenied,
+        WindowsInstallRobustness::Robust => {
+            err.kind() == std::io::ErrorKind::PermissionDenied
+                || (cfg!(windows)
+                    && matches!(
+                        err.raw_os_error(),
+                        Some(ERROR_SHARING_VIOLATION | ERROR_LOCK_VIOLATION)
+                    ))
+        }
+    }
+}
+
+/// Convert an absolute path to an extended-length path (e.g., `\\?\C:\...`), if enabled by the
+/// current [`WindowsInstallRobustness`], such that files beneath it aren't subject to the
+/// `MAX_PATH` limit.
+///
+/// Paths that are relative, contain `.` or `..` components, or are already extended-length paths
+/// are returned unchanged.
+pub fn long_path(path: &Path) -> Cow<'_, Path> {
+    if !windows_install_robustness().long_paths() {
+        return Cow::Borrowed(path);
+    }
+    to_extended_length_path(path)
+}
+
+#[cfg(windows)]
+fn to_extended_length_path(path: &Path) -> Cow<'_, Path> {
+    use std::ffi::OsString;
+    use std::path::{Component, PathBuf, Prefix};
+
+    if !path.is_absolute()
+        || path
+            .components()
+            .any(|component| matches!(component, Component::CurDir | Component::ParentDir))
+    {
+        return Cow::Borrowed(path);
+    }
+
+    let mut components = path.components();
+    let Some(Component::Prefix(prefix)) = components.next() else {
+        return Cow::Borrowed(path);
+    };
+
+    // Extended-length paths must use backslashes as separators, so rebuild the remainder of the
+    // path from its components.
+    let mut extended = match prefix.kind() {
+        Prefix::Disk(_) => {
+            let mut extended = OsString::from(r"\\?\");
+            extended.push(prefix.as_os_str());
+            extended
+        }
+        Prefix::UNC(server, share) => {
+            let mut extended = OsString::from(r"\\?\UNC\");
+            extended.push(server);
+            extended.push(r"\");
+            extended.push(share);
+            extended
+        }
+        Prefix::Verbatim(_)
+        | Prefix::VerbatimDisk(_)
+        | Prefix::VerbatimUNC(..)
+        | Prefix::DeviceNS(_) => return Cow::Borrowed(path),
+    };
+    for component in components {
+        if matches!(component, Component::RootDir) {
+            continue;
+        }
+        extended.push(r"\");
+        extended.push(component.as_os_str());
+    }
+    Cow::Owned(PathBuf::from(extended))
+}
+
+#[cfg(not(windows))]
+fn to_extended_length_path(path: &Path) -> Cow<'_, Path> {
+    Cow::Borrowed(path)
+}
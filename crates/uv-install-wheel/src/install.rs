@@ -1,7 +1,7 @@
 //! Like `wheel.rs`, but for installing wheels that have already been unzipped, rather than
 //! reading from a zip file.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use fs_err as fs;
@@ -11,7 +11,7 @@ use tracing::{instrument, trace};
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
 use uv_pep440::Version;
-use uv_pypi_types::{DirectUrl, Metadata10};
+use uv_pypi_types::{DirectUrl, HashDigest, Metadata10};
 
 use crate::linker::{LinkMode, Locks};
 use crate::wheel::{
@@ -24,6 +24,8 @@ use crate::{Error, Layout};
 ///
 /// The caller must ensure that the wheel is compatible to the environment.
 ///
+/// Returns the path to the installed `.dist-info` directory.
+///
 /// <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#installing-a-wheel-distribution-1-0-py32-none-any-whl>
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
@@ -35,11 +37,12 @@ pub fn install_wheel(
     filename: &WheelFilename,
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&CacheInfo>,
+    hashes: &[HashDigest],
     installer: Option<&str>,
     installer_metadata: bool,
     link_mode: LinkMode,
     locks: &Locks,
-) -> Result<(), Error> {
+) -> Result<PathBuf, Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
     let metadata = dist_info_metadata(&dist_info_prefix, &wheel)?;
     let Metadata10 { name, version } = Metadata10::parse_pkg_info(&metadata)
@@ -91,6 +94,8 @@ pub fn install_wheel(
 
     if console_scripts.is_empty() && gui_scripts.is_empty() {
         trace!(?name, "No entrypoints");
+    } else if !layout.install_scripts {
+        trace!(?name, "Skipping entrypoints");
     } else {
         trace!(?name, "Writing entrypoints");
 
@@ -131,6 +136,12 @@ pub fn install_wheel(
         // Script are unsupported through data
         // 2.e Remove empty distribution-1.0.data directory.
         fs::remove_dir_all(data_dir)?;
+
+        // If scripts were skipped, drop them from the RECORD, since they were never installed.
+        if !layout.install_scripts {
+            let scripts_prefix = format!("{dist_info_prefix}.data/scripts/");
+            record.retain(|entry| !entry.path.starts_with(&scripts_prefix));
+        }
     } else {
         trace!(?name, "No data");
     }
@@ -143,6 +154,7 @@ pub fn install_wheel(
             true,
             direct_url,
             cache_info,
+            hashes,
             installer,
             &mut record,
         )?;
@@ -158,5 +170,5 @@ pub fn install_wheel(
         record_writer.serialize(entry)?;
     }
 
-    Ok(())
+    Ok(site_packages.join(format!("{dist_info_prefix}.dist-info")))
 }
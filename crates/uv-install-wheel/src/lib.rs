@@ -13,6 +13,7 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
+pub use verify::{RecordMismatch, verify_wheel};
 pub use wheel::{LibKind, parse_wheel_file, read_record_file};
 
 mod install;
@@ -20,6 +21,7 @@ mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
@@ -33,6 +35,9 @@ pub struct Layout {
     pub os_name: String,
     /// The [`Scheme`] paths for the interpreter.
     pub scheme: Scheme,
+    /// Whether to install scripts, i.e., entry points and the contents of the `.data/scripts`
+    /// directory.
+    pub install_scripts: bool,
 }
 
 /// Note: The caller is responsible for adding the path of the wheel we're installing.
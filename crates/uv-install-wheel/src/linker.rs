@@ -19,8 +19,16 @@ pub struct Locks(Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>);
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(transform = add_reflink_alias))]
 pub enum LinkMode {
-    /// Clone (i.e., copy-on-write) packages from the wheel into the `site-packages` directory.
+    /// Clone (i.e., copy-on-write, or `reflink`) packages from the wheel into the `site-packages`
+    /// directory.
+    ///
+    /// Uses `clonefile` on APFS and `FICLONE` (i.e., reflink) on filesystems like btrfs and XFS,
+    /// falling back to copying (with a warning) if the filesystem does not support cloning. Also
+    /// accepted as `reflink`.
+    #[serde(alias = "reflink")]
+    #[cfg_attr(feature = "clap", value(alias = "reflink"))]
     Clone,
     /// Copy packages from the wheel into the `site-packages` directory.
     Copy,
@@ -35,6 +43,25 @@ pub enum LinkMode {
     Symlink,
 }
 
+/// Add the `reflink` alias to the JSON schema for [`LinkMode`], which `schemars` omits for
+/// `serde` aliases.
+#[cfg(feature = "schemars")]
+fn add_reflink_alias(schema: &mut schemars::Schema) {
+    if let Some(variants) = schema
+        .get_mut("oneOf")
+        .and_then(serde_json::Value::as_array_mut)
+    {
+        variants.insert(
+            1,
+            serde_json::json!({
+                "description": "Alias for `clone`.",
+                "type": "string",
+                "const": "reflink"
+            }),
+        );
+    }
+}
+
 impl Default for LinkMode {
     fn default() -> Self {
         if cfg!(any(target_os = "macos", target_os = "ios")) {
@@ -54,6 +81,10 @@ impl LinkMode {
         wheel: impl AsRef<Path>,
         locks: &Locks,
     ) -> Result<usize, Error> {
+        // If enabled, use extended-length paths on Windows, to avoid the `MAX_PATH` limit for
+        // deeply nested package files.
+        let site_packages = uv_fs::long_path(site_packages.as_ref());
+        let wheel = uv_fs::long_path(wheel.as_ref());
         match self {
             Self::Clone => clone_wheel_files(site_packages, wheel, locks),
             Self::Copy => copy_wheel_files(site_packages, wheel, locks),
@@ -93,6 +124,18 @@ fn clone_wheel_files(
         count += 1;
     }
 
+    if attempt == Attempt::UseCopyFallback {
+        warn_user_once!(
+            "Failed to clone files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, reflinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
+        );
+        debug!(
+            "Installed `{}` via copy, as cloning is not supported",
+            wheel.as_ref().display()
+        );
+    } else {
+        debug!("Installed `{}` via clone", wheel.as_ref().display());
+    }
+
     // The directory mtime is not updated when cloning and the mtime is used by CPython's
     // import mechanisms to determine if it should look for new packages in a directory.
     // Here, we force the mtime to be updated to ensure that packages are importable without
@@ -185,7 +228,7 @@ fn clone_recursive(
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         if reflink::reflink(&from, &tempfile).is_ok() {
-                            fs::rename(&tempfile, to)?;
+                            rename(&tempfile, &to)?;
                         } else {
                             debug!(
                                 "Failed to clone `{}` to temporary location `{}`, attempting to copy files as a fallback",
@@ -221,7 +264,7 @@ fn clone_recursive(
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         reflink::reflink(&from, &tempfile)?;
-                        fs::rename(&tempfile, to)?;
+                        rename(&tempfile, &to)?;
                     }
                 } else {
                     return Err(Error::Reflink { from, to, err });
@@ -237,9 +280,6 @@ fn clone_recursive(
             } else {
                 synchronized_copy(&from, &to, locks)?;
             }
-            warn_user_once!(
-                "Failed to clone files; falling back to full copy. This may lead to degraded performance.\n         If the cache and target directories are on different filesystems, reflinking may not be supported.\n         If this is intentional, set `export UV_LINK_MODE=copy` or use `--link-mode=copy` to suppress this warning."
-            );
         }
     }
 
@@ -312,7 +352,7 @@ fn hardlink_wheel_files(
             Attempt::Initial => {
                 // Once https://github.com/rust-lang/rust/issues/86442 is stable, use that.
                 attempt = Attempt::Subsequent;
-                if let Err(err) = fs::hard_link(path, &out_path) {
+                if let Err(err) = hard_link(path, &out_path) {
                     // If the file already exists, remove it and try again.
                     if err.kind() == std::io::ErrorKind::AlreadyExists {
                         debug!(
@@ -322,8 +362,8 @@ fn hardlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        if fs::hard_link(path, &tempfile).is_ok() {
-                            fs_err::rename(&tempfile, &out_path)?;
+                        if hard_link(path, &tempfile).is_ok() {
+                            rename(&tempfile, &out_path)?;
                         } else {
                             debug!(
                                 "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
@@ -345,7 +385,7 @@ fn hardlink_wheel_files(
                 }
             }
             Attempt::Subsequent => {
-                if let Err(err) = fs::hard_link(path, &out_path) {
+                if let Err(err) = hard_link(path, &out_path) {
                     // If the file already exists, remove it and try again.
                     if err.kind() == std::io::ErrorKind::AlreadyExists {
                         debug!(
@@ -355,8 +395,8 @@ fn hardlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        fs::hard_link(path, &tempfile)?;
-                        fs_err::rename(&tempfile, &out_path)?;
+                        hard_link(path, &tempfile)?;
+                        rename(&tempfile, &out_path)?;
                     } else {
                         return Err(err.into());
                     }
@@ -421,7 +461,7 @@ fn symlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         if create_symlink(path, &tempfile).is_ok() {
-                            fs::rename(&tempfile, &out_path)?;
+                            rename(&tempfile, &out_path)?;
                         } else {
                             debug!(
                                 "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
@@ -454,7 +494,7 @@ fn symlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         create_symlink(path, &tempfile)?;
-                        fs::rename(&tempfile, &out_path)?;
+                        rename(&tempfile, &out_path)?;
                     } else {
                         return Err(err.into());
                     }
@@ -492,11 +532,45 @@ fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<(
     let _dir_guard = dir_lock.lock().unwrap();
 
     // Copy the file, which will also set its permissions.
-    fs::copy(from, to)?;
+    copy(from, to)?;
 
     Ok(())
 }
 
+/// Copy from `from` to `to`, retrying (on Windows) if the operation fails due to transient
+/// operating system errors.
+///
+/// If enabled by the [`uv_fs::WindowsInstallRobustness`], the file is first copied to a temporary
+/// path in the same directory, then renamed into place, such that the destination is never
+/// observed (e.g., by antivirus software) in a partially written state.
+fn copy(from: &Path, to: &Path) -> std::io::Result<()> {
+    if uv_fs::windows_install_robustness().rename_through_temp() {
+        let parent = to.parent().expect("copy destination must have a parent");
+        let temp_file = uv_fs::tempfile_in(parent)?.into_temp_path();
+        uv_fs::retry_transient_sync(to, "copying", || fs::copy(from, &temp_file))?;
+        uv_fs::retry_transient_sync(to, "renaming", || fs::rename(&temp_file, to))?;
+        // The temporary file has been renamed into place, so there's nothing left to clean up.
+        if let Err(err) = temp_file.keep() {
+            trace!("Failed to release temporary file: {err}");
+        }
+        Ok(())
+    } else {
+        uv_fs::retry_transient_sync(to, "copying", || fs::copy(from, to)).map(|_| ())
+    }
+}
+
+/// Rename `from` to `to`, retrying (on Windows) if the operation fails due to transient operating
+/// system errors.
+fn rename(from: &Path, to: &Path) -> std::io::Result<()> {
+    uv_fs::retry_transient_sync(to, "renaming", || fs::rename(from, to))
+}
+
+/// Hard link `from` to `to`, retrying (on Windows) if the operation fails due to transient
+/// operating system errors.
+fn hard_link(from: &Path, to: &Path) -> std::io::Result<()> {
+    uv_fs::retry_transient_sync(to, "hard linking", || fs::hard_link(from, to))
+}
+
 #[cfg(unix)]
 fn create_symlink<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> std::io::Result<()> {
     fs_err::os::unix::fs::symlink(original, link)
@@ -510,3 +584,54 @@ fn create_symlink<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> std::
         fs_err::os::windows::fs::symlink_file(original, link)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use super::{LinkMode, Locks};
+
+    #[test]
+    fn reflink_alias() -> Result<()> {
+        let mode: LinkMode = serde_json::from_str("\"reflink\"")?;
+        assert_eq!(mode, LinkMode::Clone);
+        let mode: LinkMode = serde_json::from_str("\"clone\"")?;
+        assert_eq!(mode, LinkMode::Clone);
+        Ok(())
+    }
+
+    /// Cloning must produce the same tree whether or not the filesystem supports reflinks, falling
+    /// back to copying (and overwriting existing files) otherwise.
+    #[test]
+    fn clone_wheel_files() -> Result<()> {
+        let temp = assert_fs::TempDir::new()?;
+        let wheel = temp.child("wheel");
+        wheel.child("foo/__init__.py").write_str("VERSION = 2\n")?;
+        wheel.child("foo/bar.py").write_str("")?;
+        wheel
+            .child("foo-2.0.0.dist-info/METADATA")
+            .write_str("Name: foo\n")?;
+
+        let site_packages = temp.child("site-packages");
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("VERSION = 1\n")?;
+
+        let count = LinkMode::Clone.link_wheel_files(
+            site_packages.path(),
+            wheel.path(),
+            &Locks::default(),
+        )?;
+        assert_eq!(count, 2);
+
+        site_packages
+            .child("foo/__init__.py")
+            .assert("VERSION = 2\n");
+        site_packages.child("foo/bar.py").assert("");
+        site_packages
+            .child("foo-2.0.0.dist-info/METADATA")
+            .assert("Name: foo\n");
+        Ok(())
+    }
+}
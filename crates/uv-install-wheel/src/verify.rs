@@ -0,0 +1,83 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use tracing::trace;
+
+use crate::Error;
+use crate::wheel::{copy_and_hash, read_record_file};
+
+/// A file listed in a `RECORD` that doesn't match the installed contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordMismatch {
+    /// The file no longer exists.
+    Missing(PathBuf),
+    /// The file's hash differs from the hash recorded at install time.
+    Modified(PathBuf),
+}
+
+impl RecordMismatch {
+    /// The path to the file, relative to the `site-packages` directory (as listed in the `RECORD`).
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Missing(path) | Self::Modified(path) => path,
+        }
+    }
+}
+
+/// Verify the files of the wheel represented by the given `.dist-info` directory against the
+/// hashes in its `RECORD` file.
+///
+/// Entries without a hash (like the `RECORD` itself) and entries using hash algorithms other than
+/// SHA-256 are skipped.
+pub fn verify_wheel(dist_info: &Path) -> Result<Vec<RecordMismatch>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    let mut mismatches = Vec::new();
+    for entry in record {
+        let Some(expected) = entry.hash.as_deref() else {
+            continue;
+        };
+        if !expected.starts_with("sha256=") {
+            trace!("Skipping unsupported hash for: {}", entry.path);
+            continue;
+        }
+
+        let path = site_packages.join(&entry.path);
+        let mut file = match fs::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                mismatches.push(RecordMismatch::Missing(PathBuf::from(entry.path)));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if actual != expected {
+            trace!(
+                "Hash mismatch for {}: {actual} != {expected}",
+                path.display()
+            );
+            mismatches.push(RecordMismatch::Modified(PathBuf::from(entry.path)));
+        }
+    }
+
+    Ok(mismatches)
+}
@@ -15,7 +15,7 @@ use walkdir::WalkDir;
 use uv_cache_info::CacheInfo;
 use uv_fs::{Simplified, persist_with_retry_sync, relative_to};
 use uv_normalize::PackageName;
-use uv_pypi_types::DirectUrl;
+use uv_pypi_types::{DirectUrl, HashDigest};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_trampoline_builder::windows_script_launcher;
 use uv_warnings::warn_user_once;
@@ -79,7 +79,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -596,6 +599,9 @@ pub(crate) fn install_data(
                 // Move the content of the folder to the root of the venv
                 move_folder_recorded(&path, &layout.scheme.data, site_packages, record)?;
             }
+            Some("scripts") if !layout.install_scripts => {
+                trace!(?dist_name, "Skipping data/scripts");
+            }
             Some("scripts") => {
                 trace!(
                     ?dist_name,
@@ -710,6 +716,7 @@ pub(crate) fn write_installer_metadata(
     requested: bool,
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&CacheInfo>,
+    hashes: &[HashDigest],
     installer: Option<&str>,
     record: &mut Vec<RecordEntry>,
 ) -> Result<(), Error> {
@@ -733,6 +740,14 @@ pub(crate) fn write_installer_metadata(
             record,
         )?;
     }
+    if !hashes.is_empty() {
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("uv_hashes.json"),
+            serde_json::to_string(hashes)?.as_bytes(),
+            record,
+        )?;
+    }
     if let Some(installer) = installer {
         write_file_recorded(
             site_packages,
@@ -1227,6 +1242,7 @@ mod test {
             true,
             None,
             None,
+            &[],
             Some("uv"),
             &mut record,
         )
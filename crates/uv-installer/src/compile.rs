@@ -5,6 +5,7 @@ use std::time::Duration;
 use std::{env, io, panic};
 
 use async_channel::{Receiver, SendError};
+use rustc_hash::FxHashSet;
 use tempfile::tempdir_in;
 use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
@@ -13,7 +14,7 @@ use tokio::sync::oneshot;
 use tracing::{debug, instrument};
 use walkdir::WalkDir;
 
-use uv_configuration::Concurrency;
+use uv_configuration::{BytecodeOptimizationLevel, Concurrency};
 use uv_fs::Simplified;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
@@ -62,6 +63,9 @@ pub enum CompileError {
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
+/// Files in `exclude` are skipped. If `optimization` is `None`, the interpreter's default
+/// optimization level is used.
+///
 /// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
 /// a broken `python`.
 ///
@@ -69,19 +73,21 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
+#[instrument(skip(python_executable, exclude))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     concurrency: &Concurrency,
     cache: &Path,
+    optimization: Option<BytecodeOptimizationLevel>,
+    exclude: &FxHashSet<PathBuf>,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths: `{}`",
         dir.display()
     );
-    let worker_count = concurrency.installs;
+    let worker_count = concurrency.compiles;
 
     // A larger buffer is significantly faster than just 1 or the worker count.
     let (sender, receiver) = async_channel::bounded::<PathBuf>(worker_count * 10);
@@ -123,6 +129,7 @@ pub async fn compile_tree(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
             pip_compileall_py.clone(),
+            optimization,
             receiver.clone(),
             timeout,
         );
@@ -174,7 +181,10 @@ pub async fn compile_tree(
                 }
             };
         // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
-        if metadata.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
+        if metadata.is_file()
+            && entry.path().extension().is_some_and(|ext| ext == "py")
+            && !exclude.contains(entry.path())
+        {
             source_files += 1;
             if let Err(err) = sender.send(entry.path().to_owned()).await {
                 // The workers exited.
@@ -215,6 +225,7 @@ async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
     pip_compileall_py: PathBuf,
+    optimization: Option<BytecodeOptimizationLevel>,
     receiver: Receiver<PathBuf>,
     timeout: Option<Duration>,
 ) -> Result<(), CompileError> {
@@ -230,7 +241,8 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py, optimization)
+                    .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -299,6 +311,7 @@ async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
     pip_compileall_py: &Path,
+    optimization: Option<BytecodeOptimizationLevel>,
 ) -> Result<
     Option<(
         Child,
@@ -309,8 +322,12 @@ async fn launch_bytecode_compiler(
     CompileError,
 > {
     // We input the paths through stdin and get the successful paths returned through stdout.
-    let mut bytecode_compiler = Command::new(interpreter)
-        .arg(pip_compileall_py)
+    let mut command = Command::new(interpreter);
+    command.arg(pip_compileall_py);
+    if let Some(optimization) = optimization {
+        command.arg(optimization.to_string());
+    }
+    let mut bytecode_compiler = command
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
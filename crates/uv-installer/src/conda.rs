@@ -0,0 +1,99 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::debug;
+
+use uv_distribution_types::InstalledDist;
+use uv_fs::Simplified;
+use uv_python::PythonEnvironment;
+
+#[derive(Debug, Error)]
+pub enum CondaError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error("Failed to parse Conda package record: `{}`", _0.user_display())]
+    Json(PathBuf, #[source] serde_json::Error),
+}
+
+/// The distributions in a Conda environment that were installed by Conda, rather than by a Python
+/// package installer.
+///
+/// Conda records the files installed by each package in the `conda-meta` directory at the root of
+/// the environment; packages built by `conda-build` additionally mark their `.dist-info`
+/// directory with an `INSTALLER` of `conda`.
+#[derive(Debug, Default)]
+pub struct CondaPackages {
+    /// The `.dist-info` and `.egg-info` paths installed by Conda packages.
+    metadata: FxHashSet<PathBuf>,
+}
+
+/// A package record in the `conda-meta` directory, of which only the installed files are read.
+#[derive(Debug, Deserialize)]
+struct PackageRecord {
+    #[serde(default)]
+    files: Vec<PathBuf>,
+}
+
+impl CondaPackages {
+    /// Read the Conda-managed packages in the given environment.
+    ///
+    /// Returns `None` if the environment is not a Conda environment.
+    pub fn from_environment(venv: &PythonEnvironment) -> Result<Option<Self>, CondaError> {
+        let conda_meta = venv.root().join("conda-meta");
+        let entries = match fs::read_dir(&conda_meta) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut metadata = FxHashSet::default();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+            let contents = fs::read(&path)?;
+            let record = serde_json::from_slice::<PackageRecord>(&contents)
+                .map_err(|err| CondaError::Json(path.clone(), err))?;
+
+            // Ex) `lib/python3.12/site-packages/numpy-2.0.0.dist-info/METADATA`
+            for file in &record.files {
+                for path in std::iter::once(file.as_path()).chain(file.parent()) {
+                    if is_metadata(path) {
+                        metadata.insert(venv.root().join(path));
+                    }
+                }
+            }
+        }
+
+        debug!(
+            "Found {} Conda-managed Python packages in: {}",
+            metadata.len(),
+            conda_meta.user_display()
+        );
+
+        Ok(Some(Self { metadata }))
+    }
+
+    /// Returns `true` if the installed distribution is managed by Conda.
+    pub fn contains(&self, dist: &InstalledDist) -> bool {
+        if self.metadata.contains(dist.install_path()) {
+            return true;
+        }
+        dist.installer()
+            .ok()
+            .flatten()
+            .is_some_and(|installer| installer == "conda")
+    }
+}
+
+/// Returns `true` if the path refers to the metadata directory (or file) of a Python package.
+fn is_metadata(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "dist-info" || extension == "egg-info")
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use uv_configuration::InstallHooks;
+use uv_distribution_types::CachedDist;
+use uv_static::EnvVars;
+
+/// A hook to invoke around the installation of each distribution.
+///
+/// Hooks are invoked from the installer's worker threads, so may be called concurrently for
+/// different distributions.
+pub trait InstallHook: Send + Sync {
+    /// Invoked with the unpacked wheel just before it is linked into the environment.
+    ///
+    /// Returning an error aborts the installation.
+    fn pre_install(&self, wheel: &CachedDist) -> Result<()>;
+
+    /// Invoked after the wheel has been installed, with the path to the installed `.dist-info`
+    /// directory.
+    ///
+    /// Returning an error aborts the installation.
+    fn post_install(&self, wheel: &CachedDist, dist_info: &Path) -> Result<()>;
+}
+
+impl InstallHook for InstallHooks {
+    fn pre_install(&self, wheel: &CachedDist) -> Result<()> {
+        run_hook(&self.pre_install, "pre-install", wheel, wheel.path())
+    }
+
+    fn post_install(&self, wheel: &CachedDist, dist_info: &Path) -> Result<()> {
+        run_hook(&self.post_install, "post-install", wheel, dist_info)
+    }
+}
+
+/// Run a hook command for the given distribution, if one is configured.
+fn run_hook(command: &[String], stage: &str, wheel: &CachedDist, path: &Path) -> Result<()> {
+    let Some((program, args)) = command.split_first() else {
+        return Ok(());
+    };
+
+    let filename = wheel.filename();
+    debug!(
+        "Running {stage} hook for {filename}: `{}`",
+        command.join(" ")
+    );
+
+    // Route the hook's output to stderr, to avoid interfering with any structured output on stdout.
+    let status = Command::new(program)
+        .args(args)
+        .env(EnvVars::UV_HOOK_STAGE, stage)
+        .env(EnvVars::UV_HOOK_PACKAGE_NAME, filename.name.as_str())
+        .env(
+            EnvVars::UV_HOOK_PACKAGE_VERSION,
+            filename.version.to_string(),
+        )
+        .env(EnvVars::UV_HOOK_PATH, path)
+        .stdin(Stdio::null())
+        .stdout(std::io::stderr())
+        .status()
+        .with_context(|| format!("Failed to run {stage} hook `{}`", command.join(" ")))?;
+
+    if !status.success() {
+        anyhow::bail!(
+            "The {stage} hook `{}` rejected `{}` ({status})",
+            command.join(" "),
+            filename.name
+        );
+    }
+
+    Ok(())
+}
@@ -12,15 +12,20 @@ use uv_distribution_types::CachedDist;
 use uv_install_wheel::{Layout, LinkMode};
 use uv_python::PythonEnvironment;
 
+use crate::{InstallHook, SourceDateEpoch};
+
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: LinkMode,
     cache: Option<&'a Cache>,
     reporter: Option<Arc<dyn Reporter>>,
+    hook: Option<Arc<dyn InstallHook>>,
     /// The name of the [`Installer`].
     name: Option<String>,
     /// The metadata associated with the [`Installer`].
     metadata: bool,
+    /// The fixed timestamp to use for reproducible installations.
+    source_date_epoch: Option<SourceDateEpoch>,
 }
 
 impl<'a> Installer<'a> {
@@ -31,8 +36,10 @@ impl<'a> Installer<'a> {
             link_mode: LinkMode::default(),
             cache: None,
             reporter: None,
+            hook: None,
             name: Some("uv".to_string()),
             metadata: true,
+            source_date_epoch: None,
         }
     }
 
@@ -60,6 +67,15 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the [`InstallHook`] to invoke around the installation of each wheel.
+    #[must_use]
+    pub fn with_hook(self, hook: Arc<dyn InstallHook>) -> Self {
+        Self {
+            hook: Some(hook),
+            ..self
+        }
+    }
+
     /// Set the `installer_name` to something other than `"uv"`.
     #[must_use]
     pub fn with_installer_name(self, installer_name: Option<String>) -> Self {
@@ -78,6 +94,18 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the [`SourceDateEpoch`] for reproducible installations.
+    ///
+    /// When set, metadata that varies between installations (e.g., the timestamps recorded in
+    /// `uv_cache.json`) is omitted from the installed distributions.
+    #[must_use]
+    pub fn with_source_date_epoch(self, source_date_epoch: Option<SourceDateEpoch>) -> Self {
+        Self {
+            source_date_epoch,
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -86,8 +114,10 @@ impl<'a> Installer<'a> {
             cache,
             link_mode,
             reporter,
+            hook,
             name: installer_name,
             metadata: installer_metadata,
+            source_date_epoch,
         } = self;
 
         if cache.is_some_and(Cache::is_temporary) {
@@ -111,8 +141,10 @@ impl<'a> Installer<'a> {
                 installer_name,
                 link_mode,
                 reporter,
+                hook,
                 relocatable,
                 installer_metadata,
+                source_date_epoch.is_some(),
             );
 
             // This may fail if the main task was cancelled.
@@ -141,8 +173,10 @@ impl<'a> Installer<'a> {
             self.name,
             self.link_mode,
             self.reporter,
+            self.hook,
             self.venv.relocatable(),
             self.metadata,
+            self.source_date_epoch.is_some(),
         )
     }
 }
@@ -155,14 +189,21 @@ fn install(
     installer_name: Option<String>,
     link_mode: LinkMode,
     reporter: Option<Arc<dyn Reporter>>,
+    hook: Option<Arc<dyn InstallHook>>,
     relocatable: bool,
     installer_metadata: bool,
+    reproducible: bool,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
     LazyLock::force(&RAYON_INITIALIZE);
     let locks = uv_install_wheel::Locks::default();
     wheels.par_iter().try_for_each(|wheel| {
-        uv_install_wheel::install_wheel(
+        if let Some(hook) = hook.as_ref() {
+            hook.pre_install(wheel)
+                .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
+        }
+
+        let dist_info = uv_install_wheel::install_wheel(
             &layout,
             relocatable,
             wheel.path(),
@@ -171,11 +212,12 @@ fn install(
                 .parsed_url()
                 .map(uv_pypi_types::DirectUrl::from)
                 .as_ref(),
-            if wheel.cache_info().is_empty() {
+            if reproducible || wheel.cache_info().is_empty() {
                 None
             } else {
                 Some(wheel.cache_info())
             },
+            wheel.hashes(),
             installer_name.as_deref(),
             installer_metadata,
             link_mode,
@@ -183,6 +225,11 @@ fn install(
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
 
+        if let Some(hook) = hook.as_ref() {
+            hook.post_install(wheel, &dist_info)
+                .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
+        }
+
         if let Some(reporter) = reporter.as_ref() {
             reporter.on_install_progress(wheel);
         }
@@ -1,15 +1,23 @@
 pub use compile::{CompileError, compile_tree};
+pub use conda::{CondaError, CondaPackages};
+pub use hooks::InstallHook;
 pub use installer::{Installer, Reporter as InstallReporter};
-pub use plan::{Plan, Planner};
-pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
+pub use plan::{Plan, Planner, is_seed_package, retain_seed_packages};
+pub use preparer::{CacheMiss, Error as PrepareError, Preparer, Reporter as PrepareReporter};
+pub use reproducible::{ReproducibleError, SourceDateEpoch};
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
+pub use transaction::{Transaction, TransactionError};
 pub use uninstall::{UninstallError, uninstall};
 
 mod compile;
+mod conda;
+mod hooks;
 mod preparer;
 
 mod installer;
 mod plan;
+mod reproducible;
 mod satisfies;
 mod site_packages;
+mod transaction;
 mod uninstall;
@@ -9,8 +9,8 @@ use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, LocalArchivePointer, RegistryWheelIndex,
 };
 use uv_distribution_types::{
-    BuiltDist, CachedDirectUrlDist, CachedDist, Dist, Error, Hashed, IndexLocations, InstalledDist,
-    Name, RequirementSource, Resolution, ResolvedDist, SourceDist,
+    BuiltDist, CachedDirectUrlDist, CachedDist, Dist, Error, HashPolicy, Hashed, IndexLocations,
+    InstalledDist, Name, RequirementSource, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::Simplified;
 use uv_platform_tags::Tags;
@@ -160,6 +160,14 @@ impl<'a> Planner<'a> {
                         if !entry.built && no_binary {
                             return None;
                         }
+                        // If the index requires hashes, the cached distribution must match them.
+                        if index_locations.require_hashes_from_index_for(&wheel.best_wheel().index)
+                            && !entry.dist.satisfies(HashPolicy::Validate(
+                                wheel.best_wheel().file.hashes.as_slice(),
+                            ))
+                        {
+                            return None;
+                        }
                         Some(&entry.dist)
                     }) {
                         debug!("Registry requirement already cached: {distribution}");
@@ -311,6 +319,14 @@ impl<'a> Planner<'a> {
                         if !entry.built && no_binary {
                             return None;
                         }
+                        // If the index requires hashes, the cached distribution must match them.
+                        if index_locations.require_hashes_from_index_for(&sdist.index)
+                            && !entry
+                                .dist
+                                .satisfies(HashPolicy::Validate(sdist.file.hashes.as_slice()))
+                        {
+                            return None;
+                        }
                         Some(&entry.dist)
                     }) {
                         debug!("Registry requirement already cached: {distribution}");
@@ -430,9 +446,7 @@ impl<'a> Planner<'a> {
 
         // Remove any unnecessary packages.
         if site_packages.any() {
-            // Retain seed packages unless: (1) the virtual environment was created by uv and
-            // (2) the `--seed` argument was not passed to `uv venv`.
-            let seed_packages = !venv.cfg().is_ok_and(|cfg| cfg.is_uv() && !cfg.is_seed());
+            let seed_packages = retain_seed_packages(venv);
             for dist_info in site_packages {
                 if seed_packages && is_seed_package(&dist_info, venv) {
                     debug!("Preserving seed package: {dist_info}");
@@ -453,8 +467,16 @@ impl<'a> Planner<'a> {
     }
 }
 
+/// Returns `true` if any seed packages in the environment should be retained, even if they aren't
+/// required.
+pub fn retain_seed_packages(venv: &PythonEnvironment) -> bool {
+    // Retain seed packages unless: (1) the virtual environment was created by uv and
+    // (2) the `--seed` argument was not passed to `uv venv`.
+    !venv.cfg().is_ok_and(|cfg| cfg.is_uv() && !cfg.is_seed())
+}
+
 /// Returns `true` if the given distribution is a seed package.
-fn is_seed_package(dist_info: &InstalledDist, venv: &PythonEnvironment) -> bool {
+pub fn is_seed_package(dist_info: &InstalledDist, venv: &PythonEnvironment) -> bool {
     if venv.interpreter().python_tuple() >= (3, 12) {
         matches!(dist_info.name().as_ref(), "uv" | "pip")
     } else {
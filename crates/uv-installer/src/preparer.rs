@@ -1,15 +1,17 @@
 use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+use std::pin::pin;
 use std::sync::Arc;
 
-use futures::{FutureExt, Stream, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
+use futures::{FutureExt, Stream, StreamExt, TryFutureExt, stream::FuturesUnordered};
 use tracing::{debug, instrument};
 
 use uv_cache::Cache;
 use uv_configuration::BuildOptions;
 use uv_distribution::{DistributionDatabase, LocalWheel};
 use uv_distribution_types::{
-    BuildableSource, CachedDist, DerivationChain, Dist, DistErrorKind, Hashed, Identifier, Name,
-    RemoteSource, Resolution,
+    BuildableSource, BuiltDist, CachedDist, DerivationChain, Dist, DistErrorKind, Hashed,
+    Identifier, Name, RemoteSource, Resolution, SourceDist,
 };
 use uv_pep508::PackageName;
 use uv_platform_tags::Tags;
@@ -95,10 +97,29 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
         distributions
             .sort_unstable_by_key(|distribution| Reverse(distribution.size().unwrap_or(u64::MAX)));
 
-        let wheels = self
-            .prepare_stream(distributions, in_flight, resolution)
-            .try_collect()
-            .await?;
+        let mut stream = pin!(self.prepare_stream(distributions, in_flight, resolution));
+
+        // When offline, continue past any distributions that are missing from the cache, such that
+        // all of them can be reported at once.
+        let mut wheels = Vec::new();
+        let mut misses = Vec::new();
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(wheel) => wheels.push(wheel),
+                Err(Error::Dist(_, dist, _, err)) if err.is_offline() => {
+                    misses.push(CacheMiss {
+                        url: err.offline_url().map(ToString::to_string),
+                        dist,
+                    });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if !misses.is_empty() {
+            misses.sort_unstable_by(|a, b| a.dist.name().cmp(b.dist.name()));
+            return Err(Error::Offline(misses));
+        }
 
         if let Some(reporter) = self.reporter.as_ref() {
             reporter.on_complete();
@@ -227,19 +248,34 @@ pub enum Error {
     CyclicBuildDependency(PackageName),
     #[error("Unzip failed in another thread: {0}")]
     Thread(String),
+    #[error(
+        "Network connectivity is disabled, but the following distributions are missing from the cache:{}",
+        _0.iter().map(|miss| format!("\n- {miss}")).collect::<String>()
+    )]
+    Offline(Vec<CacheMiss>),
 }
 
-impl Error {
-    /// Create an [`Error`] from a distribution error.
-    fn from_dist(dist: Dist, err: uv_distribution::Error, resolution: &Resolution) -> Self {
-        let chain =
-            DerivationChain::from_resolution(resolution, (&dist).into()).unwrap_or_default();
-        Self::Dist(
-            DistErrorKind::from_dist(&dist, &err),
-            Box::new(dist),
-            chain,
-            err,
-        )
+/// A distribution that couldn't be prepared because it's missing from the cache and network
+/// connectivity is disabled.
+#[derive(Debug)]
+pub struct CacheMiss {
+    /// The distribution that was missing from the cache.
+    pub dist: Box<Dist>,
+    /// The URL that would have been fetched, if known.
+    pub url: Option<String>,
+}
+
+impl Display for CacheMiss {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Distributions from a URL already include the URL in their display representation.
+        let is_registry = matches!(
+            *self.dist,
+            Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+        );
+        match &self.url {
+            Some(url) if is_registry => write!(f, "`{}` ({url})", self.dist),
+            _ => write!(f, "`{}`", self.dist),
+        }
     }
 }
 
@@ -0,0 +1,123 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use filetime::FileTime;
+use fs_err as fs;
+use thiserror::Error;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+
+/// A fixed timestamp to apply to the files in an environment, such that repeated installations
+/// produce identical `site-packages` directories.
+///
+/// See: <https://reproducible-builds.org/specs/source-date-epoch/>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceDateEpoch(FileTime);
+
+impl SourceDateEpoch {
+    /// Read the [`SourceDateEpoch`] from the `SOURCE_DATE_EPOCH` environment variable, if set.
+    pub fn from_env() -> Result<Option<Self>, ReproducibleError> {
+        let Some(value) = std::env::var_os(EnvVars::SOURCE_DATE_EPOCH) else {
+            return Ok(None);
+        };
+        let value = value.to_string_lossy();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        let seconds = value
+            .parse::<u64>()
+            .ok()
+            .and_then(|seconds| i64::try_from(seconds).ok())
+            .ok_or_else(|| ReproducibleError::InvalidEpoch(value.to_string()))?;
+        Ok(Some(Self(FileTime::from_unix_time(seconds, 0))))
+    }
+
+    /// Set the modification time of every file and directory in the environment's
+    /// `site-packages` to the epoch, along with any files that were installed outside of
+    /// `site-packages` (e.g., scripts), as listed in each distribution's `RECORD`.
+    ///
+    /// Symbolic links are updated in place, rather than followed, to avoid modifying files that
+    /// live outside of the environment (e.g., in the cache).
+    ///
+    /// Returns the number of paths that were updated.
+    pub fn normalize(&self, venv: &PythonEnvironment) -> Result<usize, ReproducibleError> {
+        let mut count = 0;
+        for site_packages in venv.site_packages() {
+            if !site_packages.is_dir() {
+                continue;
+            }
+            for entry in WalkDir::new(&site_packages) {
+                let entry = entry
+                    .map_err(|err| ReproducibleError::Walkdir(site_packages.to_path_buf(), err))?;
+
+                // Files installed outside of `site-packages` are listed in the `RECORD` with a
+                // relative path (e.g., `../../../bin/foo`).
+                if entry.depth() == 2
+                    && entry.file_name() == "RECORD"
+                    && entry
+                        .path()
+                        .parent()
+                        .and_then(Path::file_name)
+                        .is_some_and(|name| name.to_string_lossy().ends_with(".dist-info"))
+                {
+                    count += self.normalize_record(&site_packages, entry.path())?;
+                }
+
+                self.set(entry.path())?;
+                count += 1;
+            }
+        }
+        debug!("Normalized the modification time of {count} paths");
+        Ok(count)
+    }
+
+    /// Set the modification time of any files listed in the `RECORD` that live outside of
+    /// `site-packages`.
+    fn normalize_record(
+        &self,
+        site_packages: &Path,
+        record: &Path,
+    ) -> Result<usize, ReproducibleError> {
+        let mut reader = fs::File::open(record)?;
+        let entries = uv_install_wheel::read_record_file(&mut reader)?;
+        let mut count = 0;
+        for entry in entries {
+            if !entry.path.starts_with("../") {
+                continue;
+            }
+            let path = site_packages.join(&entry.path);
+            if path.symlink_metadata().is_err() {
+                continue;
+            }
+            self.set(&path)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Set the access and modification times of the given path, without following symlinks.
+    fn set(&self, path: &Path) -> Result<(), ReproducibleError> {
+        filetime::set_symlink_file_times(path, self.0, self.0)
+            .map_err(|err| ReproducibleError::SetTime(path.to_path_buf(), err))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReproducibleError {
+    #[error(
+        "Invalid value for `SOURCE_DATE_EPOCH`: `{0}` (expected a non-negative integer number of seconds)"
+    )]
+    InvalidEpoch(String),
+    #[error("Failed to list files in: `{}`", _0.user_display())]
+    Walkdir(PathBuf, #[source] walkdir::Error),
+    #[error("Failed to set the modification time of: `{}`", _0.user_display())]
+    SetTime(PathBuf, #[source] io::Error),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Record(#[from] uv_install_wheel::Error),
+}
@@ -0,0 +1,598 @@
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+use walkdir::WalkDir;
+
+use uv_distribution_types::{CachedDist, InstalledDist, Name};
+use uv_fs::Simplified;
+use uv_install_wheel::LibKind;
+use uv_python::PythonEnvironment;
+
+/// The name of the directory, within `site-packages`, that holds the journal of an in-progress
+/// installation.
+const TRANSACTION_DIR: &str = ".uv-transaction";
+
+/// The name of the journal file, within the transaction directory.
+const JOURNAL_FILE: &str = "journal.jsonl";
+
+/// A set of modifications to an environment, recorded in an on-disk journal such that they can be
+/// rolled back if the installation fails (or is interrupted) partway through.
+///
+/// Before a distribution is uninstalled, its files are backed up into the journal directory; before
+/// any distributions are installed, the unzipped wheels are recorded in the journal. The journal is
+/// append-only, with one JSON entry per line, such that recording a step doesn't rewrite the
+/// entries recorded before it. On failure,
+/// the newly installed files are removed and the backups are restored. If the process is killed
+/// before either [`Transaction::commit`] or [`Transaction::rollback`] is called, the journal is
+/// left behind, and the next installation rolls it back via [`Transaction::recover`].
+#[derive(Debug)]
+pub struct Transaction {
+    /// The `purelib` directory into which wheels are installed.
+    purelib: PathBuf,
+    /// The `platlib` directory into which wheels are installed.
+    platlib: PathBuf,
+    /// The directory containing the journal and the backed-up files.
+    directory: PathBuf,
+    /// The recorded modifications.
+    journal: Journal,
+}
+
+#[derive(Debug, Default)]
+struct Journal {
+    /// The files that were backed up prior to removal, as `(original, backup)` pairs.
+    backups: Vec<(PathBuf, PathBuf)>,
+    /// The unzipped wheels that are being installed into the environment.
+    installs: Vec<Install>,
+}
+
+impl Journal {
+    /// Add an entry to the in-memory journal.
+    fn push(&mut self, entry: Entry) {
+        match entry {
+            Entry::Backup { original, backup } => self.backups.push((original, backup)),
+            Entry::Install(install) => self.installs.push(install),
+        }
+    }
+}
+
+/// A single line in the on-disk journal.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Entry {
+    /// A file that was backed up prior to removal.
+    Backup { original: PathBuf, backup: PathBuf },
+    /// An unzipped wheel that's being installed into the environment.
+    Install(Install),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Install {
+    /// The unzipped wheel.
+    wheel: PathBuf,
+    /// The `site-packages` directory into which the wheel is installed (i.e., `purelib` or
+    /// `platlib`, depending on the wheel's `Root-Is-Purelib`).
+    root: PathBuf,
+}
+
+impl Transaction {
+    /// Begin a new [`Transaction`] for the given environment.
+    pub fn begin(venv: &PythonEnvironment) -> Result<Self, TransactionError> {
+        let (purelib, platlib, directory) = locations(venv)?;
+        Self::begin_in(purelib, platlib, directory)
+    }
+
+    /// Begin a new [`Transaction`] that installs into the given `purelib` and `platlib`
+    /// directories, with the journal stored in `directory`.
+    fn begin_in(
+        purelib: PathBuf,
+        platlib: PathBuf,
+        directory: PathBuf,
+    ) -> Result<Self, TransactionError> {
+        // Discard any leftovers from a previous transaction.
+        match fs::remove_dir_all(&directory) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        fs::create_dir_all(directory.join("backup"))?;
+        fs::File::create(directory.join(JOURNAL_FILE))?.sync_all()?;
+
+        Ok(Self {
+            purelib,
+            platlib,
+            directory,
+            journal: Journal::default(),
+        })
+    }
+
+    /// Roll back the journal of a previous installation that failed to complete, if any.
+    ///
+    /// Returns `true` if the environment was restored.
+    pub fn recover(venv: &PythonEnvironment) -> Result<bool, TransactionError> {
+        let (purelib, platlib, directory) = locations(venv)?;
+        Self::recover_in(purelib, platlib, directory)
+    }
+
+    /// Roll back the journal stored in `directory`, if any.
+    fn recover_in(
+        purelib: PathBuf,
+        platlib: PathBuf,
+        directory: PathBuf,
+    ) -> Result<bool, TransactionError> {
+        let path = directory.join(JOURNAL_FILE);
+        let journal = match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let mut journal = Journal::default();
+                let mut lines = contents.lines().filter(|line| !line.is_empty()).peekable();
+                while let Some(line) = lines.next() {
+                    match serde_json::from_str::<Entry>(line) {
+                        Ok(entry) => journal.push(entry),
+                        // An entry is only appended before the step it records is taken, so a
+                        // truncated final entry (from a process killed mid-write) can be skipped.
+                        Err(err) if lines.peek().is_none() && err.is_eof() => {
+                            debug!("Skipping truncated journal entry: {line}");
+                        }
+                        Err(err) => return Err(TransactionError::Journal(path, err)),
+                    }
+                }
+                journal
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                // If the journal was never written, no modifications were made.
+                if directory.is_dir() {
+                    fs::remove_dir_all(&directory)?;
+                }
+                return Ok(false);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        debug!(
+            "Found incomplete installation journal at: {}",
+            directory.user_display()
+        );
+        Self {
+            purelib,
+            platlib,
+            directory,
+            journal,
+        }
+        .rollback()?;
+        Ok(true)
+    }
+
+    /// Back up the files of an installed distribution, prior to uninstalling it.
+    pub fn backup(&mut self, dist: &InstalledDist) -> Result<(), TransactionError> {
+        let files = match dist {
+            InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+                let record = dist.install_path().join("RECORD");
+                let mut record = match fs::File::open(&record) {
+                    Ok(record) => record,
+                    // The uninstall will fail with a more descriptive error.
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+                    Err(err) => return Err(err.into()),
+                };
+                let site_packages = dist
+                    .install_path()
+                    .parent()
+                    .expect("dist-info directory is in a site-packages directory");
+                uv_install_wheel::read_record_file(&mut record)?
+                    .into_iter()
+                    .map(|entry| site_packages.join(entry.path))
+                    .collect::<Vec<_>>()
+            }
+            InstalledDist::EggInfoDirectory(_)
+            | InstalledDist::LegacyEditable(_)
+            | InstalledDist::EggInfoFile(_) => WalkDir::new(dist.install_path())
+                .into_iter()
+                .filter_map(Result::ok)
+                .map(walkdir::DirEntry::into_path)
+                .collect(),
+        };
+
+        let mut entries = Vec::new();
+        for original in files {
+            let Ok(metadata) = fs::symlink_metadata(&original) else {
+                continue;
+            };
+            if metadata.is_dir() {
+                continue;
+            }
+            let backup = self
+                .directory
+                .join("backup")
+                .join((self.journal.backups.len() + entries.len()).to_string());
+            if fs::hard_link(&original, &backup).is_err() {
+                fs::copy(&original, &backup)?;
+            }
+            entries.push(Entry::Backup { original, backup });
+        }
+
+        trace!("Backed up files for: {}", dist.name());
+        self.append(entries)
+    }
+
+    /// Record the distributions that are about to be installed.
+    pub fn record_installs(&mut self, wheels: &[CachedDist]) -> Result<(), TransactionError> {
+        self.record_wheels(wheels.iter().map(CachedDist::path))
+    }
+
+    /// Record the unzipped wheels that are about to be installed, along with the directory that
+    /// each one is installed into.
+    fn record_wheels<'a>(
+        &mut self,
+        wheels: impl IntoIterator<Item = &'a Path>,
+    ) -> Result<(), TransactionError> {
+        let entries = wheels
+            .into_iter()
+            .map(|wheel| {
+                let root = match lib_kind(wheel) {
+                    Some(LibKind::Plat) => self.platlib.clone(),
+                    Some(LibKind::Pure) | None => self.purelib.clone(),
+                };
+                Entry::Install(Install {
+                    wheel: wheel.to_path_buf(),
+                    root,
+                })
+            })
+            .collect();
+        self.append(entries)
+    }
+
+    /// Commit the [`Transaction`], discarding the journal and any backups.
+    pub fn commit(self) -> Result<(), TransactionError> {
+        fs::remove_dir_all(&self.directory)?;
+        Ok(())
+    }
+
+    /// Roll back the [`Transaction`], removing any newly installed files and restoring the backups.
+    pub fn rollback(self) -> Result<(), TransactionError> {
+        let installs = self.journal.installs.len();
+        let backups = self.journal.backups.len();
+        debug!(
+            "Rolling back {installs} install{} and {backups} backed-up file{}",
+            if installs == 1 { "" } else { "s" },
+            if backups == 1 { "" } else { "s" },
+        );
+
+        for install in self.journal.installs.iter().rev() {
+            remove_install(install)?;
+        }
+
+        for (original, backup) in &self.journal.backups {
+            match fs::remove_file(original) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+            if let Some(parent) = original.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            if fs::rename(backup, original).is_err() {
+                fs::copy(backup, original)?;
+            }
+            trace!("Restored file: {}", original.display());
+        }
+
+        fs::remove_dir_all(&self.directory)?;
+        Ok(())
+    }
+
+    /// Append the given entries to the on-disk journal, then add them to the in-memory journal.
+    fn append(&mut self, entries: Vec<Entry>) -> Result<(), TransactionError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let path = self.directory.join(JOURNAL_FILE);
+        let mut contents = Vec::new();
+        for entry in &entries {
+            serde_json::to_writer(&mut contents, entry)
+                .map_err(|err| TransactionError::Journal(path.clone(), err))?;
+            contents.push(b'\n');
+        }
+        let mut file = fs::OpenOptions::new().append(true).open(&path)?;
+        file.write_all(&contents)?;
+        file.sync_data()?;
+        for entry in entries {
+            self.journal.push(entry);
+        }
+        Ok(())
+    }
+}
+
+/// Return the `purelib` and `platlib` directories that wheels are installed into, along with the
+/// path to the journal directory, for the given environment.
+fn locations(venv: &PythonEnvironment) -> Result<(PathBuf, PathBuf, PathBuf), TransactionError> {
+    let Some(site_packages) = venv.site_packages().next() else {
+        return Err(TransactionError::MissingSitePackages);
+    };
+    let directory = site_packages.join(TRANSACTION_DIR);
+    let scheme = venv.interpreter().layout().scheme;
+    Ok((scheme.purelib, scheme.platlib, directory))
+}
+
+/// Return the name of the `.dist-info` directory in an unzipped wheel, if any.
+fn dist_info(wheel: &Path) -> Option<OsString> {
+    fs::read_dir(wheel)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name())
+        .find(|name| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|ext| ext == "dist-info")
+        })
+}
+
+/// Return the [`LibKind`] of an unzipped wheel, as declared in its `WHEEL` file.
+fn lib_kind(wheel: &Path) -> Option<LibKind> {
+    let dist_info = dist_info(wheel)?;
+    let contents = fs::read_to_string(wheel.join(dist_info).join("WHEEL")).ok()?;
+    uv_install_wheel::parse_wheel_file(&contents).ok()
+}
+
+/// Remove the files installed from an unzipped wheel.
+fn remove_install(install: &Install) -> Result<(), TransactionError> {
+    let Install { wheel, root } = install;
+
+    // Identify the `.dist-info` directory of the wheel.
+    let Some(dist_info) = dist_info(wheel) else {
+        debug!(
+            "Skipping rollback of wheel without `.dist-info` directory: {}",
+            wheel.user_display()
+        );
+        return Ok(());
+    };
+
+    // If the installation completed, the `RECORD` file lists all the installed files.
+    let target = root.join(&dist_info);
+    if target.join("RECORD").is_file() {
+        uv_install_wheel::uninstall_wheel(&target)?;
+        trace!("Removed installed files for: {}", target.display());
+        return Ok(());
+    }
+
+    // Otherwise, the installation was interrupted; remove any files that were linked from the
+    // wheel.
+    for entry in WalkDir::new(wheel).contents_first(true) {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let Ok(relative) = entry.path().strip_prefix(wheel) else {
+            continue;
+        };
+        if relative.as_os_str().is_empty() {
+            continue;
+        }
+        let target = root.join(relative);
+        if entry.file_type().is_dir() {
+            // Remove any directories left empty.
+            let _ = fs::remove_dir(&target);
+        } else {
+            match fs::remove_file(&target) {
+                Ok(()) => trace!("Removed file: {}", target.display()),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+    match fs::remove_dir_all(root.join(&dist_info)) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+    Ok(())
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TransactionError {
+    #[error("Failed to read installation journal at: `{}`", _0.user_display())]
+    Journal(PathBuf, #[source] serde_json::Error),
+    #[error("The environment does not have a `site-packages` directory")]
+    MissingSitePackages,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Uninstall(#[from] uv_install_wheel::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use fs_err as fs;
+
+    use uv_distribution_types::InstalledDist;
+
+    use super::{JOURNAL_FILE, TRANSACTION_DIR, Transaction};
+
+    /// Write an unzipped wheel for `foo` at the given version, with the given `__init__.py`.
+    fn write_wheel(wheel: &Path, version: &str, init: &str, purelib: bool) -> Result<()> {
+        let dist_info = wheel.join(format!("foo-{version}.dist-info"));
+        fs::create_dir_all(wheel.join("foo"))?;
+        fs::create_dir_all(&dist_info)?;
+        fs::write(wheel.join("foo").join("__init__.py"), init)?;
+        fs::write(
+            dist_info.join("METADATA"),
+            format!("Metadata-Version: 2.1\nName: foo\nVersion: {version}\n"),
+        )?;
+        fs::write(
+            dist_info.join("WHEEL"),
+            format!("Wheel-Version: 1.0\nRoot-Is-Purelib: {purelib}\nTag: py3-none-any\n"),
+        )?;
+        fs::write(
+            dist_info.join("RECORD"),
+            format!(
+                "foo/__init__.py,,\nfoo-{version}.dist-info/METADATA,,\nfoo-{version}.dist-info/WHEEL,,\nfoo-{version}.dist-info/RECORD,,\n"
+            ),
+        )?;
+        Ok(())
+    }
+
+    /// Copy an unzipped wheel into `site-packages`, optionally skipping its `RECORD` file to
+    /// simulate an interrupted installation.
+    fn link_wheel(wheel: &Path, site_packages: &Path, record: bool) -> Result<()> {
+        for entry in walkdir::WalkDir::new(wheel) {
+            let entry = entry?;
+            let target = site_packages.join(entry.path().strip_prefix(wheel)?);
+            if entry.file_type().is_dir() {
+                fs::create_dir_all(&target)?;
+            } else if record || entry.file_name() != "RECORD" {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+        Ok(())
+    }
+
+    struct Environment {
+        _temp: tempfile::TempDir,
+        purelib: std::path::PathBuf,
+        platlib: std::path::PathBuf,
+        wheel: std::path::PathBuf,
+    }
+
+    impl Environment {
+        /// Create an environment with `foo==1.0.0` installed into `purelib`, and an unzipped
+        /// `foo==2.0.0` wheel that installs into a separate `platlib`.
+        fn new() -> Result<Self> {
+            let temp = tempfile::tempdir()?;
+            let purelib = temp.path().join("purelib");
+            let platlib = temp.path().join("platlib");
+            fs::create_dir_all(&purelib)?;
+            fs::create_dir_all(&platlib)?;
+
+            let installed = temp.path().join("foo-1.0.0");
+            write_wheel(&installed, "1.0.0", "VERSION = 1\n", true)?;
+            link_wheel(&installed, &purelib, true)?;
+
+            let wheel = temp.path().join("foo-2.0.0");
+            write_wheel(&wheel, "2.0.0", "VERSION = 2\n", false)?;
+
+            Ok(Self {
+                _temp: temp,
+                purelib,
+                platlib,
+                wheel,
+            })
+        }
+
+        fn begin(&self) -> Result<Transaction> {
+            Ok(Transaction::begin_in(
+                self.purelib.clone(),
+                self.platlib.clone(),
+                self.purelib.join(TRANSACTION_DIR),
+            )?)
+        }
+
+        fn recover(&self) -> Result<bool> {
+            Ok(Transaction::recover_in(
+                self.purelib.clone(),
+                self.platlib.clone(),
+                self.purelib.join(TRANSACTION_DIR),
+            )?)
+        }
+
+        /// Back up and uninstall `foo==1.0.0`, then record the installation of `foo==2.0.0`.
+        fn upgrade(&self, transaction: &mut Transaction) -> Result<()> {
+            let dist = InstalledDist::try_from_path(&self.purelib.join("foo-1.0.0.dist-info"))?
+                .expect("a valid `.dist-info` directory");
+            transaction.backup(&dist)?;
+            uv_install_wheel::uninstall_wheel(dist.install_path())?;
+            transaction.record_wheels([self.wheel.as_path()])?;
+            Ok(())
+        }
+
+        /// Assert that the environment matches its state prior to the upgrade.
+        fn assert_restored(&self) -> Result<()> {
+            assert_eq!(
+                fs::read_to_string(self.purelib.join("foo").join("__init__.py"))?,
+                "VERSION = 1\n"
+            );
+            assert!(self.purelib.join("foo-1.0.0.dist-info/RECORD").is_file());
+            assert!(!self.platlib.join("foo").exists());
+            assert!(!self.platlib.join("foo-2.0.0.dist-info").exists());
+            assert!(!self.purelib.join(TRANSACTION_DIR).exists());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn recover_interrupted_install() -> Result<()> {
+        let env = Environment::new()?;
+
+        // Simulate a process that's killed partway through linking the new wheel, before writing
+        // its `RECORD`.
+        let mut transaction = env.begin()?;
+        env.upgrade(&mut transaction)?;
+        link_wheel(&env.wheel, &env.platlib, false)?;
+        drop(transaction);
+
+        assert!(!env.purelib.join("foo").join("__init__.py").exists());
+        assert!(env.platlib.join("foo").join("__init__.py").is_file());
+
+        assert!(env.recover()?);
+        env.assert_restored()?;
+
+        // Once recovered, there's nothing left to roll back.
+        assert!(!env.recover()?);
+        Ok(())
+    }
+
+    #[test]
+    fn recover_truncated_journal() -> Result<()> {
+        let env = Environment::new()?;
+
+        // Simulate a process that's killed while appending the record of the new wheel, before
+        // linking it.
+        let mut transaction = env.begin()?;
+        env.upgrade(&mut transaction)?;
+        drop(transaction);
+
+        let journal = env.purelib.join(TRANSACTION_DIR).join(JOURNAL_FILE);
+        let contents = fs::read_to_string(&journal)?;
+        let truncated = contents.trim_end().len() - 10;
+        fs::write(&journal, &contents[..truncated])?;
+
+        // The backups are still restored.
+        assert!(env.recover()?);
+        env.assert_restored()?;
+        Ok(())
+    }
+
+    #[test]
+    fn rollback_completed_install() -> Result<()> {
+        let env = Environment::new()?;
+
+        // Simulate an installation that completes, followed by a failure in a later step.
+        let mut transaction = env.begin()?;
+        env.upgrade(&mut transaction)?;
+        link_wheel(&env.wheel, &env.platlib, true)?;
+
+        transaction.rollback()?;
+        env.assert_restored()?;
+        Ok(())
+    }
+
+    #[test]
+    fn commit_install() -> Result<()> {
+        let env = Environment::new()?;
+
+        let mut transaction = env.begin()?;
+        env.upgrade(&mut transaction)?;
+        link_wheel(&env.wheel, &env.platlib, true)?;
+        transaction.commit()?;
+
+        assert!(!env.recover()?);
+        assert_eq!(
+            fs::read_to_string(env.platlib.join("foo").join("__init__.py"))?,
+            "VERSION = 2\n"
+        );
+        assert!(!env.purelib.join("foo-1.0.0.dist-info").exists());
+        Ok(())
+    }
+}
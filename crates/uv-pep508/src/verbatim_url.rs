@@ -577,6 +577,8 @@ pub enum Scheme {
     Http,
     /// `https://...`
     Https,
+    /// `oci://...`, for package indexes served from an OCI registry
+    Oci,
 }
 
 impl Scheme {
@@ -606,6 +608,7 @@ impl Scheme {
             "svn+file" => Some(Self::SvnFile),
             "http" => Some(Self::Http),
             "https" => Some(Self::Https),
+            "oci" => Some(Self::Oci),
             _ => None,
         }
     }
@@ -640,6 +643,7 @@ impl std::fmt::Display for Scheme {
             Self::SvnFile => write!(f, "svn+file"),
             Self::Http => write!(f, "http"),
             Self::Https => write!(f, "https"),
+            Self::Oci => write!(f, "oci"),
         }
     }
 }
@@ -0,0 +1,147 @@
+//! PEP 740 attestations, uploaded alongside the distributions they attest to.
+//!
+//! uv doesn't sign attestations itself; they're generated by `pypi-attestations sign`, which signs
+//! with Sigstore using the ambient OIDC identity of the CI job (or an interactive sign-in), and
+//! writes each attestation next to its distribution.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use uv_fs::Simplified;
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error("Failed to read attestation: `{}`", _0.user_display())]
+    Io(PathBuf, #[source] io::Error),
+    #[error("Invalid attestation: `{}`", _0.user_display())]
+    Json(PathBuf, #[source] serde_json::Error),
+    #[error("Unsupported attestation version {1}: `{}`", _0.user_display())]
+    Version(PathBuf, u32),
+}
+
+/// A PEP 740 attestation for a distribution.
+#[derive(Debug, Clone)]
+pub struct Attestation {
+    /// The attestation, as JSON.
+    raw: String,
+    /// The index of the signature's entry in the Rekor transparency log.
+    log_index: Option<u64>,
+}
+
+/// The fields of an attestation that uv inspects.
+///
+/// See: <https://peps.python.org/pep-0740/#attestation-objects>
+#[derive(Deserialize)]
+struct AttestationObject {
+    version: u32,
+    verification_material: VerificationMaterial,
+}
+
+#[derive(Deserialize)]
+struct VerificationMaterial {
+    #[serde(default)]
+    transparency_entries: Vec<TransparencyEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransparencyEntry {
+    /// The log index, which is encoded as a string in the Protobuf JSON mapping.
+    #[serde(default)]
+    log_index: Option<LogIndex>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LogIndex {
+    Number(u64),
+    String(String),
+}
+
+impl Attestation {
+    /// The path of the publish attestation for a distribution, i.e.,
+    /// `<distribution>.publish.attestation`.
+    pub fn path(file: &Path) -> PathBuf {
+        let mut path = file.as_os_str().to_owned();
+        path.push(".publish.attestation");
+        PathBuf::from(path)
+    }
+
+    /// Read the publish attestation for a distribution, if it exists.
+    pub fn read(file: &Path) -> Result<Option<Self>, AttestationError> {
+        let path = Self::path(file);
+        let raw = match fs_err::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(AttestationError::Io(path, err)),
+        };
+        Self::parse(&path, raw).map(Some)
+    }
+
+    fn parse(path: &Path, raw: String) -> Result<Self, AttestationError> {
+        let object: AttestationObject = serde_json::from_str(&raw)
+            .map_err(|err| AttestationError::Json(path.to_path_buf(), err))?;
+        if object.version != 1 {
+            return Err(AttestationError::Version(
+                path.to_path_buf(),
+                object.version,
+            ));
+        }
+        let log_index = object
+            .verification_material
+            .transparency_entries
+            .into_iter()
+            .find_map(|entry| match entry.log_index? {
+                LogIndex::Number(index) => Some(index),
+                LogIndex::String(index) => index.parse().ok(),
+            });
+        Ok(Self {
+            raw: raw.trim().to_string(),
+            log_index,
+        })
+    }
+
+    /// The index of the signature's entry in the Rekor transparency log, if any.
+    pub fn log_index(&self) -> Option<u64> {
+        self.log_index
+    }
+
+    /// The value of the `attestations` field of the upload form, i.e., a JSON array of
+    /// attestations.
+    pub(crate) fn form_value(&self) -> String {
+        format!("[{}]", self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let path = Path::new("ok-1.0.0-py3-none-any.whl.publish.attestation");
+        let attestation = Attestation::parse(
+            path,
+            r#"{"version": 1, "verification_material": {"certificate": "MII...", "transparency_entries": [{"logIndex": "148218231", "logId": {"keyId": "wNI9..."}}]}, "envelope": {"statement": "eyJf...", "signature": "MEUC..."}}"#
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(attestation.log_index(), Some(148_218_231));
+        assert!(attestation.form_value().starts_with("[{\"version\": 1"));
+        assert_eq!(
+            Attestation::path(Path::new("dist/ok-1.0.0-py3-none-any.whl")),
+            PathBuf::from("dist/ok-1.0.0-py3-none-any.whl.publish.attestation")
+        );
+
+        assert!(matches!(
+            Attestation::parse(
+                path,
+                r#"{"version": 2, "verification_material": {}}"#.to_string()
+            ),
+            Err(AttestationError::Version(_, 2))
+        ));
+    }
+}
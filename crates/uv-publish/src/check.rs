@@ -0,0 +1,534 @@
+//! Checks for distributions before they are uploaded, similar to `twine check`.
+//!
+//! The checks catch problems that registries reject an upload for (or that make the project page
+//! render incorrectly), such that they surface before anything is sent to the registry.
+
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use futures::TryStreamExt;
+use itertools::Itertools;
+use tokio::io::BufReader;
+
+use fs_err::tokio::File;
+use uv_distribution_filename::{DistFilename, WheelFilename};
+use uv_metadata::find_archive_dist_info;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::PlatformTag;
+use uv_pypi_types::Metadata23;
+
+use crate::{PublishPrepareError, metadata};
+
+/// The metadata versions known to registries.
+const METADATA_VERSIONS: &[&str] = &["1.0", "1.1", "1.2", "2.1", "2.2", "2.3", "2.4"];
+
+/// The maximum length of the summary accepted by PyPI.
+const MAX_SUMMARY_LENGTH: usize = 512;
+
+/// The severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The file can be uploaded, but its metadata is incomplete.
+    Warning,
+    /// The registry will reject the file, or the project page won't render.
+    Error,
+}
+
+/// A problem found when checking a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Check a wheel or source distribution for problems before uploading it.
+///
+/// Checks the completeness of the core metadata, the rendering of the long description, the
+/// presence of the declared license files, the wheel tags, and the consistency of the metadata
+/// with the filename.
+pub async fn check_file(
+    file: &Path,
+    raw_filename: &str,
+    filename: &DistFilename,
+) -> Result<Vec<Diagnostic>, PublishPrepareError> {
+    let metadata = metadata(file, filename).await?;
+    let mut diagnostics = Vec::new();
+
+    check_filename(&metadata, raw_filename, filename, &mut diagnostics);
+    check_core_metadata(&metadata, &mut diagnostics);
+    check_description(&metadata, &mut diagnostics);
+    check_license(&metadata, &mut diagnostics);
+
+    match filename {
+        DistFilename::WheelFilename(wheel) => {
+            let contents = WheelContents::read(file, wheel)?;
+            for license_file in &metadata.license_files {
+                let path = format!("{}.dist-info/licenses/{license_file}", contents.dist_info);
+                if !contents.files.contains(&path) {
+                    diagnostics.push(missing_license_file(license_file));
+                }
+            }
+            check_wheel_tags(wheel, contents.wheel.as_deref(), &mut diagnostics);
+        }
+        DistFilename::SourceDistFilename(_) => {
+            let files = source_dist_files(file).await?;
+            for license_file in &metadata.license_files {
+                if !files.contains(license_file) {
+                    diagnostics.push(missing_license_file(license_file));
+                }
+            }
+        }
+    }
+
+    diagnostics.sort_by_key(|diagnostic| std::cmp::Reverse(diagnostic.severity));
+    Ok(diagnostics)
+}
+
+/// Check that the name and version in the metadata match the filename.
+fn check_filename(
+    metadata: &Metadata23,
+    raw_filename: &str,
+    filename: &DistFilename,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    match PackageName::from_str(&metadata.name) {
+        Ok(name) if name == *filename.name() => {}
+        Ok(_) => diagnostics.push(Diagnostic::error(format!(
+            "The name in the metadata (`{}`) doesn't match the name in the filename (`{}`)",
+            metadata.name,
+            filename.name()
+        ))),
+        Err(err) => diagnostics.push(Diagnostic::error(format!(
+            "The name in the metadata is invalid: {err}"
+        ))),
+    }
+    match Version::from_str(&metadata.version) {
+        Ok(version) if version == *filename.version() => {}
+        Ok(_) => diagnostics.push(Diagnostic::error(format!(
+            "The version in the metadata (`{}`) doesn't match the version in the filename (`{}`)",
+            metadata.version,
+            filename.version()
+        ))),
+        Err(err) => diagnostics.push(Diagnostic::error(format!(
+            "The version in the metadata is invalid: {err}"
+        ))),
+    }
+
+    // See PEP 625.
+    if let DistFilename::SourceDistFilename(source_dist) = filename {
+        let expected = format!(
+            "{}-{}.tar.gz",
+            source_dist.name.as_dist_info_name(),
+            source_dist.version
+        );
+        if raw_filename != expected {
+            diagnostics.push(Diagnostic::warning(format!(
+                "The source distribution filename is not normalized, expected `{expected}`"
+            )));
+        }
+    }
+}
+
+/// Check that the core metadata is complete.
+fn check_core_metadata(metadata: &Metadata23, diagnostics: &mut Vec<Diagnostic>) {
+    if !METADATA_VERSIONS.contains(&metadata.metadata_version.as_str()) {
+        diagnostics.push(Diagnostic::warning(format!(
+            "Unknown metadata version `{}`, the registry may reject it",
+            metadata.metadata_version
+        )));
+    }
+
+    match metadata.summary.as_deref().map(str::trim) {
+        None | Some("") => diagnostics.push(Diagnostic::warning(
+            "The metadata is missing a summary (`Summary`), set `project.description` in `pyproject.toml`",
+        )),
+        Some(summary) if summary.contains('\n') => diagnostics.push(Diagnostic::error(
+            "The summary (`Summary`) must be a single line",
+        )),
+        Some(summary) if summary.chars().count() > MAX_SUMMARY_LENGTH => {
+            diagnostics.push(Diagnostic::error(format!(
+                "The summary (`Summary`) must be at most {MAX_SUMMARY_LENGTH} characters long"
+            )));
+        }
+        Some(_) => {}
+    }
+
+    if metadata.requires_python.is_none() {
+        diagnostics.push(Diagnostic::warning(
+            "The metadata is missing the supported Python versions (`Requires-Python`), set \
+            `project.requires-python` in `pyproject.toml`",
+        ));
+    }
+}
+
+/// Check that the long description renders on the project page.
+fn check_description(metadata: &Metadata23, diagnostics: &mut Vec<Diagnostic>) {
+    let Some(description) = metadata
+        .description
+        .as_deref()
+        .filter(|description| !description.trim().is_empty())
+    else {
+        diagnostics.push(Diagnostic::warning(
+            "The metadata is missing a long description, set `project.readme` in `pyproject.toml`",
+        ));
+        return;
+    };
+
+    let Some(content_type) = &metadata.description_content_type else {
+        diagnostics.push(Diagnostic::warning(
+            "The metadata is missing a description content type (`Description-Content-Type`), \
+            the long description is rendered as reStructuredText",
+        ));
+        check_rst(description, diagnostics);
+        return;
+    };
+
+    let mut parameters = content_type.split(';').map(str::trim);
+    let mime_type = parameters.next().unwrap_or_default().to_ascii_lowercase();
+    for parameter in parameters {
+        let Some((key, value)) = parameter.split_once('=') else {
+            continue;
+        };
+        match key.trim().to_ascii_lowercase().as_str() {
+            "charset" if !value.trim().eq_ignore_ascii_case("utf-8") => {
+                diagnostics.push(Diagnostic::error(format!(
+                    "Unsupported description charset `{}`, only `UTF-8` is supported",
+                    value.trim()
+                )));
+            }
+            "variant" if mime_type == "text/markdown" => {
+                if !matches!(value.trim(), "GFM" | "CommonMark") {
+                    diagnostics.push(Diagnostic::error(format!(
+                        "Unsupported Markdown variant `{}`, expected `GFM` or `CommonMark`",
+                        value.trim()
+                    )));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match mime_type.as_str() {
+        "text/plain" | "text/markdown" => {}
+        "text/x-rst" => check_rst(description, diagnostics),
+        _ => diagnostics.push(Diagnostic::error(format!(
+            "Unsupported description content type `{mime_type}`, expected `text/plain`, \
+            `text/x-rst`, or `text/markdown`"
+        ))),
+    }
+}
+
+/// Check a reStructuredText description for section titles with underlines that are too short,
+/// which fail rendering on PyPI.
+fn check_rst(description: &str, diagnostics: &mut Vec<Diagnostic>) {
+    for (number, (title, underline)) in description.lines().tuple_windows().enumerate() {
+        let title = title.trim_end();
+        let underline = underline.trim_end();
+        if title.is_empty() || title.starts_with(char::is_whitespace) || is_adornment(title) {
+            continue;
+        }
+        // Docutils treats underlines with fewer than four characters as text.
+        if !is_adornment(underline) || underline.len() < 4 {
+            continue;
+        }
+        if underline.chars().count() < title.chars().count() {
+            diagnostics.push(Diagnostic::error(format!(
+                "The long description fails to render: the underline of the title `{title}` \
+                on line {} is too short",
+                number + 1
+            )));
+        }
+    }
+}
+
+/// Returns `true` if the line is a reStructuredText section adornment, e.g., `=====`.
+fn is_adornment(line: &str) -> bool {
+    let mut chars = line.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_punctuation() && chars.all(|char| char == first))
+}
+
+/// Check the license metadata (see PEP 639).
+fn check_license(metadata: &Metadata23, diagnostics: &mut Vec<Diagnostic>) {
+    let has_license_classifier = metadata
+        .classifiers
+        .iter()
+        .any(|classifier| classifier.starts_with("License ::"));
+    if metadata.license_expression.is_some() {
+        if metadata.license.is_some() {
+            diagnostics.push(Diagnostic::error(
+                "The metadata sets both a license expression (`License-Expression`) and a \
+                legacy license (`License`), remove `project.license.text` or \
+                `project.license.file` from `pyproject.toml`",
+            ));
+        }
+        if has_license_classifier {
+            diagnostics.push(Diagnostic::error(
+                "The metadata sets both a license expression (`License-Expression`) and license \
+                classifiers, remove the `License ::` classifiers from `pyproject.toml`",
+            ));
+        }
+    } else if metadata.license.is_none() && !has_license_classifier {
+        diagnostics.push(Diagnostic::warning(
+            "The metadata is missing a license, set `project.license` in `pyproject.toml`",
+        ));
+    }
+
+    if metadata.license_expression.is_some() || !metadata.license_files.is_empty() {
+        let supported = Version::from_str(&metadata.metadata_version)
+            .is_ok_and(|version| version >= Version::new([2, 4]));
+        if !supported {
+            diagnostics.push(Diagnostic::error(format!(
+                "`License-Expression` and `License-File` require metadata version 2.4 or later, \
+                but the metadata version is `{}`; upgrade the build backend",
+                metadata.metadata_version
+            )));
+        }
+    }
+}
+
+fn missing_license_file(license_file: &str) -> Diagnostic {
+    Diagnostic::error(format!(
+        "The license file `{license_file}` is declared in the metadata (`License-File`), but \
+        missing from the distribution"
+    ))
+}
+
+/// Check that the tags in the `WHEEL` file match the filename, and that the registry accepts the
+/// platform tags.
+fn check_wheel_tags(
+    filename: &WheelFilename,
+    wheel: Option<&str>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for platform_tag in filename.platform_tags() {
+        if matches!(platform_tag, PlatformTag::Linux { .. }) {
+            diagnostics.push(Diagnostic::error(format!(
+                "PyPI rejects wheels with the platform tag `{platform_tag}`, use `auditwheel \
+                repair` to convert it to a `manylinux` wheel"
+            )));
+        }
+    }
+
+    let Some(wheel) = wheel else {
+        diagnostics.push(Diagnostic::error(
+            "The wheel is missing the `WHEEL` file in its `.dist-info` directory",
+        ));
+        return;
+    };
+    let declared: BTreeSet<String> = wheel
+        .lines()
+        .filter_map(|line| line.strip_prefix("Tag:"))
+        .map(|tag| tag.trim().to_string())
+        .collect();
+    let expected: BTreeSet<String> = filename
+        .python_tags()
+        .iter()
+        .cartesian_product(filename.abi_tags())
+        .cartesian_product(filename.platform_tags())
+        .map(|((python, abi), platform)| format!("{python}-{abi}-{platform}"))
+        .collect();
+    if declared != expected {
+        diagnostics.push(Diagnostic::error(format!(
+            "The tags in the `WHEEL` file (`{}`) don't match the tags in the filename (`{}`)",
+            declared.iter().join("`, `"),
+            expected.iter().join("`, `"),
+        )));
+    }
+}
+
+/// The files in a wheel.
+struct WheelContents {
+    /// The `.dist-info` directory prefix, without the `.dist-info` extension.
+    dist_info: String,
+    /// The paths of the files in the wheel.
+    files: BTreeSet<String>,
+    /// The contents of the `WHEEL` file, if any.
+    wheel: Option<String>,
+}
+
+impl WheelContents {
+    fn read(file: &Path, filename: &WheelFilename) -> Result<Self, PublishPrepareError> {
+        let mut archive = zip::ZipArchive::new(fs_err::File::open(file)?)
+            .map_err(|err| PublishPrepareError::Metadata(err.into()))?;
+        let files: BTreeSet<String> = archive.file_names().map(ToString::to_string).collect();
+        let dist_info =
+            find_archive_dist_info(filename, files.iter().map(|name| ((), name.as_str())))?
+                .1
+                .to_string();
+
+        let path = format!("{dist_info}.dist-info/WHEEL");
+        let wheel = if files.contains(&path) {
+            let mut contents = String::new();
+            archive
+                .by_name(&path)
+                .map_err(|err| PublishPrepareError::Metadata(err.into()))?
+                .read_to_string(&mut contents)
+                .map_err(|err| PublishPrepareError::Read(path, err))?;
+            Some(contents)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            dist_info,
+            files,
+            wheel,
+        })
+    }
+}
+
+/// The paths of the files in a source distribution, relative to its top-level directory.
+async fn source_dist_files(file: &Path) -> Result<BTreeSet<String>, PublishPrepareError> {
+    let reader = BufReader::new(File::open(&file).await?);
+    let decoded = async_compression::tokio::bufread::GzipDecoder::new(reader);
+    let mut archive = tokio_tar::Archive::new(decoded);
+    archive
+        .entries()?
+        .map_err(PublishPrepareError::from)
+        .try_filter_map(async |entry| {
+            let path = entry.path().map_err(PublishPrepareError::from)?;
+            let mut components = path.components();
+            let Some(_top_level) = components.next() else {
+                return Ok(None);
+            };
+            let relative = components.as_path();
+            if relative.as_os_str().is_empty() {
+                return Ok(None);
+            }
+            Ok(Some(relative.to_string_lossy().replace('\\', "/")))
+        })
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(contents: &str) -> Metadata23 {
+        Metadata23::parse(contents.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn description() {
+        let mut diagnostics = Vec::new();
+        check_description(
+            &metadata(
+                "Metadata-Version: 2.4\nName: foo\nVersion: 1.0\nDescription-Content-Type: text/x-rst\n\nTitle\n===\n\nLonger title\n=====\n",
+            ),
+            &mut diagnostics,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "The long description fails to render: the underline of the title `Longer title` on line 4 is too short"
+            )]
+        );
+
+        let mut diagnostics = Vec::new();
+        check_description(
+            &metadata(
+                "Metadata-Version: 2.4\nName: foo\nVersion: 1.0\nDescription-Content-Type: text/markdown; variant=Markua\n\n# Title\n",
+            ),
+            &mut diagnostics,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "Unsupported Markdown variant `Markua`, expected `GFM` or `CommonMark`"
+            )]
+        );
+
+        let mut diagnostics = Vec::new();
+        check_description(
+            &metadata(
+                "Metadata-Version: 2.4\nName: foo\nVersion: 1.0\nDescription-Content-Type: text/markdown\n\nTitle\n===\n",
+            ),
+            &mut diagnostics,
+        );
+        assert_eq!(diagnostics, Vec::new());
+    }
+
+    #[test]
+    fn license() {
+        let mut diagnostics = Vec::new();
+        check_license(
+            &metadata(
+                "Metadata-Version: 2.3\nName: foo\nVersion: 1.0\nLicense: MIT\nLicense-Expression: MIT\n",
+            ),
+            &mut diagnostics,
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.severity == Severity::Error)
+        );
+
+        let mut diagnostics = Vec::new();
+        check_license(
+            &metadata("Metadata-Version: 2.4\nName: foo\nVersion: 1.0\nLicense-Expression: MIT\n"),
+            &mut diagnostics,
+        );
+        assert_eq!(diagnostics, Vec::new());
+    }
+
+    #[test]
+    fn wheel_tags() {
+        let filename = WheelFilename::from_str("foo-1.0-cp312-cp312-linux_x86_64.whl").unwrap();
+        let mut diagnostics = Vec::new();
+        check_wheel_tags(
+            &filename,
+            Some("Wheel-Version: 1.0\nTag: cp312-cp312-linux_x86_64\n"),
+            &mut diagnostics,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "PyPI rejects wheels with the platform tag `linux_x86_64`, use `auditwheel repair` to convert it to a `manylinux` wheel"
+            )]
+        );
+
+        let filename = WheelFilename::from_str("foo-1.0-py2.py3-none-any.whl").unwrap();
+        let mut diagnostics = Vec::new();
+        check_wheel_tags(
+            &filename,
+            Some("Wheel-Version: 1.0\nTag: py3-none-any\n"),
+            &mut diagnostics,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "The tags in the `WHEEL` file (`py3-none-any`) don't match the tags in the filename (`py2-none-any`, `py3-none-any`)"
+            )]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+mod attestations;
+mod check;
 mod trusted_publishing;
 
 use std::path::{Path, PathBuf};
@@ -12,8 +14,7 @@ use itertools::Itertools;
 use reqwest::header::AUTHORIZATION;
 use reqwest::multipart::Part;
 use reqwest::{Body, Response, StatusCode};
-use reqwest_retry::policies::ExponentialBackoff;
-use reqwest_retry::{RetryPolicy, Retryable, RetryableStrategy};
+use reqwest_retry::RetryPolicy;
 use rustc_hash::FxHashSet;
 use serde::Deserialize;
 use thiserror::Error;
@@ -27,20 +28,26 @@ use url::Url;
 use uv_auth::Credentials;
 use uv_cache::{Cache, Refresh};
 use uv_client::{
-    BaseClient, DEFAULT_RETRIES, MetadataFormat, OwnedArchive, RegistryClientBuilder,
-    RequestBuilder, UvRetryableStrategy,
+    BaseClient, HttpRetryPolicy, MetadataFormat, OwnedArchive, RegistryClientBuilder,
+    RequestBuilder, SimpleMetadata,
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
-use uv_distribution_types::{IndexCapabilities, IndexUrl};
+use uv_distribution_types::{
+    File as IndexFile, IndexCapabilities, IndexQuirks, IndexUrl, ToUrlError,
+};
 use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 
+pub use crate::attestations::{Attestation, AttestationError};
+pub use crate::check::{Diagnostic, Severity, check_file};
 use crate::trusted_publishing::TrustedPublishingError;
 
 #[derive(Error, Debug)]
@@ -78,6 +85,16 @@ pub enum PublishError {
     },
     #[error("Hash is missing in index for {0}")]
     MissingHash(Box<DistFilename>),
+    #[error("{0} is not listed on the index")]
+    MissingFile(Box<DistFilename>),
+    #[error("Invalid URL for {0} on the index")]
+    FileUrl(Box<DistFilename>, #[source] ToUrlError),
+    #[error("Failed to download {0} from {1}")]
+    Download(
+        Box<DistFilename>,
+        DisplaySafeUrl,
+        #[source] reqwest_middleware::Error,
+    ),
 }
 
 /// Failure to get the metadata for a specific file.
@@ -386,18 +403,36 @@ pub async fn upload(
     registry: &DisplaySafeUrl,
     client: &BaseClient,
     credentials: &Credentials,
+    attestation: Option<&Attestation>,
+    quirks: Option<IndexQuirks>,
+    retry_policy: &HttpRetryPolicy,
     check_url_client: Option<&CheckUrlClient<'_>>,
     download_concurrency: &Semaphore,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
-    let form_metadata = FormMetadata::read_from_file(file, filename)
+    let mut form_metadata = FormMetadata::read_from_file(file, filename)
         .await
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
+    // Omit the fields that the registry is known to reject.
+    let omitted = quirks
+        .map(IndexQuirks::omitted_upload_fields)
+        .unwrap_or_default();
+    form_metadata.omit(omitted);
+    let attestation = attestation.filter(|_| {
+        if omitted.contains(&"attestations") {
+            warn_user_once!("{registry} doesn't support attestations, skipping them");
+            false
+        } else {
+            true
+        }
+    });
+
     let mut n_past_retries = 0;
     let start_time = SystemTime::now();
-    // N.B. We cannot use the client policy here because it is set to zero retries
-    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(DEFAULT_RETRIES);
+    // N.B. We cannot use the client's retry middleware here, as the request isn't cloneable, so
+    // the client is set to zero retries and we apply the policy manually.
+    let backoff = retry_policy.backoff();
     loop {
         let (request, idx) = build_request(
             file,
@@ -407,22 +442,40 @@ pub async fn upload(
             client,
             credentials,
             &form_metadata,
+            attestation,
             reporter.clone(),
         )
         .await
         .map_err(|err| PublishError::PublishPrepare(file.to_path_buf(), Box::new(err)))?;
 
         let result = request.send().await;
-        if UvRetryableStrategy.handle(&result) == Some(Retryable::Transient) {
-            let retry_decision = retry_policy.should_retry(start_time, n_past_retries);
+        if retry_policy.is_transient(&result) {
+            let retry_decision = backoff.should_retry(start_time, n_past_retries);
             if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
-                warn_user!("Transient failure while handling response for {registry}; retrying...");
                 reporter.on_upload_complete(idx);
-                let duration = execute_after
+                let mut duration = execute_after
                     .duration_since(SystemTime::now())
                     .unwrap_or_else(|_| Duration::default());
-                tokio::time::sleep(duration).await;
+                let reason = match &result {
+                    Ok(response) => {
+                        if let Some(delay) = retry_policy.retry_after(response) {
+                            duration = duration.max(delay);
+                        }
+                        format!("status {}", response.status())
+                    }
+                    Err(err) => err.to_string(),
+                };
                 n_past_retries += 1;
+                // The legacy upload API can't resume a partial upload, so the whole file is sent
+                // again.
+                warn_user!(
+                    "Transient failure while uploading {filename} to {registry} ({reason}); \
+                    retrying in {:.1}s (attempt {} of {})...",
+                    duration.as_secs_f64(),
+                    n_past_retries,
+                    retry_policy.retries
+                );
+                tokio::time::sleep(duration).await;
                 continue;
             }
         }
@@ -465,13 +518,14 @@ pub async fn upload(
     }
 }
 
-/// Check whether we should skip the upload of a file because it already exists on the index.
-pub async fn check_url(
+/// Query the check URL for the distributions of a package.
+///
+/// Returns `None` if the package doesn't exist in the registry.
+async fn simple_metadata(
     check_url_client: &CheckUrlClient<'_>,
-    file: &Path,
-    filename: &DistFilename,
+    name: &PackageName,
     download_concurrency: &Semaphore,
-) -> Result<bool, PublishError> {
+) -> Result<Option<SimpleMetadata>, PublishError> {
     let CheckUrlClient {
         index_url,
         registry_client_builder,
@@ -483,16 +537,15 @@ pub async fn check_url(
     // Avoid using the PyPI 10min default cache.
     let cache_refresh = (*cache)
         .clone()
-        .with_refresh(Refresh::from_args(None, vec![filename.name().clone()]));
+        .with_refresh(Refresh::from_args(None, vec![name.clone()]));
     let registry_client = registry_client_builder
         .clone()
         .cache(cache_refresh)
         .wrap_existing(client);
 
-    debug!("Checking for {filename} in the registry");
     let response = match registry_client
         .package_metadata(
-            filename.name(),
+            name,
             Some(index_url.into()),
             index_capabilities,
             download_concurrency,
@@ -502,13 +555,7 @@ pub async fn check_url(
         Ok(response) => response,
         Err(err) => {
             return match err.into_kind() {
-                uv_client::ErrorKind::PackageNotFound(_) => {
-                    // The package doesn't exist, so we can't have uploaded it.
-                    warn!(
-                        "Package not found in the registry; skipping upload check for {filename}"
-                    );
-                    Ok(false)
-                }
+                uv_client::ErrorKind::PackageNotFound(_) => Ok(None),
                 kind => Err(PublishError::CheckUrlIndex(kind.into())),
             };
         }
@@ -516,29 +563,43 @@ pub async fn check_url(
     let [(_, MetadataFormat::Simple(simple_metadata))] = response.as_slice() else {
         unreachable!("We queried a single index, we must get a single response");
     };
-    let simple_metadata = OwnedArchive::deserialize(simple_metadata);
-    let Some(metadatum) = simple_metadata
-        .iter()
-        .find(|metadatum| &metadatum.version == filename.version())
+    Ok(Some(OwnedArchive::deserialize(simple_metadata)))
+}
+
+/// Check whether a version of a package already exists on the index.
+pub async fn version_exists(
+    check_url_client: &CheckUrlClient<'_>,
+    name: &PackageName,
+    version: &Version,
+    download_concurrency: &Semaphore,
+) -> Result<bool, PublishError> {
+    debug!("Checking for {name} {version} in the registry");
+    let Some(simple_metadata) =
+        simple_metadata(check_url_client, name, download_concurrency).await?
     else {
         return Ok(false);
     };
+    Ok(simple_metadata
+        .iter()
+        .any(|metadatum| &metadatum.version == version))
+}
 
-    let archived_file = match filename {
-        DistFilename::SourceDistFilename(source_dist) => metadatum
-            .files
-            .source_dists
-            .iter()
-            .find(|entry| &entry.name == source_dist)
-            .map(|entry| &entry.file),
-        DistFilename::WheelFilename(wheel) => metadatum
-            .files
-            .wheels
-            .iter()
-            .find(|entry| &entry.name == wheel)
-            .map(|entry| &entry.file),
+/// Check whether we should skip the upload of a file because it already exists on the index.
+pub async fn check_url(
+    check_url_client: &CheckUrlClient<'_>,
+    file: &Path,
+    filename: &DistFilename,
+    download_concurrency: &Semaphore,
+) -> Result<bool, PublishError> {
+    debug!("Checking for {filename} in the registry");
+    let Some(simple_metadata) =
+        simple_metadata(check_url_client, filename.name(), download_concurrency).await?
+    else {
+        // The package doesn't exist, so we can't have uploaded it.
+        warn!("Package not found in the registry; skipping upload check for {filename}");
+        return Ok(false);
     };
-    let Some(archived_file) = archived_file else {
+    let Some(archived_file) = find_file(&simple_metadata, filename) else {
         return Ok(false);
     };
 
@@ -573,6 +634,101 @@ pub async fn check_url(
     }
 }
 
+/// Download a published file from the index and verify that it matches the local file.
+///
+/// Unlike [`check_url`], which compares against the hash listed by the index, this fetches the
+/// file itself, as an installer would, such that files that are listed but not (yet) served are
+/// detected.
+pub async fn verify_download(
+    check_url_client: &CheckUrlClient<'_>,
+    file: &Path,
+    filename: &DistFilename,
+    download_concurrency: &Semaphore,
+) -> Result<(), PublishError> {
+    debug!("Verifying the download of {filename} from the registry");
+    let simple_metadata = simple_metadata(check_url_client, filename.name(), download_concurrency)
+        .await?
+        .ok_or_else(|| PublishError::MissingFile(Box::new(filename.clone())))?;
+    let archived_file = find_file(&simple_metadata, filename)
+        .ok_or_else(|| PublishError::MissingFile(Box::new(filename.clone())))?;
+    let remote_hash = archived_file
+        .hashes
+        .first()
+        .ok_or_else(|| PublishError::MissingHash(Box::new(filename.clone())))?;
+    let url = archived_file
+        .url
+        .to_url()
+        .map_err(|err| PublishError::FileUrl(Box::new(filename.clone()), err))?;
+
+    let download_error = |err: reqwest_middleware::Error| {
+        PublishError::Download(Box::new(filename.clone()), url.clone(), err)
+    };
+    let response = check_url_client
+        .client
+        .for_host(&url)
+        .get(Url::from(url.clone()))
+        .send()
+        .await
+        .map_err(download_error)?
+        .error_for_status()
+        .map_err(|err| download_error(err.into()))?;
+    let mut hasher = Hasher::from(remote_hash.algorithm);
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream
+        .try_next()
+        .await
+        .map_err(|err| download_error(err.into()))?
+    {
+        hasher.update(&chunk);
+    }
+    let downloaded_hash = HashDigest::from(hasher);
+
+    let local_hash = hash_file(file, Hasher::from(remote_hash.algorithm))
+        .await
+        .map_err(|err| {
+            PublishError::PublishPrepare(file.to_path_buf(), Box::new(PublishPrepareError::Io(err)))
+        })?;
+    for hash in [remote_hash, &downloaded_hash] {
+        if local_hash.digest != hash.digest {
+            return Err(PublishError::HashMismatch {
+                filename: Box::new(filename.clone()),
+                hash_algorithm: hash.algorithm,
+                local: local_hash.digest.to_string(),
+                remote: hash.digest.to_string(),
+            });
+        }
+    }
+    debug!(
+        "Downloaded {filename} with matching hash {}",
+        local_hash.digest
+    );
+    Ok(())
+}
+
+/// Find the entry for a file among the distributions of a package on the index.
+fn find_file<'a>(
+    simple_metadata: &'a SimpleMetadata,
+    filename: &DistFilename,
+) -> Option<&'a IndexFile> {
+    let metadatum = simple_metadata
+        .iter()
+        .find(|metadatum| &metadatum.version == filename.version())?;
+    match filename {
+        DistFilename::SourceDistFilename(source_dist) => metadatum
+            .files
+            .source_dists
+            .iter()
+            .find(|entry| &entry.name == source_dist)
+            .map(|entry| &entry.file),
+        DistFilename::WheelFilename(wheel) => metadatum
+            .files
+            .wheels
+            .iter()
+            .find(|entry| &entry.name == wheel)
+            .map(|entry| &entry.file),
+    }
+}
+
 /// Calculate the SHA256 of a file.
 async fn hash_file(path: impl AsRef<Path>, hasher: Hasher) -> Result<HashDigest, io::Error> {
     debug!("Hashing {}", path.as_ref().display());
@@ -752,6 +908,18 @@ impl FormMetadata {
         Ok(Self(form_metadata))
     }
 
+    /// Remove the given fields, e.g., because the registry rejects them.
+    fn omit(&mut self, fields: &[&str]) {
+        self.0.retain(|(name, _)| {
+            if fields.contains(name) {
+                debug!("Omitting `{name}` from the upload");
+                false
+            } else {
+                true
+            }
+        });
+    }
+
     /// Returns an iterator over the metadata fields.
     fn iter(&self) -> std::slice::Iter<'_, (&'static str, String)> {
         self.0.iter()
@@ -777,12 +945,17 @@ async fn build_request<'a>(
     client: &'a BaseClient,
     credentials: &Credentials,
     form_metadata: &FormMetadata,
+    attestation: Option<&Attestation>,
     reporter: Arc<impl Reporter>,
 ) -> Result<(RequestBuilder<'a>, usize), PublishPrepareError> {
     let mut form = reqwest::multipart::Form::new();
     for (key, value) in form_metadata.iter() {
         form = form.text(*key, value.clone());
     }
+    if let Some(attestation) = attestation {
+        debug!("Attaching attestation");
+        form = form.text("attestations", attestation.form_value());
+    }
 
     let file = File::open(file).await?;
     let file_size = file.metadata().await?.len();
@@ -1004,6 +1177,7 @@ mod tests {
             &client,
             &Credentials::basic(Some("ferris".to_string()), Some("F3RR!S".to_string())),
             &form_metadata,
+            None,
             Arc::new(DummyReporter),
         )
         .await
@@ -1156,6 +1330,7 @@ mod tests {
             &client,
             &Credentials::basic(Some("ferris".to_string()), Some("F3RR!S".to_string())),
             &form_metadata,
+            None,
             Arc::new(DummyReporter),
         )
         .await
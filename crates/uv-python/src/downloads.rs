@@ -725,7 +725,7 @@ impl ManagedPythonDownload {
         let mut total_attempts = 0;
         let mut retried_here = false;
         let start_time = SystemTime::now();
-        let retry_policy = client.retry_policy();
+        let retry_policy = client.download_retry_policy();
         loop {
             let result = self
                 .fetch(
@@ -260,6 +260,17 @@ impl PythonEnvironment {
         })))
     }
 
+    /// Create a [`PythonEnvironment`] that omits scripts (e.g., entry points) when installing
+    /// packages.
+    #[must_use]
+    pub fn without_scripts(self) -> Self {
+        let inner = Arc::unwrap_or_clone(self.0);
+        Self(Arc::new(PythonEnvironmentShared {
+            interpreter: inner.interpreter.without_scripts(),
+            ..inner
+        }))
+    }
+
     /// Returns the root (i.e., `prefix`) of the Python interpreter.
     pub fn root(&self) -> &Path {
         &self.0.root
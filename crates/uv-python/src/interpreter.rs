@@ -56,6 +56,7 @@ pub struct Interpreter {
     tags: OnceLock<Tags>,
     target: Option<Target>,
     prefix: Option<Prefix>,
+    install_scripts: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
     real_executable: PathBuf,
@@ -91,6 +92,7 @@ impl Interpreter {
             tags: OnceLock::new(),
             target: None,
             prefix: None,
+            install_scripts: true,
             real_executable: executable.as_ref().to_path_buf(),
         })
     }
@@ -127,6 +129,16 @@ impl Interpreter {
         })
     }
 
+    /// Return a new [`Interpreter`] that omits scripts (e.g., entry points) when installing
+    /// packages.
+    #[must_use]
+    pub fn without_scripts(self) -> Self {
+        Self {
+            install_scripts: false,
+            ..self
+        }
+    }
+
     /// Return the base Python executable; that is, the Python executable that should be
     /// considered the "base" for the virtual environment. This is typically the Python executable
     /// from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
@@ -534,6 +546,7 @@ impl Interpreter {
             python_version: self.python_tuple(),
             sys_executable: self.sys_executable().to_path_buf(),
             os_name: self.markers.os_name().to_string(),
+            install_scripts: self.install_scripts,
             scheme: if let Some(target) = self.target.as_ref() {
                 target.scheme()
             } else if let Some(prefix) = self.prefix.as_ref() {
@@ -15,6 +15,8 @@ pub enum RequirementsSource {
     Editable(RequirementsTxtRequirement),
     /// Dependencies were provided via a `pylock.toml` file.
     PylockToml(PathBuf),
+    /// Dependencies were provided via a `uv.lock` file (e.g., `pip install -r uv.lock`).
+    UvLock(PathBuf),
     /// Dependencies were provided via a `requirements.txt` file (e.g., `pip install -r requirements.txt`).
     RequirementsTxt(PathBuf),
     /// Dependencies were provided via a `pyproject.toml` file (e.g., `pip-compile pyproject.toml`).
@@ -39,6 +41,8 @@ impl RequirementsSource {
             Ok(Self::SetupCfg(path))
         } else if path.ends_with("environment.yml") {
             Ok(Self::EnvironmentYml(path))
+        } else if path.ends_with("uv.lock") {
+            Ok(Self::UvLock(path))
         } else if path
             .file_name()
             .is_some_and(|file_name| file_name.to_str().is_some_and(is_pylock_toml))
@@ -59,7 +63,7 @@ impl RequirementsSource {
 
     /// Parse a [`RequirementsSource`] from a `requirements.txt` file.
     pub fn from_requirements_txt(path: PathBuf) -> Result<Self> {
-        for file_name in ["pyproject.toml", "setup.py", "setup.cfg"] {
+        for file_name in ["pyproject.toml", "setup.py", "setup.cfg", "uv.lock"] {
             if path.ends_with(file_name) {
                 return Err(anyhow::anyhow!(
                     "The file `{}` appears to be a `{}` file, but requirements must be specified in `requirements.txt` format",
@@ -91,7 +95,7 @@ impl RequirementsSource {
 
     /// Parse a [`RequirementsSource`] from a `constraints.txt` file.
     pub fn from_constraints_txt(path: PathBuf) -> Result<Self> {
-        for file_name in ["pyproject.toml", "setup.py", "setup.cfg"] {
+        for file_name in ["pyproject.toml", "setup.py", "setup.cfg", "uv.lock"] {
             if path.ends_with(file_name) {
                 return Err(anyhow::anyhow!(
                     "The file `{}` appears to be a `{}` file, but constraints must be specified in `requirements.txt` format",
@@ -123,7 +127,7 @@ impl RequirementsSource {
 
     /// Parse a [`RequirementsSource`] from an `overrides.txt` file.
     pub fn from_overrides_txt(path: PathBuf) -> Result<Self> {
-        for file_name in ["pyproject.toml", "setup.py", "setup.cfg"] {
+        for file_name in ["pyproject.toml", "setup.py", "setup.cfg", "uv.lock"] {
             if path.ends_with(file_name) {
                 return Err(anyhow::anyhow!(
                     "The file `{}` appears to be a `{}` file, but overrides must be specified in `requirements.txt` format",
@@ -181,6 +185,7 @@ impl RequirementsSource {
         if (name == "pyproject.toml"
             || name == "setup.py"
             || name == "setup.cfg"
+            || name == "uv.lock"
             || is_pylock_toml(name))
             && Path::new(&name).is_file()
         {
@@ -231,6 +236,7 @@ impl RequirementsSource {
         if (name == "pyproject.toml"
             || name == "setup.py"
             || name == "setup.cfg"
+            || name == "uv.lock"
             || is_pylock_toml(name))
             && Path::new(&name).is_file()
         {
@@ -273,13 +279,20 @@ impl RequirementsSource {
     pub fn allows_extras(&self) -> bool {
         matches!(
             self,
-            Self::PylockToml(_) | Self::PyprojectToml(_) | Self::SetupPy(_) | Self::SetupCfg(_)
+            Self::PylockToml(_)
+                | Self::UvLock(_)
+                | Self::PyprojectToml(_)
+                | Self::SetupPy(_)
+                | Self::SetupCfg(_)
         )
     }
 
     /// Returns `true` if the source allows groups to be specified.
     pub fn allows_groups(&self) -> bool {
-        matches!(self, Self::PylockToml(_) | Self::PyprojectToml(_))
+        matches!(
+            self,
+            Self::PylockToml(_) | Self::UvLock(_) | Self::PyprojectToml(_)
+        )
     }
 }
 
@@ -289,6 +302,7 @@ impl std::fmt::Display for RequirementsSource {
             Self::Package(package) => write!(f, "{package:?}"),
             Self::Editable(path) => write!(f, "-e {path:?}"),
             Self::PylockToml(path)
+            | Self::UvLock(path)
             | Self::RequirementsTxt(path)
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
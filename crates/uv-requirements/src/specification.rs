@@ -62,6 +62,8 @@ pub struct RequirementsSpecification {
     pub overrides: Vec<UnresolvedRequirementSpecification>,
     /// The `pylock.toml` file from which to extract the resolution.
     pub pylock: Option<PathBuf>,
+    /// The `uv.lock` file from which to extract the resolution.
+    pub uv_lock: Option<PathBuf>,
     /// The source trees from which to extract requirements.
     pub source_trees: Vec<PathBuf>,
     /// The groups to use for `source_trees`
@@ -201,6 +203,16 @@ impl RequirementsSpecification {
                     ..Self::default()
                 }
             }
+            RequirementsSource::UvLock(path) => {
+                if !path.is_file() {
+                    return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+                }
+
+                Self {
+                    uv_lock: Some(path.clone()),
+                    ..Self::default()
+                }
+            }
             RequirementsSource::EnvironmentYml(path) => {
                 return Err(anyhow::anyhow!(
                     "Conda environment files (i.e., `{}`) are not supported",
@@ -220,9 +232,10 @@ impl RequirementsSpecification {
     ) -> Result<Self> {
         let mut spec = Self::default();
 
-        // Disallow `pylock.toml` files as constraints.
+        // Disallow `pylock.toml` and `uv.lock` files as constraints.
         if let Some(pylock_toml) = constraints.iter().find_map(|source| {
-            if let RequirementsSource::PylockToml(path) = source {
+            if let RequirementsSource::PylockToml(path) | RequirementsSource::UvLock(path) = source
+            {
                 Some(path)
             } else {
                 None
@@ -234,9 +247,10 @@ impl RequirementsSpecification {
             ));
         }
 
-        // Disallow `pylock.toml` files as overrides.
+        // Disallow `pylock.toml` and `uv.lock` files as overrides.
         if let Some(pylock_toml) = overrides.iter().find_map(|source| {
-            if let RequirementsSource::PylockToml(path) = source {
+            if let RequirementsSource::PylockToml(path) | RequirementsSource::UvLock(path) = source
+            {
                 Some(path)
             } else {
                 None
@@ -248,42 +262,42 @@ impl RequirementsSpecification {
             ));
         }
 
-        // If we have a `pylock.toml`, don't allow additional requirements, constraints, or
-        // overrides.
-        if let Some(pylock_toml) = requirements.iter().find_map(|source| {
-            if let RequirementsSource::PylockToml(path) = source {
-                Some(path)
-            } else {
-                None
-            }
+        // If we have a `pylock.toml` or `uv.lock`, don't allow additional requirements,
+        // constraints, or overrides.
+        if let Some((pylock_toml, kind)) = requirements.iter().find_map(|source| match source {
+            RequirementsSource::PylockToml(path) => Some((path, "pylock.toml")),
+            RequirementsSource::UvLock(path) => Some((path, "uv.lock")),
+            _ => None,
         }) {
-            if requirements
-                .iter()
-                .any(|source| !matches!(source, RequirementsSource::PylockToml(..)))
-            {
+            if requirements.iter().any(|source| {
+                !matches!(
+                    source,
+                    RequirementsSource::PylockToml(..) | RequirementsSource::UvLock(..)
+                )
+            }) {
                 return Err(anyhow::anyhow!(
-                    "Cannot specify additional requirements alongside a `pylock.toml` file",
+                    "Cannot specify additional requirements alongside a `{kind}` file",
                 ));
             }
             if !constraints.is_empty() {
                 return Err(anyhow::anyhow!(
-                    "Cannot specify additional requirements with a `pylock.toml` file"
+                    "Cannot specify additional requirements with a `{kind}` file"
                 ));
             }
             if !overrides.is_empty() {
                 return Err(anyhow::anyhow!(
-                    "Cannot specify constraints with a `pylock.toml` file"
+                    "Cannot specify constraints with a `{kind}` file"
                 ));
             }
 
-            // If we have a `pylock.toml`, disallow specifying paths for groups; instead, require
-            // that all groups refer to the `pylock.toml` file.
+            // If we have a `pylock.toml` or `uv.lock`, disallow specifying paths for groups;
+            // instead, require that all groups refer to the lockfile.
             if let Some(groups) = groups {
                 let mut names = Vec::new();
                 for group in &groups.groups {
                     if group.path.is_some() {
                         return Err(anyhow::anyhow!(
-                            "Cannot specify paths for groups with a `pylock.toml` file; all groups must refer to the `pylock.toml` file"
+                            "Cannot specify paths for groups with a `{kind}` file; all groups must refer to the `{kind}` file"
                         ));
                     }
                     names.push(group.name.clone());
@@ -365,9 +379,28 @@ impl RequirementsSpecification {
                         pylock.user_display()
                     ));
                 }
+                if let Some(existing) = spec.uv_lock.as_ref() {
+                    return Err(anyhow::anyhow!(
+                        "Multiple lockfiles specified: `{}` vs. `{}`",
+                        existing.user_display(),
+                        pylock.user_display()
+                    ));
+                }
                 spec.pylock = Some(pylock);
             }
 
+            // Allow at most one `uv.lock`, which can't be combined with a `pylock.toml`.
+            if let Some(uv_lock) = source.uv_lock {
+                if let Some(existing) = spec.uv_lock.as_ref().or(spec.pylock.as_ref()) {
+                    return Err(anyhow::anyhow!(
+                        "Multiple lockfiles specified: `{}` vs. `{}`",
+                        existing.user_display(),
+                        uv_lock.user_display()
+                    ));
+                }
+                spec.uv_lock = Some(uv_lock);
+            }
+
             // Use the first project name discovered.
             if spec.project.is_none() {
                 spec.project = source.project;
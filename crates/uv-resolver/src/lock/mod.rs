@@ -27,10 +27,10 @@ use uv_distribution_filename::{
 };
 use uv_distribution_types::{
     BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
-    Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations, IndexMetadata,
+    Dist, DistributionMetadata, File, FileLocation, GitSourceDist, IndexLocations, IndexMetadata,
     IndexUrl, Name, PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel,
     RegistrySourceDist, RemoteSource, Requirement, RequirementSource, RequiresPython, ResolvedDist,
-    SimplifiedMarkerTree, StaticMetadata, ToUrlError, UrlString,
+    SimplifiedMarkerTree, SourcePatch, SourcePatches, StaticMetadata, ToUrlError, UrlString,
 };
 use uv_fs::{PortablePath, PortablePathBuf, relative_to};
 use uv_git::{RepositoryReference, ResolvedRepositoryReference};
@@ -57,6 +57,8 @@ pub use crate::lock::export::{PylockToml, PylockTomlErrorKind};
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
 pub use crate::lock::tree::TreeDisplay;
+use crate::lock::url_prefix::UrlPrefixes;
+use crate::lock::wheel_list::WheelLists;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
@@ -67,6 +69,8 @@ mod export;
 mod installable;
 mod map;
 mod tree;
+mod url_prefix;
+mod wheel_list;
 
 /// The current version of the lockfile format.
 pub const VERSION: u32 = 1;
@@ -74,6 +78,13 @@ pub const VERSION: u32 = 1;
 /// The current revision of the lockfile format.
 const REVISION: u32 = 2;
 
+/// The revision of the lockfile format that introduced the compact format, in which URL prefixes
+/// and wheel lists are shared across packages.
+///
+/// Only compact lockfiles are written with this revision, so the format remains readable by
+/// versions of uv that predate it unless `uv lock --minimize` is used.
+const COMPACT_REVISION: u32 = 3;
+
 static LINUX_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
     let pep508 = MarkerTree::from_str("os_name == 'posix' and sys_platform == 'linux'").unwrap();
     UniversalMarker::new(pep508, ConflictMarker::TRUE)
@@ -153,6 +164,9 @@ pub struct Lock {
     by_id: FxHashMap<PackageId, usize>,
     /// The input requirements to the resolution.
     manifest: ResolverManifest,
+    /// Whether the lockfile is written in the compact format, in which common URL prefixes and
+    /// wheel lists are deduplicated into shared `[url-prefixes]` and `[wheel-lists]` tables.
+    compact: bool,
 }
 
 impl Lock {
@@ -537,6 +551,7 @@ impl Lock {
             packages,
             by_id,
             manifest,
+            compact: false,
         };
         Ok(lock)
     }
@@ -555,6 +570,18 @@ impl Lock {
         self
     }
 
+    /// Set whether the lockfile should be written in the compact format.
+    #[must_use]
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self.revision = if compact {
+            self.revision.max(COMPACT_REVISION)
+        } else {
+            self.revision.min(REVISION)
+        };
+        self
+    }
+
     /// Record the supported environments that were used to generate this lock.
     #[must_use]
     pub fn with_supported_environments(mut self, supported_environments: Vec<MarkerTree>) -> Self {
@@ -584,6 +611,140 @@ impl Lock {
         self
     }
 
+    /// Returns the packages that are locked against the given index.
+    pub fn index_packages(
+        &self,
+        index: &IndexUrl,
+        root: &Path,
+    ) -> Result<Vec<&Package>, LockError> {
+        let source = Source::from_index_url(index, root)?;
+        Ok(self
+            .packages
+            .iter()
+            .filter(|package| package.id.source == source)
+            .collect())
+    }
+
+    /// Re-pin the packages locked against the `old` index to the `new` index, without
+    /// re-resolving.
+    ///
+    /// Each artifact is matched by filename against the `files` available on the new index, and
+    /// its URL is rewritten to the location reported by the new index. If both the lockfile and the
+    /// new index provide a hash for an artifact, they must match. Versions are left unchanged.
+    ///
+    /// Returns the migrated [`Lock`], along with the number of packages that were migrated.
+    pub fn migrate_index(
+        &self,
+        old: &IndexUrl,
+        new: &IndexUrl,
+        files: &FxHashMap<PackageName, Vec<File>>,
+        root: &Path,
+    ) -> Result<(Self, usize), LockError> {
+        let old_source = Source::from_index_url(old, root)?;
+        let new_source = Source::from_index_url(new, root)?;
+        if !matches!(new_source, Source::Registry(RegistrySource::Url(_))) {
+            return Err(LockErrorKind::MigrateToLocalIndex { index: new.clone() }.into());
+        }
+
+        let mut migrated = 0;
+        let mut packages = self.packages.clone();
+        for package in &mut packages {
+            for dependency in package
+                .dependencies
+                .iter_mut()
+                .chain(package.optional_dependencies.values_mut().flatten())
+                .chain(package.dependency_groups.values_mut().flatten())
+            {
+                if dependency.package_id.source == old_source {
+                    dependency.package_id.source = new_source.clone();
+                }
+            }
+
+            if package.id.source != old_source {
+                continue;
+            }
+
+            let available = files
+                .get(&package.id.name)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let find = |filename: &str| {
+                available
+                    .iter()
+                    .find(|file| file.filename.as_ref() == filename)
+                    .ok_or_else(|| LockErrorKind::MigrationMissingArtifact {
+                        id: package.id.clone(),
+                        filename: filename.to_string(),
+                        index: new.clone(),
+                    })
+            };
+
+            let sdist = if let Some(sdist) = &package.sdist {
+                if let Some(filename) = sdist.filename() {
+                    let file = find(&filename)?;
+                    let metadata = match sdist {
+                        SourceDist::Url { metadata, .. }
+                        | SourceDist::Path { metadata, .. }
+                        | SourceDist::Metadata { metadata } => metadata,
+                    };
+                    let hash =
+                        migrate_hash(&package.id, &filename, new, metadata.hash.as_ref(), file)?;
+                    Some(SourceDist::Url {
+                        url: normalize_file_location(&file.url)
+                            .map_err(LockErrorKind::InvalidUrl)?,
+                        metadata: SourceDistMetadata {
+                            hash,
+                            size: file.size.or(metadata.size),
+                            upload_time: migrate_upload_time(file)?.or(metadata.upload_time),
+                        },
+                    })
+                } else {
+                    Some(sdist.clone())
+                }
+            } else {
+                None
+            };
+
+            let mut wheels = Vec::with_capacity(package.wheels.len());
+            for wheel in &package.wheels {
+                let filename = wheel.filename.to_string();
+                let file = find(&filename)?;
+                let hash = migrate_hash(&package.id, &filename, new, wheel.hash.as_ref(), file)?;
+                wheels.push(Wheel {
+                    url: WheelWireSource::Url {
+                        url: normalize_file_location(&file.url)
+                            .map_err(LockErrorKind::InvalidUrl)?,
+                    },
+                    hash,
+                    size: file.size.or(wheel.size),
+                    upload_time: migrate_upload_time(file)?.or(wheel.upload_time),
+                    filename: wheel.filename.clone(),
+                });
+            }
+
+            package.sdist = sdist;
+            package.wheels = wheels;
+            package.id.source = new_source.clone();
+            migrated += 1;
+        }
+
+        let lock = Self::new(
+            self.version,
+            self.revision,
+            packages,
+            self.requires_python.clone(),
+            self.options.clone(),
+            self.manifest.clone(),
+            self.conflicts.clone(),
+            self.supported_environments.clone(),
+            self.required_environments.clone(),
+            self.fork_markers.clone(),
+        )?
+        .with_compact(self.compact);
+
+        Ok((lock, migrated))
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -607,6 +768,11 @@ impl Lock {
         self.revision
     }
 
+    /// Returns `true` if the lockfile is written in the compact format.
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
     /// Returns the number of packages in the lockfile.
     pub fn len(&self) -> usize {
         self.packages.len()
@@ -688,6 +854,11 @@ impl Lock {
         )
     }
 
+    /// Return the patches to apply to source distributions, as recorded in the lockfile.
+    pub fn patches(&self, root: &Path) -> SourcePatches {
+        SourcePatches::from_entries(root.to_path_buf(), self.manifest.patches.iter().cloned())
+    }
+
     /// Return the workspace root used to generate this lock.
     pub fn root(&self) -> Option<&Package> {
         self.packages.iter().find(|package| {
@@ -1055,6 +1226,22 @@ impl Lock {
                 manifest_table.insert("dependency-metadata", Item::ArrayOfTables(tables));
             }
 
+            if !self.manifest.patches.is_empty() {
+                let mut tables = ArrayOfTables::new();
+                for patch in &self.manifest.patches {
+                    let mut table = Table::new();
+                    table.insert("name", value(patch.name.to_string()));
+                    table.insert("version", value(patch.version.to_string()));
+                    table.insert(
+                        "path",
+                        value(PortablePath::from(patch.path.as_path()).to_string()),
+                    );
+                    table.insert("hash", value(patch.hash.to_string()));
+                    tables.push(table);
+                }
+                manifest_table.insert("patches", Item::ArrayOfTables(tables));
+            }
+
             if !manifest_table.is_empty() {
                 doc.insert("manifest", Item::Table(manifest_table));
             }
@@ -1069,9 +1256,46 @@ impl Lock {
             *dist_count_by_name.entry(dist.id.name.clone()).or_default() += 1;
         }
 
+        // In the compact format, deduplicate the URL prefixes and wheel lists shared across
+        // packages.
+        let (url_prefixes, wheel_lists) = if self.compact {
+            (
+                UrlPrefixes::from_urls(self.packages.iter().flat_map(Package::artifact_urls)),
+                WheelLists::from_packages(&self.packages),
+            )
+        } else {
+            (UrlPrefixes::default(), WheelLists::default())
+        };
+        if !url_prefixes.is_empty() {
+            let mut url_prefixes_table = Table::new();
+            for (alias, prefix) in url_prefixes.iter() {
+                url_prefixes_table.insert(alias, value(prefix));
+            }
+            doc.insert("url-prefixes", Item::Table(url_prefixes_table));
+        }
+        if !wheel_lists.is_empty() {
+            let mut wheel_lists_table = Table::new();
+            for (alias, wheels) in wheel_lists.iter() {
+                let wheels = each_element_on_its_line_array(
+                    wheels
+                        .iter()
+                        .map(|wheel| wheel.to_toml(&url_prefixes))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .into_iter(),
+                );
+                wheel_lists_table.insert(alias, value(wheels));
+            }
+            doc.insert("wheel-lists", Item::Table(wheel_lists_table));
+        }
+
         let mut packages = ArrayOfTables::new();
         for dist in &self.packages {
-            packages.push(dist.to_toml(&self.requires_python, &dist_count_by_name)?);
+            packages.push(dist.to_toml(
+                &self.requires_python,
+                &dist_count_by_name,
+                &url_prefixes,
+                &wheel_lists,
+            )?);
         }
 
         doc.insert("package", Item::ArrayOfTables(packages));
@@ -1262,6 +1486,7 @@ impl Lock {
         build_constraints: &[Requirement],
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         dependency_metadata: &DependencyMetadata,
+        patches: &[SourcePatch],
         indexes: Option<&IndexLocations>,
         tags: &Tags,
         hasher: &HashStrategy,
@@ -1430,6 +1655,15 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same patches.
+        {
+            let expected = patches.iter().cloned().collect::<BTreeSet<_>>();
+            let actual = &self.manifest.patches;
+            if expected != *actual {
+                return Ok(SatisfiesResult::MismatchedPatches(expected, actual));
+            }
+        }
+
         // Collect the set of available indexes (both `--index-url` and `--find-links` entries).
         let remotes = indexes.map(|locations| {
             locations
@@ -1794,6 +2028,8 @@ pub enum SatisfiesResult<'lock> {
     ),
     /// The lockfile uses different static metadata.
     MismatchedStaticMetadata(BTreeSet<StaticMetadata>, &'lock BTreeSet<StaticMetadata>),
+    /// The lockfile uses different patches.
+    MismatchedPatches(BTreeSet<SourcePatch>, &'lock BTreeSet<SourcePatch>),
     /// The lockfile is missing a workspace member.
     MissingRoot(PackageName),
     /// The lockfile referenced a remote index that was not provided
@@ -1873,6 +2109,9 @@ pub struct ResolverManifest {
     /// The static metadata provided to the resolver.
     #[serde(default)]
     dependency_metadata: BTreeSet<StaticMetadata>,
+    /// The patches applied to source distributions.
+    #[serde(default)]
+    patches: BTreeSet<SourcePatch>,
 }
 
 impl ResolverManifest {
@@ -1886,6 +2125,7 @@ impl ResolverManifest {
         build_constraints: impl IntoIterator<Item = Requirement>,
         dependency_groups: impl IntoIterator<Item = (GroupName, Vec<Requirement>)>,
         dependency_metadata: impl IntoIterator<Item = StaticMetadata>,
+        patches: impl IntoIterator<Item = SourcePatch>,
     ) -> Self {
         Self {
             members: members.into_iter().collect(),
@@ -1898,6 +2138,7 @@ impl ResolverManifest {
                 .map(|(group, requirements)| (group, requirements.into_iter().collect()))
                 .collect(),
             dependency_metadata: dependency_metadata.into_iter().collect(),
+            patches: patches.into_iter().collect(),
         }
     }
 
@@ -1939,6 +2180,7 @@ impl ResolverManifest {
                 })
                 .collect::<Result<BTreeMap<_, _>, _>>()?,
             dependency_metadata: self.dependency_metadata,
+            patches: self.patches,
         })
     }
 }
@@ -1964,6 +2206,12 @@ struct LockWire {
     options: ResolverOptions,
     #[serde(default)]
     manifest: ResolverManifest,
+    /// The URL prefixes shared by the artifacts in a compact lockfile.
+    #[serde(default)]
+    url_prefixes: UrlPrefixes,
+    /// The wheel lists shared by the packages in a compact lockfile.
+    #[serde(default)]
+    wheel_lists: WheelLists,
     #[serde(rename = "package", alias = "distribution", default)]
     packages: Vec<PackageWire>,
 }
@@ -1971,7 +2219,26 @@ struct LockWire {
 impl TryFrom<LockWire> for Lock {
     type Error = LockError;
 
-    fn try_from(wire: LockWire) -> Result<Lock, LockError> {
+    fn try_from(mut wire: LockWire) -> Result<Lock, LockError> {
+        // The compact format is only defined as of `COMPACT_REVISION`, so refuse to interpret
+        // shared tables in a lockfile that predates it.
+        let revision = wire.revision.unwrap_or(0);
+        if revision < COMPACT_REVISION
+            && (!wire.url_prefixes.is_empty() || !wire.wheel_lists.is_empty())
+        {
+            return Err(LockErrorKind::CompactRevision { revision }.into());
+        }
+
+        // Expand any wheel lists and URLs that reference the shared tables.
+        for dist in &mut wire.packages {
+            dist.expand_wheel_list(&wire.wheel_lists)?;
+        }
+        if !wire.url_prefixes.is_empty() {
+            for dist in &mut wire.packages {
+                dist.expand_url_prefixes(&wire.url_prefixes)?;
+            }
+        }
+
         // Count the number of sources for each package name. When
         // there's only one source for a particular package name (the
         // overwhelmingly common case), we can omit some data (like source and
@@ -2021,7 +2288,8 @@ impl TryFrom<LockWire> for Lock {
             supported_environments,
             required_environments,
             fork_markers,
-        )?;
+        )?
+        .with_compact(revision >= COMPACT_REVISION);
 
         Ok(lock)
     }
@@ -2617,6 +2885,8 @@ impl Package {
         &self,
         requires_python: &RequiresPython,
         dist_count_by_name: &FxHashMap<PackageName, u64>,
+        url_prefixes: &UrlPrefixes,
+        wheel_lists: &WheelLists,
     ) -> Result<Table, toml_edit::ser::Error> {
         let mut table = Table::new();
 
@@ -2672,14 +2942,16 @@ impl Package {
         }
 
         if let Some(ref sdist) = self.sdist {
-            table.insert("sdist", value(sdist.to_toml()?));
+            table.insert("sdist", value(sdist.to_toml(url_prefixes)?));
         }
 
-        if !self.wheels.is_empty() {
+        if let Some(alias) = wheel_lists.alias(&self.wheels) {
+            table.insert("wheel-list", value(alias));
+        } else if !self.wheels.is_empty() {
             let wheels = each_element_on_its_line_array(
                 self.wheels
                     .iter()
-                    .map(Wheel::to_toml)
+                    .map(|wheel| wheel.to_toml(url_prefixes))
                     .collect::<Result<Vec<_>, _>>()?
                     .into_iter(),
             );
@@ -2797,6 +3069,63 @@ impl Package {
         self.id.version.as_ref()
     }
 
+    /// Returns the URLs of the package's remote wheels.
+    pub fn wheel_urls(&self) -> impl Iterator<Item = &UrlString> {
+        self.wheels.iter().filter_map(|wheel| match &wheel.url {
+            WheelWireSource::Url { url } => Some(url),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+        })
+    }
+
+    /// Returns the remote URLs of the package's source distribution and wheels.
+    fn artifact_urls(&self) -> impl Iterator<Item = &str> {
+        let sdist = self.sdist.as_ref().and_then(|sdist| match sdist {
+            SourceDist::Url { url, .. } => Some(url.as_ref()),
+            SourceDist::Path { .. } | SourceDist::Metadata { .. } => None,
+        });
+        let wheels = self.wheels.iter().filter_map(|wheel| match &wheel.url {
+            WheelWireSource::Url { url } => Some(url.as_ref()),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+        });
+        sdist.into_iter().chain(wheels)
+    }
+
+    /// Returns the filenames of the package's source distribution and wheels, along with their
+    /// locked hashes, for those artifacts that have a hash.
+    pub fn artifact_hashes(&self) -> impl Iterator<Item = (Cow<'_, str>, &HashDigest)> {
+        let sdist = self.sdist.as_ref().and_then(|sdist| {
+            let metadata = match sdist {
+                SourceDist::Url { metadata, .. }
+                | SourceDist::Path { metadata, .. }
+                | SourceDist::Metadata { metadata } => metadata,
+            };
+            Some((sdist.filename()?, &metadata.hash.as_ref()?.0))
+        });
+        let wheels = self.wheels.iter().filter_map(|wheel| {
+            Some((
+                Cow::Owned(wheel.filename.to_string()),
+                &wheel.hash.as_ref()?.0,
+            ))
+        });
+        sdist.into_iter().chain(wheels)
+    }
+
+    /// Returns the remote URLs of the package's source distribution and wheels, along with their
+    /// hashes, if known.
+    pub fn remote_artifacts(&self) -> impl Iterator<Item = (&UrlString, Option<&HashDigest>)> {
+        let sdist = self.sdist.as_ref().and_then(|sdist| match sdist {
+            SourceDist::Url { url, metadata } => {
+                Some((url, metadata.hash.as_ref().map(|hash| &hash.0)))
+            }
+            SourceDist::Path { .. } | SourceDist::Metadata { .. } => None,
+        });
+        let wheels = self.wheels.iter().filter_map(|wheel| match &wheel.url {
+            WheelWireSource::Url { url } => Some((url, wheel.hash.as_ref().map(|hash| &hash.0))),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+        });
+        sdist.into_iter().chain(wheels)
+    }
+
     /// Return the fork markers for this package, if any.
     pub fn fork_markers(&self) -> &[UniversalMarker] {
         self.fork_markers.as_slice()
@@ -2901,6 +3230,10 @@ struct PackageWire {
     sdist: Option<SourceDist>,
     #[serde(default)]
     wheels: Vec<Wheel>,
+    /// The name of a shared list in the `[wheel-lists]` table, used in place of `wheels` in a
+    /// compact lockfile.
+    #[serde(default)]
+    wheel_list: Option<String>,
     #[serde(default, rename = "resolution-markers")]
     fork_markers: Vec<SimplifiedMarkerTree>,
     #[serde(default)]
@@ -2951,6 +3284,43 @@ impl PackageMetadata {
 }
 
 impl PackageWire {
+    /// Replace a reference to a shared list in the [`WheelLists`] with the wheels themselves.
+    fn expand_wheel_list(&mut self, wheel_lists: &WheelLists) -> Result<(), LockError> {
+        let Some(alias) = self.wheel_list.take() else {
+            return Ok(());
+        };
+        let Some(wheels) = wheel_lists.get(&alias) else {
+            return Err(LockErrorKind::UnknownWheelList {
+                id: self.id.clone(),
+                alias,
+            }
+            .into());
+        };
+        self.wheels.extend_from_slice(wheels);
+        Ok(())
+    }
+
+    /// Expand any artifact URLs that reference the shared [`UrlPrefixes`].
+    fn expand_url_prefixes(&mut self, url_prefixes: &UrlPrefixes) -> Result<(), LockError> {
+        let unknown = |alias| LockErrorKind::UnknownUrlPrefix {
+            id: self.id.clone(),
+            alias,
+        };
+        if let Some(SourceDist::Url { url, .. }) = &mut self.sdist {
+            if let Some(expanded) = url_prefixes.expand(url).map_err(unknown)? {
+                *url = expanded;
+            }
+        }
+        for wheel in &mut self.wheels {
+            if let WheelWireSource::Url { url } = &mut wheel.url {
+                if let Some(expanded) = url_prefixes.expand(url).map_err(unknown)? {
+                    *url = expanded;
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn unwire(
         self,
         requires_python: &RequiresPython,
@@ -3942,12 +4312,15 @@ enum SourceDistWire {
 
 impl SourceDist {
     /// Returns the TOML representation of this source distribution.
-    fn to_toml(&self) -> Result<InlineTable, toml_edit::ser::Error> {
+    fn to_toml(&self, url_prefixes: &UrlPrefixes) -> Result<InlineTable, toml_edit::ser::Error> {
         let mut table = InlineTable::new();
         match &self {
             SourceDist::Metadata { .. } => {}
             SourceDist::Url { url, .. } => {
-                table.insert("url", Value::from(url.as_ref()));
+                table.insert(
+                    "url",
+                    Value::from(url_prefixes.abbreviate(url.as_ref()).as_ref()),
+                );
             }
             SourceDist::Path { path, .. } => {
                 table.insert("path", Value::from(PortablePath::from(path).to_string()));
@@ -4387,11 +4760,14 @@ enum WheelWireSource {
 
 impl Wheel {
     /// Returns the TOML representation of this wheel.
-    fn to_toml(&self) -> Result<InlineTable, toml_edit::ser::Error> {
+    fn to_toml(&self, url_prefixes: &UrlPrefixes) -> Result<InlineTable, toml_edit::ser::Error> {
         let mut table = InlineTable::new();
         match &self.url {
             WheelWireSource::Url { url } => {
-                table.insert("url", Value::from(url.as_ref()));
+                table.insert(
+                    "url",
+                    Value::from(url_prefixes.abbreviate(url.as_ref()).as_ref()),
+                );
             }
             WheelWireSource::Path { path } => {
                 table.insert("path", Value::from(PortablePath::from(path).to_string()));
@@ -4691,6 +5067,55 @@ impl From<Hash> for Hashes {
     }
 }
 
+/// Verify the locked hash of an artifact against the hashes reported for it by a new index.
+///
+/// The new index must report a hash with the same algorithm as the locked hash, and the two must
+/// match. If the lockfile doesn't include a hash, the strongest hash reported by the index is used.
+fn migrate_hash(
+    id: &PackageId,
+    filename: &str,
+    index: &IndexUrl,
+    locked: Option<&Hash>,
+    file: &File,
+) -> Result<Option<Hash>, LockError> {
+    let Some(locked) = locked else {
+        return Ok(file.hashes.iter().max().cloned().map(Hash::from));
+    };
+    let Some(actual) = file
+        .hashes
+        .iter()
+        .find(|hash| hash.algorithm == locked.0.algorithm)
+    else {
+        return Err(LockErrorKind::MigrationMissingHash {
+            id: id.clone(),
+            filename: filename.to_string(),
+            index: index.clone(),
+            algorithm: locked.0.algorithm,
+        }
+        .into());
+    };
+    if *actual != locked.0 {
+        return Err(LockErrorKind::MigrationHashMismatch {
+            id: id.clone(),
+            filename: filename.to_string(),
+            index: index.clone(),
+            expected: locked.clone(),
+            actual: Hash::from(actual.clone()),
+        }
+        .into());
+    }
+    Ok(Some(locked.clone()))
+}
+
+/// Returns the upload time of a [`File`], if known.
+fn migrate_upload_time(file: &File) -> Result<Option<Timestamp>, LockError> {
+    Ok(file
+        .upload_time_utc_ms
+        .map(Timestamp::from_millisecond)
+        .transpose()
+        .map_err(LockErrorKind::InvalidTimestamp)?)
+}
+
 /// Convert a [`FileLocation`] into a normalized [`UrlString`].
 fn normalize_file_location(location: &FileLocation) -> Result<UrlString, ToUrlError> {
     match location {
@@ -5501,6 +5926,76 @@ enum LockErrorKind {
         /// The name of the dependency that is missing a `source` field.
         name: PackageName,
     },
+    /// An error that occurs when an artifact URL references a prefix that
+    /// isn't defined in the `url-prefixes` table.
+    #[error("For package `{id}`, found reference to unknown URL prefix `{alias}`", id = id.cyan(), alias = alias.cyan())]
+    UnknownUrlPrefix {
+        /// The ID of the package whose artifact references the prefix.
+        id: PackageId,
+        /// The alias of the missing prefix.
+        alias: String,
+    },
+    /// An error that occurs when a package references a wheel list that isn't defined in the
+    /// `wheel-lists` table.
+    #[error("For package `{id}`, found reference to unknown wheel list `{alias}`", id = id.cyan(), alias = alias.cyan())]
+    UnknownWheelList {
+        /// The ID of the package that references the list.
+        id: PackageId,
+        /// The alias of the missing list.
+        alias: String,
+    },
+    /// An error that occurs when a lockfile uses the compact format, but declares a revision that
+    /// predates it.
+    #[error("The lockfile uses the compact format, which requires revision {compact}, but declares revision {revision}", compact = COMPACT_REVISION)]
+    CompactRevision {
+        /// The revision declared by the lockfile.
+        revision: u32,
+    },
+    /// An error that occurs when an artifact in the lockfile is not available on the index that
+    /// the lockfile is being migrated to.
+    #[error("For package `{id}`, the distribution `{filename}` is not available on `{index}`", id = id.cyan(), filename = filename.cyan(), index = index.cyan())]
+    MigrationMissingArtifact {
+        /// The ID of the package whose artifact is missing.
+        id: PackageId,
+        /// The filename of the missing artifact.
+        filename: String,
+        /// The index that the lockfile is being migrated to.
+        index: IndexUrl,
+    },
+    /// An error that occurs when the hash of an artifact in the lockfile doesn't match the hash
+    /// reported by the index that the lockfile is being migrated to.
+    #[error("For package `{id}`, the hash of `{filename}` on `{index}` does not match the lockfile (expected `{expected}`, found `{actual}`)", id = id.cyan(), filename = filename.cyan(), index = index.cyan())]
+    MigrationHashMismatch {
+        /// The ID of the package whose artifact has a mismatched hash.
+        id: PackageId,
+        /// The filename of the artifact.
+        filename: String,
+        /// The index that the lockfile is being migrated to.
+        index: IndexUrl,
+        /// The hash recorded in the lockfile.
+        expected: Hash,
+        /// The hash reported by the index.
+        actual: Hash,
+    },
+    /// An error that occurs when the index that a lockfile is being migrated to doesn't report a
+    /// hash that can be compared to the locked hash of an artifact.
+    #[error("For package `{id}`, `{index}` does not report a `{algorithm}` hash for `{filename}` to verify against the lockfile", id = id.cyan(), filename = filename.cyan(), index = index.cyan())]
+    MigrationMissingHash {
+        /// The ID of the package whose artifact can't be verified.
+        id: PackageId,
+        /// The filename of the artifact.
+        filename: String,
+        /// The index that the lockfile is being migrated to.
+        index: IndexUrl,
+        /// The algorithm of the locked hash.
+        algorithm: HashAlgorithm,
+    },
+    /// An error that occurs when attempting to migrate a lockfile to a local index.
+    #[error("Cannot migrate to local index `{index}`; only remote indexes are supported", index = index.cyan())]
+    MigrateToLocalIndex {
+        /// The local index.
+        index: IndexUrl,
+    },
     /// An error that occurs when parsing an existing requirement.
     #[error("Could not compute relative path between workspace and requirement")]
     RequirementRelativePath(
@@ -6002,4 +6497,302 @@ source = { editable = "path/to/dir" }
         let result: Result<Lock, _> = toml::from_str(data);
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn compact_url_prefixes() {
+        let data = r#"version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[url-prefixes]
+pythonhosted = "https://files.pythonhosted.org/packages"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "{pythonhosted}/db/4d/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6" }
+wheels = [
+    { url = "{pythonhosted}/14/fd/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        assert!(lock.is_compact());
+        assert_eq!(
+            lock.packages()[0].wheels[0].url,
+            WheelWireSource::Url {
+                url: UrlString::new(SmallString::from(
+                    "https://files.pythonhosted.org/packages/14/fd/anyio-4.3.0-py3-none-any.whl"
+                )),
+            }
+        );
+
+        // Writing the lockfile should preserve the compact format.
+        assert_eq!(lock.to_toml().unwrap(), data);
+
+        // Writing the lockfile without the compact format should expand the URLs.
+        let expanded = lock.with_compact(false).to_toml().unwrap();
+        assert!(!expanded.contains("url-prefixes"));
+        assert!(expanded.contains("revision = 2"));
+        assert!(
+            expanded.contains("https://files.pythonhosted.org/packages/db/4d/anyio-4.3.0.tar.gz")
+        );
+    }
+
+    #[test]
+    fn compact_unknown_url_prefix() {
+        let data = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[url-prefixes]
+pythonhosted = "https://files.pythonhosted.org/packages"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "{pytorch}/14/fd/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+"#;
+        let err = toml::from_str::<Lock>(data).unwrap_err().to_string();
+        assert!(err.contains("unknown URL prefix"), "{err}");
+        assert!(err.contains("pytorch"), "{err}");
+    }
+
+    #[test]
+    fn compact_wheel_lists() {
+        let data = r#"version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[wheel-lists]
+anyio-4-3-0 = [
+    { url = "https://files.pythonhosted.org/packages/14/fd/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+wheel-list = "anyio-4-3-0"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://mirror.example.com/simple" }
+wheel-list = "anyio-4-3-0"
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        assert!(lock.is_compact());
+        assert_eq!(lock.packages()[0].wheels, lock.packages()[1].wheels);
+        assert_eq!(lock.packages()[0].wheels.len(), 1);
+
+        // Writing the lockfile should share the list again, named after the first package.
+        let written = lock.to_toml().unwrap();
+        assert!(
+            written.contains("[wheel-lists]\nanyio-4-3-0 = ["),
+            "{written}"
+        );
+        assert_eq!(written.matches("wheel-list = \"anyio-4-3-0\"").count(), 2);
+
+        // Writing the lockfile without the compact format should inline the wheels.
+        let expanded = lock.with_compact(false).to_toml().unwrap();
+        assert!(!expanded.contains("wheel-list"));
+        assert_eq!(expanded.matches("anyio-4.3.0-py3-none-any.whl").count(), 2);
+    }
+
+    #[test]
+    fn compact_unknown_wheel_list() {
+        let data = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+wheel-list = "anyio-4.3.0"
+"#;
+        let err = toml::from_str::<Lock>(data).unwrap_err().to_string();
+        assert!(err.contains("unknown wheel list"), "{err}");
+    }
+
+    #[test]
+    fn compact_requires_revision() {
+        let data = r#"
+version = 1
+revision = 2
+requires-python = ">=3.12"
+
+[url-prefixes]
+pythonhosted = "https://files.pythonhosted.org/packages"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "{pythonhosted}/14/fd/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+"#;
+        let err = toml::from_str::<Lock>(data).unwrap_err().to_string();
+        assert!(err.contains("requires revision 3"), "{err}");
+    }
+
+    fn mirror_file(filename: &str, hash: &str) -> File {
+        File {
+            dist_info_metadata: false,
+            filename: SmallString::from(filename),
+            hashes: HashDigests::from(HashDigest::from_str(hash).unwrap()),
+            requires_python: None,
+            size: None,
+            upload_time_utc_ms: None,
+            url: FileLocation::AbsoluteUrl(UrlString::new(SmallString::from(format!(
+                "https://mirror.example.com/files/{filename}"
+            )))),
+            yanked: None,
+        }
+    }
+
+    #[test]
+    fn migrate_index() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+]
+sdist = { url = "https://files.pythonhosted.org/packages/db/4d/anyio-4.3.0.tar.gz", hash = "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/14/fd/anyio-4.3.0-py3-none-any.whl", hash = "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8" },
+]
+
+[[package]]
+name = "idna"
+version = "3.7"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/e5/3e/idna-3.7-py3-none-any.whl", hash = "sha256:82fee1fc78add43492d3a1898bfa6d8a904cc97d8427f683ed8e798d07761aa0" },
+]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        let old = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        let new = IndexUrl::from_str("https://mirror.example.com/simple").unwrap();
+        let files = FxHashMap::from_iter([
+            (
+                PackageName::from_str("anyio").unwrap(),
+                vec![
+                    mirror_file(
+                        "anyio-4.3.0.tar.gz",
+                        "sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6",
+                    ),
+                    mirror_file(
+                        "anyio-4.3.0-py3-none-any.whl",
+                        "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8",
+                    ),
+                ],
+            ),
+            (
+                PackageName::from_str("idna").unwrap(),
+                vec![mirror_file(
+                    "idna-3.7-py3-none-any.whl",
+                    "sha256:82fee1fc78add43492d3a1898bfa6d8a904cc97d8427f683ed8e798d07761aa0",
+                )],
+            ),
+        ]);
+
+        let (migrated, count) = lock
+            .migrate_index(&old, &new, &files, Path::new("/"))
+            .unwrap();
+        assert_eq!(count, 2);
+
+        let encoded = migrated.to_toml().unwrap();
+        assert!(!encoded.contains("pypi.org"), "{encoded}");
+        assert!(!encoded.contains("pythonhosted"), "{encoded}");
+        assert!(
+            encoded.contains(r#"source = { registry = "https://mirror.example.com/simple" }"#),
+            "{encoded}"
+        );
+        assert!(
+            encoded.contains("https://mirror.example.com/files/anyio-4.3.0.tar.gz"),
+            "{encoded}"
+        );
+        assert_eq!(
+            migrated
+                .packages()
+                .iter()
+                .map(Package::version)
+                .collect::<Vec<_>>(),
+            lock.packages()
+                .iter()
+                .map(Package::version)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn migrate_index_hash_mismatch() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.7"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/e5/3e/idna-3.7-py3-none-any.whl", hash = "sha256:82fee1fc78add43492d3a1898bfa6d8a904cc97d8427f683ed8e798d07761aa0" },
+]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        let old = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        let new = IndexUrl::from_str("https://mirror.example.com/simple").unwrap();
+        let name = PackageName::from_str("idna").unwrap();
+
+        // The new index reports a different hash for the locked wheel.
+        let files = FxHashMap::from_iter([(
+            name.clone(),
+            vec![mirror_file(
+                "idna-3.7-py3-none-any.whl",
+                "sha256:0000000000000000000000000000000000000000000000000000000000000000",
+            )],
+        )]);
+        let err = lock
+            .migrate_index(&old, &new, &files, Path::new("/"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("does not match the lockfile"), "{err}");
+
+        // The new index only reports a hash with a different algorithm, so the locked hash can't be
+        // verified.
+        let files = FxHashMap::from_iter([(
+            name.clone(),
+            vec![mirror_file(
+                "idna-3.7-py3-none-any.whl",
+                "md5:00000000000000000000000000000000",
+            )],
+        )]);
+        let err = lock
+            .migrate_index(&old, &new, &files, Path::new("/"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("does not report a `sha256` hash"), "{err}");
+
+        // The new index doesn't include the locked wheel.
+        let files = FxHashMap::from_iter([(name, vec![])]);
+        let err = lock
+            .migrate_index(&old, &new, &files, Path::new("/"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("is not available on"), "{err}");
+    }
 }
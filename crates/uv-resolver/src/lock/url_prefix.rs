@@ -0,0 +1,198 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use rustc_hash::FxHashMap;
+
+use uv_distribution_types::UrlString;
+use uv_small_str::SmallString;
+
+/// A table of URL prefixes shared by the artifacts in a compact lockfile.
+///
+/// In the compact lockfile format, the common prefix of artifact URLs (e.g.,
+/// `https://files.pythonhosted.org/packages`) is written once to the `[url-prefixes]` table, and
+/// each artifact URL references it by name, as in `{pythonhosted}/9a/47/...`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(transparent)]
+pub(crate) struct UrlPrefixes(BTreeMap<String, String>);
+
+impl UrlPrefixes {
+    /// Compute the [`UrlPrefixes`] shared by the given artifact URLs.
+    ///
+    /// A prefix is only included if it's shared by at least two URLs, since abbreviating a single
+    /// URL would only increase the size of the lockfile.
+    pub(crate) fn from_urls<'a>(urls: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for url in urls {
+            if let Some(prefix) = Self::prefix(url) {
+                *counts.entry(prefix).or_default() += 1;
+            }
+        }
+
+        // Assign each prefix a short, deterministic alias based on its host. Iterating over the
+        // (sorted) prefixes ensures that collisions are resolved identically across runs.
+        let mut prefixes = BTreeMap::new();
+        let mut aliases: FxHashMap<String, usize> = FxHashMap::default();
+        for (prefix, count) in counts {
+            if count < 2 {
+                continue;
+            }
+            let base = Self::alias(prefix);
+            let seen = aliases.entry(base.clone()).or_default();
+            *seen += 1;
+            let alias = if *seen == 1 {
+                base
+            } else {
+                format!("{base}-{seen}")
+            };
+            prefixes.insert(alias, prefix.to_string());
+        }
+        Self(prefixes)
+    }
+
+    /// Returns `true` if there are no prefixes in the table.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the `(alias, prefix)` pairs in the table.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(alias, prefix)| (alias.as_str(), prefix.as_str()))
+    }
+
+    /// Abbreviate a URL by replacing its prefix with a reference to the corresponding alias.
+    pub(crate) fn abbreviate<'a>(&self, url: &'a str) -> Cow<'a, str> {
+        let Some(prefix) = Self::prefix(url) else {
+            return Cow::Borrowed(url);
+        };
+        let Some((alias, _)) = self.0.iter().find(|(_, candidate)| *candidate == prefix) else {
+            return Cow::Borrowed(url);
+        };
+        Cow::Owned(format!("{{{alias}}}{}", &url[prefix.len()..]))
+    }
+
+    /// Expand a URL that references an alias in the table.
+    ///
+    /// Returns `Ok(None)` if the URL is not abbreviated, and `Err` with the alias if the URL
+    /// references an alias that isn't present in the table.
+    pub(crate) fn expand(&self, url: &UrlString) -> Result<Option<UrlString>, String> {
+        let Some(rest) = url.as_ref().strip_prefix('{') else {
+            return Ok(None);
+        };
+        let Some((alias, path)) = rest.split_once('}') else {
+            return Err(rest.to_string());
+        };
+        let Some(prefix) = self.0.get(alias) else {
+            return Err(alias.to_string());
+        };
+        Ok(Some(UrlString::new(SmallString::from(format!(
+            "{prefix}{path}"
+        )))))
+    }
+
+    /// Returns the shareable prefix of a URL: the scheme, authority, and first path segment (e.g.,
+    /// `https://files.pythonhosted.org/packages`).
+    fn prefix(url: &str) -> Option<&str> {
+        let (scheme, rest) = url.split_once("://")?;
+        if !matches!(scheme, "http" | "https") {
+            return None;
+        }
+        let authority_end = rest.find('/')?;
+        let path = &rest[authority_end + 1..];
+        let segment_end = path.find('/')?;
+        Some(&url[..scheme.len() + 3 + authority_end + 1 + segment_end])
+    }
+
+    /// Returns a short alias for a prefix, derived from the second-level domain of its host (e.g.,
+    /// `pythonhosted` for `https://files.pythonhosted.org/packages`).
+    fn alias(prefix: &str) -> String {
+        let host = prefix
+            .split_once("://")
+            .map(|(_, rest)| rest)
+            .unwrap_or(prefix)
+            .split(['/', ':', '@'])
+            .next()
+            .unwrap_or_default();
+        let mut labels = host.rsplit('.');
+        let alias = match (labels.next(), labels.next()) {
+            (Some(_), Some(domain)) => domain,
+            (Some(host), None) => host,
+            _ => "index",
+        };
+        alias
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let urls = [
+            "https://files.pythonhosted.org/packages/9a/47/foo-1.0.0-py3-none-any.whl",
+            "https://files.pythonhosted.org/packages/3b/00/foo-1.0.0.tar.gz",
+            "https://download.pytorch.org/whl/cpu/torch-2.0.0-cp312-none-any.whl",
+            "https://download.pytorch.org/whl/cpu/torch-2.0.0-cp311-none-any.whl",
+            "https://example.com/unique/bar-1.0.0-py3-none-any.whl",
+        ];
+        let prefixes = UrlPrefixes::from_urls(urls);
+        assert_eq!(
+            prefixes.iter().collect::<Vec<_>>(),
+            vec![
+                ("pythonhosted", "https://files.pythonhosted.org/packages"),
+                ("pytorch", "https://download.pytorch.org/whl"),
+            ]
+        );
+
+        for url in urls {
+            let abbreviated = prefixes.abbreviate(url);
+            let expanded = prefixes
+                .expand(&UrlString::new(SmallString::from(abbreviated.as_ref())))
+                .unwrap()
+                .map(|url| url.to_string())
+                .unwrap_or_else(|| abbreviated.to_string());
+            assert_eq!(expanded, url);
+        }
+
+        assert_eq!(
+            prefixes.abbreviate(urls[0]),
+            "{pythonhosted}/9a/47/foo-1.0.0-py3-none-any.whl"
+        );
+        assert_eq!(prefixes.abbreviate(urls[4]), urls[4]);
+    }
+
+    #[test]
+    fn alias_collision() {
+        let prefixes = UrlPrefixes::from_urls([
+            "https://a.example.com/one/x.whl",
+            "https://a.example.com/one/y.whl",
+            "https://b.example.com/two/x.whl",
+            "https://b.example.com/two/y.whl",
+        ]);
+        assert_eq!(
+            prefixes.iter().collect::<Vec<_>>(),
+            vec![
+                ("example", "https://a.example.com/one"),
+                ("example-2", "https://b.example.com/two"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_alias() {
+        let prefixes = UrlPrefixes::default();
+        let url = UrlString::new(SmallString::from("{missing}/foo-1.0.0.tar.gz"));
+        assert_eq!(prefixes.expand(&url), Err("missing".to_string()));
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use rustc_hash::FxHashMap;
+
+use uv_distribution_filename::WheelFilename;
+
+use crate::lock::{Package, Wheel};
+
+/// A table of wheel lists shared by multiple packages in a compact lockfile.
+///
+/// In the compact lockfile format, a list of wheels that's identical across packages (e.g., a
+/// package that's locked against multiple indexes that serve the same files) is written once to
+/// the `[wheel-lists]` table, and each package references it by name, as in
+/// `wheel-list = "numpy-2-0-0"`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(transparent)]
+pub(super) struct WheelLists(BTreeMap<String, Vec<Wheel>>);
+
+impl WheelLists {
+    /// Compute the [`WheelLists`] shared by the given packages.
+    ///
+    /// A list is only included if it's shared by at least two packages, and each list is named
+    /// after the first package that includes it (e.g., `numpy-2-0-0`), so that names remain bare
+    /// TOML keys.
+    pub(super) fn from_packages(packages: &[Package]) -> Self {
+        // Group the lists by their first wheel, to avoid comparing every pair of packages.
+        let mut candidates: FxHashMap<&WheelFilename, Vec<(&Package, usize)>> =
+            FxHashMap::default();
+        for package in packages {
+            let Some(first) = package.wheels.first() else {
+                continue;
+            };
+            let candidates = candidates.entry(&first.filename).or_default();
+            if let Some((_, count)) = candidates
+                .iter_mut()
+                .find(|(candidate, _)| candidate.wheels == package.wheels)
+            {
+                *count += 1;
+            } else {
+                candidates.push((package, 1));
+            }
+        }
+
+        let mut shared = candidates
+            .into_values()
+            .flatten()
+            .filter(|(_, count)| *count >= 2)
+            .map(|(package, _)| package)
+            .collect::<Vec<_>>();
+        shared.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut lists = BTreeMap::new();
+        for package in shared {
+            let base = match &package.id.version {
+                Some(version) => format!("{}-{version}", package.id.name),
+                None => package.id.name.to_string(),
+            }
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect::<String>();
+            let mut alias = base.clone();
+            let mut seen = 1;
+            while lists.contains_key(&alias) {
+                seen += 1;
+                alias = format!("{base}-{seen}");
+            }
+            lists.insert(alias, package.wheels.clone());
+        }
+        Self(lists)
+    }
+
+    /// Returns `true` if there are no lists in the table.
+    pub(super) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the `(alias, wheels)` pairs in the table.
+    pub(super) fn iter(&self) -> impl Iterator<Item = (&str, &[Wheel])> {
+        self.0
+            .iter()
+            .map(|(alias, wheels)| (alias.as_str(), wheels.as_slice()))
+    }
+
+    /// Returns the alias of the list that matches the given wheels, if any.
+    pub(super) fn alias(&self, wheels: &[Wheel]) -> Option<&str> {
+        if wheels.is_empty() {
+            return None;
+        }
+        self.0
+            .iter()
+            .find(|(_, candidate)| candidate.as_slice() == wheels)
+            .map(|(alias, _)| alias.as_str())
+    }
+
+    /// Returns the list of wheels with the given alias.
+    pub(super) fn get(&self, alias: &str) -> Option<&[Wheel]> {
+        self.0.get(alias).map(Vec::as_slice)
+    }
+}
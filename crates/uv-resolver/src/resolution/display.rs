@@ -5,7 +5,9 @@ use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
+use uv_distribution_types::{
+    DistributionMetadata, Name, SourceAnnotation, SourceAnnotations, SourceLines,
+};
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 
@@ -35,6 +37,9 @@ pub struct DisplayResolutionGraph<'a> {
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The lines at which each package is declared in the source files, used to include the line
+    /// of each source in the annotations (e.g., `# via -r requirements.in:3`).
+    source_lines: &'a SourceLines,
 }
 
 #[derive(Debug)]
@@ -61,6 +66,7 @@ impl<'a> DisplayResolutionGraph<'a> {
         include_annotations: bool,
         include_index_annotation: bool,
         annotation_style: AnnotationStyle,
+        source_lines: &'a SourceLines,
     ) -> DisplayResolutionGraph<'a> {
         for fork_marker in &underlying.fork_markers {
             assert!(
@@ -79,6 +85,7 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_annotations,
             include_index_annotation,
             annotation_style,
+            source_lines,
         }
     }
 }
@@ -96,7 +103,10 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
                         &requirement.name,
-                        SourceAnnotation::Requirement(origin.clone()),
+                        SourceAnnotation::Requirement(
+                            origin.clone(),
+                            self.source_lines.get(origin.path(), &requirement.name),
+                        ),
                     );
                 }
             }
@@ -112,7 +122,10 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
                         &requirement.name,
-                        SourceAnnotation::Constraint(origin.clone()),
+                        SourceAnnotation::Constraint(
+                            origin.clone(),
+                            self.source_lines.get(origin.path(), &requirement.name),
+                        ),
                     );
                 }
             }
@@ -128,7 +141,10 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
                         &requirement.name,
-                        SourceAnnotation::Override(origin.clone()),
+                        SourceAnnotation::Override(
+                            origin.clone(),
+                            self.source_lines.get(origin.path(), &requirement.name),
+                        ),
                     );
                 }
             }
@@ -1,5 +1,6 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Formatter};
+use std::path::Path;
 use std::sync::Arc;
 
 use indexmap::IndexSet;
@@ -18,7 +19,7 @@ use uv_distribution_types::{
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
-use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
+use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind, RequirementOrigin};
 use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
 
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
@@ -618,6 +619,19 @@ impl ResolverOutput {
         &self.diagnostics
     }
 
+    /// Return the paths of the files that declared the requirements, constraints, and overrides
+    /// used to build the graph (e.g., `requirements.in` or `pyproject.toml` files).
+    pub fn source_files(&self) -> BTreeSet<&Path> {
+        self.requirements
+            .iter()
+            .chain(self.constraints.requirements())
+            .chain(self.overrides.requirements())
+            .filter_map(|requirement| requirement.origin.as_ref())
+            .filter(|origin| !matches!(origin, RequirementOrigin::Workspace))
+            .map(RequirementOrigin::path)
+            .collect()
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -375,7 +375,7 @@ pub struct Tool {
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct ToolUv {
     #[serde(flatten)]
-    pub globals: GlobalOptions,
+    pub globals: Box<GlobalOptions>,
     #[serde(flatten)]
     pub top_level: ResolverInstallerOptions,
     pub override_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
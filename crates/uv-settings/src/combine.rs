@@ -3,11 +3,14 @@ use std::path::PathBuf;
 
 use url::Url;
 
+use uv_cache::{CacheEvictionWeights, CacheRoots};
 use uv_configuration::{
-    ConfigSettings, ExportFormat, IndexStrategy, KeyringProviderType, PackageConfigSettings,
-    RequiredVersion, TargetTriple, TrustedPublishing,
+    ByteSize, BytecodeOptimizationLevel, ConfigSettings, ExportFormat, HttpConnectionOptions,
+    IndexStrategy, InstallHooks, KeyringProviderType, NoBuildIsolationPackages,
+    PackageConfigSettings, RequiredVersion, RetryPolicyOptions, TargetTriple, TrustedPublishing,
 };
-use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex};
+use uv_distribution_types::{Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex, ProxyUrl};
+use uv_fs::WindowsInstallRobustness;
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
@@ -77,12 +80,18 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(BytecodeOptimizationLevel);
+impl_combine_or!(ByteSize);
+impl_combine_or!(CacheEvictionWeights);
+impl_combine_or!(CacheRoots);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExportFormat);
 impl_combine_or!(ForkStrategy);
+impl_combine_or!(HttpConnectionOptions);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
+impl_combine_or!(InstallHooks);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
 impl_combine_or!(DisplaySafeUrl);
@@ -92,11 +101,13 @@ impl_combine_or!(PipExtraIndex);
 impl_combine_or!(PipFindLinks);
 impl_combine_or!(PipIndex);
 impl_combine_or!(PrereleaseMode);
+impl_combine_or!(ProxyUrl);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
+impl_combine_or!(RetryPolicyOptions);
 impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
@@ -104,7 +115,9 @@ impl_combine_or!(TargetTriple);
 impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
+impl_combine_or!(WindowsInstallRobustness);
 impl_combine_or!(bool);
+impl_combine_or!(u64);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -142,6 +155,17 @@ impl Combine for Option<PackageConfigSettings> {
     }
 }
 
+impl Combine for Option<NoBuildIsolationPackages> {
+    /// Combine two lists by extending the list in `self` with the list in `other`, or two tables
+    /// by merging them, if they're both `Some`.
+    fn combine(self, other: Option<NoBuildIsolationPackages>) -> Option<NoBuildIsolationPackages> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.merge(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for serde::de::IgnoredAny {
     fn combine(self, _other: Self) -> Self {
         self
@@ -32,13 +32,16 @@ impl Deref for FilesystemOptions {
 }
 
 impl FilesystemOptions {
+    /// Return the path to the user configuration file, whether or not it exists.
+    pub fn user_file() -> Option<PathBuf> {
+        user_config_dir().map(|dir| dir.join("uv").join("uv.toml"))
+    }
+
     /// Load the user [`FilesystemOptions`].
     pub fn user() -> Result<Option<Self>, Error> {
-        let Some(dir) = user_config_dir() else {
+        let Some(file) = Self::user_file() else {
             return Ok(None);
         };
-        let root = dir.join("uv");
-        let file = root.join("uv.toml");
 
         tracing::debug!("Searching for user configuration in: `{}`", file.display());
         match read_file(&file) {
@@ -206,6 +209,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         install_mirrors: _,
         publish: _,
         add: _,
+        sync: _,
         pip: _,
         cache_keys: _,
         override_dependencies: _,
@@ -221,6 +225,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         dependency_groups,
         managed,
         package,
+        build_constraint_dependencies_package,
+        patches,
+        tasks,
         build_backend,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -259,6 +266,18 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if build_constraint_dependencies_package.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "build-constraint-dependencies-package",
+        ));
+    }
+    if patches.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "patches"));
+    }
+    if tasks.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "tasks"));
+    }
     if build_backend.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -277,15 +296,33 @@ fn warn_uv_toml_masked_fields(options: &Options) {
             GlobalOptions {
                 required_version,
                 native_tls,
+                http_proxy,
+                https_proxy,
+                no_proxy,
+                retry_policy,
+                download_retry_policy,
+                upload_retry_policy,
+                http_connection,
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_size,
+                cache_eviction_weights,
+                cache_index_isolation,
+                cache_roots,
+                remote_cache_url,
+                remote_cache_upload,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
+                concurrent_downloads_per_host,
+                download_bandwidth,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_compiles,
+                windows_install_robustness,
+                wait_timeout,
                 allow_insecure_host,
             },
         top_level:
@@ -308,6 +345,10 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 exclude_newer,
                 link_mode,
                 compile_bytecode,
+                compile_bytecode_level,
+                compile_bytecode_package,
+                no_compile_bytecode_package,
+                install_hooks,
                 no_sources,
                 upgrade,
                 upgrade_package,
@@ -331,6 +372,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 check_url,
             },
         add: AddOptions { add_bounds },
+        sync:
+            SyncOptions {
+                expose_scripts,
+                confirm_download_size,
+                max_download_size,
+            },
         pip,
         cache_keys,
         override_dependencies,
@@ -346,6 +393,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         dependency_groups: _,
         managed: _,
         package: _,
+        build_constraint_dependencies_package: _,
+        patches: _,
+        tasks: _,
         build_backend: _,
     } = options;
 
@@ -357,6 +407,27 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if native_tls.is_some() {
         masked_fields.push("native-tls");
     }
+    if http_proxy.is_some() {
+        masked_fields.push("http-proxy");
+    }
+    if https_proxy.is_some() {
+        masked_fields.push("https-proxy");
+    }
+    if no_proxy.is_some() {
+        masked_fields.push("no-proxy");
+    }
+    if retry_policy.is_some() {
+        masked_fields.push("retry-policy");
+    }
+    if download_retry_policy.is_some() {
+        masked_fields.push("download-retry-policy");
+    }
+    if upload_retry_policy.is_some() {
+        masked_fields.push("upload-retry-policy");
+    }
+    if http_connection.is_some() {
+        masked_fields.push("http-connection");
+    }
     if offline.is_some() {
         masked_fields.push("offline");
     }
@@ -366,6 +437,24 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if cache_max_size.is_some() {
+        masked_fields.push("cache-max-size");
+    }
+    if cache_eviction_weights.is_some() {
+        masked_fields.push("cache-eviction-weights");
+    }
+    if cache_index_isolation.is_some() {
+        masked_fields.push("cache-index-isolation");
+    }
+    if cache_roots.is_some() {
+        masked_fields.push("cache-roots");
+    }
+    if remote_cache_url.is_some() {
+        masked_fields.push("remote-cache-url");
+    }
+    if remote_cache_upload.is_some() {
+        masked_fields.push("remote-cache-upload");
+    }
     if preview.is_some() {
         masked_fields.push("preview");
     }
@@ -378,12 +467,27 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if concurrent_downloads.is_some() {
         masked_fields.push("concurrent-downloads");
     }
+    if concurrent_downloads_per_host.is_some() {
+        masked_fields.push("concurrent-downloads-per-host");
+    }
+    if download_bandwidth.is_some() {
+        masked_fields.push("download-bandwidth");
+    }
     if concurrent_builds.is_some() {
         masked_fields.push("concurrent-builds");
     }
     if concurrent_installs.is_some() {
         masked_fields.push("concurrent-installs");
     }
+    if concurrent_compiles.is_some() {
+        masked_fields.push("concurrent-compiles");
+    }
+    if windows_install_robustness.is_some() {
+        masked_fields.push("windows-install-robustness");
+    }
+    if wait_timeout.is_some() {
+        masked_fields.push("wait-timeout");
+    }
     if allow_insecure_host.is_some() {
         masked_fields.push("allow-insecure-host");
     }
@@ -441,6 +545,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if compile_bytecode.is_some() {
         masked_fields.push("compile-bytecode");
     }
+    if compile_bytecode_level.is_some() {
+        masked_fields.push("compile-bytecode-level");
+    }
+    if compile_bytecode_package.is_some() {
+        masked_fields.push("compile-bytecode-package");
+    }
+    if no_compile_bytecode_package.is_some() {
+        masked_fields.push("no-compile-bytecode-package");
+    }
+    if install_hooks.is_some() {
+        masked_fields.push("install-hooks");
+    }
     if no_sources.is_some() {
         masked_fields.push("no-sources");
     }
@@ -489,6 +605,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if add_bounds.is_some() {
         masked_fields.push("add-bounds");
     }
+    if expose_scripts.is_some() {
+        masked_fields.push("expose-scripts");
+    }
+    if confirm_download_size.is_some() {
+        masked_fields.push("confirm-download-size");
+    }
+    if max_download_size.is_some() {
+        masked_fields.push("max-download-size");
+    }
     if pip.is_some() {
         masked_fields.push("pip");
     }
@@ -552,6 +677,8 @@ pub enum Error {
 pub struct EnvironmentOptions {
     pub python_install_bin: Option<bool>,
     pub python_install_registry: Option<bool>,
+    pub remote_cache_upload: Option<bool>,
+    pub cache_index_isolation: Option<bool>,
 }
 
 impl EnvironmentOptions {
@@ -562,6 +689,12 @@ impl EnvironmentOptions {
             python_install_registry: parse_boolish_environment_variable(
                 EnvVars::UV_PYTHON_INSTALL_REGISTRY,
             )?,
+            remote_cache_upload: parse_boolish_environment_variable(
+                EnvVars::UV_REMOTE_CACHE_UPLOAD,
+            )?,
+            cache_index_isolation: parse_boolish_environment_variable(
+                EnvVars::UV_CACHE_INDEX_ISOLATION,
+            )?,
         })
     }
 }
@@ -2,14 +2,18 @@ use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use uv_cache::{CacheEvictionWeights, CacheRoots};
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageConfigSettings,
-    PackageNameSpecifier, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing,
+    ByteSize, BytecodeOptimizationLevel, ConfigSettings, HttpConnectionOptions, IndexStrategy,
+    InstallHooks, KeyringProviderType, NoBuildIsolationPackages, PackageConfigSettings,
+    PackageNameSpecifier, RequiredVersion, RetryPolicyOptions, TargetTriple, TrustedHost,
+    TrustedPublishing,
 };
 use uv_distribution_types::{
-    Index, IndexUrl, IndexUrlError, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
+    Index, IndexUrl, IndexUrlError, PipExtraIndex, PipFindLinks, PipIndex, ProxyUrl, StaticMetadata,
 };
+use uv_fs::WindowsInstallRobustness;
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
@@ -58,6 +62,9 @@ pub struct Options {
     #[serde(flatten)]
     pub add: AddOptions,
 
+    #[serde(flatten)]
+    pub sync: SyncOptions,
+
     #[option_group]
     pub pip: Option<PipOptions>,
 
@@ -150,6 +157,15 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub r#package: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub build_constraint_dependencies_package: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub patches: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub tasks: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub build_backend: Option<serde::de::IgnoredAny>,
 }
@@ -210,6 +226,115 @@ pub struct GlobalOptions {
         "#
     )]
     pub native_tls: Option<bool>,
+    /// The proxy to use for HTTP requests.
+    ///
+    /// Supports `http`, `https`, `socks5`, and `socks5h` proxies. Takes precedence over the
+    /// `HTTP_PROXY` environment variable, which is used by default.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            http-proxy = "http://proxy.example.com:8080"
+        "#
+    )]
+    pub http_proxy: Option<ProxyUrl>,
+    /// The proxy to use for HTTPS requests.
+    ///
+    /// Supports `http`, `https`, `socks5`, and `socks5h` proxies. Takes precedence over the
+    /// `HTTPS_PROXY` environment variable, which is used by default.
+    ///
+    /// To use a proxy for the requests to a specific index, set `proxy` on the index instead.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            https-proxy = "socks5h://localhost:1080"
+        "#
+    )]
+    pub https_proxy: Option<ProxyUrl>,
+    /// Hosts for which the proxies should be bypassed.
+    ///
+    /// Accepts host names (matching the host and its subdomains), IP addresses, and CIDR blocks,
+    /// or `*` to bypass the proxies entirely. Takes precedence over the `NO_PROXY` environment
+    /// variable, which is used by default. Per-index proxies are not affected.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-proxy = ["localhost", "internal.example.com", "10.0.0.0/8"]
+        "#
+    )]
+    pub no_proxy: Option<Vec<String>>,
+    /// The policy for retrying HTTP requests that failed with a transient error, such as a
+    /// connection failure, a timeout, or an HTTP 5xx or 429 response.
+    ///
+    /// Supports `retries`, the number of times a failed request is retried (defaults to `3`, and
+    /// overridden by `UV_HTTP_RETRIES`); `backoff-base`, the delay before the first retry in
+    /// seconds, which doubles with each subsequent retry (defaults to `1`); `backoff-max`, the
+    /// maximum delay between retries in seconds (defaults to `1800`); `jitter`, whether to
+    /// randomize the delays (defaults to `true`); `retry-after`, whether to wait for the delay
+    /// requested by the server via the `Retry-After` header of an HTTP 429 or 503 response, up to
+    /// `backoff-max` (defaults to `false`); and `retry-on`, the classes of failures to retry, among
+    /// `network`, `server-errors`, and `rate-limits` (defaults to all).
+    ///
+    /// Applies to all requests other than artifact downloads and uploads, which follow the
+    /// `download-retry-policy` and `upload-retry-policy`, respectively. The effective policies are
+    /// logged in verbose mode.
+    #[option(
+        default = r#"{ retries = 3, backoff-base = 1, backoff-max = 1800, jitter = true, retry-after = false, retry-on = ["network", "server-errors", "rate-limits"] }"#,
+        value_type = "dict",
+        example = r#"
+            retry-policy = { retries = 5, backoff-max = 30, retry-after = true }
+        "#
+    )]
+    pub retry_policy: Option<RetryPolicyOptions>,
+    /// The policy for retrying downloads of wheels, source distributions, and Python
+    /// installations that failed with a transient error.
+    ///
+    /// Supports the same fields as `retry-policy`, with any unset fields falling back to the
+    /// `retry-policy`.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            download-retry-policy = { retries = 10, backoff-base = 5 }
+        "#
+    )]
+    pub download_retry_policy: Option<RetryPolicyOptions>,
+    /// The policy for retrying uploads with `uv publish` that failed with a transient error.
+    ///
+    /// Supports the same fields as `retry-policy`, with any unset fields falling back to the
+    /// `retry-policy`. Since the upload API can't resume a partial upload, each retry sends the
+    /// whole file again.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            upload-retry-policy = { retries = 5, backoff-base = 10 }
+        "#
+    )]
+    pub upload_retry_policy: Option<RetryPolicyOptions>,
+    /// The tuning of the HTTP client's connections, for example, to improve the throughput of
+    /// parallel requests to CDN-backed indexes.
+    ///
+    /// Supports `pool-max-idle-per-host`, the maximum number of idle connections kept open per host
+    /// (defaults to `20`); `pool-idle-timeout`, the time after which an idle connection is closed,
+    /// in seconds (defaults to `90`); `tcp-keepalive`, the interval between TCP keep-alive probes,
+    /// in seconds; `http2-keep-alive-interval` and `http2-keep-alive-timeout`, the interval between
+    /// HTTP/2 `PING` frames and the time to wait for their acknowledgement, in seconds;
+    /// `http2-initial-stream-window-size` and `http2-initial-connection-window-size`, the HTTP/2
+    /// flow-control windows for each multiplexed request and for each connection, in bytes;
+    /// `http2-adaptive-window`, whether to size the HTTP/2 flow-control windows dynamically; and
+    /// `http3`, whether to use HTTP/3 for all requests (experimental, and only available in builds
+    /// of uv with the `http3` feature enabled).
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            http-connection = { pool-max-idle-per-host = 64, http2-adaptive-window = true }
+        "#
+    )]
+    pub http_connection: Option<HttpConnectionOptions>,
     /// Disable network access, relying only on locally cached data and locally available files.
     #[option(
         default = "false",
@@ -241,6 +366,117 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The maximum size of the cache (e.g., `10GB` or `500MiB`).
+    ///
+    /// When the cache exceeds this size, uv evicts the least-recently-used wheels, source
+    /// distributions, and Python interpreter archives (as cached in `UV_PYTHON_CACHE_DIR`) until
+    /// the cache is back under the limit, per the `cache-eviction-weights`. The limit is enforced
+    /// after installing packages that were downloaded from the network, and by `uv cache prune`.
+    ///
+    /// Decimal units (`kB`, `MB`, `GB`, `TB`) are powers of 1000, while binary units (`KiB`, `MiB`,
+    /// `GiB`, `TiB`) are powers of 1024. By default, the cache size is unbounded.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            cache-max-size = "10GB"
+        "#
+    )]
+    pub cache_max_size: Option<ByteSize>,
+    /// The relative weights used to prioritize cache entries for eviction when the cache exceeds
+    /// `cache-max-size`.
+    ///
+    /// Entries are evicted in order of the time since they were last used, multiplied by the
+    /// weight of their kind, such that entries with a higher weight are evicted sooner. A weight of
+    /// `0` exempts the corresponding entries from eviction.
+    ///
+    /// Supports `wheels` (defaults to `1.0`), `source-distributions`, which includes the wheels
+    /// built from them (defaults to `0.5`, as they must be rebuilt once evicted), and `python`, for
+    /// Python interpreter archives (defaults to `1.0`).
+    #[option(
+        default = r#"{ wheels = 1.0, source-distributions = 0.5, python = 1.0 }"#,
+        value_type = "dict",
+        example = r#"
+            cache-eviction-weights = { wheels = 1.0, source-distributions = 0.1, python = 0.0 }
+        "#
+    )]
+    pub cache_eviction_weights: Option<CacheEvictionWeights>,
+    /// Whether to isolate the cache entries for each package index.
+    ///
+    /// The wheels, source distributions, and metadata fetched from each index are always cached
+    /// separately. By default, though, identical wheels fetched from different indexes (e.g.,
+    /// PyPI and an internal mirror) share a single unpacked copy, and wheels built from identical
+    /// source distributions are shared via the remote cache, if any.
+    ///
+    /// When enabled, the unpacked wheels and remotely cached builds are also scoped to the index
+    /// that provided them, such that switching between indexes can never serve an artifact that
+    /// the configured index didn't provide.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            cache-index-isolation = true
+        "#
+    )]
+    pub cache_index_isolation: Option<bool>,
+    /// The directories to which individual cache buckets are routed, in lieu of the cache
+    /// directory.
+    ///
+    /// Supports `archive`, for the unpacked wheels that are linked into environments, `wheels`,
+    /// for the pre-built wheels downloaded from an index or URL, `source-distributions`, for
+    /// source distributions along with the wheels built from them, and `git`, for Git
+    /// repositories. Each bucket is stored under the given directory with the same name it would
+    /// have under the cache directory, such that several buckets can share a directory.
+    ///
+    /// For example, the unpacked wheels can be kept on a fast disk, while the source
+    /// distributions are kept on a larger, slower one. The unpacked wheels should be on the same
+    /// file system as the environments uv installs into, to allow for hardlinking.
+    ///
+    /// Existing cache entries are not moved when a bucket is routed elsewhere.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            cache-roots = { archive = "/mnt/nvme/uv", source-distributions = "/mnt/hdd/uv" }
+        "#
+    )]
+    pub cache_roots: Option<CacheRoots>,
+    /// The URL of a remote cache to consult before building source distributions.
+    ///
+    /// Before building a wheel from a source distribution, uv will attempt to fetch a matching
+    /// wheel from the remote cache, falling back to a local build if the wheel is missing or
+    /// can't be fetched. Wheels are matched by the identity of the source distribution (its
+    /// SHA-256 digest or URL, or its Git commit), the build settings, and the target interpreter.
+    /// Local source distributions (i.e., paths and directories) are never shared.
+    ///
+    /// Supports HTTP servers (`https://`), as well as Amazon S3 (`s3://<bucket>/<prefix>`),
+    /// Google Cloud Storage (`gs://<bucket>/<prefix>`), and Azure Blob Storage
+    /// (`az://<account>/<container>/<prefix>`) object stores.
+    ///
+    /// For HTTP servers, credentials are read from the URL itself, or from the netrc file or
+    /// keyring, as for package indexes. For object stores, credentials are read from the
+    /// environment (e.g., `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY` for S3).
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            remote-cache-url = "https://cache.example.com/uv"
+        "#
+    )]
+    pub remote_cache_url: Option<DisplaySafeUrl>,
+    /// Whether to upload wheels built from source distributions to the remote cache.
+    ///
+    /// When enabled, any wheel that uv builds from a source distribution is uploaded to the
+    /// `remote-cache-url` via HTTP `PUT`, such that it can be fetched by other machines rather
+    /// than rebuilt. Failed uploads are ignored.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            remote-cache-upload = true
+        "#
+    )]
+    pub remote_cache_upload: Option<bool>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -281,6 +517,32 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_downloads: Option<NonZeroUsize>,
+    /// The maximum number of in-flight concurrent downloads that uv will perform against any one
+    /// host at any given time.
+    ///
+    /// Useful to avoid tripping the rate limits of a package index or artifact proxy, while still
+    /// downloading from other hosts in parallel. Defaults to the value of `concurrent-downloads`.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-downloads-per-host = 8
+        "#
+    )]
+    pub concurrent_downloads_per_host: Option<NonZeroUsize>,
+    /// The maximum bandwidth that uv will use for downloading packages, in bytes per second,
+    /// shared across all concurrent downloads.
+    ///
+    /// Decimal units (`kB`, `MB`, `GB`, `TB`) are powers of 1000, while binary units (`KiB`, `MiB`,
+    /// `GiB`, `TiB`) are powers of 1024. By default, the bandwidth is unbounded.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            download-bandwidth = "10MB"
+        "#
+    )]
+    pub download_bandwidth: Option<ByteSize>,
     /// The maximum number of source distributions that uv will build concurrently at any given
     /// time.
     ///
@@ -304,6 +566,44 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// The number of Python processes used when compiling packages to bytecode.
+    ///
+    /// Defaults to the value of `concurrent-installs`.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-compiles = 4
+        "#
+    )]
+    pub concurrent_compiles: Option<NonZeroUsize>,
+    /// The strategy used to work around transient file system errors when installing packages on
+    /// Windows, such as files that are temporarily locked by antivirus software (`Access is
+    /// denied`), or package files with paths that exceed the `MAX_PATH` limit.
+    ///
+    /// Has no effect on other platforms.
+    #[option(
+        default = "\"retry\"",
+        value_type = "str",
+        example = r#"
+            windows-install-robustness = "robust"
+        "#,
+        possible_values = true
+    )]
+    pub windows_install_robustness: Option<WindowsInstallRobustness>,
+    /// The maximum time to wait for a lock held by another uv process, in seconds.
+    ///
+    /// By default, uv waits indefinitely for locks held by other uv processes (e.g., on a virtual
+    /// environment or a cache entry). If the lock isn't released within the given time, uv exits
+    /// with an error identifying the process that holds the lock.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            wait-timeout = 300
+        "#
+    )]
+    pub wait_timeout: Option<u64>,
     /// Allow insecure connections to host.
     ///
     /// Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
@@ -336,6 +636,10 @@ pub struct InstallerOptions {
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+    pub compile_bytecode_package: Option<Vec<PackageName>>,
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
+    pub install_hooks: Option<InstallHooks>,
     pub reinstall: Option<bool>,
     pub reinstall_package: Option<Vec<PackageName>>,
     pub no_build: Option<bool>,
@@ -371,7 +675,7 @@ pub struct ResolverOptions {
     pub no_binary: Option<bool>,
     pub no_binary_package: Option<Vec<PackageName>>,
     pub no_build_isolation: Option<bool>,
-    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub no_build_isolation_package: Option<NoBuildIsolationPackages>,
     pub no_sources: Option<bool>,
 }
 
@@ -499,8 +803,8 @@ pub struct ResolverInstallerOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     #[option(
         default = "\"disabled\"",
         value_type = "str",
@@ -616,14 +920,20 @@ pub struct ResolverInstallerOptions {
     ///
     /// Assumes that the packages' build dependencies specified by [PEP 518](https://peps.python.org/pep-0518/)
     /// are already installed.
+    ///
+    /// Alternatively, accepts a table from packages to build requirements that are shared from
+    /// the environment into the otherwise isolated build environment of the package, e.g., a
+    /// locally built CUDA-enabled `torch` with `{ flash-attn = ["torch"] }`. The shared packages
+    /// must be installed in the environment, and their versions must satisfy the build
+    /// requirements of the package.
     #[option(
         default = "[]",
-        value_type = "list[str]",
+        value_type = "list[str] | dict[str, list[str]]",
         example = r#"
         no-build-isolation-package = ["package1", "package2"]
     "#
     )]
-    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub no_build_isolation_package: Option<NoBuildIsolationPackages>,
     /// Limit candidate packages to those that were uploaded prior to a given point in time.
     ///
     /// Accepts a superset of [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339.html) (e.g.,
@@ -639,8 +949,8 @@ pub struct ResolverInstallerOptions {
     pub exclude_newer: Option<ExcludeNewer>,
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write, or `reflink`) on macOS, and `hardlink` on
+    /// Linux and Windows.
     #[option(
         default = "\"clone\" (macOS) or \"hardlink\" (Linux, Windows)",
         value_type = "str",
@@ -668,6 +978,63 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub compile_bytecode: Option<bool>,
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Level `1` removes `assert` statements and any code conditional on `__debug__`; level `2`
+    /// additionally removes docstrings. Optimized bytecode is only used when Python is run with
+    /// the corresponding `-O` flag (or `PYTHONOPTIMIZE` is set).
+    ///
+    /// Defaults to the interpreter's optimization level (typically, `0`).
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            compile-bytecode-level = 2
+        "#
+    )]
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+    /// Compile a specific package to bytecode after installation, even if `compile-bytecode` is
+    /// disabled.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            compile-bytecode-package = ["ruff"]
+        "#
+    )]
+    pub compile_bytecode_package: Option<Vec<PackageName>>,
+    /// Don't compile a specific package to bytecode after installation, even if
+    /// `compile-bytecode` is enabled.
+    ///
+    /// Useful for excluding large packages (e.g., `botocore`) for which compilation dominates
+    /// installation time.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-compile-bytecode-package = ["botocore"]
+        "#
+    )]
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
+    /// Commands to invoke before and after each package is installed into the environment, e.g.,
+    /// to run a malware or license scanner.
+    ///
+    /// Each command is provided as a list of arguments and is invoked once per package, with the
+    /// `UV_HOOK_STAGE`, `UV_HOOK_PACKAGE_NAME`, `UV_HOOK_PACKAGE_VERSION`, and `UV_HOOK_PATH`
+    /// environment variables set. For `pre-install` hooks, `UV_HOOK_PATH` is the path to the
+    /// unpacked wheel, just before it is linked into the environment; for `post-install` hooks,
+    /// it's the path to the installed `.dist-info` directory.
+    ///
+    /// If a hook exits with a non-zero status, the installation is aborted and any changes to the
+    /// environment are rolled back.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            install-hooks = { pre-install = ["scan-package", "--strict"] }
+        "#
+    )]
+    pub install_hooks: Option<InstallHooks>,
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -1040,8 +1407,8 @@ pub struct PipOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider subprocess`, uv uses the `keyring` CLI to handle authentication.
+    /// With `--keyring-provider native`, uv uses the operating system's credential store instead.
     #[option(
         default = "disabled",
         value_type = "str",
@@ -1112,14 +1479,20 @@ pub struct PipOptions {
     ///
     /// Assumes that the packages' build dependencies specified by [PEP 518](https://peps.python.org/pep-0518/)
     /// are already installed.
+    ///
+    /// Alternatively, accepts a table from packages to build requirements that are shared from
+    /// the environment into the otherwise isolated build environment of the package, e.g., a
+    /// locally built CUDA-enabled `torch` with `{ flash-attn = ["torch"] }`. The shared packages
+    /// must be installed in the environment, and their versions must satisfy the build
+    /// requirements of the package.
     #[option(
         default = "[]",
-        value_type = "list[str]",
+        value_type = "list[str] | dict[str, list[str]]",
         example = r#"
             no-build-isolation-package = ["package1", "package2"]
         "#
     )]
-    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub no_build_isolation_package: Option<NoBuildIsolationPackages>,
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[option(
@@ -1471,6 +1844,16 @@ pub struct PipOptions {
         "#
     )]
     pub emit_index_annotation: Option<bool>,
+    /// Include the line of the file that declared each requirement in the comment annotations
+    /// (e.g., `# via -r requirements.in:3` or `# via project (pyproject.toml:8)`).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-source-lines = true
+        "#
+    )]
+    pub emit_source_lines: Option<bool>,
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
     #[option(
@@ -1484,8 +1867,8 @@ pub struct PipOptions {
     pub annotation_style: Option<AnnotationStyle>,
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write, or `reflink`) on macOS, and `hardlink` on
+    /// Linux and Windows.
     #[option(
         default = "\"clone\" (macOS) or \"hardlink\" (Linux, Windows)",
         value_type = "str",
@@ -1513,6 +1896,63 @@ pub struct PipOptions {
         "#
     )]
     pub compile_bytecode: Option<bool>,
+    /// The optimization level to use when compiling Python files to bytecode.
+    ///
+    /// Level `1` removes `assert` statements and any code conditional on `__debug__`; level `2`
+    /// additionally removes docstrings. Optimized bytecode is only used when Python is run with
+    /// the corresponding `-O` flag (or `PYTHONOPTIMIZE` is set).
+    ///
+    /// Defaults to the interpreter's optimization level (typically, `0`).
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            compile-bytecode-level = 2
+        "#
+    )]
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+    /// Compile a specific package to bytecode after installation, even if `compile-bytecode` is
+    /// disabled.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            compile-bytecode-package = ["ruff"]
+        "#
+    )]
+    pub compile_bytecode_package: Option<Vec<PackageName>>,
+    /// Don't compile a specific package to bytecode after installation, even if
+    /// `compile-bytecode` is enabled.
+    ///
+    /// Useful for excluding large packages (e.g., `botocore`) for which compilation dominates
+    /// installation time.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-compile-bytecode-package = ["botocore"]
+        "#
+    )]
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
+    /// Commands to invoke before and after each package is installed into the environment, e.g.,
+    /// to run a malware or license scanner.
+    ///
+    /// Each command is provided as a list of arguments and is invoked once per package, with the
+    /// `UV_HOOK_STAGE`, `UV_HOOK_PACKAGE_NAME`, `UV_HOOK_PACKAGE_VERSION`, and `UV_HOOK_PATH`
+    /// environment variables set. For `pre-install` hooks, `UV_HOOK_PATH` is the path to the
+    /// unpacked wheel, just before it is linked into the environment; for `post-install` hooks,
+    /// it's the path to the installed `.dist-info` directory.
+    ///
+    /// If a hook exits with a non-zero status, the installation is aborted and any changes to the
+    /// environment are rolled back.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            install-hooks = { pre-install = ["scan-package", "--strict"] }
+        "#
+    )]
+    pub install_hooks: Option<InstallHooks>,
     /// Require a matching hash for each requirement.
     ///
     /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
@@ -1704,6 +2144,10 @@ impl From<ResolverInstallerOptions> for InstallerOptions {
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            compile_bytecode_level: value.compile_bytecode_level,
+            compile_bytecode_package: value.compile_bytecode_package,
+            no_compile_bytecode_package: value.no_compile_bytecode_package,
+            install_hooks: value.install_hooks,
             reinstall: value.reinstall,
             reinstall_package: value.reinstall_package,
             no_build: value.no_build,
@@ -1740,10 +2184,13 @@ pub struct ToolOptions {
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub no_build_isolation: Option<bool>,
-    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub no_build_isolation_package: Option<NoBuildIsolationPackages>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
+    pub compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+    pub compile_bytecode_package: Option<Vec<PackageName>>,
+    pub no_compile_bytecode_package: Option<Vec<PackageName>>,
     pub no_sources: Option<bool>,
     pub no_build: Option<bool>,
     pub no_build_package: Option<Vec<PackageName>>,
@@ -1772,6 +2219,9 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            compile_bytecode_level: value.compile_bytecode_level,
+            compile_bytecode_package: value.compile_bytecode_package,
+            no_compile_bytecode_package: value.no_compile_bytecode_package,
             no_sources: value.no_sources,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
@@ -1802,6 +2252,10 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             exclude_newer: value.exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            compile_bytecode_level: value.compile_bytecode_level,
+            compile_bytecode_package: value.compile_bytecode_package,
+            no_compile_bytecode_package: value.no_compile_bytecode_package,
+            install_hooks: None,
             no_sources: value.no_sources,
             upgrade: None,
             upgrade_package: None,
@@ -1824,15 +2278,33 @@ pub struct OptionsWire {
     // globals: GlobalOptions
     required_version: Option<RequiredVersion>,
     native_tls: Option<bool>,
+    http_proxy: Option<ProxyUrl>,
+    https_proxy: Option<ProxyUrl>,
+    no_proxy: Option<Vec<String>>,
+    retry_policy: Option<RetryPolicyOptions>,
+    download_retry_policy: Option<RetryPolicyOptions>,
+    upload_retry_policy: Option<RetryPolicyOptions>,
+    http_connection: Option<HttpConnectionOptions>,
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_max_size: Option<ByteSize>,
+    cache_eviction_weights: Option<CacheEvictionWeights>,
+    cache_index_isolation: Option<bool>,
+    cache_roots: Option<CacheRoots>,
+    remote_cache_url: Option<DisplaySafeUrl>,
+    remote_cache_upload: Option<bool>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
     concurrent_downloads: Option<NonZeroUsize>,
+    concurrent_downloads_per_host: Option<NonZeroUsize>,
+    download_bandwidth: Option<ByteSize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    concurrent_compiles: Option<NonZeroUsize>,
+    windows_install_robustness: Option<WindowsInstallRobustness>,
+    wait_timeout: Option<u64>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -1851,10 +2323,14 @@ pub struct OptionsWire {
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
     no_build_isolation: Option<bool>,
-    no_build_isolation_package: Option<Vec<PackageName>>,
+    no_build_isolation_package: Option<NoBuildIsolationPackages>,
     exclude_newer: Option<ExcludeNewer>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
+    compile_bytecode_level: Option<BytecodeOptimizationLevel>,
+    compile_bytecode_package: Option<Vec<PackageName>>,
+    no_compile_bytecode_package: Option<Vec<PackageName>>,
+    install_hooks: Option<InstallHooks>,
     no_sources: Option<bool>,
     upgrade: Option<bool>,
     upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
@@ -1881,6 +2357,12 @@ pub struct OptionsWire {
     // add: AddOptions
     add_bounds: Option<AddBoundsKind>,
 
+    // #[serde(flatten)]
+    // sync: SyncOptions
+    expose_scripts: Option<PathBuf>,
+    confirm_download_size: Option<ByteSize>,
+    max_download_size: Option<ByteSize>,
+
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
 
@@ -1901,6 +2383,9 @@ pub struct OptionsWire {
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
+    build_constraint_dependencies_package: Option<serde::de::IgnoredAny>,
+    patches: Option<serde::de::IgnoredAny>,
+    tasks: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
@@ -1914,9 +2399,22 @@ impl From<OptionsWire> for Options {
         let OptionsWire {
             required_version,
             native_tls,
+            http_proxy,
+            https_proxy,
+            no_proxy,
+            retry_policy,
+            download_retry_policy,
+            upload_retry_policy,
+            http_connection,
             offline,
             no_cache,
             cache_dir,
+            cache_max_size,
+            cache_eviction_weights,
+            cache_index_isolation,
+            cache_roots,
+            remote_cache_url,
+            remote_cache_upload,
             preview,
             python_preference,
             python_downloads,
@@ -1924,8 +2422,13 @@ impl From<OptionsWire> for Options {
             pypy_install_mirror,
             python_downloads_json_url,
             concurrent_downloads,
+            concurrent_downloads_per_host,
+            download_bandwidth,
             concurrent_builds,
             concurrent_installs,
+            concurrent_compiles,
+            windows_install_robustness,
+            wait_timeout,
             index,
             index_url,
             extra_index_url,
@@ -1945,6 +2448,10 @@ impl From<OptionsWire> for Options {
             exclude_newer,
             link_mode,
             compile_bytecode,
+            compile_bytecode_level,
+            compile_bytecode_package,
+            no_compile_bytecode_package,
+            install_hooks,
             no_sources,
             upgrade,
             upgrade_package,
@@ -1972,7 +2479,13 @@ impl From<OptionsWire> for Options {
             dev_dependencies,
             managed,
             package,
+            build_constraint_dependencies_package,
+            patches,
+            tasks,
             add_bounds: bounds,
+            expose_scripts,
+            confirm_download_size,
+            max_download_size,
             // Used by the build backend
             build_backend,
         } = value;
@@ -1981,15 +2494,33 @@ impl From<OptionsWire> for Options {
             globals: GlobalOptions {
                 required_version,
                 native_tls,
+                http_proxy,
+                https_proxy,
+                no_proxy,
+                retry_policy,
+                download_retry_policy,
+                upload_retry_policy,
+                http_connection,
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_size,
+                cache_eviction_weights,
+                cache_index_isolation,
+                cache_roots,
+                remote_cache_url,
+                remote_cache_upload,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
+                concurrent_downloads_per_host,
+                download_bandwidth,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_compiles,
+                windows_install_robustness,
+                wait_timeout,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
             },
@@ -2012,6 +2543,10 @@ impl From<OptionsWire> for Options {
                 exclude_newer,
                 link_mode,
                 compile_bytecode,
+                compile_bytecode_level,
+                compile_bytecode_package,
+                no_compile_bytecode_package,
+                install_hooks,
                 no_sources,
                 upgrade,
                 upgrade_package,
@@ -2042,6 +2577,11 @@ impl From<OptionsWire> for Options {
                 check_url,
             },
             add: AddOptions { add_bounds: bounds },
+            sync: SyncOptions {
+                expose_scripts,
+                confirm_download_size,
+                max_download_size,
+            },
             workspace,
             sources,
             dev_dependencies,
@@ -2049,6 +2589,9 @@ impl From<OptionsWire> for Options {
             dependency_groups,
             managed,
             package,
+            build_constraint_dependencies_package,
+            patches,
+            tasks,
         }
     }
 }
@@ -2129,3 +2672,57 @@ pub struct AddOptions {
     )]
     pub add_bounds: Option<AddBoundsKind>,
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SyncOptions {
+    /// A directory in which to expose the console scripts installed in the project environment.
+    ///
+    /// When set, `uv sync` creates a launcher in the directory for each script installed in the
+    /// project environment (e.g., `pytest`), such that the scripts can be invoked without
+    /// activating the environment, as long as the directory is on the `PATH`. Launchers for
+    /// scripts that are no longer installed are removed on subsequent syncs.
+    ///
+    /// Relative paths are resolved against the workspace root.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            expose-scripts = "bin"
+        "#
+    )]
+    pub expose_scripts: Option<PathBuf>,
+
+    /// Report the total size of the distributions to be downloaded before syncing, if it exceeds
+    /// the given size (e.g., `500MB` or `2GiB`).
+    ///
+    /// When running interactively, `uv sync` and `uv add` will also ask for confirmation before
+    /// proceeding with the download. Otherwise, the download size is reported and the sync
+    /// proceeds.
+    ///
+    /// Sizes are estimated from the file sizes reported by the index; distributions for which the
+    /// index does not report a size are not counted.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            confirm-download-size = "1GB"
+        "#
+    )]
+    pub confirm_download_size: Option<ByteSize>,
+
+    /// Refuse to sync if the total size of the distributions to be downloaded exceeds the given
+    /// size (e.g., `500MB` or `2GiB`).
+    ///
+    /// Sizes are estimated from the file sizes reported by the index; distributions for which the
+    /// index does not report a size are not counted.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-download-size = "5GiB"
+        "#
+    )]
+    pub max_download_size: Option<ByteSize>,
+}
@@ -45,6 +45,22 @@ impl EnvVars {
     /// directory for caching instead of the default cache directory.
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `cache-max-size` setting. If set, uv will evict the least-recently-used
+    /// entries from the cache when it exceeds the given size (e.g., `10GB`).
+    pub const UV_CACHE_MAX_SIZE: &'static str = "UV_CACHE_MAX_SIZE";
+
+    /// Equivalent to the `cache-index-isolation` setting. If set, uv will scope the unpacked wheels
+    /// and remotely cached builds to the index that provided them.
+    pub const UV_CACHE_INDEX_ISOLATION: &'static str = "UV_CACHE_INDEX_ISOLATION";
+
+    /// Equivalent to the `remote-cache-url` setting. If set, uv will fetch wheels built from
+    /// source distributions from the remote cache at the given URL, rather than building them.
+    pub const UV_REMOTE_CACHE_URL: &'static str = "UV_REMOTE_CACHE_URL";
+
+    /// Equivalent to the `remote-cache-upload` setting. If set, uv will upload any wheels that it
+    /// builds from source distributions to the remote cache.
+    pub const UV_REMOTE_CACHE_UPLOAD: &'static str = "UV_REMOTE_CACHE_UPLOAD";
+
     /// Equivalent to the `--no-cache` command-line argument. If set, uv will not use the
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
@@ -104,6 +120,15 @@ impl EnvVars {
     /// as constraints for any source distribution builds. Uses space-separated list of files.
     pub const UV_BUILD_CONSTRAINT: &'static str = "UV_BUILD_CONSTRAINT";
 
+    /// The Python interpreter the uv build backend compiles extension modules for and calls build
+    /// hook entry points with. Set by the `uv_build` package to the interpreter running the build,
+    /// defaults to `python3` (or `python` on Windows) from the `PATH`.
+    pub const UV_BUILD_BACKEND_PYTHON: &'static str = "UV_BUILD_BACKEND_PYTHON";
+
+    /// Set by the uv build backend when running build hooks, the directory in which the hook can
+    /// generate files to add to the wheel.
+    pub const UV_BUILD_HOOK_OUTPUT_DIR: &'static str = "UV_BUILD_HOOK_OUTPUT_DIR";
+
     /// Equivalent to the `--override` command-line argument. If set, uv will use this file
     /// as the overrides file. Uses space-separated list of files.
     pub const UV_OVERRIDE: &'static str = "UV_OVERRIDE";
@@ -162,9 +187,31 @@ impl EnvVars {
     /// will compile Python source files to bytecode after installation.
     pub const UV_COMPILE_BYTECODE: &'static str = "UV_COMPILE_BYTECODE";
 
+    /// Equivalent to the `--compile-bytecode-level` command-line argument. If set, uv will
+    /// compile Python source files to bytecode with the given optimization level (`0`, `1`, or
+    /// `2`).
+    pub const UV_COMPILE_BYTECODE_LEVEL: &'static str = "UV_COMPILE_BYTECODE_LEVEL";
+
     /// Timeout (in seconds) for bytecode compilation.
     pub const UV_COMPILE_BYTECODE_TIMEOUT: &'static str = "UV_COMPILE_BYTECODE_TIMEOUT";
 
+    /// Set by uv when invoking an `install-hooks` command, to either `pre-install` or
+    /// `post-install`.
+    pub const UV_HOOK_STAGE: &'static str = "UV_HOOK_STAGE";
+
+    /// Set by uv when invoking an `install-hooks` command, to the name of the package being
+    /// installed.
+    pub const UV_HOOK_PACKAGE_NAME: &'static str = "UV_HOOK_PACKAGE_NAME";
+
+    /// Set by uv when invoking an `install-hooks` command, to the version of the package being
+    /// installed.
+    pub const UV_HOOK_PACKAGE_VERSION: &'static str = "UV_HOOK_PACKAGE_VERSION";
+
+    /// Set by uv when invoking an `install-hooks` command. For `pre-install` hooks, the path to
+    /// the unpacked wheel; for `post-install` hooks, the path to the installed `.dist-info`
+    /// directory.
+    pub const UV_HOOK_PATH: &'static str = "UV_HOOK_PATH";
+
     /// Equivalent to the `--no-editable` command-line argument. If set, uv
     /// installs any editable dependencies, including the project and any workspace members, as
     /// non-editable
@@ -196,7 +243,8 @@ impl EnvVars {
     pub const UV_PUBLISH_TOKEN: &'static str = "UV_PUBLISH_TOKEN";
 
     /// Equivalent to the `--index` command-line argument in `uv publish`. If
-    /// set, uv the index with this name in the configuration for publishing.
+    /// set, uv publishes to the indexes with these (space-separated) names in the
+    /// configuration.
     pub const UV_PUBLISH_INDEX: &'static str = "UV_PUBLISH_INDEX";
 
     /// Equivalent to the `--username` command-line argument in `uv publish`. If
@@ -214,6 +262,10 @@ impl EnvVars {
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
+    /// Equivalent to the `--max-download-size` command-line argument. If set, uv will refuse to
+    /// sync if the total size of the distributions to be downloaded exceeds the given size.
+    pub const UV_MAX_DOWNLOAD_SIZE: &'static str = "UV_MAX_DOWNLOAD_SIZE";
+
     /// Equivalent to the `--locked` command-line argument. If set, uv will assert that the
     /// `uv.lock` remains unchanged.
     pub const UV_LOCKED: &'static str = "UV_LOCKED";
@@ -239,6 +291,14 @@ impl EnvVars {
     /// perform at any given time.
     pub const UV_CONCURRENT_DOWNLOADS: &'static str = "UV_CONCURRENT_DOWNLOADS";
 
+    /// Sets the maximum number of in-flight concurrent downloads that uv will
+    /// perform against any one host at any given time.
+    pub const UV_CONCURRENT_DOWNLOADS_PER_HOST: &'static str = "UV_CONCURRENT_DOWNLOADS_PER_HOST";
+
+    /// Equivalent to the `download-bandwidth` setting. The maximum bandwidth that uv will use for
+    /// downloading packages, in bytes per second, optionally followed by a unit (e.g., `10MB`).
+    pub const UV_DOWNLOAD_BANDWIDTH: &'static str = "UV_DOWNLOAD_BANDWIDTH";
+
     /// Sets the maximum number of source distributions that uv will build
     /// concurrently at any given time.
     pub const UV_CONCURRENT_BUILDS: &'static str = "UV_CONCURRENT_BUILDS";
@@ -247,10 +307,33 @@ impl EnvVars {
     /// packages.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Equivalent to the `windows-install-robustness` setting. The strategy used to work around
+    /// transient file system errors when installing packages on Windows: `none`, `retry`, or
+    /// `robust`.
+    pub const UV_WINDOWS_INSTALL_ROBUSTNESS: &'static str = "UV_WINDOWS_INSTALL_ROBUSTNESS";
+
+    /// Equivalent to the `--wait-timeout` command-line argument. The maximum time to wait for a
+    /// lock held by another uv process, in seconds.
+    pub const UV_WAIT_TIMEOUT: &'static str = "UV_WAIT_TIMEOUT";
+
+    /// Equivalent to the `--trace-http` command-line argument. Records the HTTP requests made by
+    /// uv to the given file.
+    pub const UV_TRACE_HTTP: &'static str = "UV_TRACE_HTTP";
+
+    /// Controls the number of Python processes used when compiling packages to bytecode.
+    /// Defaults to the value of `UV_CONCURRENT_INSTALLS`.
+    pub const UV_CONCURRENT_COMPILES: &'static str = "UV_CONCURRENT_COMPILES";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// Specifies the directory where uv stores the credentials managed by `uv auth login`.
+    pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
+
+    /// The passphrase with which the credential store is sealed, as set by `uv auth seal`.
+    pub const UV_CREDENTIALS_PASSPHRASE: &'static str = "UV_CREDENTIALS_PASSPHRASE";
+
     /// Specifies the directory where uv stores managed tools.
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
 
@@ -287,6 +370,10 @@ impl EnvVars {
     /// Note that currently, only local paths are supported.
     pub const UV_PYTHON_DOWNLOADS_JSON_URL: &'static str = "UV_PYTHON_DOWNLOADS_JSON_URL";
 
+    /// The URL of the OSV API to query for known vulnerabilities in `uv pip audit`, e.g., for a
+    /// mirror. Defaults to `https://api.osv.dev`.
+    pub const UV_OSV_URL: &'static str = "UV_OSV_URL";
+
     /// Specifies the directory for caching the archives of managed Python installations before
     /// installation.
     pub const UV_PYTHON_CACHE_DIR: &'static str = "UV_PYTHON_CACHE_DIR";
@@ -431,9 +518,15 @@ impl EnvVars {
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
-    /// The number of retries for HTTP requests. (default: 3)
+    /// The number of retries for HTTP requests, overriding the `retry-policy` and
+    /// `download-retry-policy` settings. (default: 3)
     pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
 
+    /// The total number of times interrupted downloads are resumed, across all downloads in a
+    /// single invocation. Each download is also resumed at most `UV_HTTP_RETRIES` times.
+    /// (default: 20)
+    pub const UV_HTTP_RESUME_BUDGET: &'static str = "UV_HTTP_RESUME_BUDGET";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
 
@@ -445,6 +538,18 @@ impl EnvVars {
     /// See [`PycInvalidationMode`](https://docs.python.org/3/library/py_compile.html#py_compile.PycInvalidationMode).
     pub const PYC_INVALIDATION_MODE: &'static str = "PYC_INVALIDATION_MODE";
 
+    /// A Unix timestamp to apply as the modification time of installed files, for reproducible
+    /// installations.
+    ///
+    /// When set, uv will also omit metadata that varies between installations, such that repeated
+    /// installations produce identical `site-packages` directories.
+    ///
+    /// The uv build backend uses it as the modification time of the files in source
+    /// distributions and wheels.
+    ///
+    /// See [`SOURCE_DATE_EPOCH`](https://reproducible-builds.org/specs/source-date-epoch/).
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// Used to detect an activated virtual environment.
     pub const VIRTUAL_ENV: &'static str = "VIRTUAL_ENV";
 
@@ -485,6 +590,39 @@ impl EnvVars {
     /// Defaults to `13.0`, the least-recent non-EOL macOS version at time of writing.
     pub const MACOSX_DEPLOYMENT_TARGET: &'static str = "MACOSX_DEPLOYMENT_TARGET";
 
+    /// The C compiler used to build extension modules, which uv includes in the fingerprint of
+    /// the build environment when caching wheels built from source distributions.
+    pub const CC: &'static str = "CC";
+
+    /// The C++ compiler used to build extension modules, which uv includes in the fingerprint of
+    /// the build environment when caching wheels built from source distributions.
+    pub const CXX: &'static str = "CXX";
+
+    /// Flags passed to the C compiler, which uv includes in the fingerprint of the build
+    /// environment when caching wheels built from source distributions.
+    pub const CFLAGS: &'static str = "CFLAGS";
+
+    /// Flags passed to the C++ compiler, which uv includes in the fingerprint of the build
+    /// environment when caching wheels built from source distributions.
+    pub const CXXFLAGS: &'static str = "CXXFLAGS";
+
+    /// Flags passed to the C preprocessor, which uv includes in the fingerprint of the build
+    /// environment when caching wheels built from source distributions.
+    pub const CPPFLAGS: &'static str = "CPPFLAGS";
+
+    /// Flags passed to the linker, which uv includes in the fingerprint of the build environment
+    /// when caching wheels built from source distributions.
+    pub const LDFLAGS: &'static str = "LDFLAGS";
+
+    /// The architectures targeted when building extension modules on macOS, which uv includes in
+    /// the fingerprint of the build environment when caching wheels built from source
+    /// distributions.
+    pub const ARCHFLAGS: &'static str = "ARCHFLAGS";
+
+    /// Flags passed to the Rust compiler, which uv includes in the fingerprint of the build
+    /// environment when caching wheels built from source distributions.
+    pub const RUSTFLAGS: &'static str = "RUSTFLAGS";
+
     /// Disables colored output (takes precedence over `FORCE_COLOR`).
     ///
     /// See [no-color.org](https://no-color.org).
@@ -562,12 +700,24 @@ impl EnvVars {
     /// Used for trusted publishing via `uv publish`.
     pub const GITHUB_ACTIONS: &'static str = "GITHUB_ACTIONS";
 
-    /// Used for trusted publishing via `uv publish`. Contains the oidc token url.
+    /// Used for trusted publishing via `uv publish`, and for index token exchange. Contains the oidc token url.
     pub const ACTIONS_ID_TOKEN_REQUEST_URL: &'static str = "ACTIONS_ID_TOKEN_REQUEST_URL";
 
-    /// Used for trusted publishing via `uv publish`. Contains the oidc request token.
+    /// Used for trusted publishing via `uv publish`, and for index token exchange. Contains the oidc request token.
     pub const ACTIONS_ID_TOKEN_REQUEST_TOKEN: &'static str = "ACTIONS_ID_TOKEN_REQUEST_TOKEN";
 
+    /// The user that triggered a GitHub Actions workflow, used by `uv publish` as the username
+    /// for indexes with `quirks = "github"`.
+    pub const GITHUB_ACTOR: &'static str = "GITHUB_ACTOR";
+
+    /// The token of a GitHub Actions workflow, used by `uv publish` to authenticate with indexes
+    /// with `quirks = "github"`.
+    pub const GITHUB_TOKEN: &'static str = "GITHUB_TOKEN";
+
+    /// The token of a GitLab CI job, used by `uv publish` to authenticate with indexes with
+    /// `quirks = "gitlab"`.
+    pub const CI_JOB_TOKEN: &'static str = "CI_JOB_TOKEN";
+
     /// Sets the encoding for standard I/O streams (e.g., PYTHONIOENCODING=utf-8).
     #[attr_hidden]
     pub const PYTHONIOENCODING: &'static str = "PYTHONIOENCODING";
@@ -580,6 +730,19 @@ impl EnvVars {
     #[attr_hidden]
     pub const PYTHONUTF8: &'static str = "PYTHONUTF8";
 
+    /// Set by the uv build backend when compiling Rust extension modules, such that PyO3 builds
+    /// for the target Python interpreter.
+    pub const PYO3_PYTHON: &'static str = "PYO3_PYTHON";
+
+    /// Set by `uv build --python-platform` to the `sysconfig.get_platform()` value of the target
+    /// platform, e.g., `manylinux_2_28-aarch64`, such that build backends build and tag wheels for
+    /// the target platform.
+    pub const _PYTHON_HOST_PLATFORM: &'static str = "_PYTHON_HOST_PLATFORM";
+
+    /// Set by `uv build --python-platform` to the Rust target triple of the target platform, such
+    /// that cargo compiles Rust extension modules for the target platform.
+    pub const CARGO_BUILD_TARGET: &'static str = "CARGO_BUILD_TARGET";
+
     /// Adds directories to Python module search path (e.g., `PYTHONPATH=/path/to/modules`).
     pub const PYTHONPATH: &'static str = "PYTHONPATH";
 
@@ -776,4 +939,59 @@ impl EnvVars {
 
     /// Disable Hugging Face authentication, even if `HF_TOKEN` is set.
     pub const UV_NO_HF_TOKEN: &'static str = "UV_NO_HF_TOKEN";
+
+    /// Disable automatic authentication for AWS CodeArtifact repositories, which otherwise
+    /// acquires tokens using the AWS credential chain.
+    pub const UV_NO_CODEARTIFACT_TOKEN: &'static str = "UV_NO_CODEARTIFACT_TOKEN";
+
+    /// Disable automatic authentication for Google Artifact Registry repositories, which
+    /// otherwise mints access tokens from Application Default Credentials.
+    pub const UV_NO_ARTIFACT_REGISTRY_TOKEN: &'static str = "UV_NO_ARTIFACT_REGISTRY_TOKEN";
+
+    /// The AWS access key ID, used to authenticate requests to an `s3://` remote cache.
+    pub const AWS_ACCESS_KEY_ID: &'static str = "AWS_ACCESS_KEY_ID";
+
+    /// The AWS secret access key, used to authenticate requests to an `s3://` remote cache.
+    pub const AWS_SECRET_ACCESS_KEY: &'static str = "AWS_SECRET_ACCESS_KEY";
+
+    /// The AWS session token, used to authenticate requests to an `s3://` remote cache with
+    /// temporary credentials.
+    pub const AWS_SESSION_TOKEN: &'static str = "AWS_SESSION_TOKEN";
+
+    /// The AWS region of the bucket backing an `s3://` remote cache.
+    pub const AWS_REGION: &'static str = "AWS_REGION";
+
+    /// The AWS region of the bucket backing an `s3://` remote cache, if `AWS_REGION` is not set.
+    pub const AWS_DEFAULT_REGION: &'static str = "AWS_DEFAULT_REGION";
+
+    /// A custom endpoint for an `s3://` remote cache, for S3-compatible object stores
+    /// (e.g., `http://localhost:9000` for MinIO).
+    pub const AWS_ENDPOINT_URL: &'static str = "AWS_ENDPOINT_URL";
+
+    /// An OAuth access token, used to authenticate requests to a `gs://` remote cache
+    /// (e.g., as generated by `gcloud auth print-access-token`).
+    pub const GOOGLE_OAUTH_ACCESS_TOKEN: &'static str = "GOOGLE_OAUTH_ACCESS_TOKEN";
+
+    /// A shared access signature, used to authenticate requests to an `az://` remote cache.
+    pub const AZURE_STORAGE_SAS_TOKEN: &'static str = "AZURE_STORAGE_SAS_TOKEN";
+
+    /// The Microsoft Entra ID tenant, used to acquire tokens for indexes with an
+    /// `azure-identity`.
+    pub const AZURE_TENANT_ID: &'static str = "AZURE_TENANT_ID";
+
+    /// The client ID of the service principal or user-assigned managed identity, used to acquire
+    /// tokens for indexes with an `azure-identity`.
+    pub const AZURE_CLIENT_ID: &'static str = "AZURE_CLIENT_ID";
+
+    /// The client secret of the service principal, used to acquire tokens for indexes with
+    /// `azure-identity = "service-principal"`.
+    pub const AZURE_CLIENT_SECRET: &'static str = "AZURE_CLIENT_SECRET";
+
+    /// The managed identity endpoint on Azure App Service and Azure Functions, used to acquire
+    /// tokens for indexes with `azure-identity = "managed-identity"`.
+    pub const IDENTITY_ENDPOINT: &'static str = "IDENTITY_ENDPOINT";
+
+    /// The secret header for the managed identity endpoint on Azure App Service and Azure
+    /// Functions.
+    pub const IDENTITY_HEADER: &'static str = "IDENTITY_HEADER";
 }
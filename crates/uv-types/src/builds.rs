@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use dashmap::DashMap;
 
-use uv_configuration::{BuildKind, SourceStrategy};
+use uv_configuration::{BuildKind, NoBuildIsolationPackages, SourceStrategy};
 use uv_pep508::PackageName;
 use uv_python::PythonEnvironment;
 
@@ -13,7 +13,7 @@ pub enum BuildIsolation<'a> {
     #[default]
     Isolated,
     Shared(&'a PythonEnvironment),
-    SharedPackage(&'a PythonEnvironment, &'a [PackageName]),
+    SharedPackage(&'a PythonEnvironment, &'a NoBuildIsolationPackages),
 }
 
 impl BuildIsolation<'_> {
@@ -23,7 +23,7 @@ impl BuildIsolation<'_> {
             Self::Isolated => true,
             Self::Shared(_) => false,
             Self::SharedPackage(_, packages) => {
-                package.is_none_or(|package| !packages.iter().any(|p| p == package))
+                package.is_none_or(|package| !packages.contains(package))
             }
         }
     }
@@ -34,7 +34,7 @@ impl BuildIsolation<'_> {
             Self::Isolated => None,
             Self::Shared(env) => Some(env),
             Self::SharedPackage(env, packages) => {
-                if package.is_some_and(|package| packages.iter().any(|p| p == package)) {
+                if package.is_some_and(|package| packages.contains(package)) {
                     Some(env)
                 } else {
                     None
@@ -42,6 +42,25 @@ impl BuildIsolation<'_> {
             }
         }
     }
+
+    /// Returns the environment and the build requirements to share from it into the isolated
+    /// build environment of a given package, if any.
+    pub fn shared_packages(
+        &self,
+        package: Option<&PackageName>,
+    ) -> Option<(&PythonEnvironment, &[PackageName])> {
+        match self {
+            Self::Isolated | Self::Shared(_) => None,
+            Self::SharedPackage(env, packages) => {
+                let shared = packages.shared(package?);
+                if shared.is_empty() {
+                    None
+                } else {
+                    Some((env, shared))
+                }
+            }
+        }
+    }
 }
 
 /// A key for the build cache, which includes the interpreter, source root, subdirectory, source
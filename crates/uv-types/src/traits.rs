@@ -8,12 +8,13 @@ use rustc_hash::FxHashSet;
 
 use uv_cache::Cache;
 use uv_configuration::{
-    BuildKind, BuildOptions, BuildOutput, ConfigSettings, PackageConfigSettings, SourceStrategy,
+    BuildKind, BuildOptions, BuildOutput, ConfigSettings, Constraints, PackageConfigSettings,
+    SourceStrategy,
 };
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
     CachedDist, DependencyMetadata, DistributionId, IndexCapabilities, IndexLocations,
-    InstalledDist, IsBuildBackendError, Requirement, Resolution, SourceDist,
+    InstalledDist, IsBuildBackendError, Requirement, Resolution, SourceDist, SourcePatches,
 };
 use uv_git::GitResolver;
 use uv_pep508::PackageName;
@@ -92,6 +93,16 @@ pub trait BuildContext {
     /// The [`ConfigSettings`] used to build a specific package.
     fn config_settings_package(&self) -> &PackageConfigSettings;
 
+    /// The [`Constraints`] applied when resolving build requirements.
+    fn build_constraints(&self) -> &Constraints;
+
+    /// The [`Constraints`] applied when resolving the build requirements of a specific package,
+    /// including the global build constraints, if the package has build constraints of its own.
+    fn build_constraints_package(&self, package: &PackageName) -> Option<&Constraints>;
+
+    /// The [`SourcePatches`] applied to source distributions before building them.
+    fn patches(&self) -> &SourcePatches;
+
     /// Whether to incorporate `tool.uv.sources` when resolving requirements.
     fn sources(&self) -> SourceStrategy;
 
@@ -102,9 +113,13 @@ pub trait BuildContext {
     fn workspace_cache(&self) -> &WorkspaceCache;
 
     /// Resolve the given requirements into a ready-to-install set of package versions.
+    ///
+    /// `package` is the package whose build requirements are resolved, if any, to apply its
+    /// package-specific build constraints.
     fn resolve<'a>(
         &'a self,
         requirements: &'a [Requirement],
+        package: Option<&'a PackageName>,
         build_stack: &'a BuildStack,
     ) -> impl Future<Output = Result<Resolution, impl IsBuildBackendError>> + 'a;
 
@@ -21,7 +21,7 @@ use serde::de::{IntoDeserializer, SeqAccess};
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 use uv_build_backend::BuildBackendSettings;
-use uv_distribution_types::{Index, IndexName, RequirementSource};
+use uv_distribution_types::{Index, IndexName, RequirementSource, SourcePatch};
 use uv_fs::{PortablePathBuf, relative_to};
 use uv_git_types::GitReference;
 use uv_macros::OptionsMetadata;
@@ -501,6 +501,68 @@ pub struct ToolUv {
     )]
     pub build_constraint_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
 
+    /// Constraints to apply when solving the build dependencies of specific packages.
+    ///
+    /// The constraints are applied in addition to the
+    /// [`build-constraint-dependencies`](#build-constraint-dependencies) when resolving the build
+    /// dependencies of the package, but not when resolving the build dependencies of other
+    /// packages, including the packages built from source to build the package itself.
+    ///
+    /// Run with `--verbose` to see which build constraints applied to each build environment.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read
+    ///     `build-constraint-dependencies-package` from the `pyproject.toml` at the workspace root,
+    ///     and will ignore any declarations in other workspace members.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<BTreeMap<PackageName, Vec<String>>>",
+            description = "PEP 508-style requirements by package name, e.g., `{ flash-attn = [\"setuptools<70\"] }`."
+        )
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict[str, list[str]]",
+        example = r#"
+            # Build `flash-attn` with setuptools older than v70, while other packages may use
+            # newer versions.
+            build-constraint-dependencies-package = { flash-attn = ["setuptools<70"] }
+        "#
+    )]
+    pub build_constraint_dependencies_package:
+        Option<BTreeMap<PackageName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>>,
+
+    /// Patch files to apply to the source distributions of specific package versions before
+    /// building them.
+    ///
+    /// Each entry applies a patch file in unified diff format (e.g., as produced by `diff -u` or
+    /// `git diff`) to the source distribution of the given package version, such that small fixes
+    /// to upstream packages can be carried without publishing a fork to an index. The path is
+    /// relative to the workspace root, and the hash of the patch file is verified before it's
+    /// applied. The patches are recorded in the lockfile, such that changing a patch invalidates
+    /// the lockfile.
+    ///
+    /// Patches are only applied when building a package from a source distribution, i.e., from a
+    /// package index or a local source distribution archive. To ensure that a patched package is
+    /// built from source, rather than installed from a wheel, use
+    /// [`no-binary-package`](#no-binary-package).
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `patches` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            patches = [
+                { name = "flask", version = "3.0.0", path = "patches/flask.patch", hash = "sha256:2f9e2b1e0a7c3f4d5b6a8c9d0e1f2a3b4c5d6e7f8a9b0c1d2e3f4a5b6c7d8e9f" },
+            ]
+        "#
+    )]
+    pub patches: Option<Vec<SourcePatch>>,
+
     /// A list of supported environments against which to resolve dependencies.
     ///
     /// By default, uv will resolve for all possible environments during a `uv lock` operation.
@@ -609,6 +671,38 @@ pub struct ToolUv {
     )]
     pub conflicts: Option<SchemaConflicts>,
 
+    /// Tasks that can be run in the project environment with `uv task run`.
+    ///
+    /// Each task is either a command, or a table with the following fields:
+    ///
+    /// - `cmd`: The command to run. A string is run in the system shell (`sh` on Unix, `cmd` on
+    ///   Windows); a list of strings is run directly, without a shell.
+    /// - `env`: Environment variables to set when running the command.
+    /// - `cwd`: The working directory, relative to the directory containing the `pyproject.toml`.
+    /// - `depends-on`: The tasks to run before the task.
+    /// - `groups`: The dependency groups to install before running the task.
+    /// - `help`: A description of the task, displayed by `uv task list`.
+    ///
+    /// A task without a `cmd` only runs the tasks it depends on.
+    ///
+    /// Tasks are defined per project: in a workspace, `uv task run` reads the tasks of the current
+    /// workspace member, or the member selected with `--package`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<BTreeMap<String, TaskWire>>")
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str | list[str] | dict]",
+        example = r#"
+            [tool.uv.tasks]
+            lint = "ruff check"
+            test = { cmd = ["pytest", "-x"], groups = ["test"], env = { PYTHONWARNINGS = "error" } }
+            check = { depends-on = ["lint", "test"], help = "Run all checks" }
+        "#
+    )]
+    pub tasks: Option<BTreeMap<String, Task>>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -749,6 +843,99 @@ pub struct DependencyGroupSettings {
     pub requires_python: Option<VersionSpecifiers>,
 }
 
+/// A task defined in `tool.uv.tasks`.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[serde(from = "TaskWire")]
+pub struct Task {
+    /// The command to run, if any.
+    pub cmd: Option<TaskCommand>,
+    /// Environment variables to set when running the command.
+    pub env: BTreeMap<String, String>,
+    /// The working directory, relative to the directory containing the `pyproject.toml`.
+    pub cwd: Option<PathBuf>,
+    /// The tasks to run before this task.
+    pub depends_on: Vec<String>,
+    /// The dependency groups to install before running this task.
+    pub groups: Vec<GroupName>,
+    /// A description of the task.
+    pub help: Option<String>,
+}
+
+/// The command of a task in `tool.uv.tasks`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum TaskCommand {
+    /// A command line, run in the system shell.
+    Shell(String),
+    /// A program and its arguments, run without a shell.
+    Args(Vec<String>),
+}
+
+impl std::fmt::Display for TaskCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shell(command) => write!(f, "{command}"),
+            Self::Args(args) => write!(f, "{}", args.join(" ")),
+        }
+    }
+}
+
+/// A task, as written in `tool.uv.tasks`: either a bare command or a table.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(
+    untagged,
+    expecting = "a command string, a list of command arguments, or a task table"
+)]
+enum TaskWire {
+    Command(TaskCommand),
+    Table(TaskTable),
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct TaskTable {
+    cmd: Option<TaskCommand>,
+    #[serde(default)]
+    env: BTreeMap<String, String>,
+    cwd: Option<PathBuf>,
+    #[serde(default)]
+    depends_on: Vec<String>,
+    #[serde(default)]
+    groups: Vec<GroupName>,
+    help: Option<String>,
+}
+
+impl From<TaskWire> for Task {
+    fn from(wire: TaskWire) -> Self {
+        match wire {
+            TaskWire::Command(cmd) => Self {
+                cmd: Some(cmd),
+                ..Self::default()
+            },
+            TaskWire::Table(TaskTable {
+                cmd,
+                env,
+                cwd,
+                depends_on,
+                groups,
+                help,
+            }) => Self {
+                cmd,
+                env,
+                cwd,
+                depends_on,
+                groups,
+                help,
+            },
+        }
+    }
+}
+
 #[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -6,14 +6,15 @@ use std::str::FromStr;
 use std::{fmt, iter, mem};
 use thiserror::Error;
 use toml_edit::{
-    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TomlError, Value,
+    Array, ArrayOfTables, DocumentMut, Formatted, InlineTable, Item, RawString, Table, TomlError,
+    Value,
 };
 
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
 use uv_normalize::GroupName;
-use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{Operator, Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{ExtraName, MarkerTree, PackageName, Requirement, VersionOrUrl};
 use uv_redacted::DisplaySafeUrl;
 
@@ -973,6 +974,85 @@ impl PyProjectTomlMut {
         Ok(requirements)
     }
 
+    /// Adds an `include-group` entry for the given group to a group in `dependency-groups`.
+    ///
+    /// Returns `true` if the entry was added, `false` if the group already included it.
+    pub fn add_dependency_group_include(
+        &mut self,
+        group: &GroupName,
+        include: &GroupName,
+    ) -> Result<bool, Error> {
+        let deps = self.dependency_groups_array(group)?;
+        if !find_includes(include, deps).is_empty() {
+            return Ok(false);
+        }
+
+        let mut table = InlineTable::new();
+        table.insert("include-group", Value::from(include.as_ref()));
+        let mut value = Value::InlineTable(table);
+
+        // Treat any trailing comments as leading comments on the added entry, as in
+        // `add_dependency`.
+        value.decor_mut().set_prefix(deps.trailing().clone());
+        deps.set_trailing("");
+        deps.push_formatted(value);
+
+        reformat_array_multiline(deps);
+
+        Ok(true)
+    }
+
+    /// Removes the `include-group` entries for the given group from a group in
+    /// `dependency-groups`.
+    ///
+    /// Returns `true` if any entries were removed.
+    pub fn remove_dependency_group_include(
+        &mut self,
+        group: &GroupName,
+        include: &GroupName,
+    ) -> Result<bool, Error> {
+        let Some(group_dependencies) = self
+            .doc
+            .get_mut("dependency-groups")
+            .map(|groups| {
+                groups
+                    .as_table_like_mut()
+                    .ok_or(Error::MalformedDependencies)
+            })
+            .transpose()?
+            .and_then(|groups| {
+                groups.iter_mut().find_map(|(key, value)| {
+                    if GroupName::from_str(key.get()).is_ok_and(|g| g == *group) {
+                        Some(value)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .map(|dependencies| {
+                dependencies
+                    .as_array_mut()
+                    .ok_or(Error::MalformedDependencies)
+            })
+            .transpose()?
+        else {
+            return Ok(false);
+        };
+
+        let positions = find_includes(include, group_dependencies);
+        if positions.is_empty() {
+            return Ok(false);
+        }
+
+        // Reverse to preserve indices as we remove them.
+        for i in positions.into_iter().rev() {
+            group_dependencies.remove(i);
+        }
+        reformat_array_multiline(group_dependencies);
+
+        Ok(true)
+    }
+
     /// Remove a matching source from `tool.uv.sources`, if it exists.
     fn remove_source(&mut self, name: &PackageName) -> Result<(), Error> {
         // If the dependency is still in use, don't remove the source.
@@ -1147,6 +1227,76 @@ impl PyProjectTomlMut {
 
         Ok(())
     }
+
+    /// Replace the version of a dependency in all of its specifiers, e.g., when bumping the
+    /// version of a workspace member.
+    ///
+    /// Only specifiers that pin or lower-bound the old version (`==`, `===`, `>=`, and `~=`) are
+    /// updated; other specifiers are left as-is.
+    ///
+    /// This method searches `project.dependencies`, `project.optional-dependencies`,
+    /// `dependency-groups`, and `tool.uv.dev-dependencies`. Returns the updated requirements, as
+    /// pairs of the old and new requirement.
+    pub fn replace_dependency_version(
+        &mut self,
+        name: &PackageName,
+        old: &Version,
+        new: &Version,
+    ) -> Vec<(Requirement, Requirement)> {
+        let mut replaced = Vec::new();
+
+        for (key, item) in self.doc.as_table_mut().iter_mut() {
+            match key.get() {
+                "project" => {
+                    let Some(project) = item.as_table_like_mut() else {
+                        continue;
+                    };
+                    for (key, item) in project.iter_mut() {
+                        match key.get() {
+                            "dependencies" => {
+                                if let Some(deps) = item.as_array_mut() {
+                                    replaced.extend(replace_version(name, old, new, deps));
+                                }
+                            }
+                            "optional-dependencies" => {
+                                let Some(extras) = item.as_table_like_mut() else {
+                                    continue;
+                                };
+                                for (_, deps) in extras.iter_mut() {
+                                    if let Some(deps) = deps.as_array_mut() {
+                                        replaced.extend(replace_version(name, old, new, deps));
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                "dependency-groups" => {
+                    let Some(groups) = item.as_table_like_mut() else {
+                        continue;
+                    };
+                    for (_, deps) in groups.iter_mut() {
+                        if let Some(deps) = deps.as_array_mut() {
+                            replaced.extend(replace_version(name, old, new, deps));
+                        }
+                    }
+                }
+                "tool" => {
+                    if let Some(deps) = item
+                        .get_mut("uv")
+                        .and_then(|uv| uv.get_mut("dev-dependencies"))
+                        .and_then(Item::as_array_mut)
+                    {
+                        replaced.extend(replace_version(name, old, new, deps));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        replaced
+    }
 }
 
 /// Returns an implicit table.
@@ -1471,6 +1621,52 @@ fn remove_dependency(name: &PackageName, deps: &mut Array) -> Vec<Requirement> {
     removed
 }
 
+/// Replace the old version with the new version in the specifiers of all dependencies with the
+/// given name, returning the old and new requirements.
+fn replace_version(
+    name: &PackageName,
+    old: &Version,
+    new: &Version,
+    deps: &mut Array,
+) -> Vec<(Requirement, Requirement)> {
+    let mut replaced = Vec::new();
+    for (i, req) in find_dependencies(name, None, deps) {
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &req.version_or_url else {
+            continue;
+        };
+
+        let updated_specifiers = specifiers
+            .iter()
+            .map(|specifier| {
+                let operator = *specifier.operator();
+                if specifier.version() == old
+                    && matches!(
+                        operator,
+                        Operator::Equal
+                            | Operator::ExactEqual
+                            | Operator::GreaterThanEqual
+                            | Operator::TildeEqual
+                    )
+                {
+                    VersionSpecifier::from_version(operator, new.clone())
+                        .unwrap_or_else(|_| specifier.clone())
+                } else {
+                    specifier.clone()
+                }
+            })
+            .collect::<VersionSpecifiers>();
+        if updated_specifiers == *specifiers {
+            continue;
+        }
+
+        let mut updated = req.clone();
+        updated.version_or_url = Some(VersionOrUrl::VersionSpecifier(updated_specifiers));
+        deps.replace(i, updated.to_string());
+        replaced.push((req, updated));
+    }
+    replaced
+}
+
 /// Returns a `Vec` containing the all dependencies with the given name, along with their positions
 /// in the array.
 fn find_dependencies(
@@ -1489,6 +1685,20 @@ fn find_dependencies(
     to_replace
 }
 
+/// Returns the positions of all `include-group` entries for the given group.
+fn find_includes(include: &GroupName, deps: &Array) -> Vec<usize> {
+    deps.iter()
+        .enumerate()
+        .filter(|(_, dep)| {
+            dep.as_inline_table()
+                .and_then(|table| table.get("include-group"))
+                .and_then(Value::as_str)
+                .is_some_and(|name| GroupName::from_str(name).is_ok_and(|name| name == *include))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
 /// Returns the key in `tool.uv.sources` that matches the given package name.
 fn find_source(name: &PackageName, sources: &Table) -> Option<String> {
     for (key, _) in sources {
@@ -9,7 +9,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
 use uv_configuration::DependencyGroupsWithDefaults;
-use uv_distribution_types::{Index, Requirement, RequirementSource};
+use uv_distribution_types::{Index, Requirement, RequirementSource, SourcePatch};
 use uv_fs::{CWD, Simplified};
 use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
@@ -615,6 +615,38 @@ impl Workspace {
         build_constraints.clone()
     }
 
+    /// Returns the build constraints for specific packages in the workspace.
+    pub fn build_constraints_package(
+        &self,
+    ) -> BTreeMap<PackageName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>> {
+        let Some(build_constraints) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.build_constraint_dependencies_package.as_ref())
+        else {
+            return BTreeMap::new();
+        };
+        build_constraints.clone()
+    }
+
+    /// Returns the patches to apply to the source distributions of specific package versions.
+    ///
+    /// The patch paths are relative to the workspace root.
+    pub fn patches(&self) -> Vec<SourcePatch> {
+        let Some(patches) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.patches.as_ref())
+        else {
+            return vec![];
+        };
+        patches.clone()
+    }
+
     /// The path to the workspace root, the directory containing the top level `pyproject.toml` with
     /// the `uv.tool.workspace`, or the `pyproject.toml` in an implicit single workspace project.
     pub fn install_path(&self) -> &PathBuf {
@@ -1974,9 +2006,12 @@ mod tests {
                       "override-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "build-constraint-dependencies-package": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2074,9 +2109,12 @@ mod tests {
                       "override-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "build-constraint-dependencies-package": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2287,9 +2325,12 @@ mod tests {
                       "override-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "build-constraint-dependencies-package": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2396,9 +2437,12 @@ mod tests {
                       "override-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "build-constraint-dependencies-package": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2518,9 +2562,12 @@ mod tests {
                       "override-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "build-constraint-dependencies-package": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
@@ -2614,9 +2661,12 @@ mod tests {
                       "override-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "build-constraint-dependencies-package": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "tasks": null,
                       "build-backend": null
                     }
                   },
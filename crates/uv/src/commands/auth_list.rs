@@ -0,0 +1,55 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+
+use uv_auth::CredentialStore;
+
+use crate::commands::{ExitStatus, read_credential_store};
+use crate::printer::Printer;
+
+/// List the credentials in uv's credential store, with the passwords masked.
+pub(crate) fn auth_list(printer: Printer) -> Result<ExitStatus> {
+    let path = CredentialStore::default_path()
+        .context("Failed to determine the credential store location; set `UV_CREDENTIALS_DIR`")?;
+    let store = read_credential_store(&path)?;
+
+    let rows = store
+        .iter()
+        .map(|stored| {
+            let password = if stored.password.is_some() {
+                "********"
+            } else {
+                "(native keyring)"
+            };
+            (
+                stored.service.to_string(),
+                stored.username.as_str(),
+                password,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+        writeln!(printer.stderr(), "No credentials found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let service_width = rows
+        .iter()
+        .map(|(service, ..)| service.len())
+        .max()
+        .unwrap_or(0);
+    let username_width = rows
+        .iter()
+        .map(|(_, username, _)| username.len())
+        .max()
+        .unwrap_or(0);
+    for (service, username, password) in rows {
+        writeln!(
+            printer.stdout(),
+            "{service:service_width$}  {username:username_width$}  {password}"
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,87 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result, bail};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::{CredentialStore, KeyringProvider, StoredCredentials};
+use uv_configuration::KeyringProviderType;
+
+use crate::commands::{ExitStatus, read_credential_store};
+use crate::printer::Printer;
+
+/// Store credentials for an index in uv's credential store.
+pub(crate) async fn auth_login(
+    service: &str,
+    username: Option<String>,
+    password: Option<String>,
+    keyring_provider: Option<KeyringProviderType>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let service = CredentialStore::service_url(service)
+        .with_context(|| format!("Invalid index URL or host: `{service}`"))?;
+    let path = CredentialStore::default_path()
+        .context("Failed to determine the credential store location; set `UV_CREDENTIALS_DIR`")?;
+
+    let native = match keyring_provider.unwrap_or_default() {
+        KeyringProviderType::Disabled => false,
+        KeyringProviderType::Native => true,
+        KeyringProviderType::Subprocess => {
+            bail!(
+                "Storing credentials via the `keyring` command is not supported; use `keyring set` instead"
+            );
+        }
+    };
+
+    let term = Term::stderr();
+    let username = if let Some(username) = username {
+        username
+    } else {
+        if !term.is_term() {
+            bail!("No username provided; use `--username` to provide one non-interactively");
+        }
+        uv_console::input("Enter username: ", &term).context("Failed to read username")?
+    };
+    if username.is_empty() {
+        bail!("The username must not be empty");
+    }
+    let password = if let Some(password) = password {
+        password
+    } else {
+        if !term.is_term() {
+            bail!("No password provided; use `--password` to provide one non-interactively");
+        }
+        uv_console::password("Enter password: ", &term).context("Failed to read password")?
+    };
+
+    // Read the store first, to avoid writing to the keyring if the store is invalid.
+    let mut store = read_credential_store(&path)?;
+
+    let password = if native {
+        let host = KeyringProvider::host_service_name(&service)
+            .with_context(|| format!("Expected a URL with a host, but got: `{service}`"))?;
+        KeyringProvider::native()
+            .store(&host, &username, &password)
+            .await
+            .with_context(|| format!("Failed to store credentials for `{username}@{host}`"))?;
+        None
+    } else {
+        Some(password)
+    };
+
+    store.insert(StoredCredentials {
+        service: service.clone(),
+        username: username.clone(),
+        password,
+    });
+    store.write(&path)?;
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for {} on {}",
+        username.bold(),
+        service.cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
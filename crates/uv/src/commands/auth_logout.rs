@@ -0,0 +1,71 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::{CredentialStore, KeyringProvider};
+
+use crate::commands::{ExitStatus, read_credential_store};
+use crate::printer::Printer;
+
+/// Remove credentials for an index from uv's credential store.
+pub(crate) async fn auth_logout(
+    service: &str,
+    username: Option<&str>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let service = CredentialStore::service_url(service)
+        .with_context(|| format!("Invalid index URL or host: `{service}`"))?;
+    let path = CredentialStore::default_path()
+        .context("Failed to determine the credential store location; set `UV_CREDENTIALS_DIR`")?;
+
+    let mut store = read_credential_store(&path)?;
+    let removed = store.remove(&service, username);
+    if removed.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No credentials found for {}",
+            service.cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for stored in &removed {
+        // Passwords in the native keyring are stored per host, so retain them if they're still
+        // referenced by other credentials on the same host.
+        if stored.password.is_some() {
+            continue;
+        }
+        let Some(host) = KeyringProvider::host_service_name(&stored.service) else {
+            continue;
+        };
+        let referenced = store.iter().any(|other| {
+            other.password.is_none()
+                && other.username == stored.username
+                && KeyringProvider::host_service_name(&other.service).as_ref() == Some(&host)
+        });
+        if !referenced {
+            KeyringProvider::native()
+                .remove(&host, &stored.username)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to remove credentials for `{}@{host}`",
+                        stored.username
+                    )
+                })?;
+        }
+    }
+    store.write(&path)?;
+
+    for stored in &removed {
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for {} on {}",
+            stored.username.bold(),
+            service.cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,82 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result, bail};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::{CredentialStore, SealKey};
+use uv_configuration::KeyringProviderType;
+use uv_static::EnvVars;
+
+use crate::commands::{ExitStatus, read_credential_store};
+use crate::printer::Printer;
+
+/// Encrypt uv's credential store.
+pub(crate) fn auth_seal(
+    keyring_provider: Option<KeyringProviderType>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let path = CredentialStore::default_path()
+        .context("Failed to determine the credential store location; set `UV_CREDENTIALS_DIR`")?;
+
+    let native = match keyring_provider.unwrap_or_default() {
+        KeyringProviderType::Disabled => false,
+        KeyringProviderType::Native => true,
+        KeyringProviderType::Subprocess => {
+            bail!("Sealing the credential store via the `keyring` command is not supported");
+        }
+    };
+
+    let mut store = read_credential_store(&path)?;
+    if store.seal_key().is_some() {
+        bail!(
+            "The credential store is already sealed; use `{}` first to change how it's sealed",
+            "uv auth unseal".green()
+        );
+    }
+
+    let key = if native {
+        SealKey::generate_native()?
+    } else {
+        SealKey::passphrase(read_new_passphrase()?)
+    };
+    store.seal(key);
+    store.write(&path)?;
+
+    writeln!(
+        printer.stderr(),
+        "Sealed the credential store at {}",
+        CredentialStore::sealed_path(&path).display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read the passphrase with which to seal the store, from `UV_CREDENTIALS_PASSPHRASE` or a prompt.
+fn read_new_passphrase() -> Result<String> {
+    if let Some(passphrase) = std::env::var(EnvVars::UV_CREDENTIALS_PASSPHRASE)
+        .ok()
+        .filter(|passphrase| !passphrase.is_empty())
+    {
+        return Ok(passphrase);
+    }
+
+    let term = Term::stderr();
+    if !term.is_term() {
+        bail!(
+            "No passphrase provided; set `{}` to provide one non-interactively",
+            EnvVars::UV_CREDENTIALS_PASSPHRASE
+        );
+    }
+    let passphrase =
+        uv_console::password("Enter passphrase: ", &term).context("Failed to read passphrase")?;
+    if passphrase.is_empty() {
+        bail!("The passphrase must not be empty");
+    }
+    let confirmation =
+        uv_console::password("Confirm passphrase: ", &term).context("Failed to read passphrase")?;
+    if passphrase != confirmation {
+        bail!("The passphrases do not match");
+    }
+    Ok(passphrase)
+}
@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::CredentialStore;
+
+use crate::commands::{ExitStatus, read_credential_store};
+use crate::printer::Printer;
+
+/// Decrypt uv's credential store, such that it's stored in plain text.
+pub(crate) fn auth_unseal(printer: Printer) -> Result<ExitStatus> {
+    let path = CredentialStore::default_path()
+        .context("Failed to determine the credential store location; set `UV_CREDENTIALS_DIR`")?;
+
+    let mut store = read_credential_store(&path)?;
+    let Some(key) = store.unseal() else {
+        writeln!(printer.stderr(), "The credential store is not sealed")?;
+        return Ok(ExitStatus::Success);
+    };
+    store.write(&path)?;
+    key.remove_native()?;
+
+    writeln!(
+        printer.stderr(),
+        "Unsealed the credential store at {}",
+        path.display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
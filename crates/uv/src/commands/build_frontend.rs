@@ -1,22 +1,28 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::fmt::Write as _;
-use std::io::Write as _;
+use std::io::{Read as _, Write as _};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::{fmt, io};
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use thiserror::Error;
-use tracing::instrument;
+use tracing::{debug, instrument};
 
 use uv_build_backend::check_direct_build;
+use uv_build_frontend::{SourceBuild, SourceBuildContext};
 use uv_cache::{Cache, CacheBucket};
+use uv_cli::BuildFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildKind, BuildOptions, BuildOutput, Concurrency, ConfigSettings, Constraints,
     DependencyGroupsWithDefaults, HashCheckingMode, IndexStrategy, KeyringProviderType,
-    PackageConfigSettings, PreviewMode, SourceStrategy,
+    NoBuildIsolationPackages, PackageConfigSettings, PreviewMode, SourceStrategy, TargetTriple,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_filename::{
@@ -25,10 +31,14 @@ use uv_distribution_filename::{
 use uv_distribution_types::{
     DependencyMetadata, Index, IndexLocations, RequiresPython, SourceDist,
 };
+use uv_extract::hash::Hasher;
 use uv_fs::{Simplified, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_pep508::VerbatimUrl;
+use uv_platform_tags::Os;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
@@ -37,11 +47,13 @@ use uv_python::{
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy};
-use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError, WorkspaceMember};
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
+use crate::commands::project::sync::SchemaReport;
 use crate::commands::project::{ProjectError, find_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
@@ -90,6 +102,21 @@ enum Error {
     InvalidBuiltWheelFilename(#[source] uv_distribution_filename::WheelFilenameError),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error(
+        "`{0}` is not reproducible: the first build has digest `{1}`, but the second build has \
+         digest `{2}`"
+    )]
+    NotReproducible(String, HashDigest, HashDigest),
+    #[error("`{0}` is not reproducible: the second build did not produce it")]
+    NotReproducibleMissing(String),
+    #[error("Failed to read wheel: `{}`", .0.user_display())]
+    ReadWheel(PathBuf, #[source] zip::result::ZipError),
+    #[error(
+        "`{0}` built from the source distribution is missing files that are in the wheel built \
+         from the source tree, include them in the source distribution: `{files}`",
+        files = .1.join("`, `")
+    )]
+    MissingFromSdist(String, Vec<String>),
 }
 
 /// Build source distributions and wheels.
@@ -97,7 +124,7 @@ enum Error {
 pub(crate) async fn build_frontend(
     project_dir: &Path,
     src: Option<PathBuf>,
-    package: Option<PackageName>,
+    package: Vec<PackageName>,
     all_packages: bool,
     output_dir: Option<PathBuf>,
     sdist: bool,
@@ -105,9 +132,13 @@ pub(crate) async fn build_frontend(
     list: bool,
     build_logs: bool,
     force_pep517: bool,
+    reproducible_check: bool,
+    from_sdist: bool,
+    output_format: BuildFormat,
     build_constraints: Vec<RequirementsSource>,
     hash_checking: Option<HashCheckingMode>,
     python: Option<String>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
@@ -122,7 +153,7 @@ pub(crate) async fn build_frontend(
     let build_result = build_impl(
         project_dir,
         src.as_deref(),
-        package.as_ref(),
+        &package,
         all_packages,
         output_dir.as_deref(),
         sdist,
@@ -130,9 +161,13 @@ pub(crate) async fn build_frontend(
         list,
         build_logs,
         force_pep517,
+        reproducible_check,
+        from_sdist,
+        output_format,
         &build_constraints,
         hash_checking,
         python.as_deref(),
+        python_platform,
         install_mirrors,
         settings,
         network_settings,
@@ -165,7 +200,7 @@ enum BuildResult {
 async fn build_impl(
     project_dir: &Path,
     src: Option<&Path>,
-    package: Option<&PackageName>,
+    package: &[PackageName],
     all_packages: bool,
     output_dir: Option<&Path>,
     sdist: bool,
@@ -173,9 +208,13 @@ async fn build_impl(
     list: bool,
     build_logs: bool,
     force_pep517: bool,
+    reproducible_check: bool,
+    from_sdist: bool,
+    output_format: BuildFormat,
     build_constraints: &[RequirementsSource],
     hash_checking: Option<HashCheckingMode>,
     python_request: Option<&str>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
@@ -187,6 +226,12 @@ async fn build_impl(
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<BuildResult> {
+    if !preview.is_enabled() && matches!(output_format, BuildFormat::Json) {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview` to disable this warning."
+        );
+    }
+
     // Extract the resolver settings.
     let ResolverSettings {
         index_locations,
@@ -211,7 +256,13 @@ async fn build_impl(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Determine the source to build.
     let src = if let Some(src) = src {
@@ -245,7 +296,7 @@ async fn build_impl(
     .await;
 
     // If a `--package` or `--all-packages` was provided, adjust the source directory.
-    let packages = if let Some(package) = package {
+    let layers = if !package.is_empty() {
         if matches!(src, Source::File(_)) {
             return Err(anyhow::anyhow!(
                 "Cannot specify `--package` when building from a file"
@@ -259,26 +310,40 @@ async fn build_impl(
             }
         };
 
-        let package = workspace
-            .packages()
-            .get(package)
-            .ok_or_else(|| anyhow::anyhow!("Package `{package}` not found in workspace"))?;
+        let mut members = Vec::with_capacity(package.len());
+        for package in package {
+            let member = workspace
+                .packages()
+                .get(package)
+                .ok_or_else(|| anyhow::anyhow!("Package `{package}` not found in workspace"))?;
 
-        if !package.pyproject_toml().is_package(true) {
-            let name = &package.project().name;
-            let pyproject_toml = package.root().join("pyproject.toml");
-            return Err(anyhow::anyhow!(
-                "Package `{}` is missing a `{}`. For example, to build with `{}`, add the following to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
-                name.cyan(),
-                "build-system".green(),
-                "setuptools".cyan(),
-                pyproject_toml.user_display().cyan()
-            ));
+            if !member.pyproject_toml().is_package(true) {
+                let name = &member.project().name;
+                let pyproject_toml = member.root().join("pyproject.toml");
+                return Err(anyhow::anyhow!(
+                    "Package `{}` is missing a `{}`. For example, to build with `{}`, add the following to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
+                    name.cyan(),
+                    "build-system".green(),
+                    "setuptools".cyan(),
+                    pyproject_toml.user_display().cyan()
+                ));
+            }
+
+            if !members
+                .iter()
+                .any(|existing: &&WorkspaceMember| existing.project().name == *package)
+            {
+                members.push(member);
+            }
         }
 
-        vec![AnnotatedSource::from(Source::Directory(Cow::Borrowed(
-            package.root(),
-        )))]
+        if let [member] = members.as_slice() {
+            vec![vec![AnnotatedSource::from(Source::Directory(
+                Cow::Borrowed(member.root()),
+            ))]]
+        } else {
+            dependency_layers(members)
+        }
     } else if all_packages {
         if matches!(src, Source::File(_)) {
             return Err(anyhow::anyhow!(
@@ -298,17 +363,13 @@ async fn build_impl(
             return Err(anyhow::anyhow!("No packages found in workspace"));
         }
 
-        let packages: Vec<_> = workspace
+        let members: Vec<_> = workspace
             .packages()
             .values()
             .filter(|package| package.pyproject_toml().is_package(true))
-            .map(|package| AnnotatedSource {
-                source: Source::Directory(Cow::Borrowed(package.root())),
-                package: Some(package.project().name.clone()),
-            })
             .collect();
 
-        if packages.is_empty() {
+        if members.is_empty() {
             let member = workspace.packages().values().next().unwrap();
             let name = &member.project().name;
             let pyproject_toml = member.root().join("pyproject.toml");
@@ -321,16 +382,20 @@ async fn build_impl(
             ));
         }
 
-        packages
+        dependency_layers(members)
     } else {
-        vec![AnnotatedSource::from(src)]
+        vec![vec![AnnotatedSource::from(src)]]
     };
 
-    let results: Vec<_> = futures::future::join_all(packages.into_iter().map(|source| {
-        let future = build_package(
-            source.clone(),
+    // Share the resolved build requirements between the packages.
+    let source_build_contexts = RefCell::default();
+
+    let build = |source, output_dir| {
+        build_package(
+            source,
             output_dir,
             python_request,
+            python_platform,
             install_mirrors.clone(),
             no_config,
             workspace.as_ref(),
@@ -343,6 +408,7 @@ async fn build_impl(
             hash_checking,
             build_logs,
             force_pep517,
+            from_sdist,
             build_constraints,
             *no_build_isolation,
             no_build_isolation_package,
@@ -359,21 +425,63 @@ async fn build_impl(
             *link_mode,
             config_setting,
             config_settings_package,
+            &source_build_contexts,
             preview,
+        )
+    };
+
+    // The second builds for `--reproducible-check` go to a temporary directory.
+    let rebuild_dir = if reproducible_check {
+        Some(tempfile::tempdir()?)
+    } else {
+        None
+    };
+
+    // Build the packages concurrently, but each package only after the workspace members it
+    // depends on.
+    let mut results = Vec::new();
+    for layer in layers {
+        results.extend(
+            futures::future::join_all(layer.into_iter().map(|source| {
+                let future = build(source.clone(), output_dir);
+                async {
+                    let result = future.await;
+                    (source, result)
+                }
+            }))
+            .await,
         );
-        async {
-            let result = future.await;
-            (source, result)
-        }
-    }))
-    .await;
+    }
+
+    let results = if let Some(rebuild_dir) = &rebuild_dir {
+        futures::future::join_all(results.into_iter().map(|(source, result)| {
+            let future = build(source.clone(), Some(rebuild_dir.path()));
+            async move {
+                let result = match result {
+                    Ok(messages) => match future.await {
+                        Ok(rebuild) => check_reproducible(messages, &rebuild),
+                        Err(err) => Err(err),
+                    },
+                    Err(err) => Err(err),
+                };
+                (source, result)
+            }
+        }))
+        .await
+    } else {
+        results
+    };
 
     let mut success = true;
+    let mut artifacts = Vec::new();
     for (source, result) in results {
         match result {
             Ok(messages) => {
                 for message in messages {
                     message.print(printer)?;
+                    if matches!(output_format, BuildFormat::Json) {
+                        artifacts.extend(ArtifactReport::from_message(&message)?);
+                    }
                 }
             }
             Err(err) => {
@@ -397,6 +505,18 @@ async fn build_impl(
         }
     }
 
+    if matches!(output_format, BuildFormat::Json) {
+        let report = BuildReport {
+            schema: SchemaReport::default(),
+            artifacts,
+        };
+        writeln!(
+            printer.stdout_important(),
+            "{}",
+            serde_json::to_string_pretty(&report)?
+        )?;
+    }
+
     if success {
         Ok(BuildResult::Success)
     } else {
@@ -404,11 +524,207 @@ async fn build_impl(
     }
 }
 
+/// Group the workspace members into layers that are built one after the other, such that each
+/// member is built after the members it depends on, including through its build requirements.
+///
+/// The members in a layer don't depend on each other and are built concurrently.
+fn dependency_layers(members: Vec<&WorkspaceMember>) -> Vec<Vec<AnnotatedSource<'_>>> {
+    let names: FxHashSet<&PackageName> = members
+        .iter()
+        .map(|member| &member.project().name)
+        .collect();
+    let mut remaining: Vec<(&WorkspaceMember, BTreeSet<PackageName>)> = members
+        .iter()
+        .map(|member| {
+            let dependencies = member_dependencies(member)
+                .into_iter()
+                .filter(|name| *name != member.project().name && names.contains(name))
+                .collect();
+            (*member, dependencies)
+        })
+        .collect();
+
+    let mut layers = Vec::new();
+    while !remaining.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) = remaining.iter().partition(|(_, dependencies)| {
+            dependencies.iter().all(|dependency| {
+                !remaining
+                    .iter()
+                    .any(|(member, _)| member.project().name == *dependency)
+            })
+        });
+        // With a dependency cycle, build the remaining members together.
+        let (layer, rest) = if ready.is_empty() {
+            debug!("Found a dependency cycle between workspace members, building them together");
+            (blocked, Vec::new())
+        } else {
+            (ready, blocked)
+        };
+        layers.push(
+            layer
+                .iter()
+                .map(|(member, _)| AnnotatedSource {
+                    source: Source::Directory(Cow::Borrowed(member.root())),
+                    package: Some(member.project().name.clone()),
+                })
+                .collect(),
+        );
+        remaining = rest.into_iter().cloned().collect();
+    }
+    layers
+}
+
+/// The names of the dependencies, optional dependencies and build requirements of a workspace
+/// member.
+fn member_dependencies(member: &WorkspaceMember) -> BTreeSet<PackageName> {
+    let project = member.project();
+    let build_requires = toml::from_str::<toml::Table>(&member.pyproject_toml().raw)
+        .ok()
+        .and_then(|table| {
+            let requires = table.get("build-system")?.get("requires")?.as_array()?;
+            Some(
+                requires
+                    .iter()
+                    .filter_map(|requirement| requirement.as_str().map(ToString::to_string))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .unwrap_or_default();
+
+    project
+        .dependencies
+        .iter()
+        .flatten()
+        .chain(
+            project
+                .optional_dependencies
+                .iter()
+                .flat_map(|extras| extras.values().flatten()),
+        )
+        .chain(&build_requires)
+        .filter_map(|requirement| {
+            uv_pep508::Requirement::<VerbatimUrl>::from_str(requirement)
+                .ok()
+                .map(|requirement| requirement.name)
+        })
+        .collect()
+}
+
+/// Compare the distributions of two builds, adding the digest of each reproducible distribution
+/// to the messages.
+fn check_reproducible(
+    messages: Vec<BuildMessage>,
+    rebuild: &[BuildMessage],
+) -> Result<Vec<BuildMessage>, Error> {
+    let mut checked = Vec::with_capacity(messages.len() * 2);
+    for message in messages {
+        let BuildMessage::Build {
+            normalized_filename,
+            raw_filename,
+            output_dir,
+            ..
+        } = &message
+        else {
+            checked.push(message);
+            continue;
+        };
+        let Some(BuildMessage::Build {
+            output_dir: rebuild_dir,
+            ..
+        }) = rebuild
+            .iter()
+            .find(|rebuild| rebuild.raw_filename() == raw_filename)
+        else {
+            return Err(Error::NotReproducibleMissing(raw_filename.clone()));
+        };
+        let digest = sha256(&output_dir.join(raw_filename))?;
+        let rebuild_digest = sha256(&rebuild_dir.join(raw_filename))?;
+        if digest != rebuild_digest {
+            return Err(Error::NotReproducible(
+                raw_filename.clone(),
+                digest,
+                rebuild_digest,
+            ));
+        }
+        let reproducible = BuildMessage::Reproducible {
+            normalized_filename: normalized_filename.clone(),
+            raw_filename: raw_filename.clone(),
+            digest,
+        };
+        checked.push(message);
+        checked.push(reproducible);
+    }
+    Ok(checked)
+}
+
+/// Check that the wheel built from the source distribution contains all files of the wheel built
+/// from the source tree, which would otherwise be missing for users building from the source
+/// distribution.
+fn check_from_sdist(
+    wheel_build: &BuildMessage,
+    tree_build: &BuildMessage,
+) -> Result<BuildMessage, Error> {
+    let (
+        BuildMessage::Build {
+            normalized_filename,
+            raw_filename,
+            output_dir,
+            ..
+        },
+        BuildMessage::Build {
+            raw_filename: tree_filename,
+            output_dir: tree_dir,
+            ..
+        },
+    ) = (wheel_build, tree_build)
+    else {
+        unreachable!("Checking the source distribution requires building the wheels");
+    };
+    let files = wheel_files(&output_dir.join(raw_filename))?;
+    let tree_files = wheel_files(&tree_dir.join(tree_filename))?;
+    let missing: Vec<String> = tree_files.difference(&files).cloned().collect();
+    if !missing.is_empty() {
+        return Err(Error::MissingFromSdist(raw_filename.clone(), missing));
+    }
+    Ok(BuildMessage::FromSdist {
+        normalized_filename: normalized_filename.clone(),
+        raw_filename: raw_filename.clone(),
+        files: files.len(),
+    })
+}
+
+/// The files in a wheel, excluding directory entries.
+fn wheel_files(path: &Path) -> Result<BTreeSet<String>, Error> {
+    let archive = zip::ZipArchive::new(fs_err::File::open(path)?)
+        .map_err(|err| Error::ReadWheel(path.to_path_buf(), err))?;
+    Ok(archive
+        .file_names()
+        .filter(|name| !name.ends_with('/'))
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Compute the SHA-256 digest of a built distribution.
+fn sha256(path: &Path) -> Result<HashDigest, Error> {
+    let mut reader = fs_err::File::open(path)?;
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(HashDigest::from(hasher))
+}
+
 #[allow(clippy::fn_params_excessive_bools)]
 async fn build_package(
     source: AnnotatedSource<'_>,
     output_dir: Option<&Path>,
     python_request: Option<&str>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     no_config: bool,
     workspace: Result<&Workspace, &WorkspaceError>,
@@ -421,9 +737,10 @@ async fn build_package(
     hash_checking: Option<HashCheckingMode>,
     build_logs: bool,
     force_pep517: bool,
+    from_sdist: bool,
     build_constraints: &[RequirementsSource],
     no_build_isolation: bool,
-    no_build_isolation_package: &[PackageName],
+    no_build_isolation_package: &NoBuildIsolationPackages,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
     exclude_newer: Option<ExcludeNewer>,
@@ -437,6 +754,7 @@ async fn build_package(
     link_mode: LinkMode,
     config_setting: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
+    source_build_contexts: &RefCell<FxHashMap<PathBuf, SourceBuildContext>>,
     preview: PreviewMode,
 ) -> Result<Vec<BuildMessage>, Error> {
     let output_dir = if let Some(output_dir) = output_dir {
@@ -581,12 +899,24 @@ async fn build_package(
         workspace_cache,
         concurrency,
         preview,
+    )
+    .with_source_build_context(
+        source_build_contexts
+            .borrow_mut()
+            .entry(interpreter.sys_executable().to_path_buf())
+            .or_default()
+            .clone(),
+    )
+    .with_build_extra_env_vars(
+        python_platform
+            .map(cross_build_env_vars)
+            .unwrap_or_default(),
     );
 
     prepare_output_directory(&output_dir).await?;
 
     // Determine the build plan.
-    let plan = BuildPlan::determine(&source, sdist, wheel).map_err(Error::BuildPlan)?;
+    let plan = BuildPlan::determine(&source, sdist, wheel, from_sdist).map_err(Error::BuildPlan)?;
 
     // Check if the build backend is matching uv version that allows calling in the uv build backend
     // directly.
@@ -694,7 +1024,34 @@ async fn build_package(
                 Some(sdist_build.normalized_filename().version()),
             )
             .await?;
+
+            // Build a second wheel from the source tree, and check that the wheel from the source
+            // distribution contains all of its files.
+            let checked = if from_sdist {
+                let tree_dir = tempfile::tempdir()?;
+                let tree_build = build_wheel(
+                    source.path(),
+                    tree_dir.path(),
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel from source tree",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                    Some(sdist_build.normalized_filename().version()),
+                )
+                .await?;
+                Some(check_from_sdist(&wheel_build, &tree_build)?)
+            } else {
+                None
+            };
+
             build_results.push(wheel_build);
+            build_results.extend(checked);
         }
         BuildPlan::Sdist => {
             let sdist_build = build_sdist(
@@ -816,6 +1173,34 @@ async fn build_package(
     Ok(build_results)
 }
 
+/// The environment variables that configure the build backend to build for the target platform.
+///
+/// The build requirements are still installed for the current platform, as they run during the
+/// build.
+fn cross_build_env_vars(python_platform: TargetTriple) -> Vec<(&'static str, String)> {
+    let mut env_vars = vec![
+        (
+            EnvVars::_PYTHON_HOST_PLATFORM,
+            python_platform.host_platform(),
+        ),
+        (
+            EnvVars::CARGO_BUILD_TARGET,
+            python_platform.rust_target().to_string(),
+        ),
+    ];
+    if let Os::Macos { major, minor } = python_platform.platform().os() {
+        env_vars.push((
+            EnvVars::ARCHFLAGS,
+            format!("-arch {}", python_platform.platform_machine()),
+        ));
+        env_vars.push((
+            EnvVars::MACOSX_DEPLOYMENT_TARGET,
+            format!("{major}.{minor}"),
+        ));
+    }
+    env_vars
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 enum BuildAction {
     /// Only list the files that would be included, don't actually build.
@@ -900,6 +1285,7 @@ async fn build_sdist(
                 ),
                 raw_filename: filename,
                 output_dir: output_dir.to_path_buf(),
+                backend: BackendReport::uv(),
             }
         }
         BuildAction::Pep517 => {
@@ -935,6 +1321,7 @@ async fn build_sdist(
                 ),
                 raw_filename: filename,
                 output_dir: output_dir.to_path_buf(),
+                backend: BackendReport::from_source_build(&builder),
             }
         }
     };
@@ -1003,6 +1390,7 @@ async fn build_wheel(
                 normalized_filename: DistFilename::WheelFilename(filename),
                 raw_filename,
                 output_dir: output_dir.to_path_buf(),
+                backend: BackendReport::uv(),
             }
         }
         BuildAction::Pep517 => {
@@ -1037,6 +1425,7 @@ async fn build_wheel(
                 ),
                 raw_filename: filename,
                 output_dir: output_dir.to_path_buf(),
+                backend: BackendReport::from_source_build(&builder),
             }
         }
     };
@@ -1148,6 +1537,8 @@ enum BuildMessage {
         raw_filename: String,
         /// The location of the built distribution.
         output_dir: PathBuf,
+        /// The build backend that built the distribution.
+        backend: BackendReport,
     },
     /// Show the list of files that would be included in a distribution.
     List {
@@ -1160,6 +1551,25 @@ enum BuildMessage {
         // Included file and source file, if not generated.
         file_list: Vec<(String, Option<PathBuf>)>,
     },
+    /// A distribution that was identical when built a second time.
+    Reproducible {
+        /// The normalized name of the built distribution.
+        normalized_filename: DistFilename,
+        /// The name of the built distribution before parsing and normalization.
+        raw_filename: String,
+        /// The digest of both builds.
+        digest: HashDigest,
+    },
+    /// A wheel built from the source distribution that contains all files of the wheel built
+    /// from the source tree.
+    FromSdist {
+        /// The normalized name of the built wheel.
+        normalized_filename: DistFilename,
+        /// The name of the built wheel before parsing and normalization.
+        raw_filename: String,
+        /// The number of files in the wheel.
+        files: usize,
+    },
 }
 
 impl BuildMessage {
@@ -1174,6 +1584,14 @@ impl BuildMessage {
                 normalized_filename: name,
                 ..
             } => name,
+            BuildMessage::Reproducible {
+                normalized_filename: name,
+                ..
+            } => name,
+            BuildMessage::FromSdist {
+                normalized_filename: name,
+                ..
+            } => name,
         }
     }
 
@@ -1186,6 +1604,12 @@ impl BuildMessage {
             BuildMessage::List {
                 raw_filename: name, ..
             } => name,
+            BuildMessage::Reproducible {
+                raw_filename: name, ..
+            } => name,
+            BuildMessage::FromSdist {
+                raw_filename: name, ..
+            } => name,
         }
     }
 
@@ -1227,11 +1651,174 @@ impl BuildMessage {
                     }
                 }
             }
+            BuildMessage::Reproducible {
+                raw_filename,
+                digest,
+                ..
+            } => {
+                writeln!(
+                    printer.stderr(),
+                    "Reproduced {} ({})",
+                    raw_filename.bold().cyan(),
+                    digest.to_string().dimmed()
+                )?;
+            }
+            BuildMessage::FromSdist {
+                raw_filename,
+                files,
+                ..
+            } => {
+                writeln!(
+                    printer.stderr(),
+                    "Checked {} against the source tree ({})",
+                    raw_filename.bold().cyan(),
+                    format!("{files} files").dimmed()
+                )?;
+            }
         }
         Ok(())
     }
 }
 
+/// A report of the built distributions, for `--output-format json`.
+#[derive(Debug, Serialize)]
+struct BuildReport {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The built source distributions and wheels.
+    artifacts: Vec<ArtifactReport>,
+}
+
+/// A built source distribution or wheel.
+#[derive(Debug, Serialize)]
+struct ArtifactReport {
+    /// The absolute path to the built distribution.
+    path: PathBuf,
+    /// The filename of the built distribution.
+    filename: String,
+    /// Whether the distribution is a source distribution or a wheel.
+    kind: ArtifactKind,
+    /// The name of the package.
+    name: PackageName,
+    /// The version of the package.
+    version: Version,
+    /// The compatibility tags of a wheel, e.g., `py3-none-any`, empty for source distributions.
+    tags: Vec<String>,
+    /// The size of the built distribution in bytes.
+    size: u64,
+    /// The SHA-256 digest of the built distribution.
+    sha256: String,
+    /// The build backend that built the distribution.
+    backend: BackendReport,
+}
+
+impl ArtifactReport {
+    /// Create a report for a built distribution, or `None` for other messages.
+    fn from_message(message: &BuildMessage) -> Result<Option<Self>, Error> {
+        let BuildMessage::Build {
+            normalized_filename,
+            raw_filename,
+            output_dir,
+            backend,
+        } = message
+        else {
+            return Ok(None);
+        };
+        let path = std::path::absolute(output_dir.join(raw_filename))?;
+        let (kind, tags) = match normalized_filename {
+            DistFilename::SourceDistFilename(_) => (ArtifactKind::Sdist, Vec::new()),
+            DistFilename::WheelFilename(filename) => {
+                let tags = filename
+                    .python_tags()
+                    .iter()
+                    .flat_map(|python| {
+                        filename.abi_tags().iter().flat_map(move |abi| {
+                            filename
+                                .platform_tags()
+                                .iter()
+                                .map(move |platform| format!("{python}-{abi}-{platform}"))
+                        })
+                    })
+                    .collect();
+                (ArtifactKind::Wheel, tags)
+            }
+        };
+        Ok(Some(Self {
+            size: fs_err::metadata(&path)?.len(),
+            sha256: sha256(&path)?.digest.to_string(),
+            path,
+            filename: raw_filename.clone(),
+            kind,
+            name: normalized_filename.name().clone(),
+            version: normalized_filename.version().clone(),
+            tags,
+            backend: backend.clone(),
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ArtifactKind {
+    Sdist,
+    Wheel,
+}
+
+/// The build backend that built a distribution.
+#[derive(Debug, Clone, Serialize)]
+struct BackendReport {
+    /// The PEP 517 build backend, e.g., `hatchling.build`.
+    backend: String,
+    /// The name of the distribution providing the build backend, if known.
+    name: Option<PackageName>,
+    /// The version of the distribution providing the build backend, if known.
+    version: Option<Version>,
+    /// The build constraints that applied to the packages in the isolated build environment.
+    build_constraints: Vec<BuildConstraintReport>,
+}
+
+/// A build constraint that applied to a package in the build environment.
+#[derive(Debug, Clone, Serialize)]
+struct BuildConstraintReport {
+    /// The build constraint, e.g., `setuptools<70`.
+    constraint: String,
+    /// The name of the constrained package.
+    name: PackageName,
+    /// The version of the constrained package in the build environment.
+    version: Option<Version>,
+}
+
+impl BackendReport {
+    /// The uv build backend, when building directly without PEP 517.
+    fn uv() -> Self {
+        Self {
+            backend: "uv_build".to_string(),
+            name: Some(PackageName::from_str("uv-build").expect("valid package name")),
+            version: Version::from_str(uv_version::version()).ok(),
+            build_constraints: Vec::new(),
+        }
+    }
+
+    /// The build backend of a PEP 517 build.
+    fn from_source_build(builder: &SourceBuild) -> Self {
+        let (name, version) = builder.backend_distribution().unzip();
+        Self {
+            backend: builder.backend().to_string(),
+            name,
+            version,
+            build_constraints: builder
+                .applied_build_constraints()
+                .iter()
+                .map(|applied| BuildConstraintReport {
+                    constraint: applied.constraint.to_string(),
+                    name: applied.constraint.name.clone(),
+                    version: applied.version.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum BuildPlan {
     /// Build a source distribution from source, then build the wheel from the source distribution.
@@ -1251,8 +1838,18 @@ enum BuildPlan {
 }
 
 impl BuildPlan {
-    fn determine(source: &AnnotatedSource, sdist: bool, wheel: bool) -> Result<Self> {
+    fn determine(
+        source: &AnnotatedSource,
+        sdist: bool,
+        wheel: bool,
+        from_sdist: bool,
+    ) -> Result<Self> {
         Ok(match &source.source {
+            Source::File(_) if from_sdist => {
+                return Err(anyhow::anyhow!(
+                    "`--from-sdist` requires a source directory, not a source distribution"
+                ));
+            }
             Source::File(_) => {
                 // We're building from a file, which must be a source distribution.
                 match (sdist, wheel) {
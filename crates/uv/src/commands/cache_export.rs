@@ -0,0 +1,89 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_resolver::Lock;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Export cache entries to an archive.
+///
+/// If a lockfile is provided, only the entries for the packages in the lockfile are exported.
+pub(crate) fn cache_export(
+    output: &Path,
+    lockfile: Option<&Path>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Collect the packages in the lockfile, if any.
+    let packages = if let Some(lockfile) = lockfile {
+        let encoded = fs_err::read_to_string(lockfile)?;
+        let lock = toml::from_str::<Lock>(&encoded)
+            .with_context(|| format!("Failed to parse lockfile at: {}", lockfile.user_display()))?;
+        Some(
+            lock.packages()
+                .iter()
+                .map(|package| package.name().clone())
+                .collect::<FxHashSet<_>>(),
+        )
+    } else {
+        None
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Exporting cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    // Write to a temporary file, then move it into place, to avoid leaving a partial archive.
+    let parent = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let temp_file = tempfile::NamedTempFile::new_in(parent)?;
+    let summary = cache
+        .export(
+            packages.as_ref(),
+            std::io::BufWriter::new(temp_file.as_file()),
+        )
+        .with_context(|| format!("Failed to export cache at: {}", cache.root().user_display()))?;
+    temp_file
+        .persist(output)
+        .with_context(|| format!("Failed to write archive to: {}", output.user_display()))?;
+
+    let bytes = if summary.total_bytes < 1024 {
+        format!("{}B", summary.total_bytes)
+    } else {
+        let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+        format!("{bytes:.1}{unit}")
+    };
+    let files = if summary.num_files == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{} files", summary.num_files)
+    };
+    writeln!(
+        printer.stderr(),
+        "Exported {files} ({}) to: {}",
+        bytes.green(),
+        output.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,53 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Import cache entries from an archive created by `uv cache export`.
+pub(crate) fn cache_import(input: &Path, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    writeln!(
+        printer.stderr(),
+        "Importing cache from: {}",
+        input.user_display().cyan()
+    )?;
+
+    let reader = std::io::BufReader::new(fs_err::File::open(input)?);
+    let summary = cache
+        .import(reader)
+        .with_context(|| format!("Failed to import cache from: {}", input.user_display()))?;
+
+    if summary.num_files == 0 {
+        writeln!(printer.stderr(), "Cache is already up-to-date")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let bytes = if summary.total_bytes < 1024 {
+        format!("{}B", summary.total_bytes)
+    } else {
+        let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+        format!("{bytes:.1}{unit}")
+    };
+    let files = if summary.num_files == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{} files", summary.num_files)
+    };
+    write!(printer.stderr(), "Imported {files} ({})", bytes.green())?;
+    if summary.num_skipped > 0 {
+        write!(
+            printer.stderr(),
+            ", skipped {} existing",
+            summary.num_skipped
+        )?;
+    }
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,103 @@
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_settings::FilesystemOptions;
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Move the cache to another directory, updating the configuration to match.
+pub(crate) fn cache_move(
+    target: &Path,
+    config_file: Option<&Path>,
+    cache_dir_override: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if cache.is_temporary() {
+        bail!("The cache can't be moved when `--no-cache` is provided");
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Moving cache from {} to {}",
+        cache.root().user_display().cyan(),
+        target.user_display().cyan()
+    )?;
+
+    let (relocated, summary) = cache
+        .relocate(target)
+        .with_context(|| format!("Failed to move cache to: {}", target.user_display()))?;
+
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        writeln!(
+            printer.stderr(),
+            "Removed cached environments ({})",
+            bytes.green()
+        )?;
+    }
+
+    // Point the configuration at the new cache directory.
+    let Some(config_file) = config_file
+        .map(Path::to_path_buf)
+        .or_else(FilesystemOptions::user_file)
+    else {
+        warn_user!(
+            "Unable to determine the user configuration directory; set `cache-dir` to `{}` to use the moved cache",
+            relocated.root().simplified_display()
+        );
+        return Ok(ExitStatus::Success);
+    };
+    update_cache_dir(&config_file, relocated.root()).with_context(|| {
+        format!(
+            "Failed to update `cache-dir` in: {}",
+            config_file.user_display()
+        )
+    })?;
+    writeln!(
+        printer.stderr(),
+        "Updated `{}` in: {}",
+        "cache-dir".green(),
+        config_file.user_display().cyan()
+    )?;
+
+    if cache_dir_override {
+        warn_user!(
+            "The cache directory was provided via `--cache-dir` or `UV_CACHE_DIR`, which takes precedence over the configuration file; update it to `{}` to use the moved cache",
+            relocated.root().simplified_display()
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Set the `cache-dir` in the given `uv.toml`, creating the file if it doesn't exist and
+/// preserving any other settings.
+fn update_cache_dir(config_file: &Path, cache_dir: &Path) -> Result<()> {
+    let contents = match fs_err::read_to_string(config_file) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+    let mut document = contents.parse::<toml_edit::DocumentMut>()?;
+    document["cache-dir"] = toml_edit::value(cache_dir.simplified_display().to_string());
+
+    if let Some(parent) = config_file.parent() {
+        fs_err::create_dir_all(parent)?;
+    }
+    fs_err::write(config_file, document.to_string())?;
+    Ok(())
+}
@@ -3,14 +3,27 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, PruneFilter, Pruner, Removal};
+use uv_configuration::ByteSize;
 use uv_fs::Simplified;
+use uv_warnings::warn_user;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+///
+/// Any entries matching the [`PruneFilter`] are removed, even if they're still reachable. If a
+/// maximum size is provided (or configured via `cache-max-size`), evict the least-recently-used
+/// entries until the cache is under that size.
+pub(crate) fn cache_prune(
+    ci: bool,
+    max_size: Option<ByteSize>,
+    filter: &PruneFilter,
+    dry_run: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -26,48 +39,96 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
         cache.root().user_display().cyan()
     )?;
 
+    let mut pruner = if dry_run {
+        Pruner::dry_run()
+    } else {
+        Pruner::default()
+    };
     let mut summary = Removal::default();
 
     // Prune the source distribution cache, which is tightly coupled to the builder crate.
-    summary += uv_distribution::prune(cache)
+    summary += uv_distribution::prune(cache, &mut pruner)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
     // Prune the remaining cache buckets.
     summary += cache
-        .prune(ci)
+        .prune(ci, &mut pruner)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Remove any entries that match the filters, even if they're still in use.
+    summary += cache
+        .prune_matching(filter, &mut pruner)
+        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+
+    // Evict entries until the cache is under the maximum size.
+    let max_size = max_size.map(ByteSize::bytes).or(cache.max_size());
+    if let Some(max_size) = max_size {
+        summary += cache.evict(max_size, &mut pruner).with_context(|| {
+            format!(
+                "Failed to evict entries from cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+    }
+
     // Write a summary of the number of files and directories removed.
+    let verb = if dry_run { "Would remove" } else { "Removed" };
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
             write!(printer.stderr(), "No unused entries found")?;
         }
         (0, 1) => {
-            write!(printer.stderr(), "Removed 1 directory")?;
+            write!(printer.stderr(), "{verb} 1 directory")?;
         }
         (0, num_dirs_removed) => {
-            write!(printer.stderr(), "Removed {num_dirs_removed} directories")?;
+            write!(printer.stderr(), "{verb} {num_dirs_removed} directories")?;
         }
         (1, _) => {
-            write!(printer.stderr(), "Removed 1 file")?;
+            write!(printer.stderr(), "{verb} 1 file")?;
         }
         (num_files_removed, _) => {
-            write!(printer.stderr(), "Removed {num_files_removed} files")?;
+            write!(printer.stderr(), "{verb} {num_files_removed} files")?;
         }
     }
 
     // If any, write a summary of the total byte count removed.
     if summary.total_bytes > 0 {
-        let bytes = if summary.total_bytes < 1024 {
-            format!("{}B", summary.total_bytes)
-        } else {
-            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
-            format!("{bytes:.1}{unit}")
-        };
-        write!(printer.stderr(), " ({})", bytes.green())?;
+        write!(
+            printer.stderr(),
+            " ({})",
+            format_bytes(summary.total_bytes).green()
+        )?;
     }
 
     writeln!(printer.stderr())?;
 
+    // Some entries (e.g., cached index metadata) are never evicted, so the cache can remain above
+    // the maximum size.
+    if let Some(max_size) = max_size {
+        let size = cache.size().with_context(|| {
+            format!(
+                "Failed to compute size of cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+        if size > max_size {
+            warn_user!(
+                "The cache ({}) still exceeds the maximum size ({}), as the remaining entries can't be evicted",
+                format_bytes(size),
+                format_bytes(max_size)
+            );
+        }
+    }
+
     Ok(ExitStatus::Success)
 }
+
+/// Format a byte count for display (e.g., `512B` or `1.5MiB`).
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::{Cache, rm_rf};
+use uv_client::DataWithCachePolicy;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Mark the cached index metadata for the given packages as requiring revalidation.
+pub(crate) fn cache_refresh(
+    packages: &[PackageName],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut refreshed = 0;
+    for package in packages {
+        for entry in cache.simple_entries(package)? {
+            match DataWithCachePolicy::require_revalidation(&entry) {
+                Ok(()) => {
+                    debug!("Marked cache entry for revalidation: {}", entry.display());
+                    refreshed += 1;
+                }
+                Err(err) => {
+                    // An unreadable entry would be replaced on the next request anyway, so remove
+                    // it outright.
+                    debug!(
+                        "Removing unreadable cache entry `{}`: {err}",
+                        entry.display()
+                    );
+                    rm_rf(&entry)?;
+                    refreshed += 1;
+                }
+            }
+        }
+
+        // Forget that the package was missing from any index, such that it's requested again.
+        for entry in cache.simple_not_found_entries(package)? {
+            debug!("Removing not-found cache entry: {}", entry.display());
+            rm_rf(&entry)?;
+            refreshed += 1;
+        }
+    }
+
+    match refreshed {
+        0 => writeln!(printer.stderr(), "No cache entries found")?,
+        1 => writeln!(
+            printer.stderr(),
+            "Marked {} for revalidation",
+            "1 cache entry".bold()
+        )?,
+        _ => writeln!(
+            printer.stderr(),
+            "Marked {} for revalidation",
+            format!("{refreshed} cache entries").bold()
+        )?,
+    }
+
+    Ok(ExitStatus::Success)
+}
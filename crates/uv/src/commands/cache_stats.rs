@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_cli::CacheStatsFormat;
+use uv_fs::Simplified;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Show statistics on the contents and usage of the cache.
+pub(crate) fn cache_stats(
+    output_format: CacheStatsFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let stats = cache
+        .stats()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    match output_format {
+        CacheStatsFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&stats)?)?;
+        }
+        CacheStatsFormat::Text => {
+            writeln!(
+                printer.stdout(),
+                "{}: {} ({})",
+                "Cache".bold(),
+                cache.root().user_display().cyan(),
+                format_bytes(stats.total_bytes)
+            )?;
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}", "Buckets:".bold())?;
+            for bucket in &stats.buckets {
+                if bucket.files == 0 {
+                    continue;
+                }
+                writeln!(
+                    printer.stdout(),
+                    "  {:<16} {:>10}  {} {}",
+                    bucket.name,
+                    format_bytes(bucket.bytes),
+                    bucket.files,
+                    if bucket.files == 1 { "file" } else { "files" }
+                )?;
+            }
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}", "Recent runs:".bold())?;
+            match stats.runs.hit_rate {
+                Some(hit_rate) => writeln!(
+                    printer.stdout(),
+                    "  {} {}, {} {}, {} {} ({:.1}% hit rate)",
+                    stats.runs.count,
+                    if stats.runs.count == 1 { "run" } else { "runs" },
+                    stats.runs.hits,
+                    if stats.runs.hits == 1 { "hit" } else { "hits" },
+                    stats.runs.misses,
+                    if stats.runs.misses == 1 {
+                        "miss"
+                    } else {
+                        "misses"
+                    },
+                    hit_rate * 100.0
+                )?,
+                None => writeln!(printer.stdout(), "  No runs recorded")?,
+            }
+
+            if !stats.largest_packages.is_empty() {
+                writeln!(printer.stdout())?;
+                writeln!(printer.stdout(), "{}", "Largest packages:".bold())?;
+                for package in &stats.largest_packages {
+                    writeln!(
+                        printer.stdout(),
+                        "  {:<24} {:>10}  {} {}",
+                        package.name.as_str(),
+                        format_bytes(package.bytes),
+                        package.entries,
+                        if package.entries == 1 {
+                            "entry"
+                        } else {
+                            "entries"
+                        }
+                    )?;
+                }
+            }
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}", "Last used:".bold())?;
+            for age in &stats.last_used {
+                writeln!(
+                    printer.stdout(),
+                    "  {:<8} {:>10}  {} {}",
+                    age.label,
+                    format_bytes(age.bytes),
+                    age.entries,
+                    if age.entries == 1 { "entry" } else { "entries" }
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format a byte count for display.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
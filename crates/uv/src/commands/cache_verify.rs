@@ -0,0 +1,123 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheBucket, Removal, rm_rf};
+use uv_fs::Simplified;
+
+use crate::commands::store_gc::{archives, verify_archive};
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, optionally removing any corrupted entries.
+pub(crate) fn cache_verify(repair: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let mut summary = Removal::default();
+
+    // Verify the unpacked wheels against their `RECORD` files.
+    let store = archives(&cache.bucket(CacheBucket::Archive))?;
+    let mut verified = store.len();
+    let mut corrupted = 0;
+    for archive in &store {
+        let Some((name, reason)) = verify_archive(archive)? else {
+            continue;
+        };
+        corrupted += 1;
+        if repair {
+            writeln!(
+                printer.stderr(),
+                "Removing corrupted wheel `{}` ({reason})",
+                name.cyan()
+            )?;
+            summary += rm_rf(archive)?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Found corrupted wheel `{}` ({reason})",
+                name.cyan()
+            )?;
+        }
+    }
+
+    // Verify the pointers, metadata, and built wheels in the wheel and source distribution
+    // buckets, which are tightly coupled to the distribution crate.
+    let verification = uv_distribution::verify(cache)
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+    verified += verification.verified;
+    for entry in &verification.corrupted {
+        corrupted += 1;
+        if repair {
+            writeln!(
+                printer.stderr(),
+                "Removing corrupted entry ({}): {}",
+                entry.reason,
+                entry.path.user_display().cyan()
+            )?;
+            for path in &entry.remove {
+                summary += rm_rf(path)?;
+            }
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Found corrupted entry ({}): {}",
+                entry.reason,
+                entry.path.user_display().cyan()
+            )?;
+        }
+    }
+
+    let verified = verified - corrupted;
+    write!(
+        printer.stderr(),
+        "Verified {verified} {}",
+        if verified == 1 { "entry" } else { "entries" }
+    )?;
+    match (corrupted, repair) {
+        (0, _) => write!(printer.stderr(), ", no corrupted entries found")?,
+        (1, true) => write!(printer.stderr(), ", removed 1 corrupted entry")?,
+        (corrupted, true) => write!(printer.stderr(), ", removed {corrupted} corrupted entries")?,
+        (1, false) => write!(printer.stderr(), ", found 1 corrupted entry")?,
+        (corrupted, false) => write!(printer.stderr(), ", found {corrupted} corrupted entries")?,
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    if corrupted > 0 && !repair {
+        writeln!(
+            printer.stderr(),
+            "{}{} Run `{}` to remove the corrupted entries",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "uv cache verify --repair".green()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
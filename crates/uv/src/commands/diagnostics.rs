@@ -120,6 +120,10 @@ impl OperationDiagnostic {
                 dist_error(kind, dist, &chain, Arc::new(err), self.hint);
                 None
             }
+            pip::operations::Error::Prepare(err @ uv_installer::PrepareError::Offline(_)) => {
+                offline_error(err);
+                None
+            }
             pip::operations::Error::Requirements(err) => {
                 if let Some(context) = self.context {
                     let err = miette::Report::msg(format!("{err}"))
@@ -359,6 +363,27 @@ pub(crate) fn native_tls_hint(err: uv_client::Error) {
     anstream::eprint!("{report:?}");
 }
 
+/// Render a failure to prepare distributions that are missing from the cache while offline, with
+/// a help message describing how to populate the cache.
+pub(crate) fn offline_error(err: uv_installer::PrepareError) {
+    #[derive(Debug, miette::Diagnostic, thiserror::Error)]
+    #[error("{err}")]
+    #[diagnostic()]
+    struct Diagnostic {
+        err: uv_installer::PrepareError,
+        #[help]
+        help: String,
+    }
+
+    let help = format!(
+        "Re-run without `{}` to download the missing distributions, or use `{}` to populate the cache from a lockfile ahead of time",
+        "--offline".green(),
+        "uv prefetch".green()
+    );
+    let report = miette::Report::new(Diagnostic { err, help });
+    anstream::eprint!("{report:?}");
+}
+
 /// Format a [`DerivationChain`] as a human-readable error message.
 fn format_chain(name: &PackageName, version: Option<&Version>, chain: &DerivationChain) -> String {
     /// Format a step in the [`DerivationChain`] as a human-readable error message.
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde_json::json;
+use tracing::debug;
+
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifiers;
+use uv_pypi_types::{HashAlgorithm, HashDigest, ResolutionMetadata};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// A distribution to include in the index.
+#[derive(Debug)]
+struct IndexFile {
+    filename: DistFilename,
+    path: PathBuf,
+    size: u64,
+    hash: HashDigest,
+    /// The hash of the extracted metadata file, for wheels.
+    metadata_hash: Option<HashDigest>,
+    requires_python: Option<VersionSpecifiers>,
+}
+
+/// Generate a static Simple API index from a directory of wheels and source distributions.
+pub(crate) fn index_build(
+    directory: &Path,
+    output: Option<&Path>,
+    base_url: Option<&DisplaySafeUrl>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let directory = std::path::absolute(directory)?;
+    let output = match output {
+        Some(output) => std::path::absolute(output)?,
+        None => directory.join("simple"),
+    };
+
+    // Collect the distributions in the directory, by package.
+    let mut packages: BTreeMap<PackageName, Vec<IndexFile>> = BTreeMap::new();
+    let mut entries = fs_err::read_dir(&directory)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(fs_err::DirEntry::file_name);
+    for entry in entries {
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let path = entry.path();
+        let Some(filename) = path
+            .file_name()
+            .and_then(|filename| filename.to_str())
+            .and_then(DistFilename::try_from_normalized_filename)
+        else {
+            debug!("Skipping non-distribution file: {}", path.user_display());
+            continue;
+        };
+        let size = entry.metadata()?.len();
+        let file = index_file(filename, path, size)?;
+        packages
+            .entry(file.filename.name().clone())
+            .or_default()
+            .push(file);
+    }
+
+    // Write a page for each package.
+    for (name, files) in &packages {
+        let page = output.join(name.as_str());
+        fs_err::create_dir_all(&page)?;
+
+        let links = files
+            .iter()
+            .map(|file| Ok((file, file_url(file, &page, base_url)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut html = String::new();
+        writeln!(html, "<!DOCTYPE html>")?;
+        writeln!(html, "<html>")?;
+        writeln!(html, "  <head>")?;
+        writeln!(
+            html,
+            "    <meta name=\"pypi:repository-version\" content=\"1.1\">"
+        )?;
+        writeln!(html, "    <title>Links for {name}</title>")?;
+        writeln!(html, "  </head>")?;
+        writeln!(html, "  <body>")?;
+        writeln!(html, "    <h1>Links for {name}</h1>")?;
+        for (file, url) in &links {
+            write!(
+                html,
+                "    <a href=\"{}#{}={}\"",
+                escape(url),
+                file.hash.algorithm,
+                file.hash.digest
+            )?;
+            if let Some(requires_python) = &file.requires_python {
+                write!(
+                    html,
+                    " data-requires-python=\"{}\"",
+                    escape(&requires_python.to_string())
+                )?;
+            }
+            if let Some(metadata_hash) = &file.metadata_hash {
+                let metadata = format!("{}={}", metadata_hash.algorithm, metadata_hash.digest);
+                write!(
+                    html,
+                    " data-dist-info-metadata=\"{metadata}\" data-core-metadata=\"{metadata}\""
+                )?;
+            }
+            writeln!(html, ">{}</a><br>", file.filename)?;
+        }
+        writeln!(html, "  </body>")?;
+        writeln!(html, "</html>")?;
+        fs_err::write(page.join("index.html"), html)?;
+
+        let json = json!({
+            "meta": { "api-version": "1.1" },
+            "name": name,
+            "files": links.iter().map(|(file, url)| {
+                let metadata = file.metadata_hash.as_ref().map_or(
+                    json!(false),
+                    |hash| json!({ hash.algorithm.to_string(): hash.digest }),
+                );
+                json!({
+                    "filename": file.filename.to_string(),
+                    "url": url,
+                    "hashes": { file.hash.algorithm.to_string(): file.hash.digest },
+                    "requires-python": file.requires_python.as_ref().map(ToString::to_string),
+                    "size": file.size,
+                    "core-metadata": metadata,
+                    "dist-info-metadata": metadata,
+                })
+            }).collect::<Vec<_>>(),
+            "versions": files
+                .iter()
+                .map(|file| file.filename.version().to_string())
+                .collect::<std::collections::BTreeSet<_>>(),
+        });
+        fs_err::write(
+            page.join("index.json"),
+            serde_json::to_string_pretty(&json)?,
+        )?;
+    }
+
+    // Write the root page, listing each package.
+    fs_err::create_dir_all(&output)?;
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>")?;
+    writeln!(html, "<html>")?;
+    writeln!(html, "  <head>")?;
+    writeln!(
+        html,
+        "    <meta name=\"pypi:repository-version\" content=\"1.1\">"
+    )?;
+    writeln!(html, "    <title>Simple index</title>")?;
+    writeln!(html, "  </head>")?;
+    writeln!(html, "  <body>")?;
+    for name in packages.keys() {
+        writeln!(html, "    <a href=\"{name}/\">{name}</a><br>")?;
+    }
+    writeln!(html, "  </body>")?;
+    writeln!(html, "</html>")?;
+    fs_err::write(output.join("index.html"), html)?;
+
+    let json = json!({
+        "meta": { "api-version": "1.1" },
+        "projects": packages.keys().map(|name| json!({ "name": name })).collect::<Vec<_>>(),
+    });
+    fs_err::write(
+        output.join("index.json"),
+        serde_json::to_string_pretty(&json)?,
+    )?;
+
+    let files = packages.values().map(Vec::len).sum::<usize>();
+    let s = if packages.len() == 1 { "" } else { "s" };
+    let files_s = if files == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Indexed {} ({}) at: {}",
+        format!("{} package{s}", packages.len()).bold(),
+        format!("{files} file{files_s}").dimmed(),
+        output.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Hash a distribution and, for wheels, extract its metadata into a `.metadata` file alongside it.
+fn index_file(filename: DistFilename, path: PathBuf, size: u64) -> Result<IndexFile> {
+    let mut reader = fs_err::File::open(&path)?;
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    let hash = HashDigest::from(hasher);
+
+    let (metadata_hash, requires_python) = match &filename {
+        DistFilename::WheelFilename(wheel) => {
+            let mut archive = zip::ZipArchive::new(fs_err::File::open(&path)?)
+                .with_context(|| format!("Failed to read wheel: {}", path.user_display()))?;
+            let metadata =
+                uv_metadata::read_archive_metadata(wheel, &mut archive).with_context(|| {
+                    format!("Failed to read metadata from: {}", path.user_display())
+                })?;
+            let requires_python = ResolutionMetadata::parse_metadata(&metadata)
+                .with_context(|| format!("Failed to parse metadata from: {}", path.user_display()))?
+                .requires_python;
+
+            let mut metadata_path = path.clone().into_os_string();
+            metadata_path.push(".metadata");
+            fs_err::write(&metadata_path, &metadata)?;
+
+            let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+            hasher.update(&metadata);
+            (Some(HashDigest::from(hasher)), requires_python)
+        }
+        DistFilename::SourceDistFilename(_) => (None, None),
+    };
+
+    Ok(IndexFile {
+        filename,
+        path,
+        size,
+        hash,
+        metadata_hash,
+        requires_python,
+    })
+}
+
+/// Return the URL at which a distribution is linked from a package page: under the base URL, if
+/// provided, or relative to the page otherwise.
+fn file_url(file: &IndexFile, page: &Path, base_url: Option<&DisplaySafeUrl>) -> Result<String> {
+    if let Some(base_url) = base_url {
+        let mut url = base_url.clone();
+        url.path_segments_mut()
+            .map_err(|()| anyhow::anyhow!("Invalid base URL: {base_url}"))?
+            .pop_if_empty()
+            .push(&file.filename.to_string());
+        return Ok(url.to_string());
+    }
+
+    let relative = uv_fs::relative_to(&file.path, page)?;
+    Ok(relative
+        .components()
+        .map(|component| match component {
+            Component::ParentDir => "..".to_string(),
+            component => component.as_os_str().to_string_lossy().into_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// Escape a string for use in HTML.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
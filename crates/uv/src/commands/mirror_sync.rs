@@ -0,0 +1,388 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use futures::{StreamExt, TryStreamExt};
+use owo_colors::OwoColorize;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{MetadataFormat, RegistryClient, RegistryClientBuilder, VersionFiles};
+use uv_configuration::Concurrency;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{IndexCapabilities, RemoteSource};
+use uv_extract::hash::Hasher;
+use uv_fs::{CWD, Simplified};
+use uv_pep508::{Requirement, VersionOrUrl};
+use uv_pypi_types::{HashAlgorithm, HashDigest, VerbatimParsedUrl};
+use uv_redacted::DisplaySafeUrl;
+use uv_resolver::Lock;
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes, index_build};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// An artifact to mirror.
+#[derive(Debug)]
+struct Artifact {
+    filename: DistFilename,
+    url: DisplaySafeUrl,
+    /// The hashes declared for the artifact by the lockfile or the index.
+    hashes: Vec<HashDigest>,
+}
+
+/// The outcome of mirroring an artifact.
+enum Outcome {
+    /// The artifact was downloaded, with the given size.
+    Downloaded(u64),
+    /// The artifact was already present in the output directory.
+    Existing,
+}
+
+/// Download the artifacts for a lockfile or a list of packages into a directory.
+pub(crate) async fn mirror_sync(
+    packages: &[Requirement<VerbatimParsedUrl>],
+    lockfile: Option<&Path>,
+    output: &Path,
+    build_index: bool,
+    base_url: Option<&DisplaySafeUrl>,
+    settings: &ResolverSettings,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if network_settings.connectivity.is_offline() {
+        bail!("Unable to mirror artifacts in offline mode");
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .retries_from_env()?
+        .native_tls(network_settings.native_tls)
+        .connectivity(network_settings.connectivity)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        )
+        .index_locations(&settings.index_locations)
+        .index_strategy(settings.index_strategy)
+        .keyring(settings.keyring_provider)
+        .build();
+
+    // Collect the artifacts to mirror, by filename.
+    let artifacts = if packages.is_empty() {
+        let lockfile = lockfile.map_or_else(|| CWD.join("uv.lock"), Path::to_path_buf);
+        lock_artifacts(&lockfile, settings).await?
+    } else {
+        package_artifacts(&client, packages, settings, concurrency).await?
+    };
+
+    if artifacts.is_empty() {
+        warn_user!("No artifacts found to mirror");
+        return Ok(ExitStatus::Success);
+    }
+
+    fs_err::tokio::create_dir_all(output).await?;
+
+    // Download each artifact that isn't already present.
+    let client = &client;
+    let outcomes = futures::stream::iter(artifacts.into_values())
+        .map(async |artifact| mirror_artifact(client, &artifact, output).await)
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut downloaded = 0;
+    let mut bytes = 0;
+    let mut existing = 0;
+    for outcome in outcomes {
+        match outcome {
+            Outcome::Downloaded(size) => {
+                downloaded += 1;
+                bytes += size;
+            }
+            Outcome::Existing => existing += 1,
+        }
+    }
+
+    if downloaded > 0 {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        let s = if downloaded == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Downloaded {} ({bytes:.1}{unit}) to: {}",
+                format!("{downloaded} artifact{s}").bold(),
+                output.user_display().cyan()
+            )
+            .dimmed()
+        )?;
+    }
+    if existing > 0 {
+        let s = if existing == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Skipped {} already present",
+                format!("{existing} artifact{s}").bold()
+            )
+            .dimmed()
+        )?;
+    }
+
+    if build_index {
+        index_build(output, None, base_url, printer)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Collect the remote artifacts from a lockfile.
+async fn lock_artifacts(
+    lockfile: &Path,
+    settings: &ResolverSettings,
+) -> Result<BTreeMap<String, Artifact>> {
+    let encoded = fs_err::tokio::read_to_string(lockfile)
+        .await
+        .with_context(|| format!("Failed to read lockfile: {}", lockfile.user_display()))?;
+    let lock = toml::from_str::<Lock>(&encoded)
+        .with_context(|| format!("Failed to parse lockfile: {}", lockfile.user_display()))?;
+
+    let mut artifacts = BTreeMap::new();
+    for package in lock.packages() {
+        for (url, hash) in package.remote_artifacts() {
+            let filename = url.filename()?;
+            let Some(filename) = DistFilename::try_from_normalized_filename(&filename) else {
+                debug!("Skipping artifact with unrecognized filename: {url}");
+                continue;
+            };
+            if !allowed(&filename, settings) {
+                debug!("Skipping artifact disallowed by the build options: {filename}");
+                continue;
+            }
+            artifacts.insert(
+                filename.to_string(),
+                Artifact {
+                    filename,
+                    url: url.to_url()?,
+                    hashes: hash.into_iter().cloned().collect(),
+                },
+            );
+        }
+    }
+    Ok(artifacts)
+}
+
+/// Collect the artifacts for every version of each package that satisfies the requirement, from
+/// the configured indexes.
+async fn package_artifacts(
+    client: &RegistryClient,
+    packages: &[Requirement<VerbatimParsedUrl>],
+    settings: &ResolverSettings,
+    concurrency: Concurrency,
+) -> Result<BTreeMap<String, Artifact>> {
+    let capabilities = IndexCapabilities::default();
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    let mut artifacts = BTreeMap::new();
+    for requirement in packages {
+        let specifiers = match &requirement.version_or_url {
+            None => None,
+            Some(VersionOrUrl::VersionSpecifier(specifiers)) => Some(specifiers),
+            Some(VersionOrUrl::Url(_)) => {
+                bail!("Only packages from an index can be mirrored, but received: `{requirement}`")
+            }
+        };
+
+        let archives = client
+            .package_metadata(
+                &requirement.name,
+                None,
+                &capabilities,
+                &download_concurrency,
+            )
+            .await?;
+
+        let mut files = Vec::new();
+        for (_, archive) in archives {
+            match archive {
+                MetadataFormat::Simple(archive) => {
+                    for datum in archive.iter() {
+                        let version_files =
+                            rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                                .expect("archived version files always deserializes");
+                        files.extend(version_files.all());
+                    }
+                }
+                MetadataFormat::Flat(entries) => {
+                    files.extend(
+                        entries
+                            .into_iter()
+                            .map(|entry| (entry.filename, entry.file)),
+                    );
+                }
+            }
+        }
+
+        let mut found = false;
+        for (filename, file) in files {
+            if specifiers.is_some_and(|specifiers| !specifiers.contains(filename.version())) {
+                continue;
+            }
+            found = true;
+            if !allowed(&filename, settings) {
+                debug!("Skipping artifact disallowed by the build options: {filename}");
+                continue;
+            }
+            artifacts.insert(
+                filename.to_string(),
+                Artifact {
+                    url: file.url.to_url()?,
+                    hashes: file.hashes.as_slice().to_vec(),
+                    filename,
+                },
+            );
+        }
+
+        if !found {
+            warn_user!("No versions of `{requirement}` were found");
+        }
+    }
+    Ok(artifacts)
+}
+
+/// Returns `true` if the build options allow the given artifact.
+fn allowed(filename: &DistFilename, settings: &ResolverSettings) -> bool {
+    match filename {
+        DistFilename::WheelFilename(wheel) => {
+            !settings.build_options.no_binary_package(&wheel.name)
+        }
+        DistFilename::SourceDistFilename(sdist) => {
+            !settings.build_options.no_build_package(&sdist.name)
+        }
+    }
+}
+
+/// Download an artifact into the output directory, verifying it against the declared hashes.
+async fn mirror_artifact(
+    client: &RegistryClient,
+    artifact: &Artifact,
+    output: &Path,
+) -> Result<Outcome> {
+    let target = output.join(artifact.filename.to_string());
+
+    // Skip any artifacts that are already present, as long as they match the declared hashes.
+    if target.is_file() {
+        if artifact.hashes.is_empty() {
+            debug!("Skipping existing artifact: {}", target.user_display());
+            return Ok(Outcome::Existing);
+        }
+        let actual = hash_file(target.clone(), algorithms(&artifact.hashes)).await?;
+        if hashes_match(&artifact.hashes, &actual) {
+            debug!("Skipping existing artifact: {}", target.user_display());
+            return Ok(Outcome::Existing);
+        }
+        debug!(
+            "Replacing existing artifact with mismatched hash: {}",
+            target.user_display()
+        );
+    }
+
+    debug!("Downloading: {}", artifact.url);
+    let response = client
+        .uncached_client(&artifact.url)
+        .get(Url::from(artifact.url.clone()))
+        .send()
+        .await
+        .with_context(|| format!("Failed to download: {}", artifact.url))?
+        .error_for_status()
+        .with_context(|| format!("Failed to download: {}", artifact.url))?;
+
+    // Stream the artifact to a temporary file, hashing it along the way.
+    let temp = tempfile::NamedTempFile::new_in(output)?;
+    let mut hashers = algorithms(&artifact.hashes)
+        .into_iter()
+        .map(Hasher::from)
+        .collect::<Vec<_>>();
+    let mut size = 0;
+    {
+        let mut file = fs_err::tokio::File::create(temp.path()).await?;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.with_context(|| format!("Failed to download: {}", artifact.url))?;
+            for hasher in &mut hashers {
+                hasher.update(&chunk);
+            }
+            size += chunk.len() as u64;
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+    }
+
+    let actual = hashers
+        .into_iter()
+        .map(HashDigest::from)
+        .collect::<Vec<_>>();
+    if !artifact.hashes.is_empty() && !hashes_match(&artifact.hashes, &actual) {
+        return Err(uv_distribution::Error::hash_mismatch(
+            artifact.filename.to_string(),
+            &artifact.hashes,
+            &actual,
+        )
+        .into());
+    }
+
+    temp.persist(&target)
+        .with_context(|| format!("Failed to write: {}", target.user_display()))?;
+
+    Ok(Outcome::Downloaded(size))
+}
+
+/// Return the algorithms with which to hash an artifact: those of the declared hashes, or SHA-256
+/// if none were declared.
+fn algorithms(hashes: &[HashDigest]) -> Vec<HashAlgorithm> {
+    let mut algorithms = hashes.iter().map(|hash| hash.algorithm).collect::<Vec<_>>();
+    algorithms.sort();
+    algorithms.dedup();
+    if algorithms.is_empty() {
+        algorithms.push(HashAlgorithm::Sha256);
+    }
+    algorithms
+}
+
+/// Returns `true` if the computed hashes match every declared hash.
+fn hashes_match(expected: &[HashDigest], actual: &[HashDigest]) -> bool {
+    expected.iter().all(|hash| actual.contains(hash))
+}
+
+/// Hash a file with the given algorithms.
+async fn hash_file(path: PathBuf, algorithms: Vec<HashAlgorithm>) -> Result<Vec<HashDigest>> {
+    tokio::task::spawn_blocking(move || {
+        let mut reader = fs_err::File::open(&path)?;
+        let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            for hasher in &mut hashers {
+                hasher.update(&buffer[..read]);
+            }
+        }
+        Ok(hashers.into_iter().map(HashDigest::from).collect())
+    })
+    .await?
+}
@@ -1,17 +1,34 @@
 use anstream::AutoStream;
 use anyhow::Context;
+use console::Term;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::io::stdout;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
+use tracing::{debug, warn};
 
+pub(crate) use auth_list::auth_list;
+pub(crate) use auth_login::auth_login;
+pub(crate) use auth_logout::auth_logout;
+pub(crate) use auth_seal::auth_seal;
+pub(crate) use auth_unseal::auth_unseal;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_export::cache_export;
+pub(crate) use cache_import::cache_import;
+pub(crate) use cache_move::cache_move;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_refresh::cache_refresh;
+pub(crate) use cache_stats::cache_stats;
+pub(crate) use cache_verify::cache_verify;
 pub(crate) use help::help;
+pub(crate) use index_build::index_build;
+pub(crate) use mirror_sync::mirror_sync;
+pub(crate) use pip::audit::pip_audit;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
@@ -21,16 +38,25 @@ pub(crate) use pip::show::pip_show;
 pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
+pub(crate) use pip::wheel::pip_wheel;
 pub(crate) use project::add::add;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
+pub(crate) use project::lock_snapshot::SnapshotAction;
+pub(crate) use project::prefetch::prefetch;
+pub(crate) use project::prune::prune;
 pub(crate) use project::remove::remove;
-pub(crate) use project::run::{RunCommand, run};
+pub(crate) use project::run::{EachPackage, RunCommand, run};
 pub(crate) use project::sync::sync;
+pub(crate) use project::task::{list as task_list, run as task_run};
 pub(crate) use project::tree::tree;
+pub(crate) use project::verify::verify;
 pub(crate) use project::version::{project_version, self_version};
-pub(crate) use publish::publish;
+pub(crate) use publish::{
+    PYPI_ATTESTATIONS, PublishTarget, PublishWait, check as publish_check, publish,
+    publish_workspace, yank as publish_yank,
+};
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::find::find_script as python_find_script;
@@ -41,6 +67,7 @@ pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
+pub(crate) use store_gc::store_gc;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
@@ -49,25 +76,40 @@ pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
+use uv_auth::CredentialStore;
 use uv_cache::Cache;
-use uv_configuration::Concurrency;
-use uv_distribution_types::InstalledMetadata;
-use uv_fs::{CWD, Simplified};
-use uv_installer::compile_tree;
+use uv_configuration::{CompileBytecode, Concurrency};
+use uv_distribution_types::{InstalledDist, InstalledMetadata, Name};
+use uv_fs::{CWD, LockedFile, Simplified};
+use uv_installer::{SitePackages, compile_tree};
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
+use uv_static::EnvVars;
 pub(crate) use venv::venv;
 
 use crate::printer::Printer;
 
+mod auth_list;
+mod auth_login;
+mod auth_logout;
+mod auth_seal;
+mod auth_unseal;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_export;
+mod cache_import;
+mod cache_move;
 mod cache_prune;
+mod cache_refresh;
+mod cache_stats;
+mod cache_verify;
 mod diagnostics;
 mod help;
+mod index_build;
+mod mirror_sync;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -75,6 +117,7 @@ mod python;
 pub(crate) mod reporters;
 #[cfg(feature = "self-update")]
 mod self_update;
+mod store_gc;
 mod tool;
 mod venv;
 
@@ -104,6 +147,39 @@ impl From<ExitStatus> for ExitCode {
     }
 }
 
+/// Resolve the result of acquiring a best-effort lock (e.g., on an environment).
+///
+/// If the lock can't be acquired, uv proceeds without it, unless the wait for another process
+/// timed out (see `--wait-timeout`), in which case the error is propagated.
+pub(crate) fn optional_lock(
+    result: Result<LockedFile, std::io::Error>,
+    resource: &str,
+) -> Result<Option<LockedFile>, std::io::Error> {
+    match result {
+        Ok(lock) => Ok(Some(lock)),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => Err(err),
+        Err(err) => {
+            warn!("Failed to acquire {resource} lock: {err}");
+            Ok(None)
+        }
+    }
+}
+
+/// Read uv's credential store, prompting for its passphrase if it's sealed with one and
+/// `UV_CREDENTIALS_PASSPHRASE` is unset.
+pub(super) fn read_credential_store(path: &Path) -> anyhow::Result<CredentialStore> {
+    let term = Term::stderr();
+    if std::env::var_os(EnvVars::UV_CREDENTIALS_PASSPHRASE).is_none()
+        && term.is_term()
+        && CredentialStore::needs_passphrase(path)?
+    {
+        let passphrase = uv_console::password("Enter passphrase for the credential store: ", &term)
+            .context("Failed to read passphrase")?;
+        return CredentialStore::read_with_passphrase(path, Some(passphrase));
+    }
+    CredentialStore::read(path)
+}
+
 /// Format a duration as a human-readable string, Cargo-style.
 pub(super) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
@@ -146,14 +222,25 @@ pub(super) struct DryRunEvent<T: Display> {
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
+/// Files belonging to packages that are excluded by the [`CompileBytecode`] policy are skipped.
+///
 /// See the `--compile` option on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    compile: &CompileBytecode,
     concurrency: &Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
+
+    // Identify the files of any packages that shouldn't be compiled.
+    let exclude = if compile.is_all() {
+        FxHashSet::default()
+    } else {
+        excluded_files(venv, compile)?
+    };
+
     let mut files = 0;
     for site_packages in venv.site_packages() {
         let site_packages = CWD.join(site_packages);
@@ -162,6 +249,8 @@ pub(super) async fn compile_bytecode(
             venv.python_executable(),
             concurrency,
             cache.root(),
+            compile.level(),
+            &exclude,
         )
         .await
         .with_context(|| {
@@ -185,6 +274,40 @@ pub(super) async fn compile_bytecode(
     Ok(())
 }
 
+/// Return the files, as listed in their `RECORD`, of the installed packages that should not be
+/// compiled to bytecode.
+fn excluded_files(
+    venv: &PythonEnvironment,
+    compile: &CompileBytecode,
+) -> anyhow::Result<FxHashSet<PathBuf>> {
+    let mut exclude = FxHashSet::default();
+    let site_packages = SitePackages::from_environment(venv)?;
+    for dist in site_packages.iter() {
+        if compile.contains(dist.name()) {
+            continue;
+        }
+        // Legacy installations don't include a `RECORD`; their files are always compiled.
+        if !matches!(dist, InstalledDist::Registry(_) | InstalledDist::Url(_)) {
+            continue;
+        }
+        let Some(root) = dist.install_path().parent() else {
+            continue;
+        };
+        let mut record = match fs_err::File::open(dist.install_path().join("RECORD")) {
+            Ok(record) => record,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        debug!("Skipping bytecode compilation for: {}", dist.name());
+        exclude.extend(
+            uv_install_wheel::read_record_file(&mut record)?
+                .into_iter()
+                .map(|entry| CWD.join(root.join(entry.path))),
+        );
+    }
+    Ok(exclude)
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size.
 ///
 /// Returns a tuple of `(quantity, units)`.
@@ -0,0 +1,594 @@
+//! Scan packages for known vulnerabilities with the OSV API.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClient, BaseClientBuilder};
+use uv_configuration::{Concurrency, PreviewMode};
+use uv_distribution_types::{Name, RequirementSource, UnresolvedRequirement};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::{Operator, Version};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::PylockToml;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// The default URL of the OSV API.
+const OSV_URL: &str = "https://api.osv.dev";
+
+/// How long the known vulnerabilities of a package version are cached.
+const ADVISORY_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Scan the packages in an environment, or pinned in requirements files, for known
+/// vulnerabilities.
+pub(crate) async fn pip_audit(
+    requirements: &[RequirementsSource],
+    fix: bool,
+    ignore_vulns: &[String],
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    python: Option<&str>,
+    system: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled() {
+        warn_user!(
+            "`uv pip audit` is experimental and may change without warning. Pass `--preview` to disable this warning"
+        );
+    }
+
+    let start = Instant::now();
+
+    let client_builder = BaseClientBuilder::new()
+        .retries_from_env()?
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
+
+    // Collect the packages to audit, either from the requirements files or the environment.
+    let packages = if requirements.is_empty() {
+        let environment = PythonEnvironment::find(
+            &python.map(PythonRequest::parse).unwrap_or_default(),
+            EnvironmentPreference::from_system_flag(system, false),
+            cache,
+            preview,
+        )?;
+
+        report_target_environment(&environment, cache, printer)?;
+
+        let site_packages = SitePackages::from_environment(&environment)?;
+        site_packages
+            .iter()
+            .filter(|dist| {
+                // Editable installs are local projects, which aren't covered by the advisories.
+                if dist.is_editable() {
+                    debug!("Skipping editable package: `{}`", dist.name());
+                    return false;
+                }
+                true
+            })
+            .map(|dist| (dist.name().clone(), dist.version().clone()))
+            .collect::<BTreeSet<_>>()
+    } else {
+        pinned_packages(requirements, &client_builder).await?
+    };
+
+    let osv_url = std::env::var(EnvVars::UV_OSV_URL).unwrap_or_else(|_| OSV_URL.to_string());
+    let osv_url = DisplaySafeUrl::parse(&format!("{}/v1/query", osv_url.trim_end_matches('/')))
+        .with_context(|| format!("Invalid OSV API URL: `{osv_url}`"))?;
+    let client = client_builder.build();
+    let client = AdvisoryClient {
+        client: &client,
+        cache,
+        url: &osv_url,
+    };
+
+    // Fetch the known vulnerabilities of each package.
+    let mut fetches = futures::stream::iter(&packages)
+        .map(async |(name, version)| {
+            let vulnerabilities = client.vulnerabilities(name, version).await?;
+            Ok::<_, anyhow::Error>((name, version, vulnerabilities))
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut findings = Vec::new();
+    while let Some((name, version, vulnerabilities)) = fetches.next().await.transpose()? {
+        let vulnerabilities = vulnerabilities
+            .into_iter()
+            .filter(|vulnerability| {
+                if vulnerability.matches(ignore_vulns) {
+                    debug!("Ignoring `{}` for `{name}=={version}`", vulnerability.id);
+                    return false;
+                }
+                true
+            })
+            .collect::<Vec<_>>();
+        if !vulnerabilities.is_empty() {
+            findings.push(Finding {
+                name,
+                version,
+                vulnerabilities,
+            });
+        }
+    }
+    findings.sort_by(|a, b| a.name.cmp(b.name));
+
+    let s = if packages.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Audited {} {}",
+            format!("{} package{}", packages.len(), s).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    if findings.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No known vulnerabilities found".to_string().dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let count = findings
+        .iter()
+        .map(|finding| finding.vulnerabilities.len())
+        .sum::<usize>();
+    writeln!(
+        printer.stderr(),
+        "Found {} in {}",
+        format!(
+            "{count} known {}",
+            if count == 1 {
+                "vulnerability"
+            } else {
+                "vulnerabilities"
+            }
+        )
+        .bold(),
+        format!(
+            "{} package{}",
+            findings.len(),
+            if findings.len() == 1 { "" } else { "s" }
+        )
+        .bold()
+    )?;
+
+    write_table(&findings, printer)?;
+
+    if fix {
+        write_fix(&findings, !requirements.is_empty(), printer)?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Read the packages from `requirements.txt` and `pylock.toml` files, which must pin each package
+/// to an exact version.
+async fn pinned_packages(
+    requirements: &[RequirementsSource],
+    client_builder: &BaseClientBuilder<'_>,
+) -> Result<BTreeSet<(PackageName, Version)>> {
+    let mut packages = BTreeSet::new();
+    for source in requirements {
+        match source {
+            RequirementsSource::RequirementsTxt(_) => {
+                let spec = RequirementsSpecification::from_source(source, client_builder).await?;
+                for requirement in spec.requirements {
+                    let UnresolvedRequirement::Named(requirement) = &requirement.requirement else {
+                        anyhow::bail!(
+                            "Requirement `{}` in `{source}` must have a package name to be audited",
+                            requirement.requirement
+                        );
+                    };
+                    match &requirement.source {
+                        RequirementSource::Registry { specifier, .. } => {
+                            let version = match &**specifier {
+                                [specifier]
+                                    if matches!(
+                                        specifier.operator(),
+                                        Operator::Equal | Operator::ExactEqual
+                                    ) =>
+                                {
+                                    specifier.version().clone()
+                                }
+                                _ => anyhow::bail!(
+                                    "Requirement `{requirement}` in `{source}` must be pinned to an exact version to be audited, e.g., with `uv pip compile`"
+                                ),
+                            };
+                            packages.insert((requirement.name.clone(), version));
+                        }
+                        // Packages from URLs, Git and local paths aren't covered by the
+                        // advisories.
+                        _ => debug!("Skipping non-registry requirement: `{requirement}`"),
+                    }
+                }
+            }
+            RequirementsSource::PylockToml(path) => {
+                let content = fs_err::tokio::read_to_string(path).await?;
+                let lock = toml::from_str::<PylockToml>(&content).with_context(|| {
+                    format!("Not a valid `pylock.toml` file: {}", path.user_display())
+                })?;
+                for package in lock.packages {
+                    if let Some(version) = package.version {
+                        packages.insert((package.name, version));
+                    } else {
+                        debug!("Skipping package without version: `{}`", package.name);
+                    }
+                }
+            }
+            _ => anyhow::bail!(
+                "Expected a `requirements.txt` or `pylock.toml` file to audit, but found: `{source}`"
+            ),
+        }
+    }
+    Ok(packages)
+}
+
+/// A package version with known vulnerabilities.
+#[derive(Debug)]
+struct Finding<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    vulnerabilities: Vec<Vulnerability>,
+}
+
+/// A known vulnerability of a package version, merged across the databases that report it.
+#[derive(Debug)]
+struct Vulnerability {
+    id: String,
+    aliases: BTreeSet<String>,
+    /// The versions that fix the vulnerability, above the audited version.
+    fix_versions: BTreeSet<Version>,
+    severity: Option<String>,
+}
+
+impl Vulnerability {
+    /// Whether the vulnerability has any of the given IDs, as ID or alias.
+    fn matches(&self, ids: &[String]) -> bool {
+        ids.iter()
+            .any(|id| *id == self.id || self.aliases.contains(id))
+    }
+}
+
+/// Print a table of the vulnerabilities.
+fn write_table(findings: &[Finding], printer: Printer) -> Result<()> {
+    let header = ["Name", "Version", "ID", "Fix Versions", "Severity"];
+    let rows = findings
+        .iter()
+        .flat_map(|finding| {
+            finding.vulnerabilities.iter().map(|vulnerability| {
+                [
+                    finding.name.to_string(),
+                    finding.version.to_string(),
+                    vulnerability.id.clone(),
+                    vulnerability.fix_versions.iter().join(", "),
+                    vulnerability
+                        .severity
+                        .clone()
+                        .unwrap_or_else(|| "unknown".to_string()),
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let widths = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([header[column].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let line = |cells: &[&str]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .join(" ")
+            .trim_end()
+            .to_string()
+    };
+    writeln!(printer.stdout(), "{}", line(&header[..]))?;
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>();
+    writeln!(
+        printer.stdout(),
+        "{}",
+        line(&separator.iter().map(String::as_str).collect::<Vec<_>>())
+    )?;
+    for row in &rows {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            line(&row.iter().map(String::as_str).collect::<Vec<_>>())
+        )?;
+    }
+    Ok(())
+}
+
+/// Print the commands to upgrade the vulnerable packages to the lowest version that fixes all of
+/// their vulnerabilities.
+fn write_fix(findings: &[Finding], requirements: bool, printer: Printer) -> Result<()> {
+    let mut upgrades = Vec::new();
+    let mut unfixed = Vec::new();
+    for finding in findings {
+        // Each vulnerability must be fixed by the version, so take the highest of the lowest fix
+        // versions.
+        let fix_version = finding
+            .vulnerabilities
+            .iter()
+            .map(|vulnerability| vulnerability.fix_versions.first())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|versions| versions.into_iter().max());
+        if let Some(fix_version) = fix_version {
+            upgrades.push(format!("'{}>={fix_version}'", finding.name));
+        } else {
+            unfixed.push(finding.name);
+        }
+    }
+
+    writeln!(printer.stdout())?;
+    if !upgrades.is_empty() {
+        if requirements {
+            writeln!(
+                printer.stdout(),
+                "To upgrade the vulnerable packages, re-run `uv pip compile` with:"
+            )?;
+            writeln!(
+                printer.stdout(),
+                "    {}",
+                upgrades
+                    .iter()
+                    .map(|upgrade| format!("--upgrade-package {upgrade}"))
+                    .join(" ")
+            )?;
+        } else {
+            writeln!(printer.stdout(), "To upgrade the vulnerable packages, run:")?;
+            writeln!(
+                printer.stdout(),
+                "    uv pip install {}",
+                upgrades.join(" ")
+            )?;
+        }
+    }
+    if !unfixed.is_empty() {
+        writeln!(
+            printer.stdout(),
+            "No version with fixes is known for: {}",
+            unfixed.iter().map(|name| format!("`{name}`")).join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+/// A client to fetch the known vulnerabilities of a package version from the OSV API, cached in
+/// the uv cache.
+#[derive(Debug, Copy, Clone)]
+struct AdvisoryClient<'a> {
+    client: &'a BaseClient,
+    cache: &'a Cache,
+    url: &'a DisplaySafeUrl,
+}
+
+impl AdvisoryClient<'_> {
+    /// Fetch the known vulnerabilities of a package version.
+    async fn vulnerabilities(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Result<Vec<Vulnerability>> {
+        let entry = self.cache.entry(
+            CacheBucket::Advisories,
+            name.to_string(),
+            format!("{version}.json"),
+        );
+
+        let cached = if self.cache.must_revalidate_package(name) {
+            None
+        } else {
+            fs_err::metadata(entry.path())
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .filter(|modified| {
+                    SystemTime::now()
+                        .duration_since(*modified)
+                        .is_ok_and(|age| age < ADVISORY_TTL)
+                })
+                .and_then(|_| fs_err::read(entry.path()).ok())
+        };
+
+        let response = if let Some(cached) = cached {
+            debug!("Using cached advisories for: `{name}=={version}`");
+            cached
+        } else {
+            debug!("Fetching advisories for: `{name}=={version}`");
+            let query = OsvQuery {
+                version: version.to_string(),
+                package: OsvPackage {
+                    name: name.to_string(),
+                    ecosystem: "PyPI".to_string(),
+                },
+            };
+            let response = self
+                .client
+                .for_host(self.url)
+                .post(Url::from(self.url.clone()))
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(serde_json::to_vec(&query)?)
+                .send()
+                .await
+                .with_context(|| format!("Failed to fetch advisories from: `{}`", self.url))?
+                .error_for_status()?
+                .bytes()
+                .await?
+                .to_vec();
+            fs_err::tokio::create_dir_all(entry.dir()).await?;
+            uv_fs::write_atomic(entry.path(), &response).await?;
+            response
+        };
+
+        let response: OsvResponse = serde_json::from_slice(&response)
+            .with_context(|| format!("Invalid OSV response for: `{name}=={version}`"))?;
+        Ok(merge_vulnerabilities(response.vulns, name, version))
+    }
+}
+
+/// Convert the OSV vulnerabilities, merging the entries that are aliases of each other, such as a
+/// PyPA advisory and a GitHub advisory for the same CVE.
+fn merge_vulnerabilities(
+    mut vulns: Vec<OsvVulnerability>,
+    name: &PackageName,
+    version: &Version,
+) -> Vec<Vulnerability> {
+    vulns.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut merged: Vec<Vulnerability> = Vec::new();
+    for vuln in vulns {
+        if vuln.withdrawn.is_some() {
+            continue;
+        }
+
+        let fix_versions = vuln
+            .affected
+            .iter()
+            .filter(|affected| {
+                affected.package.as_ref().is_some_and(|package| {
+                    package.ecosystem == "PyPI"
+                        && PackageName::from_str(&package.name)
+                            .is_ok_and(|package| package == *name)
+                })
+            })
+            .flat_map(|affected| &affected.ranges)
+            .filter(|range| range.kind == "ECOSYSTEM")
+            .flat_map(|range| &range.events)
+            .filter_map(|event| event.fixed.as_deref())
+            .filter_map(|fixed| Version::from_str(fixed).ok())
+            .filter(|fixed| fixed > version)
+            .collect::<BTreeSet<_>>();
+        let severity = vuln
+            .database_specific
+            .and_then(|database_specific| database_specific.severity)
+            .map(|severity| severity.to_lowercase());
+
+        if let Some(existing) = merged.iter_mut().find(|existing| {
+            existing.aliases.contains(&vuln.id)
+                || vuln.aliases.contains(&existing.id)
+                || vuln
+                    .aliases
+                    .iter()
+                    .any(|alias| existing.aliases.contains(alias))
+        }) {
+            existing.aliases.insert(vuln.id);
+            existing.aliases.extend(vuln.aliases);
+            existing.fix_versions.extend(fix_versions);
+            if existing.severity.is_none() {
+                existing.severity = severity;
+            }
+        } else {
+            merged.push(Vulnerability {
+                id: vuln.id,
+                aliases: vuln.aliases.into_iter().collect(),
+                fix_versions,
+                severity,
+            });
+        }
+    }
+    merged
+}
+
+/// A query for the vulnerabilities of a package version.
+///
+/// See: <https://google.github.io/osv.dev/post-v1-query/>
+#[derive(Debug, Serialize)]
+struct OsvQuery {
+    version: String,
+    package: OsvPackage,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OsvPackage {
+    name: String,
+    ecosystem: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvResponse {
+    #[serde(default)]
+    vulns: Vec<OsvVulnerability>,
+}
+
+/// A vulnerability in the OSV schema.
+///
+/// See: <https://ossf.github.io/osv-schema/>
+#[derive(Debug, Deserialize)]
+struct OsvVulnerability {
+    id: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    withdrawn: Option<String>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+    database_specific: Option<OsvDatabaseSpecific>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAffected {
+    package: Option<OsvPackage>,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    events: Vec<OsvEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvEvent {
+    fixed: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvDatabaseSpecific {
+    severity: Option<String>,
+}
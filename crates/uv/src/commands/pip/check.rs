@@ -1,27 +1,44 @@
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::Write;
 use std::time::Instant;
 
 use anyhow::Result;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_cache::Cache;
 use uv_configuration::PreviewMode;
-use uv_distribution_types::{Diagnostic, InstalledDist};
+use uv_distribution_types::{Diagnostic, InstalledDist, Name};
 use uv_installer::{SitePackages, SitePackagesDiagnostic};
+use uv_normalize::PackageName;
+use uv_pep508::MarkerTree;
+use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
 
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
 
+/// The outcome of checking the installed packages.
+#[derive(Debug)]
+pub(crate) struct PipCheckOutcome {
+    /// The exit status of the check.
+    pub(crate) status: ExitStatus,
+    /// The requirements to install to restore the consistency of the environment, if requested.
+    pub(crate) fixes: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+}
+
 /// Check for incompatibilities in installed packages.
 pub(crate) fn pip_check(
     python: Option<&str>,
     system: bool,
+    explain: bool,
+    fix: bool,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
-) -> Result<ExitStatus> {
+) -> Result<PipCheckOutcome> {
     let start = Instant::now();
 
     // Detect the current Python interpreter.
@@ -64,27 +81,213 @@ pub(crate) fn pip_check(
             "All installed packages are compatible".to_string().dimmed()
         )?;
 
-        Ok(ExitStatus::Success)
+        return Ok(PipCheckOutcome {
+            status: ExitStatus::Success,
+            fixes: Vec::new(),
+        });
+    }
+
+    let incompats = if diagnostics.len() == 1 {
+        "incompatibility"
+    } else {
+        "incompatibilities"
+    };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {}",
+            format!("{} {}", diagnostics.len(), incompats).bold()
+        )
+        .dimmed()
+    )?;
+
+    // Collect the requirements of each installed package, to explain the broken requirements.
+    let requirements = if explain {
+        packages
+            .iter()
+            .filter_map(|dist| {
+                let metadata = dist.metadata().ok()?;
+                let requires_dist = Box::into_iter(metadata.requires_dist)
+                    .filter(|requirement| requirement.evaluate_markers(&markers, &[]))
+                    .collect_vec();
+                Some((dist.name().clone(), requires_dist))
+            })
+            .collect::<FxHashMap<_, _>>()
+    } else {
+        FxHashMap::default()
+    };
+
+    for diagnostic in &diagnostics {
+        writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+        if explain {
+            explain_diagnostic(diagnostic, &requirements, printer)?;
+        }
+    }
+
+    // Determine the requirements that restore the consistency of the environment: each missing or
+    // incompatible dependency is installed or replaced, leaving all other packages as-is.
+    let fixes = if fix {
+        let mut fixes = BTreeMap::new();
+        for diagnostic in &diagnostics {
+            let (SitePackagesDiagnostic::MissingDependency { requirement, .. }
+            | SitePackagesDiagnostic::IncompatibleDependency { requirement, .. }) = diagnostic
+            else {
+                continue;
+            };
+            let requirement = uv_pep508::Requirement {
+                marker: MarkerTree::TRUE,
+                origin: None,
+                ..requirement.clone()
+            };
+            fixes.insert(requirement.to_string(), requirement);
+        }
+        fixes.into_values().collect_vec()
     } else {
-        let incompats = if diagnostics.len() == 1 {
-            "incompatibility"
+        Vec::new()
+    };
+
+    if fix {
+        if fixes.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                "No incompatibilities can be fixed automatically".dimmed()
+            )?;
         } else {
-            "incompatibilities"
-        };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Restoring consistency with: {}",
+                    fixes
+                        .iter()
+                        .map(|requirement| format!("`{requirement}`").bold().to_string())
+                        .join(", ")
+                )
+                .dimmed()
+            )?;
+        }
+    }
+
+    Ok(PipCheckOutcome {
+        status: ExitStatus::Failure,
+        fixes,
+    })
+}
+
+/// Explain a broken requirement: how the package that declares it came to be installed, and
+/// which other installed packages constrain the same dependency.
+fn explain_diagnostic(
+    diagnostic: &SitePackagesDiagnostic,
+    requirements: &FxHashMap<PackageName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+    printer: Printer,
+) -> Result<()> {
+    let (SitePackagesDiagnostic::MissingDependency {
+        package,
+        requirement,
+    }
+    | SitePackagesDiagnostic::IncompatibleDependency {
+        package,
+        requirement,
+        ..
+    }) = diagnostic
+    else {
+        return Ok(());
+    };
+
+    match requirement_chain(package, requirements) {
+        Some(chain) => writeln!(
+            printer.stderr(),
+            "  `{package}` is required by: {}",
+            chain.iter().map(|name| format!("`{name}`")).join(" -> ")
+        )?,
+        None => writeln!(
+            printer.stderr(),
+            "  `{package}` is not required by any other installed package"
+        )?,
+    }
+
+    let constraints = requirements
+        .iter()
+        .filter(|(dependent, _)| *dependent != package)
+        .flat_map(|(dependent, requires_dist)| {
+            requires_dist
+                .iter()
+                .filter(|dependency| dependency.name == requirement.name)
+                .map(move |dependency| format!("`{dependent}` (`{dependency}`)"))
+        })
+        .sorted()
+        .collect_vec();
+    if !constraints.is_empty() {
         writeln!(
             printer.stderr(),
-            "{}",
-            format!(
-                "Found {}",
-                format!("{} {}", diagnostics.len(), incompats).bold()
-            )
-            .dimmed()
+            "  `{}` is also required by: {}",
+            requirement.name,
+            constraints.join(", ")
         )?;
+    }
 
-        for diagnostic in &diagnostics {
-            writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+    Ok(())
+}
+
+/// Find the shortest chain of installed packages that require the given package, starting from
+/// a package that isn't required by any other installed package.
+///
+/// Returns `None` if the package isn't required by any other installed package.
+fn requirement_chain(
+    package: &PackageName,
+    requirements: &FxHashMap<PackageName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+) -> Option<Vec<PackageName>> {
+    // Invert the requirements, mapping each package to the packages that require it.
+    let mut dependents: FxHashMap<&PackageName, Vec<&PackageName>> = FxHashMap::default();
+    for (dependent, requires_dist) in requirements {
+        for dependency in requires_dist {
+            if &dependency.name != dependent {
+                dependents
+                    .entry(&dependency.name)
+                    .or_default()
+                    .push(dependent);
+            }
+        }
+    }
+    for parents in dependents.values_mut() {
+        parents.sort();
+        parents.dedup();
+    }
+
+    dependents.get(package)?;
+
+    // Walk up the dependents breadth-first, until reaching a package without dependents.
+    let mut parent_of: FxHashMap<&PackageName, &PackageName> = FxHashMap::default();
+    let mut seen = FxHashSet::from_iter([package]);
+    let mut queue = VecDeque::from([package]);
+    let mut root = None;
+    while let Some(name) = queue.pop_front() {
+        let Some(parents) = dependents.get(name) else {
+            root = Some(name);
+            break;
+        };
+        for &parent in parents {
+            if seen.insert(parent) {
+                parent_of.insert(parent, name);
+                queue.push_back(parent);
+            }
         }
+    }
+
+    // If every dependent is part of a cycle, start from the closest one.
+    let root = root.unwrap_or_else(|| dependents[package][0]);
 
-        Ok(ExitStatus::Failure)
+    let mut chain = vec![root.clone()];
+    let mut current = root;
+    while current != package {
+        let Some(child) = parent_of.get(current) else {
+            chain.push(package.clone());
+            break;
+        };
+        chain.push((*child).clone());
+        current = child;
     }
+    Some(chain)
 }
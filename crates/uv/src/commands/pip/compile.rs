@@ -14,14 +14,14 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, ExportFormat, ExtrasSpecification,
-    IndexStrategy, NoBinary, NoBuild, PackageConfigSettings, PreviewMode, Reinstall,
-    SourceStrategy, Upgrade,
+    IndexStrategy, NoBinary, NoBuild, NoBuildIsolationPackages, PackageConfigSettings, PreviewMode,
+    Reinstall, SourceStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Origin, Requirement, RequiresPython, UnresolvedRequirementSpecification, Verbatim,
+    Origin, Requirement, RequiresPython, SourceLines, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -85,6 +85,7 @@ pub(crate) async fn pip_compile(
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    include_source_lines: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -94,7 +95,7 @@ pub(crate) async fn pip_compile(
     config_settings: ConfigSettings,
     config_settings_package: PackageConfigSettings,
     no_build_isolation: bool,
-    no_build_isolation_package: Vec<PackageName>,
+    no_build_isolation_package: NoBuildIsolationPackages,
     build_options: BuildOptions,
     mut python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
@@ -186,7 +187,13 @@ pub(crate) async fn pip_compile(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -195,6 +202,7 @@ pub(crate) async fn pip_compile(
         constraints,
         overrides,
         pylock,
+        uv_lock,
         source_trees,
         groups,
         extras: used_extras,
@@ -220,6 +228,13 @@ pub(crate) async fn pip_compile(
         ));
     }
 
+    // Reject `uv.lock` files, which are already resolved.
+    if uv_lock.is_some() {
+        return Err(anyhow!(
+            "`uv.lock` is not a supported input format for `uv pip compile`"
+        ));
+    }
+
     let constraints = constraints
         .iter()
         .cloned()
@@ -566,6 +581,21 @@ pub(crate) async fn pip_compile(
         )?;
     }
 
+    // If requested, read the source files to annotate each source with the line that declared
+    // the requirement.
+    let mut source_lines = SourceLines::default();
+    if include_annotations && include_source_lines {
+        for path in resolution.source_files() {
+            match fs_err::read_to_string(path) {
+                Ok(contents) => source_lines.insert(path.to_path_buf(), &contents),
+                Err(err) => debug!(
+                    "Failed to read source lines from `{}`: {err}",
+                    path.user_display()
+                ),
+            }
+        }
+    }
+
     match format {
         ExportFormat::RequirementsTxt => {
             if include_marker_expression {
@@ -655,6 +685,7 @@ pub(crate) async fn pip_compile(
                     include_annotations,
                     include_index_annotation,
                     annotation_style,
+                    &source_lines,
                 )
             )?;
         }
@@ -1,5 +1,6 @@
 use std::fmt::Write;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -9,15 +10,21 @@ use uv_cache::Cache;
 use uv_configuration::PreviewMode;
 use uv_distribution_types::{Diagnostic, InstalledDist, Name};
 use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pypi_types::{DirectUrl, HashDigest};
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations::report_target_environment;
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
+    exclude: &[PackageName],
+    hashes: bool,
     strict: bool,
     python: Option<&str>,
     system: bool,
@@ -54,35 +61,81 @@ pub(crate) fn pip_freeze(
         None => vec![SitePackages::from_environment(&environment)?],
     };
 
-    site_packages
+    let dists = site_packages
         .iter()
         .flat_map(uv_installer::SitePackages::iter)
         .filter(|dist| !(exclude_editable && dist.is_editable()))
+        .filter(|dist| !exclude.contains(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
-        .map(|dist| match dist {
-            InstalledDist::Registry(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDist::Url(dist) => {
-                if dist.editable {
-                    format!("-e {}", dist.url)
-                } else {
-                    format!("{} @ {}", dist.name().bold(), dist.url)
+        .collect::<Vec<_>>();
+
+    // Read the recorded hashes of each distribution, if requested.
+    let mut unhashed = Vec::new();
+    let digests = if hashes {
+        dists
+            .iter()
+            .map(|dist| {
+                let digests = installed_hashes(dist)?;
+                if digests.is_empty() {
+                    unhashed.push(dist.name());
                 }
+                Ok(digests)
+            })
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        vec![Vec::new(); dists.len()]
+    };
+
+    dists
+        .iter()
+        .zip(digests)
+        .map(|(dist, digests)| {
+            let mut line = match dist {
+                InstalledDist::Registry(dist) => {
+                    format!("{}=={}", dist.name().bold(), dist.version)
+                }
+                InstalledDist::Url(dist) => {
+                    if dist.editable {
+                        format!("-e {}", dist.url)
+                    } else {
+                        format!("{} @ {}", dist.name().bold(), dist.url)
+                    }
+                }
+                InstalledDist::EggInfoFile(dist) => {
+                    format!("{}=={}", dist.name().bold(), dist.version)
+                }
+                InstalledDist::EggInfoDirectory(dist) => {
+                    format!("{}=={}", dist.name().bold(), dist.version)
+                }
+                InstalledDist::LegacyEditable(dist) => {
+                    format!("-e {}", dist.target.display())
+                }
+            };
+
+            // Display the distribution hashes, if any, in the format used by `--require-hashes`.
+            for digest in digests {
+                line.push_str(" \\\n");
+                line.push_str("    --hash=");
+                line.push_str(&digest.to_string());
             }
-            InstalledDist::EggInfoFile(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDist::EggInfoDirectory(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDist::LegacyEditable(dist) => {
-                format!("-e {}", dist.target.display())
-            }
+
+            line
         })
         .dedup()
         .try_for_each(|dist| writeln!(printer.stdout(), "{dist}"))?;
 
+    if !unhashed.is_empty() {
+        let s = if unhashed.len() == 1 { "" } else { "s" };
+        warn_user!(
+            "No hashes were recorded for the following package{s}, which will be rejected when installing with `--require-hashes`: {}",
+            unhashed
+                .into_iter()
+                .dedup()
+                .map(|name| format!("`{name}`"))
+                .join(", ")
+        );
+    }
+
     // Validate that the environment is consistent.
     if strict {
         // Determine the markers to use for resolution.
@@ -103,3 +156,42 @@ pub(crate) fn pip_freeze(
 
     Ok(ExitStatus::Success)
 }
+
+/// Return the hashes of the archive from which the distribution was installed, if recorded.
+fn installed_hashes(dist: &InstalledDist) -> Result<Vec<HashDigest>> {
+    match dist {
+        InstalledDist::Registry(_) => {
+            Ok(InstalledDist::hashes(dist.install_path())?.unwrap_or_default())
+        }
+        InstalledDist::Url(url_dist) => {
+            if let Some(hashes) = InstalledDist::hashes(dist.install_path())? {
+                return Ok(hashes);
+            }
+
+            // Fall back to the archive hashes in `direct_url.json`, as written by other
+            // installers.
+            let DirectUrl::ArchiveUrl { archive_info, .. } = &*url_dist.direct_url else {
+                return Ok(Vec::new());
+            };
+            let hashes = archive_info
+                .hashes
+                .iter()
+                .flatten()
+                .map(|(algorithm, digest)| format!("{algorithm}:{digest}"))
+                .chain(
+                    archive_info
+                        .hash
+                        .as_deref()
+                        .map(|hash| hash.replacen('=', ":", 1)),
+                )
+                .filter_map(|hash| HashDigest::from_str(&hash).ok())
+                .sorted()
+                .dedup()
+                .collect();
+            Ok(hashes)
+        }
+        InstalledDist::EggInfoFile(_)
+        | InstalledDist::EggInfoDirectory(_)
+        | InstalledDist::LegacyEditable(_) => Ok(Vec::new()),
+    }
+}
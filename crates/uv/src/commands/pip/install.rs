@@ -1,16 +1,19 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Context;
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
-use tracing::{Level, debug, enabled, warn};
+use tracing::{Level, debug, enabled};
 
 use uv_cache::Cache;
+use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy,
+    BuildOptions, CompileBytecode, Concurrency, ConfigSettings, Constraints, DownloadLimits,
+    DryRun, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallHooks, InstallOptions,
+    NoBuildIsolationPackages, PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy,
     Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
@@ -21,9 +24,8 @@ use uv_distribution_types::{
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_installer::{SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
-use uv_pep508::PackageName;
+use uv_installer::{CondaPackages, SatisfiesResult, SitePackages};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pypi_types::Conflicts;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
@@ -31,8 +33,8 @@ use uv_python::{
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
-    PythonRequirement, ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, Installable, Lock, OptionsBuilder, Package,
+    PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_torch::{TorchMode, TorchStrategy};
 use uv_types::{BuildIsolation, HashStrategy};
@@ -42,8 +44,9 @@ use uv_workspace::WorkspaceCache;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::report::{RequestedPackages, write_report};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, diagnostics, optional_lock};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
 
@@ -71,13 +74,14 @@ pub(crate) async fn pip_install(
     network_settings: &NetworkSettings,
     reinstall: Reinstall,
     link_mode: LinkMode,
-    compile: bool,
+    compile: &CompileBytecode,
+    install_hooks: &InstallHooks,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     no_build_isolation: bool,
-    no_build_isolation_package: Vec<PackageName>,
+    no_build_isolation_package: NoBuildIsolationPackages,
     build_options: BuildOptions,
     modifications: Modifications,
     python_version: Option<PythonVersion>,
@@ -88,12 +92,15 @@ pub(crate) async fn pip_install(
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
+    force: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    report: Option<&Path>,
+    output_format: SyncFormat,
     printer: Printer,
     preview: PreviewMode,
 ) -> anyhow::Result<ExitStatus> {
@@ -104,7 +111,13 @@ pub(crate) async fn pip_install(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -113,6 +126,7 @@ pub(crate) async fn pip_install(
         constraints,
         overrides,
         pylock,
+        uv_lock,
         source_trees,
         groups,
         index_url,
@@ -238,13 +252,14 @@ pub(crate) async fn pip_install(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    // If the environment is a Conda environment, avoid modifying any Conda-managed packages.
+    let conda_packages = if force {
+        None
+    } else {
+        CondaPackages::from_environment(&environment)?
+    };
+
+    let _lock = optional_lock(environment.lock().await, "environment")?;
 
     // Determine the markers to use for the resolution.
     let interpreter = environment.interpreter();
@@ -265,6 +280,9 @@ pub(crate) async fn pip_install(
         && source_trees.is_empty()
         && groups.is_empty()
         && pylock.is_none()
+        && uv_lock.is_none()
+        && report.is_none()
+        && matches!(output_format, SyncFormat::Text)
         && matches!(modifications, Modifications::Sufficient)
     {
         match site_packages.satisfies_spec(&requirements, &constraints, &overrides, &marker_env)? {
@@ -435,6 +453,9 @@ pub(crate) async fn pip_install(
         preview,
     );
 
+    // Track the explicitly requested packages, for the installation report.
+    let requested = RequestedPackages::from_requirements(&requirements);
+
     let (resolution, hasher) = if let Some(pylock) = pylock {
         // Read the `pylock.toml` from disk, and deserialize it from TOML.
         let install_path = std::path::absolute(&pylock)?;
@@ -482,6 +503,49 @@ pub(crate) async fn pip_install(
         )?;
         let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
 
+        (resolution, hasher)
+    } else if let Some(uv_lock) = uv_lock {
+        // Read the `uv.lock` from disk, and deserialize it from TOML.
+        let install_path = std::path::absolute(&uv_lock)?;
+        let install_path = install_path.parent().unwrap();
+        let content = fs_err::tokio::read_to_string(&uv_lock).await?;
+        let lock = toml::from_str::<Lock>(&content)
+            .with_context(|| format!("Not a valid `uv.lock` file: {}", uv_lock.user_display()))?;
+
+        // Verify that the Python version is compatible with the lock file.
+        if !lock
+            .requires_python()
+            .contains(interpreter.python_version())
+        {
+            return Err(anyhow::anyhow!(
+                "The requested interpreter resolved to Python {}, which is incompatible with the `uv.lock`'s Python requirement: `{}`",
+                interpreter.python_version(),
+                lock.requires_python(),
+            ));
+        }
+
+        // Only install the extras and groups that were explicitly requested.
+        let extras = extras.with_defaults(DefaultExtras::default());
+        let groups = groups
+            .get(&uv_lock)
+            .cloned()
+            .unwrap_or_default()
+            .with_defaults(DefaultGroups::List(Vec::new()));
+
+        let target = LockfileTarget {
+            install_path,
+            lock: &lock,
+        };
+        let resolution = target.to_resolution(
+            &marker_env,
+            &tags,
+            &extras,
+            &groups,
+            &build_options,
+            &InstallOptions::default(),
+        )?;
+        let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
         (resolution, hasher)
     } else {
         // When resolving, don't take any external preferences into account.
@@ -548,6 +612,7 @@ pub(crate) async fn pip_install(
         &build_options,
         link_mode,
         compile,
+        install_hooks,
         &index_locations,
         config_settings,
         config_settings_package,
@@ -559,14 +624,37 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         &cache,
         &environment,
+        conda_packages.as_ref(),
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        &DownloadLimits::default(),
         dry_run,
         printer,
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => {
+            // Write the installation report, if requested.
+            if let Some(report) = report {
+                write_report(
+                    report,
+                    &resolution,
+                    &changelog,
+                    &requested,
+                    interpreter.markers(),
+                    printer,
+                )?;
+            }
+
+            // Write the changes that would be made to the environment, if requested.
+            if let (SyncFormat::Json, Some(plan)) = (output_format, &changelog.plan) {
+                writeln!(
+                    printer.stdout_important(),
+                    "{}",
+                    serde_json::to_string_pretty(plan)?
+                )?;
+            }
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -584,3 +672,37 @@ pub(crate) async fn pip_install(
 
     Ok(ExitStatus::Success)
 }
+
+/// A `uv.lock` installed outside of its project, e.g., via `uv pip install -r uv.lock`.
+///
+/// Like an entire workspace, the roots are the workspace members recorded in the lockfile, or the
+/// single project at the root of the lockfile.
+#[derive(Debug, Copy, Clone)]
+struct LockfileTarget<'lock> {
+    /// The directory containing the `uv.lock`, against which relative paths are resolved.
+    install_path: &'lock Path,
+    /// The lockfile to install.
+    lock: &'lock Lock,
+}
+
+impl<'lock> Installable<'lock> for LockfileTarget<'lock> {
+    fn install_path(&self) -> &'lock Path {
+        self.install_path
+    }
+
+    fn lock(&self) -> &'lock Lock {
+        self.lock
+    }
+
+    fn roots(&self) -> impl Iterator<Item = &PackageName> {
+        if self.lock.members().is_empty() {
+            Either::Left(self.lock.root().into_iter().map(Package::name))
+        } else {
+            Either::Right(self.lock.members().iter())
+        }
+    }
+
+    fn project_name(&self) -> Option<&PackageName> {
+        None
+    }
+}
@@ -5,6 +5,7 @@ use uv_client::{MetadataFormat, RegistryClient, VersionFiles};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{IndexCapabilities, IndexMetadataRef, IndexUrl, RequiresPython};
 use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifiers;
 use uv_platform_tags::Tags;
 use uv_resolver::{ExcludeNewer, PrereleaseMode};
 use uv_warnings::warn_user_once;
@@ -31,6 +32,26 @@ impl LatestClient<'_> {
         index: Option<&IndexUrl>,
         download_concurrency: &Semaphore,
     ) -> anyhow::Result<Option<DistFilename>, uv_client::Error> {
+        let (latest, _) = self
+            .find_latest_satisfying(
+                package,
+                index,
+                &VersionSpecifiers::empty(),
+                download_concurrency,
+            )
+            .await?;
+        Ok(latest)
+    }
+
+    /// Find the latest version of a package from an index, along with the latest version that
+    /// satisfies the given specifiers.
+    pub(crate) async fn find_latest_satisfying(
+        &self,
+        package: &PackageName,
+        index: Option<&IndexUrl>,
+        specifiers: &VersionSpecifiers,
+        download_concurrency: &Semaphore,
+    ) -> anyhow::Result<(Option<DistFilename>, Option<DistFilename>), uv_client::Error> {
         debug!("Fetching latest version of: `{package}`");
 
         let archives = match self
@@ -46,15 +67,16 @@ impl LatestClient<'_> {
             Ok(archives) => archives,
             Err(err) => {
                 return match err.into_kind() {
-                    uv_client::ErrorKind::PackageNotFound(_) => Ok(None),
-                    uv_client::ErrorKind::NoIndex(_) => Ok(None),
-                    uv_client::ErrorKind::Offline(_) => Ok(None),
+                    uv_client::ErrorKind::PackageNotFound(_) => Ok((None, None)),
+                    uv_client::ErrorKind::NoIndex(_) => Ok((None, None)),
+                    uv_client::ErrorKind::Offline(_) => Ok((None, None)),
                     kind => Err(kind.into()),
                 };
             }
         };
 
         let mut latest: Option<DistFilename> = None;
+        let mut satisfying: Option<DistFilename> = None;
         for (_, archive) in archives {
             let MetadataFormat::Simple(archive) = archive else {
                 continue;
@@ -133,19 +155,24 @@ impl LatestClient<'_> {
                     }
                 }
 
-                match (latest.as_ref(), best) {
-                    (Some(current), Some(best)) => {
-                        if best.version() > current.version() {
-                            latest = Some(best);
-                        }
-                    }
-                    (None, Some(best)) => {
-                        latest = Some(best);
-                    }
-                    _ => {}
+                let Some(best) = best else {
+                    continue;
+                };
+                if specifiers.contains(best.version())
+                    && satisfying
+                        .as_ref()
+                        .is_none_or(|current| best.version() > current.version())
+                {
+                    satisfying = Some(best.clone());
+                }
+                if latest
+                    .as_ref()
+                    .is_none_or(|current| best.version() > current.version())
+                {
+                    latest = Some(best);
                 }
             }
         }
-        Ok(latest)
+        Ok((latest, satisfying))
     }
 }
@@ -18,14 +18,17 @@ use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType, PreviewMode};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    Diagnostic, IndexCapabilities, IndexLocations, InstalledDist, Name, RequiresPython,
+    Diagnostic, IndexCapabilities, IndexLocations, InstalledDist, Name, RequirementSource,
+    RequiresPython,
 };
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::VersionOrUrl;
 use uv_python::PythonRequest;
 use uv_python::{EnvironmentPreference, PythonEnvironment};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{ExcludeNewer, PrereleaseMode};
 
 use crate::commands::ExitStatus;
@@ -40,8 +43,10 @@ use crate::settings::NetworkSettings;
 pub(crate) async fn pip_list(
     editable: Option<bool>,
     exclude: &[PackageName],
+    only_uv_managed: bool,
     format: &ListFormat,
     outdated: bool,
+    constraints: &[RequirementsSource],
     prerelease: PrereleaseMode,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -79,10 +84,18 @@ pub(crate) async fn pip_list(
         .iter()
         .filter(|dist| editable.is_none() || editable == Some(dist.is_editable()))
         .filter(|dist| !exclude.contains(dist.name()))
+        .filter(|dist| {
+            !only_uv_managed
+                || dist
+                    .installer()
+                    .ok()
+                    .flatten()
+                    .is_some_and(|installer| installer == "uv")
+        })
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .collect_vec();
 
-    // Determine the latest version for each package.
+    // Determine the latest version, and the latest satisfiable version, for each package.
     let latest = if outdated && !results.is_empty() {
         let capabilities = IndexCapabilities::default();
 
@@ -91,7 +104,52 @@ pub(crate) async fn pip_list(
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .keyring(keyring_provider)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            );
+
+        // Collect the specifiers that an upgrade has to satisfy, from the requirements of the
+        // installed packages and the constraints.
+        let markers = environment.interpreter().markers();
+        let mut specifiers: FxHashMap<PackageName, Vec<VersionSpecifier>> = FxHashMap::default();
+        for dist in site_packages.iter() {
+            let metadata = dist.metadata()?;
+            for requirement in metadata.requires_dist {
+                if !requirement.evaluate_markers(markers, &[]) {
+                    continue;
+                }
+                if let Some(VersionOrUrl::VersionSpecifier(specifier)) = requirement.version_or_url
+                {
+                    specifiers
+                        .entry(requirement.name)
+                        .or_default()
+                        .extend(specifier);
+                }
+            }
+        }
+        let spec =
+            RequirementsSpecification::from_sources(&[], constraints, &[], None, &client_builder)
+                .await?;
+        for constraint in spec.constraints {
+            let requirement = constraint.requirement;
+            if !requirement.evaluate_markers(Some(markers), &[]) {
+                continue;
+            }
+            if let RequirementSource::Registry { specifier, .. } = requirement.source {
+                specifiers
+                    .entry(requirement.name)
+                    .or_default()
+                    .extend(specifier);
+            }
+        }
+        let specifiers = specifiers
+            .into_iter()
+            .map(|(name, specifiers)| (name, VersionSpecifiers::from_iter(specifiers)))
+            .collect::<FxHashMap<_, _>>();
 
         // Initialize the registry client.
         let client = RegistryClientBuilder::try_from(client_builder)?
@@ -125,20 +183,27 @@ pub(crate) async fn pip_list(
         let mut fetches = futures::stream::iter(&results)
             .map(async |dist| {
                 let latest = client
-                    .find_latest(dist.name(), None, &download_concurrency)
+                    .find_latest_satisfying(
+                        dist.name(),
+                        None,
+                        specifiers
+                            .get(dist.name())
+                            .unwrap_or(&VersionSpecifiers::empty()),
+                        &download_concurrency,
+                    )
                     .await?;
-                Ok::<(&PackageName, Option<DistFilename>), uv_client::Error>((dist.name(), latest))
+                Ok::<(&PackageName, Latest), uv_client::Error>((dist.name(), latest))
             })
             .buffer_unordered(concurrency.downloads);
 
         let mut map = FxHashMap::default();
-        while let Some((package, version)) = fetches.next().await.transpose()? {
-            if let Some(version) = version.as_ref() {
+        while let Some((package, (latest, satisfiable))) = fetches.next().await.transpose()? {
+            if let Some(version) = latest.as_ref() {
                 reporter.on_fetch_version(package, version.version());
             } else {
                 reporter.on_fetch_progress();
             }
-            map.insert(package, version);
+            map.insert(package, (latest, satisfiable));
         }
         reporter.on_fetch_complete();
         map
@@ -152,6 +217,7 @@ pub(crate) async fn pip_list(
             .into_iter()
             .filter(|dist| {
                 latest[dist.name()]
+                    .0
                     .as_ref()
                     .is_some_and(|filename| filename.version() > dist.version())
             })
@@ -170,13 +236,18 @@ pub(crate) async fn pip_list(
                     version: dist.version().clone(),
                     latest_version: latest
                         .get(dist.name())
-                        .and_then(|filename| filename.as_ref())
+                        .and_then(|(filename, _)| filename.as_ref())
                         .map(DistFilename::version)
                         .cloned(),
                     latest_filetype: latest
                         .get(dist.name())
-                        .and_then(|filename| filename.as_ref())
+                        .and_then(|(filename, _)| filename.as_ref())
                         .map(FileType::from),
+                    latest_satisfiable_version: latest
+                        .get(dist.name())
+                        .and_then(|(_, filename)| filename.as_ref())
+                        .map(DistFilename::version)
+                        .cloned(),
                     editable_project_location: dist
                         .as_editable()
                         .map(|url| url.to_file_path().unwrap().simplified_display().to_string()),
@@ -215,7 +286,7 @@ pub(crate) async fn pip_list(
                         .map(|dist| {
                             latest
                                 .get(dist.name())
-                                .and_then(|filename| filename.as_ref())
+                                .and_then(|(filename, _)| filename.as_ref())
                                 .map(DistFilename::version)
                                 .map(ToString::to_string)
                                 .unwrap_or_default()
@@ -229,7 +300,7 @@ pub(crate) async fn pip_list(
                         .map(|dist| {
                             latest
                                 .get(dist.name())
-                                .and_then(|filename| filename.as_ref())
+                                .and_then(|(filename, _)| filename.as_ref())
                                 .map(FileType::from)
                                 .as_ref()
                                 .map(ToString::to_string)
@@ -237,6 +308,32 @@ pub(crate) async fn pip_list(
                         })
                         .collect_vec(),
                 });
+
+                // The latest satisfiable version is only displayed if it differs from the latest
+                // version for at least one package.
+                if results.iter().any(|dist| {
+                    latest
+                        .get(dist.name())
+                        .is_some_and(|(latest, satisfiable)| {
+                            latest.as_ref().map(DistFilename::version)
+                                != satisfiable.as_ref().map(DistFilename::version)
+                        })
+                }) {
+                    columns.push(Column {
+                        header: String::from("Latest satisfiable"),
+                        rows: results
+                            .iter()
+                            .map(|dist| {
+                                latest
+                                    .get(dist.name())
+                                    .and_then(|(_, filename)| filename.as_ref())
+                                    .map(DistFilename::version)
+                                    .map(ToString::to_string)
+                                    .unwrap_or_default()
+                            })
+                            .collect_vec(),
+                    });
+                }
             }
 
             // Editable column is only displayed if at least one editable package is found.
@@ -287,6 +384,10 @@ pub(crate) async fn pip_list(
     Ok(ExitStatus::Success)
 }
 
+/// The latest version of a package, and the latest version that satisfies the requirements of
+/// the installed packages and the constraints.
+type Latest = (Option<DistFilename>, Option<DistFilename>);
+
 #[derive(Debug)]
 enum FileType {
     /// A wheel distribution (i.e., a `.whl` file).
@@ -335,6 +436,8 @@ struct Entry {
     #[serde(skip_serializing_if = "Option::is_none")]
     latest_filetype: Option<FileType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    latest_satisfiable_version: Option<Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     editable_project_location: Option<String>,
 }
 
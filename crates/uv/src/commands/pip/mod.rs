@@ -5,6 +5,7 @@ use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
+pub(crate) mod audit;
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
@@ -13,10 +14,13 @@ pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod report;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
+pub(crate) mod wheel;
+pub(crate) mod wheelhouse;
 
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
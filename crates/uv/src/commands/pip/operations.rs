@@ -3,31 +3,38 @@
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::debug;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket, CacheRun};
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, DependencyGroups, DryRun,
-    ExtrasSpecification, Overrides, PackageConfigSettings, Reinstall, Upgrade,
+    BuildOptions, CompileBytecode, Concurrency, ConfigSettings, Constraints, DependencyGroups,
+    DownloadLimits, DryRun, ExtrasSpecification, InstallHooks, Overrides, PackageConfigSettings,
+    Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
-    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    BuiltDist, Dist, DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    ResolvedDist, SourceDist,
 };
 use uv_distribution_types::{
-    DistributionMetadata, IndexLocations, InstalledMetadata, Name, Resolution,
+    CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
+    ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_installer::{Plan, Planner, Preparer, SitePackages};
+use uv_installer::{
+    CondaPackages, Plan, Planner, Preparer, SitePackages, SourceDateEpoch, Transaction,
+};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin};
 use uv_platform_tags::Tags;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
@@ -46,7 +53,7 @@ use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
-use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode};
+use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Consolidate the requirements for an installation.
@@ -150,7 +157,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 NamedRequirementsResolver::new(
                     hasher,
                     index,
-                    DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                    DistributionDatabase::new(client, build_dispatch, concurrency),
                 )
                 .with_reporter(Arc::new(ResolverReporter::from(printer)))
                 .resolve(unnamed.into_iter())
@@ -164,7 +171,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 extras,
                 hasher,
                 index,
-                DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                DistributionDatabase::new(client, build_dispatch, concurrency),
             )
             .with_reporter(Arc::new(ResolverReporter::from(printer)))
             .resolve(source_trees.iter().map(PathBuf::as_path))
@@ -272,7 +279,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 NamedRequirementsResolver::new(
                     hasher,
                     index,
-                    DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                    DistributionDatabase::new(client, build_dispatch, concurrency),
                 )
                 .with_reporter(Arc::new(ResolverReporter::from(printer)))
                 .resolve(unnamed.into_iter())
@@ -302,7 +309,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
                 &overrides,
                 hasher,
                 index,
-                DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+                DistributionDatabase::new(client, build_dispatch, concurrency),
             )
             .with_reporter(Arc::new(ResolverReporter::from(printer)))
             .resolve(&resolver_env)
@@ -349,7 +356,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             hasher,
             build_dispatch,
             installed_packages,
-            DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+            DistributionDatabase::new(client, build_dispatch, concurrency),
         )?
         .with_reporter(Arc::new(reporter));
 
@@ -385,6 +392,39 @@ pub(crate) struct Changelog {
     pub(crate) uninstalled: HashSet<LocalDist>,
     /// The distributions that were reinstalled.
     pub(crate) reinstalled: HashSet<LocalDist>,
+    /// The changes that would be made to the environment, for a dry-run installation.
+    pub(crate) plan: Option<InstallPlan>,
+    /// How each distribution was (or, for a dry-run installation, would be) obtained.
+    pub(crate) sources: FxHashMap<PackageName, InstallSource>,
+}
+
+/// How a distribution was obtained for installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum InstallSource {
+    /// A wheel was downloaded.
+    Downloaded,
+    /// A wheel was built from source.
+    Built,
+    /// A wheel was served from the cache.
+    Cached,
+}
+
+impl InstallSource {
+    /// Determine how the distributions in a [`Plan`] will be obtained.
+    fn from_plan(plan: &Plan) -> FxHashMap<PackageName, Self> {
+        plan.cached
+            .iter()
+            .map(|dist| (dist.name().clone(), Self::Cached))
+            .chain(plan.remote.iter().map(|dist| {
+                let source = match dist.as_ref() {
+                    Dist::Built(_) => Self::Downloaded,
+                    Dist::Source(_) => Self::Built,
+                };
+                (dist.name().clone(), source)
+            }))
+            .collect()
+    }
 }
 
 impl Changelog {
@@ -403,6 +443,8 @@ impl Changelog {
             installed,
             uninstalled,
             reinstalled,
+            plan: None,
+            sources: FxHashMap::default(),
         }
     }
 
@@ -412,6 +454,8 @@ impl Changelog {
             installed: installed.into_iter().map(LocalDist::from).collect(),
             uninstalled: HashSet::default(),
             reinstalled: HashSet::default(),
+            plan: None,
+            sources: FxHashMap::default(),
         }
     }
 
@@ -422,12 +466,241 @@ impl Changelog {
             || self.uninstalled.iter().any(|dist| dist.name() == name)
     }
 
+    /// Returns the names of the packages that were removed from the environment, rather than
+    /// replaced; or, for a dry-run, the packages that would be removed.
+    pub(crate) fn removed(&self) -> BTreeSet<&PackageName> {
+        if let Some(plan) = &self.plan {
+            return plan
+                .changes
+                .iter()
+                .filter(|change| matches!(change.action, PlannedAction::Remove))
+                .map(|change| &change.name)
+                .collect();
+        }
+        self.uninstalled
+            .iter()
+            .map(Name::name)
+            .filter(|name| !self.installed.iter().any(|dist| dist.name() == *name))
+            .collect()
+    }
+
     /// Returns `true` if the changelog is empty.
     pub(crate) fn is_empty(&self) -> bool {
         self.installed.is_empty() && self.uninstalled.is_empty()
     }
 }
 
+/// A structured description of the changes that an installation would make to the environment.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct InstallPlan {
+    /// The link mode that would be used to install distributions.
+    link_mode: LinkMode,
+    /// The estimated size of the distributions that would be downloaded, in bytes.
+    ///
+    /// Distributions of unknown size (e.g., direct URL dependencies) are excluded.
+    download_size: u64,
+    /// The packages that would be installed, upgraded, downgraded, reinstalled, or removed.
+    changes: Vec<PlannedChange>,
+}
+
+/// A change to a single package in an [`InstallPlan`].
+#[derive(Debug, Clone, Serialize)]
+struct PlannedChange {
+    /// The name of the package.
+    name: PackageName,
+    /// The kind of change.
+    action: PlannedAction,
+    /// The version that would be installed, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<Version>,
+    /// The currently installed version, for packages that would be replaced or removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installed_version: Option<Version>,
+    /// The artifact that would be installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    artifact: Option<PlannedArtifact>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PlannedAction {
+    /// The package is not installed, and would be installed.
+    Install,
+    /// The package is installed, and would be replaced with a newer version.
+    Upgrade,
+    /// The package is installed, and would be replaced with an older version.
+    Downgrade,
+    /// The package is installed, and would be replaced with the same version (e.g., from a
+    /// different source).
+    Reinstall,
+    /// The package is installed, and would be removed.
+    Remove,
+}
+
+/// The artifact chosen for a package in an [`InstallPlan`].
+#[derive(Debug, Clone, Serialize)]
+struct PlannedArtifact {
+    /// The kind of artifact.
+    kind: ArtifactKind,
+    /// The URL of the artifact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// The expected hashes of the artifact.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<String>,
+    /// The size of the artifact, in bytes, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    /// Whether a built wheel for the artifact is already present in the cache.
+    cached: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ArtifactKind {
+    /// A built distribution.
+    Wheel,
+    /// A source distribution archive, which must be built prior to installation.
+    Sdist,
+    /// A Git repository, which must be built prior to installation.
+    Git,
+    /// A local source tree, which must be built prior to installation.
+    Directory,
+}
+
+impl PlannedArtifact {
+    fn from_dist(dist: &Dist, hashes: &[uv_pypi_types::HashDigest], cached: bool) -> Self {
+        let (kind, url) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => (
+                ArtifactKind::Wheel,
+                wheels
+                    .best_wheel()
+                    .file
+                    .url
+                    .to_url()
+                    .ok()
+                    .map(|url| url.to_string()),
+            ),
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+                (ArtifactKind::Wheel, Some(wheel.url.to_string()))
+            }
+            Dist::Built(BuiltDist::Path(wheel)) => {
+                (ArtifactKind::Wheel, Some(wheel.url.to_string()))
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                ArtifactKind::Sdist,
+                sdist.file.url.to_url().ok().map(|url| url.to_string()),
+            ),
+            Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                (ArtifactKind::Sdist, Some(sdist.url.to_string()))
+            }
+            Dist::Source(SourceDist::Path(sdist)) => {
+                (ArtifactKind::Sdist, Some(sdist.url.to_string()))
+            }
+            Dist::Source(SourceDist::Git(sdist)) => {
+                (ArtifactKind::Git, Some(sdist.url.to_string()))
+            }
+            Dist::Source(SourceDist::Directory(sdist)) => {
+                (ArtifactKind::Directory, Some(sdist.url.to_string()))
+            }
+        };
+        Self {
+            kind,
+            url,
+            hashes: hashes.iter().map(ToString::to_string).collect(),
+            size: dist.file().and_then(|file| file.size),
+            cached,
+        }
+    }
+}
+
+impl InstallPlan {
+    /// Describe the changes in a [`Plan`], using the [`Resolution`] to identify the chosen
+    /// artifacts.
+    fn new(
+        resolution: &Resolution,
+        cached: &[CachedDist],
+        remote: &[Arc<Dist>],
+        reinstalls: &[InstalledDist],
+        extraneous: &[InstalledDist],
+        link_mode: LinkMode,
+    ) -> Self {
+        let hashes = resolution
+            .hashes()
+            .map(|(dist, hashes)| (dist.name(), hashes))
+            .collect::<FxHashMap<_, _>>();
+        let resolved = resolution
+            .distributions()
+            .map(|dist| (dist.name(), dist))
+            .collect::<FxHashMap<_, _>>();
+        let installed = reinstalls
+            .iter()
+            .map(|dist| (dist.name(), dist.version()))
+            .collect::<FxHashMap<_, _>>();
+
+        let hashes_for = |name: &PackageName| hashes.get(name).copied().unwrap_or_default();
+        let planned = |name: &PackageName, version: Option<Version>, artifact| {
+            let installed_version = installed.get(name).map(|version| (*version).clone());
+            let action = match (&installed_version, &version) {
+                (None, _) => PlannedAction::Install,
+                (Some(installed), Some(version)) if version > installed => PlannedAction::Upgrade,
+                (Some(installed), Some(version)) if version < installed => PlannedAction::Downgrade,
+                (Some(_), _) => PlannedAction::Reinstall,
+            };
+            PlannedChange {
+                name: name.clone(),
+                action,
+                version,
+                installed_version,
+                artifact,
+            }
+        };
+
+        let mut download_size = 0;
+        let mut changes = Vec::new();
+        for dist in remote {
+            let artifact = PlannedArtifact::from_dist(dist, hashes_for(dist.name()), false);
+            download_size += artifact.size.unwrap_or_default();
+            let version = resolved
+                .get(dist.name())
+                .and_then(|resolved| resolved.version())
+                .or_else(|| dist.version())
+                .cloned();
+            changes.push(planned(dist.name(), version, Some(artifact)));
+        }
+        for dist in cached {
+            let resolved = resolved.get(dist.name());
+            let artifact = resolved.and_then(|resolved| match resolved {
+                ResolvedDist::Installable { dist: resolved, .. } => Some(
+                    PlannedArtifact::from_dist(resolved, hashes_for(dist.name()), true),
+                ),
+                ResolvedDist::Installed { .. } => None,
+            });
+            let version = resolved
+                .and_then(|resolved| resolved.version())
+                .unwrap_or(&dist.filename().version)
+                .clone();
+            changes.push(planned(dist.name(), Some(version), artifact));
+        }
+        for dist in extraneous {
+            changes.push(PlannedChange {
+                name: dist.name().clone(),
+                action: PlannedAction::Remove,
+                version: None,
+                installed_version: Some(dist.version().clone()),
+                artifact: None,
+            });
+        }
+        changes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            link_mode,
+            download_size,
+            changes,
+        }
+    }
+}
+
 /// Install a set of requirements into the current environment.
 ///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
@@ -438,7 +711,8 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
-    compile: bool,
+    compile: &CompileBytecode,
+    install_hooks: &InstallHooks,
     index_urls: &IndexLocations,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
@@ -450,13 +724,32 @@ pub(crate) async fn install(
     build_dispatch: &BuildDispatch<'_>,
     cache: &Cache,
     venv: &PythonEnvironment,
+    conda_packages: Option<&CondaPackages>,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    download_limits: &DownloadLimits,
     dry_run: DryRun,
     printer: Printer,
 ) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
 
+    // If `SOURCE_DATE_EPOCH` is set, install reproducibly.
+    let source_date_epoch = SourceDateEpoch::from_env()?;
+
+    // If a previous installation failed partway through, restore the environment to its prior
+    // state before planning against it.
+    let site_packages = if dry_run.enabled() {
+        site_packages
+    } else if Transaction::recover(venv)? {
+        warn_user!(
+            "Rolled back an incomplete installation in: {}",
+            venv.root().user_display().cyan()
+        );
+        SitePackages::from_environment(venv)?
+    } else {
+        site_packages
+    };
+
     // Partition into those that should be linked from the cache (`local`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::new(resolution)
@@ -474,9 +767,23 @@ pub(crate) async fn install(
         )
         .context("Failed to determine installation plan")?;
 
+    let sources = InstallSource::from_plan(&plan);
+
     if dry_run.enabled() {
-        report_dry_run(dry_run, resolution, plan, modifications, start, printer)?;
-        return Ok(Changelog::default());
+        let plan = report_dry_run(
+            dry_run,
+            resolution,
+            plan,
+            modifications,
+            link_mode,
+            start,
+            printer,
+        )?;
+        return Ok(Changelog {
+            plan: Some(plan),
+            sources,
+            ..Changelog::default()
+        });
     }
 
     let Plan {
@@ -492,17 +799,45 @@ pub(crate) async fn install(
         Modifications::Exact => extraneous,
     };
 
+    // Refuse to replace or remove any distributions that are managed by Conda.
+    if let Some(conda_packages) = conda_packages {
+        let managed = extraneous
+            .iter()
+            .chain(&reinstalls)
+            .filter(|dist| conda_packages.contains(dist))
+            .map(|dist| format!("`{}`", dist.name()))
+            .sorted()
+            .dedup()
+            .collect::<Vec<_>>();
+        if !managed.is_empty() {
+            return Err(Error::CondaManaged(managed.join(", ")));
+        }
+    }
+
     // Nothing to do.
     if remote.is_empty()
         && cached.is_empty()
         && reinstalls.is_empty()
         && extraneous.is_empty()
-        && !compile
+        && !compile.is_enabled()
     {
         logger.on_audit(resolution.len(), start, printer)?;
         return Ok(Changelog::default());
     }
 
+    // Record the number of distributions served from the cache, to inform `uv cache stats`.
+    if !cached.is_empty() || !remote.is_empty() {
+        if let Err(err) = cache.record_run(CacheRun::new(cached.len() as u64, remote.len() as u64))
+        {
+            debug!("Failed to record cache statistics: {err}");
+        }
+    }
+
+    // Enforce any limits on the total download size before downloading anything.
+    if !remote.is_empty() && !download_limits.is_empty() {
+        check_download_size(&remote, download_limits, printer)?;
+    }
+
     // Download, build, and unzip any missing distributions.
     let wheels = if remote.is_empty() {
         vec![]
@@ -514,7 +849,7 @@ pub(crate) async fn install(
             tags,
             hasher,
             build_options,
-            DistributionDatabase::new(client, build_dispatch, concurrency.downloads),
+            DistributionDatabase::new(client, build_dispatch, concurrency),
         )
         .with_reporter(Arc::new(
             PrepareReporter::from(printer).with_length(remote.len() as u64),
@@ -529,12 +864,108 @@ pub(crate) async fn install(
         wheels
     };
 
-    // Remove any upgraded or extraneous installations.
+    // Record the modifications to the environment in a journal, such that they can be rolled back
+    // if the installation fails partway through.
+    let mut transaction = Transaction::begin(venv)?;
+
+    // Remove any upgraded or extraneous installations, and install the resolved distributions.
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
+    let installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+    let result = apply(
+        &mut transaction,
+        &uninstalls,
+        installs,
+        link_mode,
+        install_hooks,
+        installer_metadata,
+        source_date_epoch,
+        cache,
+        venv,
+        logger.as_ref(),
+        printer,
+    )
+    .await;
+
+    let installs = match result {
+        Ok(installs) => {
+            transaction.commit()?;
+            installs
+        }
+        Err(err) => {
+            if let Err(rollback) = transaction.rollback() {
+                warn_user!(
+                    "Failed to roll back the incomplete installation in {}: {rollback}",
+                    venv.root().user_display().cyan()
+                );
+            } else {
+                debug!("Rolled back the incomplete installation");
+            }
+            return Err(err);
+        }
+    };
+
+    if compile.is_enabled() {
+        compile_bytecode(venv, compile, &concurrency, cache, printer).await?;
+    }
+
+    // Normalize the modification times of the environment's files, such that repeated
+    // installations produce identical `site-packages` directories.
+    if let Some(source_date_epoch) = source_date_epoch {
+        source_date_epoch.normalize(venv)?;
+    }
+
+    // Construct a summary of the changes made to the environment.
+    let changelog = Changelog {
+        sources,
+        ..Changelog::new(installs, uninstalls)
+    };
+
+    // Notify the user of any environment modifications.
+    logger.on_complete(&changelog, printer)?;
+
+    // If we downloaded any distributions, evict entries from the cache as needed to keep it under
+    // the maximum size.
+    if !remote.is_empty() {
+        match cache.enforce_max_size() {
+            Ok(summary) => {
+                if summary.total_bytes > 0 {
+                    debug!(
+                        "Evicted {} bytes from the cache to stay under the maximum size",
+                        summary.total_bytes
+                    );
+                }
+            }
+            Err(err) => {
+                warn_user!("Failed to evict entries from the cache: {err}");
+            }
+        }
+    }
+
+    Ok(changelog)
+}
+
+/// Apply the planned modifications to the environment, recording them in the given
+/// [`Transaction`].
+///
+/// Returns the installed distributions.
+async fn apply(
+    transaction: &mut Transaction,
+    uninstalls: &[InstalledDist],
+    mut installs: Vec<CachedDist>,
+    link_mode: LinkMode,
+    install_hooks: &InstallHooks,
+    installer_metadata: bool,
+    source_date_epoch: Option<SourceDateEpoch>,
+    cache: &Cache,
+    venv: &PythonEnvironment,
+    logger: &dyn InstallLogger,
+    printer: Printer,
+) -> Result<Vec<CachedDist>, Error> {
     if !uninstalls.is_empty() {
         let start = std::time::Instant::now();
 
-        for dist_info in &uninstalls {
+        for dist_info in uninstalls {
+            transaction.backup(dist_info)?;
             match uv_installer::uninstall(dist_info).await {
                 Ok(summary) => {
                     debug!(
@@ -570,35 +1001,29 @@ pub(crate) async fn install(
     }
 
     // Install the resolved distributions.
-    let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !installs.is_empty() {
         let start = std::time::Instant::now();
-        installs = uv_installer::Installer::new(venv)
+        transaction.record_installs(&installs)?;
+        let mut installer = uv_installer::Installer::new(venv)
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
+            .with_source_date_epoch(source_date_epoch)
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
-            ))
-            // This technically can block the runtime, but we are on the main thread and
-            // have no other running tasks at this point, so this lets us avoid spawning a blocking
-            // task.
-            .install_blocking(installs)?;
+            ));
+        if !install_hooks.is_empty() {
+            installer = installer.with_hook(Arc::new(install_hooks.clone()));
+        }
+        // This technically can block the runtime, but we are on the main thread and
+        // have no other running tasks at this point, so this lets us avoid spawning a blocking
+        // task.
+        installs = installer.install_blocking(installs)?;
 
         logger.on_install(installs.len(), start, printer)?;
     }
 
-    if compile {
-        compile_bytecode(venv, &concurrency, cache, printer).await?;
-    }
-
-    // Construct a summary of the changes made to the environment.
-    let changelog = Changelog::new(installs, uninstalls);
-
-    // Notify the user of any environment modifications.
-    logger.on_complete(&changelog, printer)?;
-
-    Ok(changelog)
+    Ok(installs)
 }
 
 /// Display a message about the interpreter that was selected for the operation.
@@ -678,7 +1103,9 @@ pub(crate) fn report_target_environment(
     };
 
     // Do not report environments in the cache
-    if target.starts_with(cache.root()) {
+    if target.starts_with(cache.root())
+        || target.starts_with(cache.bucket_root(CacheBucket::Archive))
+    {
         debug!("{}", message);
         return Ok(());
     }
@@ -702,6 +1129,85 @@ pub(crate) fn report_target_environment(
     Ok(writeln!(printer.stderr(), "{}", message.dimmed())?)
 }
 
+/// Check the estimated size of the distributions to be downloaded against the given
+/// [`DownloadLimits`].
+///
+/// If the size exceeds the confirmation threshold, it's reported, and the user is asked to confirm
+/// the download when running interactively. If it exceeds the maximum, an error is returned.
+#[allow(clippy::result_large_err)]
+fn check_download_size(
+    remote: &[Arc<Dist>],
+    download_limits: &DownloadLimits,
+    printer: Printer,
+) -> Result<(), Error> {
+    let mut sizes = Vec::with_capacity(remote.len());
+    let mut unknown = 0usize;
+    for dist in remote {
+        match dist.file().and_then(|file| file.size) {
+            Some(size) => sizes.push((dist.name(), size)),
+            None => unknown += 1,
+        }
+    }
+    let total = sizes.iter().map(|(_, size)| size).sum::<u64>();
+    debug!(
+        "Estimated download size: {total} bytes across {} distributions ({unknown} of unknown size)",
+        remote.len()
+    );
+
+    if let Some(max) = download_limits.max {
+        if total > max.bytes() {
+            return Err(Error::DownloadSizeExceeded {
+                size: format_bytes(total),
+                max: format_bytes(max.bytes()),
+            });
+        }
+    }
+
+    if let Some(confirm) = download_limits.confirm {
+        if total > confirm.bytes() {
+            let s = if remote.len() == 1 { "" } else { "s" };
+            let largest = sizes
+                .iter()
+                .sorted_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)))
+                .take(3)
+                .map(|(name, size)| format!("{} ({})", name.bold(), format_bytes(*size)))
+                .join(", ");
+            writeln!(
+                printer.stderr(),
+                "Downloading {} across {} package{s} (largest: {largest})",
+                format_bytes(total).bold(),
+                remote.len(),
+            )?;
+            if unknown > 0 {
+                let s = if unknown == 1 { "" } else { "s" };
+                writeln!(
+                    printer.stderr(),
+                    "{}{} The download size of {unknown} package{s} is unknown",
+                    "note".bold().cyan(),
+                    ":".bold(),
+                )?;
+            }
+
+            let term = console::Term::stderr();
+            if term.is_term() && !uv_console::confirm("Continue with the download?", &term, true)? {
+                return Err(Error::DownloadDeclined);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Format a number of bytes for display.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
 /// Report on the results of a dry-run installation.
 #[allow(clippy::result_large_err)]
 fn report_dry_run(
@@ -709,9 +1215,10 @@ fn report_dry_run(
     resolution: &Resolution,
     plan: Plan,
     modifications: Modifications,
+    link_mode: LinkMode,
     start: std::time::Instant,
     printer: Printer,
-) -> Result<(), Error> {
+) -> Result<InstallPlan, Error> {
     let Plan {
         cached,
         remote,
@@ -725,11 +1232,20 @@ fn report_dry_run(
         Modifications::Exact => extraneous,
     };
 
+    let install_plan = InstallPlan::new(
+        resolution,
+        &cached,
+        &remote,
+        &reinstalls,
+        &extraneous,
+        link_mode,
+    );
+
     // Nothing to do.
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         DefaultInstallLogger.on_audit(resolution.len(), start, printer)?;
         writeln!(printer.stderr(), "Would make no changes")?;
-        return Ok(());
+        return Ok(install_plan);
     }
 
     // Download, build, and unzip any missing distributions.
@@ -833,7 +1349,7 @@ fn report_dry_run(
         return Err(Error::OutdatedEnvironment);
     }
 
-    Ok(())
+    Ok(install_plan)
 }
 
 /// Report any diagnostics on resolved distributions.
@@ -892,6 +1408,12 @@ pub(crate) enum Error {
     #[error(transparent)]
     Uninstall(#[from] uv_installer::UninstallError),
 
+    #[error(transparent)]
+    Transaction(#[from] uv_installer::TransactionError),
+
+    #[error(transparent)]
+    Reproducible(#[from] uv_installer::ReproducibleError),
+
     #[error(transparent)]
     Hash(#[from] uv_types::HashStrategyError),
 
@@ -909,4 +1431,13 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment,
+
+    #[error("Refusing to download {size}, which exceeds the maximum download size of {max}")]
+    DownloadSizeExceeded { size: String, max: String },
+
+    #[error("Download cancelled")]
+    DownloadDeclined,
+
+    #[error("Refusing to modify packages that are managed by Conda: {0}\n\n{hint}{colon} use `--force` to modify them anyway, or update them with `conda` instead", hint = "hint".bold().cyan(), colon = ":".bold())]
+    CondaManaged(String),
 }
@@ -0,0 +1,273 @@
+//! A JSON report of the distributions resolved by `uv pip install`, following the format of pip's
+//! [installation report](https://pip.pypa.io/en/stable/reference/installation-report/).
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use uv_distribution_types::{
+    BuiltDist, Dist, Name, Resolution, ResolvedDist, SourceDist, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
+};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerEnvironment;
+use uv_pypi_types::HashDigest;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::commands::pip::operations::{Changelog, InstallSource};
+use crate::printer::Printer;
+
+/// The packages that were explicitly requested, by name or by URL.
+#[derive(Debug, Default)]
+pub(crate) struct RequestedPackages {
+    names: FxHashSet<PackageName>,
+    urls: FxHashSet<DisplaySafeUrl>,
+}
+
+impl RequestedPackages {
+    /// Collect the requested packages from the top-level requirements.
+    pub(crate) fn from_requirements(requirements: &[UnresolvedRequirementSpecification]) -> Self {
+        let mut requested = Self::default();
+        for entry in requirements {
+            match &entry.requirement {
+                UnresolvedRequirement::Named(requirement) => {
+                    requested.names.insert(requirement.name.clone());
+                }
+                UnresolvedRequirement::Unnamed(requirement) => {
+                    requested.urls.insert(requirement.url.verbatim.to_url());
+                }
+            }
+        }
+        requested
+    }
+
+    /// Returns `true` if the distribution was requested by name or URL.
+    fn contains(&self, dist: &ResolvedDist) -> bool {
+        if self.names.contains(dist.name()) {
+            return true;
+        }
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            return false;
+        };
+        let url = match dist.as_ref() {
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => &wheel.url,
+            Dist::Built(BuiltDist::Path(wheel)) => &wheel.url,
+            Dist::Source(SourceDist::DirectUrl(sdist)) => &sdist.url,
+            Dist::Source(SourceDist::Git(sdist)) => &sdist.url,
+            Dist::Source(SourceDist::Path(sdist)) => &sdist.url,
+            Dist::Source(SourceDist::Directory(sdist)) => &sdist.url,
+            Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_)) => {
+                return false;
+            }
+        };
+        self.urls.contains(&url.to_url())
+    }
+}
+
+/// An installation report, in the format of pip's `--report`.
+#[derive(Debug, Serialize)]
+struct InstallationReport<'a> {
+    /// The version of the report format.
+    version: &'static str,
+    /// The version of uv that produced the report.
+    uv_version: &'static str,
+    /// The resolved distributions.
+    install: Vec<InstallationReportItem>,
+    /// The marker environment of the target interpreter.
+    environment: &'a MarkerEnvironment,
+}
+
+/// A resolved distribution in an [`InstallationReport`].
+#[derive(Debug, Serialize)]
+struct InstallationReportItem {
+    /// The core metadata of the distribution.
+    metadata: ItemMetadata,
+    /// The artifact chosen for the distribution, omitted if the resolver used the installed
+    /// distribution as-is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    download_info: Option<DownloadInfo>,
+    /// Whether the distribution was requested via a direct URL or path.
+    is_direct: bool,
+    /// Whether the distribution was explicitly requested, rather than a dependency.
+    requested: bool,
+    /// How the distribution was obtained (a uv-specific field).
+    source: ItemSource,
+}
+
+#[derive(Debug, Serialize)]
+struct ItemMetadata {
+    name: PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<Version>,
+}
+
+/// The origin of an artifact, in the format of the `direct_url.json` data structure.
+#[derive(Debug, Serialize)]
+struct DownloadInfo {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archive_info: Option<ArchiveInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_info: Option<VcsInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dir_info: Option<DirInfo>,
+}
+
+#[derive(Debug, Serialize)]
+struct ArchiveInfo {
+    hashes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct VcsInfo {
+    vcs: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requested_revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct DirInfo {
+    editable: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ItemSource {
+    /// A wheel was downloaded.
+    Downloaded,
+    /// A wheel was built from source.
+    Built,
+    /// A wheel was served from the cache.
+    Cached,
+    /// The distribution was already installed in the environment.
+    AlreadyInstalled,
+}
+
+impl From<InstallSource> for ItemSource {
+    fn from(source: InstallSource) -> Self {
+        match source {
+            InstallSource::Downloaded => Self::Downloaded,
+            InstallSource::Built => Self::Built,
+            InstallSource::Cached => Self::Cached,
+        }
+    }
+}
+
+impl DownloadInfo {
+    fn from_dist(dist: &Dist, hashes: &[HashDigest]) -> Self {
+        let archive = |url: String| Self {
+            url,
+            archive_info: Some(ArchiveInfo {
+                hashes: hashes
+                    .iter()
+                    .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+                    .collect(),
+            }),
+            vcs_info: None,
+            dir_info: None,
+        };
+        match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let url = &wheels.best_wheel().file.url;
+                archive(
+                    url.to_url()
+                        .map_or_else(|_| url.to_string(), |url| url.to_string()),
+                )
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => archive(wheel.url.to_url().to_string()),
+            Dist::Built(BuiltDist::Path(wheel)) => archive(wheel.url.to_url().to_string()),
+            Dist::Source(SourceDist::Registry(sdist)) => {
+                let url = &sdist.file.url;
+                archive(
+                    url.to_url()
+                        .map_or_else(|_| url.to_string(), |url| url.to_string()),
+                )
+            }
+            Dist::Source(SourceDist::DirectUrl(sdist)) => archive(sdist.url.to_url().to_string()),
+            Dist::Source(SourceDist::Path(sdist)) => archive(sdist.url.to_url().to_string()),
+            Dist::Source(SourceDist::Git(sdist)) => Self {
+                url: sdist.git.repository().to_string(),
+                archive_info: None,
+                vcs_info: Some(VcsInfo {
+                    vcs: "git",
+                    requested_revision: sdist.git.reference().as_str().map(ToString::to_string),
+                    commit_id: sdist.git.precise().map(|oid| oid.to_string()),
+                }),
+                dir_info: None,
+            },
+            Dist::Source(SourceDist::Directory(sdist)) => Self {
+                url: sdist.url.to_url().to_string(),
+                archive_info: None,
+                vcs_info: None,
+                dir_info: Some(DirInfo {
+                    editable: sdist.editable.unwrap_or(false),
+                }),
+            },
+        }
+    }
+}
+
+/// Write an installation report for the [`Resolution`] to the given path, or to stdout if the
+/// path is `-`.
+pub(crate) fn write_report(
+    path: &Path,
+    resolution: &Resolution,
+    changelog: &Changelog,
+    requested: &RequestedPackages,
+    markers: &MarkerEnvironment,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let mut install = resolution
+        .hashes()
+        .map(|(dist, hashes)| {
+            let (download_info, is_direct) = match dist {
+                ResolvedDist::Installable { dist: inner, .. } => (
+                    Some(DownloadInfo::from_dist(inner, hashes)),
+                    !matches!(
+                        inner.as_ref(),
+                        Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+                    ),
+                ),
+                ResolvedDist::Installed { .. } => (None, false),
+            };
+            let source = changelog
+                .sources
+                .get(dist.name())
+                .map_or(ItemSource::AlreadyInstalled, |source| {
+                    ItemSource::from(*source)
+                });
+            InstallationReportItem {
+                metadata: ItemMetadata {
+                    name: dist.name().clone(),
+                    version: dist.version().cloned(),
+                },
+                download_info,
+                is_direct,
+                requested: requested.contains(dist),
+                source,
+            }
+        })
+        .collect::<Vec<_>>();
+    install.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+    let report = InstallationReport {
+        version: "1",
+        uv_version: uv_version::version(),
+        install,
+        environment: markers,
+    };
+    let report = serde_json::to_string_pretty(&report)?;
+
+    if path == Path::new("-") {
+        writeln!(printer.stdout(), "{report}")?;
+    } else {
+        fs_err::write(path, format!("{report}\n"))?;
+    }
+    Ok(())
+}
@@ -1,12 +1,17 @@
+use std::collections::{BTreeSet, VecDeque};
 use std::fmt::Write;
+use std::io;
+use std::path::Path;
 
 use anyhow::Result;
 use fs_err::File;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use uv_cache::Cache;
+use uv_cli::ShowFormat;
 use uv_configuration::PreviewMode;
 use uv_distribution_types::{Diagnostic, Name};
 use uv_fs::Simplified;
@@ -20,12 +25,16 @@ use crate::commands::pip::operations::report_target_environment;
 use crate::printer::Printer;
 
 /// Show information about one or more installed packages.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_show(
     mut packages: Vec<PackageName>,
     strict: bool,
     python: Option<&str>,
     system: bool,
     files: bool,
+    entry_points: bool,
+    reverse: bool,
+    format: ShowFormat,
     cache: &Cache,
     printer: Printer,
     preview: PreviewMode,
@@ -127,73 +136,69 @@ pub(crate) fn pip_show(
         }
     }
 
-    // Print the information for each package.
-    for (i, distribution) in distributions.iter().enumerate() {
-        if i > 0 {
-            // Print a separator between packages.
-            writeln!(printer.stdout(), "---")?;
-        }
-
-        // Print the name, version, and location (e.g., the `site-packages` directory).
-        writeln!(printer.stdout(), "Name: {}", distribution.name())?;
-        writeln!(printer.stdout(), "Version: {}", distribution.version())?;
-        writeln!(
-            printer.stdout(),
-            "Location: {}",
-            distribution
-                .install_path()
-                .parent()
-                .expect("package path is not root")
-                .simplified_display()
-        )?;
-
-        if let Some(path) = distribution
-            .as_editable()
-            .and_then(|url| url.to_file_path().ok())
-        {
-            writeln!(
-                printer.stdout(),
-                "Editable project location: {}",
-                path.simplified_display()
-            )?;
-        }
-
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
-            } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
-
-            let required_by = requires_map
+    // Collect the information for each package.
+    let mut entries = Vec::with_capacity(distributions.len());
+    for distribution in &distributions {
+        let requires = requires_map.get(distribution.name());
+        let required_by = requires.map(|_| {
+            requires_map
                 .iter()
                 .filter(|(name, pkgs)| {
                     **name != distribution.name()
                         && pkgs.iter().any(|pkg| pkg == distribution.name())
                 })
-                .map(|(name, _)| name)
+                .map(|(name, _)| (*name).clone())
                 .sorted_unstable()
                 .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
-            } else {
-                writeln!(
-                    printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
-                )?;
-            }
-        }
-
-        // If requests, show the list of installed files.
-        if files {
+                .collect_vec()
+        });
+        let reverse_dependencies =
+            reverse.then(|| reverse_dependencies(distribution.name(), &requires_map));
+        let files = if files {
             let path = distribution.install_path().join("RECORD");
             let record = read_record_file(&mut File::open(path)?)?;
-            writeln!(printer.stdout(), "Files:")?;
-            for entry in record {
-                writeln!(printer.stdout(), "  {}", entry.path)?;
+            Some(record.into_iter().map(|entry| entry.path).collect_vec())
+        } else {
+            None
+        };
+        let entry_points = if entry_points {
+            Some(read_entry_points(distribution.install_path())?)
+        } else {
+            None
+        };
+
+        entries.push(Entry {
+            name: distribution.name().clone(),
+            version: distribution.version().to_string(),
+            location: distribution
+                .install_path()
+                .parent()
+                .expect("package path is not root")
+                .simplified_display()
+                .to_string(),
+            editable_project_location: distribution
+                .as_editable()
+                .and_then(|url| url.to_file_path().ok())
+                .map(|path| path.simplified_display().to_string()),
+            requires: requires.cloned(),
+            required_by,
+            reverse_dependencies,
+            entry_points,
+            files,
+        });
+    }
+
+    match format {
+        ShowFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+        }
+        ShowFormat::Text => {
+            for (i, entry) in entries.iter().enumerate() {
+                if i > 0 {
+                    // Print a separator between packages.
+                    writeln!(printer.stdout(), "---")?;
+                }
+                write_entry(entry, printer)?;
             }
         }
     }
@@ -213,3 +218,150 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// The information shown for an installed package.
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: PackageName,
+    version: String,
+    location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires: Option<Vec<PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_by: Option<Vec<PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reverse_dependencies: Option<Vec<PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entry_points: Option<Vec<EntryPoint>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<String>>,
+}
+
+/// An entry point declared in the `entry_points.txt` of an installed package.
+#[derive(Debug, Serialize)]
+struct EntryPoint {
+    group: String,
+    name: String,
+    value: String,
+}
+
+/// Print the information for a package, in the `pip show` format.
+fn write_entry(entry: &Entry, printer: Printer) -> Result<()> {
+    // Print the name, version, and location (e.g., the `site-packages` directory).
+    writeln!(printer.stdout(), "Name: {}", entry.name)?;
+    writeln!(printer.stdout(), "Version: {}", entry.version)?;
+    writeln!(printer.stdout(), "Location: {}", entry.location)?;
+
+    if let Some(path) = &entry.editable_project_location {
+        writeln!(printer.stdout(), "Editable project location: {path}")?;
+    }
+
+    // If available, print the requirements.
+    if let Some(requires) = &entry.requires {
+        write_field("Requires", requires, printer)?;
+    }
+    if let Some(required_by) = &entry.required_by {
+        write_field("Required-by", required_by, printer)?;
+    }
+
+    // If requested, show the packages that depend on the package, directly or transitively.
+    if let Some(reverse_dependencies) = &entry.reverse_dependencies {
+        write_field("Reverse-dependencies", reverse_dependencies, printer)?;
+    }
+
+    // If requested, show the declared entry points, grouped as in `entry_points.txt`.
+    if let Some(entry_points) = &entry.entry_points {
+        writeln!(printer.stdout(), "Entry-points:")?;
+        for (group, entry_points) in &entry_points
+            .iter()
+            .chunk_by(|entry_point| &entry_point.group)
+        {
+            writeln!(printer.stdout(), "  [{group}]")?;
+            for entry_point in entry_points {
+                writeln!(
+                    printer.stdout(),
+                    "  {} = {}",
+                    entry_point.name,
+                    entry_point.value
+                )?;
+            }
+        }
+    }
+
+    // If requested, show the list of installed files.
+    if let Some(files) = &entry.files {
+        writeln!(printer.stdout(), "Files:")?;
+        for file in files {
+            writeln!(printer.stdout(), "  {file}")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a comma-separated list of package names, e.g., `Requires: anyio, idna`.
+fn write_field(field: &str, names: &[PackageName], printer: Printer) -> Result<()> {
+    if names.is_empty() {
+        writeln!(printer.stdout(), "{field}:")?;
+    } else {
+        writeln!(printer.stdout(), "{field}: {}", names.iter().join(", "))?;
+    }
+    Ok(())
+}
+
+/// The installed packages that depend on `name`, directly or through other installed packages.
+fn reverse_dependencies(
+    name: &PackageName,
+    requires_map: &FxHashMap<&PackageName, Vec<PackageName>>,
+) -> Vec<PackageName> {
+    let mut seen = BTreeSet::new();
+    let mut queue = VecDeque::from([name]);
+    while let Some(dependency) = queue.pop_front() {
+        for (dependent, requires) in requires_map {
+            if *dependent != name
+                && requires.contains(dependency)
+                && seen.insert((*dependent).clone())
+            {
+                queue.push_back(*dependent);
+            }
+        }
+    }
+    seen.into_iter().collect()
+}
+
+/// Read the entry points from the `entry_points.txt` in the metadata directory of a package.
+///
+/// Returns an empty list if the package doesn't declare any entry points.
+fn read_entry_points(metadata_dir: &Path) -> Result<Vec<EntryPoint>> {
+    let contents = match fs_err::read_to_string(metadata_dir.join("entry_points.txt")) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut entry_points = Vec::new();
+    let mut group = None;
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            group = Some(section.trim().to_string());
+            continue;
+        }
+        let (Some(group), Some((name, value))) = (&group, line.split_once('=')) else {
+            continue;
+        };
+        entry_points.push(EntryPoint {
+            group: group.clone(),
+            name: name.trim().to_string(),
+            value: value.trim().to_string(),
+        });
+    }
+    Ok(entry_points)
+}
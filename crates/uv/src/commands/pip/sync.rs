@@ -1,25 +1,31 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
-use tracing::{debug, warn};
+use rustc_hash::FxHashMap;
+use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy,
+    BuildOptions, CompileBytecode, Concurrency, ConfigSettings, Constraints, DownloadLimits,
+    DryRun, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallHooks,
+    NoBuildIsolationPackages, PackageConfigSettings, PreviewMode, Reinstall, SourceStrategy,
     Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, Origin, Resolution};
+use uv_distribution_types::{
+    DependencyMetadata, Index, IndexLocations, Origin, Resolution, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
+};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_installer::SitePackages;
-use uv_normalize::{DefaultExtras, DefaultGroups};
-use uv_pep508::PackageName;
+use uv_installer::{CondaPackages, SitePackages};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pep508::RequirementOrigin;
 use uv_pypi_types::Conflicts;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
@@ -38,8 +44,9 @@ use uv_workspace::WorkspaceCache;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::wheelhouse::Wheelhouse;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, diagnostics, optional_lock};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
 
@@ -53,7 +60,8 @@ pub(crate) async fn pip_sync(
     groups: &GroupsSpecification,
     reinstall: Reinstall,
     link_mode: LinkMode,
-    compile: bool,
+    compile: &CompileBytecode,
+    install_hooks: &InstallHooks,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -61,12 +69,13 @@ pub(crate) async fn pip_sync(
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
+    find_links_only: Option<&Path>,
     allow_empty_requirements: bool,
     installer_metadata: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     no_build_isolation: bool,
-    no_build_isolation_package: Vec<PackageName>,
+    no_build_isolation_package: NoBuildIsolationPackages,
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
@@ -75,6 +84,7 @@ pub(crate) async fn pip_sync(
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
+    force: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     sources: SourceStrategy,
@@ -85,12 +95,27 @@ pub(crate) async fn pip_sync(
     printer: Printer,
     preview: PreviewMode,
 ) -> Result<ExitStatus> {
+    // If `--find-links-only` is provided, install exclusively from the given directory, without
+    // accessing the network.
+    let wheelhouse = find_links_only.map(Wheelhouse::from_path).transpose()?;
+    let connectivity = if wheelhouse.is_some() {
+        Connectivity::Offline
+    } else {
+        network_settings.connectivity
+    };
+
     let client_builder = BaseClientBuilder::new()
         .retries_from_env()?
-        .connectivity(network_settings.connectivity)
+        .connectivity(connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Initialize a few defaults.
     let overrides = &[];
@@ -100,12 +125,14 @@ pub(crate) async fn pip_sync(
     let dependency_mode = DependencyMode::Direct;
 
     // Read all requirements from the provided sources.
+    let num_sources = requirements.len();
     let RequirementsSpecification {
         project,
         requirements,
         constraints,
         overrides,
         pylock,
+        uv_lock,
         source_trees,
         groups,
         index_url,
@@ -125,6 +152,14 @@ pub(crate) async fn pip_sync(
     )
     .await?;
 
+    if let Some(uv_lock) = uv_lock {
+        return Err(anyhow::anyhow!(
+            "`uv pip sync` does not support `uv.lock` files, but received: `{}`; use `uv pip install -r {}` instead",
+            uv_lock.user_display(),
+            uv_lock.user_display()
+        ));
+    }
+
     if pylock.is_some() {
         if preview.is_disabled() {
             warn_user!(
@@ -133,6 +168,13 @@ pub(crate) async fn pip_sync(
         }
     }
 
+    // If multiple requirements files are provided, layer them in order.
+    let requirements = if num_sources > 1 {
+        layer_requirements(requirements, printer)?
+    } else {
+        requirements
+    };
+
     // Read build constraints.
     let build_constraints =
         operations::read_constraints(build_constraints, &client_builder).await?;
@@ -215,13 +257,14 @@ pub(crate) async fn pip_sync(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    // If the environment is a Conda environment, avoid modifying any Conda-managed packages.
+    let conda_packages = if force {
+        None
+    } else {
+        CondaPackages::from_environment(&environment)?
+    };
+
+    let _lock = optional_lock(environment.lock().await, "environment")?;
 
     let interpreter = environment.interpreter();
 
@@ -276,6 +319,15 @@ pub(crate) async fn pip_sync(
         no_index,
     );
 
+    // If `--find-links-only` is provided, ignore any other index locations, and ensure that every
+    // requirement can be satisfied by the directory up front.
+    let index_locations = if let Some(wheelhouse) = &wheelhouse {
+        wheelhouse.check_requirements(&requirements, marker_env.markers())?;
+        IndexLocations::new(vec![], vec![wheelhouse.index()], true)
+    } else {
+        index_locations
+    };
+
     index_locations.cache_index_credentials();
 
     // Determine the PyTorch backend.
@@ -418,6 +470,11 @@ pub(crate) async fn pip_sync(
             &tags,
             &build_options,
         )?;
+        let resolution = if let Some(wheelhouse) = &wheelhouse {
+            wheelhouse.apply(resolution, &tags)?
+        } else {
+            resolution
+        };
         let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
 
         (resolution, hasher)
@@ -485,6 +542,7 @@ pub(crate) async fn pip_sync(
         &build_options,
         link_mode,
         compile,
+        install_hooks,
         &index_locations,
         config_settings,
         config_settings_package,
@@ -496,8 +554,10 @@ pub(crate) async fn pip_sync(
         &build_dispatch,
         &cache,
         &environment,
+        conda_packages.as_ref(),
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        &DownloadLimits::default(),
         dry_run,
         printer,
     )
@@ -521,3 +581,85 @@ pub(crate) async fn pip_sync(
 
     Ok(ExitStatus::Success)
 }
+
+/// Layer the requirements read from multiple files, in the order in which the files were read.
+///
+/// If a package is required by more than one file, the requirements from the last such file
+/// replace those from the earlier files. The file that contributed each requirement is reported.
+fn layer_requirements(
+    requirements: Vec<UnresolvedRequirementSpecification>,
+    printer: Printer,
+) -> Result<Vec<UnresolvedRequirementSpecification>> {
+    // Determine the last file to require each package.
+    let mut layers: FxHashMap<PackageName, Option<RequirementOrigin>> = FxHashMap::default();
+    for entry in &requirements {
+        if let UnresolvedRequirement::Named(requirement) = &entry.requirement {
+            layers.insert(requirement.name.clone(), requirement.origin.clone());
+        }
+    }
+
+    let (requirements, replaced): (Vec<_>, Vec<_>) =
+        requirements
+            .into_iter()
+            .partition(|entry| match &entry.requirement {
+                UnresolvedRequirement::Named(requirement) => {
+                    layers.get(&requirement.name) == Some(&requirement.origin)
+                }
+                UnresolvedRequirement::Unnamed(_) => true,
+            });
+
+    // Group the requirements by the file that contributed them, preserving the order of the files.
+    let mut files: Vec<(Option<&RequirementOrigin>, Vec<String>)> = Vec::new();
+    for entry in &requirements {
+        let origin = match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => requirement.origin.as_ref(),
+            UnresolvedRequirement::Unnamed(requirement) => requirement.origin.as_ref(),
+        };
+        let requirement = entry.requirement.to_string();
+        if let Some((_, requirements)) = files.iter_mut().find(|(file, _)| *file == origin) {
+            requirements.push(requirement);
+        } else {
+            files.push((origin, vec![requirement]));
+        }
+    }
+
+    let s = if files.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!("Layering requirements from {} file{s}:", files.len()).dimmed()
+    )?;
+    for (origin, requirements) in files {
+        writeln!(
+            printer.stderr(),
+            " {} {}",
+            format!("{}:", display_origin(origin)).bold(),
+            requirements.join(", ")
+        )?;
+    }
+    for entry in replaced {
+        let UnresolvedRequirement::Named(requirement) = entry.requirement else {
+            continue;
+        };
+        let layer = display_origin(layers.get(&requirement.name).and_then(Option::as_ref));
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Replaced `{requirement}` from {} with the requirements from {layer}",
+                display_origin(requirement.origin.as_ref())
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(requirements)
+}
+
+/// Display the file from which a requirement was read.
+fn display_origin(origin: Option<&RequirementOrigin>) -> String {
+    match origin {
+        Some(origin) => format!("`{}`", origin.path().user_display()),
+        None => "the command line".to_string(),
+    }
+}
@@ -8,10 +8,12 @@ use petgraph::Direction;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::EdgeRef;
 use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use tokio::sync::Semaphore;
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::TreeFormat;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType, PreviewMode};
 use uv_distribution_types::{Diagnostic, IndexCapabilities, IndexLocations, Name, RequiresPython};
@@ -34,6 +36,7 @@ use crate::settings::NetworkSettings;
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_tree(
     show_version_specifiers: bool,
+    output_format: TreeFormat,
     depth: u8,
     prune: &[PackageName],
     package: &[PackageName],
@@ -90,7 +93,13 @@ pub(crate) async fn pip_tree(
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .keyring(keyring_provider)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            );
 
         // Initialize the registry client.
         let client = RegistryClientBuilder::try_from(client_builder)?
@@ -148,8 +157,7 @@ pub(crate) async fn pip_tree(
         FxHashMap::default()
     };
 
-    // Render the tree.
-    let rendered_tree = DisplayDependencyGraph::new(
+    let graph = DisplayDependencyGraph::new(
         depth.into(),
         prune,
         package,
@@ -159,19 +167,28 @@ pub(crate) async fn pip_tree(
         &markers,
         &packages,
         &latest,
-    )
-    .render()
-    .join("\n");
+    );
 
-    writeln!(printer.stdout(), "{rendered_tree}")?;
+    // Render the tree.
+    match output_format {
+        TreeFormat::Text => {
+            let rendered_tree = graph.render().join("\n");
 
-    if rendered_tree.contains("(*)") {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
-            "(*) Package tree already displayed".italic()
-        };
-        writeln!(printer.stdout(), "{message}")?;
+            writeln!(printer.stdout(), "{rendered_tree}")?;
+
+            if rendered_tree.contains("(*)") {
+                let message = if no_dedupe {
+                    "(*) Package tree is a cycle and cannot be shown".italic()
+                } else {
+                    "(*) Package tree already displayed".italic()
+                };
+                writeln!(printer.stdout(), "{message}")?;
+            }
+        }
+        TreeFormat::Json => {
+            let rendered_tree = serde_json::to_string_pretty(&graph.render_json())?;
+            writeln!(printer.stdout(), "{rendered_tree}")?;
+        }
     }
 
     // Validate that the environment is consistent.
@@ -497,6 +514,90 @@ impl<'env> DisplayDependencyGraph<'env> {
         lines
     }
 
+    /// Perform a depth-first traversal of the given distribution and its dependencies, mirroring
+    /// [`DisplayDependencyGraph::visit`] for the JSON representation.
+    fn visit_json(
+        &self,
+        cursor: Cursor,
+        visited: &mut FxHashMap<&'env PackageName, Vec<PackageName>>,
+        path: &mut Vec<&'env PackageName>,
+    ) -> Option<TreeNode<'env>> {
+        // Short-circuit if the current path is longer than the provided depth.
+        if path.len() > self.depth {
+            return None;
+        }
+
+        let metadata = self.graph[cursor.node()];
+        let package_name = &metadata.name;
+        let mut node = TreeNode {
+            name: package_name,
+            version: &metadata.version,
+            requirement: cursor.edge().map(|edge| self.graph[edge].to_string()),
+            latest_version: None,
+            deduplicated: false,
+            dependencies: Vec::new(),
+        };
+
+        // Skip the traversal if the package is part of a cycle, or has already been visited and
+        // de-duplication is enabled.
+        if let Some(requirements) = visited.get(package_name) {
+            if !self.no_dedupe || path.contains(&package_name) {
+                node.deduplicated = !requirements.is_empty();
+                return Some(node);
+            }
+        }
+
+        // Incorporate the latest version of the package, if known.
+        node.latest_version = self
+            .latest
+            .get(package_name)
+            .filter(|&version| *version > metadata.version);
+
+        let mut dependencies = self
+            .graph
+            .edges_directed(cursor.node(), Direction::Outgoing)
+            .map(|edge| Cursor::new(edge.target(), edge.id()))
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|node| {
+            let metadata = &self.graph[node.node()];
+            (&metadata.name, &metadata.version)
+        });
+
+        // Keep track of the dependency path to avoid cycles.
+        visited.insert(
+            package_name,
+            dependencies
+                .iter()
+                .map(|node| self.graph[node.node()].name.clone())
+                .collect(),
+        );
+        path.push(package_name);
+
+        node.dependencies = dependencies
+            .into_iter()
+            .filter_map(|dep| self.visit_json(dep, visited, path))
+            .collect();
+
+        path.pop();
+
+        Some(node)
+    }
+
+    /// Depth-first traverse the nodes to render the tree as a list of root packages.
+    pub(crate) fn render_json(&self) -> Vec<TreeNode<'env>> {
+        let mut path = Vec::new();
+        let mut visited =
+            FxHashMap::with_capacity_and_hasher(self.graph.node_count(), rustc_hash::FxBuildHasher);
+
+        self.roots
+            .iter()
+            .filter_map(|node| {
+                path.clear();
+                self.visit_json(Cursor::root(*node), &mut visited, &mut path)
+            })
+            .collect()
+    }
+
     /// Depth-first traverse the nodes to render the tree.
     pub(crate) fn render(&self) -> Vec<String> {
         let mut path = Vec::new();
@@ -513,6 +614,26 @@ impl<'env> DisplayDependencyGraph<'env> {
     }
 }
 
+/// A package in the JSON representation of the dependency tree.
+#[derive(Debug, Serialize)]
+pub(crate) struct TreeNode<'env> {
+    name: &'env PackageName,
+    version: &'env Version,
+    /// The requirement on the edge that led to the package, or `None` for root packages. In an
+    /// inverted tree, this is the requirement that the package places on its parent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirement: Option<String>,
+    /// The latest version of the package, if newer than the installed version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_version: Option<&'env Version>,
+    /// Whether the dependencies of the package were omitted, because they were already displayed
+    /// or would form a cycle.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deduplicated: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<TreeNode<'env>>,
+}
+
 /// A node in the dependency graph along with the edge that led to it, or `None` for root nodes.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
 struct Cursor(NodeIndex, Option<EdgeIndex>);
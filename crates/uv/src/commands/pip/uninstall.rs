@@ -3,7 +3,7 @@ use std::fmt::Write;
 use anyhow::Result;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
-use tracing::{debug, warn};
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
@@ -11,6 +11,7 @@ use uv_configuration::{DryRun, KeyringProviderType, PreviewMode};
 use uv_distribution_types::Requirement;
 use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
+use uv_installer::CondaPackages;
 use uv_pep508::UnnamedRequirement;
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::EnvironmentPreference;
@@ -18,8 +19,9 @@ use uv_python::PythonRequest;
 use uv_python::{Prefix, PythonEnvironment, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
+use crate::commands::pip::operations;
 use crate::commands::pip::operations::report_target_environment;
-use crate::commands::{ExitStatus, elapsed};
+use crate::commands::{ExitStatus, elapsed, optional_lock};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
 
@@ -30,6 +32,7 @@ pub(crate) async fn pip_uninstall(
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
+    force: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     cache: Cache,
@@ -46,7 +49,13 @@ pub(crate) async fn pip_uninstall(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Read all requirements from the provided sources.
     let spec = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
@@ -101,13 +110,7 @@ pub(crate) async fn pip_uninstall(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = optional_lock(environment.lock().await, "environment")?;
 
     // Index the current `site-packages` directory.
     let site_packages = uv_installer::SitePackages::from_environment(&environment)?;
@@ -204,6 +207,22 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    // If the environment is a Conda environment, refuse to remove any Conda-managed packages.
+    if !force && !dry_run.enabled() {
+        if let Some(conda_packages) = CondaPackages::from_environment(&environment)? {
+            let managed = distributions
+                .iter()
+                .filter(|dist| conda_packages.contains(dist))
+                .map(|dist| format!("`{}`", dist.name()))
+                .sorted()
+                .dedup()
+                .collect::<Vec<_>>();
+            if !managed.is_empty() {
+                return Err(operations::Error::CondaManaged(managed.join(", ")).into());
+            }
+        }
+    }
+
     // Uninstall each package.
     if !dry_run.enabled() {
         for distribution in &distributions {
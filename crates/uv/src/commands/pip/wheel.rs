@@ -0,0 +1,386 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Context;
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification, HashCheckingMode,
+    IndexStrategy, KeyringProviderType, NoBuildIsolationPackages, PackageConfigSettings,
+    PreviewMode, Reinstall, SourceStrategy, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{
+    DependencyMetadata, Index, IndexLocations, NameRequirementSpecification, Origin, Requirement,
+    Resolution, ResolvedDist, UnresolvedRequirementSpecification,
+};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_pypi_types::Conflicts;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
+    ResolutionMode, ResolverEnvironment,
+};
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
+use uv_workspace::WorkspaceCache;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+/// Build or download wheels for a set of requirements into a directory.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn pip_wheel(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    constraints_from_workspace: Vec<Requirement>,
+    overrides_from_workspace: Vec<Requirement>,
+    build_constraints_from_workspace: Vec<Requirement>,
+    extras: &ExtrasSpecification,
+    wheel_dir: &Path,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PrereleaseMode,
+    dependency_mode: DependencyMode,
+    upgrade: Upgrade,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    link_mode: LinkMode,
+    hash_checking: Option<HashCheckingMode>,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    no_build_isolation: bool,
+    no_build_isolation_package: NoBuildIsolationPackages,
+    build_options: BuildOptions,
+    exclude_newer: Option<ExcludeNewer>,
+    sources: SourceStrategy,
+    python: Option<String>,
+    system: bool,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> anyhow::Result<ExitStatus> {
+    let client_builder = BaseClientBuilder::new()
+        .retries_from_env()?
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        pylock,
+        uv_lock,
+        source_trees,
+        groups,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+        extras: _,
+    } = operations::read_requirements(
+        requirements,
+        constraints,
+        overrides,
+        extras,
+        None,
+        &client_builder,
+    )
+    .await?;
+
+    if let Some(lock) = pylock.or(uv_lock) {
+        anyhow::bail!(
+            "`uv pip wheel` does not support lockfiles, but received: `{}`",
+            lock.user_display()
+        );
+    }
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
+        .iter()
+        .cloned()
+        .chain(
+            constraints_from_workspace
+                .into_iter()
+                .map(NameRequirementSpecification::from),
+        )
+        .collect();
+
+    let overrides: Vec<UnresolvedRequirementSpecification> = overrides
+        .iter()
+        .cloned()
+        .chain(
+            overrides_from_workspace
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from),
+        )
+        .collect();
+
+    // Read build constraints.
+    let build_constraints: Vec<NameRequirementSpecification> =
+        operations::read_constraints(build_constraints, &client_builder)
+            .await?
+            .into_iter()
+            .chain(
+                build_constraints_from_workspace
+                    .iter()
+                    .cloned()
+                    .map(NameRequirementSpecification::from),
+            )
+            .collect();
+
+    // Detect the current Python interpreter, which the wheels are resolved and built for.
+    let environment = PythonEnvironment::find(
+        &python
+            .as_deref()
+            .map(PythonRequest::parse)
+            .unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(system, false),
+        &cache,
+        preview,
+    )?;
+    report_target_environment(&environment, &cache, printer)?;
+
+    // Determine the markers and tags to use for the resolution.
+    let interpreter = environment.interpreter();
+    let marker_env = resolution_markers(None, None, interpreter);
+    let tags = resolution_tags(None, None, interpreter)?;
+    let python_requirement = PythonRequirement::from_interpreter(interpreter);
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    index_locations.cache_index_credentials();
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_locations(&index_locations)
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options = build_options.combine(no_binary, no_build);
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+    };
+
+    // Determine whether to enable build isolation.
+    let build_isolation = if no_build_isolation {
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&environment, &no_build_isolation_package)
+    };
+
+    // Enforce (but never require) the build constraints, if `--require-hashes` or `--verify-hashes`
+    // is provided. _Requiring_ hashes would be too strict, and would break with pip.
+    let build_hasher = if hash_checking.is_some() {
+        HashStrategy::from_requirements(
+            std::iter::empty(),
+            build_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            HashCheckingMode::Verify,
+        )?
+    } else {
+        HashStrategy::None
+    };
+    let build_constraints = Constraints::from_requirements(
+        build_constraints
+            .iter()
+            .map(|constraint| constraint.requirement.clone()),
+    );
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        build_constraints,
+        interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state.clone(),
+        index_strategy,
+        config_settings,
+        config_settings_package,
+        build_isolation,
+        link_mode,
+        &build_options,
+        &build_hasher,
+        exclude_newer,
+        sources,
+        WorkspaceCache::default(),
+        concurrency,
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .build_options(build_options.clone())
+        .build();
+
+    // Resolve the requirements, ignoring any packages installed in the environment.
+    let resolution = match operations::resolve(
+        requirements,
+        constraints,
+        overrides,
+        source_trees,
+        project,
+        BTreeSet::default(),
+        extras,
+        &groups,
+        Vec::default(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::default(),
+        &upgrade,
+        Some(&tags),
+        ResolverEnvironment::specific(marker_env.clone()),
+        python_requirement,
+        interpreter.markers(),
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        state.index(),
+        &build_dispatch,
+        concurrency,
+        options,
+        Box::new(DefaultResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok(graph) => Resolution::from(graph),
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    // Download or build a wheel for each distribution in the resolution.
+    let start = std::time::Instant::now();
+    fs_err::tokio::create_dir_all(wheel_dir).await?;
+
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency);
+    let mut fetches =
+        futures::stream::iter(resolution.distributions().filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist),
+            ResolvedDist::Installed { .. } => None,
+        }))
+        .map(async |dist| {
+            debug!("Collecting wheel for: {dist}");
+            database
+                .get_or_build_wheel_file(dist, &tags, hasher.get(&**dist), wheel_dir)
+                .await
+                .with_context(|| format!("Failed to collect a wheel for `{dist}`"))
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut wheels = Vec::new();
+    while let Some(wheel) = fetches.next().await.transpose()? {
+        wheels.push(wheel);
+    }
+
+    let s = if wheels.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Collected {} {}",
+            format!("{} wheel{s}", wheels.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    for wheel in wheels
+        .iter()
+        .filter_map(|wheel| wheel.file_name())
+        .map(|name| name.to_string_lossy())
+        .sorted()
+    {
+        writeln!(printer.stderr(), " {} {}", "+".green(), wheel.bold())?;
+    }
+
+    // Notify the user of any resolution diagnostics.
+    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+
+    Ok(ExitStatus::Success)
+}
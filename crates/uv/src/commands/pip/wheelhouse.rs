@@ -0,0 +1,275 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{
+    BuiltDist, Dist, File, FileLocation, Index, IndexUrl, Name, RegistryBuiltDist,
+    RegistryBuiltWheel, RegistrySourceDist, RequirementSource, Resolution, ResolvedDist,
+    SourceDist, UnresolvedRequirement, UnresolvedRequirementSpecification, UrlString,
+};
+use uv_fs::Simplified;
+use uv_pep508::{MarkerEnvironment, VerbatimUrl, VerbatimUrlError};
+use uv_platform_tags::Tags;
+use uv_pypi_types::ParsedUrl;
+use uv_redacted::DisplaySafeUrl;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum WheelhouseError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Url(#[from] VerbatimUrlError),
+
+    #[error(
+        "The following distributions are missing from `{}`:{}",
+        _0.user_display(),
+        _1.iter().map(|artifact| format!("\n  - {artifact}")).join("")
+    )]
+    Missing(PathBuf, Vec<MissingArtifact>),
+}
+
+/// A distribution that can't be satisfied from a [`Wheelhouse`].
+#[derive(Debug)]
+pub(crate) enum MissingArtifact {
+    /// A locked distribution whose file is not present in the directory.
+    File { package: String, filename: String },
+    /// A requirement that isn't satisfied by any distribution in the directory.
+    Requirement(String),
+    /// A distribution that can only be fetched over the network (e.g., a Git dependency).
+    Remote(String),
+}
+
+impl Display for MissingArtifact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File { package, filename } => write!(f, "{package} (expected `{filename}`)"),
+            Self::Requirement(requirement) => write!(f, "{requirement}"),
+            Self::Remote(package) => write!(f, "{package} (requires network access)"),
+        }
+    }
+}
+
+/// A local directory of distributions (e.g., as populated by `pip download` or `pip wheel`), used
+/// to satisfy an installation without network access, as with `--find-links-only`.
+#[derive(Debug)]
+pub(crate) struct Wheelhouse {
+    /// The absolute path to the directory.
+    root: PathBuf,
+    /// The directory as a `--find-links` index.
+    url: IndexUrl,
+    /// The files in the directory, keyed by filename.
+    files: FxHashMap<String, PathBuf>,
+}
+
+impl Wheelhouse {
+    /// Read the [`Wheelhouse`] at the given path.
+    pub(crate) fn from_path(path: &Path) -> Result<Self, WheelhouseError> {
+        let root = std::path::absolute(path)?;
+
+        let mut files = FxHashMap::default();
+        for entry in fs_err::read_dir(&root)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let Ok(filename) = entry.file_name().into_string() else {
+                continue;
+            };
+            files.insert(filename, entry.path());
+        }
+
+        let url = IndexUrl::from(VerbatimUrl::from_absolute_path(&root)?);
+
+        Ok(Self { root, url, files })
+    }
+
+    /// Return the directory as a `--find-links` [`Index`].
+    pub(crate) fn index(&self) -> Index {
+        Index::from_find_links(self.url.clone())
+    }
+
+    /// Rewrite the registry distributions in a [`Resolution`] (e.g., as read from a lockfile) to
+    /// be installed from the files in this directory.
+    ///
+    /// Returns an error listing every distribution that can't be satisfied locally.
+    pub(crate) fn apply(
+        &self,
+        resolution: Resolution,
+        tags: &Tags,
+    ) -> Result<Resolution, WheelhouseError> {
+        let mut missing = Vec::new();
+        let mut replacements = FxHashMap::default();
+
+        for dist in resolution.distributions() {
+            let ResolvedDist::Installable { dist, version } = dist else {
+                continue;
+            };
+            let package = if let Some(version) = version {
+                format!("{}=={version}", dist.name())
+            } else {
+                dist.name().to_string()
+            };
+
+            match dist.as_ref() {
+                Dist::Built(BuiltDist::Registry(built)) => {
+                    // Prefer the wheel that would've been selected from the registry, but accept
+                    // any other compatible wheel in the lockfile.
+                    let best = built.best_wheel();
+                    let wheel = std::iter::once(best)
+                        .chain(
+                            built
+                                .wheels
+                                .iter()
+                                .filter(|wheel| wheel.filename.is_compatible(tags)),
+                        )
+                        .find_map(|wheel| {
+                            Some(RegistryBuiltWheel {
+                                filename: wheel.filename.clone(),
+                                file: self.localize(&wheel.file)?,
+                                index: wheel.index.clone(),
+                            })
+                        });
+                    if let Some(wheel) = wheel {
+                        replacements.insert(
+                            dist.name().clone(),
+                            Dist::Built(BuiltDist::Registry(RegistryBuiltDist {
+                                wheels: vec![wheel],
+                                best_wheel_index: 0,
+                                sdist: None,
+                            })),
+                        );
+                    } else {
+                        missing.push(MissingArtifact::File {
+                            package,
+                            filename: best.filename.to_string(),
+                        });
+                    }
+                }
+                Dist::Source(SourceDist::Registry(sdist)) => {
+                    if let Some(file) = self.localize(&sdist.file) {
+                        replacements.insert(
+                            dist.name().clone(),
+                            Dist::Source(SourceDist::Registry(RegistrySourceDist {
+                                file,
+                                ..sdist.clone()
+                            })),
+                        );
+                    } else {
+                        missing.push(MissingArtifact::File {
+                            package,
+                            filename: sdist.file.filename.to_string(),
+                        });
+                    }
+                }
+                Dist::Built(BuiltDist::DirectUrl(built)) => {
+                    missing.push(MissingArtifact::Remote(format!(
+                        "{} @ {}",
+                        dist.name(),
+                        built.url
+                    )));
+                }
+                Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                    missing.push(MissingArtifact::Remote(format!(
+                        "{} @ {}",
+                        dist.name(),
+                        sdist.url
+                    )));
+                }
+                Dist::Source(SourceDist::Git(sdist)) => {
+                    missing.push(MissingArtifact::Remote(format!(
+                        "{} @ {}",
+                        dist.name(),
+                        sdist.url
+                    )));
+                }
+                // Local distributions don't require network access.
+                Dist::Built(BuiltDist::Path(_))
+                | Dist::Source(SourceDist::Path(_) | SourceDist::Directory(_)) => {}
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(WheelhouseError::Missing(self.root.clone(), missing));
+        }
+
+        Ok(resolution.map(|dist| {
+            let ResolvedDist::Installable { dist, version } = dist else {
+                return None;
+            };
+            let replacement = replacements.get(dist.name())?;
+            Some(ResolvedDist::Installable {
+                dist: Arc::new(replacement.clone()),
+                version: version.clone(),
+            })
+        }))
+    }
+
+    /// Validate that every requirement that applies to the given environment can be satisfied by
+    /// a distribution in this directory.
+    ///
+    /// Returns an error listing every unsatisfied requirement.
+    pub(crate) fn check_requirements(
+        &self,
+        requirements: &[UnresolvedRequirementSpecification],
+        markers: &MarkerEnvironment,
+    ) -> Result<(), WheelhouseError> {
+        let mut missing = Vec::new();
+
+        for entry in requirements {
+            match &entry.requirement {
+                UnresolvedRequirement::Named(requirement) => {
+                    if !requirement.evaluate_markers(Some(markers), &[]) {
+                        continue;
+                    }
+                    match &requirement.source {
+                        RequirementSource::Registry { specifier, .. } => {
+                            let satisfied = self.files.keys().any(|filename| {
+                                DistFilename::try_from_filename(filename, &requirement.name)
+                                    .is_some_and(|filename| specifier.contains(filename.version()))
+                            });
+                            if !satisfied {
+                                missing.push(MissingArtifact::Requirement(requirement.to_string()));
+                            }
+                        }
+                        RequirementSource::Url { .. } | RequirementSource::Git { .. } => {
+                            missing.push(MissingArtifact::Remote(requirement.to_string()));
+                        }
+                        RequirementSource::Path { .. } | RequirementSource::Directory { .. } => {}
+                    }
+                }
+                UnresolvedRequirement::Unnamed(requirement) => {
+                    if !requirement.evaluate_markers(markers, &[]) {
+                        continue;
+                    }
+                    match &requirement.url.parsed_url {
+                        ParsedUrl::Archive(_) | ParsedUrl::Git(_) => {
+                            missing.push(MissingArtifact::Remote(requirement.to_string()));
+                        }
+                        ParsedUrl::Path(_) | ParsedUrl::Directory(_) => {}
+                    }
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(WheelhouseError::Missing(self.root.clone(), missing));
+        }
+
+        Ok(())
+    }
+
+    /// Return a copy of the [`File`] that points to the matching file in this directory, if any.
+    fn localize(&self, file: &File) -> Option<Box<File>> {
+        let path = self.files.get(file.filename.as_ref())?;
+        let url = DisplaySafeUrl::from_file_path(path).ok()?;
+        Some(Box::new(File {
+            url: FileLocation::AbsoluteUrl(UrlString::from(url)),
+            ..file.clone()
+        }))
+    }
+}
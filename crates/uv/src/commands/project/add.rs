@@ -10,16 +10,16 @@ use anyhow::{Context, Result, bail};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
-use tracing::{debug, warn};
+use tracing::debug;
 use url::Url;
 
 use uv_cache::Cache;
 use uv_cache_key::RepositoryUrl;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DevMode, DryRun,
-    EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, InstallOptions,
-    PreviewMode, SourceStrategy,
+    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DevMode,
+    DownloadLimits, DryRun, EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults,
+    InstallOptions, PreviewMode, SourceStrategy,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
@@ -30,7 +30,7 @@ use uv_distribution_types::{
 use uv_fs::{LockedFile, Simplified};
 use uv_git::GIT_STORE;
 use uv_git_types::GitReference;
-use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, PackageName};
+use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, GroupName, PackageName};
 use uv_pep508::{ExtraName, MarkerTree, UnnamedRequirement, VersionOrUrl};
 use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
@@ -41,7 +41,10 @@ use uv_scripts::{Pep723ItemRef, Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user_once;
-use uv_workspace::pyproject::{DependencyType, Source, SourceError, Sources, ToolUvSources};
+use uv_workspace::dependency_groups::FlatDependencyGroups;
+use uv_workspace::pyproject::{
+    DependencyType, PyProjectToml, Source, SourceError, Sources, ToolUvSources,
+};
 use uv_workspace::pyproject_mut::{AddBoundsKind, ArrayEdit, DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
 
@@ -57,7 +60,7 @@ use crate::commands::project::{
     UniversalState, default_dependency_groups, init_script_python_requirement,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
-use crate::commands::{ExitStatus, ScriptPath, diagnostics, project};
+use crate::commands::{ExitStatus, ScriptPath, diagnostics, optional_lock, project};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
@@ -74,8 +77,10 @@ pub(crate) async fn add(
     marker: Option<MarkerTree>,
     editable: Option<bool>,
     dependency_type: DependencyType,
+    include_groups: Vec<GroupName>,
     raw: bool,
     bounds: Option<AddBoundsKind>,
+    download_limits: DownloadLimits,
     indexes: Vec<Index>,
     rev: Option<String>,
     tag: Option<String>,
@@ -115,6 +120,9 @@ pub(crate) async fn add(
             RequirementsSource::PylockToml(_) => {
                 bail!("Adding requirements from a `pylock.toml` is not supported in `uv add`");
             }
+            RequirementsSource::UvLock(_) => {
+                bail!("Adding requirements from a `uv.lock` is not supported in `uv add`");
+            }
             RequirementsSource::Package(_)
             | RequirementsSource::Editable(_)
             | RequirementsSource::RequirementsTxt(_)
@@ -179,7 +187,13 @@ pub(crate) async fn add(
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            );
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
         // metadata tag.
@@ -321,20 +335,20 @@ pub(crate) async fn add(
         }
     };
 
-    let _lock = target
-        .acquire_lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = optional_lock(target.acquire_lock().await, "environment")?;
 
     let client_builder = BaseClientBuilder::new()
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(settings.resolver.keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Read the requirements.
     let RequirementsSpecification {
@@ -453,7 +467,7 @@ pub(crate) async fn add(
                 NamedRequirementsResolver::new(
                     &hasher,
                     state.index(),
-                    DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+                    DistributionDatabase::new(&client, &build_dispatch, concurrency),
                 )
                 .with_reporter(Arc::new(ResolverReporter::from(printer)))
                 .resolve(unnamed.into_iter())
@@ -621,6 +635,22 @@ pub(crate) async fn add(
         &mut toml,
     )?;
 
+    // Add any `include-group` entries to the dependency group.
+    if let DependencyType::Group(group) = &dependency_type {
+        for include in &include_groups {
+            toml.add_dependency_group_include(group, include)?;
+        }
+    }
+
+    // Validate the dependency groups before writing, e.g., to reject missing groups and cycles
+    // introduced by the includes.
+    if !include_groups.is_empty() {
+        if let AddTarget::Project(project, _) = &target {
+            let pyproject_toml = PyProjectToml::from_string(toml.to_string())?;
+            FlatDependencyGroups::from_pyproject_toml(project.root(), &pyproject_toml)?;
+        }
+    }
+
     // Validate any indexes that were provided on the command-line to ensure
     // they point to existing non-empty directories when using path URLs.
     let mut valid_indexes = Vec::with_capacity(indexes.len());
@@ -713,6 +743,7 @@ pub(crate) async fn add(
         &settings,
         &network_settings,
         installer_metadata,
+        &download_limits,
         concurrency,
         cache,
         printer,
@@ -941,6 +972,7 @@ async fn lock_and_sync(
     settings: &ResolverInstallerSettings,
     network_settings: &NetworkSettings,
     installer_metadata: bool,
+    download_limits: &DownloadLimits,
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -1127,6 +1159,8 @@ async fn lock_and_sync(
         &sync_state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        download_limits,
+        None,
         concurrency,
         cache,
         WorkspaceCache::default(),
@@ -1279,6 +1313,7 @@ impl PythonTarget {
 
 /// Represents the destination where dependencies are added, either to a project or a script.
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub(super) enum AddTarget {
     /// A PEP 723 script, with inline metadata.
     Script(Pep723Script, Box<Interpreter>),
@@ -1379,6 +1414,7 @@ impl AddTarget {
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 enum AddTargetSnapshot {
     Script(Pep723Script, Option<Vec<u8>>),
     Project(VirtualProject, Option<Vec<u8>>),
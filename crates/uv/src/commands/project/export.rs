@@ -32,6 +32,7 @@ use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverSettings};
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 enum ExportTarget {
     /// A PEP 723 script, with inline metadata.
     Script(Pep723Script),
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Context;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_python::PythonEnvironment;
+use uv_shell::Shell;
+use uv_tool::entrypoint_paths;
+use uv_warnings::warn_user;
+
+use crate::printer::Printer;
+
+/// Expose the scripts installed in the environment in the given directory, by creating a launcher
+/// for each script that points into the environment.
+///
+/// On Unix, launchers are symbolic links to the environment's scripts. On Windows, the scripts are
+/// copied, as they already reference the environment's interpreter.
+///
+/// Any existing launchers for scripts that are no longer installed in the environment are removed.
+/// Files in the directory that weren't created for this environment are left untouched.
+pub(crate) fn expose_scripts(
+    environment: &PythonEnvironment,
+    directory: &Path,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let site_packages = SitePackages::from_environment(environment)?;
+
+    // Collect the scripts installed in the environment, keyed by file name for deterministic
+    // output.
+    let mut scripts = BTreeMap::new();
+    for dist in site_packages.iter() {
+        let entrypoints = match entrypoint_paths(&site_packages, dist.name(), dist.version()) {
+            Ok(entrypoints) => entrypoints,
+            Err(err) => {
+                debug!("Failed to read scripts for `{}`: {err}", dist.name());
+                continue;
+            }
+        };
+        for (_, source) in entrypoints {
+            if let Some(file_name) = source.file_name() {
+                scripts.insert(file_name.to_os_string(), source);
+            }
+        }
+    }
+
+    fs_err::create_dir_all(directory).context("Failed to create script directory")?;
+    debug!("Exposing scripts in: {}", directory.user_display());
+
+    // Remove any launchers for scripts that are no longer installed.
+    let mut removed = Vec::new();
+    for entry in fs_err::read_dir(directory)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        if scripts.contains_key(&file_name) {
+            continue;
+        }
+        if is_launcher(&entry.path(), environment.scripts()) {
+            debug!("Removing stale launcher: {}", entry.path().user_display());
+            fs_err::remove_file(entry.path())?;
+            removed.push(file_name);
+        }
+    }
+
+    // Create a launcher for each script.
+    let mut exposed = Vec::new();
+    for (file_name, source) in &scripts {
+        let launcher = directory.join(file_name);
+        if is_current(&launcher, source) {
+            continue;
+        }
+        if launcher.symlink_metadata().is_ok() && !is_launcher(&launcher, environment.scripts()) {
+            warn_user!(
+                "Skipping `{}`, since a file with the same name already exists at: {}",
+                file_name.to_string_lossy().bold(),
+                launcher.user_display().cyan()
+            );
+            continue;
+        }
+
+        debug!("Exposing script: {}", launcher.user_display());
+        create_launcher(source, &launcher).context("Failed to expose script")?;
+        exposed.push(file_name.clone());
+    }
+
+    if !exposed.is_empty() {
+        let s = if exposed.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Exposed {} script{s} in {}: {}",
+            exposed.len(),
+            directory.user_display().cyan(),
+            exposed
+                .iter()
+                .map(|name| name.to_string_lossy().bold().to_string())
+                .join(", ")
+        )?;
+
+        if !Shell::contains_path(directory) {
+            warn_user!(
+                "`{}` is not on your PATH. To use the exposed scripts, add the directory to your PATH.",
+                directory.simplified_display().cyan()
+            );
+        }
+    }
+
+    if !removed.is_empty() {
+        let s = if removed.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Removed {} script{s} from {}: {}",
+            removed.len(),
+            directory.user_display().cyan(),
+            removed
+                .iter()
+                .map(|name| name.to_string_lossy().bold().to_string())
+                .join(", ")
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the launcher at the given path already points to the given script.
+#[cfg(unix)]
+fn is_current(launcher: &Path, source: &Path) -> bool {
+    fs_err::read_link(launcher).is_ok_and(|target| target == source)
+}
+
+/// Returns `true` if the launcher at the given path already points to the given script.
+#[cfg(windows)]
+fn is_current(launcher: &Path, source: &Path) -> bool {
+    match (fs_err::read(launcher), fs_err::read(source)) {
+        (Ok(launcher), Ok(source)) => launcher == source,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the file at the given path is a launcher for a script in the given scripts
+/// directory.
+#[cfg(unix)]
+fn is_launcher(path: &Path, scripts: &Path) -> bool {
+    fs_err::read_link(path).is_ok_and(|target| target.starts_with(scripts))
+}
+
+/// Returns `true` if the file at the given path is a launcher for a script in the given scripts
+/// directory.
+///
+/// On Windows, launchers are copies of the environment's scripts, so a launcher is only
+/// recognized while the corresponding script is still installed.
+#[cfg(windows)]
+fn is_launcher(path: &Path, scripts: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|file_name| is_current(path, &scripts.join(file_name)))
+}
+
+/// Create a launcher at the given path for the given script, replacing any existing launcher.
+#[cfg(unix)]
+fn create_launcher(source: &Path, launcher: &Path) -> std::io::Result<()> {
+    uv_fs::replace_symlink(source, launcher)
+}
+
+/// Create a launcher at the given path for the given script, replacing any existing launcher.
+#[cfg(windows)]
+fn create_launcher(source: &Path, launcher: &Path) -> std::io::Result<()> {
+    fs_err::copy(source, launcher).map(|_| ())
+}
@@ -32,6 +32,7 @@ use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::ExitStatus;
+use crate::commands::project::init_template::{InitTemplate, TemplateVariables, render};
 use crate::commands::project::{find_requires_python, init_script_python_requirement};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
@@ -45,6 +46,7 @@ pub(crate) async fn init(
     name: Option<PackageName>,
     package: bool,
     init_kind: InitKind,
+    template: Option<String>,
     bare: bool,
     description: Option<String>,
     no_description: bool,
@@ -128,11 +130,15 @@ pub(crate) async fn init(
                 }
             };
 
+            // Resolve the template, if one was requested.
+            let template = template.as_deref().map(InitTemplate::parse).transpose()?;
+
             init_project(
                 &path,
                 &name,
                 package,
                 project_kind,
+                template.as_ref(),
                 bare,
                 description,
                 no_description,
@@ -154,8 +160,9 @@ pub(crate) async fn init(
             )
             .await?;
 
-            // Create the `README.md` if it does not already exist.
-            if !no_readme {
+            // Create the `README.md` if it does not already exist. Templates include their own
+            // `README.md`, if any.
+            if !no_readme && template.is_none() {
                 let readme = path.join("README.md");
                 if !readme.exists() {
                     fs_err::write(readme, String::new())?;
@@ -219,7 +226,13 @@ async fn init_script(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     let reporter = PythonDownloadReporter::single(printer);
 
@@ -279,6 +292,7 @@ async fn init_project(
     name: &PackageName,
     package: bool,
     project_kind: InitProjectKind,
+    template: Option<&InitTemplate>,
     bare: bool,
     description: Option<String>,
     no_description: bool,
@@ -350,7 +364,19 @@ async fn init_project(
         .retries_from_env()?
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
+
+    // Fetch the template up front, to avoid resolving a Python interpreter if it's unavailable.
+    let template_files = match template {
+        Some(template) => Some(template.fetch(&client_builder, cache).await?),
+        None => None,
+    };
 
     // First, determine if there is an request for Python
     let python_request = if let Some(request) = python {
@@ -582,19 +608,39 @@ async fn init_project(
         (requires_python, python_request)
     };
 
-    project_kind.init(
-        name,
-        path,
-        &requires_python,
-        description.as_deref(),
-        no_description,
-        bare,
-        vcs,
-        build_backend,
-        author_from,
-        no_readme,
-        package,
-    )?;
+    if let Some(files) = template_files {
+        let build_system =
+            pyproject_build_system(name, build_backend.unwrap_or(ProjectBuildBackend::Uv));
+        render(
+            &files,
+            &TemplateVariables {
+                project_name: name,
+                description: description
+                    .as_deref()
+                    .unwrap_or("Add your description here"),
+                requires_python: &requires_python,
+                build_system: &build_system,
+            },
+            path,
+        )?;
+
+        // Initialize the version control system.
+        init_vcs(path, vcs)?;
+    } else {
+        project_kind.init(
+            name,
+            path,
+            &requires_python,
+            description.as_deref(),
+            no_description,
+            bare,
+            vcs,
+            build_backend,
+            author_from,
+            no_readme,
+            package,
+        )?;
+    }
 
     if let Some(workspace) = workspace {
         if workspace.excludes(path)? {
@@ -0,0 +1,532 @@
+use std::borrow::Cow;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_distribution_types::RequiresPython;
+use uv_fs::Simplified;
+use uv_git::GitResolver;
+use uv_normalize::PackageName;
+use uv_pypi_types::ParsedGitUrl;
+use uv_redacted::DisplaySafeUrl;
+
+/// A template from which to initialize a project.
+#[derive(Debug, Clone)]
+pub(crate) enum InitTemplate {
+    /// A template that ships with uv, e.g., `cli` or `fastapi`.
+    BuiltIn(BuiltInTemplate),
+    /// A template in a Git repository, e.g., `git+https://github.com/org/template@main`.
+    Git(ParsedGitUrl),
+    /// A template in a local directory.
+    Directory(PathBuf),
+}
+
+impl InitTemplate {
+    /// Parse an [`InitTemplate`] from a user-provided string.
+    ///
+    /// Remote URLs and `git+` URLs are treated as Git repositories; otherwise, the name of a
+    /// built-in template takes precedence over a directory of the same name.
+    pub(crate) fn parse(template: &str) -> Result<Self> {
+        if let Ok(url) = DisplaySafeUrl::parse(template) {
+            if url.scheme().starts_with("git+") || matches!(url.scheme(), "https" | "http" | "ssh")
+            {
+                let url = ParsedGitUrl::try_from(url)
+                    .with_context(|| format!("Invalid Git template URL: `{template}`"))?;
+                return Ok(Self::Git(url));
+            }
+        }
+
+        if let Some(template) = BuiltInTemplate::from_name(template) {
+            return Ok(Self::BuiltIn(template));
+        }
+
+        let path = std::path::absolute(template)?;
+        if path.is_dir() {
+            return Ok(Self::Directory(path));
+        }
+
+        anyhow::bail!(
+            "Template `{}` is not a built-in template, a Git URL, or a directory (available templates: {})",
+            template.cyan(),
+            BuiltInTemplate::ALL
+                .iter()
+                .map(|template| format!("`{}`", template.name()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    /// Fetch the template, returning its files.
+    pub(crate) async fn fetch(
+        &self,
+        client_builder: &BaseClientBuilder<'_>,
+        cache: &Cache,
+    ) -> Result<Vec<TemplateFile>> {
+        match self {
+            Self::BuiltIn(template) => Ok(template
+                .files()
+                .iter()
+                .map(|&(path, contents)| TemplateFile {
+                    path: PathBuf::from(path),
+                    contents: Cow::Borrowed(contents.as_bytes()),
+                })
+                .collect()),
+            Self::Git(url) => {
+                let client = client_builder.build();
+                let repository = url.url.repository();
+                let fetch = GitResolver::default()
+                    .fetch(
+                        &url.url,
+                        client.for_host(repository).clone(),
+                        client.disable_ssl(repository),
+                        client.connectivity() == Connectivity::Offline,
+                        cache.bucket(CacheBucket::Git),
+                        None,
+                    )
+                    .await
+                    .with_context(|| format!("Failed to fetch template from `{}`", url.url))?;
+                let root = match url.subdirectory.as_deref() {
+                    Some(subdirectory) => subdirectory_root(fetch.path(), subdirectory)?,
+                    None => fetch.path().to_path_buf(),
+                };
+                read_directory(&root)
+            }
+            Self::Directory(path) => read_directory(path),
+        }
+    }
+}
+
+/// Resolve the `subdirectory` of a Git template against the checkout, rejecting any subdirectory
+/// that escapes it (e.g., `../other` or an absolute path).
+fn subdirectory_root(checkout: &Path, subdirectory: &Path) -> Result<PathBuf> {
+    let root = uv_fs::normalize_path_buf(checkout.join(subdirectory));
+    if !root.starts_with(checkout) {
+        anyhow::bail!(
+            "Template subdirectory `{}` is outside of the repository",
+            subdirectory.user_display()
+        );
+    }
+    Ok(root)
+}
+
+/// A template that ships with uv.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum BuiltInTemplate {
+    /// A packaged command-line application.
+    Cli,
+    /// A packaged library.
+    Lib,
+    /// A web service built with FastAPI.
+    FastApi,
+    /// A Python package with a Rust extension module, built with maturin.
+    Maturin,
+}
+
+impl BuiltInTemplate {
+    const ALL: [Self; 4] = [Self::Cli, Self::Lib, Self::FastApi, Self::Maturin];
+
+    /// Return the [`BuiltInTemplate`] with the given name, if any.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name() == name)
+    }
+
+    /// The name by which the template is requested.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Cli => "cli",
+            Self::Lib => "lib",
+            Self::FastApi => "fastapi",
+            Self::Maturin => "maturin",
+        }
+    }
+
+    /// The files that make up the template, as relative paths and contents.
+    fn files(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Cli => &[
+                ("README.md", README),
+                (
+                    "pyproject.toml",
+                    indoc::indoc! {r#"
+                        [project]
+                        name = "{{ project_name }}"
+                        version = "0.1.0"
+                        description = "{{ description }}"
+                        readme = "README.md"
+                        requires-python = "{{ requires_python }}"
+                        dependencies = []
+
+                        [project.scripts]
+                        {{ project_name }} = "{{ module_name }}.__main__:main"
+
+                        {{ build_system }}
+                    "#},
+                ),
+                ("src/{{ module_name }}/__init__.py", ""),
+                (
+                    "src/{{ module_name }}/__main__.py",
+                    indoc::indoc! {r#"
+                        import argparse
+
+
+                        def main() -> None:
+                            parser = argparse.ArgumentParser(prog="{{ project_name }}")
+                            parser.add_argument("name", nargs="?", default="world")
+                            args = parser.parse_args()
+                            print(f"Hello, {args.name}!")
+
+
+                        if __name__ == "__main__":
+                            main()
+                    "#},
+                ),
+            ],
+            Self::Lib => &[
+                ("README.md", README),
+                (
+                    "pyproject.toml",
+                    indoc::indoc! {r#"
+                        [project]
+                        name = "{{ project_name }}"
+                        version = "0.1.0"
+                        description = "{{ description }}"
+                        readme = "README.md"
+                        requires-python = "{{ requires_python }}"
+                        dependencies = []
+
+                        [dependency-groups]
+                        dev = ["pytest"]
+
+                        {{ build_system }}
+                    "#},
+                ),
+                (
+                    "src/{{ module_name }}/__init__.py",
+                    indoc::indoc! {r#"
+                        def hello() -> str:
+                            return "Hello from {{ project_name }}!"
+                    "#},
+                ),
+                ("src/{{ module_name }}/py.typed", ""),
+                (
+                    "tests/test_{{ module_name }}.py",
+                    indoc::indoc! {r#"
+                        from {{ module_name }} import hello
+
+
+                        def test_hello() -> None:
+                            assert hello() == "Hello from {{ project_name }}!"
+                    "#},
+                ),
+            ],
+            Self::FastApi => &[
+                (
+                    "README.md",
+                    indoc::indoc! {r"
+                        # {{ project_name }}
+
+                        {{ description }}
+
+                        Start the development server with:
+
+                        ```console
+                        uv run fastapi dev main.py
+                        ```
+                    "},
+                ),
+                (
+                    "pyproject.toml",
+                    indoc::indoc! {r#"
+                        [project]
+                        name = "{{ project_name }}"
+                        version = "0.1.0"
+                        description = "{{ description }}"
+                        readme = "README.md"
+                        requires-python = "{{ requires_python }}"
+                        dependencies = [
+                            "fastapi[standard]",
+                        ]
+                    "#},
+                ),
+                (
+                    "main.py",
+                    indoc::indoc! {r#"
+                        from fastapi import FastAPI
+
+                        app = FastAPI()
+
+
+                        @app.get("/")
+                        def read_root() -> dict[str, str]:
+                            return {"message": "Hello from {{ project_name }}!"}
+                    "#},
+                ),
+            ],
+            Self::Maturin => &[
+                ("README.md", README),
+                (
+                    "pyproject.toml",
+                    indoc::indoc! {r#"
+                        [project]
+                        name = "{{ project_name }}"
+                        version = "0.1.0"
+                        description = "{{ description }}"
+                        readme = "README.md"
+                        requires-python = "{{ requires_python }}"
+                        dependencies = []
+
+                        [tool.maturin]
+                        module-name = "{{ module_name }}._core"
+                        python-packages = ["{{ module_name }}"]
+                        python-source = "src"
+
+                        [build-system]
+                        requires = ["maturin>=1.0,<2.0"]
+                        build-backend = "maturin"
+                    "#},
+                ),
+                (
+                    "Cargo.toml",
+                    indoc::indoc! {r#"
+                        [package]
+                        name = "{{ module_name }}"
+                        version = "0.1.0"
+                        edition = "2021"
+
+                        [lib]
+                        name = "_core"
+                        # "cdylib" is necessary to produce a shared library for Python to import from.
+                        crate-type = ["cdylib"]
+
+                        [dependencies]
+                        # "extension-module" tells pyo3 we want to build an extension module (skips linking against libpython.so)
+                        # "abi3-py39" tells pyo3 (and maturin) to build using the stable ABI with minimum Python version 3.9
+                        pyo3 = { version = "0.22.4", features = ["extension-module", "abi3-py39"] }
+                    "#},
+                ),
+                (
+                    "src/lib.rs",
+                    indoc::indoc! {r#"
+                        use pyo3::prelude::*;
+
+                        #[pyfunction]
+                        fn hello_from_bin() -> String {
+                            "Hello from {{ project_name }}!".to_string()
+                        }
+
+                        /// A Python module implemented in Rust. The name of this function must match
+                        /// the `lib.name` setting in the `Cargo.toml`, else Python will not be able to
+                        /// import the module.
+                        #[pymodule]
+                        fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
+                            m.add_function(wrap_pyfunction!(hello_from_bin, m)?)?;
+                            Ok(())
+                        }
+                    "#},
+                ),
+                (
+                    "src/{{ module_name }}/__init__.py",
+                    indoc::indoc! {r"
+                        from {{ module_name }}._core import hello_from_bin
+
+
+                        def hello() -> str:
+                            return hello_from_bin()
+                    "},
+                ),
+                (
+                    "src/{{ module_name }}/_core.pyi",
+                    indoc::indoc! {r"
+                        def hello_from_bin() -> str: ...
+                    "},
+                ),
+                ("src/{{ module_name }}/py.typed", ""),
+            ],
+        }
+    }
+}
+
+/// The `README.md` shared by the built-in templates.
+const README: &str = indoc::indoc! {r"
+    # {{ project_name }}
+
+    {{ description }}
+"};
+
+/// A file in a template, with a path relative to the template root.
+#[derive(Debug, Clone)]
+pub(crate) struct TemplateFile {
+    path: PathBuf,
+    contents: Cow<'static, [u8]>,
+}
+
+/// Read the files in a template directory, skipping any `.git` directory.
+fn read_directory(root: &Path) -> Result<Vec<TemplateFile>> {
+    if !root.is_dir() {
+        anyhow::bail!(
+            "Template directory does not exist: `{}`",
+            root.user_display().cyan()
+        );
+    }
+
+    let mut files = Vec::new();
+    for entry in WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git")
+    {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let path = entry
+            .path()
+            .strip_prefix(root)
+            .expect("walkdir entries are within the root")
+            .to_path_buf();
+        let contents = fs_err::read(entry.path())?;
+        files.push(TemplateFile {
+            path,
+            contents: Cow::Owned(contents),
+        });
+    }
+    Ok(files)
+}
+
+/// The variables available for substitution in a template, as `{{ name }}`.
+pub(crate) struct TemplateVariables<'a> {
+    /// The name of the project, e.g., `my-project`.
+    pub(crate) project_name: &'a PackageName,
+    /// The description of the project.
+    pub(crate) description: &'a str,
+    /// The Python requirement of the project, e.g., `>=3.12`.
+    pub(crate) requires_python: &'a RequiresPython,
+    /// The `[build-system]` table for the requested build backend.
+    pub(crate) build_system: &'a str,
+}
+
+impl TemplateVariables<'_> {
+    /// Return the value of the variable with the given name, if it exists.
+    fn get(&self, name: &str) -> Option<Cow<'_, str>> {
+        match name {
+            "project_name" => Some(Cow::Borrowed(self.project_name.as_str())),
+            "module_name" => Some(self.project_name.as_dist_info_name()),
+            "description" => Some(Cow::Borrowed(self.description)),
+            "requires_python" => Some(Cow::Owned(self.requires_python.specifiers().to_string())),
+            "python_version" => match &**self.requires_python.range().lower() {
+                Bound::Included(version) | Bound::Excluded(version) => {
+                    Some(Cow::Owned(version.to_string()))
+                }
+                Bound::Unbounded => Some(Cow::Borrowed("")),
+            },
+            "build_system" => Some(Cow::Borrowed(self.build_system.trim_end())),
+            _ => None,
+        }
+    }
+
+    /// Replace each `{{ name }}` placeholder with the value of the corresponding variable.
+    ///
+    /// Placeholders that don't refer to a known variable (e.g., `${{ matrix.python }}` in a GitHub
+    /// Actions workflow) are left as-is.
+    fn substitute(&self, content: &str) -> String {
+        let mut output = String::with_capacity(content.len());
+        let mut rest = content;
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start + 2..].find("}}") else {
+                break;
+            };
+            let end = start + 2 + len + 2;
+            output.push_str(&rest[..start]);
+            match self.get(rest[start + 2..end - 2].trim()) {
+                Some(value) => output.push_str(&value),
+                None => output.push_str(&rest[start..end]),
+            }
+            rest = &rest[end..];
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+/// Render the template files into the target directory.
+///
+/// Variables are substituted in both file paths and (UTF-8) file contents. Existing files are left
+/// untouched.
+pub(crate) fn render(
+    files: &[TemplateFile],
+    variables: &TemplateVariables,
+    path: &Path,
+) -> Result<()> {
+    if !files
+        .iter()
+        .any(|file| file.path.as_path() == Path::new("pyproject.toml"))
+    {
+        anyhow::bail!("Template does not contain a `pyproject.toml` file");
+    }
+
+    fs_err::create_dir_all(path)?;
+
+    for file in files {
+        let target = path.join(
+            file.path
+                .components()
+                .map(|component| variables.substitute(&component.as_os_str().to_string_lossy()))
+                .collect::<PathBuf>(),
+        );
+
+        if target.try_exists()? {
+            debug!(
+                "Skipping existing template file: `{}`",
+                target.user_display()
+            );
+            continue;
+        }
+
+        if let Some(parent) = target.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        match std::str::from_utf8(&file.contents) {
+            Ok(contents) => fs_err::write(&target, variables.substitute(contents))?,
+            // Copy binary files verbatim.
+            Err(_) => fs_err::write(&target, &file.contents)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subdirectory() {
+        let checkout = Path::new("/cache/git-v0/checkouts/abc/def");
+
+        assert_eq!(
+            subdirectory_root(checkout, Path::new("templates/./cli")).unwrap(),
+            checkout.join("templates").join("cli")
+        );
+        assert_eq!(
+            subdirectory_root(checkout, Path::new("templates/../cli")).unwrap(),
+            checkout.join("cli")
+        );
+
+        for subdirectory in ["..", "templates/../../other", "/etc"] {
+            let err = subdirectory_root(checkout, Path::new(subdirectory)).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Template subdirectory `{subdirectory}` is outside of the repository")
+            );
+        }
+    }
+}
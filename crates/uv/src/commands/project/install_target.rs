@@ -6,8 +6,9 @@ use itertools::Either;
 use rustc_hash::FxHashSet;
 
 use uv_configuration::{Constraints, DependencyGroupsWithDefaults, ExtrasSpecification};
-use uv_distribution_types::Index;
+use uv_distribution_types::{Index, Requirement, SourcePatches};
 use uv_normalize::PackageName;
+use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{DependencyGroupSpecifier, LenientRequirement, VerbatimParsedUrl};
 use uv_resolver::{Installable, Lock, Package};
 use uv_scripts::Pep723Script;
@@ -247,6 +248,34 @@ impl<'lock> InstallTarget<'lock> {
         self.lock().build_constraints(self.install_path())
     }
 
+    /// The patches to apply to source distributions, as recorded in the lockfile.
+    pub(crate) fn patches(&self) -> SourcePatches {
+        self.lock().patches(self.install_path())
+    }
+
+    /// The build constraints for specific packages, read from the workspace root, as they aren't
+    /// recorded in the lockfile.
+    pub(crate) fn build_constraints_package(&self) -> Vec<(PackageName, Vec<Requirement>)> {
+        match self {
+            Self::Project { workspace, .. }
+            | Self::Workspace { workspace, .. }
+            | Self::NonProjectWorkspace { workspace, .. } => workspace
+                .build_constraints_package()
+                .into_iter()
+                .map(|(package, constraints)| {
+                    let constraints = constraints
+                        .into_iter()
+                        .map(|constraint| {
+                            Requirement::from(constraint.with_origin(RequirementOrigin::Workspace))
+                        })
+                        .collect();
+                    (package, constraints)
+                })
+                .collect(),
+            Self::Script { .. } => Vec::new(),
+        }
+    }
+
     /// Validate the extras requested by the [`ExtrasSpecification`].
     #[allow(clippy::result_large_err)]
     pub(crate) fn validate_extras(self, extras: &ExtrasSpecification) -> Result<(), ProjectError> {
@@ -7,9 +7,11 @@ use std::sync::Arc;
 
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::Serialize;
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::{IndexMigration, LockFormat};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
@@ -19,7 +21,7 @@ use uv_dispatch::BuildDispatch;
 use uv_distribution::DistributionDatabase;
 use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Requirement, RequiresPython, UnresolvedRequirementSpecification,
+    Requirement, RequiresPython, SourcePatch, SourcePatches, UnresolvedRequirementSpecification,
 };
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{GroupName, PackageName};
@@ -39,11 +41,15 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::lock_links::ProjectLinks;
+use crate::commands::project::lock_snapshot::SnapshotAction;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::SchemaReport;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     init_script_python_requirement,
 };
+use crate::commands::project::{lock_links, lock_migrate, lock_snapshot};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip};
 use crate::printer::Printer;
@@ -82,6 +88,11 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    minimize: bool,
+    migrate_index: Option<IndexMigration>,
+    snapshot: Option<SnapshotAction>,
+    show_links: bool,
+    output_format: LockFormat,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -95,6 +106,12 @@ pub(crate) async fn lock(
     printer: Printer,
     preview: PreviewMode,
 ) -> anyhow::Result<ExitStatus> {
+    if !preview.is_enabled() && matches!(output_format, LockFormat::Json) {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview` to disable this warning."
+        );
+    }
+
     // If necessary, initialize the PEP 723 script.
     let script = match script {
         Some(ScriptPath::Path(path)) => {
@@ -102,7 +119,13 @@ pub(crate) async fn lock(
                 .retries_from_env()?
                 .connectivity(network_settings.connectivity)
                 .native_tls(network_settings.native_tls)
-                .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                .proxy_settings(network_settings.proxy_settings.clone())
+                .http_connection(network_settings.http_connection.clone())
+                .retry_policies(
+                    network_settings.retry_policy.clone(),
+                    network_settings.download_retry_policy.clone(),
+                );
             let reporter = PythonDownloadReporter::single(printer);
             let requires_python = init_script_python_requirement(
                 python.as_deref(),
@@ -136,6 +159,26 @@ pub(crate) async fn lock(
         LockTarget::Workspace(&workspace)
     };
 
+    // If requested, save or restore a snapshot of the lockfile without resolving.
+    if let Some(action) = snapshot {
+        return lock_snapshot::snapshot(target, action, printer).await;
+    }
+
+    // If requested, re-pin the existing lockfile to a different index without re-resolving.
+    if let Some(migration) = migrate_index {
+        return lock_migrate::migrate_index(
+            target,
+            &migration,
+            dry_run,
+            &settings,
+            &network_settings,
+            concurrency,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
     // Determine the lock mode.
     let interpreter;
     let mode = if frozen {
@@ -204,32 +247,78 @@ pub(crate) async fn lock(
         printer,
         preview,
     )
+    .with_minimize(minimize)
     .execute(target)
     .await
     {
         Ok(lock) => {
-            if dry_run.enabled() {
-                // In `--dry-run` mode, show all changes.
-                let mut changed = false;
-                if let LockResult::Changed(previous, lock) = &lock {
-                    for event in LockEvent::detect_changes(previous.as_ref(), lock, dry_run) {
-                        changed = true;
-                        writeln!(printer.stderr(), "{event}")?;
+            // In `--dry-run` mode, show all changes; otherwise, only show changes to an existing
+            // lockfile.
+            let events = match &lock {
+                LockResult::Changed(previous, lock) if dry_run.enabled() || previous.is_some() => {
+                    LockEvent::detect_changes(previous.as_ref(), lock, dry_run).collect::<Vec<_>>()
+                }
+                _ => Vec::new(),
+            };
+
+            // If requested, fetch the project links for each changed package.
+            let links = if show_links {
+                let previous = match &lock {
+                    LockResult::Changed(previous, _) => previous.as_ref(),
+                    LockResult::Unchanged(_) => None,
+                };
+                lock_links::fetch_links(
+                    &events,
+                    previous,
+                    lock.lock(),
+                    target.install_path(),
+                    &settings,
+                    &network_settings,
+                    concurrency,
+                    cache,
+                )
+                .await?
+            } else {
+                FxHashMap::default()
+            };
+
+            match output_format {
+                LockFormat::Text => {
+                    for event in &events {
+                        match links.get(event.name()) {
+                            Some(links) => writeln!(
+                                printer.stderr(),
+                                "{event}{}",
+                                links.to_human_readable_string()
+                            )?,
+                            None => writeln!(printer.stderr(), "{event}")?,
+                        }
+                    }
+                    if dry_run.enabled() && events.is_empty() {
+                        writeln!(
+                            printer.stderr(),
+                            "{}",
+                            "No lockfile changes detected".bold()
+                        )?;
                     }
                 }
-                if !changed {
+                LockFormat::Json => {
+                    let report = LockReport {
+                        schema: SchemaReport::default(),
+                        changes: events
+                            .iter()
+                            .map(|event| {
+                                ChangeReport::from_event(event, links.get(event.name()).cloned())
+                            })
+                            .collect(),
+                        dry_run: dry_run.enabled(),
+                    };
                     writeln!(
-                        printer.stderr(),
+                        printer.stdout_important(),
                         "{}",
-                        "No lockfile changes detected".bold()
+                        serde_json::to_string_pretty(&report)?
                     )?;
                 }
-            } else {
-                if let LockResult::Changed(Some(previous), lock) = &lock {
-                    for event in LockEvent::detect_changes(Some(previous), lock, dry_run) {
-                        writeln!(printer.stderr(), "{event}")?;
-                    }
-                }
             }
 
             Ok(ExitStatus::Success)
@@ -263,6 +352,7 @@ pub(super) enum LockMode<'env> {
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    minimize: bool,
     settings: &'env ResolverSettings,
     network_settings: &'env NetworkSettings,
     state: &'env UniversalState,
@@ -291,6 +381,7 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            minimize: false,
             settings,
             network_settings,
             state,
@@ -313,6 +404,13 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Write the lockfile in the compact format, even if the existing lockfile is not compact.
+    #[must_use]
+    pub(super) fn with_minimize(mut self, minimize: bool) -> Self {
+        self.minimize = minimize;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -391,6 +489,21 @@ impl<'env> LockOperation<'env> {
                 )
                 .await?;
 
+                // If requested, convert the lockfile to the compact format.
+                let result = if self.minimize {
+                    match result {
+                        LockResult::Unchanged(lock) if !lock.is_compact() => {
+                            LockResult::Changed(Some(lock.clone()), lock.with_compact(true))
+                        }
+                        LockResult::Changed(previous, lock) => {
+                            LockResult::Changed(previous, lock.with_compact(true))
+                        }
+                        result @ LockResult::Unchanged(_) => result,
+                    }
+                } else {
+                    result
+                };
+
                 // If the lockfile changed, write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
                     if let LockResult::Changed(_, lock) = &result {
@@ -450,6 +563,7 @@ async fn do_lock(
     let overrides = target.overrides();
     let constraints = target.constraints();
     let build_constraints = target.build_constraints();
+    let patches = target.patches();
     let dependency_groups = target.dependency_groups()?;
     let source_trees = vec![];
 
@@ -458,6 +572,16 @@ async fn do_lock(
     let overrides = target.lower(overrides, index_locations, *sources)?;
     let constraints = target.lower(constraints, index_locations, *sources)?;
     let build_constraints = target.lower(build_constraints, index_locations, *sources)?;
+    let build_constraints_package = target
+        .build_constraints_package()
+        .into_iter()
+        .map(|(package, constraints)| {
+            Ok((
+                package,
+                target.lower(constraints, index_locations, *sources)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ProjectError>>()?;
     let dependency_groups = dependency_groups
         .into_iter()
         .map(|(name, group)| {
@@ -602,7 +726,13 @@ async fn do_lock(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     index_locations.cache_index_credentials();
 
@@ -685,9 +815,14 @@ async fn do_lock(
         workspace_cache.clone(),
         concurrency,
         preview,
-    );
+    )
+    .with_build_constraints_package(build_constraints_package)
+    .with_patches(SourcePatches::from_entries(
+        target.install_path().to_path_buf(),
+        patches.iter().cloned(),
+    ));
 
-    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency);
 
     // If any of the resolution-determining settings changed, invalidate the lock.
     let existing_lock = if let Some(existing_lock) = existing_lock {
@@ -706,6 +841,7 @@ async fn do_lock(
             environments,
             required_environments,
             dependency_metadata,
+            &patches,
             interpreter,
             &requires_python,
             index_locations,
@@ -864,11 +1000,13 @@ async fn do_lock(
                 build_constraints,
                 dependency_groups,
                 dependency_metadata.values().cloned(),
+                patches.iter().cloned(),
             )
             .relative_to(target.install_path())?;
 
             let previous = existing_lock.map(ValidatedLock::into_lock);
             let lock = Lock::from_resolution(&resolution, target.install_path())?
+                .with_compact(previous.as_ref().is_some_and(Lock::is_compact))
                 .with_manifest(manifest)
                 .with_conflicts(conflicts)
                 .with_supported_environments(
@@ -884,6 +1022,19 @@ async fn do_lock(
                         .unwrap_or_default(),
                 );
 
+            // Warn about any patches that don't match a locked package, as they'll never be applied.
+            for patch in &patches {
+                if !lock.packages().iter().any(|package| {
+                    *package.name() == patch.name && package.version() == Some(&patch.version)
+                }) {
+                    warn_user!(
+                        "The patch for `{}=={}` does not match any package in the lockfile, and will not be applied",
+                        patch.name,
+                        patch.version
+                    );
+                }
+            }
+
             Ok(LockResult::Changed(previous, lock))
         }
     }
@@ -920,6 +1071,7 @@ impl ValidatedLock {
         environments: Option<&SupportedEnvironments>,
         required_environments: Option<&SupportedEnvironments>,
         dependency_metadata: &DependencyMetadata,
+        patches: &[SourcePatch],
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
         index_locations: &IndexLocations,
@@ -1129,6 +1281,7 @@ impl ValidatedLock {
                 build_constraints,
                 dependency_groups,
                 dependency_metadata,
+                patches,
                 indexes,
                 interpreter.tags()?,
                 hasher,
@@ -1226,6 +1379,13 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedPatches(expected, actual) => {
+                debug!(
+                    "Ignoring existing lockfile due to mismatched patches:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MissingRoot(name) => {
                 debug!("Ignoring existing lockfile due to missing root package: `{name}`");
                 Ok(Self::Preferable(lock))
@@ -1318,6 +1478,13 @@ pub(crate) enum LockEvent<'lock> {
 }
 
 impl<'lock> LockEvent<'lock> {
+    /// Returns the name of the package affected by the event.
+    pub(crate) fn name(&self) -> &PackageName {
+        match self {
+            Self::Update(_, name, ..) | Self::Add(_, name, _) | Self::Remove(_, name, _) => name,
+        }
+    }
+
     /// Detect the change events between an (optional) existing and updated lockfile.
     pub(crate) fn detect_changes(
         existing_lock: Option<&'lock Lock>,
@@ -1382,6 +1549,70 @@ impl<'lock> LockEvent<'lock> {
     }
 }
 
+/// A report of the changes to the lockfile, for use with `--output-format json`.
+#[derive(Debug, Serialize)]
+struct LockReport<'lock> {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The changes to the lockfile.
+    changes: Vec<ChangeReport<'lock>>,
+    /// Whether this is a dry run.
+    dry_run: bool,
+}
+
+/// The kind of change to a package in the lockfile.
+#[derive(Debug, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum ChangeAction {
+    Add,
+    Update,
+    Remove,
+}
+
+/// A report of the change to a single package in the lockfile.
+#[derive(Debug, Serialize)]
+struct ChangeReport<'lock> {
+    /// The name of the package.
+    name: &'lock PackageName,
+    /// The kind of change.
+    action: ChangeAction,
+    /// The versions of the package in the existing lockfile; `null` for dynamic versions.
+    previous: Vec<Option<&'lock Version>>,
+    /// The versions of the package in the updated lockfile; `null` for dynamic versions.
+    current: Vec<Option<&'lock Version>>,
+    /// Links to the project metadata of the updated package, if requested via `--show-links`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<ProjectLinks>,
+}
+
+impl<'lock> ChangeReport<'lock> {
+    fn from_event(event: &'lock LockEvent<'lock>, links: Option<ProjectLinks>) -> Self {
+        match event {
+            LockEvent::Update(_, name, existing, new) => Self {
+                name,
+                action: ChangeAction::Update,
+                previous: existing.iter().copied().collect(),
+                current: new.iter().copied().collect(),
+                links,
+            },
+            LockEvent::Add(_, name, new) => Self {
+                name,
+                action: ChangeAction::Add,
+                previous: Vec::new(),
+                current: new.iter().copied().collect(),
+                links,
+            },
+            LockEvent::Remove(_, name, existing) => Self {
+                name,
+                action: ChangeAction::Remove,
+                previous: existing.iter().copied().collect(),
+                current: Vec::new(),
+                links,
+            },
+        }
+    }
+}
+
 impl std::fmt::Display for LockEvent<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         /// Format a version for inclusion in the upgrade report.
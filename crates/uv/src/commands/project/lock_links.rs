@@ -0,0 +1,248 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use tokio::sync::Semaphore;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::{MetadataFormat, RegistryClient, RegistryClientBuilder, VersionFiles};
+use uv_configuration::Concurrency;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{IndexCapabilities, IndexMetadataRef};
+use uv_normalize::PackageName;
+use uv_pypi_types::Metadata23;
+use uv_redacted::DisplaySafeUrl;
+use uv_resolver::{Lock, Package};
+
+use crate::commands::project::lock::LockEvent;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// Links to the project metadata for a locked package, as advertised in its core metadata.
+#[derive(Debug, Default, Clone, Serialize)]
+pub(crate) struct ProjectLinks {
+    /// The URL of the project's home page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    /// The URL of the project's changelog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    changelog: Option<String>,
+}
+
+impl ProjectLinks {
+    /// Extract the [`ProjectLinks`] from the core metadata of a distribution.
+    fn from_metadata(metadata: Metadata23) -> Option<Self> {
+        let mut links = Self::default();
+        for entry in &metadata.project_urls {
+            let Some((label, url)) = entry.split_once(',') else {
+                continue;
+            };
+            let url = url.trim();
+            // Normalize the label per PEP 753 (e.g., `Release Notes` to `releasenotes`).
+            let label = label
+                .chars()
+                .filter(|c| !c.is_ascii_punctuation() && !c.is_whitespace())
+                .collect::<String>()
+                .to_lowercase();
+            match label.as_str() {
+                "homepage" => {
+                    links.homepage.get_or_insert_with(|| url.to_string());
+                }
+                "changelog" | "changes" | "releasenotes" | "news" | "whatsnew" | "history" => {
+                    links.changelog.get_or_insert_with(|| url.to_string());
+                }
+                _ => {}
+            }
+        }
+        if links.homepage.is_none() {
+            links.homepage = metadata.home_page.filter(|url| !url.is_empty());
+        }
+        if links.homepage.is_none() && links.changelog.is_none() {
+            None
+        } else {
+            Some(links)
+        }
+    }
+
+    /// Format the [`ProjectLinks`] for inclusion in the human-readable change summary.
+    pub(crate) fn to_human_readable_string(&self) -> String {
+        let mut output = String::new();
+        if let Some(homepage) = &self.homepage {
+            let _ = write!(output, "\n    {} {}", "Homepage:".dimmed(), homepage.cyan());
+        }
+        if let Some(changelog) = &self.changelog {
+            let _ = write!(
+                output,
+                "\n    {} {}",
+                "Changelog:".dimmed(),
+                changelog.cyan()
+            );
+        }
+        output
+    }
+}
+
+/// Fetch the [`ProjectLinks`] for each package that was added, updated, or removed in the lockfile.
+///
+/// Links are fetched on a best-effort basis, from the [PEP 658](https://peps.python.org/pep-0658/)
+/// core metadata of a wheel of each package, as advertised by the package's index. Added and
+/// updated packages are described by their new version, and removed packages by their previous
+/// version. Packages for which the metadata is unavailable are omitted.
+pub(crate) async fn fetch_links(
+    events: &[LockEvent<'_>],
+    previous: Option<&Lock>,
+    lock: &Lock,
+    root: &Path,
+    settings: &ResolverSettings,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+) -> anyhow::Result<FxHashMap<PackageName, ProjectLinks>> {
+    if network_settings.connectivity.is_offline() {
+        debug!("Skipping project links in offline mode");
+        return Ok(FxHashMap::default());
+    }
+
+    // Identify the locked package that describes each change.
+    let packages = events
+        .iter()
+        .filter_map(|event| {
+            let (source, versions) = match event {
+                LockEvent::Add(_, _, versions) | LockEvent::Update(_, _, _, versions) => {
+                    (lock, versions)
+                }
+                LockEvent::Remove(_, _, versions) => (previous?, versions),
+            };
+            source.packages().iter().find(|package| {
+                package.name() == event.name() && versions.contains(&package.version())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if packages.is_empty() {
+        return Ok(FxHashMap::default());
+    }
+
+    // Initialize the registry client, such that the index configuration (including credentials)
+    // applies to the metadata requests.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .retries_from_env()?
+        .native_tls(network_settings.native_tls)
+        .connectivity(network_settings.connectivity)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        )
+        .index_locations(&settings.index_locations)
+        .keyring(settings.keyring_provider)
+        .build();
+    let capabilities = IndexCapabilities::default();
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    let client = &client;
+    let capabilities = &capabilities;
+    let download_concurrency = &download_concurrency;
+    let links = futures::stream::iter(packages)
+        .map(async |package| {
+            let name = package.name();
+            let links =
+                fetch_package_links(client, package, root, capabilities, download_concurrency)
+                    .await
+                    .inspect_err(|err| {
+                        debug!("Failed to fetch project metadata for `{name}`: {err}")
+                    })
+                    .ok()??;
+            Some((name.clone(), links))
+        })
+        .buffer_unordered(concurrency.downloads)
+        .filter_map(async |entry| entry)
+        .collect::<FxHashMap<_, _>>()
+        .await;
+
+    Ok(links)
+}
+
+/// Fetch the [`ProjectLinks`] for a locked package from its index.
+///
+/// Returns `Ok(None)` if the package isn't sourced from an index, or if the index doesn't serve the
+/// core metadata for any of the package's wheels.
+async fn fetch_package_links(
+    client: &RegistryClient,
+    package: &Package,
+    root: &Path,
+    capabilities: &IndexCapabilities,
+    download_concurrency: &Semaphore,
+) -> anyhow::Result<Option<ProjectLinks>> {
+    let (Some(index), Some(version)) = (package.index(root)?, package.version()) else {
+        return Ok(None);
+    };
+
+    let archives = client
+        .package_metadata(
+            package.name(),
+            Some(IndexMetadataRef::from(&index)),
+            capabilities,
+            download_concurrency,
+        )
+        .await?;
+
+    // Find a wheel of the locked version for which the index advertises core metadata (i.e., the
+    // `core-metadata` field in the JSON API, or `data-core-metadata` in the HTML API).
+    let mut metadata_file = None;
+    for (_, archive) in archives {
+        let MetadataFormat::Simple(archive) = archive else {
+            continue;
+        };
+        for datum in archive.iter() {
+            let version_files =
+                rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                    .expect("archived version files always deserializes");
+            metadata_file = version_files
+                .all()
+                .find(|(filename, file)| {
+                    matches!(filename, DistFilename::WheelFilename(_))
+                        && filename.version() == version
+                        && file.dist_info_metadata
+                })
+                .map(|(_, file)| file);
+            if metadata_file.is_some() {
+                break;
+            }
+        }
+    }
+    let Some(file) = metadata_file else {
+        debug!("No core metadata available for `{}`", package.name());
+        return Ok(None);
+    };
+
+    // Per PEP 658, the metadata is served alongside the wheel, with a `.metadata` suffix.
+    let mut url = file.url.to_url()?;
+    let path = format!("{}.metadata", url.path());
+    url.set_path(&path);
+
+    Ok(ProjectLinks::from_metadata(
+        fetch_metadata(client, &url).await?,
+    ))
+}
+
+/// Fetch and parse the core metadata at the given URL.
+async fn fetch_metadata(
+    client: &RegistryClient,
+    url: &DisplaySafeUrl,
+) -> anyhow::Result<Metadata23> {
+    let response = client
+        .uncached_client(url)
+        .get(Url::from(url.clone()))
+        .send()
+        .await?
+        .error_for_status()?;
+    let bytes = response.bytes().await?;
+    Ok(Metadata23::parse(&bytes)?)
+}
@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use anyhow::Context;
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tokio::sync::Semaphore;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_cli::IndexMigration;
+use uv_client::{MetadataFormat, RegistryClient, RegistryClientBuilder, VersionFiles};
+use uv_configuration::{Concurrency, DryRun};
+use uv_distribution_types::{File, IndexCapabilities, IndexMetadataRef, IndexUrl};
+use uv_extract::hash::Hasher;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::project::ProjectError;
+use crate::commands::project::lock_target::LockTarget;
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverSettings};
+
+/// Re-pin the packages in the lockfile from one index to another, without re-resolving.
+pub(super) async fn migrate_index(
+    target: LockTarget<'_>,
+    migration: &IndexMigration,
+    dry_run: DryRun,
+    settings: &ResolverSettings,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let IndexMigration { old, new } = migration;
+
+    // Read the existing lockfile.
+    let Some(lock) = target.read().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    // Determine the locked versions of each package that are sourced from the old index.
+    let mut versions: BTreeMap<&PackageName, FxHashSet<&Version>> = BTreeMap::new();
+    for package in lock.index_packages(old, target.install_path())? {
+        let entry = versions.entry(package.name()).or_default();
+        if let Some(version) = package.version() {
+            entry.insert(version);
+        }
+    }
+
+    if versions.is_empty() {
+        warn_user!(
+            "No packages in the lockfile are sourced from `{}`",
+            old.cyan()
+        );
+        return Ok(ExitStatus::Success);
+    }
+
+    // Initialize the registry client.
+    let client =
+        RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
+            .retries_from_env()?
+            .native_tls(network_settings.native_tls)
+            .connectivity(network_settings.connectivity)
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            )
+            .index_locations(&settings.index_locations)
+            .keyring(settings.keyring_provider)
+            .build();
+    let capabilities = IndexCapabilities::default();
+    let download_concurrency = Semaphore::new(concurrency.downloads);
+
+    // Fetch the available files for each package from the new index.
+    let client = &client;
+    let capabilities = &capabilities;
+    let download_concurrency = &download_concurrency;
+    let mut fetches = futures::stream::iter(versions)
+        .map(async |(name, versions)| {
+            let files = fetch_files(
+                client,
+                name,
+                &versions,
+                new,
+                capabilities,
+                download_concurrency,
+            )
+            .await?;
+            Ok::<_, uv_client::Error>((name.clone(), files))
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut files = FxHashMap::default();
+    while let Some((name, entries)) = fetches.next().await.transpose()? {
+        files.insert(name, entries);
+    }
+
+    // If the new index doesn't report a hash that can be compared to the locked hash of an
+    // artifact, download the artifact to compute one, rather than trusting the new index blindly.
+    for package in lock.index_packages(old, target.install_path())? {
+        let Some(available) = files.get_mut(package.name()) else {
+            continue;
+        };
+        for (filename, locked) in package.artifact_hashes() {
+            let Some(file) = available
+                .iter_mut()
+                .find(|file| file.filename.as_ref() == filename)
+            else {
+                continue;
+            };
+            if file
+                .hashes
+                .iter()
+                .any(|hash| hash.algorithm == locked.algorithm)
+            {
+                continue;
+            }
+            warn_user!(
+                "`{}` does not report a `{}` hash for `{}`; downloading it to verify the locked hash",
+                new.cyan(),
+                locked.algorithm,
+                filename.cyan()
+            );
+            let digest = hash_file(client, file, locked.algorithm).await?;
+            file.hashes = HashDigests::from(
+                file.hashes
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(digest))
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    // Rewrite the lockfile to point to the new index.
+    let (migrated, count) = lock.migrate_index(old, new, &files, target.install_path())?;
+
+    let packages = if count == 1 { "package" } else { "packages" };
+    if dry_run.enabled() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Would migrate {} {packages} from `{}` to `{}`",
+                count.bold(),
+                old.cyan(),
+                new.cyan()
+            )
+            .dimmed()
+        )?;
+    } else {
+        target.commit(&migrated).await?;
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Migrated {} {packages} from `{}` to `{}`",
+                count.bold(),
+                old.cyan(),
+                new.cyan()
+            )
+            .dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Download a [`File`] and compute its hash with the given algorithm.
+async fn hash_file(
+    client: &RegistryClient,
+    file: &File,
+    algorithm: HashAlgorithm,
+) -> anyhow::Result<HashDigest> {
+    let url = file.url.to_url()?;
+    debug!("Downloading to compute `{algorithm}` hash: {url}");
+    let response = client
+        .uncached_client(&url)
+        .get(Url::from(url.clone()))
+        .send()
+        .await
+        .with_context(|| format!("Failed to download: {url}"))?
+        .error_for_status()
+        .with_context(|| format!("Failed to download: {url}"))?;
+
+    let mut hasher = Hasher::from(algorithm);
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.with_context(|| format!("Failed to download: {url}"))?;
+        hasher.update(&chunk);
+    }
+    Ok(HashDigest::from(hasher))
+}
+
+/// Fetch the files for the given versions of a package from an index.
+async fn fetch_files(
+    client: &RegistryClient,
+    name: &PackageName,
+    versions: &FxHashSet<&Version>,
+    index: &IndexUrl,
+    capabilities: &IndexCapabilities,
+    download_concurrency: &Semaphore,
+) -> Result<Vec<File>, uv_client::Error> {
+    debug!("Fetching files for `{name}` from: {index}");
+
+    let archives = match client
+        .package_metadata(
+            name,
+            Some(IndexMetadataRef::from(index)),
+            capabilities,
+            download_concurrency,
+        )
+        .await
+    {
+        Ok(archives) => archives,
+        Err(err) => {
+            return match err.into_kind() {
+                // If the package is missing from the index, report the missing artifacts later on.
+                uv_client::ErrorKind::PackageNotFound(_) => Ok(Vec::new()),
+                kind => Err(kind.into()),
+            };
+        }
+    };
+
+    let mut files = Vec::new();
+    for (_, archive) in archives {
+        let MetadataFormat::Simple(archive) = archive else {
+            continue;
+        };
+        for datum in archive.iter() {
+            let version_files =
+                rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                    .expect("archived version files always deserializes");
+            files.extend(
+                version_files
+                    .all()
+                    .filter(|(filename, _)| versions.contains(filename.version()))
+                    .map(|(_, file)| file),
+            );
+        }
+    }
+    Ok(files)
+}
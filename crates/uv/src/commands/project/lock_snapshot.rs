@@ -0,0 +1,200 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, bail};
+use owo_colors::OwoColorize;
+
+use uv_configuration::DryRun;
+use uv_fs::Simplified;
+use uv_resolver::Lock;
+
+use crate::commands::ExitStatus;
+use crate::commands::project::ProjectError;
+use crate::commands::project::lock::LockEvent;
+use crate::commands::project::lock_target::LockTarget;
+use crate::printer::Printer;
+
+/// An operation on the named snapshots of a lockfile.
+#[derive(Debug, Clone)]
+pub(crate) enum SnapshotAction {
+    /// Save the current lockfile as a named snapshot.
+    Create(String),
+    /// Restore the lockfile from a named snapshot.
+    Rollback(String),
+    /// List the saved snapshots.
+    List,
+}
+
+/// Perform a [`SnapshotAction`] on the lockfile for the given target.
+pub(super) async fn snapshot(
+    target: LockTarget<'_>,
+    action: SnapshotAction,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let directory = target.install_path().join(".uv").join("snapshots");
+    match action {
+        SnapshotAction::Create(name) => create(target, &directory, &name, printer).await,
+        SnapshotAction::Rollback(name) => rollback(target, &directory, &name, printer).await,
+        SnapshotAction::List => list(&directory, printer).await,
+    }
+}
+
+/// Save the current lockfile as a named snapshot.
+async fn create(
+    target: LockTarget<'_>,
+    directory: &Path,
+    name: &str,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    validate_name(name)?;
+
+    let Some(contents) = target.read_bytes().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    let path = directory.join(format!("{name}.lock"));
+    if path.exists() {
+        bail!(
+            "A snapshot named `{}` already exists at: `{}`",
+            name.cyan(),
+            path.user_display().cyan()
+        );
+    }
+
+    fs_err::tokio::create_dir_all(directory).await?;
+
+    // Exclude the snapshots from version control, as with the project environment.
+    let gitignore = target.install_path().join(".uv").join(".gitignore");
+    if !gitignore.exists() {
+        fs_err::tokio::write(&gitignore, "*").await?;
+    }
+
+    fs_err::tokio::write(&path, contents).await?;
+
+    writeln!(
+        printer.stderr(),
+        "Saved lockfile snapshot `{}` to: {}",
+        name.cyan(),
+        path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Restore the lockfile from a named snapshot.
+async fn rollback(
+    target: LockTarget<'_>,
+    directory: &Path,
+    name: &str,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    validate_name(name)?;
+
+    let path = directory.join(format!("{name}.lock"));
+    let encoded = match fs_err::tokio::read_to_string(&path).await {
+        Ok(encoded) => encoded,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!(
+                "No snapshot named `{}` found (run `{}` to see the available snapshots)",
+                name.cyan(),
+                "uv lock --list-snapshots".green()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Ensure that the snapshot is a valid lockfile before restoring it.
+    let snapshot: Lock = toml::from_str(&encoded)
+        .with_context(|| format!("Failed to parse snapshot at: `{}`", path.user_display()))?;
+    let existing = target.read().await.ok().flatten();
+
+    fs_err::tokio::write(target.lock_path(), encoded).await?;
+
+    for event in LockEvent::detect_changes(existing.as_ref(), &snapshot, DryRun::Disabled) {
+        writeln!(printer.stderr(), "{event}")?;
+    }
+    writeln!(
+        printer.stderr(),
+        "Restored lockfile from snapshot `{}`",
+        name.cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the saved snapshots, along with their creation time and a summary of their contents.
+async fn list(directory: &Path, printer: Printer) -> anyhow::Result<ExitStatus> {
+    let mut snapshots = Vec::new();
+    match fs_err::tokio::read_dir(directory).await {
+        Ok(mut entries) => {
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let Some(name) = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_suffix(".lock"))
+                else {
+                    continue;
+                };
+                let modified = jiff::Timestamp::try_from(entry.metadata().await?.modified()?)?;
+                let summary = match fs_err::tokio::read_to_string(&path)
+                    .await
+                    .ok()
+                    .and_then(|encoded| toml::from_str::<Lock>(&encoded).ok())
+                {
+                    Some(lock) => {
+                        let count = lock.packages().len();
+                        format!(
+                            "{count} {}",
+                            if count == 1 { "package" } else { "packages" }
+                        )
+                    }
+                    None => "unreadable".to_string(),
+                };
+                snapshots.push((modified, name.to_string(), summary));
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    if snapshots.is_empty() {
+        writeln!(printer.stderr(), "No lockfile snapshots found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Show the most recent snapshots first.
+    snapshots.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+
+    let width = snapshots
+        .iter()
+        .map(|(_, name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    for (modified, name, summary) in snapshots {
+        writeln!(
+            printer.stdout(),
+            "{name:width$}  {}  {}",
+            modified.strftime("%Y-%m-%d %H:%M:%S UTC").dimmed(),
+            summary
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Validate that a snapshot name can be used as a file name.
+fn validate_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty()
+        || name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        bail!(
+            "Invalid snapshot name `{}`: names may only contain alphanumeric characters, `-`, `_`, and `.`, and may not start with `.`",
+            name.cyan()
+        );
+    }
+    Ok(())
+}
@@ -5,7 +5,7 @@ use itertools::Either;
 
 use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy};
 use uv_distribution::LoweredRequirement;
-use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
+use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython, SourcePatch};
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
@@ -96,6 +96,24 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the build constraints for specific packages in the [`LockTarget`].
+    pub(crate) fn build_constraints_package(
+        self,
+    ) -> BTreeMap<PackageName, Vec<uv_pep508::Requirement<VerbatimParsedUrl>>> {
+        match self {
+            Self::Workspace(workspace) => workspace.build_constraints_package(),
+            Self::Script(_) => BTreeMap::new(),
+        }
+    }
+
+    /// Returns the patches to apply to source distributions in the [`LockTarget`].
+    pub(crate) fn patches(self) -> Vec<SourcePatch> {
+        match self {
+            Self::Workspace(workspace) => workspace.patches(),
+            Self::Script(_) => Vec::new(),
+        }
+    }
+
     /// Return the dependency groups that are attached to the target directly, as opposed to being
     /// attached to any members within the target.
     pub(crate) fn dependency_groups(
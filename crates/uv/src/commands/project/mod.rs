@@ -12,8 +12,8 @@ use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::cache_digest;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    PreviewMode, Reinstall, SourceStrategy, Upgrade,
+    Concurrency, Constraints, DependencyGroupsWithDefaults, DownloadLimits, DryRun,
+    ExtrasSpecification, PreviewMode, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredRequirement};
@@ -52,7 +52,7 @@ use uv_workspace::{RequiresPythonSources, Workspace, WorkspaceCache};
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
-use crate::commands::{capitalize, conjunction, pip};
+use crate::commands::{capitalize, conjunction, optional_lock, pip};
 use crate::printer::Printer;
 use crate::settings::{
     InstallerSettingsRef, NetworkSettings, ResolverInstallerSettings, ResolverSettings,
@@ -61,14 +61,23 @@ use crate::settings::{
 pub(crate) mod add;
 pub(crate) mod environment;
 pub(crate) mod export;
+mod expose;
 pub(crate) mod init;
+mod init_template;
 mod install_target;
 pub(crate) mod lock;
+mod lock_links;
+mod lock_migrate;
+pub(crate) mod lock_snapshot;
 mod lock_target;
+pub(crate) mod prefetch;
+pub(crate) mod prune;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
+pub(crate) mod task;
 pub(crate) mod tree;
+pub(crate) mod verify;
 pub(crate) mod version;
 
 #[derive(thiserror::Error, Debug)]
@@ -106,6 +115,14 @@ pub(crate) enum ProjectError {
     )]
     LockedPlatformIncompatibility(String),
 
+    #[error(
+        "Package `{0}` is not included in the locked environment for the requested extras and dependency groups"
+    )]
+    OnlyPackageNotFound(PackageName),
+
+    #[error(transparent)]
+    Wheelhouse(#[from] crate::commands::pip::wheelhouse::WheelhouseError),
+
     #[error(transparent)]
     Conflict(#[from] ConflictError),
 
@@ -692,7 +709,13 @@ impl ScriptInterpreter {
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            );
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -976,7 +999,13 @@ impl ProjectInterpreter {
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            );
 
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -1272,12 +1301,10 @@ impl ProjectEnvironment {
         preview: PreviewMode,
     ) -> Result<Self, ProjectError> {
         // Lock the project environment to avoid synchronization issues.
-        let _lock = ProjectInterpreter::lock(workspace)
-            .await
-            .inspect_err(|err| {
-                warn!("Failed to acquire project environment lock: {err}");
-            })
-            .ok();
+        let _lock = optional_lock(
+            ProjectInterpreter::lock(workspace).await,
+            "project environment",
+        )?;
 
         let upgradeable = preview.is_enabled()
             && python
@@ -1504,12 +1531,7 @@ impl ScriptEnvironment {
         preview: PreviewMode,
     ) -> Result<Self, ProjectError> {
         // Lock the script environment to avoid synchronization issues.
-        let _lock = ScriptInterpreter::lock(script)
-            .await
-            .inspect_err(|err| {
-                warn!("Failed to acquire script environment lock: {err}");
-            })
-            .ok();
+        let _lock = optional_lock(ScriptInterpreter::lock(script).await, "script environment")?;
 
         let upgradeable = python_request
             .as_ref()
@@ -1698,6 +1720,7 @@ pub(crate) async fn resolve_names(
                 upgrade: _,
             },
         compile_bytecode: _,
+        install_hooks: _,
         reinstall: _,
     } = settings;
 
@@ -1707,7 +1730,13 @@ pub(crate) async fn resolve_names(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     index_locations.cache_index_credentials();
 
@@ -1768,7 +1797,7 @@ pub(crate) async fn resolve_names(
         NamedRequirementsResolver::new(
             &hasher,
             state.index(),
-            DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+            DistributionDatabase::new(&client, &build_dispatch, concurrency),
         )
         .with_reporter(Arc::new(ResolverReporter::from(printer)))
         .resolve(unnamed.into_iter())
@@ -1867,7 +1896,13 @@ pub(crate) async fn resolve_environment(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Determine the tags, markers, and interpreter to use for resolution.
     let tags = interpreter.tags()?;
@@ -2031,6 +2066,7 @@ pub(crate) async fn sync_environment(
         exclude_newer,
         link_mode,
         compile_bytecode,
+        install_hooks,
         reinstall,
         build_options,
         sources,
@@ -2041,7 +2077,13 @@ pub(crate) async fn sync_environment(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     let site_packages = SitePackages::from_environment(&venv)?;
 
@@ -2118,6 +2160,7 @@ pub(crate) async fn sync_environment(
         build_options,
         link_mode,
         compile_bytecode,
+        install_hooks,
         index_locations,
         config_setting,
         config_settings_package,
@@ -2129,8 +2172,10 @@ pub(crate) async fn sync_environment(
         &build_dispatch,
         cache,
         &venv,
+        None,
         logger,
         installer_metadata,
+        &DownloadLimits::default(),
         dry_run,
         printer,
     )
@@ -2170,6 +2215,7 @@ pub(crate) async fn update_environment(
     resolve: Box<dyn ResolveLogger>,
     install: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    download_limits: &DownloadLimits,
     concurrency: Concurrency,
     cache: &Cache,
     workspace_cache: WorkspaceCache,
@@ -2200,6 +2246,7 @@ pub(crate) async fn update_environment(
                 upgrade,
             },
         compile_bytecode,
+        install_hooks,
         reinstall,
     } = settings;
 
@@ -2208,7 +2255,13 @@ pub(crate) async fn update_environment(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Respect all requirements from the provided sources.
     let RequirementsSpecification {
@@ -2376,7 +2429,8 @@ pub(crate) async fn update_environment(
         reinstall,
         build_options,
         *link_mode,
-        *compile_bytecode,
+        compile_bytecode,
+        install_hooks,
         index_locations,
         config_setting,
         config_settings_package,
@@ -2388,8 +2442,10 @@ pub(crate) async fn update_environment(
         &build_dispatch,
         cache,
         &venv,
+        None,
         install,
         installer_metadata,
+        download_limits,
         dry_run,
         printer,
     )
@@ -0,0 +1,246 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, ExtrasSpecification, HashCheckingMode, InstallOptions,
+    PreviewMode, TargetTriple,
+};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Index, ResolvedDist};
+use uv_installer::Preparer;
+use uv_normalize::DefaultExtras;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::{FlatIndex, Installable};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::operations;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::{apply_no_virtual_project, store_credentials_from_target};
+use crate::commands::project::{
+    PlatformState, ProjectError, ProjectInterpreter, default_dependency_groups, detect_conflicts,
+};
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
+
+/// Populate the cache with the distributions in the project's lockfile, without installing them.
+pub(crate) async fn prefetch(
+    project_dir: &Path,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    python: Option<String>,
+    python_platform: Option<TargetTriple>,
+    install_mirrors: PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Read the lockfile, without checking if it is up-to-date.
+    let Some(lock) = LockTarget::from(project.workspace()).read().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    // Determine the groups and extras to include.
+    let groups = groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    // Discover the interpreter, without creating (or modifying) the project environment.
+    let interpreter = ProjectInterpreter::discover(
+        project.workspace(),
+        project_dir,
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &network_settings,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Project {
+            workspace: project.workspace(),
+            name: project.project_name(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    // Validate that the set of requested extras and development groups are compatible, and
+    // defined in the lockfile.
+    detect_conflicts(&lock, &extras, &groups)?;
+    target.validate_extras(&extras)?;
+    target.validate_groups(&groups)?;
+
+    // Determine the distributions that the lockfile requires for the target platform.
+    let marker_env = resolution_markers(None, python_platform.as_ref(), &interpreter);
+    let tags = resolution_tags(None, python_platform.as_ref(), &interpreter)?;
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        &extras,
+        &groups,
+        &settings.resolver.build_options,
+        &InstallOptions::default(),
+    )?;
+
+    // Always skip virtual projects, which shouldn't be built or installed.
+    let resolution = apply_no_virtual_project(resolution);
+
+    let distributions = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist.clone()),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let index_locations = &settings.resolver.index_locations;
+    index_locations.cache_index_credentials();
+
+    // Populate credentials from the target.
+    store_credentials_from_target(target);
+
+    // Initialize the registry client.
+    let client_builder = BaseClientBuilder::new()
+        .retries_from_env()?
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .keyring(settings.resolver.keyring_provider)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
+    let client = RegistryClientBuilder::try_from(client_builder)?
+        .cache(cache.clone())
+        .index_locations(index_locations)
+        .index_strategy(settings.resolver.index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Extract the hashes from the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+    let build_hasher = HashStrategy::default();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            &settings.resolver.build_options,
+        )
+    };
+
+    // Create a build dispatch, for any distributions that need to be built from source.
+    let state = PlatformState::default();
+    let build_constraints = target.build_constraints();
+    let build_constraints_package = target.build_constraints_package();
+    let patches = target.patches();
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        &interpreter,
+        index_locations,
+        &flat_index,
+        &settings.resolver.dependency_metadata,
+        state.clone().into_inner(),
+        settings.resolver.index_strategy,
+        &settings.resolver.config_setting,
+        &settings.resolver.config_settings_package,
+        BuildIsolation::Isolated,
+        settings.resolver.link_mode,
+        &settings.resolver.build_options,
+        &build_hasher,
+        settings.resolver.exclude_newer.clone(),
+        settings.resolver.sources,
+        workspace_cache,
+        concurrency,
+        preview,
+    )
+    .with_build_constraints_package(build_constraints_package)
+    .with_patches(patches);
+
+    // Download, build, and unzip the distributions into the cache.
+    let preparer = Preparer::new(
+        cache,
+        &tags,
+        &hasher,
+        &settings.resolver.build_options,
+        DistributionDatabase::new(&client, &build_dispatch, concurrency),
+    )
+    .with_reporter(Arc::new(
+        PrepareReporter::from(printer).with_length(distributions.len() as u64),
+    ));
+
+    let wheels = match preparer
+        .prepare(distributions, state.in_flight(), &resolution)
+        .await
+    {
+        Ok(wheels) => wheels,
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(operations::Error::Prepare(err))
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    let s = if wheels.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Prefetched {} {}",
+            format!("{} package{}", wheels.len(), s).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
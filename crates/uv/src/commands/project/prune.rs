@@ -0,0 +1,337 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_configuration::{
+    BuildOptions, DependencyGroups, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
+    ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_distribution_types::{InstalledMetadata, Name};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::{DefaultExtras, DefaultGroups, ExtraName, GroupName, PackageName};
+use uv_platform_tags::Tags;
+use uv_pypi_types::ResolverMarkerEnvironment;
+use uv_python::PythonEnvironment;
+use uv_resolver::{Installable, Lock, LockError};
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::apply_no_virtual_project;
+use crate::commands::project::{ProjectError, default_dependency_groups};
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Remove any packages from the project environment that aren't required by the lockfile.
+pub(crate) async fn prune(
+    project_dir: &Path,
+    dry_run: DryRun,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    settings: ResolverInstallerSettings,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Read the lockfile, without checking if it is up-to-date.
+    let Some(lock) = LockTarget::from(project.workspace()).read().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    // Locate the project environment.
+    let root = project.workspace().venv(None);
+    let venv = match PythonEnvironment::from_root(&root, cache) {
+        Ok(venv) => venv,
+        Err(uv_python::Error::MissingEnvironment(_)) => {
+            bail!(
+                "No project environment found at: `{}` (run `{}` to create it)",
+                root.user_display().cyan(),
+                "uv sync".green()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Determine the groups and extras to include.
+    let groups = groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Project {
+            workspace: project.workspace(),
+            name: project.project_name(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    let marker_env = resolution_markers(None, None, venv.interpreter());
+    let tags = resolution_tags(None, None, venv.interpreter())?;
+    let explainer = PruneExplainer::new(
+        target,
+        &marker_env,
+        &tags,
+        &extras,
+        &groups,
+        &settings.resolver.build_options,
+        &InstallOptions::default(),
+    )?;
+
+    // Identify any installed packages that aren't required by the lockfile, retaining seed
+    // packages as in an exact sync.
+    let site_packages = SitePackages::from_environment(&venv)?;
+    let retain_seed_packages = uv_installer::retain_seed_packages(&venv);
+    let mut extraneous = site_packages
+        .iter()
+        .filter(|dist| !explainer.is_required(dist.name()))
+        .filter(|dist| !(retain_seed_packages && uv_installer::is_seed_package(dist, &venv)))
+        .cloned()
+        .collect::<Vec<_>>();
+    extraneous.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+
+    if extraneous.is_empty() {
+        if dry_run.enabled() {
+            writeln!(printer.stderr(), "Would make no changes")?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                "No packages to remove from the project environment".dimmed()
+            )?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Uninstall each package.
+    if !dry_run.enabled() {
+        for dist in &extraneous {
+            let summary = uv_installer::uninstall(dist).await?;
+            debug!(
+                "Uninstalled {} ({} file{}, {} director{})",
+                dist.name(),
+                summary.file_count,
+                if summary.file_count == 1 { "" } else { "s" },
+                summary.dir_count,
+                if summary.dir_count == 1 { "y" } else { "ies" },
+            );
+        }
+    }
+
+    let uninstalls = extraneous.len();
+    let s = if uninstalls == 1 { "" } else { "s" };
+    if dry_run.enabled() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Would uninstall {}",
+                format!("{uninstalls} package{s}").bold(),
+            )
+            .dimmed()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Uninstalled {} {}",
+                format!("{uninstalls} package{s}").bold(),
+                format!("in {}", elapsed(start.elapsed())).dimmed(),
+            )
+            .dimmed()
+        )?;
+    }
+
+    for dist in extraneous {
+        writeln!(
+            printer.stderr(),
+            " {} {}{} {}",
+            "-".red(),
+            dist.name().as_ref().bold(),
+            dist.installed_version().to_string().dimmed(),
+            format!("({})", explainer.explain(dist.name())).dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Determines why an installed package isn't required by a lockfile, given the selected extras,
+/// dependency groups, and installation options.
+pub(crate) struct PruneExplainer<'lock> {
+    lock: &'lock Lock,
+    /// The packages required by the selected extras and groups.
+    required: FxHashSet<PackageName>,
+    /// The packages that would be required, if not for the `--no-install-*` options.
+    unfiltered: FxHashSet<PackageName>,
+    /// The packages that would be required by each extra that isn't enabled.
+    extras: Vec<(ExtraName, FxHashSet<PackageName>)>,
+    /// The packages that would be required by each dependency group that isn't enabled.
+    groups: Vec<(GroupName, FxHashSet<PackageName>)>,
+}
+
+impl<'lock> PruneExplainer<'lock> {
+    pub(crate) fn new(
+        target: InstallTarget<'lock>,
+        marker_env: &ResolverMarkerEnvironment,
+        tags: &Tags,
+        extras: &ExtrasSpecificationWithDefaults,
+        groups: &DependencyGroupsWithDefaults,
+        build_options: &BuildOptions,
+        install_options: &InstallOptions,
+    ) -> Result<Self, LockError> {
+        let required = |extras: &ExtrasSpecificationWithDefaults,
+                        groups: &DependencyGroupsWithDefaults,
+                        install_options: &InstallOptions|
+         -> Result<FxHashSet<PackageName>, LockError> {
+            let resolution = target.to_resolution(
+                marker_env,
+                tags,
+                extras,
+                groups,
+                build_options,
+                install_options,
+            )?;
+            Ok(apply_no_virtual_project(resolution)
+                .distributions()
+                .map(|dist| dist.name().clone())
+                .collect())
+        };
+
+        // Identify the extras and groups defined by the root packages.
+        let roots = target.roots().collect::<FxHashSet<_>>();
+        let members = target
+            .lock()
+            .packages()
+            .iter()
+            .filter(|package| roots.contains(package.name()))
+            .collect::<Vec<_>>();
+        let known_extras = members
+            .iter()
+            .flat_map(|package| package.provides_extras())
+            .filter(|extra| !extras.contains(extra))
+            .collect::<FxHashSet<_>>();
+        let known_groups = members
+            .iter()
+            .flat_map(|package| package.dependency_groups().keys())
+            .chain(target.lock().dependency_groups().keys())
+            .filter(|group| !groups.contains(group))
+            .collect::<FxHashSet<_>>();
+
+        Ok(Self {
+            lock: target.lock(),
+            required: required(extras, groups, install_options)?,
+            unfiltered: required(extras, groups, &InstallOptions::default())?,
+            extras: known_extras
+                .into_iter()
+                .map(|extra| {
+                    let spec = ExtrasSpecification::from_extra(vec![extra.clone()])
+                        .with_defaults(DefaultExtras::default());
+                    Ok((extra.clone(), required(&spec, groups, install_options)?))
+                })
+                .collect::<Result<_, LockError>>()?,
+            groups: known_groups
+                .into_iter()
+                .map(|group| {
+                    let spec = DependencyGroups::from_group(group.clone())
+                        .with_defaults(DefaultGroups::default());
+                    Ok((group.clone(), required(extras, &spec, install_options)?))
+                })
+                .collect::<Result<_, LockError>>()?,
+        })
+    }
+
+    /// Returns `true` if the package is required by the selected extras and groups.
+    pub(crate) fn is_required(&self, name: &PackageName) -> bool {
+        self.required.contains(name)
+    }
+
+    /// Determine why the given package isn't required.
+    pub(crate) fn explain(&self, name: &PackageName) -> PruneReason {
+        if !self
+            .lock
+            .packages()
+            .iter()
+            .any(|package| package.name() == name)
+        {
+            return PruneReason::NotLocked;
+        }
+
+        if self.unfiltered.contains(name) {
+            return PruneReason::Excluded;
+        }
+
+        let mut extras = self
+            .extras
+            .iter()
+            .filter(|(_, required)| required.contains(name))
+            .map(|(extra, _)| extra.clone())
+            .collect::<Vec<_>>();
+        let mut groups = self
+            .groups
+            .iter()
+            .filter(|(_, required)| required.contains(name))
+            .map(|(group, _)| group.clone())
+            .collect::<Vec<_>>();
+        if extras.is_empty() && groups.is_empty() {
+            return PruneReason::NotRequired;
+        }
+        extras.sort_unstable();
+        groups.sort_unstable();
+
+        PruneReason::Optional { extras, groups }
+    }
+}
+
+/// The reason an installed package isn't required by the lockfile.
+#[derive(Debug)]
+pub(crate) enum PruneReason {
+    /// The package isn't present in the lockfile.
+    NotLocked,
+    /// The package is required, but was omitted via `--no-install-project`, etc.
+    Excluded,
+    /// The package is only required by extras or dependency groups that aren't enabled.
+    Optional {
+        extras: Vec<ExtraName>,
+        groups: Vec<GroupName>,
+    },
+    /// The package is in the lockfile, but isn't required in the current environment (e.g., due to
+    /// its markers, or because it's only required by another workspace member).
+    NotRequired,
+}
+
+impl std::fmt::Display for PruneReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotLocked => write!(f, "not in the lockfile"),
+            Self::Excluded => write!(f, "excluded from installation"),
+            Self::Optional { extras, groups } => {
+                let sources = extras
+                    .iter()
+                    .map(|extra| format!("the `{extra}` extra"))
+                    .chain(groups.iter().map(|group| format!("the `{group}` group")))
+                    .collect::<Vec<_>>();
+                write!(f, "only required by {}", sources.join(" or "))
+            }
+            Self::NotRequired => write!(f, "not required in this environment"),
+        }
+    }
+}
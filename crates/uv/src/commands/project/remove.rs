@@ -5,15 +5,15 @@ use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
-use tracing::{debug, warn};
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_configuration::{
-    Concurrency, DependencyGroups, DryRun, EditableMode, ExtrasSpecification, InstallOptions,
-    PreviewMode,
+    Concurrency, DependencyGroups, DownloadLimits, DryRun, EditableMode, ExtrasSpecification,
+    InstallOptions, PreviewMode,
 };
 use uv_fs::Simplified;
-use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups};
+use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, GroupName};
 use uv_pep508::PackageName;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_scripts::{Pep723ItemRef, Pep723Metadata, Pep723Script};
@@ -33,7 +33,7 @@ use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     default_dependency_groups,
 };
-use crate::commands::{ExitStatus, diagnostics, project};
+use crate::commands::{ExitStatus, diagnostics, optional_lock, project};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
@@ -47,6 +47,7 @@ pub(crate) async fn remove(
     no_sync: bool,
     packages: Vec<PackageName>,
     dependency_type: DependencyType,
+    include_groups: Vec<GroupName>,
     package: Option<PackageName>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -176,6 +177,17 @@ pub(crate) async fn remove(
         }
     }
 
+    // Remove any `include-group` entries from the dependency group.
+    if let DependencyType::Group(ref group) = dependency_type {
+        for include in &include_groups {
+            if !toml.remove_dependency_group_include(group, include)? {
+                anyhow::bail!(
+                    "The group `{include}` is not included in `dependency-groups.{group}`"
+                );
+            }
+        }
+    }
+
     let content = toml.to_string();
 
     // Save the modified `pyproject.toml` or script.
@@ -281,13 +293,7 @@ pub(crate) async fn remove(
         }
     };
 
-    let _lock = target
-        .acquire_lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = optional_lock(target.acquire_lock().await, "environment")?;
 
     // Determine the lock mode.
     let mode = if locked {
@@ -363,6 +369,8 @@ pub(crate) async fn remove(
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        &DownloadLimits::default(),
+        None,
         concurrency,
         cache,
         WorkspaceCache::default(),
@@ -372,7 +380,7 @@ pub(crate) async fn remove(
     )
     .await
     {
-        Ok(()) => {}
+        Ok(_) => {}
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -386,6 +394,7 @@ pub(crate) async fn remove(
 
 /// Represents the destination where dependencies are added, either to a project or a script.
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 enum RemoveTarget {
     /// A PEP 723 script, with inline metadata.
     Project(VirtualProject),
@@ -3,13 +3,16 @@ use std::env::VarError;
 use std::ffi::OsString;
 use std::fmt::Write;
 use std::io::Read;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
 
 use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio::process::Command;
 use tracing::{debug, trace, warn};
 use url::Url;
@@ -18,8 +21,8 @@ use uv_cache::Cache;
 use uv_cli::ExternalCommand;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DryRun, EditableMode, ExtrasSpecification,
-    InstallOptions, PreviewMode,
+    Concurrency, Constraints, DependencyGroups, DownloadLimits, DryRun, EditableMode,
+    ExtrasSpecification, InstallOptions, PreviewMode,
 };
 use uv_distribution_types::Requirement;
 use uv_fs::which::is_executable;
@@ -57,7 +60,7 @@ use crate::commands::project::{
     validate_project_requires_python,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::{ExitStatus, diagnostics, project};
+use crate::commands::{ExitStatus, diagnostics, elapsed, optional_lock, project};
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
@@ -76,6 +79,7 @@ pub(crate) async fn run(
     isolated: bool,
     all_packages: bool,
     package: Option<PackageName>,
+    each_package: Option<EachPackage>,
     no_project: bool,
     no_config: bool,
     extras: ExtrasSpecification,
@@ -193,6 +197,9 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     // The lockfile used for the base environment.
     let mut base_lock: Option<(Lock, PathBuf)> = None;
 
+    // The workspace members to run the command in, if `--each-package` is set.
+    let mut members: Vec<(PackageName, PathBuf)> = Vec::new();
+
     // Determine whether the command to execute is a PEP 723 script.
     let temp_dir;
     let script_interpreter = if let Some(script) = script {
@@ -241,13 +248,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             .await?
             .into_environment()?;
 
-            let _lock = environment
-                .lock()
-                .await
-                .inspect_err(|err| {
-                    warn!("Failed to acquire environment lock: {err}");
-                })
-                .ok();
+            let _lock = optional_lock(environment.lock().await, "environment")?;
 
             // Determine the lock mode.
             let mode = if frozen {
@@ -316,6 +317,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Box::new(SummaryInstallLogger)
                 },
                 installer_metadata,
+                &DownloadLimits::default(),
+                None,
                 concurrency,
                 cache,
                 workspace_cache.clone(),
@@ -325,7 +328,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             )
             .await
             {
-                Ok(()) => {}
+                Ok(_) => {}
                 Err(ProjectError::Operation(err)) => {
                     return diagnostics::OperationDiagnostic::native_tls(
                         network_settings.native_tls,
@@ -394,13 +397,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         )
                     });
 
-                let _lock = environment
-                    .lock()
-                    .await
-                    .inspect_err(|err| {
-                        warn!("Failed to acquire environment lock: {err}");
-                    })
-                    .ok();
+                let _lock = optional_lock(environment.lock().await, "environment")?;
 
                 match update_environment(
                     environment,
@@ -421,6 +418,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    &DownloadLimits::default(),
                     concurrency,
                     cache,
                     workspace_cache.clone(),
@@ -505,6 +503,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 "`--package` is a no-op for Python scripts with inline metadata, which always run in isolation"
             );
         }
+        if each_package.is_some() {
+            bail!(
+                "`--each-package` is not supported for Python scripts with inline metadata, which always run in isolation"
+            );
+        }
         if no_sync {
             warn_user!(
                 "`--no-sync` is a no-op for Python scripts with inline metadata, which always run in isolation"
@@ -595,6 +598,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             }
         }
 
+        if each_package.is_some() && project.is_none() {
+            bail!("`--each-package` can only be used in a project or workspace");
+        }
+
         if let Some(project) = project {
             if let Some(project_name) = project.project_name() {
                 debug!(
@@ -607,6 +614,23 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     project.workspace().install_path().display()
                 );
             }
+
+            // Determine the workspace members to run the command in.
+            if let Some(each_package) = each_package.as_ref() {
+                for name in &each_package.skip {
+                    if !project.workspace().packages().contains_key(name) {
+                        bail!("Package `{name}` not found in workspace");
+                    }
+                }
+                members = project
+                    .workspace()
+                    .packages()
+                    .iter()
+                    .filter(|(name, _)| !each_package.skip.contains(name))
+                    .map(|(name, member)| (name.clone(), member.root().clone()))
+                    .collect();
+            }
+
             // Determine the groups and extras to include.
             let default_groups = default_dependency_groups(project.pyproject_toml())?;
             let default_extras = DefaultExtras::default();
@@ -622,7 +646,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .retries_from_env()?
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
-                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .proxy_settings(network_settings.proxy_settings.clone())
+                    .http_connection(network_settings.http_connection.clone())
+                    .retry_policies(
+                        network_settings.retry_policy.clone(),
+                        network_settings.download_retry_policy.clone(),
+                    );
 
                 // Resolve the Python request and requirement for the workspace.
                 let WorkspacePython {
@@ -714,13 +744,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         .map(|lock| (lock, project.workspace().install_path().to_owned()));
                 }
             } else {
-                let _lock = venv
-                    .lock()
-                    .await
-                    .inspect_err(|err| {
-                        warn!("Failed to acquire environment lock: {err}");
-                    })
-                    .ok();
+                let _lock = optional_lock(venv.lock().await, "environment")?;
 
                 // Determine the lock mode.
                 let mode = if frozen {
@@ -829,6 +853,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    &DownloadLimits::default(),
+                    None,
                     concurrency,
                     cache,
                     workspace_cache.clone(),
@@ -838,7 +864,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 )
                 .await
                 {
-                    Ok(()) => {}
+                    Ok(_) => {}
                     Err(ProjectError::Operation(err)) => {
                         return diagnostics::OperationDiagnostic::native_tls(
                             network_settings.native_tls,
@@ -864,7 +890,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .retries_from_env()?
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
-                    .allow_insecure_host(network_settings.allow_insecure_host.clone());
+                    .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .proxy_settings(network_settings.proxy_settings.clone())
+                    .http_connection(network_settings.http_connection.clone())
+                    .retry_policies(
+                        network_settings.retry_policy.clone(),
+                        network_settings.download_retry_policy.clone(),
+                    );
 
                 // (1) Explicit request from user
                 let python_request = if let Some(request) = python.as_deref() {
@@ -935,7 +967,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             .retries_from_env()?
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+            .allow_insecure_host(network_settings.allow_insecure_host.clone())
+            .proxy_settings(network_settings.proxy_settings.clone())
+            .http_connection(network_settings.http_connection.clone())
+            .retry_policies(
+                network_settings.retry_policy.clone(),
+                network_settings.download_retry_policy.clone(),
+            );
 
         let spec =
             RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
@@ -1234,9 +1272,6 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         return Ok(ExitStatus::Error);
     };
 
-    debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
-
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -1267,19 +1302,38 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     .flat_map(std::env::split_paths),
             ),
     )?;
-    process.env(EnvVars::PATH, new_path);
 
-    // Increment recursion depth counter.
-    process.env(
-        EnvVars::UV_RUN_RECURSION_DEPTH,
-        (recursion_depth + 1).to_string(),
-    );
+    let configure = |process: &mut Command| {
+        process.env(EnvVars::PATH, &new_path);
+
+        // Increment recursion depth counter.
+        process.env(
+            EnvVars::UV_RUN_RECURSION_DEPTH,
+            (recursion_depth + 1).to_string(),
+        );
 
-    // Ensure `VIRTUAL_ENV` is set.
-    if interpreter.is_virtualenv() {
-        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        // Ensure `VIRTUAL_ENV` is set.
+        if interpreter.is_virtualenv() {
+            process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        }
+    };
+
+    if let Some(each_package) = each_package {
+        return run_each_package(
+            &command,
+            interpreter,
+            members,
+            each_package.jobs,
+            configure,
+            printer,
+        )
+        .await;
     }
 
+    debug!("Running `{command}`");
+    let mut process = command.as_command(interpreter);
+    configure(&mut process);
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
@@ -1290,6 +1344,149 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     run_to_completion(handle).await
 }
 
+/// The workspace members to run a command in, with `uv run --each-package`.
+#[derive(Debug, Clone)]
+pub(crate) struct EachPackage {
+    /// The workspace members to skip.
+    pub(crate) skip: Vec<PackageName>,
+    /// The maximum number of members to run the command in at once.
+    pub(crate) jobs: NonZeroUsize,
+}
+
+/// Run the command in each of the given workspace members, from the member's directory.
+///
+/// The status of each run is reported as it completes. When running in multiple members at once,
+/// the output of each run is captured and printed once it completes, such that the output of
+/// concurrent runs isn't interleaved. Returns the exit status of the first failing member, in
+/// workspace order, or success if the command succeeded in every member.
+async fn run_each_package(
+    command: &RunCommand,
+    interpreter: &Interpreter,
+    members: Vec<(PackageName, PathBuf)>,
+    jobs: NonZeroUsize,
+    configure: impl Fn(&mut Command),
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let start = std::time::Instant::now();
+    let capture = jobs.get() > 1;
+
+    let mut runs = futures::stream::iter(members.into_iter().enumerate())
+        .map(|(index, (name, root))| {
+            let mut process = command.as_command(interpreter);
+            configure(&mut process);
+            process.current_dir(&root);
+            if capture {
+                process
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::piped());
+            }
+            async move {
+                debug!("Running `{command}` in: {}", root.user_display());
+                let mut handle = process.spawn().with_context(|| {
+                    format!(
+                        "Failed to spawn `{}` in `{name}`",
+                        command.display_executable()
+                    )
+                })?;
+                let stdout = handle.stdout.take();
+                let stderr = handle.stderr.take();
+                let (status, stdout, stderr) = tokio::try_join!(
+                    run_to_completion(handle),
+                    read_captured(stdout),
+                    read_captured(stderr),
+                )?;
+                Ok::<_, anyhow::Error>((index, name, status, stdout, stderr))
+            }
+        })
+        .buffer_unordered(jobs.get());
+
+    let mut results = Vec::new();
+    while let Some((index, name, status, stdout, stderr)) = runs.next().await.transpose()? {
+        // Print the captured output of the run before reporting its status.
+        {
+            use std::io::Write;
+            std::io::stdout().lock().write_all(&stdout)?;
+            std::io::stderr().lock().write_all(&stderr)?;
+        }
+
+        match status {
+            ExitStatus::Success | ExitStatus::External(0) => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {}",
+                    "Succeeded in".green().bold(),
+                    name.bold()
+                )?;
+            }
+            ExitStatus::External(code) => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {} {}",
+                    "Failed in".red().bold(),
+                    name.bold(),
+                    format!("(exit code {code})").dimmed()
+                )?;
+            }
+            ExitStatus::Failure | ExitStatus::Error => {
+                writeln!(
+                    printer.stderr(),
+                    "{} {}",
+                    "Failed in".red().bold(),
+                    name.bold()
+                )?;
+            }
+        }
+        results.push((index, name, status));
+    }
+    results.sort_by_key(|(index, ..)| *index);
+
+    let failed = results
+        .iter()
+        .filter(|(.., status)| !matches!(status, ExitStatus::Success | ExitStatus::External(0)))
+        .collect::<Vec<_>>();
+
+    let s = if results.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Ran `{command}` in {} {}",
+            format!("{} package{s}", results.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    let Some((_, _, status)) = failed.first() else {
+        return Ok(ExitStatus::Success);
+    };
+
+    let s = if failed.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}{} The command failed in {} package{s}: {}",
+        "error".red().bold(),
+        ":".bold(),
+        failed.len(),
+        failed
+            .iter()
+            .map(|(_, name, _)| format!("`{}`", name.cyan()))
+            .join(", ")
+    )?;
+
+    Ok(*status)
+}
+
+/// Read the captured output of a child process to completion, if it was captured.
+async fn read_captured(pipe: Option<impl AsyncRead + Unpin>) -> anyhow::Result<Vec<u8>> {
+    let mut output = Vec::new();
+    if let Some(mut pipe) = pipe {
+        pipe.read_to_end(&mut output).await?;
+    }
+    Ok(output)
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: &RequirementsSpecification,
@@ -1639,6 +1836,12 @@ impl RunCommand {
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone())
+                    .proxy_settings(network_settings.proxy_settings.clone())
+                    .http_connection(network_settings.http_connection.clone())
+                    .retry_policies(
+                        network_settings.retry_policy.clone(),
+                        network_settings.download_retry_policy.clone(),
+                    )
                     .build();
                 let response = client
                     .for_host(&url)
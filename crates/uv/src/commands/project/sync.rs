@@ -1,31 +1,34 @@
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use serde::Serialize;
-use tracing::warn;
+use tracing::debug;
 use uv_cache::Cache;
 use uv_cli::SyncFormat;
-use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    PreviewMode, TargetTriple,
+    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DownloadLimits,
+    DryRun, EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode,
+    InstallOptions, PreviewMode, TargetTriple,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Requirement, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, Index, IndexLocations, Name, Requirement, Resolution, ResolvedDist,
+    SourceDist,
 };
 use uv_fs::{PortablePathBuf, Simplified};
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_python::{
+    Prefix, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, Target,
+};
 use uv_resolver::{FlatIndex, Installable, Lock};
 use uv_scripts::{Pep723ItemRef, Pep723Script};
 use uv_settings::PythonInstallMirrors;
@@ -35,17 +38,21 @@ use uv_workspace::pyproject::Source;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{Changelog, InstallPlan, Modifications};
 use crate::commands::pip::resolution_markers;
+use crate::commands::pip::wheelhouse::Wheelhouse;
 use crate::commands::pip::{operations, resolution_tags};
+use crate::commands::project::expose;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::prune::PruneExplainer;
 use crate::commands::project::{
-    PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState,
-    default_dependency_groups, detect_conflicts, script_specification, update_environment,
+    PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptEnvironment,
+    UniversalState, default_dependency_groups, detect_conflicts, script_specification,
+    update_environment,
 };
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, diagnostics, optional_lock};
 use crate::printer::Printer;
 use crate::settings::{InstallerSettingsRef, NetworkSettings, ResolverInstallerSettings};
 
@@ -57,6 +64,12 @@ pub(crate) async fn sync(
     frozen: bool,
     dry_run: DryRun,
     active: Option<bool>,
+    install_target: Option<Target>,
+    install_prefix: Option<Prefix>,
+    no_scripts: bool,
+    expose_scripts: Option<PathBuf>,
+    download_limits: DownloadLimits,
+    find_links_only: Option<PathBuf>,
     all_packages: bool,
     package: Option<PackageName>,
     extras: ExtrasSpecification,
@@ -64,13 +77,14 @@ pub(crate) async fn sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    prune_explain: bool,
     python: Option<String>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
-    settings: ResolverInstallerSettings,
-    network_settings: NetworkSettings,
+    mut settings: ResolverInstallerSettings,
+    mut network_settings: NetworkSettings,
     script: Option<Pep723Script>,
     installer_metadata: bool,
     concurrency: Concurrency,
@@ -86,6 +100,18 @@ pub(crate) async fn sync(
         );
     }
 
+    // If `--find-links-only` is provided, install exclusively from the given directory, without
+    // accessing the network. Any build dependencies are resolved from the directory, too.
+    let wheelhouse = find_links_only
+        .as_deref()
+        .map(Wheelhouse::from_path)
+        .transpose()?;
+    if let Some(wheelhouse) = &wheelhouse {
+        settings.resolver.index_locations =
+            IndexLocations::new(vec![], vec![wheelhouse.index()], true);
+        network_settings.connectivity = Connectivity::Offline;
+    }
+
     // Identify the target.
     let workspace_cache = WorkspaceCache::default();
     let target = if let Some(script) = script {
@@ -141,6 +167,52 @@ pub(crate) async fn sync(
 
     // Discover or create the virtual environment.
     let environment = match &target {
+        SyncTarget::Project(project) if install_target.is_some() || install_prefix.is_some() => {
+            // When installing into a `--target` or `--prefix` directory, discover the project's
+            // interpreter, but don't create (or modify) the project environment.
+            let interpreter = ProjectInterpreter::discover(
+                project.workspace(),
+                project_dir,
+                &groups,
+                python.as_deref().map(PythonRequest::parse),
+                &network_settings,
+                python_preference,
+                python_downloads,
+                &install_mirrors,
+                false,
+                no_config,
+                active,
+                cache,
+                printer,
+                preview,
+            )
+            .await?
+            .into_interpreter();
+
+            let environment = PythonEnvironment::from_interpreter(interpreter);
+            let environment = if let Some(target) = install_target {
+                debug!(
+                    "Using `--target` directory at {}",
+                    target.root().user_display()
+                );
+                environment.with_target(target)?
+            } else if let Some(prefix) = install_prefix {
+                debug!(
+                    "Using `--prefix` directory at {}",
+                    prefix.root().user_display()
+                );
+                environment.with_prefix(prefix)?
+            } else {
+                environment
+            };
+            let environment = if no_scripts {
+                environment.without_scripts()
+            } else {
+                environment
+            };
+
+            SyncEnvironment::Directory(environment)
+        }
         SyncTarget::Project(project) => SyncEnvironment::Project(
             ProjectEnvironment::get_or_init(
                 project.workspace(),
@@ -180,18 +252,13 @@ pub(crate) async fn sync(
         ),
     };
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = optional_lock(environment.lock().await, "environment")?;
 
     let sync_report = SyncReport {
         dry_run: dry_run.enabled(),
         environment: EnvironmentReport::from(&environment),
         action: SyncAction::from(&environment),
+        plan: None,
         target: TargetName::from(&target),
     };
 
@@ -252,6 +319,7 @@ pub(crate) async fn sync(
                 Box::new(DefaultResolveLogger),
                 Box::new(DefaultInstallLogger),
                 installer_metadata,
+                &download_limits,
                 concurrency,
                 cache,
                 workspace_cache.clone(),
@@ -352,7 +420,7 @@ pub(crate) async fn sync(
         writeln!(printer.stderr(), "{message}")?;
     }
 
-    let report = Report {
+    let mut report = Report {
         schema: SchemaReport::default(),
         target: TargetName::from(&target),
         project: target.project().map(ProjectReport::from),
@@ -362,8 +430,11 @@ pub(crate) async fn sync(
         dry_run: dry_run.enabled(),
     };
 
-    if let Some(output) = report.format(output_format) {
-        writeln!(printer.stdout_important(), "{output}")?;
+    // In dry-run mode, defer the report until the install plan is known.
+    if !dry_run.enabled() {
+        if let Some(output) = report.format(output_format) {
+            writeln!(printer.stdout_important(), "{output}")?;
+        }
     }
 
     // Identify the installation target.
@@ -373,13 +444,13 @@ pub(crate) async fn sync(
     let state = state.fork();
 
     // Perform the sync operation.
-    match do_sync(
+    let result = do_sync(
         sync_target,
         &environment,
         &extras,
         &groups,
         editable,
-        install_options,
+        install_options.clone(),
         modifications,
         python_platform.as_ref(),
         (&settings).into(),
@@ -387,6 +458,8 @@ pub(crate) async fn sync(
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        &download_limits,
+        wheelhouse.as_ref(),
         concurrency,
         cache,
         workspace_cache,
@@ -394,9 +467,68 @@ pub(crate) async fn sync(
         printer,
         preview,
     )
-    .await
-    {
-        Ok(()) => {}
+    .await;
+
+    // In dry-run mode, print the deferred report, including the install plan if the sync
+    // succeeded, before handling the outcome, such that it's printed even if the sync fails.
+    if dry_run.enabled() {
+        if let Ok(changelog) = &result {
+            report.sync.plan.clone_from(&changelog.plan);
+        }
+        if let Some(output) = report.format(output_format) {
+            writeln!(printer.stdout_important(), "{output}")?;
+        }
+    }
+
+    match result {
+        Ok(changelog) => {
+            // Explain why any extraneous packages were removed.
+            if prune_explain {
+                let removed = changelog.removed();
+                if !removed.is_empty() {
+                    let marker_env = resolution_markers(
+                        None,
+                        python_platform.as_ref(),
+                        environment.interpreter(),
+                    );
+                    let tags =
+                        resolution_tags(None, python_platform.as_ref(), environment.interpreter())?;
+                    let explainer = PruneExplainer::new(
+                        sync_target,
+                        &marker_env,
+                        &tags,
+                        &extras,
+                        &groups,
+                        &settings.resolver.build_options,
+                        &install_options,
+                    )?;
+                    for name in removed {
+                        writeln!(
+                            printer.stderr(),
+                            "{} {} {}",
+                            if dry_run.enabled() {
+                                "Would remove"
+                            } else {
+                                "Removed"
+                            },
+                            name.bold(),
+                            format!("({})", explainer.explain(name)).dimmed()
+                        )?;
+                    }
+                }
+            }
+
+            // Expose the environment's scripts in the requested directory. Relative paths (from
+            // configuration files) are resolved against the workspace root.
+            if let (Some(directory), SyncTarget::Project(project), SyncEnvironment::Project(venv)) =
+                (expose_scripts.as_ref(), &target, &environment)
+            {
+                if !dry_run.enabled() {
+                    let directory = project.workspace().install_path().join(directory);
+                    expose::expose_scripts(venv, &directory, printer)?;
+                }
+            }
+        }
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -492,6 +624,7 @@ fn identify_installation_target<'a>(
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 enum SyncTarget {
     /// Sync a project environment.
     Project(VirtualProject),
@@ -521,6 +654,8 @@ enum SyncEnvironment {
     Project(ProjectEnvironment),
     /// A Python environment for a script.
     Script(ScriptEnvironment),
+    /// A `--target` or `--prefix` directory, installed into with the project's interpreter.
+    Directory(PythonEnvironment),
 }
 
 impl SyncEnvironment {
@@ -528,6 +663,19 @@ impl SyncEnvironment {
         match self {
             Self::Project(env) => env.dry_run_target(),
             Self::Script(env) => env.dry_run_target(),
+            Self::Directory(_) => None,
+        }
+    }
+
+    /// Return the root of the `--target` or `--prefix` directory, if any.
+    fn directory(&self) -> Option<&Path> {
+        match self {
+            Self::Project(_) | Self::Script(_) => None,
+            Self::Directory(env) => env
+                .interpreter()
+                .target()
+                .map(Target::root)
+                .or_else(|| env.interpreter().prefix().map(Prefix::root)),
         }
     }
 }
@@ -539,6 +687,7 @@ impl Deref for SyncEnvironment {
         match self {
             Self::Project(environment) => Deref::deref(environment),
             Self::Script(environment) => Deref::deref(environment),
+            Self::Directory(environment) => environment,
         }
     }
 }
@@ -559,13 +708,15 @@ pub(super) async fn do_sync(
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    download_limits: &DownloadLimits,
+    wheelhouse: Option<&Wheelhouse>,
     concurrency: Concurrency,
     cache: &Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
     printer: Printer,
     preview: PreviewMode,
-) -> Result<(), ProjectError> {
+) -> Result<Changelog, ProjectError> {
     // Extract the project settings.
     let InstallerSettingsRef {
         index_locations,
@@ -579,6 +730,7 @@ pub(super) async fn do_sync(
         exclude_newer,
         link_mode,
         compile_bytecode,
+        install_hooks,
         reinstall,
         build_options,
         sources,
@@ -589,7 +741,13 @@ pub(super) async fn do_sync(
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        );
 
     // Validate that the Python version is supported by the lockfile.
     if !target
@@ -650,12 +808,29 @@ pub(super) async fn do_sync(
         &install_options,
     )?;
 
+    // If `--only-package` is provided, ensure that the package would otherwise be installed.
+    if let Some(package) = &install_options.only_install_package {
+        if !resolution
+            .distributions()
+            .any(|dist| dist.name() == package)
+        {
+            return Err(ProjectError::OnlyPackageNotFound(package.clone()));
+        }
+    }
+
     // Always skip virtual projects, which shouldn't be built or installed.
     let resolution = apply_no_virtual_project(resolution);
 
     // If necessary, convert editable to non-editable distributions.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // If `--find-links-only` is provided, install any registry distributions from the directory.
+    let resolution = if let Some(wheelhouse) = wheelhouse {
+        wheelhouse.apply(resolution, &tags)?
+    } else {
+        resolution
+    };
+
     index_locations.cache_index_credentials();
 
     // Populate credentials from the target.
@@ -681,6 +856,8 @@ pub(super) async fn do_sync(
 
     // Read the build constraints from the lockfile.
     let build_constraints = target.build_constraints();
+    let build_constraints_package = target.build_constraints_package();
+    let patches = target.patches();
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
@@ -720,12 +897,14 @@ pub(super) async fn do_sync(
         workspace_cache.clone(),
         concurrency,
         preview,
-    );
+    )
+    .with_build_constraints_package(build_constraints_package)
+    .with_patches(patches);
 
     let site_packages = SitePackages::from_environment(venv)?;
 
     // Sync the environment.
-    operations::install(
+    let changelog = operations::install(
         &resolution,
         site_packages,
         modifications,
@@ -733,6 +912,7 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        install_hooks,
         index_locations,
         config_setting,
         config_settings_package,
@@ -744,18 +924,20 @@ pub(super) async fn do_sync(
         &build_dispatch,
         cache,
         venv,
+        None,
         logger,
         installer_metadata,
+        download_limits,
         dry_run,
         printer,
     )
     .await?;
 
-    Ok(())
+    Ok(changelog)
 }
 
 /// Filter out any virtual workspace members.
-fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
+pub(super) fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
         let ResolvedDist::Installable { dist, .. } = dist else {
             return true;
@@ -815,7 +997,7 @@ fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolu
 ///
 /// These credentials can come from any of `tool.uv.sources`, `tool.uv.dev-dependencies`,
 /// `project.dependencies`, and `project.optional-dependencies`.
-fn store_credentials_from_target(target: InstallTarget<'_>) {
+pub(super) fn store_credentials_from_target(target: InstallTarget<'_>) {
     // Iterate over any indexes in the target.
     for index in target.indexes() {
         if let Some(credentials) = index.credentials() {
@@ -920,7 +1102,7 @@ enum SchemaVersion {
 }
 
 #[derive(Serialize, Debug, Default)]
-struct SchemaReport {
+pub(crate) struct SchemaReport {
     /// The version of the schema.
     version: SchemaVersion,
 }
@@ -991,6 +1173,7 @@ impl From<&SyncEnvironment> for SyncAction {
             SyncEnvironment::Script(ScriptEnvironment::WouldCreate(..)) => SyncAction::Create,
             SyncEnvironment::Script(ScriptEnvironment::WouldReplace(..)) => SyncAction::Replace,
             SyncEnvironment::Script(ScriptEnvironment::Replaced(..)) => SyncAction::Update,
+            SyncEnvironment::Directory(..) => SyncAction::Check,
         }
     }
 }
@@ -1096,14 +1279,18 @@ impl From<&PythonEnvironment> for EnvironmentReport {
 
 impl From<&SyncEnvironment> for EnvironmentReport {
     fn from(env: &SyncEnvironment) -> Self {
-        let report = EnvironmentReport::from(&**env);
+        let mut report = EnvironmentReport::from(&**env);
         // Replace the path if necessary; we construct a temporary virtual environment during dry
         // run invocations and want to report the path we _would_ use.
         if let Some(path) = env.dry_run_target() {
-            report.with_path(path.into())
-        } else {
-            report
+            report = report.with_path(path.into());
+        }
+        // When installing into a `--target` or `--prefix` directory, report the directory rather
+        // than the interpreter's environment. (The interpreter itself lives elsewhere.)
+        if let Some(path) = env.directory() {
+            report.path = path.into();
         }
+        report
     }
 }
 
@@ -1128,6 +1315,9 @@ struct SyncReport {
     environment: EnvironmentReport,
     /// The action performed during the sync, e.g., what was done to the environment.
     action: SyncAction,
+    /// The changes that would be made to the environment, in dry-run mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plan: Option<InstallPlan>,
 
     // We store these fields so the report can format itself self-contained, but the outer
     // [`Report`] is intended to include these in user-facing output
@@ -1152,6 +1342,7 @@ impl SyncReport {
             action,
             dry_run,
             target,
+            ..
         } = self;
 
         let action = action.message(*target, *dry_run)?;
@@ -0,0 +1,425 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_configuration::{
+    Concurrency, DependencyGroups, DownloadLimits, DryRun, EditableMode, ExtrasSpecification,
+    InstallOptions, PreviewMode,
+};
+use uv_normalize::{DefaultExtras, PackageName};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
+use uv_workspace::pyproject::{Task, TaskCommand};
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
+
+use crate::child::run_to_completion;
+use crate::commands::pip::loggers::{SummaryInstallLogger, SummaryResolveLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::LockMode;
+use crate::commands::project::{
+    ProjectEnvironment, ProjectError, UniversalState, default_dependency_groups,
+};
+use crate::commands::{ExitStatus, diagnostics, optional_lock, project};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
+
+/// Run the given tasks, and the tasks they depend on, in the project environment.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn run(
+    project_dir: &Path,
+    requested: Vec<String>,
+    locked: bool,
+    frozen: bool,
+    no_sync: bool,
+    package: Option<PackageName>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let workspace_cache = WorkspaceCache::default();
+    let project = discover(project_dir, package.as_ref(), &workspace_cache).await?;
+
+    // Determine the order in which to run the tasks.
+    let tasks = project_tasks(&project);
+    let order = task_order(&requested, &tasks)?;
+
+    // Determine the groups to include: the default groups, and any groups required by the tasks.
+    let required_groups = order
+        .iter()
+        .flat_map(|name| tasks[*name].groups.iter().cloned())
+        .unique()
+        .collect::<Vec<_>>();
+    let groups = DependencyGroups::from_args(
+        false,
+        false,
+        false,
+        required_groups,
+        Vec::new(),
+        false,
+        Vec::new(),
+        false,
+    )
+    .with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
+
+    let venv = ProjectEnvironment::get_or_init(
+        project.workspace(),
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &install_mirrors,
+        &network_settings,
+        python_preference,
+        python_downloads,
+        no_sync,
+        no_config,
+        None,
+        cache,
+        DryRun::Disabled,
+        printer,
+        preview,
+    )
+    .await?
+    .into_environment()?;
+
+    if no_sync {
+        debug!("Skipping environment synchronization due to `--no-sync`");
+    } else {
+        let _lock = optional_lock(venv.lock().await, "environment")?;
+
+        // Determine the lock mode.
+        let mode = if frozen {
+            LockMode::Frozen
+        } else if locked {
+            LockMode::Locked(venv.interpreter())
+        } else {
+            LockMode::Write(venv.interpreter())
+        };
+
+        let lock_state = UniversalState::default();
+        let sync_state = lock_state.fork();
+
+        let result = match project::lock::LockOperation::new(
+            mode,
+            &settings.resolver,
+            &network_settings,
+            &lock_state,
+            Box::new(SummaryResolveLogger),
+            concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(project.workspace().into())
+        .await
+        {
+            Ok(result) => result,
+            Err(ProjectError::Operation(err)) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let target = match &project {
+            VirtualProject::Project(project) => InstallTarget::Project {
+                workspace: project.workspace(),
+                name: project.project_name(),
+                lock: result.lock(),
+            },
+            VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+                workspace,
+                lock: result.lock(),
+            },
+        };
+
+        // Validate that the dependency groups required by the tasks are defined in the lockfile.
+        target.validate_groups(&groups)?;
+
+        match project::sync::do_sync(
+            target,
+            &venv,
+            &extras,
+            &groups,
+            EditableMode::default(),
+            InstallOptions::default(),
+            Modifications::Sufficient,
+            None,
+            (&settings).into(),
+            &network_settings,
+            &sync_state,
+            Box::new(SummaryInstallLogger),
+            installer_metadata,
+            &DownloadLimits::default(),
+            None,
+            concurrency,
+            cache,
+            workspace_cache.clone(),
+            DryRun::Disabled,
+            printer,
+            preview,
+        )
+        .await
+        {
+            Ok(_) => {}
+            Err(ProjectError::Operation(err)) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    for name in order {
+        let task = &tasks[name];
+        let Some(cmd) = task.cmd.as_ref() else {
+            continue;
+        };
+
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Running task `{}`: {cmd}", name.cyan()).dimmed()
+        )?;
+
+        let status = run_task(name, task, cmd, project.root(), &venv).await?;
+        if !matches!(status, ExitStatus::Success | ExitStatus::External(0)) {
+            writeln!(
+                printer.stderr(),
+                "{}{} Task `{}` failed",
+                "error".red().bold(),
+                ":".bold(),
+                name.cyan()
+            )?;
+            return Ok(status);
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the tasks defined in the project.
+pub(crate) async fn list(
+    project_dir: &Path,
+    package: Option<PackageName>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace_cache = WorkspaceCache::default();
+    let project = discover(project_dir, package.as_ref(), &workspace_cache).await?;
+
+    let tasks = project_tasks(&project);
+    if tasks.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No tasks defined in `{}`",
+            "tool.uv.tasks".cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, task) in &tasks {
+        let description = task
+            .help
+            .clone()
+            .or_else(|| task.cmd.as_ref().map(ToString::to_string))
+            .unwrap_or_else(|| {
+                format!(
+                    "Runs: {}",
+                    task.depends_on
+                        .iter()
+                        .map(|name| format!("`{name}`"))
+                        .join(", ")
+                )
+            });
+        writeln!(printer.stdout(), "{} {}", name.bold(), description.dimmed())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Discover the project whose tasks should be run, i.e., the current project or the given
+/// workspace member.
+async fn discover(
+    project_dir: &Path,
+    package: Option<&PackageName>,
+    workspace_cache: &WorkspaceCache,
+) -> Result<VirtualProject> {
+    if let Some(package) = package {
+        Ok(VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), workspace_cache)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        ))
+    } else {
+        Ok(
+            VirtualProject::discover(project_dir, &DiscoveryOptions::default(), workspace_cache)
+                .await?,
+        )
+    }
+}
+
+/// Returns the tasks defined in the project's `tool.uv.tasks` table.
+fn project_tasks(project: &VirtualProject) -> BTreeMap<String, Task> {
+    project
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.tasks.clone())
+        .unwrap_or_default()
+}
+
+/// Determine the order in which to run the requested tasks, such that each task runs after the
+/// tasks it depends on, and each task runs at most once.
+fn task_order<'a>(
+    requested: &'a [String],
+    tasks: &'a BTreeMap<String, Task>,
+) -> Result<Vec<&'a str>> {
+    fn visit<'a>(
+        name: &'a str,
+        tasks: &'a BTreeMap<String, Task>,
+        visited: &mut FxHashSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) -> Result<()> {
+        if visited.contains(name) {
+            return Ok(());
+        }
+        if let Some(index) = stack.iter().position(|task| *task == name) {
+            bail!(
+                "Task `{name}` depends on itself: {}",
+                stack[index..]
+                    .iter()
+                    .chain(std::iter::once(&name))
+                    .map(|task| format!("`{task}`"))
+                    .join(" -> ")
+            );
+        }
+
+        let (name, task) = tasks
+            .get_key_value(name)
+            .expect("task existence is checked by the caller");
+
+        stack.push(name);
+        for dependency in &task.depends_on {
+            if !tasks.contains_key(dependency) {
+                bail!("Task `{name}` depends on `{dependency}`, which is not defined");
+            }
+            visit(dependency, tasks, visited, stack, order)?;
+        }
+        stack.pop();
+
+        visited.insert(name);
+        order.push(name);
+        Ok(())
+    }
+
+    let mut visited = FxHashSet::default();
+    let mut stack = Vec::new();
+    let mut order = Vec::new();
+    for name in requested {
+        if !tasks.contains_key(name) {
+            let hint = if tasks.is_empty() {
+                format!("no tasks are defined in `{}`", "tool.uv.tasks".cyan())
+            } else {
+                format!(
+                    "the available tasks are: {}",
+                    tasks
+                        .keys()
+                        .map(|task| format!("`{}`", task.cyan()))
+                        .join(", ")
+                )
+            };
+            bail!(
+                "Task `{name}` not found\n\n{}{} {hint}",
+                "hint".bold().cyan(),
+                ":".bold()
+            );
+        }
+        visit(name, tasks, &mut visited, &mut stack, &mut order)?;
+    }
+    Ok(order)
+}
+
+/// Run a single task in the given environment, returning its exit status.
+async fn run_task(
+    name: &str,
+    task: &Task,
+    cmd: &TaskCommand,
+    root: &Path,
+    venv: &PythonEnvironment,
+) -> Result<ExitStatus> {
+    let mut process = match cmd {
+        TaskCommand::Shell(command) => {
+            let mut process = if cfg!(windows) {
+                let mut process = Command::new("cmd");
+                process.arg("/C");
+                process
+            } else {
+                let mut process = Command::new("sh");
+                process.arg("-c");
+                process
+            };
+            process.arg(command);
+            process
+        }
+        TaskCommand::Args(args) => {
+            let Some((program, args)) = args.split_first() else {
+                bail!("Task `{name}` has an empty command");
+            };
+            let mut process = Command::new(program);
+            process.args(args);
+            process
+        }
+    };
+
+    // Run the task from the project root, or the configured working directory.
+    let cwd = task
+        .cwd
+        .as_ref()
+        .map_or_else(|| root.to_path_buf(), |cwd| root.join(cwd));
+    process.current_dir(&cwd);
+
+    // Place the environment's scripts at the front of the `PATH`.
+    let new_path = std::env::join_paths(
+        std::iter::once(venv.scripts().to_path_buf()).chain(
+            std::env::var_os(EnvVars::PATH)
+                .as_ref()
+                .iter()
+                .flat_map(std::env::split_paths)
+                .map(PathBuf::from),
+        ),
+    )?;
+    process.env(EnvVars::PATH, new_path);
+    process.env(EnvVars::VIRTUAL_ENV, venv.root().as_os_str());
+    process.envs(&task.env);
+
+    debug!("Running task `{name}`: `{cmd}` in: {}", cwd.display());
+    let handle = process
+        .spawn()
+        .with_context(|| format!("Failed to spawn task `{name}`: `{cmd}`"))?;
+
+    run_to_completion(handle).await
+}
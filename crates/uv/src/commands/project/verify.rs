@@ -0,0 +1,339 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+
+use uv_cache::Cache;
+use uv_configuration::{
+    Concurrency, DependencyGroups, DownloadLimits, DryRun, EditableMode, ExtrasSpecification,
+    InstallOptions, PreviewMode, Reinstall,
+};
+use uv_distribution_types::{InstalledDist, Name};
+use uv_fs::Simplified;
+use uv_install_wheel::RecordMismatch;
+use uv_installer::SitePackages;
+use uv_normalize::{DefaultExtras, PackageName};
+use uv_pep440::Version;
+use uv_python::PythonEnvironment;
+use uv_resolver::Installable;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::pip::loggers::DefaultInstallLogger;
+use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::{apply_no_virtual_project, do_sync};
+use crate::commands::project::{PlatformState, ProjectError, default_dependency_groups};
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
+
+/// Verify the integrity of the project environment against the lockfile and the `RECORD` of each
+/// installed package.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn verify(
+    project_dir: &Path,
+    fix: bool,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    modifications: Modifications,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Read the lockfile, without checking if it is up-to-date.
+    let Some(lock) = LockTarget::from(project.workspace()).read().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    // Locate the project environment.
+    let root = project.workspace().venv(None);
+    let venv = match PythonEnvironment::from_root(&root, cache) {
+        Ok(venv) => venv,
+        Err(uv_python::Error::MissingEnvironment(_)) => {
+            bail!(
+                "No project environment found at: `{}` (run `{}` to create it)",
+                root.user_display().cyan(),
+                "uv sync".green()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Determine the groups and extras to include.
+    let groups = groups.with_defaults(default_dependency_groups(project.pyproject_toml())?);
+    let extras = extras.with_defaults(DefaultExtras::default());
+
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Project {
+            workspace: project.workspace(),
+            name: project.project_name(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    // Determine the distributions that the lockfile requires for this environment.
+    let marker_env = resolution_markers(None, None, venv.interpreter());
+    let tags = resolution_tags(None, None, venv.interpreter())?;
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        &extras,
+        &groups,
+        &settings.resolver.build_options,
+        &InstallOptions::default(),
+    )?;
+    let resolution = apply_no_virtual_project(resolution);
+
+    // Compare the environment against the lockfile.
+    let site_packages = SitePackages::from_environment(&venv)?;
+    let mut issues = Vec::new();
+    let mut required = FxHashSet::default();
+    let mut verified = 0;
+    for dist in resolution.distributions() {
+        required.insert(dist.name());
+
+        let installed = site_packages.get_packages(dist.name());
+        if installed.is_empty() {
+            issues.push(Issue::Missing {
+                name: dist.name().clone(),
+                version: dist.version().cloned(),
+            });
+            continue;
+        }
+
+        for installed in installed {
+            if let Some(version) = dist.version() {
+                if installed.version() != version {
+                    issues.push(Issue::Mismatched {
+                        dist: installed.clone(),
+                        expected: version.clone(),
+                    });
+                    continue;
+                }
+            }
+            if let Some(issue) = verify_files(installed)? {
+                issues.push(issue);
+            }
+            verified += 1;
+        }
+    }
+
+    if matches!(modifications, Modifications::Exact) {
+        for installed in site_packages.iter() {
+            if !required.contains(installed.name()) {
+                issues.push(Issue::Extraneous {
+                    dist: installed.clone(),
+                });
+            }
+        }
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {} {} {}",
+            verified,
+            if verified == 1 { "package" } else { "packages" },
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    if issues.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    for issue in &issues {
+        writeln!(printer.stderr(), "{issue}")?;
+    }
+
+    if !fix {
+        writeln!(
+            printer.stderr(),
+            "Found {} {} in the project environment; run `{}` to repair it",
+            issues.len(),
+            if issues.len() == 1 { "issue" } else { "issues" },
+            "uv verify --fix".green()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Reinstall any packages with missing or modified files; all other issues are repaired by
+    // syncing the environment as usual.
+    let damaged = issues
+        .iter()
+        .filter_map(|issue| match issue {
+            Issue::Damaged { dist, .. } => Some(dist.name().clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mut settings = settings;
+    settings.reinstall = settings
+        .reinstall
+        .combine(Reinstall::Packages(damaged, Vec::new()));
+
+    match do_sync(
+        target,
+        &venv,
+        &extras,
+        &groups,
+        EditableMode::Editable,
+        InstallOptions::default(),
+        modifications,
+        None,
+        (&settings).into(),
+        &network_settings,
+        &PlatformState::default(),
+        Box::new(DefaultInstallLogger),
+        installer_metadata,
+        &DownloadLimits::default(),
+        None,
+        concurrency,
+        cache,
+        workspace_cache,
+        DryRun::Disabled,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(_) => {}
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Repaired {} {} in the project environment",
+        issues.len(),
+        if issues.len() == 1 { "issue" } else { "issues" },
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// A discrepancy between the project environment and the lockfile.
+#[derive(Debug)]
+enum Issue {
+    /// A package in the lockfile is not installed.
+    Missing {
+        name: PackageName,
+        version: Option<Version>,
+    },
+    /// A package is installed, but not required by the lockfile.
+    Extraneous { dist: InstalledDist },
+    /// A package is installed at a different version than the lockfile.
+    Mismatched {
+        dist: InstalledDist,
+        expected: Version,
+    },
+    /// The files of an installed package don't match its `RECORD`, or the `RECORD` is missing.
+    Damaged {
+        dist: InstalledDist,
+        mismatches: Option<Vec<RecordMismatch>>,
+    },
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { name, version } => {
+                write!(f, " {} {}", "+".green(), name.bold())?;
+                if let Some(version) = version {
+                    write!(f, "{}", format!("=={version}").dimmed())?;
+                }
+                write!(f, " {}", "(not installed)".dimmed())
+            }
+            Self::Extraneous { dist } => write!(
+                f,
+                " {} {}{} {}",
+                "-".red(),
+                dist.name().bold(),
+                format!("=={}", dist.version()).dimmed(),
+                "(not in the lockfile)".dimmed()
+            ),
+            Self::Mismatched { dist, expected } => write!(
+                f,
+                " {} {}{} {}",
+                "~".yellow(),
+                dist.name().bold(),
+                format!("=={}", dist.version()).dimmed(),
+                format!("(locked at {expected})").dimmed()
+            ),
+            Self::Damaged { dist, mismatches } => {
+                write!(
+                    f,
+                    " {} {}{}",
+                    "!".red(),
+                    dist.name().bold(),
+                    format!("=={}", dist.version()).dimmed(),
+                )?;
+                let Some(mismatches) = mismatches else {
+                    return write!(f, " {}", "(missing `RECORD` file)".dimmed());
+                };
+                for mismatch in mismatches {
+                    let reason = match mismatch {
+                        RecordMismatch::Missing(_) => "missing",
+                        RecordMismatch::Modified(_) => "modified",
+                    };
+                    write!(
+                        f,
+                        "\n     {} {}",
+                        mismatch.path().user_display(),
+                        format!("({reason})").dimmed()
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Re-hash the files of an installed distribution against its `RECORD`.
+fn verify_files(dist: &InstalledDist) -> Result<Option<Issue>> {
+    match dist {
+        InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+            match uv_install_wheel::verify_wheel(dist.install_path()) {
+                Ok(mismatches) if mismatches.is_empty() => Ok(None),
+                Ok(mismatches) => Ok(Some(Issue::Damaged {
+                    dist: dist.clone(),
+                    mismatches: Some(mismatches),
+                })),
+                Err(uv_install_wheel::Error::MissingRecord(_)) => Ok(Some(Issue::Damaged {
+                    dist: dist.clone(),
+                    mismatches: None,
+                })),
+                Err(err) => Err(err.into()),
+            }
+        }
+        // Legacy installations don't include hashes of their files.
+        InstalledDist::EggInfoDirectory(_)
+        | InstalledDist::LegacyEditable(_)
+        | InstalledDist::EggInfoFile(_) => Ok(None),
+    }
+}
@@ -1,5 +1,5 @@
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::{Context, Result, anyhow};
@@ -10,13 +10,13 @@ use uv_cache::Cache;
 use uv_cli::version::VersionInfo;
 use uv_cli::{VersionBump, VersionFormat};
 use uv_configuration::{
-    Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, InstallOptions, PreviewMode,
+    Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DownloadLimits, DryRun,
+    EditableMode, ExtrasSpecification, InstallOptions, PreviewMode,
 };
 use uv_fs::Simplified;
 use uv_normalize::DefaultExtras;
 use uv_pep440::{BumpCommand, PrereleaseKind, Version};
-use uv_pep508::PackageName;
+use uv_pep508::{PackageName, Requirement};
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_settings::PythonInstallMirrors;
 use uv_workspace::pyproject_mut::Error;
@@ -287,11 +287,37 @@ pub(crate) async fn project_version(
         None
     };
 
+    // Find any workspace members that depend on the specific version being replaced.
+    let dependents = if let Some(new_version) = &new_version {
+        find_dependents(&project, &name, &old_version, new_version)?
+    } else {
+        Vec::new()
+    };
+    for dependent in &dependents {
+        for (old, new) in &dependent.replaced {
+            writeln!(
+                printer.stderr(),
+                "{} `{}`: `{}` => `{}`",
+                if dry_run { "Would update" } else { "Updated" },
+                dependent.path.user_display(),
+                old.cyan(),
+                new.cyan(),
+            )?;
+        }
+    }
+
     // Update the toml and lock
     let status = if dry_run {
         ExitStatus::Success
     } else if let Some(new_version) = &new_version {
-        let project = update_project(project, new_version, &mut toml, &pyproject_path)?;
+        for dependent in &dependents {
+            fs_err::write(&dependent.path, dependent.toml.to_string())?;
+        }
+        let mut project = update_project(project, new_version, &mut toml, &pyproject_path)?;
+        // The in-memory workspace members are stale after editing their `pyproject.toml`.
+        if !dependents.is_empty() {
+            project = find_target(project_dir, package.as_ref(), explicit_project).await?;
+        }
         Box::pin(lock_and_sync(
             project,
             project_dir,
@@ -376,6 +402,56 @@ async fn find_target(
     Ok(project)
 }
 
+/// A workspace member whose dependency on the project must be updated to the new version.
+struct Dependent {
+    /// The path to the `pyproject.toml` of the workspace member.
+    path: PathBuf,
+    /// The edited `pyproject.toml`.
+    toml: PyProjectTomlMut,
+    /// The replaced requirements, as pairs of the old and new requirement.
+    replaced: Vec<(Requirement, Requirement)>,
+}
+
+/// Find the workspace members (and the virtual workspace root, if any) that depend on the given
+/// version of the project, and update their requirements to the new version in-memory.
+fn find_dependents(
+    project: &VirtualProject,
+    name: &PackageName,
+    old_version: &Version,
+    new_version: &Version,
+) -> Result<Vec<Dependent>> {
+    let workspace = project.workspace();
+
+    let members = workspace
+        .packages()
+        .values()
+        .filter(|member| member.root() != project.root())
+        .map(|member| (member.root().as_path(), member.pyproject_toml()));
+    let root = workspace.is_non_project().then(|| {
+        (
+            workspace.install_path().as_path(),
+            workspace.pyproject_toml(),
+        )
+    });
+
+    let mut dependents = Vec::new();
+    for (root, pyproject_toml) in members.chain(root) {
+        let mut toml =
+            PyProjectTomlMut::from_toml(&pyproject_toml.raw, DependencyTarget::PyProjectToml)?;
+        let replaced = toml.replace_dependency_version(name, old_version, new_version);
+        if replaced.is_empty() {
+            continue;
+        }
+        dependents.push(Dependent {
+            path: root.join("pyproject.toml"),
+            toml,
+            replaced,
+        });
+    }
+
+    Ok(dependents)
+}
+
 /// Update the pyproject.toml on-disk and in-memory with a new version
 fn update_project(
     project: VirtualProject,
@@ -647,6 +723,8 @@ async fn lock_and_sync(
         &state,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        &DownloadLimits::default(),
+        None,
         concurrency,
         cache,
         workspace_cache,
@@ -656,7 +734,7 @@ async fn lock_and_sync(
     )
     .await
     {
-        Ok(()) => {}
+        Ok(_) => {}
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -1,38 +1,270 @@
 use std::fmt::Write;
 use std::iter;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, bail};
 use console::Term;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use petgraph::graph::DiGraph;
+use rustc_hash::FxHashMap;
 use tokio::sync::Semaphore;
 use tracing::{debug, info};
 use uv_auth::Credentials;
 use uv_cache::Cache;
-use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBuilder};
+use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, IndexTls, RegistryClientBuilder};
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
-use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexQuirks, IndexUrl};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::{Requirement, VerbatimUrl};
 use uv_publish::{
-    CheckUrlClient, TrustedPublishResult, check_trusted_publishing, files_for_publishing, upload,
+    Attestation, CheckUrlClient, Severity, TrustedPublishResult, check_file,
+    check_trusted_publishing, files_for_publishing, upload, version_exists,
 };
 use uv_redacted::DisplaySafeUrl;
-use uv_warnings::warn_user_once;
+use uv_static::EnvVars;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::{DependencyType, PyProjectToml};
+use uv_workspace::pyproject_mut::{AddBoundsKind, DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
-use crate::settings::NetworkSettings;
+use crate::settings::{NetworkSettings, YankAction};
+
+/// An upload endpoint to publish to, as configured by `--publish-url` or an index.
+#[derive(Debug, Clone)]
+pub(crate) struct PublishTarget {
+    /// The name of the index, if the target was configured by an index.
+    pub(crate) index: Option<String>,
+    pub(crate) publish_url: DisplaySafeUrl,
+    pub(crate) check_url: Option<IndexUrl>,
+    pub(crate) index_tls: Vec<IndexTls>,
+    /// The quirks of the registry, if configured by the index.
+    pub(crate) quirks: Option<IndexQuirks>,
+}
+
+impl PublishTarget {
+    /// Resolve the target for the index with the given name in the configuration.
+    pub(crate) fn from_index(index_name: &str, index_locations: &IndexLocations) -> Result<Self> {
+        let index = find_index(index_name, index_locations)?;
+        // With quirks, the publish URL can be derived from the index URL.
+        let publish_url = index
+            .publish_url
+            .clone()
+            .or_else(|| {
+                index
+                    .quirks
+                    .and_then(|quirks| quirks.publish_url(index.url.url()))
+            })
+            .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
+        let check_url = index.url.clone();
+        // Apply the index's TLS settings to both the upload and the index URL.
+        let index_tls = IndexTls::from_index(index)
+            .into_iter()
+            .flat_map(|tls| {
+                [
+                    IndexTls {
+                        url: publish_url.clone(),
+                        ..tls.clone()
+                    },
+                    tls,
+                ]
+            })
+            .collect();
+        Ok(Self {
+            index: Some(index_name.to_string()),
+            publish_url,
+            check_url: Some(check_url),
+            index_tls,
+            quirks: index.quirks,
+        })
+    }
+}
+
+/// Find the index with the given name in the configuration.
+fn find_index<'a>(index_name: &str, index_locations: &'a IndexLocations) -> Result<&'a Index> {
+    index_locations
+        .simple_indexes()
+        .find(|index| {
+            index
+                .name
+                .as_ref()
+                .is_some_and(|name| name.as_ref() == index_name)
+        })
+        .with_context(|| {
+            let mut index_names: Vec<String> = index_locations
+                .simple_indexes()
+                .filter_map(|index| index.name.as_ref())
+                .map(ToString::to_string)
+                .collect();
+            index_names.sort();
+            if index_names.is_empty() {
+                format!("No indexes were found, can't use index: `{index_name}`")
+            } else {
+                let index_names = index_names.join("`, `");
+                format!("Index not found: `{index_name}`. Found indexes: `{index_names}`")
+            }
+        })
+}
+
+impl std::fmt::Display for PublishTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.index {
+            Some(index) => write!(f, "`{index}`"),
+            None => write!(f, "{}", self.publish_url),
+        }
+    }
+}
+
+/// How to wait for the published files to become available on the index.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PublishWait {
+    /// The maximum time to wait for the files to become available.
+    pub(crate) timeout: Duration,
+    /// Whether to download the files from the index and verify their hashes.
+    pub(crate) verify: bool,
+}
+
+/// The initial interval at which the index is polled for published files.
+const WAIT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The maximum interval at which the index is polled for published files.
+const MAX_WAIT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The files uploaded to, and skipped for, a target.
+#[derive(Debug, Default, Clone, Copy)]
+struct PublishSummary {
+    uploaded: usize,
+    skipped: usize,
+}
 
 pub(crate) async fn publish(
     paths: Vec<String>,
-    publish_url: DisplaySafeUrl,
+    targets: Vec<PublishTarget>,
+    attest: Option<&str>,
+    wait: Option<PublishWait>,
+    trusted_publishing: TrustedPublishing,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    username: Option<String>,
+    password: Option<String>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if network_settings.connectivity.is_offline() {
+        bail!("Unable to publish files in offline mode");
+    }
+
+    let files = files_for_publishing(paths)?;
+    if files.is_empty() {
+        bail!("No files found to publish");
+    }
+
+    let attestations = if let Some(requirement) = attest {
+        attest_files(&files, requirement, printer).await?
+    } else {
+        vec![None; files.len()]
+    };
+
+    // With a single target, fail on the first error.
+    if let [target] = targets.as_slice() {
+        publish_target(
+            &files,
+            &attestations,
+            target,
+            wait,
+            trusted_publishing,
+            keyring_provider,
+            network_settings,
+            username,
+            password,
+            cache,
+            printer,
+        )
+        .await?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // With multiple targets, continue past failures, and report the result for each target.
+    let mut results = Vec::with_capacity(targets.len());
+    for target in &targets {
+        let result = publish_target(
+            &files,
+            &attestations,
+            target,
+            wait,
+            trusted_publishing,
+            keyring_provider,
+            network_settings,
+            username.clone(),
+            password.clone(),
+            cache,
+            printer,
+        )
+        .await;
+        if let Err(err) = &result {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to publish to {target}: {err}",
+                "error".red().bold()
+            )?;
+            for source in err.chain().skip(1) {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    source.to_string().trim()
+                )?;
+            }
+        }
+        results.push((target, result));
+    }
+
+    writeln!(printer.stderr())?;
+    let mut failed = false;
+    for (target, result) in results {
+        match result {
+            Ok(summary) => writeln!(
+                printer.stderr(),
+                "{} {target}: {} uploaded, {} skipped",
+                "Published".bold().green(),
+                summary.uploaded,
+                summary.skipped
+            )?,
+            Err(_) => {
+                failed = true;
+                writeln!(printer.stderr(), "{} {target}", "Failed".bold().red())?;
+            }
+        }
+    }
+
+    if failed {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Build and publish the releasable workspace members, in dependency order.
+pub(crate) async fn publish_workspace(
+    project_dir: &Path,
+    targets: Vec<PublishTarget>,
+    bounds: Option<AddBoundsKind>,
+    attest: Option<&str>,
+    wait: Option<PublishWait>,
     trusted_publishing: TrustedPublishing,
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     username: Option<String>,
     password: Option<String>,
-    check_url: Option<IndexUrl>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -40,89 +272,625 @@ pub(crate) async fn publish(
         bail!("Unable to publish files in offline mode");
     }
 
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    .context("`--workspace` was provided, but no workspace was found")?;
+    let members = release_order(&workspace, printer)?;
+    if members.is_empty() {
+        bail!("No workspace members to publish");
+    }
+    debug!(
+        "Publishing workspace members in order: {}",
+        members
+            .iter()
+            .map(|member| &member.project().name)
+            .join(", ")
+    );
+
+    let dist = tempfile::tempdir()?;
+    let download_concurrency = Semaphore::new(1);
+    let mut versions = FxHashMap::default();
+    for member in members {
+        let name = &member.project().name;
+        let out_dir = dist.path().join(name.as_str());
+        build_member(&workspace, member, &out_dir, bounds, &versions).await?;
+
+        let files = files_for_publishing(vec![out_dir.join("*").to_string_lossy().into_owned()])?;
+        let Some((_, _, filename)) = files.first() else {
+            bail!("No distributions were built for `{name}`");
+        };
+        let version = filename.version().clone();
+
+        // Skip the targets that already have the version.
+        let mut remaining = Vec::with_capacity(targets.len());
+        for target in &targets {
+            let upload_client = upload_client(target, keyring_provider, network_settings);
+            let exists = match check_url_client(
+                target,
+                &upload_client,
+                keyring_provider,
+                network_settings,
+                cache,
+            )? {
+                Some(check_url_client) => {
+                    version_exists(&check_url_client, name, &version, &download_concurrency).await?
+                }
+                None => false,
+            };
+            if exists {
+                writeln!(
+                    printer.stderr(),
+                    "{name} {version} already exists on {target}, skipping"
+                )?;
+            } else {
+                remaining.push(target.clone());
+            }
+        }
+
+        if !remaining.is_empty() {
+            let status = publish(
+                vec![out_dir.join("*").to_string_lossy().into_owned()],
+                remaining,
+                attest,
+                wait,
+                trusted_publishing,
+                keyring_provider,
+                network_settings,
+                username.clone(),
+                password.clone(),
+                cache,
+                printer,
+            )
+            .await?;
+            if !matches!(status, ExitStatus::Success) {
+                writeln!(
+                    printer.stderr(),
+                    "{}: Stopping after `{name}` failed to publish, since later workspace members may depend on it",
+                    "error".red().bold()
+                )?;
+                return Ok(status);
+            }
+        }
+
+        versions.insert(name.clone(), version);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns the workspace members to publish, such that each member comes after the members it
+/// depends on.
+///
+/// Skips members that aren't packages and members marked as private with the
+/// `Private :: Do Not Upload` classifier.
+fn release_order(workspace: &Workspace, printer: Printer) -> Result<Vec<&WorkspaceMember>> {
+    let mut graph = DiGraph::<&WorkspaceMember, ()>::new();
+    let mut nodes = FxHashMap::default();
+    for (name, member) in workspace.packages() {
+        if !member.pyproject_toml().is_package(true) {
+            debug!("Skipping workspace member `{name}`, which is not a package");
+            continue;
+        }
+        if is_private(member.pyproject_toml()) {
+            writeln!(
+                printer.stderr(),
+                "Skipping `{name}`, which is marked as private with the `Private :: Do Not Upload` classifier"
+            )?;
+            continue;
+        }
+        nodes.insert(name, graph.add_node(member));
+    }
+
+    for (name, dependent) in &nodes {
+        let member = graph[*dependent];
+        for dependency in member_dependencies(member) {
+            if let Some(dependency) = nodes.get(&dependency.name) {
+                if dependency != dependent {
+                    graph.update_edge(*dependency, *dependent, ());
+                }
+            } else if dependency.name != **name
+                && workspace.packages().contains_key(&dependency.name)
+            {
+                warn_user_once!(
+                    "`{name}` depends on the workspace member `{}`, which is not published",
+                    dependency.name
+                );
+            }
+        }
+    }
+
+    let order = petgraph::algo::toposort(&graph, None).map_err(|cycle| {
+        anyhow::anyhow!(
+            "Workspace members can't be published in dependency order, as there is a dependency cycle involving `{}`",
+            graph[cycle.node_id()].project().name
+        )
+    })?;
+    Ok(order.into_iter().map(|node| graph[node]).collect())
+}
+
+/// Returns `true` if the project is marked as private with the `Private :: Do Not Upload`
+/// classifier.
+fn is_private(pyproject_toml: &PyProjectToml) -> bool {
+    let Ok(document) = toml::from_str::<toml::Table>(&pyproject_toml.raw) else {
+        return false;
+    };
+    document
+        .get("project")
+        .and_then(|project| project.get("classifiers"))
+        .and_then(toml::Value::as_array)
+        .is_some_and(|classifiers| {
+            classifiers
+                .iter()
+                .any(|classifier| classifier.as_str() == Some("Private :: Do Not Upload"))
+        })
+}
+
+/// The production and optional dependencies of a workspace member.
+fn member_dependencies(member: &WorkspaceMember) -> Vec<MemberDependency> {
+    let project = member.project();
+    let arrays =
+        iter::once((
+            DependencyType::Production,
+            project.dependencies.as_deref().unwrap_or_default(),
+        ))
+        .chain(project.optional_dependencies.iter().flatten().map(
+            |(extra, requirements)| {
+                (
+                    DependencyType::Optional(extra.clone()),
+                    requirements.as_slice(),
+                )
+            },
+        ));
+
+    let mut dependencies = Vec::new();
+    for (dependency_type, requirements) in arrays {
+        for (index, requirement) in requirements.iter().enumerate() {
+            let Ok(requirement) = Requirement::<VerbatimUrl>::from_str(requirement) else {
+                continue;
+            };
+            dependencies.push(MemberDependency {
+                dependency_type: dependency_type.clone(),
+                index,
+                name: requirement.name,
+                has_version: requirement.version_or_url.is_some(),
+            });
+        }
+    }
+    dependencies
+}
+
+/// A dependency in the `pyproject.toml` of a workspace member.
+struct MemberDependency {
+    dependency_type: DependencyType,
+    /// The index of the requirement in its dependency array.
+    index: usize,
+    name: PackageName,
+    /// Whether the requirement has a version constraint or URL.
+    has_version: bool,
+}
+
+/// Build the distributions of a workspace member into the output directory with `uv build`.
+///
+/// With `bounds`, dependencies on published workspace members without a version constraint are
+/// temporarily rewritten to requirements on the published version.
+async fn build_member(
+    workspace: &Workspace,
+    member: &WorkspaceMember,
+    out_dir: &Path,
+    bounds: Option<AddBoundsKind>,
+    versions: &FxHashMap<PackageName, Version>,
+) -> Result<()> {
+    let name = &member.project().name;
+    let pyproject_toml = member.root().join("pyproject.toml");
+
+    let original = if let Some(bounds) = bounds {
+        let original = fs_err::read_to_string(&pyproject_toml)?;
+        let mut toml = PyProjectTomlMut::from_toml(&original, DependencyTarget::PyProjectToml)?;
+        let mut rewritten = false;
+        for dependency in member_dependencies(member) {
+            if dependency.has_version {
+                continue;
+            }
+            let Some(version) = versions.get(&dependency.name) else {
+                continue;
+            };
+            debug!(
+                "Adding a {bounds} bound on `{}` {version} to `{name}`",
+                dependency.name
+            );
+            toml.set_dependency_bound(
+                &dependency.dependency_type,
+                dependency.index,
+                version.clone(),
+                bounds,
+            )?;
+            rewritten = true;
+        }
+        if rewritten {
+            fs_err::write(&pyproject_toml, toml.to_string())?;
+            Some(original)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    let status = tokio::process::Command::new(uv_executable()?)
+        .args(["build", "--package", name.as_str(), "--out-dir"])
+        .arg(out_dir)
+        .current_dir(workspace.install_path())
+        .status()
+        .await;
+
+    // Restore the `pyproject.toml` before handling any error.
+    if let Some(original) = original {
+        fs_err::write(&pyproject_toml, original)?;
+    }
+
+    let status = status.with_context(|| format!("Failed to run `uv build` for `{name}`"))?;
+    if !status.success() {
+        bail!("Failed to build `{name}`: `uv build` failed with {status}");
+    }
+    Ok(())
+}
+
+/// Check the files for problems that registries reject, without uploading them.
+pub(crate) async fn check(paths: Vec<String>, printer: Printer) -> Result<ExitStatus> {
     let files = files_for_publishing(paths)?;
+    if files.is_empty() {
+        bail!("No files found to check");
+    }
+
+    let mut failed = 0;
+    for (file, raw_filename, filename) in &files {
+        let diagnostics = check_file(file, raw_filename, filename)
+            .await
+            .with_context(|| format!("Failed to check `{}`", file.user_display()))?;
+        if diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            failed += 1;
+            writeln!(printer.stderr(), "{} {filename}", "Failed".bold().red())?;
+        } else {
+            writeln!(printer.stderr(), "{} {filename}", "Passed".bold().green())?;
+        }
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Severity::Error => {
+                    writeln!(printer.stderr(), "  {}: {diagnostic}", "error".red().bold())?;
+                }
+                Severity::Warning => {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {diagnostic}",
+                        "warning".yellow().bold()
+                    )?;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        writeln!(
+            printer.stderr(),
+            "{failed} of {} {} failed the checks",
+            files.len(),
+            if files.len() == 1 { "file" } else { "files" }
+        )?;
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Yank a release from an index, or undo the yanking of a release.
+pub(crate) async fn yank(
+    action: YankAction,
+    release: &str,
+    index_name: Option<&str>,
+    publish_url: &DisplaySafeUrl,
+    index_locations: &IndexLocations,
+    dry_run: bool,
+    yes: bool,
+    network_settings: &NetworkSettings,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let (name, version) = release.split_once("==").with_context(|| {
+        format!("Expected a release as `<package>==<version>`, found: `{release}`")
+    })?;
+    let name = PackageName::from_str(name.trim())?;
+    let version = Version::from_str(version.trim())?;
+    // Identify the index by its name in the configuration, or else by its upload URL.
+    let (url, quirks, target) = match index_name {
+        Some(index_name) => {
+            let index = find_index(index_name, index_locations)?;
+            (index.url.url(), index.quirks, format!("`{index_name}`"))
+        }
+        None => (publish_url, None, publish_url.to_string()),
+    };
+    let (verb, past) = match action {
+        YankAction::Yank { .. } => ("Yank", "Yanked"),
+        YankAction::Unyank => ("Unyank", "Unyanked"),
+    };
+
+    // Determine the API before asking for confirmation, such that unsupported indexes fail early.
+    let pypi = match url.host_str() {
+        Some("pypi.org" | "upload.pypi.org") => Some("pypi.org"),
+        Some("test.pypi.org") => Some("test.pypi.org"),
+        _ => None,
+    };
+    if let Some(host) = pypi {
+        bail!(
+            "{host} doesn't provide an API for yanking releases; manage the release at \
+            https://{host}/manage/project/{name}/release/{version}/ instead"
+        );
+    }
+    let codeartifact = quirks == Some(IndexQuirks::CodeArtifact) || is_codeartifact_url(url);
+    if !codeartifact {
+        bail!(
+            "{target} doesn't provide a supported API for yanking releases; currently, yanking \
+            is supported for AWS CodeArtifact"
+        );
+    }
+    if let YankAction::Yank {
+        reason: Some(reason),
+    } = &action
+    {
+        warn_user!(
+            "AWS CodeArtifact doesn't store a reason for yanking releases, ignoring: `{reason}`"
+        );
+    }
+
+    if dry_run {
+        writeln!(
+            printer.stderr(),
+            "Would {} {name} {version} on {target}",
+            verb.to_lowercase()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+    if network_settings.connectivity.is_offline() {
+        bail!("Unable to {} releases in offline mode", verb.to_lowercase());
+    }
+
+    if !yes {
+        let term = Term::stderr();
+        if !term.is_term() {
+            bail!(
+                "Refusing to {} {name} {version} without confirmation; pass `--yes` to confirm",
+                verb.to_lowercase()
+            );
+        }
+        let prompt = format!("{verb} {name} {version} on {target}?");
+        if !uv_console::confirm(&prompt, &term, false)? {
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
+    set_version_listed(
+        url,
+        name.as_str(),
+        &version.to_string(),
+        matches!(action, YankAction::Unyank),
+    )
+    .await
+    .with_context(|| format!("Failed to {} {name} {version}", verb.to_lowercase()))?;
+    writeln!(
+        printer.stderr(),
+        "{} {name} {version} on {target}",
+        past.bold().green()
+    )?;
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the URL is an AWS CodeArtifact repository.
+#[cfg(feature = "codeartifact")]
+fn is_codeartifact_url(url: &DisplaySafeUrl) -> bool {
+    uv_auth::is_codeartifact(url)
+}
+
+/// Returns `true` if the URL is an AWS CodeArtifact repository.
+#[cfg(not(feature = "codeartifact"))]
+fn is_codeartifact_url(_url: &DisplaySafeUrl) -> bool {
+    false
+}
+
+/// Unlist a release on an AWS CodeArtifact repository, or list it again.
+#[cfg(feature = "codeartifact")]
+async fn set_version_listed(
+    url: &DisplaySafeUrl,
+    name: &str,
+    version: &str,
+    listed: bool,
+) -> Result<()> {
+    uv_auth::set_version_listed(url, name, version, listed).await
+}
+
+/// Unlist a release on an AWS CodeArtifact repository, or list it again.
+#[cfg(not(feature = "codeartifact"))]
+async fn set_version_listed(
+    _url: &DisplaySafeUrl,
+    _name: &str,
+    _version: &str,
+    _listed: bool,
+) -> Result<()> {
+    bail!("uv was built without support for AWS CodeArtifact")
+}
+
+/// The `pypi-attestations` requirement used to sign attestations, unless overridden with
+/// `--attest-from`.
+///
+/// The version is pinned, such that a new release can't change how files are signed without a
+/// corresponding uv release.
+pub(crate) const PYPI_ATTESTATIONS: &str = "pypi-attestations==0.0.26";
+
+/// The path to the uv executable, to run uv subcommands.
+fn uv_executable() -> Result<PathBuf> {
+    match std::env::var_os(EnvVars::UV) {
+        Some(uv) => Ok(PathBuf::from(uv)),
+        None => Ok(std::env::current_exe()?),
+    }
+}
+
+/// Read the attestations for the files, signing the files without one with
+/// `pypi-attestations sign`, as installed from the given requirement.
+async fn attest_files(
+    files: &[(PathBuf, String, DistFilename)],
+    requirement: &str,
+    printer: Printer,
+) -> Result<Vec<Option<Attestation>>> {
+    let unsigned = files
+        .iter()
+        .map(|(file, ..)| file)
+        .filter(|file| !Attestation::path(file).exists())
+        .collect::<Vec<_>>();
+    if !unsigned.is_empty() {
+        let n = unsigned.len();
+        writeln!(
+            printer.stderr(),
+            "{} {n} {} with `pypi-attestations`",
+            "Attesting".bold().green(),
+            if n == 1 { "file" } else { "files" }
+        )?;
+        let status = tokio::process::Command::new(uv_executable()?)
+            .args([
+                "tool",
+                "run",
+                "--from",
+                requirement,
+                "pypi-attestations",
+                "sign",
+            ])
+            .args(&unsigned)
+            .status()
+            .await
+            .context("Failed to run `pypi-attestations sign`")?;
+        if !status.success() {
+            bail!("`pypi-attestations sign` failed with {status}");
+        }
+    }
+
+    let mut attestations = Vec::with_capacity(files.len());
+    for (file, _, filename) in files {
+        let attestation = Attestation::read(file)?
+            .with_context(|| format!("No attestation was generated for {filename}"))?;
+        let path = Attestation::path(file);
+        if let Some(index) = attestation.log_index() {
+            writeln!(
+                printer.stderr(),
+                "Attestation for {filename}: {} (Rekor log index {index}: https://search.sigstore.dev/?logIndex={index})",
+                path.user_display().cyan()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Attestation for {filename}: {}",
+                path.user_display().cyan()
+            )?;
+        }
+        attestations.push(Some(attestation));
+    }
+    writeln!(
+        printer.stderr(),
+        "To inspect the signing identity of an attestation, run `{}`",
+        format!("uvx --from {requirement} pypi-attestations inspect <ATTESTATION>").green()
+    )?;
+
+    Ok(attestations)
+}
+
+/// Publish the files to a single target.
+async fn publish_target(
+    files: &[(PathBuf, String, DistFilename)],
+    attestations: &[Option<Attestation>],
+    target: &PublishTarget,
+    wait: Option<PublishWait>,
+    trusted_publishing: TrustedPublishing,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    username: Option<String>,
+    password: Option<String>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PublishSummary> {
+    let PublishTarget {
+        publish_url,
+        check_url,
+        quirks,
+        ..
+    } = target;
+    // Determine the index to wait on before uploading, such that we fail early.
+    let wait_url = wait.map(|_| wait_url(target)).transpose()?;
+
     match files.len() {
-        0 => bail!("No files found to publish"),
         1 => writeln!(printer.stderr(), "Publishing 1 file to {publish_url}")?,
         n => writeln!(printer.stderr(), "Publishing {n} files {publish_url}")?,
     }
 
-    // * For the uploads themselves, we roll our own retries due to
-    //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
-    //   the default retries. We set the retries to 0 here and manually construct the retry policy
-    //   in the upload loop.
-    // * We want to allow configuring TLS for the registry, while for trusted publishing we know the
-    //   defaults are correct.
-    // * For the uploads themselves, we know we need an authorization header and we can't nor
-    //   shouldn't try cloning the request to make an unauthenticated request first, but we want
-    //   keyring integration. For trusted publishing, we use an OIDC auth routine without keyring
-    //   or other auth integration.
-    let upload_client = BaseClientBuilder::new()
-        .retries(0)
-        .keyring(keyring_provider)
-        .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone())
-        // Don't try cloning the request to make an unauthenticated request first.
-        .auth_integration(AuthIntegration::OnlyAuthenticated)
-        // Set a very high timeout for uploads, connections are often 10x slower on upload than
-        // download. 15 min is taken from the time a trusted publishing token is valid.
-        .default_timeout(Duration::from_secs(15 * 60))
-        .build();
+    let upload_client = upload_client(target, keyring_provider, network_settings);
     let oidc_client = BaseClientBuilder::new()
         .auth_integration(AuthIntegration::NoAuthMiddleware)
         .wrap_existing(&upload_client);
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
 
+    // Use the credentials of the CI job for registries that accept them.
+    let (username, password) = match (username, password) {
+        (None, None) if publish_url.username().is_empty() => quirks
+            .and_then(ci_credentials)
+            .map_or((None, None), |(username, password)| {
+                (Some(username), Some(password))
+            }),
+        credentials => credentials,
+    };
+    // CodeArtifact tokens are acquired by the authentication middleware, so don't prompt.
+    let prompt = if *quirks == Some(IndexQuirks::CodeArtifact) {
+        Prompt::Disabled
+    } else {
+        Prompt::Enabled
+    };
+
     let (publish_url, credentials) = gather_credentials(
-        publish_url,
+        publish_url.clone(),
         username,
         password,
         trusted_publishing,
         keyring_provider,
         &oidc_client,
         check_url.as_ref(),
-        Prompt::Enabled,
+        prompt,
         printer,
     )
     .await?;
 
     // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = &check_url {
-        let index_locations = IndexLocations::new(
-            vec![Index::from_index_url(index_url.clone())],
-            Vec::new(),
-            false,
-        );
-        let registry_client_builder = RegistryClientBuilder::new(cache.clone())
-            .retries_from_env()?
-            .native_tls(network_settings.native_tls)
-            .connectivity(network_settings.connectivity)
-            .allow_insecure_host(network_settings.allow_insecure_host.clone())
-            .index_locations(&index_locations)
-            .keyring(keyring_provider);
-        Some(CheckUrlClient {
-            index_url: index_url.clone(),
-            registry_client_builder,
-            client: &upload_client,
-            index_capabilities: IndexCapabilities::default(),
-            cache,
-        })
-    } else {
-        None
-    };
+    let check_url_client = check_url_client(
+        target,
+        &upload_client,
+        keyring_provider,
+        network_settings,
+        cache,
+    )?;
 
-    for (file, raw_filename, filename) in files {
+    let mut summary = PublishSummary::default();
+    for ((file, raw_filename, filename), attestation) in files.iter().zip(attestations) {
         if let Some(check_url_client) = &check_url_client {
-            if uv_publish::check_url(check_url_client, &file, &filename, &download_concurrency)
+            if uv_publish::check_url(check_url_client, file, filename, &download_concurrency)
                 .await?
             {
                 writeln!(printer.stderr(), "File {filename} already exists, skipping")?;
+                summary.skipped += 1;
                 continue;
             }
         }
 
-        let size = fs_err::metadata(&file)?.len();
+        let size = fs_err::metadata(file)?.len();
         let (bytes, unit) = human_readable_bytes(size);
         writeln!(
             printer.stderr(),
@@ -132,12 +900,15 @@ pub(crate) async fn publish(
         )?;
         let reporter = PublishReporter::single(printer);
         let uploaded = upload(
-            &file,
-            &raw_filename,
-            &filename,
+            file,
+            raw_filename,
+            filename,
             &publish_url,
             &upload_client,
             &credentials,
+            attestation.as_ref(),
+            *quirks,
+            &network_settings.upload_retry_policy,
             check_url_client.as_ref(),
             &download_concurrency,
             // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
@@ -145,7 +916,10 @@ pub(crate) async fn publish(
         )
         .await?; // Filename and/or URL are already attached, if applicable.
         info!("Upload succeeded");
-        if !uploaded {
+        if uploaded {
+            summary.uploaded += 1;
+        } else {
+            summary.skipped += 1;
             writeln!(
                 printer.stderr(),
                 "{}",
@@ -154,17 +928,227 @@ pub(crate) async fn publish(
         }
     }
 
-    Ok(ExitStatus::Success)
+    if let (Some(wait), Some(wait_url)) = (wait, wait_url) {
+        wait_for_files(
+            files,
+            target,
+            wait,
+            wait_url,
+            &upload_client,
+            keyring_provider,
+            network_settings,
+            cache,
+            printer,
+        )
+        .await?;
+    }
+
+    Ok(summary)
+}
+
+/// The index on which to wait for the published files: the check URL of the target, or the index
+/// of PyPI and TestPyPI, for which the check URL is usually omitted.
+fn wait_url(target: &PublishTarget) -> Result<IndexUrl> {
+    if let Some(check_url) = &target.check_url {
+        return Ok(check_url.clone());
+    }
+    match target.publish_url.host_str() {
+        Some("upload.pypi.org") => Ok(IndexUrl::from_str("https://pypi.org/simple/")?),
+        Some("test.pypi.org") => Ok(IndexUrl::from_str("https://test.pypi.org/simple/")?),
+        _ => bail!(
+            "`--wait` and `--verify` require an index to check, but {target} has no check URL; \
+            use `--index` or `--check-url`"
+        ),
+    }
+}
+
+/// Wait until the files are available on the index of the target, and optionally verify that
+/// they can be downloaded from it.
+async fn wait_for_files(
+    files: &[(PathBuf, String, DistFilename)],
+    target: &PublishTarget,
+    wait: PublishWait,
+    wait_url: IndexUrl,
+    upload_client: &BaseClient,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    let target = PublishTarget {
+        check_url: Some(wait_url),
+        ..target.clone()
+    };
+    let Some(check_url_client) = check_url_client(
+        &target,
+        upload_client,
+        keyring_provider,
+        network_settings,
+        cache,
+    )?
+    else {
+        unreachable!("The target has a check URL");
+    };
+    let download_concurrency = Semaphore::new(1);
+
+    let start = Instant::now();
+    let mut interval = WAIT_INTERVAL;
+    loop {
+        let mut missing = 0;
+        for (file, _, filename) in files {
+            if !uv_publish::check_url(&check_url_client, file, filename, &download_concurrency)
+                .await?
+            {
+                debug!("{filename} is not yet available on {target}");
+                missing += 1;
+            }
+        }
+        if missing == 0 {
+            break;
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= wait.timeout {
+            bail!(
+                "Timed out after {}s waiting for {missing} {} to become available on {target}",
+                wait.timeout.as_secs(),
+                if missing == 1 { "file" } else { "files" }
+            );
+        }
+        writeln!(
+            printer.stderr(),
+            "Waiting for {missing} {} to become available on {target}...",
+            if missing == 1 { "file" } else { "files" }
+        )?;
+        tokio::time::sleep(interval.min(wait.timeout - elapsed)).await;
+        interval = (interval * 2).min(MAX_WAIT_INTERVAL);
+    }
+    writeln!(
+        printer.stderr(),
+        "{} on {target}",
+        "Available".bold().green()
+    )?;
+
+    if wait.verify {
+        for (file, _, filename) in files {
+            uv_publish::verify_download(&check_url_client, file, filename, &download_concurrency)
+                .await?;
+            writeln!(printer.stderr(), "{} {filename}", "Verified".bold().green())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Build the client for uploading to a target.
+fn upload_client(
+    target: &PublishTarget,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+) -> BaseClient {
+    // * For the uploads themselves, we roll our own retries due to
+    //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
+    //   the default retries. We set the retries to 0 here and manually construct the retry policy
+    //   in the upload loop.
+    // * We want to allow configuring TLS for the registry, while for trusted publishing we know the
+    //   defaults are correct.
+    // * For the uploads themselves, we know we need an authorization header and we can't nor
+    //   shouldn't try cloning the request to make an unauthenticated request first, but we want
+    //   keyring integration. For trusted publishing, we use an OIDC auth routine without keyring
+    //   or other auth integration.
+    BaseClientBuilder::new()
+        .retries(0)
+        .keyring(keyring_provider)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        )
+        .index_tls(target.index_tls.clone())
+        // Don't try cloning the request to make an unauthenticated request first.
+        .auth_integration(AuthIntegration::OnlyAuthenticated)
+        // Set a very high timeout for uploads, connections are often 10x slower on upload than
+        // download. 15 min is taken from the time a trusted publishing token is valid.
+        .default_timeout(Duration::from_secs(15 * 60))
+        .build()
+}
+
+/// Build the client for querying the index of a target for existing files, if the target has a
+/// check URL.
+fn check_url_client<'a>(
+    target: &PublishTarget,
+    upload_client: &'a BaseClient,
+    keyring_provider: KeyringProviderType,
+    network_settings: &NetworkSettings,
+    cache: &'a Cache,
+) -> Result<Option<CheckUrlClient<'a>>> {
+    let Some(index_url) = &target.check_url else {
+        return Ok(None);
+    };
+    let mut index = Index::from_index_url(index_url.clone());
+    if let Some(tls) = target
+        .index_tls
+        .iter()
+        .find(|tls| tls.url == *index_url.url())
+    {
+        index.ssl_ca_cert.clone_from(&tls.ca_cert);
+        index.ssl_client_cert.clone_from(&tls.client_cert);
+        index.ssl_client_key.clone_from(&tls.client_key);
+    }
+    let index_locations = IndexLocations::new(vec![index], Vec::new(), false);
+    let registry_client_builder = RegistryClientBuilder::new(cache.clone())
+        .retries_from_env()?
+        .native_tls(network_settings.native_tls)
+        .connectivity(network_settings.connectivity)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone())
+        .proxy_settings(network_settings.proxy_settings.clone())
+        .http_connection(network_settings.http_connection.clone())
+        .retry_policies(
+            network_settings.retry_policy.clone(),
+            network_settings.download_retry_policy.clone(),
+        )
+        .index_locations(&index_locations)
+        .keyring(keyring_provider);
+    Ok(Some(CheckUrlClient {
+        index_url: index_url.clone(),
+        registry_client_builder,
+        client: upload_client,
+        index_capabilities: IndexCapabilities::default(),
+        cache,
+    }))
 }
 
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
     Enabled,
-    #[allow(dead_code)]
     Disabled,
 }
 
+/// The username and token of the CI job, for registries that accept them for uploads.
+fn ci_credentials(quirks: IndexQuirks) -> Option<(String, String)> {
+    match quirks {
+        IndexQuirks::GitLab => {
+            let token = std::env::var(EnvVars::CI_JOB_TOKEN).ok()?;
+            debug!("Using the GitLab CI job token");
+            Some(("gitlab-ci-token".to_string(), token))
+        }
+        IndexQuirks::GitHub => {
+            let token = std::env::var(EnvVars::GITHUB_TOKEN).ok()?;
+            let actor =
+                std::env::var(EnvVars::GITHUB_ACTOR).unwrap_or_else(|_| "token".to_string());
+            debug!("Using the GitHub Actions token of `{actor}`");
+            Some((actor, token))
+        }
+        IndexQuirks::Artifactory
+        | IndexQuirks::Nexus
+        | IndexQuirks::Devpi
+        | IndexQuirks::CodeArtifact => None,
+    }
+}
+
 /// Unify the different possible source for username and password information.
 ///
 /// Possible credential sources are environment variables, the CLI, the URL, the keyring, trusted
@@ -0,0 +1,148 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheBucket, Pruner, rm_rf};
+use uv_fs::Simplified;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Remove unused (and, optionally, corrupted) wheels from the store.
+pub(crate) fn store_gc(verify: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let store = cache.bucket(CacheBucket::Archive);
+    if !store.exists() {
+        writeln!(
+            printer.stderr(),
+            "No store found at: {}",
+            store.user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Collecting garbage in store at: {}",
+        store.user_display().cyan()
+    )?;
+
+    // Remove any wheels that are no longer referenced by the cache.
+    let before = archives(&store)?.len();
+    let mut summary = cache
+        .prune_archives(&mut Pruner::default())
+        .with_context(|| format!("Failed to prune store at: {}", store.user_display()))?;
+    let remaining = archives(&store)?;
+    let unused = before - remaining.len();
+
+    // Remove any wheels that fail the integrity check.
+    let mut corrupted = 0;
+    if verify {
+        for archive in &remaining {
+            let Some((name, reason)) = verify_archive(archive)? else {
+                continue;
+            };
+            writeln!(
+                printer.stderr(),
+                "Removing corrupted wheel `{}` ({reason})",
+                name.cyan()
+            )?;
+            summary += rm_rf(archive)?;
+            corrupted += 1;
+        }
+    }
+
+    match unused {
+        0 => write!(printer.stderr(), "No unused wheels found")?,
+        1 => write!(printer.stderr(), "Removed 1 unused wheel")?,
+        unused => write!(printer.stderr(), "Removed {unused} unused wheels")?,
+    }
+    if verify {
+        let verified = remaining.len() - corrupted;
+        write!(
+            printer.stderr(),
+            ", verified {verified} {}",
+            if verified == 1 { "wheel" } else { "wheels" }
+        )?;
+        if corrupted > 0 {
+            write!(
+                printer.stderr(),
+                ", and removed {corrupted} corrupted {}",
+                if corrupted == 1 { "wheel" } else { "wheels" }
+            )?;
+        }
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Verify the integrity of an unpacked wheel in the store against its `RECORD` file.
+///
+/// Returns the name of the wheel and a description of the corruption, if any.
+pub(crate) fn verify_archive(archive: &Path) -> Result<Option<(String, String)>> {
+    let Some(dist_info) = dist_info(archive)? else {
+        return Ok(None);
+    };
+    let reason = match uv_install_wheel::verify_wheel(&dist_info) {
+        Ok(mismatches) if mismatches.is_empty() => return Ok(None),
+        Ok(mismatches) => format!(
+            "{} {} missing or modified",
+            mismatches.len(),
+            if mismatches.len() == 1 {
+                "file"
+            } else {
+                "files"
+            }
+        ),
+        Err(uv_install_wheel::Error::MissingRecord(_)) => "missing `RECORD` file".to_string(),
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("Failed to verify wheel at: {}", archive.user_display()));
+        }
+    };
+    let name = dist_info
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    Ok(Some((name, reason)))
+}
+
+/// Return the paths to the wheels in the store.
+pub(crate) fn archives(store: &Path) -> Result<Vec<PathBuf>> {
+    match fs_err::read_dir(store) {
+        Ok(entries) => Ok(entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Return the path to the `.dist-info` directory of an unpacked wheel, if any.
+fn dist_info(archive: &Path) -> Result<Option<PathBuf>> {
+    for entry in fs_err::read_dir(archive)? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "dist-info")
+            && path.is_dir()
+        {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
@@ -8,6 +8,7 @@ use std::path::Path;
 use std::process::ExitCode;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 use anstream::eprintln;
 use anyhow::{Context, Result, bail};
@@ -19,16 +20,18 @@ use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
 use tracing::{debug, instrument};
 
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, PruneFilter, Refresh, RemoteCache};
 use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
-    BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands, PipCommand, PipNamespace,
-    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
-    ToolNamespace, TopLevelArgs, compat::CompatArgs,
+    AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
+    IndexCommand, IndexNamespace, MirrorCommand, MirrorNamespace, PipCommand, PipNamespace,
+    ProjectCommand, PublishArgs, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace,
+    StoreCommand, StoreNamespace, TaskCommand, TaskNamespace, ToolCommand, ToolNamespace,
+    TopLevelArgs, compat::CompatArgs,
 };
-use uv_configuration::min_stack_size;
+use uv_configuration::{ByteSize, DryRun, ExtrasSpecification, min_stack_size};
 use uv_fs::{CWD, Simplified};
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
@@ -43,12 +46,13 @@ use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
+use crate::commands::pip::operations::Modifications;
 use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, GlobalSettings, PipAuditSettings, PipCheckSettings, PipCompileSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PipWheelSettings, PublishSettings, PublishYankSettings,
 };
 
 pub(crate) mod child;
@@ -299,7 +303,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .map(Pep723Item::metadata)
         .and_then(|metadata| metadata.tool.as_ref())
         .and_then(|tool| tool.uv.as_ref())
-        .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()))
+        .map(|uv| Options::simple((*uv.globals).clone(), uv.top_level.clone()))
         .map(FilesystemOptions::from)
         .combine(filesystem);
 
@@ -309,8 +313,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Resolve the global settings.
     let globals = GlobalSettings::resolve(&cli.top_level.global_args, filesystem.as_ref());
 
+    // Determine whether the cache directory was set via `--cache-dir` or `UV_CACHE_DIR`, which take
+    // precedence over any configuration updated by `uv cache move`.
+    let cache_dir_override = cli.top_level.cache_args.cache_dir.is_some();
+
     // Resolve the cache settings.
-    let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
+    let cache_settings =
+        CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref(), &environment);
 
     // Enforce the required version.
     if let Some(required_version) = globals.required_version.as_ref() {
@@ -407,6 +416,28 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Don't initialize the rayon threadpool yet, this is too costly when we're doing a noop sync.
     uv_configuration::RAYON_PARALLELISM.store(globals.concurrency.installs, Ordering::Relaxed);
 
+    // Configure how file system operations work around transient errors on Windows.
+    uv_fs::set_windows_install_robustness(globals.windows_install_robustness);
+
+    // Configure how long to wait for locks held by other processes.
+    uv_fs::set_lock_timeout(globals.wait_timeout.map(Duration::from_secs));
+
+    // Configure the bandwidth available to downloads.
+    uv_client::set_download_bandwidth(
+        globals
+            .download_bandwidth
+            .map(ByteSize::bytes)
+            .filter(|bytes| *bytes > 0),
+    );
+
+    // Record the HTTP requests made by the command, if requested.
+    let _http_trace = globals
+        .trace_http
+        .as_deref()
+        .map(uv_client::start_http_trace)
+        .transpose()
+        .context("Failed to create HTTP trace file")?;
+
     debug!("uv {}", uv_cli::version::uv_self_version());
 
     // Write out any resolved settings.
@@ -427,7 +458,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let remote = cache_settings
+        .remote_url
+        .map(|url| RemoteCache::new(url, cache_settings.remote_upload))
+        .transpose()?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_max_size(cache_settings.max_size.map(ByteSize::bytes))
+        .with_eviction_weights(cache_settings.eviction_weights.unwrap_or_default())
+        .with_index_isolation(cache_settings.index_isolation)
+        .with_roots(cache_settings.roots.unwrap_or_default())
+        .with_remote(remote);
 
     match *cli.command {
         Commands::Help(args) => commands::help(
@@ -506,6 +546,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.emit_build_options,
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
+                args.settings.emit_source_lines,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
@@ -579,7 +620,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.reinstall,
                 args.settings.link_mode,
-                args.settings.compile_bytecode,
+                &args.settings.compile_bytecode,
+                &args.settings.install_hooks,
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -587,6 +629,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 &globals.network_settings,
+                args.find_links_only.as_deref(),
                 args.settings.allow_empty_requirements,
                 globals.installer_metadata,
                 &args.settings.config_setting,
@@ -601,6 +644,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
+                args.force,
                 args.settings.target,
                 args.settings.prefix,
                 args.settings.sources,
@@ -728,7 +772,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &globals.network_settings,
                 args.settings.reinstall,
                 args.settings.link_mode,
-                args.settings.compile_bytecode,
+                &args.settings.compile_bytecode,
+                &args.settings.install_hooks,
                 args.settings.hash_checking,
                 globals.installer_metadata,
                 &args.settings.config_setting,
@@ -745,12 +790,91 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
+                args.force,
                 args.settings.target,
                 args.settings.prefix,
                 globals.python_preference,
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.report.as_deref(),
+                args.output_format,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Wheel(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipWheelSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_constraints = args
+                .build_constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::pip_wheel(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.constraints_from_workspace,
+                args.overrides_from_workspace,
+                args.build_constraints_from_workspace,
+                &args.settings.extras,
+                args.wheel_dir.as_deref().unwrap_or(Path::new(".")),
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.dependency_mode,
+                args.settings.upgrade,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &globals.network_settings,
+                args.settings.link_mode,
+                args.settings.hash_checking,
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.no_build_isolation,
+                args.settings.no_build_isolation_package,
+                args.settings.build_options,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.python,
+                args.settings.system,
+                globals.concurrency,
+                cache,
                 printer,
                 globals.preview,
             )
@@ -781,6 +905,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
+                args.force,
                 args.settings.target,
                 args.settings.prefix,
                 cache,
@@ -804,6 +929,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::pip_freeze(
                 args.exclude_editable,
+                &args.exclude,
+                args.hashes,
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
@@ -825,11 +952,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Initialize the cache.
             let cache = cache.init()?;
 
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+
             commands::pip_list(
                 args.editable,
                 &args.exclude,
+                args.only_uv_managed,
                 &args.format,
                 args.outdated,
+                &constraints,
                 args.settings.prerelease,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -862,6 +997,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.system,
                 args.files,
+                args.entry_points,
+                args.reverse,
+                args.output_format,
                 &cache,
                 printer,
                 globals.preview,
@@ -878,6 +1016,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             commands::pip_tree(
                 args.show_version_specifiers,
+                args.output_format,
                 args.depth,
                 &args.prune,
                 &args.package,
@@ -910,13 +1049,128 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Initialize the cache.
             let cache = cache.init()?;
 
+            let outcome = commands::pip_check(
+                args.settings.python.as_deref(),
+                args.settings.system,
+                args.explain,
+                args.fix,
+                &cache,
+                printer,
+                globals.preview,
+            )?;
+            if !args.fix || outcome.fixes.is_empty() {
+                return Ok(outcome.status);
+            }
+
+            // Install the missing and incompatible dependencies, then check the environment again.
+            let requirements = outcome
+                .fixes
+                .iter()
+                .map(|requirement| {
+                    RequirementsSource::from_package_argument(&requirement.to_string())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let groups = GroupsSpecification {
+                root: project_dir.to_path_buf(),
+                groups: Vec::new(),
+            };
+
+            let status = Box::pin(commands::pip_install(
+                &requirements,
+                &[],
+                &[],
+                &[],
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                &ExtrasSpecification::default(),
+                &groups,
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.dependency_mode,
+                args.settings.upgrade,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.torch_backend,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &globals.network_settings,
+                args.settings.reinstall,
+                args.settings.link_mode,
+                &args.settings.compile_bytecode,
+                &args.settings.install_hooks,
+                args.settings.hash_checking,
+                globals.installer_metadata,
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.no_build_isolation,
+                args.settings.no_build_isolation_package,
+                args.settings.build_options,
+                Modifications::Sufficient,
+                None,
+                None,
+                false,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.python.clone(),
+                args.settings.system,
+                args.settings.break_system_packages,
+                false,
+                None,
+                None,
+                globals.python_preference,
+                globals.concurrency,
+                cache.clone(),
+                DryRun::Disabled,
+                None,
+                printer,
+                globals.preview,
+            ))
+            .await?;
+            if !matches!(status, ExitStatus::Success) {
+                return Ok(status);
+            }
+
             commands::pip_check(
+                args.settings.python.as_deref(),
+                args.settings.system,
+                false,
+                false,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .map(|outcome| outcome.status)
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Audit(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipAuditSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            let requirements = args
+                .requirements
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            commands::pip_audit(
+                &requirements,
+                args.fix,
+                &args.ignore_vuln,
+                &globals.network_settings,
+                globals.concurrency,
                 args.settings.python.as_deref(),
                 args.settings.system,
                 &cache,
                 printer,
                 globals.preview,
             )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Clean(args),
@@ -929,7 +1183,62 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            let filter = PruneFilter {
+                older_than: args.older_than,
+                packages: args.package,
+                buckets: args.bucket,
+            };
+            commands::cache_prune(
+                args.ci,
+                args.max_size,
+                &filter,
+                args.dry_run,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Refresh(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_refresh(&args.package, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_export(&args.output, args.lockfile.as_deref(), &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => {
+            show_settings!(args);
+            let cache = cache.init()?;
+            commands::cache_import(&args.input, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.repair, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Stats(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_stats(args.output_format, &cache, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Move(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_move(
+                &args.target,
+                cli.top_level.config_file.as_deref(),
+                cache_dir_override,
+                &cache,
+                printer,
+            )
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -937,6 +1246,71 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Store(StoreNamespace {
+            command: StoreCommand::Gc(args),
+        }) => {
+            show_settings!(args);
+            commands::store_gc(args.verify, &cache, printer)
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            commands::auth_login(
+                &args.service,
+                args.username,
+                args.password,
+                args.keyring_provider,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => commands::auth_logout(&args.service, args.username.as_deref(), printer).await,
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::List,
+        }) => commands::auth_list(printer),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Seal(args),
+        }) => commands::auth_seal(args.keyring_provider, printer),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Unseal,
+        }) => commands::auth_unseal(printer),
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Build(args),
+        }) => {
+            show_settings!(args);
+            commands::index_build(
+                &args.directory,
+                args.output.as_deref(),
+                args.base_url.as_ref(),
+                printer,
+            )
+        }
+        Commands::Mirror(MirrorNamespace {
+            command: MirrorCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::MirrorSyncSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::mirror_sync(
+                &args.packages,
+                args.lockfile.as_deref(),
+                &args.output,
+                args.build_index,
+                args.base_url.as_ref(),
+                &args.settings,
+                &globals.network_settings,
+                globals.concurrency,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -966,9 +1340,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.list,
                 args.build_logs,
                 args.force_pep517,
+                args.reproducible_check,
+                args.from_sdist,
+                args.output_format,
                 build_constraints,
                 args.hash_checking,
                 args.python,
+                args.python_platform,
                 args.install_mirrors,
                 &args.settings,
                 &globals.network_settings,
@@ -1536,6 +1914,35 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::python_update_shell(printer).await?;
             Ok(ExitStatus::Success)
         }
+        Commands::Publish(PublishArgs {
+            command: Some(command),
+            ..
+        }) => {
+            show_settings!(command);
+
+            let PublishYankSettings {
+                action,
+                release,
+                index,
+                dry_run,
+                yes,
+                publish_url,
+                index_locations,
+            } = PublishYankSettings::resolve(command, filesystem);
+
+            commands::publish_yank(
+                action,
+                &release,
+                index.as_deref(),
+                &publish_url,
+                &index_locations,
+                dry_run,
+                yes,
+                &globals.network_settings,
+                printer,
+            )
+            .await
+        }
         Commands::Publish(args) => {
             show_settings!(args);
 
@@ -1559,54 +1966,64 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 keyring_provider,
                 check_url,
                 index,
+                attest,
+                check,
+                workspace,
+                bounds,
+                wait,
                 index_locations,
             } = PublishSettings::resolve(args, filesystem);
 
-            let (publish_url, check_url) = if let Some(index_name) = index {
-                debug!("Publishing with index {index_name}");
-                let index = index_locations
-                    .simple_indexes()
-                    .find(|index| {
-                        index
-                            .name
-                            .as_ref()
-                            .is_some_and(|name| name.as_ref() == index_name)
-                    })
-                    .with_context(|| {
-                        let mut index_names: Vec<String> = index_locations
-                            .simple_indexes()
-                            .filter_map(|index| index.name.as_ref())
-                            .map(ToString::to_string)
-                            .collect();
-                        index_names.sort();
-                        if index_names.is_empty() {
-                            format!("No indexes were found, can't use index: `{index_name}`")
-                        } else {
-                            let index_names = index_names.join("`, `");
-                            format!(
-                                "Index not found: `{index_name}`. Found indexes: `{index_names}`"
-                            )
-                        }
-                    })?;
-                let publish_url = index
-                    .publish_url
-                    .clone()
-                    .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
-                let check_url = index.url.clone();
-                (publish_url, Some(check_url))
+            if check {
+                return commands::publish_check(files, printer).await;
+            }
+
+            let targets = if index.is_empty() {
+                vec![commands::PublishTarget {
+                    index: None,
+                    publish_url,
+                    check_url,
+                    index_tls: Vec::new(),
+                    quirks: None,
+                }]
             } else {
-                (publish_url, check_url)
+                debug!("Publishing with indexes: {}", index.join(", "));
+                index
+                    .iter()
+                    .map(|index_name| {
+                        commands::PublishTarget::from_index(index_name, &index_locations)
+                    })
+                    .collect::<Result<Vec<_>>>()?
             };
 
+            if workspace {
+                return commands::publish_workspace(
+                    &project_dir,
+                    targets,
+                    bounds,
+                    attest.as_deref(),
+                    wait,
+                    trusted_publishing,
+                    keyring_provider,
+                    &globals.network_settings,
+                    username,
+                    password,
+                    &cache,
+                    printer,
+                )
+                .await;
+            }
+
             commands::publish(
                 files,
-                publish_url,
+                targets,
+                attest.as_deref(),
+                wait,
                 trusted_publishing,
                 keyring_provider,
                 &globals.network_settings,
                 username,
                 password,
-                check_url,
                 &cache,
                 printer,
             )
@@ -1690,6 +2107,7 @@ async fn run_project(
                 args.name,
                 args.package,
                 args.kind,
+                args.template,
                 args.bare,
                 args.description,
                 args.no_description,
@@ -1752,6 +2170,7 @@ async fn run_project(
                 args.isolated,
                 args.all_packages,
                 args.package,
+                args.each_package,
                 args.no_project,
                 no_config,
                 args.extras,
@@ -1800,6 +2219,12 @@ async fn run_project(
                 args.frozen,
                 args.dry_run,
                 args.active,
+                args.target,
+                args.prefix,
+                args.no_scripts,
+                args.expose_scripts,
+                args.download_limits,
+                args.find_links_only,
                 args.all_packages,
                 args.package,
                 args.extras,
@@ -1807,6 +2232,7 @@ async fn run_project(
                 args.editable,
                 args.install_options,
                 args.modifications,
+                args.prune_explain,
                 args.python,
                 args.python_platform,
                 args.install_mirrors,
@@ -1854,6 +2280,11 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.minimize,
+                args.migrate_index,
+                args.snapshot,
+                args.show_links,
+                args.output_format,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -1964,8 +2395,10 @@ async fn run_project(
                 args.marker,
                 args.editable,
                 args.dependency_type,
+                args.include_groups,
                 args.raw,
                 args.bounds,
+                args.download_limits,
                 args.indexes,
                 args.rev,
                 args.tag,
@@ -2016,6 +2449,7 @@ async fn run_project(
                 args.no_sync,
                 args.packages,
                 args.dependency_type,
+                args.include_groups,
                 args.package,
                 args.python,
                 args.install_mirrors,
@@ -2117,6 +2551,124 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Verify(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::VerifySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::verify(
+                project_dir,
+                args.fix,
+                args.extras,
+                args.groups,
+                args.modifications,
+                args.settings,
+                globals.network_settings,
+                globals.installer_metadata,
+                globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Prune(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ProjectPruneSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::prune(
+                project_dir,
+                args.dry_run,
+                args.extras,
+                args.groups,
+                args.settings,
+                &cache,
+                printer,
+            ))
+            .await
+        }
+        ProjectCommand::Prefetch(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PrefetchSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                Refresh::from(args.settings.reinstall.clone())
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::prefetch(
+                project_dir,
+                args.extras,
+                args.groups,
+                args.python,
+                args.python_platform,
+                args.install_mirrors,
+                globals.python_preference,
+                globals.python_downloads,
+                args.settings,
+                globals.network_settings,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Task(TaskNamespace {
+            command: TaskCommand::Run(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::TaskRunSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::task_run(
+                project_dir,
+                args.tasks,
+                args.locked,
+                args.frozen,
+                args.no_sync,
+                args.package,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                globals.network_settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Task(TaskNamespace {
+            command: TaskCommand::List(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::TaskListSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            Box::pin(commands::task_list(project_dir, args.package, printer)).await
+        }
         ProjectCommand::Export(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::ExportSettings::resolve(args, filesystem);
@@ -3,35 +3,40 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, CacheEvictionWeights, CacheRoots, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBump, VersionFormat,
+    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, IndexMigration, InitArgs, ListFormat,
+    LockArgs, LockFormat, Maybe, MirrorSyncArgs, PipAuditArgs, PipCheckArgs, PipCompileArgs,
+    PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs,
+    PipUninstallArgs, PipWheelArgs, PrefetchArgs, ProjectPruneArgs, PythonFindArgs,
+    PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs,
+    PythonUpgradeArgs, RemoveArgs, RunArgs, ShowFormat, SyncArgs, SyncFormat, TaskListArgs,
+    TaskRunArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs,
+    TreeArgs, TreeFormat, VenvArgs, VerifyArgs, VersionArgs, VersionBump, VersionFormat,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
-    ToolUpgradeArgs,
-    options::{flag, resolver_installer_options, resolver_options},
+    AuthorFrom, BuildArgs, BuildFormat, ExportArgs, PublishArgs, PublishCommand, PythonDirArgs,
+    ResolverInstallerArgs, ToolUpgradeArgs,
+    options::{flag, mirror_options, resolver_installer_options, resolver_options},
 };
-use uv_client::Connectivity;
+use uv_client::{Connectivity, HttpRetryPolicy, ProxySettings};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PackageConfigSettings, PreviewMode,
+    BuildOptions, ByteSize, CompileBytecode, Concurrency, ConfigSettings, DependencyGroups,
+    DownloadLimits, DryRun, EditableMode, ExportFormat, ExtrasSpecification, HashCheckingMode,
+    HttpConnectionOptions, IndexStrategy, InstallHooks, InstallOptions, KeyringProviderType,
+    NoBinary, NoBuild, NoBuildIsolationPackages, PackageConfigSettings, PreviewMode,
     ProjectBuildBackend, Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost,
     TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl, Requirement};
+use uv_fs::WindowsInstallRobustness;
 use uv_install_wheel::LinkMode;
-use uv_normalize::{PackageName, PipGroupName};
+use uv_normalize::{GroupName, PackageName, PipGroupName};
 use uv_pep508::{ExtraName, MarkerTree, RequirementOrigin};
-use uv_pypi_types::SupportedEnvironments;
+use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
@@ -47,12 +52,17 @@ use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::DependencyType;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::commands::ToolRunCommand;
+use crate::commands::{
+    EachPackage, PYPI_ATTESTATIONS, PublishWait, SnapshotAction, ToolRunCommand,
+};
 use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
 
+/// The default time to wait for published files to become available with `--verify`, in seconds.
+const DEFAULT_PUBLISH_WAIT_TIMEOUT: u64 = 600;
+
 /// The resolved global settings to use for any invocation of the CLI.
 #[derive(Debug, Clone)]
 pub(crate) struct GlobalSettings {
@@ -68,6 +78,10 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    pub(crate) windows_install_robustness: WindowsInstallRobustness,
+    pub(crate) wait_timeout: Option<u64>,
+    pub(crate) download_bandwidth: Option<ByteSize>,
+    pub(crate) trace_http: Option<PathBuf>,
 }
 
 impl GlobalSettings {
@@ -107,6 +121,16 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
+                // Unless set explicitly, allow as many downloads per host as overall.
+                downloads_per_host: env(env::CONCURRENT_DOWNLOADS_PER_HOST)
+                    .combine(
+                        workspace
+                            .and_then(|workspace| workspace.globals.concurrent_downloads_per_host),
+                    )
+                    .or(env(env::CONCURRENT_DOWNLOADS))
+                    .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
                 builds: env(env::CONCURRENT_BUILDS)
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_builds))
                     .map(NonZeroUsize::get)
@@ -115,6 +139,13 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
+                // Unless set explicitly, compile with as many workers as are used for installs.
+                compiles: env(env::CONCURRENT_COMPILES)
+                    .combine(workspace.and_then(|workspace| workspace.globals.concurrent_compiles))
+                    .or(env(env::CONCURRENT_INSTALLS))
+                    .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
+                    .map(NonZeroUsize::get)
+                    .unwrap_or_else(Concurrency::threads),
             },
             show_settings: args.show_settings,
             preview: PreviewMode::from(
@@ -136,6 +167,17 @@ impl GlobalSettings {
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !args.no_installer_metadata,
+            windows_install_robustness: env(env::UV_WINDOWS_INSTALL_ROBUSTNESS)
+                .combine(
+                    workspace.and_then(|workspace| workspace.globals.windows_install_robustness),
+                )
+                .unwrap_or_default(),
+            wait_timeout: args
+                .wait_timeout
+                .combine(workspace.and_then(|workspace| workspace.globals.wait_timeout)),
+            download_bandwidth: env(env::UV_DOWNLOAD_BANDWIDTH)
+                .combine(workspace.and_then(|workspace| workspace.globals.download_bandwidth)),
+            trace_http: args.trace_http.clone(),
         }
     }
 }
@@ -161,6 +203,11 @@ pub(crate) struct NetworkSettings {
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) proxy_settings: ProxySettings,
+    pub(crate) retry_policy: HttpRetryPolicy,
+    pub(crate) download_retry_policy: HttpRetryPolicy,
+    pub(crate) upload_retry_policy: HttpRetryPolicy,
+    pub(crate) http_connection: HttpConnectionOptions,
 }
 
 impl NetworkSettings {
@@ -193,10 +240,37 @@ impl NetworkSettings {
                     .flatten(),
             )
             .collect();
+        let proxy_settings = workspace
+            .map(|workspace| ProxySettings {
+                http_proxy: workspace.globals.http_proxy.clone(),
+                https_proxy: workspace.globals.https_proxy.clone(),
+                no_proxy: workspace.globals.no_proxy.clone().unwrap_or_default(),
+            })
+            .unwrap_or_default();
+        let retry_policy = workspace
+            .and_then(|workspace| workspace.globals.retry_policy.as_ref())
+            .map(|options| HttpRetryPolicy::from_options(options, &HttpRetryPolicy::default()))
+            .unwrap_or_default();
+        let download_retry_policy = workspace
+            .and_then(|workspace| workspace.globals.download_retry_policy.as_ref())
+            .map(|options| HttpRetryPolicy::from_options(options, &retry_policy))
+            .unwrap_or_else(|| retry_policy.clone());
+        let upload_retry_policy = workspace
+            .and_then(|workspace| workspace.globals.upload_retry_policy.as_ref())
+            .map(|options| HttpRetryPolicy::from_options(options, &retry_policy))
+            .unwrap_or_else(|| retry_policy.clone());
+        let http_connection = workspace
+            .and_then(|workspace| workspace.globals.http_connection.clone())
+            .unwrap_or_default();
         Self {
             connectivity,
             native_tls,
             allow_insecure_host,
+            proxy_settings,
+            retry_policy,
+            download_retry_policy,
+            upload_retry_policy,
+            http_connection,
         }
     }
 }
@@ -206,11 +280,21 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) max_size: Option<ByteSize>,
+    pub(crate) eviction_weights: Option<CacheEvictionWeights>,
+    pub(crate) index_isolation: bool,
+    pub(crate) roots: Option<CacheRoots>,
+    pub(crate) remote_url: Option<DisplaySafeUrl>,
+    pub(crate) remote_upload: bool,
 }
 
 impl CacheSettings {
     /// Resolve the [`CacheSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn resolve(args: CacheArgs, workspace: Option<&FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(
+        args: CacheArgs,
+        workspace: Option<&FilesystemOptions>,
+        environment: &EnvironmentOptions,
+    ) -> Self {
         Self {
             no_cache: args.no_cache
                 || workspace
@@ -219,6 +303,22 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            max_size: env(env::UV_CACHE_MAX_SIZE)
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_max_size)),
+            eviction_weights: workspace
+                .and_then(|workspace| workspace.globals.cache_eviction_weights),
+            index_isolation: environment
+                .cache_index_isolation
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_index_isolation))
+                .unwrap_or(false),
+            roots: workspace.and_then(|workspace| workspace.globals.cache_roots.clone()),
+            remote_url: env(env::UV_REMOTE_CACHE_URL).or_else(|| {
+                workspace.and_then(|workspace| workspace.globals.remote_cache_url.clone())
+            }),
+            remote_upload: environment
+                .remote_cache_upload
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.remote_cache_upload))
+                .unwrap_or(false),
         }
     }
 }
@@ -230,6 +330,7 @@ pub(crate) struct InitSettings {
     pub(crate) name: Option<PackageName>,
     pub(crate) package: bool,
     pub(crate) kind: InitKind,
+    pub(crate) template: Option<String>,
     pub(crate) bare: bool,
     pub(crate) description: Option<String>,
     pub(crate) no_description: bool,
@@ -257,6 +358,7 @@ impl InitSettings {
             app,
             lib,
             script,
+            template,
             description,
             no_description,
             vcs,
@@ -296,6 +398,7 @@ impl InitSettings {
             name,
             package,
             kind,
+            template,
             bare,
             description,
             no_description,
@@ -327,6 +430,7 @@ pub(crate) struct RunSettings {
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
+    pub(crate) each_package: Option<EachPackage>,
     pub(crate) no_project: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
@@ -383,6 +487,9 @@ impl RunSettings {
             refresh,
             all_packages,
             package,
+            each_package,
+            skip_package,
+            jobs,
             no_project,
             python,
             show_resolution,
@@ -438,8 +545,12 @@ impl RunSettings {
                 .collect(),
             isolated,
             show_resolution,
-            all_packages,
+            all_packages: all_packages || each_package,
             package,
+            each_package: each_package.then(|| EachPackage {
+                skip: skip_package,
+                jobs: jobs.unwrap_or(NonZeroUsize::MIN),
+            }),
             no_project,
             no_sync,
             active: flag(active, no_active, "active"),
@@ -720,6 +831,9 @@ impl ToolUpgradeSettings {
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            compile_bytecode_level,
+            compile_bytecode_package,
+            no_compile_bytecode_package,
             no_sources,
             build,
         } = args;
@@ -755,6 +869,9 @@ impl ToolUpgradeSettings {
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
+            compile_bytecode_level,
+            compile_bytecode_package,
+            no_compile_bytecode_package,
             no_sources,
         };
 
@@ -1163,11 +1280,18 @@ pub(crate) struct SyncSettings {
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    pub(crate) target: Option<Target>,
+    pub(crate) prefix: Option<Prefix>,
+    pub(crate) no_scripts: bool,
+    pub(crate) expose_scripts: Option<PathBuf>,
+    pub(crate) download_limits: DownloadLimits,
+    pub(crate) find_links_only: Option<PathBuf>,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: EditableMode,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) prune_explain: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
@@ -1198,13 +1322,21 @@ impl SyncSettings {
             no_editable,
             inexact,
             exact,
+            prune_explain,
             no_install_project,
             no_install_workspace,
             no_install_package,
+            only_package,
             locked,
             frozen,
+            find_links_only,
             active,
             no_active,
+            target,
+            prefix,
+            no_scripts,
+            expose_scripts,
+            max_download_size,
             dry_run,
             installer,
             build,
@@ -1223,11 +1355,43 @@ impl SyncSettings {
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
-        let settings = ResolverInstallerSettings::combine(
+        // Resolve relative paths on the command line against the current working directory. (Paths
+        // in configuration files are resolved against the workspace root.)
+        let expose_scripts = expose_scripts
+            .map(|path| std::path::absolute(&path).unwrap_or(path))
+            .or_else(|| {
+                filesystem
+                    .as_ref()
+                    .and_then(|fs| fs.sync.expose_scripts.clone())
+            });
+        let download_limits = DownloadLimits {
+            confirm: filesystem
+                .as_ref()
+                .and_then(|fs| fs.sync.confirm_download_size),
+            max: max_download_size
+                .or_else(|| filesystem.as_ref().and_then(|fs| fs.sync.max_download_size)),
+        };
+
+        let mut settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
             filesystem,
         );
 
+        // If `--only-package` is provided, always reinstall the package, and leave the rest of the
+        // environment untouched.
+        let modifications = if only_package.is_some() {
+            Modifications::Sufficient
+        } else if flag(exact, inexact, "inexact").unwrap_or(true) {
+            Modifications::Exact
+        } else {
+            Modifications::Sufficient
+        };
+        if let Some(only_package) = &only_package {
+            settings.reinstall = settings
+                .reinstall
+                .combine(Reinstall::Packages(vec![only_package.clone()], vec![]));
+        }
+
         let check = flag(check, no_check, "check").unwrap_or_default();
         let dry_run = if check {
             DryRun::Check
@@ -1238,10 +1402,17 @@ impl SyncSettings {
         Self {
             output_format,
             locked,
-            frozen,
+            // `--find-links-only` installs the lockfile as-is, without network access.
+            frozen: frozen || find_links_only.is_some(),
             dry_run,
             script,
             active: flag(active, no_active, "active"),
+            target: target.map(Target::from),
+            prefix: prefix.map(Prefix::from),
+            no_scripts,
+            expose_scripts,
+            download_limits,
+            find_links_only,
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -1266,12 +1437,10 @@ impl SyncSettings {
                 no_install_project,
                 no_install_workspace,
                 no_install_package,
+                only_package,
             ),
-            modifications: if flag(exact, inexact, "inexact").unwrap_or(true) {
-                Modifications::Exact
-            } else {
-                Modifications::Sufficient
-            },
+            modifications,
+            prune_explain,
             all_packages,
             package,
             python: python.and_then(Maybe::into_option),
@@ -1283,119 +1452,409 @@ impl SyncSettings {
     }
 }
 
-/// The resolved settings to use for a `lock` invocation.
+/// The resolved settings to use for a `verify` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
-pub(crate) struct LockSettings {
-    pub(crate) locked: bool,
-    pub(crate) frozen: bool,
+pub(crate) struct VerifySettings {
+    pub(crate) fix: bool,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) modifications: Modifications,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl VerifySettings {
+    /// Resolve the [`VerifySettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: VerifyArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let VerifyArgs {
+            fix,
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            no_default_groups,
+            only_group,
+            all_groups,
+            inexact,
+            exact,
+            installer,
+            build,
+        } = args;
+
+        Self {
+            fix,
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                dev,
+                no_dev,
+                only_dev,
+                group,
+                no_group,
+                no_default_groups,
+                only_group,
+                all_groups,
+            ),
+            modifications: if flag(exact, inexact, "inexact").unwrap_or(true) {
+                Modifications::Exact
+            } else {
+                Modifications::Sufficient
+            },
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `prune` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ProjectPruneSettings {
     pub(crate) dry_run: DryRun,
-    pub(crate) script: Option<PathBuf>,
-    pub(crate) python: Option<String>,
-    pub(crate) install_mirrors: PythonInstallMirrors,
-    pub(crate) refresh: Refresh,
-    pub(crate) settings: ResolverSettings,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) settings: ResolverInstallerSettings,
 }
 
-impl LockSettings {
-    /// Resolve the [`LockSettings`] from the CLI and filesystem configuration.
+impl ProjectPruneSettings {
+    /// Resolve the [`ProjectPruneSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: LockArgs, filesystem: Option<FilesystemOptions>) -> Self {
-        let LockArgs {
-            check,
-            check_exists,
+    pub(crate) fn resolve(args: ProjectPruneArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ProjectPruneArgs {
             dry_run,
-            script,
-            resolver,
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            no_default_groups,
+            only_group,
+            all_groups,
+            installer,
             build,
-            refresh,
-            python,
         } = args;
 
+        Self {
+            dry_run: DryRun::from_args(dry_run),
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                dev,
+                no_dev,
+                only_dev,
+                group,
+                no_group,
+                no_default_groups,
+                only_group,
+                all_groups,
+            ),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `prefetch` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PrefetchSettings {
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl PrefetchSettings {
+    /// Resolve the [`PrefetchSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PrefetchArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PrefetchArgs {
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            no_default_groups,
+            only_group,
+            all_groups,
+            python,
+            python_platform,
+            installer,
+            build,
+        } = args;
         let install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
         Self {
-            locked: check,
-            frozen: check_exists,
-            dry_run: DryRun::from_args(dry_run),
-            script,
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                dev,
+                no_dev,
+                only_dev,
+                group,
+                no_group,
+                no_default_groups,
+                only_group,
+                all_groups,
+            ),
             python: python.and_then(Maybe::into_option),
-            refresh: Refresh::from(refresh),
-            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            python_platform,
             install_mirrors,
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
         }
     }
 }
 
-/// The resolved settings to use for a `add` invocation.
-#[allow(clippy::struct_excessive_bools, dead_code)]
+/// The resolved settings to use for a `task run` invocation.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct AddSettings {
+pub(crate) struct TaskRunSettings {
+    pub(crate) tasks: Vec<String>,
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
-    pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
-    pub(crate) packages: Vec<String>,
-    pub(crate) requirements: Vec<PathBuf>,
-    pub(crate) constraints: Vec<PathBuf>,
-    pub(crate) marker: Option<MarkerTree>,
-    pub(crate) dependency_type: DependencyType,
-    pub(crate) editable: Option<bool>,
-    pub(crate) extras: Vec<ExtraName>,
-    pub(crate) raw: bool,
-    pub(crate) bounds: Option<AddBoundsKind>,
-    pub(crate) rev: Option<String>,
-    pub(crate) tag: Option<String>,
-    pub(crate) branch: Option<String>,
     pub(crate) package: Option<PackageName>,
-    pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
-    pub(crate) workspace: Option<bool>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
-    pub(crate) indexes: Vec<Index>,
     pub(crate) settings: ResolverInstallerSettings,
 }
 
-impl AddSettings {
-    /// Resolve the [`AddSettings`] from the CLI and filesystem configuration.
+impl TaskRunSettings {
+    /// Resolve the [`TaskRunSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: AddArgs, filesystem: Option<FilesystemOptions>) -> Self {
-        let AddArgs {
-            packages,
-            requirements,
-            constraints,
-            marker,
-            dev,
-            optional,
-            group,
-            editable,
-            no_editable,
-            extra,
-            raw,
-            bounds,
-            rev,
-            tag,
-            branch,
+    pub(crate) fn resolve(args: TaskRunArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let TaskRunArgs {
+            tasks,
             no_sync,
             locked,
             frozen,
-            active,
-            no_active,
+            package,
+            python,
             installer,
             build,
             refresh,
-            package,
-            script,
-            python,
-            workspace,
-            no_workspace,
         } = args;
 
-        let dependency_type = if let Some(extra) = optional {
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            tasks,
+            locked,
+            frozen,
+            no_sync,
+            package,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `task list` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct TaskListSettings {
+    pub(crate) package: Option<PackageName>,
+}
+
+impl TaskListSettings {
+    /// Resolve the [`TaskListSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: TaskListArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let TaskListArgs { package } = args;
+
+        Self { package }
+    }
+}
+
+/// The resolved settings to use for a `lock` invocation.
+#[allow(clippy::struct_excessive_bools, dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct LockSettings {
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) dry_run: DryRun,
+    pub(crate) minimize: bool,
+    pub(crate) migrate_index: Option<IndexMigration>,
+    pub(crate) snapshot: Option<SnapshotAction>,
+    pub(crate) show_links: bool,
+    pub(crate) output_format: LockFormat,
+    pub(crate) script: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl LockSettings {
+    /// Resolve the [`LockSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: LockArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let LockArgs {
+            check,
+            check_exists,
+            dry_run,
+            minimize,
+            migrate_index,
+            snapshot,
+            rollback,
+            list_snapshots,
+            show_links,
+            output_format,
+            script,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            locked: check,
+            frozen: check_exists,
+            dry_run: DryRun::from_args(dry_run),
+            minimize,
+            migrate_index,
+            snapshot: if let Some(name) = snapshot {
+                Some(SnapshotAction::Create(name))
+            } else if let Some(name) = rollback {
+                Some(SnapshotAction::Rollback(name))
+            } else if list_snapshots {
+                Some(SnapshotAction::List)
+            } else {
+                None
+            },
+            show_links,
+            output_format,
+            script,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
+/// The resolved settings to use for a `add` invocation.
+#[allow(clippy::struct_excessive_bools, dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct AddSettings {
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) active: Option<bool>,
+    pub(crate) no_sync: bool,
+    pub(crate) packages: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) marker: Option<MarkerTree>,
+    pub(crate) dependency_type: DependencyType,
+    pub(crate) include_groups: Vec<GroupName>,
+    pub(crate) editable: Option<bool>,
+    pub(crate) extras: Vec<ExtraName>,
+    pub(crate) raw: bool,
+    pub(crate) bounds: Option<AddBoundsKind>,
+    pub(crate) download_limits: DownloadLimits,
+    pub(crate) rev: Option<String>,
+    pub(crate) tag: Option<String>,
+    pub(crate) branch: Option<String>,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) script: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) workspace: Option<bool>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) indexes: Vec<Index>,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl AddSettings {
+    /// Resolve the [`AddSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: AddArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AddArgs {
+            packages,
+            requirements,
+            constraints,
+            marker,
+            dev,
+            optional,
+            group,
+            include_group,
+            editable,
+            no_editable,
+            extra,
+            raw,
+            bounds,
+            max_download_size,
+            rev,
+            tag,
+            branch,
+            no_sync,
+            locked,
+            frozen,
+            active,
+            no_active,
+            installer,
+            build,
+            refresh,
+            package,
+            script,
+            python,
+            workspace,
+            no_workspace,
+        } = args;
+
+        let dependency_type = if let Some(extra) = optional {
             DependencyType::Optional(extra)
         } else if let Some(group) = group {
             DependencyType::Group(group)
@@ -1471,6 +1930,13 @@ impl AddSettings {
             .unwrap_or_default();
 
         let bounds = bounds.or(filesystem.as_ref().and_then(|fs| fs.add.add_bounds));
+        let download_limits = DownloadLimits {
+            confirm: filesystem
+                .as_ref()
+                .and_then(|fs| fs.sync.confirm_download_size),
+            max: max_download_size
+                .or_else(|| filesystem.as_ref().and_then(|fs| fs.sync.max_download_size)),
+        };
 
         Self {
             locked,
@@ -1485,8 +1951,10 @@ impl AddSettings {
                 .collect(),
             marker,
             dependency_type,
+            include_groups: include_group,
             raw,
             bounds,
+            download_limits,
             rev,
             tag,
             branch,
@@ -1517,6 +1985,7 @@ pub(crate) struct RemoveSettings {
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
     pub(crate) dependency_type: DependencyType,
+    pub(crate) include_groups: Vec<GroupName>,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
@@ -1534,6 +2003,7 @@ impl RemoveSettings {
             optional,
             packages,
             group,
+            include_group,
             no_sync,
             locked,
             frozen,
@@ -1574,6 +2044,7 @@ impl RemoveSettings {
             no_sync,
             packages,
             dependency_type,
+            include_groups: include_group,
             package,
             script,
             python: python.and_then(Maybe::into_option),
@@ -1836,6 +2307,7 @@ impl ExportSettings {
                 no_emit_project,
                 no_emit_workspace,
                 no_emit_package,
+                None,
             ),
             output_file,
             locked,
@@ -1919,6 +2391,8 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            emit_source_lines,
+            no_emit_source_lines,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2030,6 +2504,11 @@ impl PipCompileSettings {
                         no_emit_index_annotation,
                         "emit-index-annotation",
                     ),
+                    emit_source_lines: flag(
+                        emit_source_lines,
+                        no_emit_source_lines,
+                        "emit-source-lines",
+                    ),
                     annotation_style,
                     torch_backend,
                     ..PipOptions::from(resolver)
@@ -2046,7 +2525,9 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) find_links_only: Option<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) force: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2073,8 +2554,10 @@ impl PipSyncSettings {
             no_system,
             break_system_packages,
             no_break_system_packages,
+            force,
             target,
             prefix,
+            find_links_only,
             allow_empty_requirements,
             no_allow_empty_requirements,
             no_build,
@@ -2100,7 +2583,9 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            find_links_only,
             dry_run: DryRun::from_args(dry_run),
+            force,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2148,6 +2633,9 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) report: Option<PathBuf>,
+    pub(crate) output_format: SyncFormat,
+    pub(crate) force: bool,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
@@ -2183,6 +2671,7 @@ impl PipInstallSettings {
             no_system,
             break_system_packages,
             no_break_system_packages,
+            force,
             target,
             prefix,
             no_build,
@@ -2196,6 +2685,8 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            report,
+            output_format,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2259,6 +2750,9 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            report,
+            output_format,
+            force,
             constraints_from_workspace,
             overrides_from_workspace,
             build_constraints_from_workspace,
@@ -2300,12 +2794,141 @@ impl PipInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `pip wheel` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipWheelSettings {
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) wheel_dir: Option<PathBuf>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) overrides_from_workspace: Vec<Requirement>,
+    pub(crate) build_constraints_from_workspace: Vec<Requirement>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipWheelSettings {
+    /// Resolve the [`PipWheelSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipWheelArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipWheelArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            build_constraints,
+            extra,
+            all_extras,
+            no_all_extras,
+            wheel_dir,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+        } = args;
+
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let overrides_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .override_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let build_constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .build_constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            package,
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            build_constraints: build_constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            wheel_dir,
+            constraints_from_workspace,
+            overrides_from_workspace,
+            build_constraints_from_workspace,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras, "all-extras"),
+                    no_deps: flag(no_deps, deps, "deps"),
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip uninstall` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) force: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2321,6 +2944,7 @@ impl PipUninstallSettings {
             no_system,
             break_system_packages,
             no_break_system_packages,
+            force,
             target,
             prefix,
             dry_run,
@@ -2331,6 +2955,7 @@ impl PipUninstallSettings {
             package,
             requirements,
             dry_run: DryRun::from_args(dry_run),
+            force,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2355,6 +2980,8 @@ impl PipUninstallSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipFreezeSettings {
     pub(crate) exclude_editable: bool,
+    pub(crate) exclude: Vec<PackageName>,
+    pub(crate) hashes: bool,
     pub(crate) paths: Option<Vec<PathBuf>>,
     pub(crate) settings: PipSettings,
 }
@@ -2364,6 +2991,8 @@ impl PipFreezeSettings {
     pub(crate) fn resolve(args: PipFreezeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipFreezeArgs {
             exclude_editable,
+            exclude,
+            hashes,
             strict,
             no_strict,
             python,
@@ -2375,6 +3004,8 @@ impl PipFreezeSettings {
 
         Self {
             exclude_editable,
+            exclude,
+            hashes,
             paths,
             settings: PipSettings::combine(
                 PipOptions {
@@ -2394,8 +3025,10 @@ impl PipFreezeSettings {
 pub(crate) struct PipListSettings {
     pub(crate) editable: Option<bool>,
     pub(crate) exclude: Vec<PackageName>,
+    pub(crate) only_uv_managed: bool,
     pub(crate) format: ListFormat,
     pub(crate) outdated: bool,
+    pub(crate) constraints: Vec<PathBuf>,
     pub(crate) settings: PipSettings,
 }
 
@@ -2406,9 +3039,11 @@ impl PipListSettings {
             editable,
             exclude_editable,
             exclude,
+            only_uv_managed,
             format,
             outdated,
             no_outdated,
+            constraints,
             strict,
             no_strict,
             fetch,
@@ -2421,8 +3056,13 @@ impl PipListSettings {
         Self {
             editable: flag(editable, exclude_editable, "exclude-editable"),
             exclude,
+            only_uv_managed,
             format,
             outdated: flag(outdated, no_outdated, "outdated").unwrap_or(false),
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2441,6 +3081,9 @@ impl PipListSettings {
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
     pub(crate) files: bool,
+    pub(crate) entry_points: bool,
+    pub(crate) reverse: bool,
+    pub(crate) output_format: ShowFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -2452,6 +3095,9 @@ impl PipShowSettings {
             strict,
             no_strict,
             files,
+            entry_points,
+            reverse,
+            output_format,
             python,
             system,
             no_system,
@@ -2461,6 +3107,9 @@ impl PipShowSettings {
         Self {
             package,
             files,
+            entry_points,
+            reverse,
+            output_format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2478,6 +3127,7 @@ impl PipShowSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipTreeSettings {
     pub(crate) show_version_specifiers: bool,
+    pub(crate) output_format: TreeFormat,
     pub(crate) depth: u8,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
@@ -2492,6 +3142,7 @@ impl PipTreeSettings {
     pub(crate) fn resolve(args: PipTreeArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipTreeArgs {
             show_version_specifiers,
+            output_format,
             tree,
             strict,
             no_strict,
@@ -2504,6 +3155,7 @@ impl PipTreeSettings {
 
         Self {
             show_version_specifiers,
+            output_format,
             depth: tree.depth,
             prune: tree.prune,
             no_dedupe: tree.no_dedupe,
@@ -2526,6 +3178,8 @@ impl PipTreeSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCheckSettings {
+    pub(crate) explain: bool,
+    pub(crate) fix: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2533,12 +3187,53 @@ impl PipCheckSettings {
     /// Resolve the [`PipCheckSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: PipCheckArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PipCheckArgs {
+            explain,
+            fix,
             python,
             system,
             no_system,
         } = args;
 
         Self {
+            explain,
+            fix,
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    ..PipOptions::default()
+                },
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip audit` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipAuditSettings {
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) fix: bool,
+    pub(crate) ignore_vuln: Vec<String>,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipAuditSettings {
+    /// Resolve the [`PipAuditSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PipAuditArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PipAuditArgs {
+            requirements,
+            fix,
+            ignore_vuln,
+            python,
+            system,
+            no_system,
+        } = args;
+
+        Self {
+            requirements,
+            fix,
+            ignore_vuln,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2555,7 +3250,7 @@ impl PipCheckSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct BuildSettings {
     pub(crate) src: Option<PathBuf>,
-    pub(crate) package: Option<PackageName>,
+    pub(crate) package: Vec<PackageName>,
     pub(crate) all_packages: bool,
     pub(crate) out_dir: Option<PathBuf>,
     pub(crate) sdist: bool,
@@ -2563,9 +3258,13 @@ pub(crate) struct BuildSettings {
     pub(crate) list: bool,
     pub(crate) build_logs: bool,
     pub(crate) force_pep517: bool,
+    pub(crate) reproducible_check: bool,
+    pub(crate) from_sdist: bool,
+    pub(crate) output_format: BuildFormat,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) python: Option<String>,
+    pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -2583,6 +3282,9 @@ impl BuildSettings {
             wheel,
             list,
             force_pep517,
+            reproducible_check,
+            from_sdist,
+            output_format,
             build_constraints,
             require_hashes,
             no_require_hashes,
@@ -2591,6 +3293,7 @@ impl BuildSettings {
             build_logs,
             no_build_logs,
             python,
+            python_platform,
             build,
             refresh,
             resolver,
@@ -2615,11 +3318,15 @@ impl BuildSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             force_pep517,
+            reproducible_check,
+            from_sdist,
+            output_format,
             hash_checking: HashCheckingMode::from_args(
                 flag(require_hashes, no_require_hashes, "require-hashes"),
                 flag(verify_hashes, no_verify_hashes, "verify-hashes"),
             ),
             python: python.and_then(Maybe::into_option),
+            python_platform,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -2705,10 +3412,11 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) config_setting: &'a ConfigSettings,
     pub(crate) config_settings_package: &'a PackageConfigSettings,
     pub(crate) no_build_isolation: bool,
-    pub(crate) no_build_isolation_package: &'a [PackageName],
+    pub(crate) no_build_isolation_package: &'a NoBuildIsolationPackages,
     pub(crate) exclude_newer: Option<ExcludeNewer>,
     pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: &'a CompileBytecode,
+    pub(crate) install_hooks: &'a InstallHooks,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: SourceStrategy,
@@ -2731,7 +3439,7 @@ pub(crate) struct ResolverSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) link_mode: LinkMode,
     pub(crate) no_build_isolation: bool,
-    pub(crate) no_build_isolation_package: Vec<PackageName>,
+    pub(crate) no_build_isolation_package: NoBuildIsolationPackages,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) resolution: ResolutionMode,
     pub(crate) sources: SourceStrategy,
@@ -2812,7 +3520,8 @@ impl From<ResolverOptions> for ResolverSettings {
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolver: ResolverSettings,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: CompileBytecode,
+    pub(crate) install_hooks: InstallHooks,
     pub(crate) reinstall: Reinstall,
 }
 
@@ -2886,7 +3595,13 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .collect(),
                 ),
             },
-            compile_bytecode: value.compile_bytecode.unwrap_or_default(),
+            compile_bytecode: CompileBytecode::from_args(
+                value.compile_bytecode,
+                value.compile_bytecode_package.unwrap_or_default(),
+                value.no_compile_bytecode_package.unwrap_or_default(),
+                value.compile_bytecode_level,
+            ),
+            install_hooks: value.install_hooks.unwrap_or_default(),
             reinstall: Reinstall::from_args(
                 value.reinstall,
                 value.reinstall_package.unwrap_or_default(),
@@ -2914,7 +3629,7 @@ pub(crate) struct PipSettings {
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) torch_backend: Option<TorchMode>,
     pub(crate) no_build_isolation: bool,
-    pub(crate) no_build_isolation_package: Vec<PackageName>,
+    pub(crate) no_build_isolation_package: NoBuildIsolationPackages,
     pub(crate) build_options: BuildOptions,
     pub(crate) allow_empty_requirements: bool,
     pub(crate) strict: bool,
@@ -2942,9 +3657,11 @@ pub(crate) struct PipSettings {
     pub(crate) emit_build_options: bool,
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
+    pub(crate) emit_source_lines: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
+    pub(crate) compile_bytecode: CompileBytecode,
+    pub(crate) install_hooks: InstallHooks,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) upgrade: Upgrade,
@@ -3012,9 +3729,14 @@ impl PipSettings {
             emit_build_options,
             emit_marker_expression,
             emit_index_annotation,
+            emit_source_lines,
             annotation_style,
             link_mode,
             compile_bytecode,
+            compile_bytecode_level,
+            compile_bytecode_package,
+            no_compile_bytecode_package,
+            install_hooks,
             require_hashes,
             verify_hashes,
             no_sources,
@@ -3043,6 +3765,10 @@ impl PipSettings {
             exclude_newer: top_level_exclude_newer,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
+            compile_bytecode_level: top_level_compile_bytecode_level,
+            compile_bytecode_package: top_level_compile_bytecode_package,
+            no_compile_bytecode_package: top_level_no_compile_bytecode_package,
+            install_hooks: top_level_install_hooks,
             no_sources: top_level_no_sources,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
@@ -3078,6 +3804,13 @@ impl PipSettings {
         let exclude_newer = exclude_newer.combine(top_level_exclude_newer);
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
+        let compile_bytecode_level =
+            compile_bytecode_level.combine(top_level_compile_bytecode_level);
+        let compile_bytecode_package =
+            compile_bytecode_package.combine(top_level_compile_bytecode_package);
+        let no_compile_bytecode_package =
+            no_compile_bytecode_package.combine(top_level_no_compile_bytecode_package);
+        let install_hooks = install_hooks.combine(top_level_install_hooks);
         let no_sources = no_sources.combine(top_level_no_sources);
         let upgrade = upgrade.combine(top_level_upgrade);
         let upgrade_package = upgrade_package.combine(top_level_upgrade_package);
@@ -3207,6 +3940,10 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
+            emit_source_lines: args
+                .emit_source_lines
+                .combine(emit_source_lines)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
@@ -3220,9 +3957,19 @@ impl PipSettings {
                 .unwrap_or_default(),
             target: args.target.combine(target).map(Target::from),
             prefix: args.prefix.combine(prefix).map(Prefix::from),
-            compile_bytecode: args
-                .compile_bytecode
-                .combine(compile_bytecode)
+            compile_bytecode: CompileBytecode::from_args(
+                args.compile_bytecode.combine(compile_bytecode),
+                args.compile_bytecode_package
+                    .combine(compile_bytecode_package)
+                    .unwrap_or_default(),
+                args.no_compile_bytecode_package
+                    .combine(no_compile_bytecode_package)
+                    .unwrap_or_default(),
+                args.compile_bytecode_level.combine(compile_bytecode_level),
+            ),
+            install_hooks: args
+                .install_hooks
+                .combine(install_hooks)
                 .unwrap_or_default(),
             sources: SourceStrategy::from_args(
                 args.no_sources.combine(no_sources).unwrap_or_default(),
@@ -3276,7 +4023,8 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             no_build_isolation_package: &settings.resolver.no_build_isolation_package,
             exclude_newer: settings.resolver.exclude_newer,
             link_mode: settings.resolver.link_mode,
-            compile_bytecode: settings.compile_bytecode,
+            compile_bytecode: &settings.compile_bytecode,
+            install_hooks: &settings.install_hooks,
             reinstall: &settings.reinstall,
             build_options: &settings.resolver.build_options,
             sources: settings.resolver.sources,
@@ -3291,7 +4039,12 @@ pub(crate) struct PublishSettings {
     pub(crate) files: Vec<String>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
-    pub(crate) index: Option<String>,
+    pub(crate) index: Vec<String>,
+    pub(crate) attest: Option<String>,
+    pub(crate) check: bool,
+    pub(crate) workspace: bool,
+    pub(crate) bounds: Option<AddBoundsKind>,
+    pub(crate) wait: Option<PublishWait>,
 
     // Both CLI and configuration.
     pub(crate) publish_url: DisplaySafeUrl,
@@ -3317,13 +4070,8 @@ impl PublishSettings {
             trusted_publishing,
             check_url,
         } = publish;
-        let ResolverInstallerOptions {
-            keyring_provider,
-            index,
-            extra_index_url,
-            index_url,
-            ..
-        } = top_level;
+        let keyring_provider = top_level.keyring_provider;
+        let index_locations = publish_index_locations(top_level);
 
         // Tokens are encoded in the same way as username/password
         let (username, password) = if let Some(token) = args.token {
@@ -3349,16 +4097,144 @@ impl PublishSettings {
                 .unwrap_or_default(),
             check_url: args.check_url.combine(check_url),
             index: args.index,
-            index_locations: IndexLocations::new(
-                index
-                    .into_iter()
-                    .flatten()
-                    .chain(extra_index_url.into_iter().flatten().map(Index::from))
-                    .chain(index_url.into_iter().map(Index::from))
-                    .collect(),
-                Vec::new(),
-                false,
-            ),
+            attest: args.attest.then(|| {
+                args.attest_from
+                    .unwrap_or_else(|| PYPI_ATTESTATIONS.to_string())
+            }),
+            check: args.check,
+            workspace: args.workspace,
+            bounds: args.bounds,
+            wait: match (args.wait, args.verify) {
+                (Some(seconds), verify) => Some(PublishWait {
+                    timeout: Duration::from_secs(seconds),
+                    verify,
+                }),
+                (None, true) => Some(PublishWait {
+                    timeout: Duration::from_secs(DEFAULT_PUBLISH_WAIT_TIMEOUT),
+                    verify: true,
+                }),
+                (None, false) => None,
+            },
+            index_locations,
+        }
+    }
+}
+
+/// Whether to yank a release, or to undo the yanking of a release.
+#[derive(Debug, Clone)]
+pub(crate) enum YankAction {
+    Yank { reason: Option<String> },
+    Unyank,
+}
+
+/// The resolved settings to use for an invocation of the `uv publish yank` or
+/// `uv publish unyank` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct PublishYankSettings {
+    // CLI only, see [`YankArgs`] for docs.
+    pub(crate) action: YankAction,
+    pub(crate) release: String,
+    pub(crate) index: Option<String>,
+    pub(crate) dry_run: bool,
+    pub(crate) yes: bool,
+
+    // Both CLI and configuration.
+    pub(crate) publish_url: DisplaySafeUrl,
+
+    // Configuration only
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl PublishYankSettings {
+    /// Resolve the [`PublishYankSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(command: PublishCommand, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options {
+            publish, top_level, ..
+        } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+        let index_locations = publish_index_locations(top_level);
+        let publish_url = |url: Option<DisplaySafeUrl>| {
+            url.combine(publish.publish_url)
+                .unwrap_or_else(|| DisplaySafeUrl::parse(PYPI_PUBLISH_URL).unwrap())
+        };
+
+        match command {
+            PublishCommand::Yank(args) => Self {
+                action: YankAction::Yank {
+                    reason: args.reason,
+                },
+                release: args.release,
+                index: args.index,
+                dry_run: args.dry_run,
+                yes: args.yes,
+                publish_url: publish_url(args.publish_url),
+                index_locations,
+            },
+            PublishCommand::Unyank(args) => Self {
+                action: YankAction::Unyank,
+                release: args.release,
+                index: args.index,
+                dry_run: args.dry_run,
+                yes: args.yes,
+                publish_url: publish_url(args.publish_url),
+                index_locations,
+            },
+        }
+    }
+}
+
+/// The indexes in the configuration, by which `uv publish` selects the index to publish to.
+fn publish_index_locations(top_level: ResolverInstallerOptions) -> IndexLocations {
+    let ResolverInstallerOptions {
+        index,
+        extra_index_url,
+        index_url,
+        ..
+    } = top_level;
+    IndexLocations::new(
+        index
+            .into_iter()
+            .flatten()
+            .chain(extra_index_url.into_iter().flatten().map(Index::from))
+            .chain(index_url.into_iter().map(Index::from))
+            .collect(),
+        Vec::new(),
+        false,
+    )
+}
+
+/// The resolved settings to use for a `mirror sync` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct MirrorSyncSettings {
+    pub(crate) packages: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    pub(crate) lockfile: Option<PathBuf>,
+    pub(crate) output: PathBuf,
+    pub(crate) build_index: bool,
+    pub(crate) base_url: Option<DisplaySafeUrl>,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl MirrorSyncSettings {
+    /// Resolve the [`MirrorSyncSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: MirrorSyncArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let options = mirror_options(&args);
+        let MirrorSyncArgs {
+            packages,
+            lockfile,
+            output,
+            build_index,
+            base_url,
+            ..
+        } = args;
+
+        Self {
+            packages,
+            lockfile,
+            output,
+            build_index,
+            base_url,
+            settings: ResolverSettings::combine(options, filesystem),
         }
     }
 }
@@ -3370,12 +4246,38 @@ mod env {
     pub(super) const CONCURRENT_DOWNLOADS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_DOWNLOADS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_DOWNLOADS_PER_HOST: (&str, &str) = (
+        EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+        "a non-zero integer",
+    );
+
     pub(super) const CONCURRENT_BUILDS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_BUILDS, "a non-zero integer");
 
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_COMPILES: (&str, &str) =
+        (EnvVars::UV_CONCURRENT_COMPILES, "a non-zero integer");
+
+    pub(super) const UV_WINDOWS_INSTALL_ROBUSTNESS: (&str, &str) = (
+        EnvVars::UV_WINDOWS_INSTALL_ROBUSTNESS,
+        "one of 'none', 'retry', or 'robust'",
+    );
+
+    pub(super) const UV_DOWNLOAD_BANDWIDTH: (&str, &str) = (
+        EnvVars::UV_DOWNLOAD_BANDWIDTH,
+        "a size in bytes, optionally followed by a unit (e.g., `10MB`)",
+    );
+
+    pub(super) const UV_CACHE_MAX_SIZE: (&str, &str) = (
+        EnvVars::UV_CACHE_MAX_SIZE,
+        "a size in bytes, optionally followed by a unit (e.g., `10GB`)",
+    );
+
+    pub(super) const UV_REMOTE_CACHE_URL: (&str, &str) =
+        (EnvVars::UV_REMOTE_CACHE_URL, "a valid URL");
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
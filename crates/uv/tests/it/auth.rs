@@ -0,0 +1,223 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use predicates::prelude::predicate;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Store, list, and remove credentials in uv's credential store.
+#[test]
+fn auth_login_list_logout() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    uv_snapshot!(context.filters(), context.auth_login()
+        .arg("https://pypi.example.com/simple")
+        .arg("--username")
+        .arg("user")
+        .arg("--password")
+        .arg("secret")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for user on https://pypi.example.com/simple
+    ");
+
+    uv_snapshot!(context.filters(), context.auth_login()
+        .arg("internal.example.com")
+        .arg("--username")
+        .arg("__token__")
+        .arg("--password")
+        .arg("token")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for __token__ on https://internal.example.com/
+    ");
+
+    // The passwords are masked.
+    uv_snapshot!(context.filters(), context.auth_list()
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://pypi.example.com/simple  user       ********
+    https://internal.example.com/    __token__  ********
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.auth_logout()
+        .arg("https://pypi.example.com/simple")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for user on https://pypi.example.com/simple
+    ");
+
+    uv_snapshot!(context.filters(), context.auth_logout()
+        .arg("https://pypi.example.com/simple")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found for https://pypi.example.com/simple
+    ");
+
+    uv_snapshot!(context.filters(), context.auth_list()
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://internal.example.com/  __token__  ********
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Seal the credential store with a passphrase, and unseal it.
+#[test]
+fn auth_seal_unseal() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    context
+        .auth_login()
+        .arg("pypi.example.com")
+        .arg("--username")
+        .arg("user")
+        .arg("--password")
+        .arg("secret")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.auth_seal()
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path())
+        .env(EnvVars::UV_CREDENTIALS_PASSPHRASE, "passphrase"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Sealed the credential store at [TEMP_DIR]/credentials/credentials.toml.age
+    ");
+    credentials_dir
+        .child("credentials.toml")
+        .assert(predicate::path::missing());
+    assert!(
+        !fs_err::read_to_string(credentials_dir.child("credentials.toml.age"))?.contains("secret")
+    );
+
+    // Without the passphrase, the store can't be read.
+    uv_snapshot!(context.filters(), context.auth_list()
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The credential store is sealed with a passphrase; set `UV_CREDENTIALS_PASSPHRASE` to read it
+    ");
+
+    uv_snapshot!(context.filters(), context.auth_list()
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path())
+        .env(EnvVars::UV_CREDENTIALS_PASSPHRASE, "passphrase"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://pypi.example.com/  user  ********
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.auth_unseal()
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path())
+        .env(EnvVars::UV_CREDENTIALS_PASSPHRASE, "passphrase"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Unsealed the credential store at [TEMP_DIR]/credentials/credentials.toml
+    ");
+    credentials_dir
+        .child("credentials.toml.age")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Without a terminal, the password must be provided on the command line.
+#[test]
+fn auth_login_non_interactive() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.auth_login()
+        .arg("pypi.example.com")
+        .arg("--username")
+        .arg("user"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No password provided; use `--password` to provide one non-interactively
+    ");
+}
+
+/// Install from an authenticated index, using the credentials in uv's credential store.
+#[test]
+#[cfg(all(feature = "python", feature = "pypi"))]
+fn auth_login_install() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let credentials_dir = context.temp_dir.child("credentials");
+
+    uv_snapshot!(context.filters(), context.auth_login()
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--username")
+        .arg("public")
+        .arg("--password")
+        .arg("heron")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for public on https://pypi-proxy.fly.dev/basic-auth/simple
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .env(EnvVars::UV_CREDENTIALS_DIR, credentials_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
@@ -3,10 +3,11 @@ use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use fs_err::File;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
 use std::env::current_dir;
+use uv_static::EnvVars;
 use zip::ZipArchive;
 
 #[test]
@@ -686,6 +687,72 @@ fn build_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Build workspace members after the members they depend on.
+#[test]
+fn build_workspace_dependency_order() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+    "#})?;
+
+    // `alpha` depends on `beta`, which depends on `gamma`.
+    for (name, dependencies) in [
+        ("alpha", r#"["beta"]"#),
+        ("beta", r#"["gamma>=0.1"]"#),
+        ("gamma", "[]"),
+    ] {
+        let member = project.child("packages").child(name);
+        member.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = {dependencies}
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#})?;
+        member
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    uv_snapshot!(context.filters(), context.build().arg("--all-packages").arg("--sdist").arg("--no-build-logs").current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    [gamma] Building source distribution...
+    [beta] Building source distribution...
+    [alpha] Building source distribution...
+    Successfully built dist/gamma-0.1.0.tar.gz
+    Successfully built dist/beta-0.1.0.tar.gz
+    Successfully built dist/alpha-0.1.0.tar.gz
+    "###);
+
+    // Selecting multiple packages uses the same order.
+    uv_snapshot!(context.filters(), context.build().arg("--package").arg("alpha").arg("--package").arg("beta").arg("--sdist").arg("--no-build-logs").current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    [beta] Building source distribution...
+    [alpha] Building source distribution...
+    Successfully built dist/beta-0.1.0.tar.gz
+    Successfully built dist/alpha-0.1.0.tar.gz
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn build_all_with_failure() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1531,6 +1598,232 @@ fn build_fast_path() -> Result<()> {
     Ok(())
 }
 
+/// Build twice with `--reproducible-check` and compare the digests.
+#[test]
+fn build_reproducible_check() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"sha256:[0-9a-f]{64}", "sha256:[DIGEST]")])
+        .collect::<Vec<_>>();
+
+    let built_by_uv = current_dir()?.join("../../scripts/packages/built-by-uv");
+
+    uv_snapshot!(&filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output1"))
+        .arg("--reproducible-check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output1/built_by_uv-0.1.0.tar.gz
+    Reproduced built_by_uv-0.1.0.tar.gz (sha256:[DIGEST])
+    Successfully built output1/built_by_uv-0.1.0-py3-none-any.whl
+    Reproduced built_by_uv-0.1.0-py3-none-any.whl (sha256:[DIGEST])
+    "###);
+
+    // The timestamps from `SOURCE_DATE_EPOCH` are reproducible too.
+    uv_snapshot!(&filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output2"))
+        .arg("--reproducible-check")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1709210096"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output2/built_by_uv-0.1.0.tar.gz
+    Reproduced built_by_uv-0.1.0.tar.gz (sha256:[DIGEST])
+    Successfully built output2/built_by_uv-0.1.0-py3-none-any.whl
+    Reproduced built_by_uv-0.1.0-py3-none-any.whl (sha256:[DIGEST])
+    "###);
+
+    Ok(())
+}
+
+/// Check the wheel built from the source distribution against the source tree with `--from-sdist`.
+#[test]
+fn build_from_sdist() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+ files\)", "([N] files)")])
+        .collect::<Vec<_>>();
+
+    let built_by_uv = current_dir()?.join("../../scripts/packages/built-by-uv");
+
+    uv_snapshot!(&filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output"))
+        .arg("--from-sdist"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Building wheel from source tree (uv build backend)...
+    Successfully built output/built_by_uv-0.1.0.tar.gz
+    Successfully built output/built_by_uv-0.1.0-py3-none-any.whl
+    Checked built_by_uv-0.1.0-py3-none-any.whl against the source tree ([N] files)
+    "###);
+
+    // A data file that is excluded from the source distribution is missing from the wheel built
+    // from it.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.hatch.build.targets.sdist]
+        exclude = ["src/project/data.txt"]
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project
+        .child("src")
+        .child("project")
+        .child("data.txt")
+        .touch()?;
+
+    uv_snapshot!(&filters, context.build()
+        .arg("--from-sdist")
+        .current_dir(project.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Building wheel from source tree...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ `project-0.1.0-py3-none-any.whl` built from the source distribution is missing files that are in the wheel built from the source tree, include them in the source distribution: `project/data.txt`
+    "###);
+
+    // Building from a source distribution can't be checked against the source tree.
+    uv_snapshot!(&filters, context.build()
+        .arg(context.temp_dir.join("output/built_by_uv-0.1.0.tar.gz"))
+        .arg("--from-sdist"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `[TEMP_DIR]/output/built_by_uv-0.1.0.tar.gz`
+      ╰─▶ `--from-sdist` requires a source directory, not a source distribution
+    "###);
+
+    Ok(())
+}
+
+/// Report the built distributions with `--output-format json`.
+#[test]
+fn build_output_format_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r#""sha256": "[0-9a-f]{64}""#, r#""sha256": "[DIGEST]""#),
+            (r#""size": \d+"#, r#""size": [SIZE]"#),
+            (
+                r#""name": "uv-build",\n(\s+)"version": "[^"]+""#,
+                r#""name": "uv-build",\n$1"version": "[VERSION]""#,
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    let built_by_uv = current_dir()?.join("../../scripts/packages/built-by-uv");
+
+    uv_snapshot!(&filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output"))
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "artifacts": [
+        {
+          "path": "[TEMP_DIR]/output/built_by_uv-0.1.0.tar.gz",
+          "filename": "built_by_uv-0.1.0.tar.gz",
+          "kind": "sdist",
+          "name": "built-by-uv",
+          "version": "0.1.0",
+          "tags": [],
+          "size": [SIZE],
+          "sha256": "[DIGEST]",
+          "backend": {
+            "backend": "uv_build",
+            "name": "uv-build",
+            "version": "[VERSION]",
+            "build_constraints": []
+          }
+        },
+        {
+          "path": "[TEMP_DIR]/output/built_by_uv-0.1.0-py3-none-any.whl",
+          "filename": "built_by_uv-0.1.0-py3-none-any.whl",
+          "kind": "wheel",
+          "name": "built-by-uv",
+          "version": "0.1.0",
+          "tags": [
+            "py3-none-any"
+          ],
+          "size": [SIZE],
+          "sha256": "[DIGEST]",
+          "backend": {
+            "backend": "uv_build",
+            "name": "uv-build",
+            "version": "[VERSION]",
+            "build_constraints": []
+          }
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output/built_by_uv-0.1.0.tar.gz
+    Successfully built output/built_by_uv-0.1.0-py3-none-any.whl
+    "#);
+
+    Ok(())
+}
+
 /// Test the `--list` option.
 #[test]
 fn build_list_files() -> Result<()> {
@@ -1663,7 +1956,7 @@ fn build_list_files_errors() -> Result<()> {
     let mut filters = context.filters();
     // In CI, we run with link mode settings.
     filters.push(("--link-mode <LINK_MODE> ", ""));
-    uv_snapshot!(filters, context.build()
+    uv_snapshot!(&filters, context.build()
         .arg(&built_by_uv)
         .arg("--out-dir")
         .arg(context.temp_dir.join("output1"))
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Export the cache entries for a lockfile, then import them into an empty cache, such that the
+/// project can be synced offline.
+#[test]
+fn export_import_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#})?;
+
+    // Sync the project, to populate the cache.
+    context.sync().assert().success();
+
+    // Add an unrelated package to the cache, which shouldn't be exported.
+    context.pip_install().arg("idna==3.6").assert().success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (
+                r"Exported \d+ files \([^)]+\)",
+                "Exported [N] files ([SIZE])",
+            ),
+            (
+                r"Imported \d+ files \([^)]+\)",
+                "Imported [N] files ([SIZE])",
+            ),
+        ])
+        .collect();
+
+    uv_snapshot!(filters, context.cache_export().arg("cache.tar.gz").arg("--lockfile").arg("uv.lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exporting cache at: [CACHE_DIR]/
+    Exported [N] files ([SIZE]) to: cache.tar.gz
+    ");
+
+    // Clear the cache and the environment.
+    context.clean().assert().success();
+    fs_err::remove_dir_all(context.temp_dir.child(".venv"))?;
+
+    uv_snapshot!(filters, context.cache_import().arg("cache.tar.gz"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Importing cache from: cache.tar.gz
+    Imported [N] files ([SIZE])
+    ");
+
+    // Importing the same archive again should be a no-op.
+    uv_snapshot!(filters, context.cache_import().arg("cache.tar.gz"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Importing cache from: cache.tar.gz
+    Cache is already up-to-date
+    ");
+
+    // The project's dependencies should be installable from the imported cache.
+    uv_snapshot!(context.filters(), context.sync().arg("--offline"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The unrelated package should not have been exported.
+    assert!(context.cache_dir.child("wheels-v5/pypi/iniconfig").exists());
+    assert!(!context.cache_dir.child("wheels-v5/pypi/idna").exists());
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache move` should relocate the cache entries and point the user configuration at the new
+/// directory.
+#[test]
+fn move_cache() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let target = context.temp_dir.child("moved-cache");
+
+    uv_snapshot!(context.filters(), context.cache_move().arg(target.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Moving cache from [CACHE_DIR]/ to moved-cache
+    Updated `cache-dir` in: [UV_USER_CONFIG_DIR]/uv.toml
+    warning: The cache directory was provided via `--cache-dir` or `UV_CACHE_DIR`, which takes precedence over the configuration file; update it to `[TEMP_DIR]/moved-cache` to use the moved cache
+    ");
+
+    assert!(!context.cache_dir.exists());
+
+    let config = fs_err::read_to_string(context.user_config_dir.child("uv").child("uv.toml"))?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(config, @r#"cache-dir = "[TEMP_DIR]/moved-cache""#);
+    });
+
+    // Any links into the unpacked wheels should point into the new directory.
+    #[cfg(unix)]
+    for entry in walkdir::WalkDir::new(target.path()) {
+        let entry = entry?;
+        if entry.path_is_symlink() {
+            let link = fs_err::read_link(entry.path())?;
+            assert!(
+                link.starts_with(target.path()),
+                "{} links to {}",
+                entry.path().display(),
+                link.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// `cache move` should refuse to move the cache into a non-empty directory.
+#[test]
+fn move_cache_non_empty() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let target = context.temp_dir.child("moved-cache");
+    target.child("file.txt").touch()?;
+
+    uv_snapshot!(context.filters(), context.cache_move().arg(target.path()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Moving cache from [CACHE_DIR]/ to moved-cache
+    error: Failed to move cache to: moved-cache
+      Caused by: The target directory already exists and is not empty: `[TEMP_DIR]/moved-cache`
+    ");
+
+    assert!(context.cache_dir.exists());
+
+    Ok(())
+}
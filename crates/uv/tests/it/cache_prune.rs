@@ -348,7 +348,7 @@ fn prune_stale_revision() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
-    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v9/[ENTRY]
+    DEBUG Removing dangling source revision: [CACHE_DIR]/sdists-v10/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
     "###);
@@ -382,3 +382,262 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --max-size` should evict the least-recently-used entries until the cache is under
+/// the given size.
+#[test]
+fn prune_max_size() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! { r"
+        ok==1.0.0
+        tqdm
+    " })?;
+
+    // Install the requirements, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .assert()
+        .success();
+
+    // Mark the `ok` entry, along with every archive, as unused since 2020. The `tqdm` entry itself
+    // remains recently used.
+    let wheels = context.cache_dir.child("wheels-v5").child("index");
+    let index = fs_err::read_dir(wheels.path())?.next().unwrap()?.path();
+    let entry = index.join("ok");
+    let archives = context.cache_dir.child("archive-v0");
+    let time = filetime::FileTime::from_unix_time(1_577_836_800, 0);
+    for path in [entry.as_path(), archives.path()] {
+        for file in walkdir::WalkDir::new(path) {
+            let file = file?;
+            if file.file_type().is_file() {
+                filetime::set_file_times(file.path(), time, time)?;
+            }
+        }
+    }
+
+    // Request a maximum size just below the current size of the cache.
+    let size = walkdir::WalkDir::new(&context.cache_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.metadata().unwrap().len())
+        .sum::<u64>();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"Removed \d+ files", "Removed [N] files"),
+            (r"\(\d+ bytes\)", "([N] bytes)"),
+            (
+                r"\[CACHE_DIR\]/(wheels-v5/index|archive-v0)/[A-Za-z0-9_-]+",
+                "[CACHE_DIR]/$1/[ENTRY]",
+            ),
+        ])
+        .collect();
+
+    // Only the least-recently-used entry should be evicted.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg((size - 1).to_string()).arg("--verbose"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Cache size ([N] bytes) exceeds the maximum ([N] bytes); evicting entries
+    DEBUG Evicting cache entry: [CACHE_DIR]/wheels-v5/index/[ENTRY]/ok
+    DEBUG Removing unreferenced cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(!entry.exists());
+    assert!(index.join("tqdm").exists());
+    assert_eq!(fs_err::read_dir(archives.path())?.count(), 1);
+
+    // Evicting everything should leave behind the entries that can't be evicted.
+    uv_snapshot!(&filters, context.prune().arg("--max-size").arg("1B"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    warning: The cache ([SIZE]) still exceeds the maximum size ([SIZE]), as the remaining entries can't be evicted
+    ");
+
+    assert!(!index.join("tqdm").exists());
+
+    Ok(())
+}
+
+/// With `UV_CACHE_MAX_SIZE`, the cache should be trimmed after installing any downloaded
+/// distributions.
+#[test]
+fn cache_max_size() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("tqdm")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .env(EnvVars::UV_CACHE_MAX_SIZE, "1B"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    ");
+
+    // The installed wheel should have been evicted from the cache.
+    let wheels = context.cache_dir.child("wheels-v5").child("index");
+    let index = fs_err::read_dir(wheels.path())?.next().unwrap()?.path();
+    assert!(!index.join("tqdm").exists());
+
+    // An invalid size should be rejected.
+    uv_snapshot!(context.filters(), context
+        .pip_sync()
+        .arg("requirements.txt")
+        .env(EnvVars::UV_CACHE_MAX_SIZE, "10 parsecs"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value for UV_CACHE_MAX_SIZE, expected a size in bytes, optionally followed by a unit (e.g., `10GB`)
+    ");
+
+    Ok(())
+}
+
+/// `cache prune --older-than`, `--package`, and `--bucket` should remove matching entries, even if
+/// they're still in use.
+#[test]
+fn prune_filters() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! { r"
+        ok==1.0.0
+        tqdm
+    " })?;
+
+    // Install the requirements, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .assert()
+        .success();
+
+    // Mark the `ok` entry, along with every archive, as unused since 2020.
+    let wheels = context.cache_dir.child("wheels-v5").child("index");
+    let index = fs_err::read_dir(wheels.path())?.next().unwrap()?.path();
+    let archives = context.cache_dir.child("archive-v0");
+    let time = filetime::FileTime::from_unix_time(1_577_836_800, 0);
+    for path in [index.join("ok").as_path(), archives.path()] {
+        for file in walkdir::WalkDir::new(path) {
+            let file = file?;
+            if file.file_type().is_file() {
+                filetime::set_file_times(file.path(), time, time)?;
+            }
+        }
+    }
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"(Removed|remove) \d+ files?", "$1 [N] files"),
+            (
+                r"\[CACHE_DIR\]/(wheels-v5/index|archive-v0|interpreter-v4)/[A-Za-z0-9_-]+",
+                "[CACHE_DIR]/$1/[ENTRY]",
+            ),
+        ])
+        .collect();
+
+    // A dry run should report the entries that would be removed, without removing them.
+    uv_snapshot!(&filters, context.prune().arg("--package").arg("tqdm").arg("--dry-run").arg("--verbose"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Removing cache entry: [CACHE_DIR]/wheels-v5/index/[ENTRY]/tqdm
+    DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    Would remove [N] files ([SIZE])
+    ");
+
+    assert!(index.join("tqdm").exists());
+
+    // Only entries that haven't been used within the given duration should be removed.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("30d").arg("--verbose"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Removing cache entry: [CACHE_DIR]/wheels-v5/index/[ENTRY]/ok
+    DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(!index.join("ok").exists());
+    assert!(index.join("tqdm").exists());
+    assert_eq!(fs_err::read_dir(archives.path())?.count(), 1);
+
+    // Entries in the given bucket should be removed, regardless of their age.
+    uv_snapshot!(&filters, context.prune().arg("--bucket").arg("interpreters").arg("--verbose"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Removing cache entry: [CACHE_DIR]/interpreter-v4/[ENTRY]
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(index.join("tqdm").exists());
+    assert_eq!(
+        fs_err::read_dir(context.cache_dir.child("interpreter-v4").path())?.count(),
+        0
+    );
+
+    // Invalid durations should be rejected.
+    uv_snapshot!(&filters, context.prune().arg("--older-than").arg("30 fortnights"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '30 fortnights' for '--older-than <DURATION>': failed to parse "30 fortnights" in the "friendly" format: expected to find unit designator suffix (e.g., 'years' or 'secs'), but found input beginning with "fortnights" instead
+
+    For more information, try '--help'.
+    "#);
+
+    Ok(())
+}
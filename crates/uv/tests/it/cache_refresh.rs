@@ -0,0 +1,86 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Return the cache disposition of the Simple API request for `iniconfig` in the given trace.
+fn simple_api_disposition(trace: &str) -> Option<String> {
+    trace
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .find(|entry| {
+            entry["url"]
+                .as_str()
+                .is_some_and(|url| url.ends_with("/simple/iniconfig/"))
+        })
+        .and_then(|entry| entry["cache"].as_str().map(ToString::to_string))
+}
+
+/// `cache refresh` should force the cached Simple API response to be revalidated, rather than
+/// reused as-is or discarded.
+#[test]
+fn refresh() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .assert()
+        .success();
+
+    // The cached response is fresh, so it should be reused without a request.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--trace-http")
+        .arg("fresh.jsonl")
+        .assert()
+        .success();
+    assert_eq!(
+        simple_api_disposition(&context.read("fresh.jsonl")).as_deref(),
+        Some("hit")
+    );
+
+    uv_snapshot!(context.filters(), context.cache_refresh().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Marked 1 cache entry for revalidation
+    ");
+
+    // The cached response should now be revalidated with a conditional request.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--trace-http")
+        .arg("refreshed.jsonl")
+        .assert()
+        .success();
+    assert_eq!(
+        simple_api_disposition(&context.read("refreshed.jsonl")).as_deref(),
+        Some("revalidated")
+    );
+
+    Ok(())
+}
+
+/// `cache refresh` should succeed, without changes, for packages that aren't in the cache.
+#[test]
+fn refresh_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_refresh().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found
+    ");
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+use crate::common::TestContext;
+
+/// `cache stats` should report the hits and misses of recent installations, along with the
+/// packages in the cache.
+#[test]
+fn stats_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The first installation should download the wheel, while the second should be served from
+    // the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    let output = context
+        .cache_stats()
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    assert_eq!(stats["runs"]["count"], 2);
+    assert_eq!(stats["runs"]["hits"], 1);
+    assert_eq!(stats["runs"]["misses"], 1);
+    assert_eq!(stats["runs"]["hit_rate"], 0.5);
+
+    assert_eq!(stats["largest_packages"][0]["name"], "iniconfig");
+    assert!(stats["largest_packages"][0]["bytes"].as_u64().unwrap() > 0);
+
+    let wheels = stats["buckets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bucket| bucket["name"] == "wheels-v5")
+        .unwrap();
+    assert!(wheels["files"].as_u64().unwrap() > 0);
+
+    // Every entry should have been used recently.
+    assert_eq!(stats["last_used"][0]["label"], "<1d");
+    assert!(stats["last_used"][0]["entries"].as_u64().unwrap() > 0);
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache verify` should detect corrupted wheels and truncated pointers, and remove them with
+/// `--repair`, such that they're fetched again on the next install.
+#[test]
+fn verify_repair() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"Verified \d+ entries", "Verified [N] entries")])
+        .collect();
+
+    uv_snapshot!(filters, context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified [N] entries, no corrupted entries found
+    ");
+
+    // Modify a file in the unpacked wheel, and truncate the pointer to it, as if the process had
+    // been killed mid-write.
+    let archive = fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+        .next()
+        .unwrap()?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "")?;
+    context
+        .cache_dir
+        .child("wheels-v5/pypi/iniconfig/2.0.0-py3-none-any.http")
+        .write_binary(&[0x00, 0x01])?;
+
+    uv_snapshot!(filters, context.cache_verify(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Found corrupted wheel `iniconfig-2.0.0` (1 file missing or modified)
+    Found corrupted entry (unreadable or truncated file): [CACHE_DIR]/wheels-v5/pypi/iniconfig/2.0.0-py3-none-any.http
+    Verified [N] entries, found 2 corrupted entries
+    hint: Run `uv cache verify --repair` to remove the corrupted entries
+    ");
+
+    uv_snapshot!(filters, context.cache_verify().arg("--repair"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Removing corrupted wheel `iniconfig-2.0.0` (1 file missing or modified)
+    Removing corrupted entry (unreadable or truncated file): [CACHE_DIR]/wheels-v5/pypi/iniconfig/2.0.0-py3-none-any.http
+    Verified [N] entries, removed 2 corrupted entries ([SIZE])
+    ");
+
+    // The wheel should be downloaded again on the next install.
+    uv_snapshot!(context.filters(), context.pip_install().arg("iniconfig==2.0.0").arg("--reinstall"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    ");
+
+    uv_snapshot!(filters, context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified [N] entries, no corrupted entries found
+    ");
+
+    Ok(())
+}
@@ -852,6 +852,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip audit` command with options shared across scenarios.
+    pub fn pip_audit(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("audit");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip check` command with options shared across scenarios.
     pub fn pip_check(&self) -> Command {
         let mut command = self.new_command();
@@ -883,6 +891,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip wheel` command with options shared across scenarios.
+    pub fn pip_wheel(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("wheel");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip uninstall` command with options shared across scenarios.
     pub fn pip_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -925,6 +941,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv task run` command with options shared across scenarios.
+    pub fn task_run(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("task").arg("run");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv task list` command with options shared across scenarios.
+    pub fn task_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("task").arg("list");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv lock` command with options shared across scenarios.
     pub fn lock(&self) -> Command {
         let mut command = self.new_command();
@@ -1123,6 +1155,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv verify` command with options shared across scenarios.
+    pub fn verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv prune` command with options shared across scenarios.
+    pub fn project_prune(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("prune");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv prefetch` command with options shared across scenarios.
+    pub fn prefetch(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("prefetch");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = self.new_command();
@@ -1139,6 +1195,118 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache refresh` command.
+    pub fn cache_refresh(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("refresh");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache import` command.
+    pub fn cache_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("import");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache stats` command.
+    pub fn cache_stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("stats");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache move` command.
+    pub fn cache_move(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("move");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv store gc` command.
+    pub fn store_gc(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("store").arg("gc");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth login` command.
+    pub fn auth_login(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("login");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth logout` command.
+    pub fn auth_logout(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("logout");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth list` command.
+    pub fn auth_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("list");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth seal` command.
+    pub fn auth_seal(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("seal");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth unseal` command.
+    pub fn auth_unseal(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").arg("unseal");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv index build` command.
+    pub fn index_build(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index").arg("build");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv mirror sync` command with options shared across scenarios.
+    pub fn mirror_sync(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("mirror").arg("sync");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
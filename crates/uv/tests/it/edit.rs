@@ -889,7 +889,7 @@ fn add_raw_error() -> Result<()> {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--raw'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
     For more information, try '--help'.
     ");
@@ -2126,7 +2126,7 @@ fn disallow_group_script_add() -> Result<()> {
     ----- stderr -----
     error: the argument '--group <GROUP>' cannot be used with '--script <SCRIPT>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --group <GROUP> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --group <GROUP> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
     For more information, try '--help'.
     "###);
@@ -4133,7 +4133,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--branch <BRANCH>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
     For more information, try '--help'.
     "###
@@ -4154,7 +4154,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--rev <REV>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
     For more information, try '--help'.
     "###
@@ -4175,7 +4175,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used multiple times
 
-    Usage: uv add [OPTIONS] <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add [OPTIONS] <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
     For more information, try '--help'.
     "###
@@ -4927,9 +4927,9 @@ fn add_requirements_file() -> Result<()> {
 
     ----- stderr -----
     error: the following required arguments were not provided:
-      <PACKAGES|--requirements <REQUIREMENTS>>
+      <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
-    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--include-group <INCLUDE_GROUP>>
 
     For more information, try '--help'.
     "###);
@@ -5872,6 +5872,128 @@ fn remove_group() -> Result<()> {
     Ok(())
 }
 
+/// Add and remove `include-group` entries in a dependency group.
+#[test]
+fn add_remove_include_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = ["ruff"]
+        test = ["pytest"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--group").arg("dev").arg("--include-group").arg("test").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // Adding the same include again is a no-op.
+    uv_snapshot!(context.filters(), context.add().arg("--group").arg("dev").arg("--include-group").arg("test").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = [
+            "ruff",
+            { include-group = "test" },
+        ]
+        test = ["pytest"]
+        "#
+        );
+    });
+
+    // Including a group that would form a cycle is rejected, without modifying the project.
+    uv_snapshot!(context.filters(), context.add().arg("--group").arg("test").arg("--include-group").arg("dev").arg("--frozen"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Project `project` has malformed dependency groups
+      Caused by: Detected a cycle in `dependency-groups`: `dev` -> `test` -> `dev`
+    ");
+
+    // Including a group that doesn't exist is rejected.
+    uv_snapshot!(context.filters(), context.add().arg("--group").arg("dev").arg("--include-group").arg("lint").arg("--frozen"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Project `project` has malformed dependency groups
+      Caused by: Failed to find group `lint` included by `dev`
+    ");
+
+    uv_snapshot!(context.filters(), context.remove().arg("--group").arg("dev").arg("--include-group").arg("test").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = [
+            "ruff",
+        ]
+        test = ["pytest"]
+        "#
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.remove().arg("--group").arg("dev").arg("--include-group").arg("test").arg("--frozen"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The group `test` is not included in `dependency-groups.dev`
+    ");
+
+    Ok(())
+}
+
 /// Add to a PEP 732 script.
 #[test]
 fn add_script() -> Result<()> {
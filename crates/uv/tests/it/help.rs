@@ -25,13 +25,21 @@ fn help() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      verify                     Verify the integrity of the project environment
+      prune                      Remove extraneous packages from the project environment
+      prefetch                   Populate the cache from the project's lockfile
+      task                       Run the tasks defined in the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
+      index                      Manage static package indexes
+      mirror                     Mirror package artifacts for offline or air-gapped use
       cache                      Manage uv's cache
+      store                      Manage uv's store of unpacked wheels
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -63,6 +71,11 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -96,24 +109,32 @@ fn help_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      version  Read or update the project's version
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      help     Display documentation for a command
+      run       Run a command or script
+      init      Create a new project
+      add       Add dependencies to the project
+      remove    Remove dependencies from the project
+      version   Read or update the project's version
+      sync      Update the project's environment
+      lock      Update the project's lockfile
+      export    Export the project's lockfile to an alternate format
+      tree      Display the project's dependency tree
+      verify    Verify the integrity of the project environment
+      prune     Remove extraneous packages from the project environment
+      prefetch  Populate the cache from the project's lockfile
+      task      Run the tasks defined in the project
+      tool      Run and install commands provided by Python packages
+      python    Manage Python versions and installations
+      pip       Manage Python packages with a pip-compatible interface
+      venv      Create a virtual environment
+      build     Build Python packages into source distributions and wheels
+      publish   Upload distributions to an index
+      auth      Manage credentials for package indexes
+      index     Manage static package indexes
+      mirror    Mirror package artifacts for offline or air-gapped use
+      cache     Manage uv's cache
+      store     Manage uv's store of unpacked wheels
+      self      Manage the uv executable
+      help      Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -142,6 +163,11 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -174,24 +200,32 @@ fn help_short_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      version  Read or update the project's version
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      help     Display documentation for a command
+      run       Run a command or script
+      init      Create a new project
+      add       Add dependencies to the project
+      remove    Remove dependencies from the project
+      version   Read or update the project's version
+      sync      Update the project's environment
+      lock      Update the project's lockfile
+      export    Export the project's lockfile to an alternate format
+      tree      Display the project's dependency tree
+      verify    Verify the integrity of the project environment
+      prune     Remove extraneous packages from the project environment
+      prefetch  Populate the cache from the project's lockfile
+      task      Run the tasks defined in the project
+      tool      Run and install commands provided by Python packages
+      python    Manage Python versions and installations
+      pip       Manage Python packages with a pip-compatible interface
+      venv      Create a virtual environment
+      build     Build Python packages into source distributions and wheels
+      publish   Upload distributions to an index
+      auth      Manage credentials for package indexes
+      index     Manage static package indexes
+      mirror    Mirror package artifacts for offline or air-gapped use
+      cache     Manage uv's cache
+      store     Manage uv's store of unpacked wheels
+      self      Manage the uv executable
+      help      Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -220,6 +254,11 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -402,6 +441,24 @@ fn help_subcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds.
+              
+              By default, uv waits indefinitely for locks held by other uv processes (e.g., on a
+              virtual environment or a cache entry). If the lock isn't released within the given
+              time, uv exits with an error identifying the process that holds the lock.
+              
+              [env: UV_WAIT_TIMEOUT=]
+
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file.
+              
+              Each request is recorded with its method, URL, status, timing, size, and cache
+              disposition. If the file has a `.har` extension, the trace is written as an HTTP
+              Archive (HAR); otherwise, it's written as JSON Lines.
+              
+              [env: UV_TRACE_HTTP=]
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -669,6 +726,24 @@ fn help_subsubcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds.
+              
+              By default, uv waits indefinitely for locks held by other uv processes (e.g., on a
+              virtual environment or a cache entry). If the lock isn't released within the given
+              time, uv exits with an error identifying the process that holds the lock.
+              
+              [env: UV_WAIT_TIMEOUT=]
+
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file.
+              
+              Each request is recorded with its method, URL, status, timing, size, and cache
+              disposition. If the file has a `.har` extension, the trace is written as an HTTP
+              Archive (HAR); otherwise, it's written as JSON Lines.
+              
+              [env: UV_TRACE_HTTP=]
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -766,6 +841,11 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -847,6 +927,11 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -882,13 +967,21 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        verify
+        prune
+        prefetch
+        task
         tool
         python
         pip
         venv
         build
         publish
+        auth
+        index
+        mirror
         cache
+        store
         self
         generate-shell-completion
     ");
@@ -909,13 +1002,21 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        verify
+        prune
+        prefetch
+        task
         tool
         python
         pip
         venv
         build
         publish
+        auth
+        index
+        mirror
         cache
+        store
         self
         generate-shell-completion
     ");
@@ -965,13 +1066,21 @@ fn help_with_global_option() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      verify                     Verify the integrity of the project environment
+      prune                      Remove extraneous packages from the project environment
+      prefetch                   Populate the cache from the project's lockfile
+      task                       Run the tasks defined in the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
+      index                      Manage static package indexes
+      mirror                     Mirror package artifacts for offline or air-gapped use
       cache                      Manage uv's cache
+      store                      Manage uv's store of unpacked wheels
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1003,6 +1112,11 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -1086,13 +1200,21 @@ fn help_with_no_pager() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      verify                     Verify the integrity of the project environment
+      prune                      Remove extraneous packages from the project environment
+      prefetch                   Populate the cache from the project's lockfile
+      task                       Run the tasks defined in the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
+      index                      Manage static package indexes
+      mirror                     Mirror package artifacts for offline or air-gapped use
       cache                      Manage uv's cache
+      store                      Manage uv's store of unpacked wheels
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1124,6 +1246,11 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --wait-timeout <WAIT_TIMEOUT>
+              The maximum time to wait for a lock held by another uv process, in seconds [env:
+              UV_WAIT_TIMEOUT=]
+          --trace-http <TRACE_HTTP>
+              Record the HTTP requests made by uv to the given file [env: UV_TRACE_HTTP=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
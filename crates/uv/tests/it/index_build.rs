@@ -0,0 +1,119 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Generate a static index from a directory of wheels, and install from it.
+#[test]
+fn index_build() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    for wheel in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context.workspace_root.join("scripts/links").join(wheel),
+            dist.join(wheel),
+        )?;
+    }
+
+    uv_snapshot!(context.filters(), context.index_build().arg("dist"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Indexed 1 package (2 files) at: dist/simple
+    ");
+
+    assert_snapshot!(context.read("dist/simple/index.html"), @r#"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.1">
+        <title>Simple index</title>
+      </head>
+      <body>
+        <a href="ok/">ok</a><br>
+      </body>
+    </html>
+    "#);
+
+    assert_snapshot!(context.read("dist/simple/ok/index.html"), @r#"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.1">
+        <title>Links for ok</title>
+      </head>
+      <body>
+        <h1>Links for ok</h1>
+        <a href="../../ok-1.0.0-py3-none-any.whl#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f" data-requires-python="&gt;=3.8" data-dist-info-metadata="sha256=3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43" data-core-metadata="sha256=3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43">ok-1.0.0-py3-none-any.whl</a><br>
+        <a href="../../ok-2.0.0-py3-none-any.whl#sha256=8163cd4f0477f8e93b856ac6a517fe5fa0f29339291fe2807d5376df685f6697" data-requires-python="&gt;=3.8" data-dist-info-metadata="sha256=cfb7ce491fdc263d74417a9e7542334d6e34291e9ac3e203e1e834c50df831cd" data-core-metadata="sha256=cfb7ce491fdc263d74417a9e7542334d6e34291e9ac3e203e1e834c50df831cd">ok-2.0.0-py3-none-any.whl</a><br>
+      </body>
+    </html>
+    "#);
+
+    // The metadata is extracted alongside each wheel.
+    dist.child("ok-1.0.0-py3-none-any.whl.metadata")
+        .assert(predicates::str::contains("Version: 1.0.0"));
+
+    // The index can be used by uv.
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(dist.join("simple")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Link to the distributions under a base URL.
+#[test]
+fn index_build_base_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+        dist.join("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    dist.child("README.md").touch()?;
+
+    context
+        .index_build()
+        .arg("dist")
+        .arg("--output")
+        .arg("public")
+        .arg("--base-url")
+        .arg("https://files.example.com/packages/")
+        .assert()
+        .success();
+
+    let json: serde_json::Value = serde_json::from_str(&context.read("public/ok/index.json"))?;
+    assert_eq!(
+        json["files"][0]["url"],
+        "https://files.example.com/packages/ok-1.0.0-py3-none-any.whl"
+    );
+    assert_eq!(json["versions"], serde_json::json!(["1.0.0"]));
+
+    let json: serde_json::Value = serde_json::from_str(&context.read("public/index.json"))?;
+    assert_eq!(json["projects"], serde_json::json!([{ "name": "ok" }]));
+
+    Ok(())
+}
@@ -3970,3 +3970,207 @@ fn git_states() {
     ");
     assert!(!context.temp_dir.child("broken-git/.git").is_dir());
 }
+
+/// Create a project from a built-in template.
+#[test]
+fn init_template_builtin() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo-bar").arg("--template").arg("cli"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    ");
+
+    let pyproject = context.read("foo-bar/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        foo-bar = "foo_bar.__main__:main"
+
+        [build-system]
+        requires = ["uv_build>=[CURRENT_VERSION],<[NEXT_BREAKING]"]
+        build-backend = "uv_build"
+        "#
+        );
+    });
+
+    let readme = context.read("foo-bar/README.md");
+    assert_snapshot!(readme, @r"
+    # foo-bar
+
+    Add your description here
+    ");
+
+    context
+        .temp_dir
+        .child("foo-bar/src/foo_bar/__main__.py")
+        .assert(predicate::path::is_file());
+
+    uv_snapshot!(context.filters(), context.run().current_dir(context.temp_dir.join("foo-bar")).arg("foo-bar").arg("Ferris"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, Ferris!
+
+    ----- stderr -----
+    warning: `VIRTUAL_ENV=[VENV]/` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo-bar==0.1.0 (from file://[TEMP_DIR]/foo-bar)
+    ");
+
+    Ok(())
+}
+
+/// Create a project from a template in a local directory, substituting variables in file names
+/// and contents.
+#[test]
+fn init_template_directory() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let template = context.temp_dir.child("template");
+    template.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "{{ project_name }}"
+        version = "0.1.0"
+        description = "{{ description }}"
+        requires-python = "{{ requires_python }}"
+        dependencies = []
+
+        {{ build_system }}
+    "#})?;
+    template
+        .child("src/{{ module_name }}/__init__.py")
+        .write_str(indoc! {r#"
+        PYTHON_VERSION = "{{ python_version }}"
+    "#})?;
+    template
+        .child(".github/workflows/ci.yml")
+        .write_str(indoc! {r"
+        name: {{ project_name }}
+        jobs:
+          test:
+            steps:
+              - run: uv run --python ${{ matrix.python-version }} pytest
+    "})?;
+    template
+        .child(".git/HEAD")
+        .write_str("ref: refs/heads/main")?;
+
+    uv_snapshot!(context.filters(), context.init()
+        .arg("foo-bar")
+        .arg("--template")
+        .arg("template")
+        .arg("--build-backend")
+        .arg("hatch")
+        .arg("--description")
+        .arg("A templated project")
+        .arg("--vcs")
+        .arg("none"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    ");
+
+    let pyproject = context.read("foo-bar/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        description = "A templated project"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+        );
+    });
+
+    let init = context.read("foo-bar/src/foo_bar/__init__.py");
+    assert_snapshot!(init, @r#"PYTHON_VERSION = "3.12""#);
+
+    // Unknown placeholders are left untouched.
+    let workflow = context.read("foo-bar/.github/workflows/ci.yml");
+    assert_snapshot!(workflow, @r"
+    name: foo-bar
+    jobs:
+      test:
+        steps:
+          - run: uv run --python ${{ matrix.python-version }} pytest
+    ");
+
+    // The template's `.git` directory is not copied, and no `README.md` is created.
+    context
+        .temp_dir
+        .child("foo-bar/.git")
+        .assert(predicate::path::missing());
+    context
+        .temp_dir
+        .child("foo-bar/README.md")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Reject templates that don't exist or don't contain a `pyproject.toml`.
+#[test]
+fn init_template_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--template").arg("django"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Template `django` is not a built-in template, a Git URL, or a directory (available templates: `cli`, `lib`, `fastapi`, `maturin`)
+    ");
+
+    let template = context.temp_dir.child("template");
+    template
+        .child("README.md")
+        .write_str("# {{ project_name }}")?;
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--template").arg("template"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Template does not contain a `pyproject.toml` file
+    ");
+
+    context
+        .temp_dir
+        .child("foo/pyproject.toml")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
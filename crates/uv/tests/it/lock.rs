@@ -12,6 +12,8 @@ use crate::common::{
 };
 use uv_fs::Simplified;
 use uv_static::EnvVars;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn lock_wheel_registry() -> Result<()> {
@@ -3031,6 +3033,115 @@ fn lock_upgrade_log_multi_version() -> Result<()> {
     Ok(())
 }
 
+/// Show links to the project metadata of changed packages on `lock --show-links`, as served by the
+/// index alongside each wheel (PEP 658).
+#[tokio::test]
+async fn lock_show_links() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let server = MockServer::start().await;
+
+    let hash = "0000000000000000000000000000000000000000000000000000000000000000";
+    for (name, versions) in [("foo", &["1.0.0", "2.0.0"][..]), ("bar", &["1.0.0"][..])] {
+        let links = versions
+            .iter()
+            .map(|version| {
+                format!(
+                    r#"<a href="/files/{name}-{version}-py3-none-any.whl#sha256={hash}" data-core-metadata="true">{name}-{version}-py3-none-any.whl</a><br/>"#
+                )
+            })
+            .collect::<String>();
+        Mock::given(method("GET"))
+            .and(path(format!("/simple/{name}/")))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                format!("<!DOCTYPE html><html><body>{links}</body></html>"),
+                "text/html",
+            ))
+            .mount(&server)
+            .await;
+    }
+
+    let metadata = [
+        (
+            "foo-1.0.0",
+            "Name: foo\nVersion: 1.0.0\nProject-URL: Homepage, https://example.com/foo\n",
+        ),
+        (
+            "foo-2.0.0",
+            "Name: foo\nVersion: 2.0.0\nProject-URL: Homepage, https://example.com/foo\nProject-URL: Changelog, https://example.com/foo/changelog\n",
+        ),
+        (
+            "bar-1.0.0",
+            "Name: bar\nVersion: 1.0.0\nHome-page: https://example.com/bar\n",
+        ),
+    ];
+    for (wheel, metadata) in metadata {
+        Mock::given(method("GET"))
+            .and(path(format!("/files/{wheel}-py3-none-any.whl.metadata")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(format!("Metadata-Version: 2.1\n{metadata}")),
+            )
+            .mount(&server)
+            .await;
+    }
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo<2", "bar"]
+
+        [[tool.uv.index]]
+        url = "{}/simple"
+        default = true
+        "#,
+        server.uri()
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Upgrade `foo` and drop `bar`; the links are reported for the new version of `foo`, and for
+    // the previous version of `bar`.
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+
+        [[tool.uv.index]]
+        url = "{}/simple"
+        default = true
+        "#,
+        server.uri()
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().env_remove(EnvVars::UV_EXCLUDE_NEWER).arg("--show-links"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Removed bar v1.0.0
+        Homepage: https://example.com/bar
+    Updated foo v1.0.0 -> v2.0.0
+        Homepage: https://example.com/foo
+        Changelog: https://example.com/foo/changelog
+    ");
+
+    Ok(())
+}
+
 /// Respect the locked version in an existing lockfile.
 #[test]
 fn lock_preference() -> Result<()> {
@@ -18759,6 +18870,105 @@ fn lock_dependency_metadata_git() -> Result<()> {
     Ok(())
 }
 
+/// Apply a patch to a source distribution before building it.
+#[test]
+fn lock_patches() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/extras-0.0.1.tar.gz"),
+        context.temp_dir.join("extras-0.0.1.tar.gz"),
+    )?;
+
+    // Relax the requirement on `iniconfig`.
+    let patch = context.temp_dir.child("patches").child("extras.patch");
+    patch.write_str(indoc! {r#"
+        --- a/setup.py
+        +++ b/setup.py
+        @@ -4,7 +4,7 @@ setup(
+             name="extras",
+             version="0.0.1",
+             install_requires=[
+        -        "iniconfig",
+        +        "iniconfig<2",
+             ],
+             extras_require={
+                 "dev": ["anyio"],
+    "#})?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["extras"]
+
+        [tool.uv.sources]
+        extras = { path = "extras-0.0.1.tar.gz" }
+
+        [[tool.uv.patches]]
+        name = "extras"
+        version = "0.0.1"
+        path = "patches/extras.patch"
+        hash = "sha256:aa2cbbcd6a64c19cb170d71f36633f31931bb6266a8aee74e6779829552ce703"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── extras v0.0.1
+        └── iniconfig v1.1.1
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // The patch is recorded in the lockfile.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(indoc! {r#"
+        [[manifest.patches]]
+        name = "extras"
+        version = "0.0.1"
+        path = "patches/extras.patch"
+        hash = "sha256:aa2cbbcd6a64c19cb170d71f36633f31931bb6266a8aee74e6779829552ce703"
+    "#}));
+
+    // If the patch file doesn't match the declared hash, the build fails.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["extras"]
+
+        [tool.uv.sources]
+        extras = { path = "extras-0.0.1.tar.gz" }
+
+        [[tool.uv.patches]]
+        name = "extras"
+        version = "0.0.1"
+        path = "patches/extras.patch"
+        hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000"
+        "#,
+    )?;
+
+    context
+        .lock()
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Hash mismatch for patch file"));
+
+    Ok(())
+}
+
 #[test]
 fn lock_strip_fragment() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -20975,6 +21185,98 @@ fn lock_dry_run() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn lock_snapshot_rollback() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Save a snapshot of the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--snapshot").arg("before"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Saved lockfile snapshot `before` to: .uv/snapshots/before.lock
+    "###);
+
+    // Saving a snapshot with the same name should fail.
+    uv_snapshot!(context.filters(), context.lock().arg("--snapshot").arg("before"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: A snapshot named `before` already exists at: `.uv/snapshots/before.lock`
+    "###);
+
+    // Upgrade the dependency.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} UTC", "[TIMESTAMP]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.lock().arg("--list-snapshots"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    before  [TIMESTAMP]  2 packages
+
+    ----- stderr -----
+    "###);
+
+    // Roll back to the snapshot.
+    uv_snapshot!(context.filters(), context.lock().arg("--rollback").arg("before"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated iniconfig v2.0.0 -> v1.1.1
+    Restored lockfile from snapshot `before`
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"version = "1.1.1""#));
+
+    // Rolling back to a missing snapshot should fail.
+    uv_snapshot!(context.filters(), context.lock().arg("--rollback").arg("missing"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No snapshot named `missing` found (run `uv lock --list-snapshots` to see the available snapshots)
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_dry_run_noop() -> Result<()> {
     let context = TestContext::new("3.12");
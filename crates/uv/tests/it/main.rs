@@ -3,6 +3,8 @@
 
 pub(crate) mod common;
 
+mod auth;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -14,9 +16,24 @@ mod build_backend;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_export;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_move;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_refresh;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_stats;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -28,6 +45,9 @@ mod export;
 
 mod help;
 
+#[cfg(feature = "python")]
+mod index_build;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "git"))]
 mod init;
 
@@ -39,8 +59,14 @@ mod lock_conflict;
 
 mod lock_scenarios;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod mirror_sync;
+
 mod network;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_audit;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod pip_check;
 
@@ -67,6 +93,15 @@ mod pip_sync;
 mod pip_tree;
 mod pip_uninstall;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod pip_wheel;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod prefetch;
+
+#[cfg(feature = "python")]
+mod prune;
+
 #[cfg(feature = "pypi")]
 mod publish;
 
@@ -96,9 +131,15 @@ mod self_update;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod show_settings;
 
+#[cfg(feature = "python")]
+mod store_gc;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod task;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
@@ -123,6 +164,9 @@ mod tree;
 #[cfg(feature = "python")]
 mod venv;
 
+#[cfg(feature = "python")]
+mod verify;
+
 mod version;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// List the files in a directory, sorted by name.
+fn list_dir(dir: &std::path::Path) -> Result<String> {
+    let mut files = fs_err::read_dir(dir)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?;
+    files.sort();
+    Ok(files.join("\n"))
+}
+
+/// Mirror the artifacts for a list of packages, then sync again without downloading anything.
+#[test]
+fn mirror_sync_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+\.\d[KMG]?i?B\)", "([SIZE])")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.mirror_sync().arg("iniconfig==2.0.0").arg("-o").arg("mirror"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloaded 2 artifacts ([SIZE]) to: mirror
+    ");
+
+    assert_snapshot!(list_dir(&context.temp_dir.join("mirror"))?, @r"
+    iniconfig-2.0.0-py3-none-any.whl
+    iniconfig-2.0.0.tar.gz
+    ");
+
+    // Artifacts that are already present are skipped.
+    uv_snapshot!(filters, context.mirror_sync().arg("iniconfig==2.0.0").arg("-o").arg("mirror"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Skipped 2 artifacts already present
+    ");
+
+    Ok(())
+}
+
+/// Mirror the artifacts in a lockfile, respecting `--no-build`, and index the mirror.
+#[test]
+fn mirror_sync_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\(\d+\.\d[KMG]?i?B\)", "([SIZE])")])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+    context.lock().assert().success();
+
+    uv_snapshot!(filters, context
+        .mirror_sync()
+        .arg("--no-build")
+        .arg("--build-index")
+        .arg("-o")
+        .arg("mirror"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloaded 1 artifact ([SIZE]) to: mirror
+    Indexed 1 package (1 file) at: mirror/simple
+    ");
+
+    // The mirror can be used as an index.
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(context.temp_dir.join("mirror/simple")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
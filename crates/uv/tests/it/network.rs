@@ -1,7 +1,10 @@
 use std::{env, io};
 
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::{ChildPath, FileWriteStr, PathChild};
 use http::StatusCode;
+use indoc::formatdoc;
 use serde_json::json;
 use uv_static::EnvVars;
 use wiremock::matchers::method;
@@ -300,3 +303,117 @@ async fn python_install_io_error() {
       Caused by: connection closed before message completed
     ");
 }
+
+/// Fall back to a mirror when the primary index returns HTTP status 500, a retryable error, and
+/// record the mirror in the lockfile.
+#[tokio::test]
+async fn fallback_index_http_500() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let (_server_drop_guard, mock_server_uri) = http_error_server().await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [[tool.uv.index]]
+        name = "primary"
+        url = "{mock_server_uri}"
+        default = true
+
+        [[tool.uv.index]]
+        name = "mirror"
+        url = "https://pypi.org/simple"
+        fallback-of = "primary"
+    "#})?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(mock_server_uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context
+        .lock()
+        .env_remove(EnvVars::UV_HTTP_RETRIES)
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Index `[SERVER]/` is unavailable (Request failed after 3 retries); falling back to `https://pypi.org/simple`
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"source = { registry = "https://pypi.org/simple" }"#));
+
+    Ok(())
+}
+
+/// Record each attempt at a request in the HTTP trace, as JSON Lines or as an HTTP Archive.
+#[tokio::test]
+async fn trace_http_http_500() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let (_server_drop_guard, mock_server_uri) = http_error_server().await;
+
+    context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--trace-http")
+        .arg("trace.jsonl")
+        .env(EnvVars::UV_HTTP_RETRIES, "1")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true")
+        .assert()
+        .failure();
+
+    let entries = context
+        .read("trace.jsonl")
+        .lines()
+        .map(|line| {
+            let entry: serde_json::Value = serde_json::from_str(line).unwrap();
+            json!({
+                "method": entry["method"],
+                "url": entry["url"],
+                "status": entry["status"],
+                "cache": entry["cache"],
+            })
+        })
+        .collect::<Vec<_>>();
+    let entry = json!({
+        "method": "GET",
+        "url": format!("{mock_server_uri}/tqdm/"),
+        "status": 500,
+        "cache": "miss",
+    });
+    assert_eq!(entries, vec![entry.clone(), entry]);
+
+    context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--trace-http")
+        .arg("trace.har")
+        .env(EnvVars::UV_HTTP_RETRIES, "0")
+        .assert()
+        .failure();
+
+    let har: serde_json::Value = serde_json::from_str(&context.read("trace.har"))?;
+    let entries = har["log"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0]["request"]["url"],
+        format!("{mock_server_uri}/tqdm/")
+    );
+    assert_eq!(entries[0]["response"]["status"], 500);
+
+    Ok(())
+}
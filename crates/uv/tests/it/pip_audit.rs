@@ -0,0 +1,125 @@
+use anyhow::Result;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::fixture::PathChild;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Audit the packages pinned in a `requirements.txt` against a mocked OSV API.
+#[tokio::test]
+async fn audit_requirements_txt() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/query"))
+        .and(body_partial_json(json!({
+            "package": { "name": "requests", "ecosystem": "PyPI" },
+            "version": "2.31.0",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "vulns": [
+                {
+                    "id": "GHSA-9wx4-h78v-vm56",
+                    "summary": "Requests `Session` object does not verify requests after making first request with verify=False",
+                    "aliases": ["CVE-2024-35195"],
+                    "affected": [
+                        {
+                            "package": { "name": "requests", "ecosystem": "PyPI" },
+                            "ranges": [
+                                {
+                                    "type": "ECOSYSTEM",
+                                    "events": [{ "introduced": "0" }, { "fixed": "2.32.0" }],
+                                }
+                            ],
+                        }
+                    ],
+                    "database_specific": { "severity": "MODERATE" },
+                }
+            ]
+        })))
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/query"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&server)
+        .await;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0\nidna==3.6\n")?;
+
+    uv_snapshot!(context
+        .pip_audit()
+        .env(EnvVars::UV_OSV_URL, server.uri())
+        .arg("--preview")
+        .arg("--fix")
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    Name     Version ID                  Fix Versions Severity
+    -------- ------- ------------------- ------------ --------
+    requests 2.31.0  GHSA-9wx4-h78v-vm56 2.32.0       moderate
+
+    To upgrade the vulnerable packages, re-run `uv pip compile` with:
+        --upgrade-package 'requests>=2.32.0'
+
+    ----- stderr -----
+    Audited 2 packages in [TIME]
+    Found 1 known vulnerability in 1 package
+    "
+    );
+
+    // The vulnerability can be ignored by its alias.
+    uv_snapshot!(context
+        .pip_audit()
+        .env(EnvVars::UV_OSV_URL, server.uri())
+        .arg("--preview")
+        .arg("--ignore-vuln")
+        .arg("CVE-2024-35195")
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 2 packages in [TIME]
+    No known vulnerabilities found
+    "
+    );
+
+    Ok(())
+}
+
+/// Requirements must be pinned to an exact version to be audited.
+#[test]
+fn audit_unpinned_requirement() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests>=2.31.0\n")?;
+
+    uv_snapshot!(context
+        .pip_audit()
+        .arg("--preview")
+        .arg("-r")
+        .arg("requirements.txt"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requirement `requests>=2.31.0` in `requirements.txt` must be pinned to an exact version to be audited, e.g., with `uv pip compile`
+    "
+    );
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -187,3 +188,52 @@ fn check_multiple_incompatible_packages() -> Result<()> {
 
     Ok(())
 }
+
+/// Explain an incompatibility, then restore the consistency of the environment with `--fix`.
+#[test]
+fn check_explain_fix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("requests==2.31.0")
+        .assert()
+        .success();
+    context.pip_install().arg("idna==2.4").assert().success();
+
+    uv_snapshot!(context.pip_check().arg("--explain"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    Found 1 incompatibility
+    The package `requests` requires `idna>=2.5,<4`, but `2.4` is installed
+      `requests` is not required by any other installed package
+    "
+    );
+
+    uv_snapshot!(context.pip_check().arg("--fix"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    Found 1 incompatibility
+    The package `requests` requires `idna>=2.5,<4`, but `2.4` is installed
+    Restoring consistency with: `idna>=2.5,<4`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - idna==2.4
+     + idna==3.6
+    Checked 5 packages in [TIME]
+    All installed packages are compatible
+    "
+    );
+
+    Ok(())
+}
@@ -12747,6 +12747,83 @@ fn emit_index_annotation_multiple_indexes() -> Result<()> {
     Ok(())
 }
 
+/// Include the line that declared each requirement and constraint with `--emit-source-lines`.
+#[test]
+fn emit_source_lines() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("# Direct dependencies\nanyio==3.7.0\nidna")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.7")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--constraint")
+        .arg("constraints.txt")
+        .arg("--emit-source-lines"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --emit-source-lines
+    anyio==3.7.0
+        # via -r requirements.in:2
+    idna==3.6
+        # via
+        #   -c constraints.txt:1
+        #   -r requirements.in:3
+        #   anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Include the line that declared each requirement in a `pyproject.toml` file with
+/// `--emit-source-lines` and `--annotation-style=line`.
+#[test]
+fn emit_source_lines_pyproject_toml() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+dependencies = [
+    "anyio==3.7.0",
+]
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--emit-source-lines")
+        .arg("--annotation-style=line"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --emit-source-lines --annotation-style=line
+    anyio==3.7.0              # via project (pyproject.toml:5)
+    idna==3.6                 # via anyio
+    sniffio==1.3.1            # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Test error message when direct dependency is an empty set.
 #[test]
 fn no_version_for_direct_dependency() -> Result<()> {
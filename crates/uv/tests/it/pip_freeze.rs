@@ -35,6 +35,60 @@ fn freeze_many() -> Result<()> {
     Ok(())
 }
 
+/// Include the recorded hashes of the installed distributions, and exclude packages by name.
+#[test]
+fn freeze_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+    )?;
+
+    // Install with hash-checking, such that the hashes are recorded.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    // Install a package without hashes.
+    context.pip_install().arg("tomli==2.0.1").assert().success();
+
+    uv_snapshot!(context.pip_freeze()
+        .arg("--hashes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    tomli==2.0.1
+
+    ----- stderr -----
+    warning: No hashes were recorded for the following package, which will be rejected when installing with `--require-hashes`: `tomli`
+    "
+    );
+
+    // Exclude the package without hashes.
+    uv_snapshot!(context.pip_freeze()
+        .arg("--hashes")
+        .arg("--exclude")
+        .arg("tomli"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 /// List a package with multiple installed distributions in a virtual environment.
 #[test]
 #[cfg(unix)]
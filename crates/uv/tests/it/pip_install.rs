@@ -1,9 +1,12 @@
+use std::collections::BTreeMap;
 use std::io::Cursor;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
+use filetime::FileTime;
 use flate2::write::GzEncoder;
 use fs_err as fs;
 use fs_err::File;
@@ -4023,6 +4026,124 @@ fn config_settings_registry() {
     );
 }
 
+/// Wheels built from source should be scoped to the build environment, such that changing the
+/// compiler flags triggers a rebuild.
+#[test]
+fn build_environment_registry() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::CFLAGS, "-O2"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    context.pip_uninstall().arg("iniconfig").assert().success();
+
+    // Re-install the package, with the same flags. We should read from the cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::CFLAGS, "-O2"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    context.pip_uninstall().arg("iniconfig").assert().success();
+
+    // Re-install the package, with different flags. We should build it from source.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .env(EnvVars::CFLAGS, "-O3"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+}
+
+/// Isolated build environments should be reused across builds with the same resolved build
+/// requirements, but not across builds with different build requirements.
+#[test]
+fn build_environment_reuse() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    for (name, requires) in [
+        ("foo", r#"["hatchling"]"#),
+        ("bar", r#"["hatchling", "iniconfig"]"#),
+        ("baz", r#"["hatchling"]"#),
+    ] {
+        let project = context.temp_dir.child(name);
+        project
+            .child("pyproject.toml")
+            .write_str(&indoc::formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+
+            [build-system]
+            requires = {requires}
+            build-backend = "hatchling.build"
+        "#})?;
+        project
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    // Build the packages one at a time, such that each build returns its environment before the
+    // next build starts.
+    let output = context
+        .pip_install()
+        .arg("./foo")
+        .arg("./bar")
+        .arg("./baz")
+        .arg("--verbose")
+        .env(EnvVars::UV_CONCURRENT_BUILDS, "1")
+        .output()?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(output.status.success(), "{stderr}");
+
+    // `foo` and `baz` share an environment, while `bar` requires its own.
+    assert_eq!(
+        stderr.matches("Reusing build environment").count(),
+        1,
+        "{stderr}"
+    );
+
+    context.assert_command("import foo, bar, baz").success();
+
+    Ok(())
+}
+
 #[test]
 fn config_settings_path() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5475,6 +5596,60 @@ fn dry_run_install_then_upgrade() -> std::result::Result<(), Box<dyn std::error:
     Ok(())
 }
 
+/// Report the changes that a dry run would make to the environment in JSON.
+#[test]
+fn dry_run_output_format_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==1.1.1")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--dry-run")
+        .arg("--output-format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "link_mode": "copy",
+      "download_size": 5892,
+      "changes": [
+        {
+          "name": "iniconfig",
+          "action": "upgrade",
+          "version": "2.0.0",
+          "installed_version": "1.1.1",
+          "artifact": {
+            "kind": "wheel",
+            "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+            "hashes": [
+              "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+            ],
+            "size": 5892,
+            "cached": false
+          }
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would uninstall 1 package
+    Would install 1 package
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "#
+    );
+
+    Ok(())
+}
+
 /// Raise an error when a direct URL's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_direct_url() -> Result<()> {
@@ -11799,6 +11974,77 @@ fn pep_751_dependency() -> Result<()> {
     Ok(())
 }
 
+/// Install the locked packages from a `uv.lock`, outside of its project.
+#[test]
+fn install_uv_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        test = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("uv.lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    // Dependency groups are only installed when requested.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("uv.lock")
+        .arg("--group")
+        .arg("test"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // A `uv.lock` can't be combined with other requirements.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("uv.lock")
+        .arg("flask"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Cannot specify additional requirements alongside a `uv.lock` file
+    "
+    );
+
+    Ok(())
+}
+
 /// Test that we show an error instead of panicking for conflicting arguments in different levels,
 /// which are not caught by clap.
 #[test]
@@ -12093,3 +12339,514 @@ fn config_settings_package() -> Result<()> {
 
     Ok(())
 }
+
+/// Run `pre-install` and `post-install` hooks around each installed distribution, and abort the
+/// installation if a hook rejects a package.
+#[test]
+#[cfg(unix)]
+fn install_hooks() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        install-hooks = { pre-install = ["sh", "-c", "test \"$UV_HOOK_PACKAGE_NAME\" != validation"], post-install = ["sh", "-c", "echo \"$UV_HOOK_STAGE $UV_HOOK_PACKAGE_NAME $UV_HOOK_PACKAGE_VERSION\" >> hooks.log"] }
+    "#})?;
+
+    // The hooks should run for the accepted package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(context.workspace_root.join("scripts/links/ok-1.0.0-py3-none-any.whl")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+    ");
+
+    let log = fs_err::read_to_string(context.temp_dir.child("hooks.log"))?;
+    assert_eq!(log, "post-install ok 1.0.0\n");
+
+    // The `pre-install` hook should reject the package, leaving the environment untouched.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(context.workspace_root.join("scripts/links/validation-1.0.0-py3-none-any.whl")), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    error: Failed to install: validation-1.0.0-py3-none-any.whl (validation==1.0.0 (from file://[WORKSPACE]/scripts/links/validation-1.0.0-py3-none-any.whl))
+      Caused by: The pre-install hook `sh -c test "$UV_HOOK_PACKAGE_NAME" != validation` rejected `validation` (exit status: 1)
+    "#);
+
+    assert!(!context.site_packages().join("validation").exists());
+
+    Ok(())
+}
+
+/// Install with `SOURCE_DATE_EPOCH` set, such that repeated installations produce identical
+/// `site-packages` directories.
+#[test]
+fn install_source_date_epoch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+
+    // Collect the contents and modification times of every file in `site-packages`.
+    let snapshot = || -> Result<BTreeMap<PathBuf, (Vec<u8>, FileTime)>> {
+        let site_packages = context.site_packages();
+        let mut files = BTreeMap::new();
+        for entry in walkdir::WalkDir::new(&site_packages) {
+            let entry = entry?;
+            let metadata = entry.path().symlink_metadata()?;
+            let contents = if metadata.is_file() {
+                fs::read(entry.path())?
+            } else {
+                Vec::new()
+            };
+            files.insert(
+                entry.path().strip_prefix(&site_packages)?.to_path_buf(),
+                (contents, FileTime::from_last_modification_time(&metadata)),
+            );
+        }
+        Ok(files)
+    };
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(&wheel)
+        .arg("--compile-bytecode")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "315532800"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+    ");
+
+    let first = snapshot()?;
+    assert!(
+        first
+            .values()
+            .all(|(_, mtime)| *mtime == FileTime::from_unix_time(315_532_800, 0))
+    );
+    assert!(first.contains_key(Path::new("ok-1.0.0.dist-info/RECORD")));
+    assert!(!first.contains_key(Path::new("ok-1.0.0.dist-info/uv_cache.json")));
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(&wheel)
+        .arg("--compile-bytecode")
+        .arg("--reinstall")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "315532800"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     ~ ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+    ");
+
+    let second = snapshot()?;
+    assert_eq!(first, second);
+
+    // An invalid epoch should be rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(&wheel)
+        .arg("--reinstall")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "yesterday"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Invalid value for `SOURCE_DATE_EPOCH`: `yesterday` (expected a non-negative integer number of seconds)
+    ");
+
+    Ok(())
+}
+
+/// Fetch wheels built from source distributions from a remote cache, and upload them when enabled.
+#[tokio::test]
+async fn install_remote_cache() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Build a source distribution for a project that uses the uv build backend.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project.child("src/foo/__init__.py").touch()?;
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    context
+        .build_backend()
+        .arg("build-sdist")
+        .arg(dist.path())
+        .current_dir(project.path())
+        .assert()
+        .success();
+    let sdist = fs::read(dist.child("foo-1.0.0.tar.gz"))?;
+
+    // Serve the source distribution, along with an empty remote cache that accepts uploads.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files/foo-1.0.0.tar.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(sdist.clone()))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(201))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    // The wheel should be built locally, then uploaded to the remote cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(format!("foo @ {}/files/foo-1.0.0.tar.gz", server.uri()))
+        .env(EnvVars::UV_REMOTE_CACHE_URL, format!("{}/cache", server.uri()))
+        .env(EnvVars::UV_REMOTE_CACHE_UPLOAD, "1"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from http://[LOCALHOST]/files/foo-1.0.0.tar.gz)
+    ");
+
+    // The wheel is uploaded prior to its manifest.
+    let uploads = server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| request.method == wiremock::http::Method::PUT)
+        .collect::<Vec<_>>();
+    assert_eq!(uploads.len(), 2);
+    let wheel = &uploads[0];
+    let manifest = &uploads[1];
+    assert!(wheel.url.path().starts_with("/cache/v1/"));
+    assert!(wheel.url.path().ends_with("/foo-1.0.0-py3-none-any.whl"));
+    assert!(manifest.url.path().ends_with(".json"));
+    assert_eq!(
+        manifest.url.path().trim_end_matches(".json"),
+        wheel
+            .url
+            .path()
+            .trim_end_matches("/foo-1.0.0-py3-none-any.whl")
+    );
+
+    // Serve the uploaded wheel from the remote cache.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/files/foo-1.0.0.tar.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(sdist))
+        .mount(&server)
+        .await;
+    for upload in &uploads {
+        Mock::given(method("GET"))
+            .and(path(upload.url.path()))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(upload.body.clone()))
+            .mount(&server)
+            .await;
+    }
+
+    context.clean().assert().success();
+
+    // The wheel should be fetched from the remote cache, rather than built.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(format!("foo @ {}/files/foo-1.0.0.tar.gz", server.uri()))
+        .arg("--reinstall")
+        .env(EnvVars::UV_REMOTE_CACHE_URL, format!("{}/cache", server.uri())), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ foo==1.0.0 (from http://[LOCALHOST]/files/foo-1.0.0.tar.gz)
+    ");
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(
+        requests
+            .iter()
+            .any(|request| request.url.path() == wheel.url.path())
+    );
+    assert!(
+        requests
+            .iter()
+            .all(|request| request.method != wiremock::http::Method::PUT)
+    );
+
+    context.assert_command("import foo").success();
+
+    Ok(())
+}
+
+/// Time out when waiting on an environment that's locked by another process, reporting the owner
+/// of the lock.
+#[test]
+#[cfg(unix)]
+fn install_wait_timeout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Hold the environment lock, as if another uv process were installing into it.
+    let lock = uv_fs::LockedFile::acquire_blocking(context.venv.join(".lock"), "test")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"held by process \d+ \(`[^`]*`\)",
+            "held by process [PID] (`[COMMAND]`)",
+        )])
+        .collect();
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("iniconfig")
+        .arg("--wait-timeout")
+        .arg("0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Timed out after 0s waiting to acquire lock for `.venv` at `.venv/.lock`, which is held by process [PID] (`[COMMAND]`)
+    ");
+
+    // Once the lock is released, the install should proceed.
+    drop(lock);
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--wait-timeout")
+        .arg("0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Write an installation report describing each resolved distribution.
+#[test]
+fn install_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--report")
+        .arg("report.json"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    let summarize = |report: &str| -> Result<String> {
+        let report: serde_json::Value = serde_json::from_str(report)?;
+        Ok(report["install"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| {
+                format!(
+                    "{}=={} source={} requested={} is_direct={} url={}",
+                    item["metadata"]["name"].as_str().unwrap(),
+                    item["metadata"]["version"].as_str().unwrap(),
+                    item["source"].as_str().unwrap(),
+                    item["requested"],
+                    item["is_direct"],
+                    item["download_info"]["url"]
+                        .as_str()
+                        .and_then(|url| url.rsplit('/').next())
+                        .unwrap_or("none"),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    };
+
+    let report = fs::read_to_string(context.temp_dir.child("report.json"))?;
+    insta::assert_snapshot!(summarize(&report)?, @r"
+    anyio==3.7.0 source=downloaded requested=true is_direct=false url=anyio-3.7.0-py3-none-any.whl
+    idna==3.6 source=downloaded requested=false is_direct=false url=idna-3.6-py3-none-any.whl
+    sniffio==1.3.1 source=downloaded requested=false is_direct=false url=sniffio-1.3.1-py3-none-any.whl
+    ");
+
+    // Reinstall a single package from the cache, writing the report to stdout.
+    let output = context
+        .pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--reinstall-package")
+        .arg("anyio")
+        .arg("--report")
+        .arg("-")
+        .output()?;
+    assert!(output.status.success());
+    insta::assert_snapshot!(summarize(&String::from_utf8(output.stdout)?)?, @r"
+    anyio==3.7.0 source=cached requested=true is_direct=false url=anyio-3.7.0-py3-none-any.whl
+    idna==3.6 source=already_installed requested=false is_direct=false url=none
+    sniffio==1.3.1 source=already_installed requested=false is_direct=false url=none
+    ");
+
+    Ok(())
+}
+
+/// Refuse to replace or remove packages that are managed by Conda, unless `--force` is provided.
+#[test]
+fn install_conda_managed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==1.1.1")
+        .assert()
+        .success();
+
+    // Record the package in `conda-meta`, as if it were installed by Conda.
+    let site_packages = context
+        .site_packages()
+        .strip_prefix(&context.venv)?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let conda_meta = context.venv.child("conda-meta");
+    conda_meta.create_dir_all()?;
+    conda_meta
+        .child("iniconfig-1.1.1-pyhd8ed1ab_0.json")
+        .write_str(&format!(
+            r#"{{"name": "iniconfig", "version": "1.1.1", "files": ["{site_packages}/iniconfig-1.1.1.dist-info/METADATA"]}}"#
+        ))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Refusing to modify packages that are managed by Conda: `iniconfig`
+
+    hint: use `--force` to modify them anyway, or update them with `conda` instead
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to modify packages that are managed by Conda: `iniconfig`
+
+    hint: use `--force` to modify them anyway, or update them with `conda` instead
+    "
+    );
+
+    // Packages that aren't managed by Conda can be installed alongside.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--no-deps"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    "
+    );
+
+    // Only the packages installed by uv are listed with `--only-uv-managed`.
+    fs_err::write(
+        context
+            .site_packages()
+            .join("iniconfig-1.1.1.dist-info")
+            .join("INSTALLER"),
+        "conda",
+    )?;
+    uv_snapshot!(context.filters(), context.pip_list()
+        .arg("--only-uv-managed"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version
+    ------- -------
+    anyio   4.3.0
+
+    ----- stderr -----
+    "
+    );
+
+    // With `--force`, the package is replaced.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--force"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
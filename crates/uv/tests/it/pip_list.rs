@@ -167,7 +167,7 @@ fn list_outdated_json() -> Result<()> {
     success: true
     exit_code: 0
     ----- stdout -----
-    [{"name":"anyio","version":"3.0.0","latest_version":"4.3.0","latest_filetype":"wheel"}]
+    [{"name":"anyio","version":"3.0.0","latest_version":"4.3.0","latest_filetype":"wheel","latest_satisfiable_version":"4.3.0"}]
 
     ----- stderr -----
     "###
@@ -176,6 +176,69 @@ fn list_outdated_json() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+fn list_outdated_constraints() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.0.0")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio<4")?;
+
+    // The latest version that satisfies the constraints is shown alongside the latest version.
+    uv_snapshot!(context.pip_list()
+        .arg("--outdated")
+        .arg("--constraints")
+        .arg("constraints.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Latest Type  Latest satisfiable
+    ------- ------- ------ ----- ------------------
+    anyio   3.0.0   4.3.0  wheel 3.7.1
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.pip_list()
+        .arg("--outdated")
+        .arg("--constraints")
+        .arg("constraints.txt")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"anyio","version":"3.0.0","latest_version":"4.3.0","latest_filetype":"wheel","latest_satisfiable_version":"3.7.1"}]
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn list_outdated_freeze() {
     let context = TestContext::new("3.12");
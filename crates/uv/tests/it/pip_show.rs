@@ -534,3 +534,112 @@ fn show_files() {
     ----- stderr -----
     "#);
 }
+
+#[test]
+#[cfg(feature = "pypi")]
+fn show_entry_points_reverse() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("flask==3.0.2")
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("flask")
+        .arg("--entry-points"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: flask
+    Version: 3.0.2
+    Location: [SITE_PACKAGES]/
+    Requires: blinker, click, itsdangerous, jinja2, werkzeug
+    Required-by:
+    Entry-points:
+      [console_scripts]
+      flask = flask.cli:main
+
+    ----- stderr -----
+    "
+    );
+
+    // `markupsafe` is required by `jinja2` and `werkzeug`, and through them by `flask`.
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("markupsafe")
+        .arg("--reverse"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: markupsafe
+    Version: 2.1.5
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: jinja2, werkzeug
+    Reverse-dependencies: flask, jinja2, werkzeug
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pypi")]
+fn show_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("anyio==4.0.0")
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("idna")
+        .arg("sniffio")
+        .arg("--reverse")
+        .arg("--entry-points")
+        .arg("--output-format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"idna","version":"3.6","location":"[SITE_PACKAGES]/","requires":[],"required_by":["anyio"],"reverse_dependencies":["anyio"],"entry_points":[]},{"name":"sniffio","version":"1.3.1","location":"[SITE_PACKAGES]/","requires":[],"required_by":["anyio"],"reverse_dependencies":["anyio"],"entry_points":[]}]
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
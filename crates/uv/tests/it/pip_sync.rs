@@ -2690,6 +2690,72 @@ fn find_links() -> Result<()> {
     Ok(())
 }
 
+/// Sync exclusively from a local directory with `--find-links-only`.
+#[test]
+fn find_links_only() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse.create_dir_all()?;
+    fs::copy(
+        context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+        wheelhouse.child("ok-1.0.0-py3-none-any.whl"),
+    )?;
+
+    // Every requirement that can't be satisfied by the directory should be reported.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        ok==1.0.0
+        tqdm==1000.0.0
+        werkzeug @ https://files.pythonhosted.org/packages/c3/fc/254c3e9b5feb89ff5b9076a23218dafbc99c96ac5941e900b71206e6313b/werkzeug-3.0.1-py3-none-any.whl
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--find-links-only")
+        .arg("wheelhouse"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following distributions are missing from `wheelhouse`:
+      - tqdm==1000.0.0
+      - werkzeug @ https://files.pythonhosted.org/packages/c3/fc/254c3e9b5feb89ff5b9076a23218dafbc99c96ac5941e900b71206e6313b/werkzeug-3.0.1-py3-none-any.whl (requires network access)
+    ");
+
+    fs::copy(
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        wheelhouse.child("tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+    requirements_txt.write_str(indoc! {r"
+        ok==1.0.0
+        tqdm==1000.0.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--find-links-only")
+        .arg("wheelhouse"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + ok==1.0.0
+     + tqdm==1000.0.0
+    ");
+
+    Ok(())
+}
+
 /// Sync using `--find-links` with `--no-index`, which should accept the local wheel.
 #[test]
 fn find_links_no_index_match() -> Result<()> {
@@ -2968,6 +3034,53 @@ fn compatible_constraint() -> Result<()> {
     Ok(())
 }
 
+/// Layer multiple requirements files, such that a package pinned by a later file replaces the pin
+/// in an earlier file.
+#[test]
+fn layered_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let base_txt = context.temp_dir.child("base.txt");
+    base_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+    "})?;
+
+    let extra_txt = context.temp_dir.child("extra.txt");
+    extra_txt.write_str(indoc! {r"
+        sniffio==1.3.1
+        anyio==4.0.0
+    "})?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<4")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("base.txt")
+        .arg("extra.txt")
+        .arg("--constraint")
+        .arg("constraints.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Layering requirements from 2 files:
+     `base.txt`: idna==3.6
+     `extra.txt`: sniffio==1.3.1, anyio==4.0.0
+    Replaced `anyio==4.3.0` from `base.txt` with the requirements from `extra.txt`
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    Ok(())
+}
+
 /// Include a `constraints.txt` file with an incompatible constraint.
 #[test]
 fn incompatible_constraint() -> Result<()> {
@@ -3351,6 +3464,84 @@ fn recompile() -> Result<()> {
     Ok(())
 }
 
+/// Limit bytecode compilation to a subset of packages, at a given optimization level.
+#[test]
+fn compile_package_selection() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "ok @ {}\nvalidation @ {}",
+        Url::from_file_path(
+            context
+                .workspace_root
+                .join("scripts/links/ok-1.0.0-py3-none-any.whl")
+        )
+        .unwrap(),
+        Url::from_file_path(
+            context
+                .workspace_root
+                .join("scripts/links/validation-1.0.0-py3-none-any.whl")
+        )
+        .unwrap(),
+    ))?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile-bytecode")
+        .arg("--no-compile-bytecode-package")
+        .arg("validation")
+        .arg("--compile-bytecode-level")
+        .arg("2")
+        .assert()
+        .success();
+
+    assert!(
+        context
+            .site_packages()
+            .join("ok")
+            .join("__pycache__")
+            .join("__init__.cpython-312.opt-2.pyc")
+            .exists()
+    );
+    assert!(
+        !context
+            .site_packages()
+            .join("validation")
+            .join("__pycache__")
+            .exists()
+    );
+
+    // Compile only the selected package.
+    fs_err::remove_dir_all(context.site_packages().join("ok").join("__pycache__"))?;
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile-bytecode-package")
+        .arg("validation")
+        .assert()
+        .success();
+
+    assert!(
+        context
+            .site_packages()
+            .join("validation")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
+    assert!(
+        !context
+            .site_packages()
+            .join("ok")
+            .join("__pycache__")
+            .exists()
+    );
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
@@ -2,6 +2,7 @@
 
 use std::process::Command;
 
+use assert_cmd::prelude::*;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -1023,6 +1024,88 @@ fn show_version_specifiers_with_invert() {
     );
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+fn output_format_json() {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("flask").unwrap();
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    uv_snapshot!(
+        context.filters(),
+        context.pip_tree()
+        .arg("--output-format")
+        .arg("json")
+        .arg("--package")
+        .arg("werkzeug"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "werkzeug",
+        "version": "3.0.1",
+        "dependencies": [
+          {
+            "name": "markupsafe",
+            "version": "2.1.5",
+            "requirement": "markupsafe>=2.1.1"
+          }
+        ]
+      }
+    ]
+
+    ----- stderr -----
+    "#
+    );
+
+    // Invert the tree, to show the packages that require `markupsafe`, limited to a single level.
+    uv_snapshot!(
+        context.filters(),
+        context.pip_tree()
+        .arg("--output-format")
+        .arg("json")
+        .arg("--invert")
+        .arg("--package")
+        .arg("markupsafe")
+        .arg("--depth")
+        .arg("1"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "markupsafe",
+        "version": "2.1.5",
+        "dependencies": [
+          {
+            "name": "jinja2",
+            "version": "3.1.3",
+            "requirement": "markupsafe>=2.0"
+          },
+          {
+            "name": "werkzeug",
+            "version": "3.0.1",
+            "requirement": "markupsafe>=2.1.1"
+          }
+        ]
+      }
+    ]
+
+    ----- stderr -----
+    "#
+    );
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 fn show_version_specifiers_with_package() {
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Collect the wheels for a `requirements.txt` into a directory.
+#[test]
+fn wheel_requirements_txt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-w")
+        .arg("wheelhouse"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: [VENV]/
+    Resolved 3 packages in [TIME]
+    Collected 3 wheels in [TIME]
+     + anyio-3.7.0-py3-none-any.whl
+     + idna-3.6-py3-none-any.whl
+     + sniffio-1.3.1-py3-none-any.whl
+    "
+    );
+
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("anyio-3.7.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // Nothing is installed into the environment.
+    context.assert_not_installed("anyio");
+
+    Ok(())
+}
+
+/// Build a wheel for a local project, without its dependencies.
+#[test]
+fn wheel_local_project_no_deps() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project.child("README").touch()?;
+
+    uv_snapshot!(context.filters(), context.pip_wheel()
+        .arg("./project")
+        .arg("--no-deps"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: [VENV]/
+    Resolved 1 package in [TIME]
+    Collected 1 wheel in [TIME]
+     + project-0.1.0-py3-none-any.whl
+    "
+    );
+
+    context
+        .temp_dir
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    Ok(())
+}
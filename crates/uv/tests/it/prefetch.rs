@@ -0,0 +1,93 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Populate the cache from the lockfile, such that a subsequent offline sync succeeds.
+#[test]
+fn prefetch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Locking fetches the metadata, but not the distributions themselves, so an offline sync
+    // should report the missing wheel.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--offline"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+      × Network connectivity is disabled, but the following distributions are missing from the cache:
+      │ - `iniconfig==2.0.0` (https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+      help: Re-run without `--offline` to download the missing distributions, or use `uv prefetch` to populate the cache from a lockfile ahead of time
+    ");
+
+    uv_snapshot!(context.filters(), context.prefetch(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Prefetched 1 package in [TIME]
+    ");
+
+    // Nothing should have been installed.
+    context.assert_not_installed("iniconfig");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--offline"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Prefetching requires a lockfile.
+#[test]
+fn prefetch_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.prefetch(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+
+use uv_fs::Simplified;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Remove packages that aren't required by the lockfile, explaining why each package is removed.
+#[test]
+fn prune() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.workspace_root.join("scripts/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [project.optional-dependencies]
+        cli = ["validation"]
+
+        [dependency-groups]
+        dev = ["simple-launcher"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        validation = {{ path = "{}" }}
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        links.join("ok-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("validation-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("simple_launcher-0.1.0-py3-none-any.whl").portable_display(),
+    })?;
+
+    context.sync().arg("--all-extras").assert().success();
+
+    // Install a package that isn't in the lockfile.
+    context
+        .pip_install()
+        .arg(links.join("tqdm-1000.0.0-py3-none-any.whl"))
+        .assert()
+        .success();
+
+    // With the `cli` extra enabled, only the package that isn't in the lockfile is extraneous.
+    uv_snapshot!(context.filters(), context.project_prune().arg("--dry-run").arg("--all-extras"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 1 package
+     - tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl) (not in the lockfile)
+    ");
+
+    uv_snapshot!(context.filters(), context.project_prune().arg("--dry-run").arg("--no-dev"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 3 packages
+     - simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl) (only required by the `dev` group)
+     - tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl) (not in the lockfile)
+     - validation==1.0.0 (from file://[WORKSPACE]/scripts/links/validation-1.0.0-py3-none-any.whl) (only required by the `cli` extra)
+    ");
+
+    uv_snapshot!(context.filters(), context.project_prune().arg("--no-dev"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 3 packages in [TIME]
+     - simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl) (only required by the `dev` group)
+     - tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl) (not in the lockfile)
+     - validation==1.0.0 (from file://[WORKSPACE]/scripts/links/validation-1.0.0-py3-none-any.whl) (only required by the `cli` extra)
+    ");
+
+    assert!(!context.site_packages().join("validation").exists());
+    assert!(context.site_packages().join("ok").exists());
+
+    uv_snapshot!(context.filters(), context.project_prune().arg("--no-dev"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No packages to remove from the project environment
+    ");
+
+    Ok(())
+}
+
+/// `uv prune` requires a lockfile.
+#[test]
+fn prune_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.project_prune(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    ");
+
+    Ok(())
+}
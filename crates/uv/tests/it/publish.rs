@@ -385,4 +385,262 @@ fn invalid_index() {
     error: Index is missing a publish URL: `foo`
     "###
     );
+
+    // With multiple indexes, all indexes are resolved before publishing to any of them
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("__token__")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--index")
+        .arg("internal")
+        .arg("--index")
+        .arg("bar")
+        .arg(&ok_wheel)
+        .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Index is missing a publish URL: `internal`
+    "###
+    );
+}
+
+/// Check files for problems without uploading them.
+#[test]
+fn check() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--check")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Passed ok-1.0.0-py3-none-any.whl
+      warning: The metadata is missing a summary (`Summary`), set `project.description` in `pyproject.toml`
+      warning: The metadata is missing a long description, set `project.readme` in `pyproject.toml`
+      warning: The metadata is missing a license, set `project.license` in `pyproject.toml`
+    "###
+    );
+
+    // The version in the filename doesn't match the metadata.
+    let mismatched = context.temp_dir.child("ok-1.0.1-py3-none-any.whl");
+    fs_err::copy("../../scripts/links/ok-1.0.0-py3-none-any.whl", &mismatched).unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--check")
+        .arg(mismatched.path()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Failed ok-1.0.1-py3-none-any.whl
+      error: The version in the metadata (`1.0.0`) doesn't match the version in the filename (`1.0.1`)
+      warning: The metadata is missing a summary (`Summary`), set `project.description` in `pyproject.toml`
+      warning: The metadata is missing a long description, set `project.readme` in `pyproject.toml`
+      warning: The metadata is missing a license, set `project.license` in `pyproject.toml`
+    1 of 1 file failed the checks
+    "###
+    );
+}
+
+/// Private workspace members are not published.
+#[test]
+fn workspace_private_member() {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+            classifiers = ["Private :: Do Not Upload"]
+
+            [build-system]
+            requires = ["uv_build>=0.7,<10000"]
+            build-backend = "uv_build"
+        "#})
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--workspace")
+        .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Skipping `foo`, which is marked as private with the `Private :: Do Not Upload` classifier
+    error: No workspace members to publish
+    "###
+    );
+}
+
+/// `--wait` requires an index to poll, which isn't known for an arbitrary upload URL.
+#[test]
+fn wait_without_check_url() {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("foo-0.1.0-py3-none-any.whl")
+        .touch()
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://example.com/upload/")
+        .arg("--wait")
+        .arg(context.temp_dir.join("*.whl")), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--wait` and `--verify` require an index to check, but https://example.com/upload/ has no check URL; use `--index` or `--check-url`
+    "###
+    );
+}
+
+/// Yanking requires an index with a supported API.
+#[test]
+fn yank_unsupported_index() {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+
+            [[tool.uv.index]]
+            name = "internal"
+            url = "https://internal.example.com/simple"
+
+            [[tool.uv.index]]
+            name = "pypi"
+            url = "https://pypi.org/simple"
+        "#})
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("yank")
+        .arg("foo==0.1.0")
+        .arg("--index")
+        .arg("internal")
+        .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `internal` doesn't provide a supported API for yanking releases; currently, yanking is supported for AWS CodeArtifact
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("unyank")
+        .arg("foo==0.1.0")
+        .arg("--index")
+        .arg("pypi")
+        .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pypi.org doesn't provide an API for yanking releases; manage the release at https://pypi.org/manage/project/foo/release/0.1.0/ instead
+    "###
+    );
+}
+
+/// Files are parsed as files, even if they match the name of a subcommand, as long as they're
+/// passed with a directory or after `--`.
+#[test]
+fn files_named_like_subcommands() {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("yank").touch().unwrap();
+    context.temp_dir.child("not-a-wheel.whl").touch().unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("./yank")
+        .arg("not-a-wheel.whl")
+        .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping file that looks like a distribution, but is not a valid distribution filename: `not-a-wheel.whl`
+    error: No files found to publish
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--")
+        .arg("yank")
+        .arg("not-a-wheel.whl")
+        .current_dir(context.temp_dir.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping file that looks like a distribution, but is not a valid distribution filename: `not-a-wheel.whl`
+    error: No files found to publish
+    "###
+    );
+}
+
+/// Releases can be yanked by upload URL, rather than by index name.
+#[test]
+fn yank_publish_url() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("yank")
+        .arg("foo==0.1.0")
+        .arg("--publish-url")
+        .arg("https://upload.pypi.org/legacy/"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pypi.org doesn't provide an API for yanking releases; manage the release at https://pypi.org/manage/project/foo/release/0.1.0/ instead
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("unyank")
+        .arg("foo==0.1.0")
+        .arg("--publish-url")
+        .arg("https://internal.example.com/upload/"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: https://internal.example.com/upload/ doesn't provide a supported API for yanking releases; currently, yanking is supported for AWS CodeArtifact
+    "###
+    );
 }
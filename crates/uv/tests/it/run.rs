@@ -3,7 +3,7 @@
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{fixture::ChildPath, prelude::*};
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use predicates::{prelude::predicate, str::contains};
 use std::path::Path;
@@ -1731,6 +1731,125 @@ fn run_in_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Run a command in each workspace member with `--each-package`.
+#[test]
+fn run_each_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child1", "child2"]
+        "#
+    })?;
+
+    for name in ["child1", "child2"] {
+        let pyproject_toml = context.temp_dir.child(name).child("pyproject.toml");
+        pyproject_toml.write_str(&formatdoc! { r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+            "#
+        })?;
+    }
+
+    // The command runs from each member's directory.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--each-package")
+        .arg("--skip-package")
+        .arg("project")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.path.basename(os.getcwd()))"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child1
+    child2
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Succeeded in child1
+    Succeeded in child2
+    Ran `python -c import os; print(os.path.basename(os.getcwd()))` in 2 packages in [TIME]
+    ");
+
+    // A failure in one member is reported, and its exit code is propagated.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--each-package")
+        .arg("--skip-package")
+        .arg("project")
+        .arg("python")
+        .arg("-c")
+        .arg("import os, sys; sys.exit(3 if os.path.basename(os.getcwd()) == 'child1' else 0)"), @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Failed in child1 (exit code 3)
+    Succeeded in child2
+    Ran `python -c import os, sys; sys.exit(3 if os.path.basename(os.getcwd()) == 'child1' else 0)` in 2 packages in [TIME]
+    error: The command failed in 1 package: `child1`
+    ");
+
+    // With `--jobs`, the output of each member is printed as a unit once the member completes, so
+    // the output of `child2` precedes that of the slower `child1`.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--each-package")
+        .arg("--skip-package")
+        .arg("project")
+        .arg("--jobs")
+        .arg("2")
+        .arg("python")
+        .arg("-c")
+        .arg("import os, time; name = os.path.basename(os.getcwd()); print(name, 'start', flush=True); time.sleep(2 if name == 'child1' else 0); print(name, 'end')"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child2 start
+    child2 end
+    child1 start
+    child1 end
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Succeeded in child2
+    Succeeded in child1
+    Ran `python -c import os, time; name = os.path.basename(os.getcwd()); print(name, 'start', flush=True); time.sleep(2 if name == 'child1' else 0); print(name, 'end')` in 2 packages in [TIME]
+    ");
+
+    // Skipping an unknown package is an error.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--each-package")
+        .arg("--skip-package")
+        .arg("child3")
+        .arg("python")
+        .arg("-c")
+        .arg("pass"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `child3` not found in workspace
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_with_editable() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5192,7 +5311,6 @@ fn run_without_overlay() -> Result<()> {
 #[test]
 fn detect_infinite_recursion() -> Result<()> {
     use crate::common::get_bin;
-    use indoc::formatdoc;
     use std::os::unix::fs::PermissionsExt;
 
     let context = TestContext::new("3.12");
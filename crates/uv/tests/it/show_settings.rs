@@ -64,11 +64,65 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -76,12 +130,22 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -140,6 +204,21 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -179,7 +258,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -216,9 +297,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -249,11 +340,65 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -261,12 +406,22 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -325,6 +480,21 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -364,7 +534,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -401,9 +573,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -435,11 +617,65 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -447,12 +683,22 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -511,6 +757,21 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -550,7 +811,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -587,9 +850,19 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -653,11 +926,65 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -665,12 +992,22 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -729,6 +1066,21 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -768,7 +1120,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -805,9 +1159,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -840,11 +1204,65 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -852,12 +1270,22 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -921,7 +1349,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -958,9 +1388,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1003,11 +1443,65 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -1015,12 +1509,22 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -1079,6 +1583,21 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -1118,7 +1637,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1157,9 +1678,19 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1215,11 +1746,65 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -1227,12 +1812,22 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -1291,6 +1886,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -1324,6 +1934,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -1363,7 +1988,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1400,9 +2027,19 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1435,11 +2072,65 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -1447,12 +2138,22 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -1513,6 +2214,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -1546,6 +2262,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -1579,6 +2310,21 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -1618,7 +2364,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1655,9 +2403,19 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1713,11 +2471,65 @@ fn resolve_find_links() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -1725,12 +2537,22 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -1790,6 +2612,21 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 no_index: true,
@@ -1828,7 +2665,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -1865,9 +2704,19 @@ fn resolve_find_links() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1922,11 +2771,65 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -1934,12 +2837,22 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -2003,7 +2916,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2040,9 +2955,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2090,11 +3015,65 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -2102,12 +3081,22 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -2166,6 +3155,21 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -2199,6 +3203,21 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -2238,7 +3257,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2275,9 +3296,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2308,11 +3339,65 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -2320,12 +3405,22 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -2384,6 +3479,21 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -2417,6 +3527,21 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -2456,7 +3581,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2493,9 +3620,19 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2549,11 +3686,65 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -2561,12 +3752,22 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -2630,7 +3831,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2667,9 +3870,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2707,11 +3920,65 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -2719,12 +3986,22 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -2788,7 +4065,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2825,9 +4104,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2865,11 +4154,65 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -2877,12 +4220,22 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -2946,7 +4299,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -2983,9 +4338,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3025,11 +4390,65 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -3037,12 +4456,22 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -3106,7 +4535,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -3143,9 +4574,19 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3204,11 +4645,65 @@ fn resolve_tool() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -3216,12 +4711,22 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3264,6 +4769,10 @@ fn resolve_tool() -> anyhow::Result<()> {
                 Clone,
             ),
             compile_bytecode: None,
+            compile_bytecode_level: None,
+            compile_bytecode_package: None,
+            no_compile_bytecode_package: None,
+            install_hooks: None,
             no_sources: None,
             upgrade: None,
             upgrade_package: None,
@@ -3300,13 +4809,24 @@ fn resolve_tool() -> anyhow::Result<()> {
                 keyring_provider: Disabled,
                 link_mode: Clone,
                 no_build_isolation: false,
-                no_build_isolation_package: [],
+                no_build_isolation_package: Packages(
+                    [],
+                ),
                 prerelease: IfNecessaryOrExplicit,
                 resolution: LowestDirect,
                 sources: Enabled,
                 upgrade: None,
             },
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             reinstall: None,
         },
         force: false,
@@ -3373,11 +4893,65 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -3385,12 +4959,22 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -3454,7 +5038,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -3491,9 +5077,19 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3565,11 +5161,65 @@ fn resolve_both() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -3577,12 +5227,22 @@ fn resolve_both() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -3641,6 +5301,21 @@ fn resolve_both() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -3680,7 +5355,9 @@ fn resolve_both() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -3717,9 +5394,19 @@ fn resolve_both() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3795,11 +5482,65 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -3807,12 +5548,22 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -3871,6 +5622,21 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -3910,7 +5676,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -3947,9 +5715,19 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4104,11 +5882,65 @@ fn resolve_config_file() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -4116,12 +5948,22 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -4180,6 +6022,21 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -4219,7 +6076,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -4256,9 +6115,19 @@ fn resolve_config_file() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4300,7 +6169,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `cache-max-size`, `cache-eviction-weights`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `concurrent-compiles`, `windows-install-robustness`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `compile-bytecode-level`, `compile-bytecode-package`, `no-compile-bytecode-package`, `install-hooks`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `expose-scripts`, `confirm-download-size`, `max-download-size`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `build-constraint-dependencies-package`, `patches`, `tasks`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4389,11 +6258,65 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -4401,12 +6324,22 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -4470,7 +6403,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -4507,9 +6442,19 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4550,11 +6495,65 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -4562,12 +6561,22 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -4631,7 +6640,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -4668,9 +6679,19 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4730,11 +6751,65 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -4742,12 +6817,22 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -4811,7 +6896,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -4848,9 +6935,19 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4902,11 +6999,65 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -4914,12 +7065,22 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -4980,6 +7141,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -5013,6 +7189,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -5052,7 +7243,9 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -5089,9 +7282,19 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5122,11 +7325,65 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -5134,12 +7391,22 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -5200,6 +7467,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -5233,6 +7515,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -5272,7 +7569,9 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -5309,9 +7608,19 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5348,11 +7657,65 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -5360,12 +7723,22 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -5426,6 +7799,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -5459,6 +7847,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -5498,7 +7901,9 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -5535,9 +7940,19 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5569,11 +7984,65 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -5581,12 +8050,22 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -5647,6 +8126,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -5680,6 +8174,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -5719,7 +8228,9 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -5756,9 +8267,19 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5797,11 +8318,65 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -5809,12 +8384,22 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -5875,6 +8460,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -5908,6 +8508,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -5947,7 +8562,9 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -5984,9 +8601,19 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6018,11 +8645,65 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -6030,12 +8711,22 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipCompileSettings {
         format: None,
@@ -6096,6 +8787,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                     Index {
                         name: None,
@@ -6129,6 +8835,21 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        fallback_of: None,
+                        ssl_ca_cert: None,
+                        ssl_client_cert: None,
+                        ssl_client_key: None,
+                        proxy: None,
+                        resolve: None,
+                        simple_api: None,
+                        core_metadata: None,
+                        quirks: None,
+                        require_hashes_from_index: false,
+                        not_found_cache_ttl: None,
+                        azure_identity: None,
+                        credential_helper: None,
+                        headers: None,
+                        token_exchange: None,
                     },
                 ],
                 flat_index: [],
@@ -6168,7 +8889,9 @@ fn index_priority() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -6205,9 +8928,19 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6252,11 +8985,65 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -6264,12 +9051,22 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipInstallSettings {
         package: [],
@@ -6281,6 +9078,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
+        output_format: Text,
+        force: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6333,7 +9133,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -6370,9 +9172,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6403,11 +9215,65 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -6415,12 +9281,22 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipInstallSettings {
         package: [],
@@ -6432,6 +9308,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
+        output_format: Text,
+        force: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6484,7 +9363,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -6521,9 +9402,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -6552,11 +9443,65 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -6564,12 +9509,22 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipInstallSettings {
         package: [],
@@ -6581,6 +9536,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
+        output_format: Text,
+        force: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6633,7 +9591,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -6670,9 +9630,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Require,
@@ -6703,11 +9673,65 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -6715,12 +9739,22 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipInstallSettings {
         package: [],
@@ -6732,6 +9766,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
+        output_format: Text,
+        force: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6784,7 +9821,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -6821,9 +9860,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -6852,11 +9901,65 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -6864,12 +9967,22 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipInstallSettings {
         package: [],
@@ -6881,6 +9994,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
+        output_format: Text,
+        force: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6933,7 +10049,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -6970,9 +10088,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -7002,11 +10130,65 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            proxy_settings: ProxySettings {
+                http_proxy: None,
+                https_proxy: None,
+                no_proxy: [],
+            },
+            retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            download_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            upload_retry_policy: HttpRetryPolicy {
+                retries: 3,
+                backoff_base: 1s,
+                backoff_max: 1800s,
+                jitter: true,
+                retry_after: false,
+                retry_on: [
+                    Network,
+                    ServerErrors,
+                    RateLimits,
+                ],
+            },
+            http_connection: HttpConnectionOptions {
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                tcp_keepalive: None,
+                http2_keep_alive_interval: None,
+                http2_keep_alive_timeout: None,
+                http2_initial_stream_window_size: None,
+                http2_initial_connection_window_size: None,
+                http2_adaptive_window: None,
+                http3: None,
+            },
         },
         concurrency: Concurrency {
             downloads: 50,
+            downloads_per_host: 50,
             builds: 16,
             installs: 8,
+            compiles: 8,
         },
         show_settings: true,
         preview: Disabled,
@@ -7014,12 +10196,22 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        windows_install_robustness: Retry,
+        wait_timeout: None,
+        download_bandwidth: None,
+        trace_http: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_size: None,
+        eviction_weights: None,
+        index_isolation: false,
+        roots: None,
+        remote_url: None,
+        remote_upload: false,
     }
     PipInstallSettings {
         package: [],
@@ -7031,6 +10223,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        report: None,
+        output_format: Text,
+        force: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7083,7 +10278,9 @@ fn verify_hashes() -> anyhow::Result<()> {
             keyring_provider: Disabled,
             torch_backend: None,
             no_build_isolation: false,
-            no_build_isolation_package: [],
+            no_build_isolation_package: Packages(
+                [],
+            ),
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
@@ -7120,9 +10317,19 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_source_lines: false,
             annotation_style: Split,
             link_mode: Clone,
-            compile_bytecode: false,
+            compile_bytecode: CompileBytecode {
+                all: false,
+                include: [],
+                exclude: [],
+                level: None,
+            },
+            install_hooks: InstallHooks {
+                pre_install: [],
+                post_install: [],
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -0,0 +1,255 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Write a project that depends on a local wheel, referenced by the given path.
+fn write_project(context: &TestContext, name: &str, wheel: &std::path::Path) -> Result<()> {
+    let pyproject_toml = context.temp_dir.child(name).child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "{name}"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        "#,
+        wheel.portable_display(),
+    })?;
+    Ok(())
+}
+
+/// Identical wheels should share a single entry in the store, even when referenced by different
+/// paths.
+#[test]
+fn store_dedup() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+    let copy = context
+        .temp_dir
+        .child("links")
+        .child("ok-1.0.0-py3-none-any.whl");
+    copy.write_binary(&fs_err::read(&wheel)?)?;
+
+    write_project(&context, "first", &wheel)?;
+    write_project(&context, "second", copy.path())?;
+
+    context
+        .sync()
+        .current_dir(context.temp_dir.child("first"))
+        .assert()
+        .success();
+    context
+        .sync()
+        .current_dir(context.temp_dir.child("second"))
+        .assert()
+        .success();
+
+    // Both wheels should be unpacked into the same, content-addressed entry.
+    let entries = fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].starts_with("sha256-"));
+
+    uv_snapshot!(context.filters(), context.store_gc(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting garbage in store at: [CACHE_DIR]/archive-v0
+    No unused wheels found
+    ");
+
+    Ok(())
+}
+
+/// `store gc` should remove unreferenced wheels, and, with `--verify`, corrupted wheels.
+#[test]
+fn store_gc_verify() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+    write_project(&context, "project", &wheel)?;
+
+    context
+        .sync()
+        .current_dir(context.temp_dir.child("project"))
+        .assert()
+        .success();
+
+    // Add an unreferenced entry to the store.
+    context
+        .cache_dir
+        .child("archive-v0")
+        .child("unused")
+        .child("file.txt")
+        .write_str("unused")?;
+
+    uv_snapshot!(context.filters(), context.store_gc().arg("--verify"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting garbage in store at: [CACHE_DIR]/archive-v0
+    Removed 1 unused wheel, verified 1 wheel ([SIZE])
+    ");
+
+    // Corrupt the remaining entry.
+    let archive = fs_err::read_dir(context.cache_dir.join("archive-v0"))?
+        .next()
+        .unwrap()?
+        .path();
+    fs_err::write(archive.join("ok").join("__init__.py"), "raise ImportError")?;
+
+    uv_snapshot!(context.filters(), context.store_gc().arg("--verify"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting garbage in store at: [CACHE_DIR]/archive-v0
+    Removing corrupted wheel `ok-1.0.0` (1 file missing or modified)
+    No unused wheels found, verified 0 wheels, and removed 1 corrupted wheel ([SIZE])
+    ");
+
+    // The wheel should be unpacked again on the next sync.
+    context
+        .sync()
+        .arg("--reinstall")
+        .current_dir(context.temp_dir.child("project"))
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.store_gc().arg("--verify"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Collecting garbage in store at: [CACHE_DIR]/archive-v0
+    No unused wheels found, verified 1 wheel
+    ");
+
+    Ok(())
+}
+
+/// Identical wheels fetched from different indexes should share a single entry in the store,
+/// unless `cache-index-isolation` is enabled.
+#[test]
+#[cfg(feature = "pypi")]
+fn store_index_isolation() -> Result<()> {
+    let requirements_txt = "iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374";
+
+    for (isolation, expected) in [(false, 1), (true, 2)] {
+        let context = TestContext::new("3.12");
+        context
+            .temp_dir
+            .child("requirements.txt")
+            .write_str(requirements_txt)?;
+
+        for index_url in [
+            "https://pypi.org/simple",
+            "https://pypi-proxy.fly.dev/simple",
+        ] {
+            context
+                .pip_install()
+                .arg("-r")
+                .arg("requirements.txt")
+                .arg("--reinstall")
+                .arg("--index-url")
+                .arg(index_url)
+                .env(
+                    EnvVars::UV_CACHE_INDEX_ISOLATION,
+                    if isolation { "1" } else { "0" },
+                )
+                .assert()
+                .success();
+        }
+
+        let entries = fs_err::read_dir(context.cache_dir.join("archive-v0"))?.count();
+        assert_eq!(entries, expected);
+    }
+
+    Ok(())
+}
+
+/// A wheel that's already in the store should be reused when an index reports the same digest,
+/// without downloading it again.
+#[tokio::test]
+#[cfg(feature = "pypi")]
+async fn store_reuse_across_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Fetch the wheel from PyPI, with hash-checking enabled, such that it's stored by digest.
+    context
+        .temp_dir
+        .child("requirements.txt")
+        .write_str("iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Serve a mirror that reports the same digest, but fails to serve the wheel itself.
+    let server = MockServer::start().await;
+    let page = format!(
+        r#"
+    <!DOCTYPE html>
+    <html>
+        <body>
+        <h1>Links for iniconfig</h1>
+        <a href="{}/files/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374">iniconfig-2.0.0-py3-none-any.whl</a><br/>
+    </body>
+    </html>
+    "#,
+        server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/iniconfig/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(page, "text/html"))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context
+        .pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .arg("--index-url")
+        .arg(server.uri()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    ");
+
+    let entries = fs_err::read_dir(context.cache_dir.join("archive-v0"))?.count();
+    assert_eq!(entries, 1);
+
+    Ok(())
+}
@@ -496,6 +496,7 @@ fn sync_dry_json() -> Result<()> {
     // Running `uv sync` should report intent to create the environment and lockfile
     uv_snapshot!(context.filters(), context.sync()
         .arg("--output-format").arg("json")
+        .arg("--link-mode").arg("copy")
         .arg("--dry-run"), @r#"
     success: true
     exit_code: 0
@@ -520,7 +521,27 @@ fn sync_dry_json() -> Result<()> {
             "implementation": "cpython"
           }
         },
-        "action": "create"
+        "action": "create",
+        "plan": {
+          "link_mode": "copy",
+          "download_size": 5892,
+          "changes": [
+            {
+              "name": "iniconfig",
+              "action": "install",
+              "version": "2.0.0",
+              "artifact": {
+                "kind": "wheel",
+                "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+                "hashes": [
+                  "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                ],
+                "size": 5892,
+                "cached": false
+              }
+            }
+          ]
+        }
       },
       "lock": {
         "path": "[TEMP_DIR]/uv.lock",
@@ -11439,3 +11460,656 @@ fn sync_does_not_remove_empty_virtual_environment_directory() -> Result<()> {
 
     Ok(())
 }
+
+/// Sync the locked packages into a `--target` directory, without creating a project environment.
+#[test]
+fn sync_target() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["simple-launcher"]
+
+        [tool.uv.sources]
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        wheel.portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    ");
+
+    // The packages should be installed at the top-level of the directory, and the project
+    // environment should be left untouched.
+    context
+        .temp_dir
+        .child("target")
+        .child("simple_launcher")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    context
+        .temp_dir
+        .child("target")
+        .child("bin")
+        .child("simple_launcher")
+        .assert(predicate::path::is_file());
+    assert!(!context.site_packages().join("simple_launcher").exists());
+
+    // A second sync should be a no-op.
+    uv_snapshot!(context.filters(), context.sync().arg("--target").arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Sync the locked packages into a `--prefix` directory, omitting scripts.
+#[test]
+fn sync_prefix_no_scripts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["simple-launcher"]
+
+        [tool.uv.sources]
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        wheel.portable_display(),
+    })?;
+
+    // `--no-scripts` requires `--target` or `--prefix`.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-scripts"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      <--target <TARGET>|--prefix <PREFIX>>
+
+    Usage: uv sync --cache-dir [CACHE_DIR] --no-scripts --exclude-newer <EXCLUDE_NEWER> <--target <TARGET>|--prefix <PREFIX>>
+
+    For more information, try '--help'.
+    ");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--prefix").arg("prefix").arg("--no-scripts"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    ");
+
+    let site_packages = context
+        .temp_dir
+        .child("prefix")
+        .child("lib")
+        .child("python3.12")
+        .child("site-packages");
+    site_packages
+        .child("simple_launcher")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    context
+        .temp_dir
+        .child("prefix")
+        .child("bin")
+        .assert(predicate::path::missing());
+
+    // The skipped scripts should not be recorded.
+    let record = fs_err::read_to_string(
+        site_packages
+            .child("simple_launcher-0.1.0.dist-info")
+            .child("RECORD"),
+    )?;
+    assert!(!record.contains("bin/simple_launcher"));
+
+    Ok(())
+}
+
+/// Explain why each extraneous package is removed by an exact sync.
+#[test]
+fn sync_prune_explain() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.workspace_root.join("scripts/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [project.optional-dependencies]
+        cli = ["validation"]
+
+        [dependency-groups]
+        dev = ["simple-launcher"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        validation = {{ path = "{}" }}
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        links.join("ok-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("validation-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("simple_launcher-0.1.0-py3-none-any.whl").portable_display(),
+    })?;
+
+    context.sync().arg("--all-extras").assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-dev").arg("--prune-explain").arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would use project environment at: .venv
+    Resolved 4 packages in [TIME]
+    Found up-to-date lockfile at: uv.lock
+    Would uninstall 2 packages
+     - simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+     - validation==1.0.0 (from file://[WORKSPACE]/scripts/links/validation-1.0.0-py3-none-any.whl)
+    Would remove simple-launcher (only required by the `dev` group)
+    Would remove validation (only required by the `cli` extra)
+    ");
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-dev").arg("--prune-explain"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+     - simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+     - validation==1.0.0 (from file://[WORKSPACE]/scripts/links/validation-1.0.0-py3-none-any.whl)
+    Removed simple-launcher (only required by the `dev` group)
+    Removed validation (only required by the `cli` extra)
+    ");
+
+    // `--prune-explain` requires an exact sync.
+    uv_snapshot!(context.filters(), context.sync().arg("--prune-explain").arg("--inexact"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--prune-explain' cannot be used with '--inexact'
+
+    Usage: uv sync --cache-dir [CACHE_DIR] --prune-explain --exclude-newer <EXCLUDE_NEWER>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Expose the scripts installed in the project environment with `--expose-scripts`.
+#[test]
+#[cfg(unix)]
+fn sync_expose_scripts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.workspace_root.join("scripts/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [dependency-groups]
+        dev = ["simple-launcher"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        links.join("ok-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("simple_launcher-0.1.0-py3-none-any.whl").portable_display(),
+    })?;
+
+    // Files that weren't created by uv should be left untouched.
+    context.temp_dir.child("bin").child("other").touch()?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--expose-scripts").arg("bin"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+     + simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    Exposed 1 script in bin: simple_launcher
+    warning: `[TEMP_DIR]/bin` is not on your PATH. To use the exposed scripts, add the directory to your PATH.
+    ");
+
+    let launcher = context.temp_dir.child("bin").child("simple_launcher");
+    assert_eq!(
+        fs_err::read_link(&launcher)?,
+        context.venv.child("bin").child("simple_launcher").path()
+    );
+
+    // Syncing again should be a no-op.
+    uv_snapshot!(context.filters(), context.sync().arg("--expose-scripts").arg("bin"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 2 packages in [TIME]
+    ");
+
+    // Removing the script from the environment should remove the launcher.
+    uv_snapshot!(context.filters(), context.sync().arg("--no-dev").arg("--expose-scripts").arg("bin"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    Removed 1 script from bin: simple_launcher
+    ");
+
+    assert!(!launcher.exists());
+    assert!(context.temp_dir.child("bin").child("other").exists());
+
+    // The directory can also be set in the project configuration, relative to the workspace root.
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [dependency-groups]
+        dev = ["simple-launcher"]
+
+        [tool.uv]
+        expose-scripts = "bin"
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        links.join("ok-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("simple_launcher-0.1.0-py3-none-any.whl").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    Exposed 1 script in bin: simple_launcher
+    warning: `[TEMP_DIR]/bin` is not on your PATH. To use the exposed scripts, add the directory to your PATH.
+    ");
+
+    assert!(launcher.exists());
+
+    // Files with the same name as a script are skipped.
+    fs_err::remove_file(&launcher)?;
+    launcher.touch()?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--reinstall-package").arg("simple-launcher"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    warning: Skipping `simple_launcher`, since a file with the same name already exists at: bin/simple_launcher
+    ");
+
+    Ok(())
+}
+
+/// Enforce limits on the total download size with `--max-download-size` and
+/// `confirm-download-size`.
+#[test]
+fn sync_download_size_limits() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+        "#,
+    )?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 2
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+            { name = "sniffio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig" },
+            { name = "sniffio" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+        ]
+    "#})?;
+
+    // The download exceeds the maximum size, so nothing should be downloaded.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--max-download-size").arg("10kB"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to download [SIZE], which exceeds the maximum download size of [SIZE]
+    ");
+
+    // The maximum can also be set in the configuration.
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        max-download-size = "12KiB"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to download [SIZE], which exceeds the maximum download size of [SIZE]
+    ");
+
+    // The command line takes precedence over the configuration. If the download exceeds the
+    // confirmation threshold, the size should be reported before downloading.
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        max-download-size = "12KiB"
+        confirm-download-size = "8KiB"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--offline").arg("--max-download-size").arg("1MB"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloading [SIZE] across 2 packages (largest: sniffio ([SIZE]), iniconfig ([SIZE]))
+      × Failed to download `sniffio==1.3.1`
+      ╰─▶ Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl`
+      help: `sniffio` (v1.3.1) was included because `project` (v0.1.0) depends on `sniffio`
+    ");
+
+    // Invalid sizes should be rejected.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--max-download-size").arg("10 parsecs"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '10 parsecs' for '--max-download-size <SIZE>': Invalid size `10 parsecs`: unknown unit `parsecs` (expected one of `B`, `kB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`)
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Reinstall a single locked package with `--only-package`, leaving the rest of the environment
+/// untouched.
+#[test]
+fn sync_only_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.workspace_root.join("scripts/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok", "simple-launcher"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        simple-launcher = {{ path = "{}" }}
+        "#,
+        links.join("ok-1.0.0-py3-none-any.whl").portable_display(),
+        links.join("simple_launcher-0.1.0-py3-none-any.whl").portable_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+     + simple-launcher==0.1.0 (from file://[WORKSPACE]/scripts/links/simple_launcher-0.1.0-py3-none-any.whl)
+    ");
+
+    // Install an extraneous package, which a regular sync would remove.
+    uv_snapshot!(context.filters(), context.pip_install().arg(links.join("tqdm-1000.0.0-py3-none-any.whl")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl)
+    ");
+
+    // Only the requested package should be reinstalled.
+    uv_snapshot!(context.filters(), context.sync().arg("--only-package").arg("ok"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+    ");
+
+    // Packages that aren't part of the locked environment should be rejected.
+    uv_snapshot!(context.filters(), context.sync().arg("--only-package").arg("tqdm"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: Package `tqdm` is not included in the locked environment for the requested extras and dependency groups
+    ");
+
+    // The extraneous package should be removed by a regular sync.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - tqdm==1000.0.0 (from file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl)
+    ");
+
+    Ok(())
+}
+
+/// Install the locked distributions from a local directory with `--find-links-only`.
+#[test]
+fn sync_find_links_only() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok", "tqdm"]
+        "#,
+    )?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 2
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/00/00/ok-1.0.0-py3-none-any.whl", hash = "sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f", size = 875 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "ok" },
+            { name = "tqdm" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "ok" },
+            { name = "tqdm" },
+        ]
+
+        [[package]]
+        name = "tqdm"
+        version = "1000.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/00/00/tqdm-1000.0.0-py3-none-any.whl", hash = "sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13", size = 1017 },
+        ]
+    "#})?;
+
+    let wheelhouse = context.temp_dir.child("wheelhouse");
+    wheelhouse.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+        wheelhouse.child("ok-1.0.0-py3-none-any.whl"),
+    )?;
+
+    // Every missing distribution should be reported, without attempting to download it.
+    uv_snapshot!(context.filters(), context.sync().arg("--find-links-only").arg("wheelhouse"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following distributions are missing from `wheelhouse`:
+      - tqdm==1000.0.0 (expected `tqdm-1000.0.0-py3-none-any.whl`)
+    ");
+
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl"),
+        wheelhouse.child("tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--find-links-only").arg("wheelhouse"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + ok==1.0.0
+     + tqdm==1000.0.0
+    ");
+
+    Ok(())
+}
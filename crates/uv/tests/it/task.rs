@@ -0,0 +1,159 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Run a task, along with the tasks it depends on, in the project environment.
+#[test]
+fn task_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [dependency-groups]
+        test = ["iniconfig"]
+
+        [tool.uv.tasks]
+        hello = ["python", "-c", "print('hello')"]
+        greet = { cmd = ["python", "-c", "import os; print(os.environ['GREETING'])"], env = { GREETING = "hi" } }
+        test = { cmd = ["python", "-c", "import iniconfig; print(iniconfig.__name__)"], groups = ["test"] }
+        check = { depends-on = ["hello", "test"] }
+        "#
+    })?;
+
+    // Tasks run in the order given, after the tasks they depend on.
+    uv_snapshot!(context.filters(), context.task_run().arg("greet").arg("check"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hi
+    hello
+    iniconfig
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Installed 1 package in [TIME]
+    Running task `greet`: python -c import os; print(os.environ['GREETING'])
+    Running task `hello`: python -c print('hello')
+    Running task `test`: python -c import iniconfig; print(iniconfig.__name__)
+    ");
+
+    // A task shared by multiple requested tasks runs once.
+    uv_snapshot!(context.filters(), context.task_run().arg("hello").arg("check"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+    iniconfig
+
+    ----- stderr -----
+    Running task `hello`: python -c print('hello')
+    Running task `test`: python -c import iniconfig; print(iniconfig.__name__)
+    ");
+
+    uv_snapshot!(context.filters(), context.task_list(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    check Runs: `hello`, `test`
+    greet python -c import os; print(os.environ['GREETING'])
+    hello python -c print('hello')
+    test python -c import iniconfig; print(iniconfig.__name__)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Stop at the first task that fails, propagating its exit code.
+#[test]
+fn task_run_failure() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv.tasks]
+        fail = "exit 3"
+        hello = ["python", "-c", "print('hello')"]
+        all = { depends-on = ["fail", "hello"] }
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.task_run().arg("all"), @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Running task `fail`: exit 3
+    error: Task `fail` failed
+    ");
+
+    Ok(())
+}
+
+/// Reject unknown tasks, undefined dependencies, and cyclic dependencies.
+#[test]
+fn task_run_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv.tasks]
+        a = { cmd = "echo a", depends-on = ["b"] }
+        b = { cmd = "echo b", depends-on = ["a"] }
+        c = { cmd = "echo c", depends-on = ["d"] }
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.task_run().arg("e"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Task `e` not found
+
+    hint: the available tasks are: `a`, `b`, `c`
+    ");
+
+    uv_snapshot!(context.filters(), context.task_run().arg("a"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Task `a` depends on itself: `a` -> `b` -> `a`
+    ");
+
+    uv_snapshot!(context.filters(), context.task_run().arg("c"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Task `c` depends on `d`, which is not defined
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+
+use uv_fs::Simplified;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Detect and repair files that were modified or deleted after installation.
+#[test]
+fn verify_modified_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        "#,
+        wheel.portable_display(),
+    })?;
+
+    context
+        .sync()
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    ");
+
+    // Modify one file, and delete another.
+    fs_err::write(
+        context.site_packages().join("ok").join("__init__.py"),
+        "raise ImportError",
+    )?;
+    fs_err::remove_file(
+        context
+            .site_packages()
+            .join("ok-1.0.0.dist-info")
+            .join("METADATA"),
+    )?;
+
+    uv_snapshot!(context.filters(), context.verify(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+     ! ok==1.0.0
+         ok-1.0.0.dist-info/METADATA (missing)
+         ok/__init__.py (modified)
+    Found 1 issue in the project environment; run `uv verify --fix` to repair it
+    ");
+
+    uv_snapshot!(context.filters(), context.verify().arg("--fix").arg("--link-mode").arg("copy"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+     ! ok==1.0.0
+         ok-1.0.0.dist-info/METADATA (missing)
+         ok/__init__.py (modified)
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+    Repaired 1 issue in the project environment
+    ");
+
+    uv_snapshot!(context.filters(), context.verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Detect packages that are missing from, or extraneous to, the lockfile.
+#[test]
+fn verify_missing_and_extraneous() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let ok = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+    let validation = context
+        .workspace_root
+        .join("scripts/links/validation-1.0.0-py3-none-any.whl");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [tool.uv.sources]
+        ok = {{ path = "{}" }}
+        "#,
+        ok.portable_display(),
+    })?;
+
+    context.sync().assert().success();
+
+    // Remove a locked package, and install an extraneous one.
+    context.pip_uninstall().arg("ok").assert().success();
+    context.pip_install().arg(&validation).assert().success();
+
+    uv_snapshot!(context.filters(), context.verify(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 0 packages in [TIME]
+     + ok==1.0.0 (not installed)
+     - validation==1.0.0 (not in the lockfile)
+    Found 2 issues in the project environment; run `uv verify --fix` to repair it
+    ");
+
+    // Extraneous packages are ignored with `--inexact`.
+    uv_snapshot!(context.filters(), context.verify().arg("--inexact"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 0 packages in [TIME]
+     + ok==1.0.0 (not installed)
+    Found 1 issue in the project environment; run `uv verify --fix` to repair it
+    ");
+
+    uv_snapshot!(context.filters(), context.verify().arg("--fix"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 0 packages in [TIME]
+     + ok==1.0.0 (not installed)
+     - validation==1.0.0 (not in the lockfile)
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0 (from file://[WORKSPACE]/scripts/links/ok-1.0.0-py3-none-any.whl)
+     - validation==1.0.0 (from file://[WORKSPACE]/scripts/links/validation-1.0.0-py3-none-any.whl)
+    Repaired 2 issues in the project environment
+    ");
+
+    Ok(())
+}
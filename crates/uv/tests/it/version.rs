@@ -2203,6 +2203,143 @@ fn version_set_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Bump the version of a workspace member, updating the specifiers of its dependents
+#[test]
+#[cfg(feature = "pypi")]
+fn version_bump_workspace_dependents() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let workspace = context.temp_dir.child("pyproject.toml");
+    workspace.write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["child1", "child2"]
+    "#})?;
+
+    let pyproject_toml = context.temp_dir.child("child1/pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "child1"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    context
+        .temp_dir
+        .child("child1")
+        .child("src")
+        .child("child1")
+        .child("__init__.py")
+        .touch()?;
+
+    let dependent_toml = context.temp_dir.child("child2/pyproject.toml");
+    dependent_toml.write_str(indoc! {r#"
+        [project]
+        name = "child2"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "child1==0.1.0",
+        ]
+
+        [project.optional-dependencies]
+        compat = ["child1>=0.1.0,<1.0.0"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv.sources]
+        child1 = { workspace = true }
+    "#})?;
+    context
+        .temp_dir
+        .child("child2")
+        .child("src")
+        .child("child2")
+        .child("__init__.py")
+        .touch()?;
+
+    // A dry run reports the edits to the dependents, without applying them.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--package")
+        .arg("child1")
+        .arg("--bump")
+        .arg("minor")
+        .arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child1 0.1.0 => 0.2.0
+
+    ----- stderr -----
+    Would update `child2/pyproject.toml`: `child1==0.1.0` => `child1==0.2.0`
+    Would update `child2/pyproject.toml`: `child1>=0.1.0, <1.0.0` => `child1>=0.2.0, <1.0.0`
+    ");
+
+    let dependent = fs_err::read_to_string(&dependent_toml)?;
+    assert!(dependent.contains(r#""child1==0.1.0""#));
+
+    // Bump the version, updating the dependents and the lockfile.
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--package")
+        .arg("child1")
+        .arg("--bump")
+        .arg("minor"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child1 0.1.0 => 0.2.0
+
+    ----- stderr -----
+    Updated `child2/pyproject.toml`: `child1==0.1.0` => `child1==0.2.0`
+    Updated `child2/pyproject.toml`: `child1>=0.1.0, <1.0.0` => `child1>=0.2.0, <1.0.0`
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child1==0.2.0 (from file://[TEMP_DIR]/child1)
+    ");
+
+    let dependent = fs_err::read_to_string(&dependent_toml)?;
+    assert_snapshot!(
+        dependent,
+    @r#"
+    [project]
+    name = "child2"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "child1==0.2.0",
+    ]
+
+    [project.optional-dependencies]
+    compat = ["child1>=0.2.0, <1.0.0"]
+
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [tool.uv.sources]
+    child1 = { workspace = true }
+    "#
+    );
+
+    // The lockfile is consistent with the updated specifiers.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Edit the version of a workspace member in a way that breaks a version
 /// constraint, forcing the lockfile to be updated non-trivially.
 ///